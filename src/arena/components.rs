@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Marker component for arena wall entities
 #[derive(Component)]
@@ -38,6 +39,52 @@ impl Default for TorchLight {
     }
 }
 
+/// Marker for everything an arena layout spawns (rocks, barriers, hazard tiles, props).
+/// Despawned when the next level builds a different layout.
+#[derive(Component)]
+pub struct ArenaFeature;
+
+/// Ground hazard a layout can paint onto the arena floor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HazardKind {
+    /// Burns the player and enemies standing on it with Fire damage
+    Lava,
+    /// Bodies on ice keep their momentum and turn slowly
+    Ice,
+}
+
+impl HazardKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HazardKind::Lava => "Lava",
+            HazardKind::Ice => "Ice",
+        }
+    }
+}
+
+/// Visual floor tile for a hazard cell (lookups go through `HazardMap`)
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HazardTile(pub HazardKind);
+
+/// Momentum carried by a body while it stands on ice.
+/// Inserted when the body steps onto ice and removed when it leaves.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct IceSlide {
+    /// Position after last frame's slide
+    pub last_position: Vec2,
+    /// Sliding velocity; `None` until the first frame on ice has been measured
+    pub velocity: Option<Vec2>,
+}
+
+impl IceSlide {
+    pub fn new(position: Vec2) -> Self {
+        Self {
+            last_position: position,
+            velocity: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,4 +106,17 @@ mod tests {
         assert_eq!(torch.max_intensity, 2000.0);
         assert_eq!(torch.target_intensity, 1500.0);
     }
+
+    #[test]
+    fn hazard_kind_names() {
+        assert_eq!(HazardKind::Lava.name(), "Lava");
+        assert_eq!(HazardKind::Ice.name(), "Ice");
+    }
+
+    #[test]
+    fn ice_slide_starts_without_velocity() {
+        let slide = IceSlide::new(Vec2::new(1.0, 2.0));
+        assert_eq!(slide.last_position, Vec2::new(1.0, 2.0));
+        assert!(slide.velocity.is_none());
+    }
 }
@@ -0,0 +1,641 @@
+use std::f32::consts::TAU;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::arena::components::HazardKind;
+use crate::arena::resources::{ArenaBounds, HazardMap};
+use crate::collision::components::ObstacleShape;
+use crate::hazards::components::PropKind;
+
+/// Layout file, relative to the asset directory
+pub const ARENA_LAYOUTS_FILE: &str = "arenas/layouts.ron";
+
+/// Layouts compiled into the binary, used until (or instead of) the asset file
+const BUILT_IN_LAYOUTS: &str = include_str!("../../assets/arenas/layouts.ron");
+
+/// Half-size of each pillar in a circular arena's ring
+pub const PILLAR_HALF_SIZE: f32 = 1.5;
+
+/// Width of each opening in a circular arena's ring
+pub const GATE_WIDTH: f32 = 10.0;
+
+/// Thickness of corridor barrier walls
+pub const BARRIER_THICKNESS: f32 = 2.0;
+
+/// Rocks and props are kept this far from the player so a new layout never spawns on top of them
+pub const ROCK_PLAYER_CLEARANCE: f32 = 4.0;
+
+/// Half-size of a rock's footprint (matches the 1.0 x 1.0 rock mesh)
+pub const ROCK_HALF_SIZE: f32 = 0.5;
+
+/// Overall shape of the playable area inside the arena walls.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ArenaShape {
+    /// The plain walled square
+    #[default]
+    Open,
+    /// A ring of pillars around the center with `gates` evenly spaced openings
+    Circle { radius: f32, gates: u32 },
+    /// Barrier walls along the X axis, `spacing` apart. Walls alternate between
+    /// openings of width `gap` at both ends and a single opening in the middle.
+    Corridors { spacing: f32, gap: f32 },
+    /// A central island plus `count` islands on a circle of radius `ring`,
+    /// with the rest of the floor covered in `sea` hazard tiles
+    Islands {
+        count: u32,
+        radius: f32,
+        ring: f32,
+        sea: HazardKind,
+    },
+}
+
+/// Rocks scattered uniformly within `spread` of `center`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RockCluster {
+    pub center: (f32, f32),
+    pub spread: (f32, f32),
+    pub count: u32,
+}
+
+/// Element-reactive props of one kind scattered uniformly within `spread` of `center`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PropCluster {
+    pub kind: PropKind,
+    pub center: (f32, f32),
+    pub spread: (f32, f32),
+    pub count: u32,
+}
+
+/// Circular patch of hazard tiles
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HazardPatch {
+    pub kind: HazardKind,
+    pub center: (f32, f32),
+    pub radius: f32,
+}
+
+/// Visual theme of a layout
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Biome {
+    pub name: String,
+    /// Ground texture path inside the asset directory (tiled across the floor)
+    pub ground_texture: String,
+    /// Multiplied into the ground texture
+    pub ground_tint: (f32, f32, f32),
+    /// Colour of the wall torch lights
+    pub torch_color: (f32, f32, f32),
+}
+
+impl Default for Biome {
+    fn default() -> Self {
+        Self {
+            name: "Stone".to_string(),
+            ground_texture: "textures/concrete-01.png".to_string(),
+            ground_tint: (1.0, 1.0, 1.0),
+            torch_color: (1.0, 0.9, 0.7),
+        }
+    }
+}
+
+impl Biome {
+    pub fn ground_color(&self) -> Color {
+        let (r, g, b) = self.ground_tint;
+        Color::srgb(r, g, b)
+    }
+
+    pub fn torch_light_color(&self) -> Color {
+        let (r, g, b) = self.torch_color;
+        Color::srgb(r, g, b)
+    }
+}
+
+/// Data description of one arena: shape, obstacles, hazards and theme.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ArenaLayout {
+    pub name: String,
+    #[serde(default)]
+    pub shape: ArenaShape,
+    #[serde(default)]
+    pub rock_clusters: Vec<RockCluster>,
+    #[serde(default)]
+    pub hazards: Vec<HazardPatch>,
+    #[serde(default)]
+    pub props: Vec<PropCluster>,
+    #[serde(default)]
+    pub biome: Biome,
+}
+
+/// Everything a layout places in the arena, generated before anything is spawned.
+#[derive(Debug, Clone, Default)]
+pub struct ArenaPlan {
+    /// Axis-aligned barrier blocks as (center, half extents)
+    pub barriers: Vec<(Vec2, Vec2)>,
+    pub rocks: Vec<Vec2>,
+    pub hazards: HazardMap,
+    pub props: Vec<(PropKind, Vec2)>,
+}
+
+impl ArenaLayout {
+    /// Reasons this layout can't be generated, if any
+    pub fn validate(&self) -> Result<(), String> {
+        match self.shape {
+            ArenaShape::Open => {}
+            ArenaShape::Circle { radius, .. } if radius <= 0.0 => {
+                return Err(format!("{}: circle radius must be positive", self.name));
+            }
+            ArenaShape::Circle { .. } => {}
+            ArenaShape::Corridors { spacing, gap } => {
+                if spacing <= BARRIER_THICKNESS {
+                    return Err(format!(
+                        "{}: corridor spacing must be more than {}",
+                        self.name, BARRIER_THICKNESS
+                    ));
+                }
+                if gap < 0.0 {
+                    return Err(format!("{}: corridor gap can't be negative", self.name));
+                }
+            }
+            ArenaShape::Islands { radius, .. } if radius <= 0.0 => {
+                return Err(format!("{}: island radius must be positive", self.name));
+            }
+            ArenaShape::Islands { .. } => {}
+        }
+        if let Some(patch) = self.hazards.iter().find(|patch| patch.radius <= 0.0) {
+            return Err(format!("{}: {} patch radius must be positive", self.name, patch.kind.name()));
+        }
+        if let Some(cluster) = self
+            .rock_clusters
+            .iter()
+            .find(|cluster| cluster.spread.0 < 0.0 || cluster.spread.1 < 0.0)
+        {
+            return Err(format!(
+                "{}: rock cluster at {:?} has a negative spread",
+                self.name, cluster.center
+            ));
+        }
+        if let Some(cluster) = self
+            .props
+            .iter()
+            .find(|cluster| cluster.spread.0 < 0.0 || cluster.spread.1 < 0.0)
+        {
+            return Err(format!(
+                "{}: {} cluster at {:?} has a negative spread",
+                self.name,
+                cluster.kind.name(),
+                cluster.center
+            ));
+        }
+        Ok(())
+    }
+
+    /// Lay out barriers, rocks and hazard tiles inside `bounds`.
+    /// Rocks avoid barriers and the area around `player`.
+    pub fn generate(&self, bounds: &ArenaBounds, player: Vec2, rng: &mut impl Rng) -> ArenaPlan {
+        let mut plan = ArenaPlan::default();
+        let center = Vec2::new(
+            (bounds.min_x + bounds.max_x) / 2.0,
+            (bounds.min_z + bounds.max_z) / 2.0,
+        );
+
+        match self.shape {
+            ArenaShape::Open => {}
+            ArenaShape::Circle { radius, gates } => {
+                plan.barriers = ring_pillars(center, radius, gates);
+            }
+            ArenaShape::Corridors { spacing, gap } => {
+                plan.barriers = corridor_walls(bounds, spacing, gap);
+            }
+            ArenaShape::Islands { count, radius, ring, sea } => {
+                let islands: Vec<Vec2> = std::iter::once(center)
+                    .chain((0..count).map(|i| {
+                        let angle = i as f32 / count as f32 * TAU;
+                        center + Vec2::from_angle(angle) * ring
+                    }))
+                    .collect();
+                for cell in HazardMap::cells_in(bounds) {
+                    let cell_center = HazardMap::cell_center(cell);
+                    if islands.iter().all(|island| island.distance(cell_center) > radius) {
+                        plan.hazards.set(cell, sea);
+                    }
+                }
+            }
+        }
+
+        for patch in &self.hazards {
+            let patch_center = Vec2::new(patch.center.0, patch.center.1);
+            for cell in HazardMap::cells_in(bounds) {
+                if HazardMap::cell_center(cell).distance(patch_center) <= patch.radius {
+                    plan.hazards.set(cell, patch.kind);
+                }
+            }
+        }
+
+        for cluster in &self.rock_clusters {
+            for _ in 0..cluster.count {
+                if let Some(position) =
+                    scatter(cluster.center, cluster.spread, bounds, player, &plan.barriers, rng)
+                {
+                    plan.rocks.push(position);
+                }
+            }
+        }
+
+        for cluster in &self.props {
+            for _ in 0..cluster.count {
+                if let Some(position) =
+                    scatter(cluster.center, cluster.spread, bounds, player, &plan.barriers, rng)
+                {
+                    plan.props.push((cluster.kind, position));
+                }
+            }
+        }
+
+        plan
+    }
+}
+
+/// Random spot within `spread` of `center` that is inside the arena, clear of
+/// barriers and away from the player. A few tries per spot; crowded clusters
+/// just end up with fewer rocks or props.
+fn scatter(
+    center: (f32, f32),
+    spread: (f32, f32),
+    bounds: &ArenaBounds,
+    player: Vec2,
+    barriers: &[(Vec2, Vec2)],
+    rng: &mut impl Rng,
+) -> Option<Vec2> {
+    let center = Vec2::new(center.0, center.1);
+    let spread = Vec2::new(spread.0, spread.1);
+    (0..8).find_map(|_| {
+        let position = center
+            + Vec2::new(
+                rng.gen_range(-spread.x..=spread.x),
+                rng.gen_range(-spread.y..=spread.y),
+            );
+        let blocked = !bounds.inner_bounds(ROCK_HALF_SIZE).contains(position)
+            || position.distance(player) < ROCK_PLAYER_CLEARANCE
+            || barriers.iter().any(|(barrier, half_extents)| {
+                ObstacleShape::Aabb { half_extents: *half_extents }
+                    .overlaps(*barrier, position, ROCK_HALF_SIZE * 2.0)
+            });
+        (!blocked).then_some(position)
+    })
+}
+
+/// Touching pillars around a circle, leaving out the ones inside a gate
+fn ring_pillars(center: Vec2, radius: f32, gates: u32) -> Vec<(Vec2, Vec2)> {
+    let count = (TAU * radius / (PILLAR_HALF_SIZE * 2.0)).ceil().max(3.0) as u32;
+    let gate_half_angle = GATE_WIDTH / 2.0 / radius;
+
+    (0..count)
+        .map(|i| i as f32 / count as f32 * TAU)
+        .filter(|angle| {
+            (0..gates).all(|gate| {
+                let gate_angle = gate as f32 / gates as f32 * TAU;
+                let offset = (angle - gate_angle).rem_euclid(TAU);
+                offset.min(TAU - offset) > gate_half_angle
+            })
+        })
+        .map(|angle| {
+            (
+                center + Vec2::from_angle(angle) * radius,
+                Vec2::splat(PILLAR_HALF_SIZE),
+            )
+        })
+        .collect()
+}
+
+/// Barrier walls mirrored around the center line so the middle corridor is always open
+fn corridor_walls(bounds: &ArenaBounds, spacing: f32, gap: f32) -> Vec<(Vec2, Vec2)> {
+    let center_x = (bounds.min_x + bounds.max_x) / 2.0;
+    let center_z = (bounds.min_z + bounds.max_z) / 2.0;
+    let half_depth = bounds.depth() / 2.0;
+    let half_thickness = BARRIER_THICKNESS / 2.0;
+    let mut walls = Vec::new();
+
+    let mut push_wall = |start_x: f32, end_x: f32, z: f32| {
+        if end_x > start_x {
+            walls.push((
+                Vec2::new((start_x + end_x) / 2.0, z),
+                Vec2::new((end_x - start_x) / 2.0, half_thickness),
+            ));
+        }
+    };
+
+    let mut line = 0;
+    loop {
+        let offset = spacing * (line as f32 + 0.5);
+        // Leave at least a corridor's worth of room before the arena wall
+        if offset + spacing / 2.0 > half_depth {
+            break;
+        }
+        for z in [center_z - offset, center_z + offset] {
+            if line % 2 == 0 {
+                push_wall(bounds.min_x + gap, bounds.max_x - gap, z);
+            } else {
+                push_wall(bounds.min_x, center_x - gap / 2.0, z);
+                push_wall(center_x + gap / 2.0, bounds.max_x, z);
+            }
+        }
+        line += 1;
+    }
+
+    walls
+}
+
+/// Ordered list of layouts; level N uses entry N-1, wrapping around.
+/// Also the asset `ArenaLayoutsLoader` reads layout files into.
+#[derive(Resource, Asset, TypePath, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArenaCatalog {
+    pub layouts: Vec<ArenaLayout>,
+}
+
+impl Default for ArenaCatalog {
+    fn default() -> Self {
+        parse_arena_catalog(BUILT_IN_LAYOUTS, Path::new(ARENA_LAYOUTS_FILE))
+            .expect("built-in arena layouts are valid")
+    }
+}
+
+impl ArenaCatalog {
+    /// Layout for a game level (levels start at 1)
+    pub fn layout_for_level(&self, level: u32) -> &ArenaLayout {
+        let index = level.saturating_sub(1) as usize % self.layouts.len();
+        &self.layouts[index]
+    }
+
+    /// Reasons this catalog can't be used, if any
+    pub fn validate(&self) -> Result<(), String> {
+        if self.layouts.is_empty() {
+            return Err("no layouts defined".to_string());
+        }
+        self.layouts.iter().try_for_each(ArenaLayout::validate)
+    }
+}
+
+/// Why an arena layout file could not be loaded.
+#[derive(Debug)]
+pub enum ArenaCatalogError {
+    /// The file could not be read
+    Io { path: PathBuf, source: std::io::Error },
+    /// The file is not valid RON or describes a layout that can't be built
+    Invalid { path: PathBuf, reason: String },
+}
+
+impl fmt::Display for ArenaCatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArenaCatalogError::Io { path, source } => {
+                write!(f, "could not read arena layouts {}: {}", path.display(), source)
+            }
+            ArenaCatalogError::Invalid { path, reason } => {
+                write!(f, "arena layouts {} are invalid: {}", path.display(), reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArenaCatalogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ArenaCatalogError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Parse and validate layout file contents.
+pub fn parse_arena_catalog(contents: &str, path: &Path) -> Result<ArenaCatalog, ArenaCatalogError> {
+    let invalid = |reason: String| ArenaCatalogError::Invalid {
+        path: path.to_path_buf(),
+        reason,
+    };
+    let catalog: ArenaCatalog = ron::from_str(contents).map_err(|err| invalid(err.to_string()))?;
+    catalog.validate().map_err(invalid)?;
+    Ok(catalog)
+}
+
+/// Loads `arenas/*.ron` layout files through the asset server. Files are only
+/// parsed here; `apply_arena_layouts` validates them before they take effect.
+#[derive(Default, TypePath)]
+pub struct ArenaLayoutsLoader;
+
+impl AssetLoader for ArenaLayoutsLoader {
+    type Asset = ArenaCatalog;
+    type Settings = ();
+    type Error = ArenaCatalogError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let path = load_context.path().to_path_buf();
+        let mut bytes = Vec::new();
+        if let Err(source) = reader.read_to_end(&mut bytes).await {
+            return Err(ArenaCatalogError::Io { path, source });
+        }
+        std::str::from_utf8(&bytes)
+            .map_err(|err| err.to_string())
+            .and_then(|contents| ron::from_str(contents).map_err(|err| err.to_string()))
+            .map_err(|reason| ArenaCatalogError::Invalid { path, reason })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn layout(shape: ArenaShape) -> ArenaLayout {
+        ArenaLayout {
+            name: "Test".to_string(),
+            shape,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn built_in_catalog_parses() {
+        let catalog = ArenaCatalog::default();
+        assert!(!catalog.layouts.is_empty());
+        assert_eq!(catalog.layouts[0].shape, ArenaShape::Open);
+    }
+
+    #[test]
+    fn layout_for_level_wraps_around() {
+        let catalog = ArenaCatalog::default();
+        let count = catalog.layouts.len() as u32;
+        assert_eq!(catalog.layout_for_level(1), &catalog.layouts[0]);
+        assert_eq!(catalog.layout_for_level(count + 1), &catalog.layouts[0]);
+        assert_eq!(catalog.layout_for_level(0), &catalog.layouts[0]);
+    }
+
+    #[test]
+    fn parse_rejects_empty_catalog() {
+        let result = parse_arena_catalog("(layouts: [])", Path::new("layouts.ron"));
+        assert!(matches!(result, Err(ArenaCatalogError::Invalid { .. })));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_shape() {
+        let contents = r#"(layouts: [(name: "Bad", shape: Circle(radius: 0.0, gates: 2))])"#;
+        let err = parse_arena_catalog(contents, Path::new("layouts.ron")).unwrap_err();
+        assert!(err.to_string().contains("circle radius"));
+    }
+
+    #[test]
+    fn parse_fills_in_defaults() {
+        let catalog = parse_arena_catalog(r#"(layouts: [(name: "Bare")])"#, Path::new("layouts.ron")).unwrap();
+        assert_eq!(catalog.layouts[0].shape, ArenaShape::Open);
+        assert_eq!(catalog.layouts[0].biome, Biome::default());
+        assert!(catalog.layouts[0].props.is_empty());
+    }
+
+    #[test]
+    fn circle_leaves_gates_open() {
+        let plan = layout(ArenaShape::Circle { radius: 30.0, gates: 4 }).generate(
+            &ArenaBounds::default(),
+            Vec2::ZERO,
+            &mut StdRng::seed_from_u64(1),
+        );
+
+        assert!(!plan.barriers.is_empty());
+        let blocked = |point: Vec2| {
+            plan.barriers.iter().any(|(center, half_extents)| {
+                ObstacleShape::Aabb { half_extents: *half_extents }.overlaps(*center, point, 0.5)
+            })
+        };
+        // Gate centers at 0, 90, 180, 270 degrees
+        assert!(!blocked(Vec2::new(30.0, 0.0)));
+        assert!(!blocked(Vec2::new(0.0, -30.0)));
+        // Between gates the ring is closed
+        let diagonal = Vec2::from_angle(TAU / 8.0) * 30.0;
+        assert!(blocked(diagonal));
+    }
+
+    #[test]
+    fn corridors_keep_center_open() {
+        let plan = layout(ArenaShape::Corridors { spacing: 20.0, gap: 10.0 }).generate(
+            &ArenaBounds::default(),
+            Vec2::ZERO,
+            &mut StdRng::seed_from_u64(1),
+        );
+
+        assert!(!plan.barriers.is_empty());
+        for (center, half_extents) in &plan.barriers {
+            assert!(
+                !ObstacleShape::Aabb { half_extents: *half_extents }.overlaps(*center, Vec2::ZERO, 5.0),
+                "barrier at {center:?} blocks the middle corridor"
+            );
+        }
+        // First walls out from the middle leave both ends open
+        let first_line: Vec<_> = plan.barriers.iter().filter(|(center, _)| center.y == 10.0).collect();
+        assert_eq!(first_line.len(), 1);
+        assert_eq!(first_line[0].1.x, 90.0);
+    }
+
+    #[test]
+    fn islands_surround_land_with_sea() {
+        let plan = layout(ArenaShape::Islands {
+            count: 4,
+            radius: 10.0,
+            ring: 40.0,
+            sea: HazardKind::Lava,
+        })
+        .generate(&ArenaBounds::default(), Vec2::ZERO, &mut StdRng::seed_from_u64(1));
+
+        assert_eq!(plan.hazards.kind_at(Vec2::ZERO), None);
+        assert_eq!(plan.hazards.kind_at(Vec2::new(40.0, 0.0)), None);
+        assert_eq!(plan.hazards.kind_at(Vec2::new(20.0, 20.0)), Some(HazardKind::Lava));
+        assert_eq!(plan.hazards.kind_at(Vec2::new(-90.0, 90.0)), Some(HazardKind::Lava));
+    }
+
+    #[test]
+    fn hazard_patches_are_stamped() {
+        let mut layout = layout(ArenaShape::Open);
+        layout.hazards.push(HazardPatch {
+            kind: HazardKind::Ice,
+            center: (20.0, 20.0),
+            radius: 6.0,
+        });
+        let plan = layout.generate(&ArenaBounds::default(), Vec2::ZERO, &mut StdRng::seed_from_u64(1));
+
+        assert_eq!(plan.hazards.kind_at(Vec2::new(20.0, 20.0)), Some(HazardKind::Ice));
+        assert_eq!(plan.hazards.kind_at(Vec2::new(40.0, 20.0)), None);
+    }
+
+    #[test]
+    fn rocks_avoid_player_and_stay_in_cluster() {
+        let mut layout = layout(ArenaShape::Open);
+        layout.rock_clusters.push(RockCluster {
+            center: (0.0, 0.0),
+            spread: (10.0, 10.0),
+            count: 30,
+        });
+        let plan = layout.generate(&ArenaBounds::default(), Vec2::ZERO, &mut StdRng::seed_from_u64(7));
+
+        assert!(!plan.rocks.is_empty());
+        for rock in &plan.rocks {
+            assert!(rock.length() >= ROCK_PLAYER_CLEARANCE);
+            assert!(rock.x.abs() <= 10.0 && rock.y.abs() <= 10.0);
+        }
+    }
+
+    #[test]
+    fn props_are_scattered_with_their_kind() {
+        let mut layout = layout(ArenaShape::Open);
+        layout.props.push(PropCluster {
+            kind: PropKind::ExplosiveBarrel,
+            center: (30.0, 0.0),
+            spread: (5.0, 5.0),
+            count: 4,
+        });
+        layout.props.push(PropCluster {
+            kind: PropKind::WaterPuddle,
+            center: (-30.0, 0.0),
+            spread: (0.0, 0.0),
+            count: 1,
+        });
+        let plan = layout.generate(&ArenaBounds::default(), Vec2::ZERO, &mut StdRng::seed_from_u64(2));
+
+        let barrels: Vec<_> = plan
+            .props
+            .iter()
+            .filter(|(kind, _)| *kind == PropKind::ExplosiveBarrel)
+            .collect();
+        assert_eq!(barrels.len(), 4);
+        assert!(barrels.iter().all(|(_, position)| position.distance(Vec2::new(30.0, 0.0)) <= 7.1));
+        assert!(plan.props.contains(&(PropKind::WaterPuddle, Vec2::new(-30.0, 0.0))));
+    }
+
+    #[test]
+    fn parse_rejects_negative_prop_spread() {
+        let contents = r#"(layouts: [(name: "Bad", props: [(kind: ToxicVent, center: (0.0, 0.0), spread: (-1.0, 0.0), count: 1)])])"#;
+        let err = parse_arena_catalog(contents, Path::new("layouts.ron")).unwrap_err();
+        assert!(err.to_string().contains("Toxic Vent"));
+    }
+
+    #[test]
+    fn generation_is_deterministic_for_a_seed() {
+        let catalog = ArenaCatalog::default();
+        let layout = catalog.layout_for_level(1);
+        let first = layout.generate(&ArenaBounds::default(), Vec2::ZERO, &mut StdRng::seed_from_u64(3));
+        let second = layout.generate(&ArenaBounds::default(), Vec2::ZERO, &mut StdRng::seed_from_u64(3));
+        assert_eq!(first.rocks, second.rocks);
+        assert_eq!(first.props, second.props);
+    }
+}
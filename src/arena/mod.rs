@@ -1,9 +1,11 @@
 pub mod components;
+pub mod layout;
 pub mod resources;
 pub mod systems;
 pub mod plugin;
 
 pub use components::*;
+pub use layout::*;
 pub use resources::*;
 pub use systems::*;
 pub use plugin::*;
@@ -1,16 +1,45 @@
 use bevy::prelude::*;
 
-use crate::arena::resources::ArenaBounds;
-use crate::arena::systems::{animate_torch_lights, cleanup_arena_walls, load_wall_model, spawn_arena_walls};
+use crate::arena::layout::{ArenaCatalog, ArenaLayoutsLoader};
+use crate::arena::resources::{ArenaBounds, CurrentArena, HazardMap, HazardTickTimer};
+use crate::arena::systems::{
+    animate_torch_lights, apply_arena_biome, apply_arena_layouts, build_arena_layout,
+    cleanup_arena_layout, cleanup_arena_walls, ice_slide_system, lava_burn_system,
+    load_arena_layouts, load_wall_model, setup_arena_assets, spawn_arena_walls,
+};
 use crate::game::sets::GameSet;
+use crate::game::systems::reset_game_level;
+use crate::movement::systems::{enemy_separation_system, player_movement};
 use crate::states::GameState;
 
 pub fn plugin(app: &mut App) {
     app.init_resource::<ArenaBounds>()
-        .add_systems(Startup, load_wall_model)
+        .init_resource::<ArenaCatalog>()
+        .init_resource::<CurrentArena>()
+        .init_resource::<HazardMap>()
+        .init_resource::<HazardTickTimer>()
+        .add_systems(Startup, (load_wall_model, setup_arena_assets))
+        // The layout depends on the GameLevel, so build it once the level is reset for a fresh run
         .add_systems(
             OnEnter(GameState::InGame),
-            spawn_arena_walls.in_set(GameSet::Spawning),
+            (build_arena_layout, apply_arena_biome, spawn_arena_walls)
+                .chain()
+                .after(reset_game_level),
+        )
+        // Ice adjusts whatever the movement systems did this frame
+        .add_systems(
+            Update,
+            ice_slide_system
+                .after(player_movement)
+                .after(enemy_separation_system)
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            lava_burn_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
         )
         .add_systems(
             Update,
@@ -18,7 +47,20 @@ pub fn plugin(app: &mut App) {
                 .in_set(GameSet::Effects)
                 .run_if(in_state(GameState::InGame)),
         )
-        .add_systems(OnExit(GameState::InGame), cleanup_arena_walls);
+        .add_systems(OnExit(GameState::InGame), cleanup_arena_walls)
+        .add_systems(OnEnter(GameState::Intro), cleanup_arena_layout)
+        .add_systems(OnEnter(GameState::GameOver), cleanup_arena_layout);
+}
+
+/// Arena layouts plugin: loads `assets/arenas/layouts.ron` through the asset
+/// server and reloads it when it changes. Until then the built-in layouts are
+/// used. Needs the `AssetPlugin`.
+pub fn layouts_plugin(app: &mut App) {
+    app.init_resource::<ArenaCatalog>()
+        .init_asset::<ArenaCatalog>()
+        .init_asset_loader::<ArenaLayoutsLoader>()
+        .add_systems(Startup, load_arena_layouts)
+        .add_systems(Update, apply_arena_layouts);
 }
 
 #[cfg(test)]
@@ -38,4 +80,16 @@ mod tests {
             "ArenaBounds resource should be registered"
         );
     }
+
+    #[test]
+    fn plugin_registers_layout_resources() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().get_resource::<ArenaCatalog>().is_some());
+        assert!(app.world().get_resource::<CurrentArena>().is_some());
+        assert!(app.world().get_resource::<HazardMap>().is_some());
+    }
 }
@@ -1,5 +1,10 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 
+use crate::arena::components::HazardKind;
+use crate::arena::layout::{ArenaCatalog, ArenaLayout};
+
 /// The half-size of the arena in world units (arena extends from -ARENA_HALF_SIZE to +ARENA_HALF_SIZE)
 /// Based on the ground plane which uses Plane3d with half-extents of 100x100 (so -100 to +100)
 pub const ARENA_HALF_SIZE: f32 = 100.0;
@@ -86,6 +91,134 @@ impl ArenaBounds {
 #[derive(Resource)]
 pub struct WallModelHandle(pub Handle<Scene>);
 
+/// Handle keeping the arena layouts file loaded (and watched for changes).
+#[derive(Resource, Debug, Default)]
+pub struct ArenaLayoutsHandle(pub Handle<ArenaCatalog>);
+
+/// Side length of a hazard tile in world units
+pub const HAZARD_TILE_SIZE: f32 = 5.0;
+
+/// Hazard tiles of the current layout on a square grid, for O(1) lookups by position.
+/// Cell (0, 0) covers 0..HAZARD_TILE_SIZE on both axes.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct HazardMap {
+    tiles: HashMap<IVec2, HazardKind>,
+}
+
+impl HazardMap {
+    /// Grid cell containing a position on the XZ plane
+    pub fn cell_of(position: Vec2) -> IVec2 {
+        (position / HAZARD_TILE_SIZE).floor().as_ivec2()
+    }
+
+    /// Center of a grid cell on the XZ plane
+    pub fn cell_center(cell: IVec2) -> Vec2 {
+        (cell.as_vec2() + Vec2::splat(0.5)) * HAZARD_TILE_SIZE
+    }
+
+    /// Cells whose centers lie inside the bounds
+    pub fn cells_in(bounds: &ArenaBounds) -> impl Iterator<Item = IVec2> + '_ {
+        let min = Self::cell_of(Vec2::new(bounds.min_x, bounds.min_z));
+        let max = Self::cell_of(Vec2::new(bounds.max_x, bounds.max_z));
+        (min.y..=max.y)
+            .flat_map(move |z| (min.x..=max.x).map(move |x| IVec2::new(x, z)))
+            .filter(|cell| bounds.contains(Self::cell_center(*cell)))
+    }
+
+    /// Cells that overlap a circle on the XZ plane
+    pub fn cells_touching(center: Vec2, radius: f32) -> impl Iterator<Item = IVec2> {
+        let min = Self::cell_of(center - Vec2::splat(radius));
+        let max = Self::cell_of(center + Vec2::splat(radius));
+        (min.y..=max.y)
+            .flat_map(move |z| (min.x..=max.x).map(move |x| IVec2::new(x, z)))
+            .filter(move |cell| {
+                let cell_min = cell.as_vec2() * HAZARD_TILE_SIZE;
+                let closest = center.clamp(cell_min, cell_min + Vec2::splat(HAZARD_TILE_SIZE));
+                closest.distance(center) < radius
+            })
+    }
+
+    pub fn set(&mut self, cell: IVec2, kind: HazardKind) {
+        self.tiles.insert(cell, kind);
+    }
+
+    pub fn clear_cell(&mut self, cell: IVec2) {
+        self.tiles.remove(&cell);
+    }
+
+    /// Hazard under a position, if any
+    pub fn kind_at(&self, position: Vec2) -> Option<HazardKind> {
+        self.tiles.get(&Self::cell_of(position)).copied()
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = (IVec2, HazardKind)> + '_ {
+        self.tiles.iter().map(|(cell, kind)| (*cell, *kind))
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+}
+
+/// Layout currently built in the arena and the level it was built for.
+/// `level` is `None` until the first layout of a run is built.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CurrentArena {
+    pub level: Option<u32>,
+    pub layout: ArenaLayout,
+}
+
+/// Shared meshes and materials for hazard tiles
+#[derive(Resource)]
+pub struct ArenaAssets {
+    /// Flat square covering one hazard tile
+    pub hazard_tile: Handle<Mesh>,
+    /// Glowing lava material
+    pub lava: Handle<StandardMaterial>,
+    /// Glossy translucent ice material
+    pub ice: Handle<StandardMaterial>,
+}
+
+impl ArenaAssets {
+    pub fn new(meshes: &mut Assets<Mesh>, materials: &mut Assets<StandardMaterial>) -> Self {
+        Self {
+            hazard_tile: meshes.add(Plane3d::new(Vec3::Y, Vec2::splat(HAZARD_TILE_SIZE / 2.0))),
+            lava: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.9, 0.25, 0.05),
+                emissive: bevy::color::LinearRgba::rgb(4.0, 0.8, 0.1),
+                ..default()
+            }),
+            ice: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.7, 0.9, 1.0, 0.8),
+                alpha_mode: AlphaMode::Blend,
+                perceptual_roughness: 0.1,
+                ..default()
+            }),
+        }
+    }
+
+    pub fn material_for(&self, kind: HazardKind) -> Handle<StandardMaterial> {
+        match kind {
+            HazardKind::Lava => self.lava.clone(),
+            HazardKind::Ice => self.ice.clone(),
+        }
+    }
+}
+
+/// Interval between lava burn ticks
+#[derive(Resource, Debug, Clone)]
+pub struct HazardTickTimer(pub Timer);
+
+impl Default for HazardTickTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(0.5, TimerMode::Repeating))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,4 +304,45 @@ mod tests {
         // Ground plane uses Plane3d with half-extents 100x100, so arena extends from -100 to +100
         assert_eq!(ARENA_HALF_SIZE, 100.0);
     }
+
+    #[test]
+    fn hazard_map_cell_of_floors_negative_positions() {
+        assert_eq!(HazardMap::cell_of(Vec2::new(0.1, 4.9)), IVec2::new(0, 0));
+        assert_eq!(HazardMap::cell_of(Vec2::new(-0.1, 5.0)), IVec2::new(-1, 1));
+        assert_eq!(HazardMap::cell_center(IVec2::new(-1, 1)), Vec2::new(-2.5, 7.5));
+    }
+
+    #[test]
+    fn hazard_map_kind_at_looks_up_tile_under_position() {
+        let mut map = HazardMap::default();
+        map.set(IVec2::new(1, -1), HazardKind::Lava);
+
+        assert_eq!(map.kind_at(Vec2::new(7.0, -3.0)), Some(HazardKind::Lava));
+        assert_eq!(map.kind_at(Vec2::new(2.0, -3.0)), None);
+
+        map.clear_cell(IVec2::new(1, -1));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn hazard_map_cells_in_covers_bounds() {
+        let bounds = ArenaBounds::new(10.0);
+        let cells: Vec<_> = HazardMap::cells_in(&bounds).collect();
+        // 20 units across with 5 unit tiles
+        assert_eq!(cells.len(), 16);
+        assert!(cells.iter().all(|cell| bounds.contains(HazardMap::cell_center(*cell))));
+    }
+
+    #[test]
+    fn hazard_map_cells_touching_covers_circle_only() {
+        // Circle centered on a tile corner touches the four tiles around it
+        let cells: Vec<_> = HazardMap::cells_touching(Vec2::new(5.0, 5.0), 2.0).collect();
+        assert_eq!(cells.len(), 4);
+        assert!(cells.contains(&IVec2::new(0, 0)));
+        assert!(cells.contains(&IVec2::new(1, 1)));
+
+        // A small circle in the middle of a tile stays inside it
+        let cells: Vec<_> = HazardMap::cells_touching(Vec2::new(2.5, 2.5), 1.0).collect();
+        assert_eq!(cells, vec![IVec2::new(0, 0)]);
+    }
 }
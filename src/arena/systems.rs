@@ -1,12 +1,302 @@
+use bevy::image::{ImageAddressMode, ImageLoaderSettings, ImageSampler, ImageSamplerDescriptor};
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::arena::components::{ArenaWall, TorchLight};
-use crate::arena::resources::{ArenaBounds, WallModelHandle, WALL_THICKNESS};
+use crate::arena::components::{ArenaFeature, ArenaWall, HazardKind, HazardTile, IceSlide, TorchLight};
+use crate::arena::layout::{ArenaCatalog, Biome, ARENA_LAYOUTS_FILE, ROCK_HALF_SIZE};
+use crate::arena::resources::{
+    ArenaAssets, ArenaBounds, ArenaLayoutsHandle, CurrentArena, HazardMap, HazardTickTimer,
+    WallModelHandle, WALL_HEIGHT, WALL_THICKNESS,
+};
+use crate::collision::components::Obstacle;
+use crate::combat::events::DamageEvent;
+use crate::element::Element;
+use crate::enemies::components::Enemy;
+use crate::game::components::{GroundPlane, Rock};
+use crate::game::resources::{GameLevel, GameMaterials, GameMeshes, GameRng};
+use crate::hazards::resources::HazardPropAssets;
+use crate::hazards::systems::spawn_hazard_prop;
+use crate::movement::components::from_xz;
+use crate::player::components::Player;
 
 /// Length of each wall segment (should match the wall.glb model dimensions)
 pub const WALL_SEGMENT_LENGTH: f32 = 12.0;
 
+/// Height of the rock mesh that barriers are scaled from
+const ROCK_MESH_HEIGHT: f32 = 0.5;
+
+/// Lava damage per second to anything standing in it
+pub const LAVA_DAMAGE_PER_SECOND: f32 = 8.0;
+
+/// How quickly a body on ice turns its momentum towards where it wants to go (per second)
+pub const ICE_GRIP: f32 = 1.5;
+
+/// Texture repeats across the ground plane
+const GROUND_UV_SCALE: f32 = 20.0;
+
+/// Ground plane material for a biome (tiled texture with the biome tint)
+pub fn ground_material(asset_server: &AssetServer, biome: &Biome) -> StandardMaterial {
+    let texture = asset_server.load_with_settings(
+        biome.ground_texture.clone(),
+        |settings: &mut ImageLoaderSettings| {
+            settings.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+                address_mode_u: ImageAddressMode::Repeat,
+                address_mode_v: ImageAddressMode::Repeat,
+                ..default()
+            });
+        },
+    );
+    StandardMaterial {
+        base_color: biome.ground_color(),
+        base_color_texture: Some(texture),
+        // Scale UVs to tile the texture across the ground plane
+        uv_transform: bevy::math::Affine2::from_scale(Vec2::splat(GROUND_UV_SCALE)),
+        ..default()
+    }
+}
+
+/// Start loading the arena layouts so designers can edit them without rebuilding
+pub fn load_arena_layouts(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ArenaLayoutsHandle(asset_server.load(ARENA_LAYOUTS_FILE)));
+}
+
+/// Once the layouts have loaded, and again whenever they change on disk,
+/// validate them and use them from the next level built. Invalid layouts are
+/// reported and ignored, keeping the built-in or previously loaded ones.
+pub fn apply_arena_layouts(
+    mut asset_events: MessageReader<AssetEvent<ArenaCatalog>>,
+    handle: Option<Res<ArenaLayoutsHandle>>,
+    files: Res<Assets<ArenaCatalog>>,
+    mut catalog: ResMut<ArenaCatalog>,
+) {
+    let Some(handle) = handle else {
+        return;
+    };
+    let changed = asset_events.read().fold(false, |changed, event| {
+        changed
+            || matches!(
+                event,
+                AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } if *id == handle.0.id()
+            )
+    });
+    let Some(file) = files.get(&handle.0).filter(|_| changed) else {
+        return;
+    };
+
+    match file.validate() {
+        Ok(()) => {
+            *catalog = file.clone();
+            info!("Loaded arena layouts from assets/{}", ARENA_LAYOUTS_FILE);
+        }
+        Err(reason) => warn!("Keeping previous arena layouts: {} is invalid: {}", ARENA_LAYOUTS_FILE, reason),
+    }
+}
+
+/// Create the shared hazard tile mesh and materials
+pub fn setup_arena_assets(
+    mut commands: Commands,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    if let (Some(mut meshes), Some(mut materials)) = (meshes, materials) {
+        commands.insert_resource(ArenaAssets::new(&mut meshes, &mut materials));
+    }
+}
+
+/// Builds the layout for the current GameLevel: barriers, rocks, hazard tiles and props.
+/// Does nothing when the layout for this level is already built (e.g. returning from pause).
+#[allow(clippy::too_many_arguments)]
+pub fn build_arena_layout(
+    mut commands: Commands,
+    catalog: Res<ArenaCatalog>,
+    game_level: Res<GameLevel>,
+    bounds: Res<ArenaBounds>,
+    mut current: ResMut<CurrentArena>,
+    mut rng: ResMut<GameRng>,
+    features: Query<Entity, With<ArenaFeature>>,
+    player_query: Query<&Transform, With<Player>>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+    arena_assets: Option<Res<ArenaAssets>>,
+    prop_assets: Option<Res<HazardPropAssets>>,
+) {
+    if current.level == Some(game_level.level) {
+        return;
+    }
+
+    for entity in features.iter() {
+        commands.entity(entity).try_despawn();
+    }
+
+    let layout = catalog.layout_for_level(game_level.level).clone();
+    let player_position = player_query
+        .iter()
+        .next()
+        .map(|transform| from_xz(transform.translation))
+        .unwrap_or(Vec2::ZERO);
+    let plan = layout.generate(&bounds, player_position, &mut *rng);
+
+    let rock_visuals = game_meshes
+        .as_ref()
+        .zip(game_materials.as_ref())
+        .map(|(meshes, materials)| (meshes.rock.clone(), materials.rock.clone()));
+
+    // Barriers are rock blocks stretched to wall height
+    for (center, half_extents) in &plan.barriers {
+        let mut entity_commands = commands.spawn((
+            ArenaFeature,
+            Obstacle::aabb(*half_extents),
+            Transform::from_translation(Vec3::new(center.x, WALL_HEIGHT / 2.0, center.y)).with_scale(
+                Vec3::new(half_extents.x * 2.0, WALL_HEIGHT / ROCK_MESH_HEIGHT, half_extents.y * 2.0),
+            ),
+            Visibility::default(),
+        ));
+        if let Some((mesh, material)) = &rock_visuals {
+            entity_commands.insert((Mesh3d(mesh.clone()), MeshMaterial3d(material.clone())));
+        }
+    }
+
+    for rock in &plan.rocks {
+        let mut entity_commands = commands.spawn((
+            ArenaFeature,
+            Rock,
+            // Matches the 1.0 x 1.0 footprint of the rock mesh
+            Obstacle::aabb(Vec2::splat(ROCK_HALF_SIZE)),
+            Transform::from_translation(Vec3::new(rock.x, ROCK_MESH_HEIGHT / 2.0, rock.y)),
+            Visibility::default(),
+        ));
+        if let Some((mesh, material)) = &rock_visuals {
+            entity_commands.insert((Mesh3d(mesh.clone()), MeshMaterial3d(material.clone())));
+        }
+    }
+
+    for (cell, kind) in plan.hazards.tiles() {
+        let center = HazardMap::cell_center(cell);
+        // Just above the ground plane to avoid z-fighting
+        let mut entity_commands = commands.spawn((
+            ArenaFeature,
+            HazardTile(kind),
+            Transform::from_translation(Vec3::new(center.x, 0.01, center.y)),
+            Visibility::default(),
+        ));
+        if let Some(assets) = &arena_assets {
+            entity_commands.insert((
+                Mesh3d(assets.hazard_tile.clone()),
+                MeshMaterial3d(assets.material_for(kind)),
+            ));
+        }
+    }
+
+    for (kind, position) in &plan.props {
+        spawn_hazard_prop(&mut commands, *kind, *position, prop_assets.as_deref()).insert(ArenaFeature);
+    }
+
+    commands.insert_resource(plan.hazards);
+    current.level = Some(game_level.level);
+    current.layout = layout;
+}
+
+/// Re-skins the ground plane with the current layout's biome when the layout changes
+pub fn apply_arena_biome(
+    mut commands: Commands,
+    current: Res<CurrentArena>,
+    asset_server: Option<Res<AssetServer>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+    ground_query: Query<Entity, With<GroundPlane>>,
+) {
+    if !current.is_changed() {
+        return;
+    }
+    let (Some(asset_server), Some(mut materials)) = (asset_server, materials) else {
+        return;
+    };
+
+    let material = materials.add(ground_material(&asset_server, &current.layout.biome));
+    for entity in ground_query.iter() {
+        commands.entity(entity).insert(MeshMaterial3d(material.clone()));
+    }
+}
+
+/// Removes the layout when the run ends so the next run builds level 1 from scratch
+pub fn cleanup_arena_layout(
+    mut commands: Commands,
+    features: Query<Entity, With<ArenaFeature>>,
+    mut current: ResMut<CurrentArena>,
+    mut hazards: ResMut<HazardMap>,
+) {
+    for entity in features.iter() {
+        // Rocks are also despawned by the game cleanup
+        commands.entity(entity).try_despawn();
+    }
+    *current = CurrentArena::default();
+    *hazards = HazardMap::default();
+}
+
+/// Burns the player and enemies standing on lava with Fire damage
+#[allow(clippy::type_complexity)]
+pub fn lava_burn_system(
+    time: Res<Time>,
+    mut timer: ResMut<HazardTickTimer>,
+    hazards: Res<HazardMap>,
+    bodies: Query<(Entity, &Transform), Or<(With<Player>, With<Enemy>)>>,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    timer.0.tick(time.delta());
+    if !timer.0.just_finished() || hazards.is_empty() {
+        return;
+    }
+
+    let amount = LAVA_DAMAGE_PER_SECOND * timer.0.duration().as_secs_f32();
+    for (entity, transform) in bodies.iter() {
+        if hazards.kind_at(from_xz(transform.translation)) == Some(HazardKind::Lava) {
+            damage_events.write(DamageEvent::with_element(entity, amount, Element::Fire).over_time());
+        }
+    }
+}
+
+/// Gives bodies on ice momentum: their actual velocity only slowly follows
+/// the movement the other movement systems asked for this frame.
+#[allow(clippy::type_complexity)]
+pub fn ice_slide_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    hazards: Res<HazardMap>,
+    mut bodies: Query<(Entity, &mut Transform, Option<&mut IceSlide>), Or<(With<Player>, With<Enemy>)>>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+
+    for (entity, mut transform, slide) in bodies.iter_mut() {
+        let position = from_xz(transform.translation);
+        let on_ice = hazards.kind_at(position) == Some(HazardKind::Ice);
+
+        match (on_ice, slide) {
+            (true, None) => {
+                commands.entity(entity).insert(IceSlide::new(position));
+            }
+            (true, Some(mut slide)) => {
+                let wanted = (position - slide.last_position) / delta;
+                // The first frame on ice measures the speed the body came in with
+                let velocity = match slide.velocity {
+                    Some(velocity) => velocity.lerp(wanted, (ICE_GRIP * delta).min(1.0)),
+                    None => wanted,
+                };
+                let slid = slide.last_position + velocity * delta;
+                transform.translation.x = slid.x;
+                transform.translation.z = slid.y;
+                slide.last_position = slid;
+                slide.velocity = Some(velocity);
+            }
+            (false, Some(_)) => {
+                commands.entity(entity).remove::<IceSlide>();
+            }
+            (false, None) => {}
+        }
+    }
+}
+
 /// Load the wall model asset
 pub fn load_wall_model(mut commands: Commands, asset_server: Res<AssetServer>) {
     let wall_handle: Handle<Scene> = asset_server.load("models/wall.glb#Scene0");
@@ -18,6 +308,7 @@ pub fn spawn_arena_walls(
     mut commands: Commands,
     bounds: Res<ArenaBounds>,
     wall_model: Option<Res<WallModelHandle>>,
+    current: Option<Res<CurrentArena>>,
     existing_walls: Query<Entity, With<ArenaWall>>,
 ) {
     // Only spawn walls once (check if any walls already exist)
@@ -27,6 +318,9 @@ pub fn spawn_arena_walls(
 
     // Get the wall model handle
     let wall_scene = wall_model.map(|w| w.0.clone());
+    let torch_color = current
+        .map(|current| current.layout.biome.torch_light_color())
+        .unwrap_or_else(|| Biome::default().torch_light_color());
 
     // Calculate how many wall segments we need for each side
     let arena_width = bounds.width();
@@ -44,6 +338,7 @@ pub fn spawn_arena_walls(
         Vec3::X,
         std::f32::consts::FRAC_PI_2,  // 90° to face -Z
         wall_scene.clone(),
+        torch_color,
     );
 
     // South wall (negative Z edge) - front needs to face +Z (inward)
@@ -55,6 +350,7 @@ pub fn spawn_arena_walls(
         Vec3::X,
         -std::f32::consts::FRAC_PI_2,  // -90° to face +Z
         wall_scene.clone(),
+        torch_color,
     );
 
     // East wall (positive X edge) - front needs to face -X (inward)
@@ -66,6 +362,7 @@ pub fn spawn_arena_walls(
         Vec3::Z,
         std::f32::consts::PI,  // 180° to face -X
         wall_scene.clone(),
+        torch_color,
     );
 
     // West wall (negative X edge) - front needs to face +X (inward)
@@ -77,10 +374,12 @@ pub fn spawn_arena_walls(
         Vec3::Z,
         0.0,  // 0° to face +X
         wall_scene,
+        torch_color,
     );
 }
 
 /// Spawns a line of wall segments
+#[allow(clippy::too_many_arguments)]
 fn spawn_wall_line(
     commands: &mut Commands,
     start_x: f32,
@@ -89,24 +388,35 @@ fn spawn_wall_line(
     direction: Vec3,
     rotation_y: f32,
     wall_scene: Option<Handle<Scene>>,
+    torch_color: Color,
 ) {
+    // Collision footprint: segment length along the line, wall thickness across it
+    let half_extents = if direction == Vec3::X {
+        Vec2::new(WALL_SEGMENT_LENGTH / 2.0, WALL_THICKNESS / 2.0)
+    } else {
+        Vec2::new(WALL_THICKNESS / 2.0, WALL_SEGMENT_LENGTH / 2.0)
+    };
+
     for i in 0..count {
         let offset = direction * (i as f32 * WALL_SEGMENT_LENGTH + WALL_SEGMENT_LENGTH / 2.0);
         let position = Vec3::new(start_x, 0.0, start_z) + offset;
 
-        spawn_wall_segment(commands, position, rotation_y, wall_scene.clone());
+        spawn_wall_segment(commands, position, rotation_y, half_extents, wall_scene.clone(), torch_color);
     }
 }
 
-/// Spawns a single wall segment with lighting (no physics - movement is clamped to arena bounds)
+/// Spawns a single wall segment with lighting and an obstacle footprint
 fn spawn_wall_segment(
     commands: &mut Commands,
     position: Vec3,
     rotation_y: f32,
+    half_extents: Vec2,
     wall_scene: Option<Handle<Scene>>,
+    torch_color: Color,
 ) {
     let mut entity_commands = commands.spawn((
         ArenaWall,
+        Obstacle::aabb(half_extents),
         Transform::from_translation(position)
             .with_rotation(Quat::from_rotation_y(rotation_y)),
         Visibility::default(),
@@ -124,7 +434,7 @@ fn spawn_wall_segment(
     // Blender coords (X=1, Y=0, Z=6) → Bevy coords (X=1, Y=6, Z=0)
     entity_commands.with_child((
         PointLight {
-            color: Color::srgb(1.0, 0.9, 0.7), // Warm light color
+            color: torch_color, // Biome torch colour
             intensity: 70_000.0,               // Lumens
             range: 20.0,
             shadows_enabled: true,
@@ -238,6 +548,33 @@ mod tests {
         assert_eq!(final_count, 0, "All walls should be removed after cleanup");
     }
 
+    #[test]
+    fn wall_obstacles_cover_every_edge() {
+        let mut app = setup_test_app();
+
+        let _ = app.world_mut().run_system_once(spawn_arena_walls);
+
+        let bounds = *app.world().resource::<ArenaBounds>();
+        let mut query = app.world_mut().query::<(&Transform, &Obstacle)>();
+        let obstacles: Vec<_> = query
+            .iter(app.world())
+            .map(|(transform, obstacle)| (Vec2::new(transform.translation.x, transform.translation.z), obstacle.shape))
+            .collect();
+
+        // Points just past each edge, including off-center ones, must be blocked
+        for point in [
+            Vec2::new(0.0, bounds.max_z + 0.3),
+            Vec2::new(37.0, bounds.min_z - 0.3),
+            Vec2::new(bounds.max_x + 0.3, -64.0),
+            Vec2::new(bounds.min_x - 0.3, 90.0),
+        ] {
+            assert!(
+                obstacles.iter().any(|(center, shape)| shape.overlaps(*center, point, 0.1)),
+                "{point:?} should be inside a wall"
+            );
+        }
+    }
+
     #[test]
     fn walls_are_positioned_at_arena_edges() {
         let mut app = setup_test_app();
@@ -279,4 +616,275 @@ mod tests {
         assert!(found_east, "Should have east wall");
         assert!(found_west, "Should have west wall");
     }
+
+    mod layout_tests {
+        use super::*;
+        use crate::arena::layout::{ArenaLayout, ArenaShape, HazardPatch, RockCluster};
+
+        fn setup_layout_app(layout: ArenaLayout) -> App {
+            let mut app = setup_test_app();
+            app.insert_resource(ArenaCatalog { layouts: vec![layout] });
+            app.init_resource::<CurrentArena>();
+            app.init_resource::<HazardMap>();
+            app.init_resource::<GameLevel>();
+            app.insert_resource(GameRng::from_seed(11));
+            app
+        }
+
+        fn rocky_layout() -> ArenaLayout {
+            ArenaLayout {
+                name: "Rocky".to_string(),
+                shape: ArenaShape::Corridors { spacing: 30.0, gap: 10.0 },
+                rock_clusters: vec![RockCluster {
+                    center: (0.0, 0.0),
+                    spread: (40.0, 40.0),
+                    count: 10,
+                }],
+                hazards: vec![HazardPatch {
+                    kind: HazardKind::Lava,
+                    center: (50.0, 50.0),
+                    radius: 6.0,
+                }],
+                ..default()
+            }
+        }
+
+        fn feature_count(app: &mut App) -> usize {
+            app.world_mut().query::<&ArenaFeature>().iter(app.world()).count()
+        }
+
+        #[test]
+        fn build_arena_layout_spawns_layout_features() {
+            let mut app = setup_layout_app(rocky_layout());
+
+            let _ = app.world_mut().run_system_once(build_arena_layout);
+
+            let rocks = app.world_mut().query::<(&Rock, &Obstacle)>().iter(app.world()).count();
+            let tiles = app.world_mut().query::<&HazardTile>().iter(app.world()).count();
+            assert!(rocks > 0, "rock clusters should spawn rocks");
+            assert!(tiles > 0, "hazard patches should spawn tiles");
+            assert_eq!(tiles, app.world().resource::<HazardMap>().len());
+            assert_eq!(app.world().resource::<CurrentArena>().level, Some(1));
+        }
+
+        #[test]
+        fn build_arena_layout_keeps_layout_for_same_level() {
+            let mut app = setup_layout_app(rocky_layout());
+
+            let _ = app.world_mut().run_system_once(build_arena_layout);
+            let first = feature_count(&mut app);
+            let _ = app.world_mut().run_system_once(build_arena_layout);
+
+            assert_eq!(feature_count(&mut app), first, "re-entering InGame must not rebuild");
+        }
+
+        #[test]
+        fn build_arena_layout_replaces_features_on_new_level() {
+            let mut app = setup_layout_app(rocky_layout());
+
+            let _ = app.world_mut().run_system_once(build_arena_layout);
+            let first: Vec<Entity> = app
+                .world_mut()
+                .query_filtered::<Entity, With<ArenaFeature>>()
+                .iter(app.world())
+                .collect();
+
+            app.world_mut().resource_mut::<GameLevel>().advance_level();
+            let _ = app.world_mut().run_system_once(build_arena_layout);
+
+            assert!(first.iter().all(|entity| app.world().get_entity(*entity).is_err()));
+            assert!(feature_count(&mut app) > 0);
+            assert_eq!(app.world().resource::<CurrentArena>().level, Some(2));
+        }
+
+        #[test]
+        fn cleanup_arena_layout_resets_arena() {
+            let mut app = setup_layout_app(rocky_layout());
+
+            let _ = app.world_mut().run_system_once(build_arena_layout);
+            let _ = app.world_mut().run_system_once(cleanup_arena_layout);
+
+            assert_eq!(feature_count(&mut app), 0);
+            assert!(app.world().resource::<HazardMap>().is_empty());
+            assert_eq!(app.world().resource::<CurrentArena>().level, None);
+        }
+
+        #[test]
+        fn walls_use_biome_torch_color() {
+            let mut app = setup_layout_app(rocky_layout());
+            app.world_mut().resource_mut::<CurrentArena>().layout.biome.torch_color = (0.2, 0.4, 1.0);
+
+            let _ = app.world_mut().run_system_once(spawn_arena_walls);
+
+            let mut query = app.world_mut().query_filtered::<&PointLight, With<TorchLight>>();
+            let lights: Vec<_> = query.iter(app.world()).collect();
+            assert!(!lights.is_empty());
+            assert!(lights.iter().all(|light| light.color == Color::srgb(0.2, 0.4, 1.0)));
+        }
+    }
+
+    mod hazard_tests {
+        use super::*;
+        use std::time::Duration;
+
+        fn setup_hazard_app(kind: HazardKind) -> App {
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin);
+            app.add_message::<DamageEvent>();
+            app.init_resource::<HazardTickTimer>();
+            let mut hazards = HazardMap::default();
+            hazards.set(IVec2::new(0, 0), kind);
+            app.insert_resource(hazards);
+            app
+        }
+
+        fn advance(app: &mut App, secs: f32) {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(secs));
+        }
+
+        #[test]
+        fn lava_burns_bodies_standing_in_it() {
+            let mut app = setup_hazard_app(HazardKind::Lava);
+            let burning = app
+                .world_mut()
+                .spawn((Transform::from_xyz(2.0, 0.0, 2.0), Enemy { speed: 3.0, strength: 10.0 }))
+                .id();
+            app.world_mut()
+                .spawn((Transform::from_xyz(20.0, 0.0, 2.0), Enemy { speed: 3.0, strength: 10.0 }));
+
+            advance(&mut app, 0.5);
+            let _ = app.world_mut().run_system_once(lava_burn_system);
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let events: Vec<_> = messages.get_cursor().read(messages).cloned().collect();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].target, burning);
+            assert_eq!(events[0].element, Some(Element::Fire));
+            assert_eq!(events[0].amount, LAVA_DAMAGE_PER_SECOND * 0.5);
+            assert!(events[0].is_over_time());
+        }
+
+        #[test]
+        fn lava_waits_for_tick() {
+            let mut app = setup_hazard_app(HazardKind::Lava);
+            app.world_mut()
+                .spawn((Transform::from_xyz(2.0, 0.0, 2.0), Enemy { speed: 3.0, strength: 10.0 }));
+
+            advance(&mut app, 0.1);
+            let _ = app.world_mut().run_system_once(lava_burn_system);
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            assert_eq!(messages.get_cursor().read(messages).count(), 0);
+        }
+
+        #[test]
+        fn ice_keeps_momentum_after_input_stops() {
+            let mut app = setup_hazard_app(HazardKind::Ice);
+            let enemy = app
+                .world_mut()
+                .spawn((Transform::from_xyz(0.5, 0.0, 2.0), Enemy { speed: 3.0, strength: 10.0 }))
+                .id();
+
+            // Step onto the ice, then move at 4 units/sec for a frame to set the momentum
+            advance(&mut app, 0.1);
+            let _ = app.world_mut().run_system_once(ice_slide_system);
+            app.world_mut().get_mut::<Transform>(enemy).unwrap().translation.x += 0.4;
+            advance(&mut app, 0.1);
+            let _ = app.world_mut().run_system_once(ice_slide_system);
+            let before = app.world().get::<Transform>(enemy).unwrap().translation.x;
+
+            // No input this frame: the enemy keeps sliding
+            advance(&mut app, 0.1);
+            let _ = app.world_mut().run_system_once(ice_slide_system);
+            let after = app.world().get::<Transform>(enemy).unwrap().translation.x;
+
+            assert!(after > before, "enemy should slide on ice ({before} -> {after})");
+        }
+
+        #[test]
+        fn leaving_ice_removes_slide() {
+            let mut app = setup_hazard_app(HazardKind::Ice);
+            let player = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(20.0, 0.0, 20.0),
+                    Player {
+                        speed: 8.0,
+                        regen_rate: 1.0,
+                        pickup_radius: 2.0,
+                        last_movement_direction: Vec3::ZERO,
+                    },
+                    IceSlide::new(Vec2::new(20.0, 20.0)),
+                ))
+                .id();
+
+            advance(&mut app, 0.1);
+            let _ = app.world_mut().run_system_once(ice_slide_system);
+
+            assert!(app.world().get::<IceSlide>(player).is_none());
+        }
+    }
+
+    mod apply_arena_layouts_tests {
+        use super::*;
+
+        /// App running `apply_arena_layouts` over a copy of the built-in
+        /// layouts, added to `Assets` directly instead of through an asset server
+        fn setup_app() -> (App, Handle<ArenaCatalog>) {
+            let mut app = App::new();
+            app.init_resource::<Assets<ArenaCatalog>>()
+                .add_message::<AssetEvent<ArenaCatalog>>()
+                .init_resource::<ArenaCatalog>()
+                .add_systems(Update, apply_arena_layouts);
+
+            let mut file = ArenaCatalog::default();
+            file.layouts[0].name = "Loaded".to_string();
+            let handle = app.world_mut().resource_mut::<Assets<ArenaCatalog>>().add(file);
+            app.insert_resource(ArenaLayoutsHandle(handle.clone()));
+            (app, handle)
+        }
+
+        fn edit_file(app: &mut App, handle: &Handle<ArenaCatalog>, edit: impl FnOnce(&mut ArenaCatalog)) {
+            {
+                let mut files = app.world_mut().resource_mut::<Assets<ArenaCatalog>>();
+                edit(files.get_mut(handle).unwrap());
+            }
+            app.world_mut().write_message(AssetEvent::Modified { id: handle.id() });
+        }
+
+        fn first_layout_name(app: &App) -> String {
+            app.world().resource::<ArenaCatalog>().layouts[0].name.clone()
+        }
+
+        #[test]
+        fn loads_then_applies_valid_changes_and_keeps_previous_on_invalid() {
+            let (mut app, handle) = setup_app();
+            app.update();
+            assert_ne!(first_layout_name(&app), "Loaded", "nothing applies before the file loads");
+
+            app.world_mut().write_message(AssetEvent::LoadedWithDependencies { id: handle.id() });
+            app.update();
+            assert_eq!(first_layout_name(&app), "Loaded");
+
+            edit_file(&mut app, &handle, |file| file.layouts[0].name = "Edited".to_string());
+            app.update();
+            assert_eq!(first_layout_name(&app), "Edited");
+
+            edit_file(&mut app, &handle, |file| file.layouts.clear());
+            app.update();
+            assert_eq!(first_layout_name(&app), "Edited");
+        }
+
+        #[test]
+        fn ignores_events_for_other_files() {
+            let (mut app, _) = setup_app();
+            let other = app.world_mut().resource_mut::<Assets<ArenaCatalog>>().add(ArenaCatalog::default());
+            app.world_mut().write_message(AssetEvent::LoadedWithDependencies { id: other.id() });
+            app.update();
+
+            assert_eq!(*app.world().resource::<ArenaCatalog>(), ArenaCatalog::default());
+        }
+    }
 }
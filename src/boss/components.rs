@@ -0,0 +1,328 @@
+use bevy::prelude::*;
+
+use crate::combat::{Armor, ElementalResistances};
+use crate::element::Element;
+
+/// Base boss health at game level 1
+pub const BOSS_BASE_HEALTH: f32 = 600.0;
+
+/// Extra boss health per game level above 1 (+40% each)
+pub const BOSS_HEALTH_PER_GAME_LEVEL_PERCENT: f32 = 0.4;
+
+/// Base boss contact damage at game level 1
+pub const BOSS_BASE_DAMAGE: f32 = 25.0;
+
+/// Extra boss contact damage per game level above 1
+pub const BOSS_DAMAGE_PER_GAME_LEVEL: f32 = 5.0;
+
+/// Boss movement speed in phase one (slower than every regular enemy kind)
+pub const BOSS_BASE_SPEED: f32 = 1.8;
+
+/// Bosses count as legendary enemies for loot, score and XP
+pub const BOSS_ENEMY_LEVEL: u8 = 5;
+
+/// Height of the boss mesh center above ground (half of 3.0 height)
+pub const BOSS_Y_HEIGHT: f32 = 1.5;
+
+/// Speed of boss projectiles in world units/sec
+pub const BOSS_PROJECTILE_SPEED: f32 = 8.0;
+
+/// Fraction of the boss's contact damage dealt by each projectile
+pub const BOSS_PROJECTILE_DAMAGE_FRACTION: f32 = 0.4;
+
+/// Total angle (radians) covered by an aimed spread
+pub const BOSS_SPREAD_ANGLE: f32 = 0.9;
+
+/// Boss armor at game level 1
+pub const BOSS_BASE_ARMOR: f32 = 3.0;
+
+/// Boss armor gained each game level
+pub const BOSS_ARMOR_PER_GAME_LEVEL: f32 = 0.5;
+
+/// Boss health for a game level
+pub fn boss_health_for_level(game_level: u32) -> f32 {
+    BOSS_BASE_HEALTH
+        * (1.0 + game_level.saturating_sub(1) as f32 * BOSS_HEALTH_PER_GAME_LEVEL_PERCENT)
+}
+
+/// Boss contact damage for a game level
+pub fn boss_damage_for_level(game_level: u32) -> f32 {
+    BOSS_BASE_DAMAGE + game_level.saturating_sub(1) as f32 * BOSS_DAMAGE_PER_GAME_LEVEL
+}
+
+/// Boss armor for a game level
+pub fn boss_armor_for_level(game_level: u32) -> Armor {
+    Armor(BOSS_BASE_ARMOR + game_level.saturating_sub(1) as f32 * BOSS_ARMOR_PER_GAME_LEVEL)
+}
+
+/// Display name of the boss guarding a game level (cycles after the last name)
+pub fn boss_name(game_level: u32) -> &'static str {
+    const NAMES: [&str; 5] = [
+        "The Hollow King",
+        "Matron of Ash",
+        "The Rimebound",
+        "Choir of Teeth",
+        "The Last Whisper",
+    ];
+    NAMES[(game_level.saturating_sub(1) as usize) % NAMES.len()]
+}
+
+/// Elemental affinities of the boss guarding a game level (cycles with `boss_name`).
+/// Every boss is immune to its own element and weak to its opposite.
+pub fn boss_resistances(game_level: u32) -> ElementalResistances {
+    const AFFINITIES: [(Element, Element); 5] = [
+        (Element::Dark, Element::Light),        // The Hollow King
+        (Element::Fire, Element::Frost),        // Matron of Ash
+        (Element::Frost, Element::Fire),        // The Rimebound
+        (Element::Psychic, Element::Lightning), // Choir of Teeth
+        (Element::Chaos, Element::Light),       // The Last Whisper
+    ];
+    let (immune, weak) = AFFINITIES[(game_level.saturating_sub(1) as usize) % AFFINITIES.len()];
+    ElementalResistances::default().immune_to(immune).weak_to(weak)
+}
+
+/// Boss fight phases, advanced as the boss loses health.
+/// Phases never go backwards, even if the boss heals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum BossPhase {
+    /// Above 66% health: slow aimed spreads
+    #[default]
+    One,
+    /// Above 33% health: alternates spreads and rings
+    Two,
+    /// Enraged: faster movement, rapid rings and wide spreads
+    Three,
+}
+
+impl BossPhase {
+    /// Phase for a health fraction (0.0 - 1.0)
+    pub fn for_health_fraction(fraction: f32) -> Self {
+        if fraction > 0.66 {
+            BossPhase::One
+        } else if fraction > 0.33 {
+            BossPhase::Two
+        } else {
+            BossPhase::Three
+        }
+    }
+
+    /// Seconds between attacks
+    pub fn attack_interval(&self) -> f32 {
+        match self {
+            BossPhase::One => 2.5,
+            BossPhase::Two => 2.0,
+            BossPhase::Three => 1.2,
+        }
+    }
+
+    /// Multiplier applied to the boss's base speed
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            BossPhase::One | BossPhase::Two => 1.0,
+            BossPhase::Three => 1.6,
+        }
+    }
+
+    /// Attack to use for the boss's `attack_index`-th attack in this phase
+    pub fn attack(&self, attack_index: u32) -> BossAttack {
+        let even = attack_index.is_multiple_of(2);
+        match self {
+            BossPhase::One => BossAttack::AimedSpread { count: 3 },
+            BossPhase::Two if even => BossAttack::AimedSpread { count: 5 },
+            BossPhase::Two => BossAttack::RadialRing { count: 12 },
+            BossPhase::Three if even => BossAttack::RadialRing { count: 16 },
+            BossPhase::Three => BossAttack::AimedSpread { count: 7 },
+        }
+    }
+}
+
+/// Projectile patterns a boss can fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossAttack {
+    /// Fan of projectiles centered on the player
+    AimedSpread { count: u32 },
+    /// Evenly spaced ring in every direction, one shot aimed at the player
+    RadialRing { count: u32 },
+}
+
+impl BossAttack {
+    /// Projectile directions on the XZ plane, given the direction toward the player
+    pub fn directions(&self, toward_player: Vec2) -> Vec<Vec2> {
+        let aim = toward_player.try_normalize().unwrap_or(Vec2::X);
+        match *self {
+            BossAttack::AimedSpread { count } => {
+                if count <= 1 {
+                    return vec![aim];
+                }
+                let step = BOSS_SPREAD_ANGLE / (count - 1) as f32;
+                (0..count)
+                    .map(|i| {
+                        let offset = -BOSS_SPREAD_ANGLE / 2.0 + i as f32 * step;
+                        Vec2::from_angle(offset).rotate(aim)
+                    })
+                    .collect()
+            }
+            BossAttack::RadialRing { count } => (0..count)
+                .map(|i| {
+                    let angle = std::f32::consts::TAU * i as f32 / count as f32;
+                    Vec2::from_angle(angle).rotate(aim)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// End-of-level boss. Spawned once `GameLevel::kills_to_advance` is reached;
+/// killing it completes the level.
+#[derive(Component, Debug, Clone)]
+pub struct Boss {
+    /// Game level this boss guards
+    pub game_level: u32,
+    /// Current fight phase
+    pub phase: BossPhase,
+    /// Cooldown between attacks (interval depends on phase)
+    pub attack_timer: Timer,
+    /// Attacks made in the current phase, used to alternate patterns
+    pub attacks_made: u32,
+    /// Phase one movement speed
+    pub base_speed: f32,
+}
+
+impl Boss {
+    pub fn new(game_level: u32) -> Self {
+        let phase = BossPhase::One;
+        Self {
+            game_level,
+            phase,
+            attack_timer: Timer::from_seconds(phase.attack_interval(), TimerMode::Repeating),
+            attacks_made: 0,
+            base_speed: BOSS_BASE_SPEED,
+        }
+    }
+
+    /// Switch to a new phase, restarting the attack cycle
+    pub fn enter_phase(&mut self, phase: BossPhase) {
+        self.phase = phase;
+        self.attack_timer = Timer::from_seconds(phase.attack_interval(), TimerMode::Repeating);
+        self.attacks_made = 0;
+    }
+
+    /// Movement speed for the current phase
+    pub fn speed(&self) -> f32 {
+        self.base_speed * self.phase.speed_multiplier()
+    }
+
+    /// The attack the boss will make next
+    pub fn next_attack(&self) -> BossAttack {
+        self.phase.attack(self.attacks_made)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod boss_stats_tests {
+        use super::*;
+
+        #[test]
+        fn boss_health_scales_with_game_level() {
+            assert_eq!(boss_health_for_level(1), 600.0);
+            assert!((boss_health_for_level(3) - 1080.0).abs() < 0.01);
+        }
+
+        #[test]
+        fn boss_damage_scales_with_game_level() {
+            assert_eq!(boss_damage_for_level(1), 25.0);
+            assert_eq!(boss_damage_for_level(4), 40.0);
+        }
+
+        #[test]
+        fn boss_armor_scales_with_game_level() {
+            assert_eq!(boss_armor_for_level(1), Armor(3.0));
+            assert_eq!(boss_armor_for_level(5), Armor(5.0));
+        }
+
+        #[test]
+        fn boss_names_cycle() {
+            assert_eq!(boss_name(1), "The Hollow King");
+            assert_eq!(boss_name(6), boss_name(1));
+            assert_ne!(boss_name(1), boss_name(2));
+        }
+
+        #[test]
+        fn boss_resistances_cycle_with_names() {
+            let hollow_king = boss_resistances(1);
+            assert_eq!(hollow_king.multiplier(Some(Element::Dark)), 0.0);
+            assert!(hollow_king.multiplier(Some(Element::Light)) > 1.0);
+            assert_eq!(hollow_king.multiplier(None), 1.0);
+            assert_eq!(boss_resistances(6), hollow_king);
+            assert_eq!(boss_resistances(2).multiplier(Some(Element::Fire)), 0.0);
+        }
+    }
+
+    mod boss_phase_tests {
+        use super::*;
+
+        #[test]
+        fn phase_follows_health_thresholds() {
+            assert_eq!(BossPhase::for_health_fraction(1.0), BossPhase::One);
+            assert_eq!(BossPhase::for_health_fraction(0.67), BossPhase::One);
+            assert_eq!(BossPhase::for_health_fraction(0.5), BossPhase::Two);
+            assert_eq!(BossPhase::for_health_fraction(0.2), BossPhase::Three);
+            assert_eq!(BossPhase::for_health_fraction(0.0), BossPhase::Three);
+        }
+
+        #[test]
+        fn later_phases_attack_faster() {
+            assert!(BossPhase::Two.attack_interval() < BossPhase::One.attack_interval());
+            assert!(BossPhase::Three.attack_interval() < BossPhase::Two.attack_interval());
+            assert!(BossPhase::Three.speed_multiplier() > BossPhase::One.speed_multiplier());
+        }
+
+        #[test]
+        fn phase_two_alternates_attacks() {
+            assert!(matches!(BossPhase::Two.attack(0), BossAttack::AimedSpread { .. }));
+            assert!(matches!(BossPhase::Two.attack(1), BossAttack::RadialRing { .. }));
+        }
+
+        #[test]
+        fn enter_phase_resets_attack_cycle() {
+            let mut boss = Boss::new(1);
+            boss.attacks_made = 3;
+            boss.enter_phase(BossPhase::Three);
+            assert_eq!(boss.phase, BossPhase::Three);
+            assert_eq!(boss.attacks_made, 0);
+            assert_eq!(boss.attack_timer.duration().as_secs_f32(), BossPhase::Three.attack_interval());
+            assert!((boss.speed() - BOSS_BASE_SPEED * 1.6).abs() < 0.001);
+        }
+    }
+
+    mod boss_attack_tests {
+        use super::*;
+
+        #[test]
+        fn aimed_spread_is_centered_on_player() {
+            let directions = BossAttack::AimedSpread { count: 3 }.directions(Vec2::new(0.0, 5.0));
+            assert_eq!(directions.len(), 3);
+            assert!(directions[1].distance(Vec2::Y) < 0.001);
+            // Outer shots mirror each other around the aim direction
+            assert!((directions[0].x + directions[2].x).abs() < 0.001);
+        }
+
+        #[test]
+        fn radial_ring_covers_all_directions() {
+            let directions = BossAttack::RadialRing { count: 4 }.directions(Vec2::X);
+            assert_eq!(directions.len(), 4);
+            let sum: Vec2 = directions.iter().copied().sum();
+            assert!(sum.length() < 0.001);
+            assert!(directions[0].distance(Vec2::X) < 0.001);
+        }
+
+        #[test]
+        fn directions_handle_zero_aim() {
+            let directions = BossAttack::AimedSpread { count: 1 }.directions(Vec2::ZERO);
+            assert_eq!(directions, vec![Vec2::X]);
+        }
+    }
+}
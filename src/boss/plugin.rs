@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+
+use crate::boss::systems::*;
+use crate::combat::{check_death_system, handle_enemy_death_system, CombatSets};
+use crate::game::events::BossDefeatedEvent;
+use crate::game::resources::GameRng;
+use crate::game::sets::GameSet;
+use crate::game::systems::track_enemy_kills_system;
+use crate::states::GameState;
+
+/// Boss plugin: summons the level boss once enough enemies are killed and
+/// completes the level when it dies. Regular spawning is paused by the game
+/// plugin while a boss is alive (see `no_boss_alive`).
+pub fn plugin(app: &mut App) {
+    app.add_message::<BossDefeatedEvent>()
+        .init_resource::<GameRng>()
+        .add_systems(
+            Update,
+            boss_phase_system
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            boss_attack_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // The boss must be read before handle_enemy_death_system despawns it
+        .add_systems(
+            Update,
+            boss_death_system
+                .in_set(CombatSets::Death)
+                .after(check_death_system)
+                .before(handle_enemy_death_system)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            spawn_boss_system
+                .in_set(GameSet::Spawning)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Count the boss kill toward the finished level before it advances
+        .add_systems(
+            Update,
+            complete_level_on_boss_defeat
+                .in_set(GameSet::Effects)
+                .after(track_enemy_kills_system)
+                .run_if(in_state(GameState::InGame)),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_registers_boss_events() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().get_resource::<Messages<BossDefeatedEvent>>().is_some());
+    }
+}
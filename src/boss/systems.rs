@@ -0,0 +1,480 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::boss::components::*;
+use crate::combat::{CheckDeath, DeathEvent, EntityType, Health};
+use crate::enemies::components::{Enemy, EnemyProjectile};
+use crate::enemies::systems::{spawn_enemy_projectile, ENEMY_SPAWN_DISTANCE};
+use crate::game::components::Level;
+use crate::game::events::{BossDefeatedEvent, GameLevelUpEvent, LootDropEvent};
+use crate::game::resources::{EnemyKindMaterials, GameLevel, GameMeshes, GameRng, PlayerPosition};
+use crate::movement::components::from_xz;
+use crate::spells::frost::permafrost::FrozenStatus;
+use crate::spells::psychic::synapse_shock::StunnedEnemy;
+use crate::states::GameState;
+
+/// Run condition: regular enemy spawning pauses while a boss is alive
+pub fn no_boss_alive(boss_query: Query<(), With<Boss>>) -> bool {
+    boss_query.is_empty()
+}
+
+/// Spawns the level boss just outside the view once `GameLevel::boss_due`.
+/// Driven by the level's state rather than a kill message, so a threshold reached
+/// while an overlay is open still summons the boss when play resumes.
+pub fn spawn_boss_system(
+    mut commands: Commands,
+    mut game_level: ResMut<GameLevel>,
+    boss_query: Query<(), With<Boss>>,
+    player_position: Res<PlayerPosition>,
+    game_meshes: Option<Res<GameMeshes>>,
+    kind_materials: Option<Res<EnemyKindMaterials>>,
+    mut rng: ResMut<GameRng>,
+) {
+    if !game_level.boss_due() || !boss_query.is_empty() {
+        return;
+    }
+
+    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+    let spawn_xz = player_position.0 + Vec2::new(angle.cos(), angle.sin()) * ENEMY_SPAWN_DISTANCE;
+    let transform = Transform::from_translation(Vec3::new(spawn_xz.x, BOSS_Y_HEIGHT, spawn_xz.y));
+
+    let level = game_level.level;
+    let boss = Boss::new(level);
+    let components = (
+        transform,
+        Enemy {
+            speed: boss.speed(),
+            strength: boss_damage_for_level(level),
+        },
+        Health::new(boss_health_for_level(level)),
+        Level::new(BOSS_ENEMY_LEVEL),
+        CheckDeath,
+        boss_resistances(level),
+        boss_armor_for_level(level),
+        boss,
+    );
+
+    if let (Some(meshes), Some(materials)) = (game_meshes.as_ref(), kind_materials.as_ref()) {
+        commands.spawn((
+            Mesh3d(meshes.boss.clone()),
+            MeshMaterial3d(materials.boss.clone()),
+            components,
+        ));
+    } else {
+        // Fallback for tests without mesh resources
+        commands.spawn(components);
+    }
+
+    game_level.boss_spawned = true;
+}
+
+/// Advances boss phases as health drops, speeding up movement and attacks.
+pub fn boss_phase_system(mut boss_query: Query<(&mut Boss, &mut Enemy, &Health)>) {
+    for (mut boss, mut enemy, health) in boss_query.iter_mut() {
+        let phase = BossPhase::for_health_fraction(health.percentage());
+        if phase > boss.phase {
+            boss.enter_phase(phase);
+            enemy.speed = boss.speed();
+        }
+    }
+}
+
+/// Fires the current phase's projectile pattern whenever the attack timer elapses.
+/// Frozen and stunned bosses hold their fire.
+#[allow(clippy::type_complexity)]
+pub fn boss_attack_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut boss_query: Query<
+        (&Transform, &Enemy, &mut Boss),
+        (Without<FrozenStatus>, Without<StunnedEnemy>),
+    >,
+    player_position: Res<PlayerPosition>,
+    game_meshes: Option<Res<GameMeshes>>,
+    kind_materials: Option<Res<EnemyKindMaterials>>,
+) {
+    for (transform, enemy, mut boss) in boss_query.iter_mut() {
+        boss.attack_timer.tick(time.delta());
+        if !boss.attack_timer.just_finished() {
+            continue;
+        }
+
+        let boss_pos = from_xz(transform.translation);
+        let damage = enemy.strength * BOSS_PROJECTILE_DAMAGE_FRACTION;
+        for direction in boss.next_attack().directions(player_position.0 - boss_pos) {
+            spawn_enemy_projectile(
+                &mut commands,
+                boss_pos,
+                EnemyProjectile::new(direction, BOSS_PROJECTILE_SPEED, damage),
+                game_meshes.as_deref(),
+                kind_materials.as_deref(),
+            );
+        }
+        boss.attacks_made += 1;
+    }
+}
+
+/// Drops the boss's guaranteed loot and announces its defeat.
+/// Must run before `handle_enemy_death_system` despawns the boss.
+pub fn boss_death_system(
+    mut death_events: MessageReader<DeathEvent>,
+    boss_query: Query<&Boss>,
+    mut loot_drop_events: MessageWriter<LootDropEvent>,
+    mut boss_defeated_events: MessageWriter<BossDefeatedEvent>,
+) {
+    for event in death_events.read() {
+        if event.entity_type != EntityType::Enemy {
+            continue;
+        }
+
+        if let Ok(boss) = boss_query.get(event.entity) {
+            loot_drop_events.write(LootDropEvent {
+                position: event.position,
+                enemy_level: BOSS_ENEMY_LEVEL,
+                affix_count: 0,
+                guaranteed_loot: true,
+            });
+            boss_defeated_events.write(BossDefeatedEvent {
+                position: event.position,
+                game_level: boss.game_level,
+            });
+        }
+    }
+}
+
+/// Completes the level when its boss is defeated: advances the game level,
+/// clears leftover boss projectiles and shows the level complete screen.
+pub fn complete_level_on_boss_defeat(
+    mut commands: Commands,
+    mut boss_defeated_events: MessageReader<BossDefeatedEvent>,
+    mut game_level: ResMut<GameLevel>,
+    mut level_up_writer: MessageWriter<GameLevelUpEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+    projectile_query: Query<Entity, With<EnemyProjectile>>,
+) {
+    if boss_defeated_events.read().last().is_none() {
+        return;
+    }
+
+    game_level.advance_level();
+    level_up_writer.write(GameLevelUpEvent {
+        new_level: game_level.level,
+    });
+
+    for entity in projectile_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    next_state.set(GameState::LevelComplete);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    mod spawn_boss_tests {
+        use super::*;
+
+        fn setup_app() -> App {
+            let mut app = App::new();
+            app.insert_resource(PlayerPosition(Vec2::new(3.0, -2.0)));
+            app.init_resource::<GameLevel>();
+            app.init_resource::<GameRng>();
+            app
+        }
+
+        fn reach_boss_threshold(app: &mut App) {
+            let mut game_level = app.world_mut().resource_mut::<GameLevel>();
+            game_level.kills_this_level = game_level.kills_to_advance();
+        }
+
+        #[test]
+        fn spawns_boss_with_level_scaled_stats() {
+            let mut app = setup_app();
+            app.world_mut().resource_mut::<GameLevel>().advance_level();
+            reach_boss_threshold(&mut app);
+
+            let _ = app.world_mut().run_system_once(spawn_boss_system);
+
+            let mut query = app.world_mut().query::<(&Boss, &Enemy, &Health, &Level, &Transform)>();
+            let bosses: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(bosses.len(), 1);
+
+            let (boss, enemy, health, level, transform) = bosses[0];
+            assert_eq!(boss.game_level, 2);
+            assert_eq!(boss.phase, BossPhase::One);
+            assert_eq!(health.max, boss_health_for_level(2));
+            assert_eq!(enemy.strength, boss_damage_for_level(2));
+            assert_eq!(level.value(), BOSS_ENEMY_LEVEL);
+            assert_eq!(transform.translation.y, BOSS_Y_HEIGHT);
+
+            let distance = from_xz(transform.translation).distance(Vec2::new(3.0, -2.0));
+            assert!((distance - ENEMY_SPAWN_DISTANCE).abs() < 0.01);
+            assert!(app.world().resource::<GameLevel>().boss_spawned);
+        }
+
+        #[test]
+        fn no_boss_before_threshold() {
+            let mut app = setup_app();
+            let _ = app.world_mut().run_system_once(spawn_boss_system);
+
+            let mut query = app.world_mut().query::<&Boss>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+        }
+
+        #[test]
+        fn only_one_boss_at_a_time() {
+            let mut app = setup_app();
+            reach_boss_threshold(&mut app);
+            let _ = app.world_mut().run_system_once(spawn_boss_system);
+
+            // More kills past the threshold don't summon a second boss
+            app.world_mut().resource_mut::<GameLevel>().register_kill();
+            let _ = app.world_mut().run_system_once(spawn_boss_system);
+
+            let mut query = app.world_mut().query::<&Boss>();
+            assert_eq!(query.iter(app.world()).count(), 1);
+        }
+
+        #[test]
+        fn boss_spawns_after_threshold_reached_outside_in_game() {
+            let mut app = App::new();
+            app.add_plugins(bevy::state::app::StatesPlugin);
+            app.init_state::<GameState>();
+            app.insert_resource(PlayerPosition(Vec2::ZERO));
+            app.init_resource::<GameLevel>();
+            app.init_resource::<GameRng>();
+            app.add_systems(Update, spawn_boss_system.run_if(in_state(GameState::InGame)));
+
+            // The threshold is reached on the frame a level-up choice opens
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::LevelUpChoice);
+            app.update();
+            reach_boss_threshold(&mut app);
+            for _ in 0..3 {
+                app.update();
+            }
+
+            let mut query = app.world_mut().query::<&Boss>();
+            assert_eq!(query.iter(app.world()).count(), 0, "No boss while the overlay is open");
+
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::InGame);
+            app.update();
+            app.update();
+
+            let mut query = app.world_mut().query::<&Boss>();
+            assert_eq!(query.iter(app.world()).count(), 1, "Boss is summoned on resume");
+        }
+
+        #[test]
+        fn no_boss_alive_tracks_boss_entities() {
+            let mut app = setup_app();
+            assert!(app.world_mut().run_system_once(no_boss_alive).unwrap());
+
+            app.world_mut().spawn(Boss::new(1));
+            assert!(!app.world_mut().run_system_once(no_boss_alive).unwrap());
+        }
+    }
+
+    mod boss_phase_system_tests {
+        use super::*;
+
+        #[test]
+        fn phase_advances_and_speeds_up_boss() {
+            let mut app = App::new();
+            let mut health = Health::new(100.0);
+            health.take_damage(80.0);
+            let boss = app.world_mut().spawn((
+                Boss::new(1),
+                Enemy { speed: BOSS_BASE_SPEED, strength: 25.0 },
+                health,
+            )).id();
+
+            let _ = app.world_mut().run_system_once(boss_phase_system);
+
+            let boss_component = app.world().get::<Boss>(boss).unwrap();
+            assert_eq!(boss_component.phase, BossPhase::Three);
+            let enemy = app.world().get::<Enemy>(boss).unwrap();
+            assert!((enemy.speed - BOSS_BASE_SPEED * 1.6).abs() < 0.001);
+        }
+
+        #[test]
+        fn phase_does_not_regress_when_healed() {
+            let mut app = App::new();
+            let mut boss = Boss::new(1);
+            boss.enter_phase(BossPhase::Two);
+            let entity = app.world_mut().spawn((
+                boss,
+                Enemy { speed: BOSS_BASE_SPEED, strength: 25.0 },
+                Health::new(100.0),
+            )).id();
+
+            let _ = app.world_mut().run_system_once(boss_phase_system);
+
+            assert_eq!(app.world().get::<Boss>(entity).unwrap().phase, BossPhase::Two);
+        }
+    }
+
+    mod boss_attack_system_tests {
+        use super::*;
+
+        fn setup_app() -> App {
+            let mut app = App::new();
+            app.init_resource::<Time>();
+            app.insert_resource(PlayerPosition(Vec2::new(10.0, 0.0)));
+            app
+        }
+
+        fn advance_time(app: &mut App, secs: f32) {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(secs));
+        }
+
+        #[test]
+        fn fires_phase_pattern_when_timer_elapses() {
+            let mut app = setup_app();
+            app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, BOSS_Y_HEIGHT, 0.0)),
+                Enemy { speed: BOSS_BASE_SPEED, strength: 25.0 },
+                Boss::new(1),
+            ));
+
+            advance_time(&mut app, BossPhase::One.attack_interval() + 0.01);
+            let _ = app.world_mut().run_system_once(boss_attack_system);
+
+            let mut query = app.world_mut().query::<&EnemyProjectile>();
+            let projectiles: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(projectiles.len(), 3, "Phase one fires a three-shot spread");
+            for projectile in projectiles {
+                assert_eq!(projectile.damage, 25.0 * BOSS_PROJECTILE_DAMAGE_FRACTION);
+                assert!(projectile.direction.x > 0.0, "Spread should aim toward the player");
+            }
+
+            let mut boss_query = app.world_mut().query::<&Boss>();
+            assert_eq!(boss_query.single(app.world()).unwrap().attacks_made, 1);
+        }
+
+        #[test]
+        fn does_not_fire_before_timer_elapses() {
+            let mut app = setup_app();
+            app.world_mut().spawn((
+                Transform::default(),
+                Enemy { speed: BOSS_BASE_SPEED, strength: 25.0 },
+                Boss::new(1),
+            ));
+
+            advance_time(&mut app, 0.5);
+            let _ = app.world_mut().run_system_once(boss_attack_system);
+
+            let mut query = app.world_mut().query::<&EnemyProjectile>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+        }
+
+        #[test]
+        fn frozen_boss_holds_fire() {
+            let mut app = setup_app();
+            app.world_mut().spawn((
+                Transform::default(),
+                Enemy { speed: BOSS_BASE_SPEED, strength: 25.0 },
+                Boss::new(1),
+                FrozenStatus::default(),
+            ));
+
+            advance_time(&mut app, BossPhase::One.attack_interval() + 0.01);
+            let _ = app.world_mut().run_system_once(boss_attack_system);
+
+            let mut query = app.world_mut().query::<&EnemyProjectile>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+        }
+    }
+
+    mod boss_death_tests {
+        use super::*;
+        use bevy::state::app::StatesPlugin;
+
+        #[test]
+        fn boss_death_drops_guaranteed_loot() {
+            let mut app = App::new();
+            app.add_message::<DeathEvent>();
+            app.add_message::<LootDropEvent>();
+            app.add_message::<BossDefeatedEvent>();
+
+            let boss = app.world_mut().spawn(Boss::new(3)).id();
+            let grunt = app.world_mut().spawn(Enemy { speed: 2.0, strength: 10.0 }).id();
+            app.world_mut().write_message(DeathEvent::new(boss, Vec3::new(1.0, 0.0, 2.0), EntityType::Enemy));
+            app.world_mut().write_message(DeathEvent::new(grunt, Vec3::ZERO, EntityType::Enemy));
+
+            let _ = app.world_mut().run_system_once(boss_death_system);
+
+            let loot_messages = app.world().resource::<Messages<LootDropEvent>>();
+            let mut cursor = loot_messages.get_cursor();
+            let drops: Vec<_> = cursor.read(loot_messages).collect();
+            assert_eq!(drops.len(), 1, "Only the boss gets a guaranteed drop");
+            assert!(drops[0].guaranteed_loot);
+            assert_eq!(drops[0].enemy_level, BOSS_ENEMY_LEVEL);
+            assert_eq!(drops[0].position, Vec3::new(1.0, 0.0, 2.0));
+
+            let defeated_messages = app.world().resource::<Messages<BossDefeatedEvent>>();
+            let mut cursor = defeated_messages.get_cursor();
+            let defeated: Vec<_> = cursor.read(defeated_messages).collect();
+            assert_eq!(defeated.len(), 1);
+            assert_eq!(defeated[0].game_level, 3);
+        }
+
+        #[test]
+        fn boss_defeat_completes_level() {
+            let mut app = App::new();
+            app.add_plugins(StatesPlugin);
+            app.init_state::<GameState>();
+            app.init_resource::<GameLevel>();
+            app.add_message::<BossDefeatedEvent>();
+            app.add_message::<GameLevelUpEvent>();
+
+            let projectile = app.world_mut().spawn(EnemyProjectile::new(Vec2::X, 8.0, 10.0)).id();
+            app.world_mut().write_message(BossDefeatedEvent {
+                position: Vec3::ZERO,
+                game_level: 1,
+            });
+
+            let _ = app.world_mut().run_system_once(complete_level_on_boss_defeat);
+            app.update();
+
+            let game_level = app.world().resource::<GameLevel>();
+            assert_eq!(game_level.level, 2);
+            assert_eq!(game_level.kills_this_level, 0);
+            assert!(app.world().get_entity(projectile).is_err(), "Boss projectiles are cleared");
+
+            let state = app.world().resource::<State<GameState>>();
+            assert_eq!(*state.get(), GameState::LevelComplete);
+
+            let level_ups = app.world().resource::<Messages<GameLevelUpEvent>>();
+            let mut cursor = level_ups.get_cursor();
+            let events: Vec<_> = cursor.read(level_ups).collect();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].new_level, 2);
+        }
+
+        #[test]
+        fn no_level_change_without_boss_defeat() {
+            let mut app = App::new();
+            app.add_plugins(StatesPlugin);
+            app.init_state::<GameState>();
+            app.init_resource::<GameLevel>();
+            app.add_message::<BossDefeatedEvent>();
+            app.add_message::<GameLevelUpEvent>();
+
+            let _ = app.world_mut().run_system_once(complete_level_on_boss_defeat);
+            app.update();
+
+            assert_eq!(app.world().resource::<GameLevel>().level, 1);
+            let state = app.world().resource::<State<GameState>>();
+            assert_eq!(*state.get(), GameState::Intro);
+        }
+    }
+}
@@ -2,11 +2,13 @@ use bevy::prelude::*;
 
 use crate::game::resources::FreeCameraState;
 use crate::game::sets::GameSet;
+use crate::input::bindings::InputMap;
 use crate::states::GameState;
 use crate::camera::systems::{free_camera_movement, free_camera_rotation, free_camera_toggle};
 
 pub fn plugin(app: &mut App) {
     app.init_resource::<FreeCameraState>()
+        .init_resource::<InputMap>()
         .add_systems(
             Update,
             (
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::input::mouse::MouseMotion;
 
 use crate::game::resources::FreeCameraState;
+use crate::input::bindings::{ActionInput, InputAction};
 
 // Re-export MessageReader for older Bevy API compatibility
 type MouseMotionReader<'w, 's> = MessageReader<'w, 's, MouseMotion>;
@@ -15,15 +16,15 @@ const MIN_PITCH: f32 = -std::f32::consts::FRAC_PI_2 + 0.1;
 /// Maximum pitch angle (looking down limit) in radians
 const MAX_PITCH: f32 = std::f32::consts::FRAC_PI_2 - 0.1;
 
-/// System that toggles free camera mode based on right mouse button state.
-/// When right mouse button is pressed, initializes yaw/pitch from current camera rotation.
+/// System that toggles free camera mode based on the FreeCamera action (right mouse by default).
+/// When pressed, initializes yaw/pitch from current camera rotation.
 /// When released, camera returns to following player.
 pub fn free_camera_toggle(
-    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    input: ActionInput,
     mut free_camera: ResMut<FreeCameraState>,
     camera_query: Query<&Transform, With<Camera3d>>,
 ) {
-    if mouse_button_input.just_pressed(MouseButton::Right) {
+    if input.just_pressed(InputAction::FreeCamera) {
         free_camera.active = true;
 
         // Initialize yaw/pitch from current camera orientation
@@ -32,7 +33,7 @@ pub fn free_camera_toggle(
             free_camera.yaw = yaw;
             free_camera.pitch = pitch;
         }
-    } else if mouse_button_input.just_released(MouseButton::Right) {
+    } else if input.just_released(InputAction::FreeCamera) {
         free_camera.active = false;
     }
 }
@@ -75,10 +76,10 @@ pub fn free_camera_rotation(
     }
 }
 
-/// System that moves the camera with WASD keys when in free camera mode.
+/// System that moves the camera with the move actions (WASD by default) when in free camera mode.
 /// Movement is relative to camera's current facing direction.
 pub fn free_camera_movement(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     free_camera: Res<FreeCameraState>,
     mut camera_query: Query<&mut Transform, With<Camera3d>>,
     time: Res<Time>,
@@ -94,16 +95,16 @@ pub fn free_camera_movement(
     // Calculate movement direction based on input
     let mut direction = Vec3::ZERO;
 
-    if keyboard_input.pressed(KeyCode::KeyW) {
+    if input.pressed(InputAction::MoveUp) {
         direction += *transform.forward();
     }
-    if keyboard_input.pressed(KeyCode::KeyS) {
+    if input.pressed(InputAction::MoveDown) {
         direction += *transform.back();
     }
-    if keyboard_input.pressed(KeyCode::KeyA) {
+    if input.pressed(InputAction::MoveLeft) {
         direction += *transform.left();
     }
-    if keyboard_input.pressed(KeyCode::KeyD) {
+    if input.pressed(InputAction::MoveRight) {
         direction += *transform.right();
     }
 
@@ -117,6 +118,7 @@ pub fn free_camera_movement(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::input::bindings::InputMap;
     use bevy::app::App;
     use bevy::ecs::system::RunSystemOnce;
 
@@ -124,6 +126,7 @@ mod tests {
     fn setup_test_app() -> App {
         let mut app = App::new();
         app.add_plugins(bevy::input::InputPlugin);
+        app.init_resource::<InputMap>();
         app.init_resource::<FreeCameraState>();
         app.add_message::<MouseMotion>();
         app
@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+
+use crate::spell::SpellType;
+
+/// Radius of the player's body when pushed out of obstacles
+pub const PLAYER_BODY_RADIUS: f32 = 0.5;
+
+/// Radius of an enemy's body when pushed out of obstacles
+pub const ENEMY_BODY_RADIUS: f32 = 0.5;
+
+/// Radius used when testing projectiles against obstacles
+pub const PROJECTILE_OBSTACLE_RADIUS: f32 = 0.25;
+
+/// Footprint of an obstacle on the XZ plane, centered on its Transform.
+/// Obstacles are treated as infinitely tall, so height is ignored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObstacleShape {
+    Circle { radius: f32 },
+    /// Axis-aligned box; the entity's rotation is ignored
+    Aabb { half_extents: Vec2 },
+}
+
+impl ObstacleShape {
+    /// Radius of a circle enclosing the whole shape
+    pub fn bounding_radius(&self) -> f32 {
+        match *self {
+            ObstacleShape::Circle { radius } => radius,
+            ObstacleShape::Aabb { half_extents } => half_extents.length(),
+        }
+    }
+
+    /// Whether a circle at `position` with `radius` overlaps the shape at `center`
+    pub fn overlaps(&self, center: Vec2, position: Vec2, radius: f32) -> bool {
+        match *self {
+            ObstacleShape::Circle { radius: own } => {
+                center.distance_squared(position) < (own + radius).powi(2)
+            }
+            ObstacleShape::Aabb { half_extents } => {
+                let closest = position.clamp(center - half_extents, center + half_extents);
+                closest.distance_squared(position) < radius * radius
+            }
+        }
+    }
+
+    /// Position of a circle moved out of the shape along the shortest path,
+    /// or `None` when it doesn't overlap.
+    pub fn push_out(&self, center: Vec2, position: Vec2, radius: f32) -> Option<Vec2> {
+        if !self.overlaps(center, position, radius) {
+            return None;
+        }
+
+        match *self {
+            ObstacleShape::Circle { radius: own } => {
+                let away = (position - center).try_normalize().unwrap_or(Vec2::X);
+                Some(center + away * (own + radius))
+            }
+            ObstacleShape::Aabb { half_extents } => {
+                let min = center - half_extents;
+                let max = center + half_extents;
+                let closest = position.clamp(min, max);
+                if closest != position {
+                    // Center outside the box: slide along the nearest edge or corner
+                    let away = (position - closest).normalize();
+                    return Some(closest + away * radius);
+                }
+
+                // Center inside the box: leave through the nearest side
+                let exits = [
+                    (position.x - min.x, Vec2::new(min.x - radius, position.y)),
+                    (max.x - position.x, Vec2::new(max.x + radius, position.y)),
+                    (position.y - min.y, Vec2::new(position.x, min.y - radius)),
+                    (max.y - position.y, Vec2::new(position.x, max.y + radius)),
+                ];
+                exits
+                    .into_iter()
+                    .min_by(|a, b| a.0.total_cmp(&b.0))
+                    .map(|(_, exit)| exit)
+            }
+        }
+    }
+}
+
+/// Static blocker for movement and projectiles (rocks, arena walls).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Obstacle {
+    pub shape: ObstacleShape,
+}
+
+impl Obstacle {
+    pub fn circle(radius: f32) -> Self {
+        Self {
+            shape: ObstacleShape::Circle { radius },
+        }
+    }
+
+    pub fn aabb(half_extents: Vec2) -> Self {
+        Self {
+            shape: ObstacleShape::Aabb { half_extents },
+        }
+    }
+}
+
+/// What a projectile does when it reaches an obstacle.
+/// Configured per spell by `SpellType::obstacle_response`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObstacleResponse {
+    /// Despawn on contact
+    #[default]
+    Stop,
+    /// Fire `ProjectileObstacleHit`; the spell's own system plays the
+    /// explosion and despawns the projectile
+    Explode,
+    /// Pass through (lobbed or spectral projectiles)
+    Pierce,
+}
+
+/// Spell projectile component checked against obstacles.
+/// Implementors are registered with `projectile_obstacle_system` in the spell plugin.
+pub trait ObstacleProjectile: Component {
+    /// Spell the projectile belongs to, which decides its `ObstacleResponse`
+    const SPELL_TYPE: SpellType;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod obstacle_shape_tests {
+        use super::*;
+
+        #[test]
+        fn circle_overlap_uses_combined_radius() {
+            let shape = ObstacleShape::Circle { radius: 1.0 };
+            assert!(shape.overlaps(Vec2::ZERO, Vec2::new(1.4, 0.0), 0.5));
+            assert!(!shape.overlaps(Vec2::ZERO, Vec2::new(1.6, 0.0), 0.5));
+        }
+
+        #[test]
+        fn circle_push_out_moves_to_surface() {
+            let shape = ObstacleShape::Circle { radius: 1.0 };
+            let pushed = shape.push_out(Vec2::ZERO, Vec2::new(0.0, 1.0), 0.5).unwrap();
+            assert!((pushed - Vec2::new(0.0, 1.5)).length() < 0.0001);
+            assert!(shape.push_out(Vec2::ZERO, Vec2::new(0.0, 2.0), 0.5).is_none());
+        }
+
+        #[test]
+        fn aabb_push_out_from_outside_slides_along_edge() {
+            let shape = ObstacleShape::Aabb { half_extents: Vec2::new(1.0, 1.0) };
+            let pushed = shape.push_out(Vec2::ZERO, Vec2::new(1.3, 0.2), 0.5).unwrap();
+            assert!((pushed - Vec2::new(1.5, 0.2)).length() < 0.0001);
+        }
+
+        #[test]
+        fn aabb_push_out_from_inside_uses_nearest_side() {
+            let shape = ObstacleShape::Aabb { half_extents: Vec2::new(6.0, 0.5) };
+            let pushed = shape.push_out(Vec2::ZERO, Vec2::new(2.0, -0.3), 0.5).unwrap();
+            assert!((pushed - Vec2::new(2.0, -1.0)).length() < 0.0001);
+        }
+
+        #[test]
+        fn bounding_radius_encloses_box_corners() {
+            let shape = ObstacleShape::Aabb { half_extents: Vec2::new(3.0, 4.0) };
+            assert_eq!(shape.bounding_radius(), 5.0);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+use crate::collision::components::ObstacleResponse;
+use crate::spell::SpellType;
+
+/// Fired when a spell projectile reaches an obstacle and doesn't pierce it
+#[derive(Message, Debug)]
+pub struct ProjectileObstacleHit {
+    pub projectile: Entity,
+    pub spell_type: SpellType,
+    pub response: ObstacleResponse,
+    /// Projectile position at impact
+    pub position: Vec3,
+}
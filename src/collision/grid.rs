@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+/// Uniform grid bucketing entities by XZ position, for cheap neighbour lookups.
+/// With the cell size at least the largest query radius, every neighbour of a
+/// point lies in its own cell or one of the eight around it.
+#[derive(Debug, Clone)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<usize>>,
+    entries: Vec<(Entity, Vec2)>,
+    /// Corners of the box holding every entry
+    bounds: Option<(Vec2, Vec2)>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(f32::EPSILON),
+            cells: HashMap::new(),
+            entries: Vec::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Empty the grid. Cells used since the last clear keep their allocations
+    /// for the next rebuild; cells that stayed empty are dropped, so the map
+    /// doesn't grow with every cell anything has ever passed through.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.bounds = None;
+        self.cells.retain(|_, bucket| {
+            let used = !bucket.is_empty();
+            bucket.clear();
+            used
+        });
+    }
+
+    /// Replace the contents with `items`
+    pub fn rebuild(&mut self, items: impl IntoIterator<Item = (Entity, Vec2)>) {
+        self.clear();
+        for (entity, position) in items {
+            self.insert(entity, position);
+        }
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2) {
+        let index = self.entries.len();
+        self.entries.push((entity, position));
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(position), max.max(position)),
+            None => (position, position),
+        });
+        self.cells.entry(self.cell_of(position)).or_default().push(index);
+    }
+
+    pub fn cell_of(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+
+    /// Every entry, in insertion order
+    pub fn entries(&self) -> &[(Entity, Vec2)] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries in the 3x3 block of cells around `position`.
+    /// Candidates only: callers still check the actual distance.
+    pub fn neighbors(&self, position: Vec2) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let center = self.cell_of(position);
+        (-1..=1)
+            .flat_map(move |dy| (-1..=1).map(move |dx| center + IVec2::new(dx, dy)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .map(|index| self.entries[*index])
+    }
+
+    /// Entries in the cells overlapping the box from `min` to `max`.
+    /// Candidates only: entries near the box edges may lie outside it.
+    pub fn in_area(&self, min: Vec2, max: Vec2) -> Vec<(Entity, Vec2)> {
+        let low = self.cell_of(min);
+        let high = self.cell_of(max);
+        let span = (high - low + IVec2::ONE).max(IVec2::ZERO);
+        let mut found = Vec::new();
+
+        if span.x as usize * span.y as usize > self.cells.len() {
+            // Box wider than the populated grid: scan the entries instead of the box.
+            // Entries keep insertion order, so results stay deterministic.
+            found.extend(self.entries.iter().copied().filter(|(_, position)| {
+                let cell = self.cell_of(*position);
+                cell.cmpge(low).all() && cell.cmple(high).all()
+            }));
+        } else {
+            for y in low.y..=high.y {
+                for x in low.x..=high.x {
+                    if let Some(bucket) = self.cells.get(&IVec2::new(x, y)) {
+                        found.extend(bucket.iter().map(|index| self.entries[*index]));
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// Entries within `radius` of `center`
+    pub fn within_radius(&self, center: Vec2, radius: f32) -> Vec<(Entity, Vec2)> {
+        let mut found = self.in_area(center - Vec2::splat(radius), center + Vec2::splat(radius));
+        found.retain(|(_, position)| position.distance_squared(center) <= radius * radius);
+        found
+    }
+
+    /// Entries in the cells lying within `radius` of the segment from `start` to `end`.
+    /// Walks the segment row by row instead of covering its whole bounding box,
+    /// so long diagonal queries only touch cells near the line.
+    /// Candidates only: callers still check the actual distance.
+    pub fn along_segment(&self, start: Vec2, end: Vec2, radius: f32) -> Vec<(Entity, Vec2)> {
+        let low_row = self.cell_of(start.min(end) - Vec2::splat(radius)).y;
+        let high_row = self.cell_of(start.max(end) + Vec2::splat(radius)).y;
+        // Inclusive column range touched in each row, starting at `low_row`
+        let spans: Vec<(i32, i32)> = (low_row..=high_row)
+            .map(|row| self.segment_columns(start, end, radius, row))
+            .collect();
+        let visited: usize = spans
+            .iter()
+            .map(|(low, high)| (high - low + 1).max(0) as usize)
+            .sum();
+        let mut found = Vec::new();
+
+        if visited > self.cells.len() {
+            // More cells than are populated: scan the entries instead, as `in_area` does
+            found.extend(self.entries.iter().copied().filter(|(_, position)| {
+                let cell = self.cell_of(*position);
+                if cell.y < low_row || cell.y > high_row {
+                    return false;
+                }
+                let (low, high) = spans[(cell.y - low_row) as usize];
+                cell.x >= low && cell.x <= high
+            }));
+        } else {
+            for (row, (low, high)) in (low_row..).zip(spans) {
+                for x in low..=high {
+                    if let Some(bucket) = self.cells.get(&IVec2::new(x, row)) {
+                        found.extend(bucket.iter().map(|index| self.entries[*index]));
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// Entries within `radius` of the segment from `start` to `end`
+    pub fn near_segment(&self, start: Vec2, end: Vec2, radius: f32) -> Vec<(Entity, Vec2)> {
+        let mut found = self.along_segment(start, end, radius);
+        found.retain(|(_, position)| distance_to_segment(*position, start, end) <= radius);
+        found
+    }
+
+    /// Columns of the cells in `row` that come within `radius` of the segment.
+    /// Returns an empty range (low > high) when the segment misses the row.
+    fn segment_columns(&self, start: Vec2, end: Vec2, radius: f32, row: i32) -> (i32, i32) {
+        // Any point of the row within `radius` of the segment is within `radius`
+        // of the part of the segment crossing this band
+        let band_low = row as f32 * self.cell_size - radius;
+        let band_high = (row + 1) as f32 * self.cell_size + radius;
+        let delta = end - start;
+        let (enter, exit) = if delta.y.abs() < f32::EPSILON {
+            if start.y < band_low || start.y > band_high {
+                return (1, 0);
+            }
+            (0.0, 1.0)
+        } else {
+            let a = (band_low - start.y) / delta.y;
+            let b = (band_high - start.y) / delta.y;
+            (a.min(b).max(0.0), a.max(b).min(1.0))
+        };
+        if enter > exit {
+            return (1, 0);
+        }
+
+        let x_enter = start.x + delta.x * enter;
+        let x_exit = start.x + delta.x * exit;
+        let low = ((x_enter.min(x_exit) - radius) / self.cell_size).floor() as i32;
+        let high = ((x_enter.max(x_exit) + radius) / self.cell_size).floor() as i32;
+        (low, high)
+    }
+
+    /// The `k` entries closest to `position`, closest first.
+    /// Searches outward in growing rings rather than sorting every entry.
+    pub fn nearest_k(&self, position: Vec2, k: usize) -> Vec<(Entity, Vec2)> {
+        let Some((min, max)) = self.bounds else {
+            return Vec::new();
+        };
+        if k == 0 {
+            return Vec::new();
+        }
+
+        // Beyond this radius the search already covers every entry
+        let farthest = position.distance(position.clamp(min, max))
+            + (max - min).length();
+        let mut radius = self.cell_size;
+        loop {
+            let mut found = self.within_radius(position, radius);
+            if found.len() >= k || radius >= farthest {
+                found.sort_by(|a, b| {
+                    a.1.distance_squared(position)
+                        .total_cmp(&b.1.distance_squared(position))
+                });
+                found.truncate(k);
+                return found;
+            }
+            radius *= 2.0;
+        }
+    }
+}
+
+/// Distance from `point` to the closest point on the segment from `start` to `end`
+pub fn distance_to_segment(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared < f32::EPSILON {
+        return point.distance(start);
+    }
+    let along = ((point - start).dot(segment) / length_squared).clamp(0.0, 1.0);
+    point.distance(start + segment * along)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entities(count: usize) -> Vec<Entity> {
+        let mut world = World::new();
+        (0..count).map(|_| world.spawn_empty().id()).collect()
+    }
+
+    #[test]
+    fn neighbors_cover_adjacent_cells_only() {
+        let e = entities(3);
+        let mut grid = SpatialGrid::new(1.0);
+        grid.rebuild([
+            (e[0], Vec2::new(0.5, 0.5)),
+            (e[1], Vec2::new(1.4, -0.2)),
+            (e[2], Vec2::new(5.0, 5.0)),
+        ]);
+
+        let found: Vec<Entity> = grid.neighbors(Vec2::new(0.6, 0.4)).map(|(entity, _)| entity).collect();
+        assert_eq!(found.len(), 2);
+        assert!(found.contains(&e[0]));
+        assert!(found.contains(&e[1]));
+    }
+
+    #[test]
+    fn negative_coordinates_use_floor_cells() {
+        let grid = SpatialGrid::new(2.0);
+        assert_eq!(grid.cell_of(Vec2::new(-0.1, 3.9)), IVec2::new(-1, 1));
+    }
+
+    #[test]
+    fn within_radius_filters_by_distance() {
+        let e = entities(3);
+        let mut grid = SpatialGrid::new(2.0);
+        grid.rebuild([
+            (e[0], Vec2::new(1.0, 0.0)),
+            (e[1], Vec2::new(3.5, 0.0)),
+            (e[2], Vec2::new(-20.0, 4.0)),
+        ]);
+
+        let found: Vec<Entity> = grid.within_radius(Vec2::ZERO, 3.0).iter().map(|(entity, _)| *entity).collect();
+        assert_eq!(found, vec![e[0]]);
+        assert_eq!(grid.within_radius(Vec2::ZERO, 100.0).len(), 3);
+    }
+
+    #[test]
+    fn nearest_k_returns_closest_first() {
+        let e = entities(4);
+        let mut grid = SpatialGrid::new(1.0);
+        grid.rebuild([
+            (e[0], Vec2::new(9.0, 0.0)),
+            (e[1], Vec2::new(0.0, 2.0)),
+            (e[2], Vec2::new(-30.0, -30.0)),
+            (e[3], Vec2::new(0.5, 0.0)),
+        ]);
+
+        let found: Vec<Entity> = grid.nearest_k(Vec2::ZERO, 3).iter().map(|(entity, _)| *entity).collect();
+        assert_eq!(found, vec![e[3], e[1], e[0]]);
+    }
+
+    #[test]
+    fn nearest_k_reaches_distant_entries() {
+        let e = entities(2);
+        let mut grid = SpatialGrid::new(1.0);
+        grid.rebuild([(e[0], Vec2::new(80.0, -60.0)), (e[1], Vec2::new(-90.0, 70.0))]);
+
+        assert_eq!(grid.nearest_k(Vec2::ZERO, 5).len(), 2);
+        assert!(SpatialGrid::new(1.0).nearest_k(Vec2::ZERO, 5).is_empty());
+    }
+
+    #[test]
+    fn near_segment_matches_distance_to_line() {
+        let e = entities(3);
+        let mut grid = SpatialGrid::new(2.0);
+        grid.rebuild([
+            (e[0], Vec2::new(5.0, 0.4)),
+            (e[1], Vec2::new(5.0, 2.0)),
+            (e[2], Vec2::new(11.0, 0.0)),
+        ]);
+
+        let found: Vec<Entity> = grid
+            .near_segment(Vec2::ZERO, Vec2::new(10.0, 0.0), 0.5)
+            .iter()
+            .map(|(entity, _)| *entity)
+            .collect();
+        assert_eq!(found, vec![e[0]]);
+    }
+
+    #[test]
+    fn along_segment_skips_cells_off_a_diagonal() {
+        let e = entities(3);
+        let mut grid = SpatialGrid::new(2.0);
+        grid.rebuild([
+            (e[0], Vec2::new(10.0, 10.3)),
+            (e[1], Vec2::new(18.0, 2.0)),
+            (e[2], Vec2::new(2.0, 18.0)),
+        ]);
+
+        let found: Vec<Entity> = grid
+            .along_segment(Vec2::ZERO, Vec2::new(20.0, 20.0), 0.5)
+            .iter()
+            .map(|(entity, _)| *entity)
+            .collect();
+        assert_eq!(found, vec![e[0]]);
+        // The bounding box of the same segment holds every entry
+        assert_eq!(grid.in_area(Vec2::splat(-0.5), Vec2::splat(20.5)).len(), 3);
+    }
+
+    #[test]
+    fn along_segment_keeps_entries_near_the_ends() {
+        let e = entities(2);
+        let mut grid = SpatialGrid::new(2.0);
+        grid.rebuild([
+            (e[0], Vec2::new(-1.5, -0.5)),
+            (e[1], Vec2::new(6.0, -1.5)),
+        ]);
+
+        let found = grid.along_segment(Vec2::ZERO, Vec2::new(6.0, 0.0), 1.5);
+        assert_eq!(found.len(), 2);
+    }
+
+    #[test]
+    fn distance_to_segment_clamps_to_endpoints() {
+        let start = Vec2::ZERO;
+        let end = Vec2::new(4.0, 0.0);
+        assert_eq!(distance_to_segment(Vec2::new(2.0, 3.0), start, end), 3.0);
+        assert_eq!(distance_to_segment(Vec2::new(7.0, 4.0), start, end), 5.0);
+        assert_eq!(distance_to_segment(Vec2::new(1.0, 1.0), start, start), 2f32.sqrt());
+    }
+
+    #[test]
+    fn rebuild_replaces_previous_contents() {
+        let e = entities(2);
+        let mut grid = SpatialGrid::new(1.0);
+        grid.rebuild([(e[0], Vec2::ZERO)]);
+        grid.rebuild([(e[1], Vec2::new(10.0, 10.0))]);
+
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.neighbors(Vec2::ZERO).count(), 0);
+        assert_eq!(grid.neighbors(Vec2::new(10.0, 10.0)).count(), 1);
+    }
+
+    #[test]
+    fn clear_drops_cells_left_empty_by_the_last_rebuild() {
+        let e = entities(1);
+        let mut grid = SpatialGrid::new(1.0);
+        for x in 0..10 {
+            grid.rebuild([(e[0], Vec2::new(x as f32, 0.0))]);
+        }
+
+        // The latest cell plus the one it moved out of, not all ten
+        assert_eq!(grid.cells.len(), 2);
+        grid.clear();
+        assert_eq!(grid.cells.len(), 1);
+        grid.clear();
+        assert!(grid.cells.is_empty());
+    }
+}
@@ -0,0 +1,13 @@
+pub mod components;
+pub mod events;
+pub mod grid;
+pub mod plugin;
+pub mod spatial;
+pub mod systems;
+
+pub use components::*;
+pub use events::*;
+pub use grid::*;
+pub use plugin::*;
+pub use spatial::*;
+pub use systems::*;
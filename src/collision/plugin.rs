@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+use crate::collision::events::ProjectileObstacleHit;
+use crate::collision::spatial::{rebuild_enemy_spatial_hash, EnemySpatialHash};
+use crate::collision::systems::{enemy_projectile_obstacle_system, resolve_obstacle_collisions};
+use crate::game::sets::GameSet;
+use crate::states::GameState;
+
+/// Obstacle collision for bodies and enemy projectiles, and the enemy spatial hash.
+/// Spell projectiles are registered with `projectile_obstacle_system` by the spell plugin.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<EnemySpatialHash>()
+        .add_message::<ProjectileObstacleHit>()
+        .add_systems(
+            Update,
+            rebuild_enemy_spatial_hash
+                .in_set(GameSet::Spatial)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (resolve_obstacle_collisions, enemy_projectile_obstacle_system)
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collision::components::Obstacle;
+    use crate::enemies::components::Enemy;
+
+    #[test]
+    fn enemies_are_pushed_out_of_obstacles_in_game() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        app.world_mut().spawn((Transform::default(), Obstacle::circle(1.0)));
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.5, 0.0, 0.0),
+                Enemy { speed: 3.0, strength: 10.0 },
+            ))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::InGame);
+        app.update();
+
+        let x = app.world().get::<Transform>(enemy).unwrap().translation.x;
+        assert!(x >= 1.5 - 0.0001);
+    }
+
+    #[test]
+    fn enemy_hash_is_rebuilt_in_game() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(3.0, 0.375, -2.0),
+            Enemy { speed: 3.0, strength: 10.0 },
+        ));
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::InGame);
+        app.update();
+
+        assert_eq!(app.world().resource::<EnemySpatialHash>().len(), 1);
+    }
+}
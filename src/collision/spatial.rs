@@ -0,0 +1,240 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::collision::grid::SpatialGrid;
+use crate::enemies::components::Enemy;
+use crate::movement::components::from_xz;
+
+/// Cell size of the enemy hash; a few enemy widths, so typical spell radii touch only a handful of cells
+pub const ENEMY_HASH_CELL_SIZE: f32 = 4.0;
+
+/// Extra distance searched around every query, covering enemies that moved
+/// since the hash was rebuilt (systems ordered before `GameSet::Spatial`)
+pub const ENEMY_HASH_SLACK: f32 = 1.0;
+
+/// Enemy positions bucketed on the XZ plane.
+/// Rebuilt once per frame in `GameSet::Spatial`; read it through `SpatialEnemies`.
+#[derive(Resource, Debug, Clone, Deref, DerefMut)]
+pub struct EnemySpatialHash(pub SpatialGrid);
+
+impl Default for EnemySpatialHash {
+    fn default() -> Self {
+        Self(SpatialGrid::new(ENEMY_HASH_CELL_SIZE))
+    }
+}
+
+/// Spatial queries over enemies for targeting and collision.
+/// Candidates come from `EnemySpatialHash` and are resolved against the live
+/// enemy query, so despawned enemies are skipped and positions are current.
+/// Without the hash (e.g. in unit tests) every enemy is a candidate.
+#[derive(SystemParam)]
+pub struct SpatialEnemies<'w, 's> {
+    hash: Option<Res<'w, EnemySpatialHash>>,
+    enemies: Query<'w, 's, (Entity, &'static Transform), With<Enemy>>,
+}
+
+impl SpatialEnemies<'_, '_> {
+    /// Current transform of `entity`, if it is still a live enemy
+    pub fn get(&self, entity: Entity) -> Option<&Transform> {
+        self.enemies.get(entity).ok().map(|(_, transform)| transform)
+    }
+
+    /// Enemies that may be within `radius` of `center`: every enemy in range,
+    /// plus possibly some just outside it. Callers keep their own range check.
+    pub fn near(&self, center: Vec2, radius: f32) -> Vec<(Entity, &Transform)> {
+        let pad = Vec2::splat(radius + ENEMY_HASH_SLACK);
+        self.in_area(center - pad, center + pad)
+    }
+
+    /// Enemies that may be within `radius` of the segment from `start` to `end`.
+    /// Like `near`, callers keep their own hit check.
+    /// Only the hash cells near the segment are searched, not its bounding box.
+    pub fn along(&self, start: Vec2, end: Vec2, radius: f32) -> Vec<(Entity, &Transform)> {
+        match &self.hash {
+            Some(hash) => self.resolve(hash.along_segment(start, end, radius + ENEMY_HASH_SLACK)),
+            None => self.enemies.iter().collect(),
+        }
+    }
+
+    /// Closest enemy within `max_distance` of `center`, with its XZ position
+    pub fn nearest(&self, center: Vec2, max_distance: f32) -> Option<(Entity, Vec2)> {
+        self.near(center, max_distance)
+            .into_iter()
+            .map(|(entity, transform)| (entity, from_xz(transform.translation)))
+            .filter(|(_, position)| position.distance(center) <= max_distance)
+            .min_by(|a, b| a.1.distance_squared(center).total_cmp(&b.1.distance_squared(center)))
+    }
+
+    /// The `k` enemies closest to `center` with their XZ positions, closest first
+    pub fn nearest_k(&self, center: Vec2, k: usize) -> Vec<(Entity, Vec2)> {
+        let mut found: Vec<(Entity, Vec2)> = match &self.hash {
+            Some(hash) => hash
+                .nearest_k(center, k)
+                .into_iter()
+                .filter_map(|(entity, _)| self.live(entity))
+                .collect(),
+            None => self
+                .enemies
+                .iter()
+                .map(|(entity, transform)| (entity, from_xz(transform.translation)))
+                .collect(),
+        };
+
+        // Re-rank by live position; without the hash this is the only ranking
+        found.sort_by(|a, b| a.1.distance_squared(center).total_cmp(&b.1.distance_squared(center)));
+        found.truncate(k);
+        found
+    }
+
+    fn live(&self, entity: Entity) -> Option<(Entity, Vec2)> {
+        self.enemies
+            .get(entity)
+            .ok()
+            .map(|(entity, transform)| (entity, from_xz(transform.translation)))
+    }
+
+    fn in_area(&self, min: Vec2, max: Vec2) -> Vec<(Entity, &Transform)> {
+        match &self.hash {
+            Some(hash) => self.resolve(hash.in_area(min, max)),
+            None => self.enemies.iter().collect(),
+        }
+    }
+
+    /// Hash candidates that are still live enemies, with their current transforms
+    fn resolve(&self, candidates: Vec<(Entity, Vec2)>) -> Vec<(Entity, &Transform)> {
+        candidates
+            .into_iter()
+            .filter_map(|(entity, _)| self.enemies.get(entity).ok())
+            .collect()
+    }
+}
+
+/// Rebuilds the enemy hash from this frame's enemy positions.
+pub fn rebuild_enemy_spatial_hash(
+    mut hash: ResMut<EnemySpatialHash>,
+    enemies: Query<(Entity, &Transform), With<Enemy>>,
+) {
+    hash.rebuild(
+        enemies
+            .iter()
+            .map(|(entity, transform)| (entity, from_xz(transform.translation))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::app::App;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn spawn_enemy(app: &mut App, x: f32, z: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_xyz(x, 0.375, z),
+                Enemy { speed: 3.0, strength: 10.0 },
+            ))
+            .id()
+    }
+
+    fn setup_indexed_app(positions: &[(f32, f32)]) -> (App, Vec<Entity>) {
+        let mut app = App::new();
+        app.init_resource::<EnemySpatialHash>();
+        let enemies = positions
+            .iter()
+            .map(|(x, z)| spawn_enemy(&mut app, *x, *z))
+            .collect();
+        let _ = app.world_mut().run_system_once(rebuild_enemy_spatial_hash);
+        (app, enemies)
+    }
+
+    #[test]
+    fn rebuild_indexes_every_enemy() {
+        let (app, _) = setup_indexed_app(&[(0.0, 0.0), (10.0, 5.0), (-40.0, 3.0)]);
+        assert_eq!(app.world().resource::<EnemySpatialHash>().len(), 3);
+    }
+
+    #[test]
+    fn near_skips_distant_enemies() {
+        let (mut app, enemies) = setup_indexed_app(&[(1.0, 0.0), (30.0, 30.0)]);
+
+        let found = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialEnemies| {
+                spatial
+                    .near(Vec2::ZERO, 2.0)
+                    .into_iter()
+                    .map(|(entity, _)| entity)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+
+        assert_eq!(found, vec![enemies[0]]);
+    }
+
+    #[test]
+    fn along_skips_enemies_off_a_diagonal() {
+        let (mut app, enemies) = setup_indexed_app(&[(10.0, 10.0), (30.0, 2.0), (2.0, 30.0)]);
+
+        let found = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialEnemies| {
+                spatial
+                    .along(Vec2::ZERO, Vec2::new(32.0, 32.0), 0.5)
+                    .into_iter()
+                    .map(|(entity, _)| entity)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap();
+
+        assert_eq!(found, vec![enemies[0]]);
+    }
+
+    #[test]
+    fn nearest_k_ranks_by_distance() {
+        let (mut app, enemies) = setup_indexed_app(&[(6.0, 0.0), (0.0, 1.0), (50.0, 0.0), (3.0, 0.0)]);
+
+        let found = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialEnemies| spatial.nearest_k(Vec2::ZERO, 2))
+            .unwrap();
+
+        assert_eq!(
+            found.iter().map(|(entity, _)| *entity).collect::<Vec<_>>(),
+            vec![enemies[1], enemies[3]]
+        );
+    }
+
+    #[test]
+    fn despawned_enemies_are_skipped_until_rebuild() {
+        let (mut app, enemies) = setup_indexed_app(&[(1.0, 0.0), (2.0, 0.0)]);
+        app.world_mut().despawn(enemies[0]);
+
+        let nearest = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialEnemies| spatial.nearest(Vec2::ZERO, 5.0))
+            .unwrap();
+
+        assert_eq!(nearest.map(|(entity, _)| entity), Some(enemies[1]));
+    }
+
+    #[test]
+    fn queries_scan_all_enemies_without_hash() {
+        let mut app = App::new();
+        let enemy = spawn_enemy(&mut app, 0.0, 4.0);
+
+        let found = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialEnemies| {
+                spatial.along(Vec2::ZERO, Vec2::new(10.0, 0.0), 0.5).len()
+            })
+            .unwrap();
+        let nearest = app
+            .world_mut()
+            .run_system_once(|spatial: SpatialEnemies| spatial.nearest(Vec2::ZERO, 5.0))
+            .unwrap();
+
+        // `along` only promises candidates; the exact check is the caller's
+        assert_eq!(found, 1);
+        assert_eq!(nearest.map(|(entity, _)| entity), Some(enemy));
+    }
+}
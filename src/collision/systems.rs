@@ -0,0 +1,339 @@
+use bevy::prelude::*;
+
+use crate::collision::components::*;
+use crate::collision::events::ProjectileObstacleHit;
+use crate::enemies::components::{Enemy, EnemyProjectile};
+use crate::movement::components::from_xz;
+use crate::player::components::Player;
+
+/// How far ahead enemies look for obstacles to steer around
+pub const STEERING_LOOKAHEAD: f32 = 3.0;
+
+/// Points sampled along the lookahead when probing for obstacles
+pub const STEERING_PROBES: usize = 3;
+
+/// Extra clearance enemies keep from obstacles while steering
+pub const STEERING_MARGIN: f32 = 0.3;
+
+/// Bends `direction` around obstacles in the path ahead, keeping its length.
+/// `reach` caps the lookahead (e.g. the distance to the chase target), so
+/// obstacles behind the target are ignored. Obstacles dead ahead are passed
+/// on the left.
+pub fn steer_around_obstacles(
+    position: Vec2,
+    direction: Vec2,
+    body_radius: f32,
+    reach: f32,
+    obstacles: impl IntoIterator<Item = (Vec2, ObstacleShape)>,
+) -> Vec2 {
+    let speed = direction.length();
+    if speed < 0.0001 {
+        return direction;
+    }
+    let forward = direction / speed;
+    let lookahead = reach.min(STEERING_LOOKAHEAD);
+    let clearance = body_radius + STEERING_MARGIN;
+
+    let mut avoidance = Vec2::ZERO;
+    for (center, shape) in obstacles {
+        let blocked_at = (1..=STEERING_PROBES).find(|step| {
+            let probe = position + forward * lookahead * (*step as f32 / STEERING_PROBES as f32);
+            shape.overlaps(center, probe, clearance)
+        });
+        let Some(step) = blocked_at else {
+            continue;
+        };
+
+        let to_obstacle = center - position;
+        let lateral = to_obstacle - forward * to_obstacle.dot(forward);
+        let away = (-lateral).try_normalize().unwrap_or_else(|| forward.perp());
+        // Nearer blockages turn harder
+        let urgency = 1.0 - (step - 1) as f32 / STEERING_PROBES as f32;
+        avoidance += away * urgency * 2.0;
+    }
+
+    (forward + avoidance).normalize_or(forward) * speed
+}
+
+/// Pushes the player and enemies out of any obstacle they walked into.
+#[allow(clippy::type_complexity)]
+pub fn resolve_obstacle_collisions(
+    mut bodies: Query<(&mut Transform, Has<Player>), (Or<(With<Player>, With<Enemy>)>, Without<Obstacle>)>,
+    obstacles: Query<(&Transform, &Obstacle)>,
+) {
+    if obstacles.is_empty() {
+        return;
+    }
+
+    for (mut transform, is_player) in bodies.iter_mut() {
+        let radius = if is_player { PLAYER_BODY_RADIUS } else { ENEMY_BODY_RADIUS };
+        let mut position = from_xz(transform.translation);
+        let mut moved = false;
+
+        for (obstacle_transform, obstacle) in obstacles.iter() {
+            let center = from_xz(obstacle_transform.translation);
+            if let Some(resolved) = obstacle.shape.push_out(center, position, radius) {
+                position = resolved;
+                moved = true;
+            }
+        }
+
+        if moved {
+            transform.translation.x = position.x;
+            transform.translation.z = position.y;
+        }
+    }
+}
+
+/// Stops or explodes spell projectiles of type `T` that reach an obstacle,
+/// according to their spell's `ObstacleResponse`.
+pub fn projectile_obstacle_system<T: ObstacleProjectile>(
+    mut commands: Commands,
+    projectiles: Query<(Entity, &Transform), With<T>>,
+    obstacles: Query<(&Transform, &Obstacle)>,
+    mut hits: MessageWriter<ProjectileObstacleHit>,
+) {
+    let response = T::SPELL_TYPE.obstacle_response();
+    if response == ObstacleResponse::Pierce {
+        return;
+    }
+
+    for (entity, transform) in projectiles.iter() {
+        let position = from_xz(transform.translation);
+        let blocked = obstacles.iter().any(|(obstacle_transform, obstacle)| {
+            obstacle.shape.overlaps(
+                from_xz(obstacle_transform.translation),
+                position,
+                PROJECTILE_OBSTACLE_RADIUS,
+            )
+        });
+        if !blocked {
+            continue;
+        }
+
+        hits.write(ProjectileObstacleHit {
+            projectile: entity,
+            spell_type: T::SPELL_TYPE,
+            response,
+            position: transform.translation,
+        });
+        if response == ObstacleResponse::Stop {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+/// Enemy projectiles are stopped by obstacles, so rocks give cover.
+pub fn enemy_projectile_obstacle_system(
+    mut commands: Commands,
+    projectiles: Query<(Entity, &Transform), With<EnemyProjectile>>,
+    obstacles: Query<(&Transform, &Obstacle)>,
+) {
+    for (entity, transform) in projectiles.iter() {
+        let position = from_xz(transform.translation);
+        if obstacles.iter().any(|(obstacle_transform, obstacle)| {
+            obstacle.shape.overlaps(
+                from_xz(obstacle_transform.translation),
+                position,
+                PROJECTILE_OBSTACLE_RADIUS,
+            )
+        }) {
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spell::SpellType;
+    use crate::spells::fire::fireball::FireballProjectile;
+    use crate::spells::frost::ice_lance::IceLanceProjectile;
+    use crate::spells::frost::ice_shard::IceShardProjectile;
+    use bevy::app::App;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn spawn_rock(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_xyz(position.x, 0.25, position.y),
+                Obstacle::aabb(Vec2::splat(0.5)),
+            ))
+            .id()
+    }
+
+    mod steering_tests {
+        use super::*;
+
+        #[test]
+        fn clear_path_keeps_direction() {
+            let obstacles = [(Vec2::new(0.0, 10.0), ObstacleShape::Circle { radius: 1.0 })];
+            let steered = steer_around_obstacles(Vec2::ZERO, Vec2::X * 2.0, 0.5, 20.0, obstacles);
+            assert_eq!(steered, Vec2::X * 2.0);
+        }
+
+        #[test]
+        fn obstacle_ahead_bends_path_but_keeps_speed() {
+            let obstacles = [(Vec2::new(2.0, 0.3), ObstacleShape::Circle { radius: 1.0 })];
+            let steered = steer_around_obstacles(Vec2::ZERO, Vec2::X, 0.5, 20.0, obstacles);
+            assert!(steered.y < 0.0, "should turn away from the obstacle's side");
+            assert!((steered.length() - 1.0).abs() < 0.0001);
+        }
+
+        #[test]
+        fn obstacle_beyond_target_is_ignored() {
+            let obstacles = [(Vec2::new(2.5, 0.0), ObstacleShape::Circle { radius: 1.0 })];
+            let steered = steer_around_obstacles(Vec2::ZERO, Vec2::X, 0.5, 0.5, obstacles);
+            assert_eq!(steered, Vec2::X);
+        }
+    }
+
+    mod resolve_obstacle_collisions_tests {
+        use super::*;
+
+        #[test]
+        fn player_inside_rock_is_pushed_out() {
+            let mut app = App::new();
+            spawn_rock(&mut app, Vec2::ZERO);
+            let player = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(0.7, 0.0, 0.0),
+                    Player {
+                        speed: 8.0,
+                        regen_rate: 1.0,
+                        pickup_radius: 2.0,
+                        last_movement_direction: Vec3::ZERO,
+                    },
+                ))
+                .id();
+
+            let _ = app.world_mut().run_system_once(resolve_obstacle_collisions);
+
+            let translation = app.world().get::<Transform>(player).unwrap().translation;
+            assert!((translation.x - (0.5 + PLAYER_BODY_RADIUS)).abs() < 0.0001);
+            assert_eq!(translation.z, 0.0);
+        }
+
+        #[test]
+        fn enemy_clear_of_obstacles_is_untouched() {
+            let mut app = App::new();
+            spawn_rock(&mut app, Vec2::ZERO);
+            let enemy = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(5.0, 0.375, 5.0),
+                    Enemy { speed: 3.0, strength: 10.0 },
+                ))
+                .id();
+
+            let _ = app.world_mut().run_system_once(resolve_obstacle_collisions);
+
+            assert_eq!(
+                app.world().get::<Transform>(enemy).unwrap().translation,
+                Vec3::new(5.0, 0.375, 5.0)
+            );
+        }
+    }
+
+    mod projectile_obstacle_tests {
+        use super::*;
+
+        fn setup_app() -> App {
+            let mut app = App::new();
+            app.add_message::<ProjectileObstacleHit>();
+            app
+        }
+
+        fn hit_count(app: &App) -> usize {
+            app.world()
+                .resource::<Messages<ProjectileObstacleHit>>()
+                .iter_current_update_messages()
+                .count()
+        }
+
+        #[test]
+        fn stopping_projectile_is_despawned() {
+            let mut app = setup_app();
+            spawn_rock(&mut app, Vec2::ZERO);
+            let shard = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(0.2, 0.5, 0.0),
+                    IceShardProjectile::new(Vec2::X, 10.0, 1.0, 5.0),
+                ))
+                .id();
+
+            let _ = app
+                .world_mut()
+                .run_system_once(projectile_obstacle_system::<IceShardProjectile>);
+
+            assert_eq!(SpellType::IceShard.obstacle_response(), ObstacleResponse::Stop);
+            assert!(app.world().get_entity(shard).is_err());
+            assert_eq!(hit_count(&app), 1);
+        }
+
+        #[test]
+        fn exploding_projectile_is_left_to_its_spell() {
+            let mut app = setup_app();
+            spawn_rock(&mut app, Vec2::ZERO);
+            let fireball = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(0.0, 1.0, 0.3),
+                    FireballProjectile::new(Vec3::X, 10.0, 5.0, 15.0),
+                ))
+                .id();
+
+            let _ = app
+                .world_mut()
+                .run_system_once(projectile_obstacle_system::<FireballProjectile>);
+
+            assert!(app.world().get_entity(fireball).is_ok());
+            let hit = app
+                .world()
+                .resource::<Messages<ProjectileObstacleHit>>()
+                .iter_current_update_messages()
+                .next()
+                .map(|hit| (hit.projectile, hit.response));
+            assert_eq!(hit, Some((fireball, ObstacleResponse::Explode)));
+        }
+
+        #[test]
+        fn piercing_projectile_ignores_obstacles() {
+            let mut app = setup_app();
+            spawn_rock(&mut app, Vec2::ZERO);
+            let lance = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(0.0, 0.5, 0.0),
+                    IceLanceProjectile::new(Vec2::X, 10.0, 1.0, 5.0),
+                ))
+                .id();
+
+            let _ = app
+                .world_mut()
+                .run_system_once(projectile_obstacle_system::<IceLanceProjectile>);
+
+            assert!(app.world().get_entity(lance).is_ok());
+            assert_eq!(hit_count(&app), 0);
+        }
+
+        #[test]
+        fn enemy_projectile_is_stopped_by_rock() {
+            let mut app = setup_app();
+            spawn_rock(&mut app, Vec2::ZERO);
+            let bolt = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(0.4, 0.8, 0.0),
+                    EnemyProjectile::new(Vec2::X, 10.0, 5.0),
+                ))
+                .id();
+
+            let _ = app.world_mut().run_system_once(enemy_projectile_obstacle_system);
+
+            assert!(app.world().get_entity(bolt).is_err());
+        }
+    }
+}
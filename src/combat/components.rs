@@ -1,4 +1,7 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::element::Element;
 
 /// Health component for entities that can take damage
 #[derive(Component, Debug, Clone, PartialEq)]
@@ -114,6 +117,118 @@ impl Default for Invincibility {
     }
 }
 
+/// Smallest share of a direct hit that gets through armor
+pub const ARMOR_MIN_DAMAGE_FRACTION: f32 = 0.2;
+
+/// Flat damage reduction against direct hits. Damage-over-time ticks ignore it,
+/// and no hit is reduced below `ARMOR_MIN_DAMAGE_FRACTION` of its damage.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Armor(pub f32);
+
+impl Armor {
+    /// Damage left of a direct hit after armor
+    pub fn reduce(&self, damage: f32) -> f32 {
+        (damage - self.0).max(damage * ARMOR_MIN_DAMAGE_FRACTION)
+    }
+}
+
+/// Pool of damage absorbed before health, e.g. an elite's shield.
+/// Applied by `apply_damage_system` after every damage modifier.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct DamageShield {
+    pub remaining: f32,
+}
+
+impl DamageShield {
+    pub fn new(amount: f32) -> Self {
+        Self { remaining: amount.max(0.0) }
+    }
+
+    /// Soak up as much of `damage` as the shield has left.
+    /// Returns the damage that gets through.
+    pub fn absorb(&mut self, damage: f32) -> f32 {
+        let absorbed = damage.min(self.remaining);
+        self.remaining -= absorbed;
+        damage - absorbed
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
+/// Damage multiplier for elements an entity resists
+pub const RESISTED_DAMAGE_MULTIPLIER: f32 = 0.5;
+
+/// Damage multiplier for elements an entity is weak to
+pub const WEAK_DAMAGE_MULTIPLIER: f32 = 1.5;
+
+/// How an entity reacts to a hit of a given element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementalResponse {
+    Normal,
+    Resisted,
+    Weak,
+    Immune,
+}
+
+impl ElementalResponse {
+    /// Classify a damage multiplier
+    pub fn from_multiplier(multiplier: f32) -> Self {
+        if multiplier <= 0.0 {
+            ElementalResponse::Immune
+        } else if multiplier < 1.0 {
+            ElementalResponse::Resisted
+        } else if multiplier > 1.0 {
+            ElementalResponse::Weak
+        } else {
+            ElementalResponse::Normal
+        }
+    }
+}
+
+/// Per-element damage multipliers applied by `apply_damage_system`.
+/// Elements without an entry (and non-elemental damage) deal normal damage.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ElementalResistances {
+    multipliers: HashMap<Element, f32>,
+}
+
+impl ElementalResistances {
+    /// Set the damage multiplier for an element
+    pub fn with(mut self, element: Element, multiplier: f32) -> Self {
+        self.multipliers.insert(element, multiplier.max(0.0));
+        self
+    }
+
+    /// Take reduced damage from `element`
+    pub fn resist(self, element: Element) -> Self {
+        self.with(element, RESISTED_DAMAGE_MULTIPLIER)
+    }
+
+    /// Take increased damage from `element`
+    pub fn weak_to(self, element: Element) -> Self {
+        self.with(element, WEAK_DAMAGE_MULTIPLIER)
+    }
+
+    /// Take no damage from `element`
+    pub fn immune_to(self, element: Element) -> Self {
+        self.with(element, 0.0)
+    }
+
+    /// Damage multiplier for a hit of the given element
+    pub fn multiplier(&self, element: Option<Element>) -> f32 {
+        element
+            .and_then(|element| self.multipliers.get(&element).copied())
+            .unwrap_or(1.0)
+    }
+
+    /// How a hit of the given element is received
+    pub fn response(&self, element: Option<Element>) -> ElementalResponse {
+        ElementalResponse::from_multiplier(self.multiplier(element))
+    }
+}
+
 /// Visual flash effect when entity takes damage
 /// When applied, the entity's material is temporarily changed to a bright white emissive
 /// to provide visual feedback that damage was dealt
@@ -152,6 +267,52 @@ mod tests {
     use super::*;
     use std::time::Duration;
 
+    mod elemental_resistances_tests {
+        use super::*;
+
+        #[test]
+        fn unlisted_and_non_elemental_damage_is_normal() {
+            let resistances = ElementalResistances::default().resist(Element::Fire);
+            assert_eq!(resistances.multiplier(Some(Element::Frost)), 1.0);
+            assert_eq!(resistances.multiplier(None), 1.0);
+            assert_eq!(resistances.response(None), ElementalResponse::Normal);
+        }
+
+        #[test]
+        fn builders_set_multipliers() {
+            let resistances = ElementalResistances::default()
+                .resist(Element::Fire)
+                .weak_to(Element::Frost)
+                .immune_to(Element::Poison);
+
+            assert_eq!(resistances.multiplier(Some(Element::Fire)), RESISTED_DAMAGE_MULTIPLIER);
+            assert_eq!(resistances.multiplier(Some(Element::Frost)), WEAK_DAMAGE_MULTIPLIER);
+            assert_eq!(resistances.multiplier(Some(Element::Poison)), 0.0);
+        }
+
+        #[test]
+        fn later_entries_override_earlier_ones() {
+            let resistances = ElementalResistances::default()
+                .immune_to(Element::Dark)
+                .weak_to(Element::Dark);
+            assert_eq!(resistances.response(Some(Element::Dark)), ElementalResponse::Weak);
+        }
+
+        #[test]
+        fn negative_multipliers_clamp_to_immune() {
+            let resistances = ElementalResistances::default().with(Element::Light, -1.0);
+            assert_eq!(resistances.multiplier(Some(Element::Light)), 0.0);
+        }
+
+        #[test]
+        fn response_classifies_multipliers() {
+            assert_eq!(ElementalResponse::from_multiplier(0.0), ElementalResponse::Immune);
+            assert_eq!(ElementalResponse::from_multiplier(0.5), ElementalResponse::Resisted);
+            assert_eq!(ElementalResponse::from_multiplier(1.0), ElementalResponse::Normal);
+            assert_eq!(ElementalResponse::from_multiplier(1.5), ElementalResponse::Weak);
+        }
+    }
+
     mod health_tests {
         use super::*;
 
@@ -256,6 +417,35 @@ mod tests {
         }
     }
 
+    mod armor_tests {
+        use super::*;
+
+        #[test]
+        fn test_armor_subtracts_flat_damage() {
+            assert_eq!(Armor(4.0).reduce(20.0), 16.0);
+            assert_eq!(Armor(0.0).reduce(20.0), 20.0);
+        }
+
+        #[test]
+        fn test_armor_keeps_minimum_fraction() {
+            assert_eq!(Armor(10.0).reduce(5.0), 5.0 * ARMOR_MIN_DAMAGE_FRACTION);
+            assert_eq!(Armor(100.0).reduce(0.0), 0.0);
+        }
+    }
+
+    mod damage_shield_tests {
+        use super::*;
+
+        #[test]
+        fn test_shield_absorbs_until_broken() {
+            let mut shield = DamageShield::new(30.0);
+            assert_eq!(shield.absorb(20.0), 0.0);
+            assert_eq!(shield.absorb(25.0), 15.0);
+            assert!(shield.is_broken());
+            assert_eq!(shield.absorb(10.0), 10.0);
+        }
+    }
+
     mod invincibility_tests {
         use super::*;
 
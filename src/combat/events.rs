@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::element::Element;
+use crate::spell::SpellType;
 
 /// Type of entity that died (for death handling)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -9,6 +10,16 @@ pub enum EntityType {
     Projectile,
 }
 
+/// Whether a hit lands once or is one tick of a lingering effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DamageKind {
+    /// Single hit; can crit and is reduced by armor
+    #[default]
+    Direct,
+    /// Tick of a burn, zone or aura; never crits and ignores armor
+    OverTime,
+}
+
 /// Message fired when an entity takes damage
 #[derive(Message, Debug, Clone)]
 pub struct DamageEvent {
@@ -20,6 +31,12 @@ pub struct DamageEvent {
     pub source: Option<Entity>,
     /// Element type of the damage (for debuff application)
     pub element: Option<Element>,
+    /// Name of the element reaction that dealt this damage, if any
+    pub reaction: Option<&'static str>,
+    /// Direct hit or damage-over-time tick
+    pub kind: DamageKind,
+    /// Spell that caused this damage, if any
+    pub spell: Option<SpellType>,
 }
 
 impl DamageEvent {
@@ -29,6 +46,9 @@ impl DamageEvent {
             amount,
             source: None,
             element: None,
+            reaction: None,
+            kind: DamageKind::Direct,
+            spell: None,
         }
     }
 
@@ -38,6 +58,9 @@ impl DamageEvent {
             amount,
             source: Some(source),
             element: None,
+            reaction: None,
+            kind: DamageKind::Direct,
+            spell: None,
         }
     }
 
@@ -47,6 +70,9 @@ impl DamageEvent {
             amount,
             source: None,
             element: Some(element),
+            reaction: None,
+            kind: DamageKind::Direct,
+            spell: None,
         }
     }
 
@@ -61,9 +87,40 @@ impl DamageEvent {
             amount,
             source: Some(source),
             element: Some(element),
+            reaction: None,
+            kind: DamageKind::Direct,
+            spell: None,
         }
     }
 
+    /// Tag this damage as dealt by an element reaction
+    pub fn as_reaction(mut self, name: &'static str) -> Self {
+        self.reaction = Some(name);
+        self
+    }
+
+    /// Tag this damage as a damage-over-time tick
+    pub fn over_time(mut self) -> Self {
+        self.kind = DamageKind::OverTime;
+        self
+    }
+
+    /// Attribute this damage to the spell that caused it
+    pub fn by_spell(mut self, spell: impl Into<Option<SpellType>>) -> Self {
+        self.spell = spell.into();
+        self
+    }
+
+    /// Check if this damage is a damage-over-time tick
+    pub fn is_over_time(&self) -> bool {
+        self.kind == DamageKind::OverTime
+    }
+
+    /// Check if this damage came from an element reaction
+    pub fn is_reaction(&self) -> bool {
+        self.reaction.is_some()
+    }
+
     /// Check if this damage is of the specified element type
     pub fn is_element(&self, element: Element) -> bool {
         self.element == Some(element)
@@ -75,6 +132,32 @@ impl DamageEvent {
     }
 }
 
+/// Message fired once a `DamageEvent` has been resolved against its target,
+/// carrying the damage actually taken after crits and modifiers
+#[derive(Message, Debug, Clone)]
+pub struct DamageDealt {
+    /// The entity that took damage
+    pub target: Entity,
+    /// Final damage after crits, debuffs, resistances and armor
+    pub amount: f32,
+    /// Damage carried by the original `DamageEvent`
+    pub base_amount: f32,
+    /// Source of the damage (if any)
+    pub source: Option<Entity>,
+    /// Element type of the damage
+    pub element: Option<Element>,
+    /// Direct hit or damage-over-time tick
+    pub kind: DamageKind,
+    /// Whether the hit was a critical hit
+    pub critical: bool,
+    /// Whether this hit took the target's last health
+    pub killing_blow: bool,
+    /// Name of the element reaction that dealt this damage, if any
+    pub reaction: Option<&'static str>,
+    /// Spell that caused this damage, if any
+    pub spell: Option<SpellType>,
+}
+
 /// Message fired when an entity dies
 #[derive(Message, Debug, Clone)]
 pub struct DeathEvent {
@@ -177,6 +260,69 @@ mod tests {
             let no_element_event = DamageEvent::new(target, 25.0);
             assert!(!no_element_event.is_poison());
         }
+
+        #[test]
+        fn test_damage_event_as_reaction() {
+            let mut world = World::new();
+            let target = world.spawn_empty().id();
+
+            let event = DamageEvent::with_element(target, 25.0, Element::Fire);
+            assert!(!event.is_reaction());
+
+            let event = event.as_reaction("Shatter");
+            assert!(event.is_reaction());
+            assert_eq!(event.reaction, Some("Shatter"));
+            assert_eq!(event.element, Some(Element::Fire));
+        }
+
+        #[test]
+        fn test_damage_event_defaults_to_direct() {
+            let mut world = World::new();
+            let target = world.spawn_empty().id();
+            let source = world.spawn_empty().id();
+
+            assert_eq!(DamageEvent::new(target, 1.0).kind, DamageKind::Direct);
+            assert_eq!(DamageEvent::with_source(target, 1.0, source).kind, DamageKind::Direct);
+            assert_eq!(
+                DamageEvent::with_element(target, 1.0, Element::Fire).kind,
+                DamageKind::Direct
+            );
+            assert_eq!(
+                DamageEvent::with_source_and_element(target, 1.0, source, Element::Fire).kind,
+                DamageKind::Direct
+            );
+        }
+
+        #[test]
+        fn test_damage_event_over_time() {
+            let mut world = World::new();
+            let target = world.spawn_empty().id();
+
+            let event = DamageEvent::with_element(target, 5.0, Element::Poison);
+            assert!(!event.is_over_time());
+
+            let event = event.over_time();
+            assert!(event.is_over_time());
+            assert_eq!(event.kind, DamageKind::OverTime);
+            assert_eq!(event.amount, 5.0);
+            assert!(event.is_poison());
+        }
+
+        #[test]
+        fn test_damage_event_by_spell() {
+            let mut world = World::new();
+            let target = world.spawn_empty().id();
+
+            let event = DamageEvent::new(target, 5.0);
+            assert!(event.spell.is_none());
+
+            let event = event.by_spell(SpellType::Fireball).over_time();
+            assert_eq!(event.spell, Some(SpellType::Fireball));
+            assert!(event.is_over_time());
+
+            let event = event.by_spell(None);
+            assert!(event.spell.is_none());
+        }
     }
 
     mod death_event_tests {
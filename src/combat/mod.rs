@@ -1,10 +1,18 @@
 pub mod components;
 pub mod events;
+pub mod pipeline;
 pub mod plugin;
 pub mod systems;
 
-pub use components::{Damage, DamageFlash, Health, Hitbox, Invincibility};
-pub use events::{DamageEvent, DeathEvent, EntityType};
+pub use components::{
+    Armor, Damage, DamageFlash, DamageShield, ElementalResistances, ElementalResponse, Health, Hitbox,
+    Invincibility,
+};
+pub use events::{DamageDealt, DamageEvent, DamageKind, DeathEvent, EntityType};
+pub use pipeline::{
+    update_spell_crit_stats_system, CritStats, DamageModifier, DamageModifierAppExt,
+    DamageModifierRegistry, DamageStage, SpellCritStats,
+};
 pub use plugin::{plugin, CombatSets};
 pub use systems::{
     apply_damage_flash_system, apply_damage_system, check_death_system, handle_enemy_death_system,
@@ -0,0 +1,442 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use bevy::ecs::world::EntityRef;
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::{Armor, ElementalResistances};
+use super::events::DamageEvent;
+use crate::element::Element;
+use crate::inventory::SpellList;
+use crate::spell::{Spell, SpellType};
+use crate::spells::dark::nightfall::InNightfallZone;
+use crate::spells::fire::cinder_shot::WeakenedDebuff;
+use crate::spells::light::sanctify::SanctifiedDebuff;
+use crate::spells::poison::corrode::CorrodedDebuff;
+
+/// Order in which damage modifiers contribute to a hit.
+/// Modifiers of an earlier stage see the damage before later ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DamageStage {
+    /// Debuffs and zones that make the target take more damage
+    Vulnerability,
+    /// Elemental resistances, weaknesses and immunities
+    Resistance,
+    /// Flat reductions such as armor
+    Mitigation,
+}
+
+/// Component on a damage target that changes the damage it takes.
+/// Register it with `DamageModifierAppExt::register_damage_modifier`.
+pub trait DamageModifier: Component {
+    /// Stage of the pipeline this modifier runs in
+    const STAGE: DamageStage;
+
+    /// Damage after this modifier, given the damage so far
+    fn modify(&self, hit: &DamageEvent, damage: f32) -> f32;
+}
+
+impl DamageModifier for WeakenedDebuff {
+    const STAGE: DamageStage = DamageStage::Vulnerability;
+
+    fn modify(&self, _hit: &DamageEvent, damage: f32) -> f32 {
+        damage * self.damage_multiplier
+    }
+}
+
+impl DamageModifier for SanctifiedDebuff {
+    const STAGE: DamageStage = DamageStage::Vulnerability;
+
+    fn modify(&self, _hit: &DamageEvent, damage: f32) -> f32 {
+        damage * self.damage_multiplier
+    }
+}
+
+impl DamageModifier for CorrodedDebuff {
+    const STAGE: DamageStage = DamageStage::Vulnerability;
+
+    fn modify(&self, _hit: &DamageEvent, damage: f32) -> f32 {
+        damage * self.damage_multiplier
+    }
+}
+
+impl DamageModifier for InNightfallZone {
+    const STAGE: DamageStage = DamageStage::Vulnerability;
+
+    fn modify(&self, hit: &DamageEvent, damage: f32) -> f32 {
+        if hit.is_element(Element::Dark) {
+            damage * self.dark_damage_multiplier
+        } else {
+            damage
+        }
+    }
+}
+
+impl DamageModifier for ElementalResistances {
+    const STAGE: DamageStage = DamageStage::Resistance;
+
+    fn modify(&self, hit: &DamageEvent, damage: f32) -> f32 {
+        damage * self.multiplier(hit.element)
+    }
+}
+
+impl DamageModifier for Armor {
+    const STAGE: DamageStage = DamageStage::Mitigation;
+
+    fn modify(&self, hit: &DamageEvent, damage: f32) -> f32 {
+        if hit.is_over_time() {
+            damage
+        } else {
+            self.reduce(damage)
+        }
+    }
+}
+
+type ModifierFn = fn(&EntityRef, &DamageEvent, f32) -> f32;
+
+fn apply_modifier<M: DamageModifier>(target: &EntityRef, hit: &DamageEvent, damage: f32) -> f32 {
+    match target.get::<M>() {
+        Some(modifier) => modifier.modify(hit, damage),
+        None => damage,
+    }
+}
+
+struct ModifierEntry {
+    stage: DamageStage,
+    type_id: TypeId,
+    apply: ModifierFn,
+}
+
+/// Ordered list of damage modifier components consulted for every hit.
+/// The default registry holds the built-in debuffs, resistances and armor.
+#[derive(Resource)]
+pub struct DamageModifierRegistry {
+    entries: Vec<ModifierEntry>,
+}
+
+impl Default for DamageModifierRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register::<WeakenedDebuff>();
+        registry.register::<SanctifiedDebuff>();
+        registry.register::<CorrodedDebuff>();
+        registry.register::<InNightfallZone>();
+        registry.register::<ElementalResistances>();
+        registry.register::<Armor>();
+        registry
+    }
+}
+
+impl DamageModifierRegistry {
+    /// Registry without any modifiers
+    pub fn empty() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Add a modifier after the others of its stage.
+    /// Returns false if it was already registered.
+    pub fn register<M: DamageModifier>(&mut self) -> bool {
+        if self.contains::<M>() {
+            return false;
+        }
+        let index = self.entries.partition_point(|entry| entry.stage <= M::STAGE);
+        self.entries.insert(
+            index,
+            ModifierEntry {
+                stage: M::STAGE,
+                type_id: TypeId::of::<M>(),
+                apply: apply_modifier::<M>,
+            },
+        );
+        true
+    }
+
+    pub fn contains<M: DamageModifier>(&self) -> bool {
+        let type_id = TypeId::of::<M>();
+        self.entries.iter().any(|entry| entry.type_id == type_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Stages of the registered modifiers, in the order they run
+    pub fn stages(&self) -> impl Iterator<Item = DamageStage> + '_ {
+        self.entries.iter().map(|entry| entry.stage)
+    }
+
+    /// Run `damage` through every modifier the target carries
+    pub fn resolve(&self, target: &EntityRef, hit: &DamageEvent, damage: f32) -> f32 {
+        self.entries
+            .iter()
+            .fold(damage, |damage, entry| (entry.apply)(target, hit, damage))
+    }
+}
+
+/// Extension trait so plugins can add their own damage modifiers on the App.
+pub trait DamageModifierAppExt {
+    /// Register a damage modifier component with the damage pipeline
+    fn register_damage_modifier<M: DamageModifier>(&mut self) -> &mut Self;
+}
+
+impl DamageModifierAppExt for App {
+    fn register_damage_modifier<M: DamageModifier>(&mut self) -> &mut Self {
+        self.init_resource::<DamageModifierRegistry>();
+        self.world_mut()
+            .resource_mut::<DamageModifierRegistry>()
+            .register::<M>();
+        self
+    }
+}
+
+/// Critical hit chance and damage multiplier of one spell
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CritStats {
+    /// Chance (0-1) for a direct hit to be critical
+    pub chance: f32,
+    /// Damage multiplier of a critical hit
+    pub multiplier: f32,
+}
+
+impl CritStats {
+    pub fn for_spell(spell: &Spell) -> Self {
+        Self {
+            chance: spell.crit_chance(),
+            multiplier: spell.crit_multiplier(),
+        }
+    }
+
+    /// Roll a hit for a crit, returning the damage multiplier to apply
+    pub fn roll(&self, rng: &mut impl Rng) -> Option<f32> {
+        (self.chance > 0.0 && rng.gen::<f32>() < self.chance).then_some(self.multiplier)
+    }
+}
+
+/// Critical hit stats of the equipped spells, used for their direct hits on enemies.
+/// Hits of spells that are no longer equipped (e.g. lingering effects) never crit.
+/// Kept in sync with the `SpellList` by `update_spell_crit_stats_system`.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct SpellCritStats {
+    by_spell: HashMap<SpellType, CritStats>,
+}
+
+impl SpellCritStats {
+    pub fn from_spell_list(spell_list: &SpellList) -> Self {
+        Self {
+            by_spell: spell_list
+                .iter_spells()
+                .map(|(_, spell)| (spell.spell_type, CritStats::for_spell(spell)))
+                .collect(),
+        }
+    }
+
+    /// Crit stats of an equipped spell
+    pub fn get(&self, spell: SpellType) -> Option<CritStats> {
+        self.by_spell.get(&spell).copied()
+    }
+
+    /// Set the crit stats of a spell
+    pub fn insert(&mut self, spell: SpellType, stats: CritStats) {
+        self.by_spell.insert(spell, stats);
+    }
+}
+
+/// Recomputes `SpellCritStats` when the equipped spells change
+pub fn update_spell_crit_stats_system(
+    spell_list: Option<Res<SpellList>>,
+    mut crit_stats: ResMut<SpellCritStats>,
+) {
+    let Some(spell_list) = spell_list else {
+        return;
+    };
+    if spell_list.is_changed() {
+        *crit_stats = SpellCritStats::from_spell_list(&spell_list);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::resources::GameRng;
+    use crate::spell::{Spell, SpellType};
+
+    /// Doubles every hit; registered by tests only
+    #[derive(Component)]
+    struct DoubleDamage;
+
+    impl DamageModifier for DoubleDamage {
+        const STAGE: DamageStage = DamageStage::Vulnerability;
+
+        fn modify(&self, _hit: &DamageEvent, damage: f32) -> f32 {
+            damage * 2.0
+        }
+    }
+
+    fn resolve(world: &mut World, registry: &DamageModifierRegistry, hit: &DamageEvent) -> f32 {
+        let target = world.entity(hit.target);
+        registry.resolve(&target, hit, hit.amount)
+    }
+
+    #[test]
+    fn default_registry_runs_stages_in_order() {
+        let registry = DamageModifierRegistry::default();
+        assert_eq!(registry.len(), 6);
+        let stages: Vec<_> = registry.stages().collect();
+        let mut sorted = stages.clone();
+        sorted.sort();
+        assert_eq!(stages, sorted);
+        assert_eq!(stages.last(), Some(&DamageStage::Mitigation));
+    }
+
+    #[test]
+    fn register_skips_duplicates() {
+        let mut registry = DamageModifierRegistry::default();
+        assert!(!registry.register::<Armor>());
+        assert_eq!(registry.len(), 6);
+    }
+
+    #[test]
+    fn registered_modifier_runs_before_later_stages() {
+        let mut world = World::new();
+        let target = world.spawn((DoubleDamage, Armor(4.0))).id();
+
+        let mut registry = DamageModifierRegistry::default();
+        assert!(registry.register::<DoubleDamage>());
+
+        // Doubled first, then armor: 10 * 2 - 4
+        let hit = DamageEvent::new(target, 10.0);
+        assert_eq!(resolve(&mut world, &registry, &hit), 16.0);
+    }
+
+    #[test]
+    fn empty_registry_leaves_damage_unchanged() {
+        let mut world = World::new();
+        let target = world.spawn(Armor(4.0)).id();
+
+        let registry = DamageModifierRegistry::empty();
+        assert!(registry.is_empty());
+        let hit = DamageEvent::new(target, 10.0);
+        assert_eq!(resolve(&mut world, &registry, &hit), 10.0);
+    }
+
+    #[test]
+    fn armor_ignores_damage_over_time() {
+        let mut world = World::new();
+        let target = world.spawn(Armor(4.0)).id();
+        let registry = DamageModifierRegistry::default();
+
+        let direct = DamageEvent::new(target, 10.0);
+        assert_eq!(resolve(&mut world, &registry, &direct), 6.0);
+
+        let tick = DamageEvent::new(target, 10.0).over_time();
+        assert_eq!(resolve(&mut world, &registry, &tick), 10.0);
+    }
+
+    #[test]
+    fn armor_applies_after_resistances() {
+        let mut world = World::new();
+        let target = world
+            .spawn((
+                ElementalResistances::default().resist(Element::Fire),
+                Armor(4.0),
+            ))
+            .id();
+        let registry = DamageModifierRegistry::default();
+
+        // Halved to 10, then armor: 10 - 4
+        let hit = DamageEvent::with_element(target, 20.0, Element::Fire);
+        assert_eq!(resolve(&mut world, &registry, &hit), 6.0);
+    }
+
+    #[test]
+    fn nightfall_only_amplifies_dark_damage() {
+        let mut world = World::new();
+        let target = world
+            .spawn(InNightfallZone {
+                dark_damage_multiplier: 1.5,
+            })
+            .id();
+        let registry = DamageModifierRegistry::default();
+
+        let dark = DamageEvent::with_element(target, 10.0, Element::Dark);
+        assert_eq!(resolve(&mut world, &registry, &dark), 15.0);
+
+        let fire = DamageEvent::with_element(target, 10.0, Element::Fire);
+        assert_eq!(resolve(&mut world, &registry, &fire), 10.0);
+    }
+
+    #[test]
+    fn app_extension_registers_modifier() {
+        let mut app = App::new();
+        app.register_damage_modifier::<DoubleDamage>();
+
+        let registry = app.world().resource::<DamageModifierRegistry>();
+        assert!(registry.contains::<DoubleDamage>());
+        assert!(registry.contains::<Armor>());
+        assert_eq!(registry.len(), 7);
+    }
+
+    #[test]
+    fn zero_chance_never_crits() {
+        let stats = CritStats {
+            chance: 0.0,
+            multiplier: 2.0,
+        };
+        let mut rng = GameRng::from_seed(1);
+        assert!((0..100).all(|_| stats.roll(&mut rng).is_none()));
+    }
+
+    #[test]
+    fn guaranteed_crit_returns_multiplier() {
+        let stats = CritStats {
+            chance: 1.0,
+            multiplier: 2.0,
+        };
+        let mut rng = GameRng::from_seed(7);
+        assert_eq!(stats.roll(&mut rng), Some(2.0));
+    }
+
+    #[test]
+    fn crit_stats_are_per_equipped_spell() {
+        let mut spell_list = SpellList::default();
+        assert_eq!(SpellCritStats::from_spell_list(&spell_list), SpellCritStats::default());
+
+        let mut spark = Spell::new(SpellType::Spark);
+        spark.level = 5;
+        let expected = CritStats::for_spell(&spark);
+        spell_list.equip(Spell::new(SpellType::Fireball));
+        spell_list.equip(spark);
+
+        let stats = SpellCritStats::from_spell_list(&spell_list);
+        assert_eq!(stats.get(SpellType::Spark), Some(expected));
+        assert_eq!(
+            stats.get(SpellType::Fireball).map(|crit| crit.chance),
+            Some(SpellType::Fireball.crit_chance())
+        );
+        assert_eq!(stats.get(SpellType::IceShard), None);
+    }
+
+    #[test]
+    fn crit_stats_follow_spell_list_changes() {
+        let mut app = App::new();
+        app.init_resource::<SpellList>()
+            .init_resource::<SpellCritStats>()
+            .add_systems(Update, update_spell_crit_stats_system);
+        app.update();
+        assert_eq!(*app.world().resource::<SpellCritStats>(), SpellCritStats::default());
+
+        app.world_mut()
+            .resource_mut::<SpellList>()
+            .equip(Spell::new(SpellType::Spark));
+        app.update();
+
+        let stats = app.world().resource::<SpellCritStats>().get(SpellType::Spark).unwrap();
+        assert_eq!(stats.chance, SpellType::Spark.crit_chance());
+        assert_eq!(stats.multiplier, SpellType::Spark.crit_multiplier());
+    }
+}
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 
-use super::events::{DamageEvent, DeathEvent};
+use super::events::{DamageDealt, DamageEvent, DeathEvent};
+use super::pipeline::{update_spell_crit_stats_system, DamageModifierRegistry, SpellCritStats};
 use super::systems::{
     apply_damage_flash_system, apply_damage_system, check_death_system, handle_enemy_death_system,
     tick_invincibility_system, update_damage_flash_system,
@@ -23,8 +24,11 @@ pub enum CombatSets {
 /// Combat plugin providing unified damage and death handling
 pub fn plugin(app: &mut App) {
     app.add_message::<DamageEvent>()
+        .add_message::<DamageDealt>()
         .add_message::<DeathEvent>()
         .add_message::<EnemyDeathEvent>()
+        .init_resource::<DamageModifierRegistry>()
+        .init_resource::<SpellCritStats>()
         .configure_sets(
             Update,
             (CombatSets::Damage, CombatSets::Death, CombatSets::Cleanup)
@@ -33,7 +37,8 @@ pub fn plugin(app: &mut App) {
         )
         .add_systems(
             Update,
-            apply_damage_system
+            (update_spell_crit_stats_system, apply_damage_system)
+                .chain()
                 .in_set(CombatSets::Damage)
                 .run_if(in_state(GameState::InGame)),
         )
@@ -81,6 +86,9 @@ mod tests {
         ));
 
         // If we get here without panicking, messages are registered
+        assert!(app.world().get_resource::<Messages<DamageDealt>>().is_some());
+        assert!(app.world().get_resource::<DamageModifierRegistry>().is_some());
+        assert!(app.world().get_resource::<SpellCritStats>().is_some());
     }
 
     #[test]
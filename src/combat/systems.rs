@@ -1,17 +1,14 @@
+use bevy::ecs::world::EntityRef;
 use bevy::prelude::*;
 
-use super::components::{DamageFlash, Health, Invincibility};
-use super::events::{DamageEvent, DeathEvent, EntityType};
-use crate::element::Element;
-use crate::enemies::components::Enemy;
+use super::components::{DamageFlash, DamageShield, Health, Invincibility};
+use super::events::{DamageDealt, DamageEvent, DeathEvent, EntityType};
+use super::pipeline::{DamageModifierRegistry, SpellCritStats};
+use crate::enemies::components::{EliteAffixes, Enemy};
 use crate::game::components::Level;
 use crate::game::events::EnemyDeathEvent;
-use crate::game::resources::DamageFlashMaterial;
+use crate::game::resources::{DamageFlashMaterial, GameRng};
 use crate::score::Score;
-use crate::spells::dark::nightfall::InNightfallZone;
-use crate::spells::fire::cinder_shot::WeakenedDebuff;
-use crate::spells::light::sanctify::SanctifiedDebuff;
-use crate::spells::poison::corrode::CorrodedDebuff;
 
 /// Marker component indicating an entity should have death checked
 /// Entities with Health and this component will be checked for death
@@ -19,53 +16,81 @@ use crate::spells::poison::corrode::CorrodedDebuff;
 pub struct CheckDeath;
 
 /// System to apply damage from DamageEvents to entities with Health.
-/// Also applies damage multipliers from debuffs like WeakenedDebuff, SanctifiedDebuff, CorrodedDebuff,
-/// and zone effects like InNightfallZone (for Dark element damage).
-#[allow(clippy::type_complexity)]
+/// Direct spell hits on enemies may crit with the stats of the equipped spell
+/// (`SpellCritStats`), then the damage runs through every modifier in the
+/// `DamageModifierRegistry` in stage order: debuffs and zones, elemental
+/// resistances, then armor. A `DamageShield` soaks up what it can of the result,
+/// and the damage that reaches health is reported as a `DamageDealt` message.
 pub fn apply_damage_system(
     mut messages: MessageReader<DamageEvent>,
-    mut query: Query<(
-        &mut Health,
-        Option<&Invincibility>,
-        Option<&WeakenedDebuff>,
-        Option<&SanctifiedDebuff>,
-        Option<&CorrodedDebuff>,
-        Option<&InNightfallZone>,
-    )>,
+    mut targets: ParamSet<(Query<EntityRef>, Query<(&mut Health, Option<&mut DamageShield>)>)>,
+    registry: Option<Res<DamageModifierRegistry>>,
+    crit_stats: Option<Res<SpellCritStats>>,
+    mut rng: Option<ResMut<GameRng>>,
+    mut dealt: Option<MessageWriter<DamageDealt>>,
 ) {
+    let fallback;
+    let registry = match registry.as_deref() {
+        Some(registry) => registry,
+        None => {
+            fallback = DamageModifierRegistry::default();
+            &fallback
+        }
+    };
+
     for event in messages.read() {
-        if let Ok((mut health, invincibility, weakened, sanctified, corroded, in_nightfall)) = query.get_mut(event.target) {
-            // Skip if invincible
-            if invincibility.is_some() {
+        let (mut final_damage, critical) = {
+            let entities = targets.p0();
+            let Ok(target) = entities.get(event.target) else {
+                continue;
+            };
+            // Skip if invincible or unable to take damage
+            if !target.contains::<Health>() || target.contains::<Invincibility>() {
                 continue;
             }
 
-            // Calculate final damage with debuff multipliers
-            let mut final_damage = event.amount;
-
-            // Apply weakened debuff multiplier
-            if let Some(weakened) = weakened {
-                final_damage *= weakened.damage_multiplier;
-            }
-
-            // Apply sanctified debuff multiplier (from Sanctify/Consecration spell)
-            if let Some(sanctified) = sanctified {
-                final_damage *= sanctified.damage_multiplier;
+            let mut damage = event.amount;
+            let mut critical = false;
+            let crit = event
+                .spell
+                .filter(|_| {
+                    target.contains::<Enemy>() && !event.is_over_time() && !event.is_reaction()
+                })
+                .zip(crit_stats.as_deref())
+                .and_then(|(spell, stats)| stats.get(spell));
+            if let (Some(crit), Some(rng)) = (crit, rng.as_mut()) {
+                if let Some(multiplier) = crit.roll(&mut **rng) {
+                    damage *= multiplier;
+                    critical = true;
+                }
             }
 
-            // Apply corroded debuff multiplier
-            if let Some(corroded) = corroded {
-                final_damage *= corroded.damage_multiplier;
-            }
+            (registry.resolve(&target, event, damage), critical)
+        };
 
-            // Apply Nightfall zone multiplier for Dark element damage
-            if let Some(nightfall) = in_nightfall {
-                if event.element == Some(Element::Dark) {
-                    final_damage *= nightfall.dark_damage_multiplier;
-                }
+        let mut killing_blow = false;
+        if let Ok((mut health, shield)) = targets.p1().get_mut(event.target) {
+            if let Some(mut shield) = shield {
+                final_damage = shield.absorb(final_damage);
             }
-
+            let was_alive = !health.is_dead();
             health.take_damage(final_damage);
+            killing_blow = was_alive && health.is_dead();
+        }
+
+        if let Some(dealt) = dealt.as_mut() {
+            dealt.write(DamageDealt {
+                target: event.target,
+                amount: final_damage,
+                base_amount: event.amount,
+                source: event.source,
+                element: event.element,
+                kind: event.kind,
+                critical,
+                killing_blow,
+                reaction: event.reaction,
+                spell: event.spell,
+            });
         }
     }
 }
@@ -108,6 +133,7 @@ pub fn handle_enemy_death_system(
     mut enemy_death_events: MessageWriter<EnemyDeathEvent>,
     mut score: ResMut<Score>,
     level_query: Query<&Level>,
+    affix_query: Query<&EliteAffixes>,
 ) {
     for event in death_events.read() {
         if event.entity_type == EntityType::Enemy {
@@ -119,12 +145,16 @@ pub fn handle_enemy_death_system(
                 .get(event.entity)
                 .map(|l| l.value())
                 .unwrap_or(1);
+            let affix_count = affix_query
+                .get(event.entity)
+                .map_or(0, |affixes| affixes.count());
 
             // Send EnemyDeathEvent for loot/experience handling
             enemy_death_events.write(EnemyDeathEvent {
                 enemy_entity: event.entity,
                 position: event.position,
                 enemy_level,
+                affix_count,
             });
 
             // Despawn the enemy
@@ -181,11 +211,35 @@ pub fn update_damage_flash_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::combat::components::{Armor, ElementalResistances};
+    use crate::combat::pipeline::{CritStats, DamageModifier, DamageModifierAppExt, DamageStage};
+    use crate::spell::SpellType;
+    use crate::element::Element;
+    use crate::spells::dark::nightfall::InNightfallZone;
     use std::time::Duration;
 
     mod apply_damage_tests {
         use super::*;
 
+        #[test]
+        fn test_shield_absorbs_damage_before_health() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.add_message::<DamageDealt>();
+            app.add_systems(Update, apply_damage_system);
+
+            let entity = app.world_mut().spawn((Health::new(100.0), DamageShield::new(30.0))).id();
+            app.world_mut().write_message(DamageEvent::new(entity, 20.0));
+            app.world_mut().write_message(DamageEvent::new(entity, 25.0));
+            app.update();
+
+            assert_eq!(app.world().get::<Health>(entity).unwrap().current, 85.0);
+            assert!(app.world().get::<DamageShield>(entity).unwrap().is_broken());
+            let dealt = app.world().resource::<Messages<DamageDealt>>();
+            let amounts: Vec<f32> = dealt.iter_current_update_messages().map(|dealt| dealt.amount).collect();
+            assert_eq!(amounts, vec![0.0, 15.0], "Only damage reaching health is reported");
+        }
+
         #[test]
         fn test_apply_damage_reduces_health() {
             let mut app = App::new();
@@ -231,6 +285,34 @@ mod tests {
             assert_eq!(health.current, 100.0);
         }
 
+        #[test]
+        fn test_apply_damage_scaled_by_elemental_resistances() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.add_systems(Update, apply_damage_system);
+
+            let resistances = ElementalResistances::default()
+                .resist(Element::Fire)
+                .weak_to(Element::Frost)
+                .immune_to(Element::Poison);
+            let resisted = app.world_mut().spawn((Health::new(100.0), resistances.clone())).id();
+            let weak = app.world_mut().spawn((Health::new(100.0), resistances.clone())).id();
+            let immune = app.world_mut().spawn((Health::new(100.0), resistances.clone())).id();
+            let physical = app.world_mut().spawn((Health::new(100.0), resistances)).id();
+
+            app.world_mut().write_message(DamageEvent::with_element(resisted, 20.0, Element::Fire));
+            app.world_mut().write_message(DamageEvent::with_element(weak, 20.0, Element::Frost));
+            app.world_mut().write_message(DamageEvent::with_element(immune, 20.0, Element::Poison));
+            app.world_mut().write_message(DamageEvent::new(physical, 20.0));
+
+            app.update();
+
+            assert_eq!(app.world().get::<Health>(resisted).unwrap().current, 90.0);
+            assert_eq!(app.world().get::<Health>(weak).unwrap().current, 70.0);
+            assert_eq!(app.world().get::<Health>(immune).unwrap().current, 100.0);
+            assert_eq!(app.world().get::<Health>(physical).unwrap().current, 80.0);
+        }
+
         #[test]
         fn test_apply_damage_handles_missing_entity() {
             let mut app = App::new();
@@ -343,6 +425,161 @@ mod tests {
             let health = app.world().get::<Health>(entity).unwrap();
             assert!((health.current - 80.0).abs() < 0.01, "Expected 80.0, got {}", health.current);
         }
+
+        #[test]
+        fn test_apply_damage_armor_reduces_direct_hits_only() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.add_systems(Update, apply_damage_system);
+
+            let direct = app.world_mut().spawn((Health::new(100.0), Armor(5.0))).id();
+            let ticked = app.world_mut().spawn((Health::new(100.0), Armor(5.0))).id();
+            app.world_mut().write_message(DamageEvent::new(direct, 20.0));
+            app.world_mut()
+                .write_message(DamageEvent::new(ticked, 20.0).over_time());
+
+            app.update();
+
+            assert_eq!(app.world().get::<Health>(direct).unwrap().current, 85.0);
+            assert_eq!(app.world().get::<Health>(ticked).unwrap().current, 80.0);
+        }
+
+        #[test]
+        fn test_apply_damage_writes_damage_dealt() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.add_message::<DamageDealt>();
+            app.add_systems(Update, apply_damage_system);
+
+            let entity = app
+                .world_mut()
+                .spawn((
+                    Health::new(100.0),
+                    ElementalResistances::default().resist(Element::Fire),
+                ))
+                .id();
+            let invincible = app
+                .world_mut()
+                .spawn((Health::new(100.0), Invincibility::default()))
+                .id();
+            app.world_mut()
+                .write_message(DamageEvent::with_element(entity, 20.0, Element::Fire));
+            app.world_mut().write_message(DamageEvent::new(invincible, 20.0));
+
+            app.update();
+
+            let messages = app.world().resource::<Messages<DamageDealt>>();
+            let dealt: Vec<_> = messages.iter_current_update_messages().collect();
+            assert_eq!(dealt.len(), 1, "Invincible targets should not report damage");
+            assert_eq!(dealt[0].target, entity);
+            assert_eq!(dealt[0].amount, 10.0);
+            assert_eq!(dealt[0].base_amount, 20.0);
+            assert_eq!(dealt[0].element, Some(Element::Fire));
+            assert!(!dealt[0].critical);
+        }
+
+        #[test]
+        fn test_apply_damage_crits_direct_hits_on_enemies() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.add_message::<DamageDealt>();
+            let mut crit_stats = SpellCritStats::default();
+            crit_stats.insert(
+                SpellType::Fireball,
+                CritStats {
+                    chance: 1.0,
+                    multiplier: 2.0,
+                },
+            );
+            app.insert_resource(crit_stats);
+            app.insert_resource(GameRng::from_seed(3));
+            app.add_systems(Update, apply_damage_system);
+
+            let spawn_enemy = |app: &mut App| {
+                app.world_mut()
+                    .spawn((Health::new(100.0), Enemy { speed: 1.0, strength: 1.0 }))
+                    .id()
+            };
+            let enemy = spawn_enemy(&mut app);
+            let burning = spawn_enemy(&mut app);
+            let unequipped = spawn_enemy(&mut app);
+            let untagged = spawn_enemy(&mut app);
+            let player = app.world_mut().spawn(Health::new(100.0)).id();
+            let fireball = |target: Entity| DamageEvent::new(target, 10.0).by_spell(SpellType::Fireball);
+            app.world_mut().write_message(fireball(enemy));
+            app.world_mut().write_message(fireball(burning).over_time());
+            app.world_mut()
+                .write_message(DamageEvent::new(unequipped, 10.0).by_spell(SpellType::IceShard));
+            app.world_mut().write_message(DamageEvent::new(untagged, 10.0));
+            app.world_mut().write_message(fireball(player));
+
+            app.update();
+
+            assert_eq!(app.world().get::<Health>(enemy).unwrap().current, 80.0);
+            for target in [burning, unequipped, untagged, player] {
+                assert_eq!(app.world().get::<Health>(target).unwrap().current, 90.0);
+            }
+
+            let messages = app.world().resource::<Messages<DamageDealt>>();
+            let crits: Vec<_> = messages
+                .iter_current_update_messages()
+                .filter(|dealt| dealt.critical)
+                .map(|dealt| dealt.target)
+                .collect();
+            assert_eq!(crits, vec![enemy]);
+        }
+
+        #[test]
+        fn test_apply_damage_flags_killing_blow_once() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.add_message::<DamageDealt>();
+            app.add_systems(Update, apply_damage_system);
+
+            let entity = app.world_mut().spawn(Health::new(15.0)).id();
+            app.world_mut()
+                .write_message(DamageEvent::new(entity, 10.0).by_spell(SpellType::Spark));
+            app.world_mut()
+                .write_message(DamageEvent::new(entity, 10.0).by_spell(SpellType::Fireball));
+            app.world_mut()
+                .write_message(DamageEvent::new(entity, 10.0).by_spell(SpellType::IceShard));
+
+            app.update();
+
+            let messages = app.world().resource::<Messages<DamageDealt>>();
+            let killers: Vec<_> = messages
+                .iter_current_update_messages()
+                .filter(|dealt| dealt.killing_blow)
+                .map(|dealt| dealt.spell)
+                .collect();
+            assert_eq!(killers, vec![Some(SpellType::Fireball)]);
+        }
+
+        #[test]
+        fn test_apply_damage_uses_registered_modifiers() {
+            #[derive(Component)]
+            struct Ward;
+
+            impl DamageModifier for Ward {
+                const STAGE: DamageStage = DamageStage::Mitigation;
+
+                fn modify(&self, _hit: &DamageEvent, _damage: f32) -> f32 {
+                    0.0
+                }
+            }
+
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.register_damage_modifier::<Ward>();
+            app.add_systems(Update, apply_damage_system);
+
+            let warded = app.world_mut().spawn((Health::new(100.0), Ward)).id();
+            app.world_mut().write_message(DamageEvent::new(warded, 30.0));
+
+            app.update();
+
+            assert_eq!(app.world().get::<Health>(warded).unwrap().current, 100.0);
+        }
     }
 
     mod check_death_tests {
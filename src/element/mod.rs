@@ -1,8 +1,11 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Element types for the spell system.
 /// Each element has a unique color for visual effects and a display name.
-#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[derive(
+    Component, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
 pub enum Element {
     #[default]
     Fire,
@@ -1,13 +1,20 @@
 use bevy::prelude::*;
 
+use serde::{Deserialize, Serialize};
+
+use crate::combat::components::RESISTED_DAMAGE_MULTIPLIER;
+use crate::combat::{Armor, DamageEvent, DamageModifier, DamageStage, ElementalResistances};
+use crate::element::Element;
+
 #[derive(Component)]
 pub struct Enemy {
     pub speed: f32,
     pub strength: f32,
 }
 
-/// Configuration for enemy stat scaling based on level
-#[derive(Debug, Clone)]
+/// Stat scaling of one enemy kind based on level, loaded from the wave script
+/// (see `enemies::waves`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnemyScaling {
     pub base_health: f32,
     pub health_per_enemy_level: f32,
@@ -16,18 +23,6 @@ pub struct EnemyScaling {
     pub damage_per_level: f32,
 }
 
-impl Default for EnemyScaling {
-    fn default() -> Self {
-        Self {
-            base_health: 25.0,
-            health_per_enemy_level: 15.0, // Enemy level 5 base = 25 + (4 * 15) = 85 HP
-            health_per_game_level_percent: 0.10, // +10% HP per game level
-            base_damage: 10.0,
-            damage_per_level: 5.0, // Level 5 = 10 + (4 * 5) = 30 damage
-        }
-    }
-}
-
 impl EnemyScaling {
     /// Calculate health for a given enemy level (1-5) and game level
     /// Formula: (base + enemy_level_bonus) * (1 + game_level_bonus)
@@ -45,23 +40,507 @@ impl EnemyScaling {
     }
 }
 
+/// Enemy archetype. Decides visuals and which behaviour components an enemy
+/// is spawned with; stats and spawn weights come from the wave script.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum EnemyKind {
+    /// Basic melee enemy that walks straight at the player
+    #[default]
+    Grunt,
+    /// Keeps its distance and fires projectiles at the player
+    Ranged,
+    /// Telegraphs, then dashes in a straight line at the player
+    Charger,
+    /// Splits into smaller fragments on death
+    Splitter,
+    /// Slow, large and very durable
+    Tank,
+    /// Fast and fragile, spawns in small packs
+    Swarm,
+}
+
+impl EnemyKind {
+    /// All enemy kinds in spawn table order
+    pub fn all() -> &'static [EnemyKind; 6] {
+        &[
+            EnemyKind::Grunt,
+            EnemyKind::Ranged,
+            EnemyKind::Charger,
+            EnemyKind::Splitter,
+            EnemyKind::Tank,
+            EnemyKind::Swarm,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyKind::Grunt => "Grunt",
+            EnemyKind::Ranged => "Ranged",
+            EnemyKind::Charger => "Charger",
+            EnemyKind::Splitter => "Splitter",
+            EnemyKind::Tank => "Tank",
+            EnemyKind::Swarm => "Swarm",
+        }
+    }
+
+    /// Movement speed in 3D world units/sec
+    pub fn speed(&self) -> f32 {
+        match self {
+            EnemyKind::Grunt => 2.6,
+            EnemyKind::Ranged => 2.2,
+            EnemyKind::Charger => 2.4,
+            EnemyKind::Splitter => 2.3,
+            EnemyKind::Tank => 1.4,
+            EnemyKind::Swarm => 4.2,
+        }
+    }
+
+    /// Multiplier applied on top of the level-based enemy scale
+    pub fn scale_multiplier(&self) -> f32 {
+        match self {
+            EnemyKind::Tank => 1.4,
+            EnemyKind::Swarm => 0.55,
+            EnemyKind::Ranged => 0.9,
+            _ => 1.0,
+        }
+    }
+
+    /// Elements this kind resists, is weak to or ignores entirely
+    pub fn elemental_resistances(&self) -> ElementalResistances {
+        let resistances = ElementalResistances::default();
+        match self {
+            EnemyKind::Grunt => resistances,
+            EnemyKind::Ranged => resistances.resist(Element::Psychic).weak_to(Element::Lightning),
+            EnemyKind::Charger => resistances.resist(Element::Fire).weak_to(Element::Frost),
+            EnemyKind::Splitter => resistances.resist(Element::Poison).weak_to(Element::Chaos),
+            EnemyKind::Tank => resistances
+                .resist(Element::Fire)
+                .resist(Element::Frost)
+                .weak_to(Element::Poison),
+            EnemyKind::Swarm => resistances.immune_to(Element::Poison).weak_to(Element::Fire),
+        }
+    }
+
+    /// Flat damage reduction against direct hits; Tanks are plated,
+    /// Ranged casters and Swarms are soft.
+    pub fn armor(&self) -> Armor {
+        match self {
+            EnemyKind::Tank => Armor(4.0),
+            EnemyKind::Grunt | EnemyKind::Charger | EnemyKind::Splitter => Armor(1.0),
+            EnemyKind::Ranged | EnemyKind::Swarm => Armor(0.0),
+        }
+    }
+}
+
+/// Elite enemy: a tougher, larger and harder-hitting version of its kind.
+/// Rolled per enemy with the wave window's `elite_chance`, and given
+/// `EliteAffixes` once spawned.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Elite;
+
+impl Elite {
+    pub const HEALTH_MULTIPLIER: f32 = 2.5;
+    pub const DAMAGE_MULTIPLIER: f32 = 1.5;
+    pub const SCALE_MULTIPLIER: f32 = 1.25;
+}
+
+/// Ranged caster behaviour: hold position around `preferred_distance` and
+/// fire projectiles at the player whenever it is within `attack_range`.
+#[derive(Component, Debug, Clone)]
+pub struct RangedAttacker {
+    /// Distance from the player the enemy tries to keep
+    pub preferred_distance: f32,
+    /// Maximum distance at which the enemy will fire
+    pub attack_range: f32,
+    /// Cooldown between shots
+    pub fire_timer: Timer,
+    /// Projectile speed in world units/sec
+    pub projectile_speed: f32,
+}
+
+impl Default for RangedAttacker {
+    fn default() -> Self {
+        Self {
+            preferred_distance: 8.0,
+            attack_range: 12.0,
+            fire_timer: Timer::from_seconds(2.0, TimerMode::Repeating),
+            projectile_speed: 9.0,
+        }
+    }
+}
+
+impl RangedAttacker {
+    /// Width of the band around `preferred_distance` where the enemy stands still
+    pub const HOLD_BAND: f32 = 1.0;
+
+    /// Direction multiplier for chasing the player at a given distance:
+    /// 1.0 to close in, -1.0 to back off and 0.0 to hold position.
+    pub fn approach_factor(&self, distance: f32) -> f32 {
+        if distance > self.preferred_distance + Self::HOLD_BAND {
+            1.0
+        } else if distance < self.preferred_distance - Self::HOLD_BAND {
+            -1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Projectile fired by a ranged enemy. Damages the player on contact.
+#[derive(Component, Debug, Clone)]
+pub struct EnemyProjectile {
+    /// Normalized direction on XZ plane
+    pub direction: Vec2,
+    pub speed: f32,
+    pub damage: f32,
+    pub lifetime: Timer,
+}
+
+impl EnemyProjectile {
+    pub fn new(direction: Vec2, speed: f32, damage: f32) -> Self {
+        Self {
+            direction: direction.normalize_or_zero(),
+            speed,
+            damage,
+            lifetime: Timer::from_seconds(3.0, TimerMode::Once),
+        }
+    }
+}
+
+/// Phases of a charger's attack cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChargerState {
+    /// Walking toward the player like a regular enemy
+    Approaching,
+    /// Standing still while the dash path is shown
+    Telegraphing,
+    /// Dashing along the locked direction
+    Dashing,
+    /// Catching its breath before approaching again
+    Recovering,
+}
+
+/// Charger behaviour: telegraph, then dash along a locked direction.
+#[derive(Component, Debug, Clone)]
+pub struct Charger {
+    pub state: ChargerState,
+    /// Timer for the current phase (unused while approaching)
+    pub timer: Timer,
+    /// Dash direction on XZ plane, locked when the telegraph starts
+    pub direction: Vec2,
+    /// Distance to the player at which the charger starts its telegraph
+    pub trigger_distance: f32,
+}
+
+impl Default for Charger {
+    fn default() -> Self {
+        Self {
+            state: ChargerState::Approaching,
+            timer: Timer::from_seconds(0.0, TimerMode::Once),
+            direction: Vec2::ZERO,
+            trigger_distance: 7.0,
+        }
+    }
+}
+
+impl Charger {
+    pub const TELEGRAPH_SECS: f32 = 0.8;
+    pub const DASH_SECS: f32 = 0.5;
+    pub const RECOVER_SECS: f32 = 0.8;
+    /// Dash speed relative to the enemy's walking speed
+    pub const DASH_SPEED_MULTIPLIER: f32 = 5.0;
+
+    /// Enter a new phase and restart the phase timer
+    pub fn enter(&mut self, state: ChargerState) {
+        let secs = match state {
+            ChargerState::Approaching => 0.0,
+            ChargerState::Telegraphing => Self::TELEGRAPH_SECS,
+            ChargerState::Dashing => Self::DASH_SECS,
+            ChargerState::Recovering => Self::RECOVER_SECS,
+        };
+        self.state = state;
+        self.timer = Timer::from_seconds(secs, TimerMode::Once);
+    }
+
+    /// Whether regular chase movement should move this charger
+    pub fn is_approaching(&self) -> bool {
+        self.state == ChargerState::Approaching
+    }
+
+    /// Length of the dash path, used to size the telegraph
+    pub fn dash_length(speed: f32) -> f32 {
+        speed * Self::DASH_SPEED_MULTIPLIER * Self::DASH_SECS
+    }
+}
+
+/// Ground marker showing a charger's upcoming dash path.
+#[derive(Component, Debug, Clone)]
+pub struct ChargeTelegraph {
+    /// Charger that owns this telegraph
+    pub owner: Entity,
+}
+
+/// Enemy running straight across the arena in a stampede. Ignores the
+/// player until it has covered its distance, then joins the chase.
+#[derive(Component, Debug, Clone)]
+pub struct Stampeding {
+    /// Normalized direction on XZ plane
+    pub direction: Vec2,
+    /// Distance left to run
+    pub distance_left: f32,
+}
+
+impl Stampeding {
+    /// Running speed relative to the enemy's walking speed
+    pub const SPEED_MULTIPLIER: f32 = 2.5;
+}
+
+/// Ground marker warning of an elite ambush. The elites appear on it once
+/// the timer finishes.
+#[derive(Component, Debug, Clone)]
+pub struct AmbushWarning {
+    pub timer: Timer,
+    pub kind: EnemyKind,
+    pub count: u32,
+}
+
+impl AmbushWarning {
+    pub const WARNING_SECS: f32 = 2.0;
+
+    pub fn new(kind: EnemyKind, count: u32) -> Self {
+        Self {
+            timer: Timer::from_seconds(Self::WARNING_SECS, TimerMode::Once),
+            kind,
+            count,
+        }
+    }
+}
+
+/// Extra ability rolled onto an elite enemy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EliteAffix {
+    /// Absorbs its first hits with a `DamageShield`
+    Shielded,
+    /// Heals when it hits the player
+    Vampiric,
+    /// Detonates on death, hurting the player if close
+    Explosive,
+    /// Moves faster
+    Hasted,
+    /// Blinks next to the player when far away
+    Teleporting,
+    /// Nearby enemies resist the aura's element
+    ElementalAura(Element),
+}
+
+impl EliteAffix {
+    /// Shield size as a fraction of the elite's max health
+    pub const SHIELD_HEALTH_FRACTION: f32 = 0.5;
+    /// Speed multiplier of Hasted elites
+    pub const HASTE_SPEED_MULTIPLIER: f32 = 1.5;
+
+    /// Every affix except `ElementalAura`, which comes in one per element
+    pub const BASIC: [EliteAffix; 5] = [
+        EliteAffix::Shielded,
+        EliteAffix::Vampiric,
+        EliteAffix::Explosive,
+        EliteAffix::Hasted,
+        EliteAffix::Teleporting,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EliteAffix::Shielded => "Shielded",
+            EliteAffix::Vampiric => "Vampiric",
+            EliteAffix::Explosive => "Explosive",
+            EliteAffix::Hasted => "Hasted",
+            EliteAffix::Teleporting => "Teleporting",
+            EliteAffix::ElementalAura(_) => "Elemental Aura",
+        }
+    }
+
+    /// Color of the affix's orb above the elite
+    pub fn color(&self) -> Color {
+        match self {
+            EliteAffix::Shielded => Color::srgb(0.4, 0.7, 1.0),
+            EliteAffix::Vampiric => Color::srgb(0.6, 0.0, 0.1),
+            EliteAffix::Explosive => Color::srgb(1.0, 0.45, 0.0),
+            EliteAffix::Hasted => Color::srgb(1.0, 1.0, 0.3),
+            EliteAffix::Teleporting => Color::srgb(0.7, 0.3, 1.0),
+            EliteAffix::ElementalAura(element) => element.color(),
+        }
+    }
+
+    /// Number of affixes an elite of the given level (1-5) rolls:
+    /// one, two from rare and three at legendary
+    pub fn count_for_level(level: u8) -> usize {
+        1 + (level.clamp(1, 5) as usize - 1) / 2
+    }
+}
+
+/// Affixes rolled onto an elite. Each affix's behaviour lives in its own
+/// component (`DamageShield`, `Vampiric`, `Explosive`, `Teleporting`,
+/// `ElementalAura`); Hasted only raises the enemy's speed.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct EliteAffixes(pub Vec<EliteAffix>);
+
+impl EliteAffixes {
+    pub fn count(&self) -> u8 {
+        self.0.len() as u8
+    }
+
+    pub fn has(&self, affix: EliteAffix) -> bool {
+        self.0.contains(&affix)
+    }
+}
+
+/// Vampiric affix: contact hits on the player heal the elite
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Vampiric {
+    /// Healing per hit as a fraction of the elite's max health
+    pub heal_fraction: f32,
+}
+
+impl Default for Vampiric {
+    fn default() -> Self {
+        Self { heal_fraction: 0.1 }
+    }
+}
+
+/// Explosive affix: detonates where the elite dies
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Explosive {
+    pub radius: f32,
+    pub damage: f32,
+}
+
+impl Explosive {
+    pub const RADIUS: f32 = 3.0;
+    /// Explosion damage relative to the elite's contact damage
+    pub const DAMAGE_MULTIPLIER: f32 = 2.0;
+
+    pub fn new(strength: f32) -> Self {
+        Self {
+            radius: Self::RADIUS,
+            damage: strength * Self::DAMAGE_MULTIPLIER,
+        }
+    }
+}
+
+/// Teleporting affix: every `timer` period, blinks next to the player if it
+/// is further away than `MIN_DISTANCE`
+#[derive(Component, Debug, Clone)]
+pub struct Teleporting {
+    pub timer: Timer,
+}
+
+impl Teleporting {
+    pub const INTERVAL_SECS: f32 = 4.0;
+    pub const MIN_DISTANCE: f32 = 8.0;
+    /// Distance from the player the elite lands at
+    pub const BLINK_DISTANCE: f32 = 4.0;
+}
+
+impl Default for Teleporting {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(Self::INTERVAL_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Elemental aura affix: pulses its element onto enemies within `RADIUS`
+/// (including the elite itself) as `AuraInfused`
+#[derive(Component, Debug, Clone)]
+pub struct ElementalAura {
+    pub element: Element,
+    pub pulse_timer: Timer,
+}
+
+impl ElementalAura {
+    pub const RADIUS: f32 = 4.0;
+    pub const PULSE_SECS: f32 = 1.0;
+
+    pub fn new(element: Element) -> Self {
+        Self {
+            element,
+            pulse_timer: Timer::from_seconds(Self::PULSE_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Enemy infused with an elite's aura: resists the aura's element until
+/// `remaining` runs out
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AuraInfused {
+    pub element: Element,
+    pub remaining: f32,
+}
+
+impl AuraInfused {
+    /// Seconds the infusion lasts after the last pulse; longer than a pulse
+    pub const DURATION_SECS: f32 = 2.0;
+
+    pub fn new(element: Element) -> Self {
+        Self {
+            element,
+            remaining: Self::DURATION_SECS,
+        }
+    }
+}
+
+impl DamageModifier for AuraInfused {
+    const STAGE: DamageStage = DamageStage::Resistance;
+
+    fn modify(&self, hit: &DamageEvent, damage: f32) -> f32 {
+        if hit.is_element(self.element) {
+            damage * RESISTED_DAMAGE_MULTIPLIER
+        } else {
+            damage
+        }
+    }
+}
+
+/// Orb circling above an elite, one per affix
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EliteAffixOrb {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl EliteAffixOrb {
+    /// Height above the elite's center, in its local (scaled) space
+    pub const HEIGHT: f32 = 1.1;
+    pub const ORBIT_RADIUS: f32 = 0.5;
+    /// Radians per second
+    pub const ORBIT_SPEED: f32 = 2.0;
+
+    /// Local position of the orb `elapsed_secs` into the orbit
+    pub fn position(&self, elapsed_secs: f32) -> Vec3 {
+        let angle = elapsed_secs * Self::ORBIT_SPEED
+            + self.index as f32 * std::f32::consts::TAU / self.count.max(1) as f32;
+        Vec3::new(angle.cos() * Self::ORBIT_RADIUS, Self::HEIGHT, angle.sin() * Self::ORBIT_RADIUS)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn enemy_scaling_default_values() {
-        let scaling = EnemyScaling::default();
-        assert_eq!(scaling.base_health, 25.0);
-        assert_eq!(scaling.health_per_enemy_level, 15.0);
-        assert_eq!(scaling.health_per_game_level_percent, 0.10);
-        assert_eq!(scaling.base_damage, 10.0);
-        assert_eq!(scaling.damage_per_level, 5.0);
+    /// Grunt stats from the built-in wave script
+    fn grunt_scaling() -> EnemyScaling {
+        EnemyScaling {
+            base_health: 25.0,
+            health_per_enemy_level: 15.0,
+            health_per_game_level_percent: 0.10,
+            base_damage: 10.0,
+            damage_per_level: 5.0,
+        }
     }
 
     #[test]
     fn enemy_scaling_calculates_correct_health_at_game_level_1() {
-        let scaling = EnemyScaling::default();
+        let scaling = grunt_scaling();
         // At game level 1, no multiplier (1.0x)
         assert_eq!(scaling.health_for_level(1, 1), 25.0);
         assert_eq!(scaling.health_for_level(2, 1), 40.0);
@@ -72,7 +551,7 @@ mod tests {
 
     #[test]
     fn enemy_scaling_health_increases_with_game_level() {
-        let scaling = EnemyScaling::default();
+        let scaling = grunt_scaling();
         // Game level 1: 25 HP (enemy level 1)
         // Game level 2: 25 * 1.1 = 27.5 HP
         // Game level 11: 25 * 2.0 = 50 HP
@@ -83,7 +562,7 @@ mod tests {
 
     #[test]
     fn enemy_scaling_health_combines_enemy_and_game_level() {
-        let scaling = EnemyScaling::default();
+        let scaling = grunt_scaling();
         // Enemy level 5 at game level 1: 85 HP
         // Enemy level 5 at game level 11: 85 * 2.0 = 170 HP
         assert_eq!(scaling.health_for_level(5, 1), 85.0);
@@ -92,7 +571,7 @@ mod tests {
 
     #[test]
     fn enemy_scaling_calculates_correct_damage() {
-        let scaling = EnemyScaling::default();
+        let scaling = grunt_scaling();
         assert_eq!(scaling.damage_for_level(1), 10.0);
         assert_eq!(scaling.damage_for_level(2), 15.0);
         assert_eq!(scaling.damage_for_level(3), 20.0);
@@ -102,7 +581,7 @@ mod tests {
 
     #[test]
     fn enemy_scaling_level_zero_same_as_level_one() {
-        let scaling = EnemyScaling::default();
+        let scaling = grunt_scaling();
         // Level 0 (invalid) should have same stats as level 1 due to saturating_sub
         assert_eq!(scaling.health_for_level(0, 1), 25.0);
         assert_eq!(scaling.damage_for_level(0), 10.0);
@@ -110,8 +589,110 @@ mod tests {
 
     #[test]
     fn enemy_scaling_game_level_zero_same_as_level_one() {
-        let scaling = EnemyScaling::default();
+        let scaling = grunt_scaling();
         // Game level 0 (invalid) should have same multiplier as level 1
         assert_eq!(scaling.health_for_level(1, 0), 25.0);
     }
-}
\ No newline at end of file
+
+    mod enemy_kind_tests {
+        use super::*;
+
+        #[test]
+        fn tank_is_slower_and_larger_than_swarm() {
+            let tank = EnemyKind::Tank;
+            let swarm = EnemyKind::Swarm;
+            assert!(tank.speed() < swarm.speed());
+            assert!(tank.scale_multiplier() > swarm.scale_multiplier());
+        }
+
+        #[test]
+        fn grunts_take_normal_damage_from_every_element() {
+            let resistances = EnemyKind::Grunt.elemental_resistances();
+            for element in Element::all() {
+                assert_eq!(resistances.multiplier(Some(*element)), 1.0, "{:?}", element);
+            }
+        }
+
+        #[test]
+        fn special_kinds_have_a_weakness() {
+            for kind in EnemyKind::all().iter().filter(|k| **k != EnemyKind::Grunt) {
+                let resistances = kind.elemental_resistances();
+                assert!(
+                    Element::all()
+                        .iter()
+                        .any(|element| resistances.multiplier(Some(*element)) > 1.0),
+                    "{:?} should be weak to at least one element",
+                    kind
+                );
+            }
+        }
+
+        #[test]
+        fn tanks_have_the_most_armor() {
+            for kind in EnemyKind::all().iter().filter(|k| **k != EnemyKind::Tank) {
+                assert!(EnemyKind::Tank.armor().0 > kind.armor().0, "{:?}", kind);
+            }
+            assert_eq!(EnemyKind::Swarm.armor(), Armor(0.0));
+        }
+    }
+
+    mod ranged_attacker_tests {
+        use super::*;
+
+        #[test]
+        fn approach_factor_closes_holds_and_backs_off() {
+            let ranged = RangedAttacker::default();
+            assert_eq!(ranged.approach_factor(ranged.preferred_distance + 5.0), 1.0);
+            assert_eq!(ranged.approach_factor(ranged.preferred_distance), 0.0);
+            assert_eq!(ranged.approach_factor(1.0), -1.0);
+        }
+    }
+
+    mod charger_tests {
+        use super::*;
+
+        #[test]
+        fn charger_starts_approaching() {
+            let charger = Charger::default();
+            assert!(charger.is_approaching());
+        }
+
+        #[test]
+        fn enter_resets_phase_timer() {
+            let mut charger = Charger::default();
+            charger.enter(ChargerState::Telegraphing);
+            assert_eq!(charger.state, ChargerState::Telegraphing);
+            assert_eq!(charger.timer.duration().as_secs_f32(), Charger::TELEGRAPH_SECS);
+            assert!(!charger.is_approaching());
+        }
+    }
+
+    mod elite_affix_tests {
+        use super::*;
+
+        #[test]
+        fn higher_levels_roll_more_affixes() {
+            assert_eq!(EliteAffix::count_for_level(1), 1);
+            assert_eq!(EliteAffix::count_for_level(2), 1);
+            assert_eq!(EliteAffix::count_for_level(3), 2);
+            assert_eq!(EliteAffix::count_for_level(5), 3);
+        }
+
+        #[test]
+        fn aura_infusion_resists_only_its_element() {
+            let infused = AuraInfused::new(Element::Frost);
+            let target = Entity::PLACEHOLDER;
+            assert_eq!(infused.modify(&DamageEvent::with_element(target, 10.0, Element::Frost), 10.0), 5.0);
+            assert_eq!(infused.modify(&DamageEvent::with_element(target, 10.0, Element::Fire), 10.0), 10.0);
+            assert_eq!(infused.modify(&DamageEvent::new(target, 10.0), 10.0), 10.0);
+        }
+
+        #[test]
+        fn affix_orbs_are_spread_around_the_orbit() {
+            let first = EliteAffixOrb { index: 0, count: 2 }.position(0.0);
+            let second = EliteAffixOrb { index: 1, count: 2 }.position(0.0);
+            assert!((first + second).xz().length() < 0.001, "Two orbs should sit opposite each other");
+            assert_eq!(first.y, EliteAffixOrb::HEIGHT);
+        }
+    }
+}
@@ -1,5 +1,8 @@
 pub mod components;
+pub mod plugin;
 pub mod systems;
+pub mod waves;
 
 pub use components::*;
-pub use systems::*;
\ No newline at end of file
+pub use plugin::*;
+pub use systems::*;
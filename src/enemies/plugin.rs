@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+
+use crate::combat::{check_death_system, handle_enemy_death_system, CombatSets, DamageModifierAppExt};
+use crate::enemies::components::AuraInfused;
+use crate::enemies::systems::*;
+use crate::game::resources::GameRng;
+use crate::game::sets::GameSet;
+use crate::spells::chaos::fracture::{
+    fracture_on_death_system, spawn_fragment_enemies_system, FractureDeathEvent,
+};
+use crate::states::GameState;
+
+/// Enemy plugin runs the per-kind behaviours (ranged attacks, charger dashes,
+/// splitter fragments), the wave events' stampedes and ambushes, and the
+/// elite affixes. Spawning itself is scheduled by the game plugin.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<GameRng>()
+        .add_message::<FractureDeathEvent>()
+        .register_damage_modifier::<AuraInfused>()
+        .add_systems(
+            Update,
+            (charger_behavior_system, stampede_movement_system, teleporting_elite_system)
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (ranged_enemy_attack_system, enemy_projectile_system, elemental_aura_system)
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Splitters and explosive elites must be read before handle_enemy_death_system despawns them
+        .add_systems(
+            Update,
+            (fracture_on_death_system, explosive_on_death_system)
+                .in_set(CombatSets::Death)
+                .after(check_death_system)
+                .before(handle_enemy_death_system)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                spawn_fragment_enemies_system,
+                elite_ambush_system,
+                (elite_affix_system, spawn_elite_affix_orbs_system).chain(),
+            )
+                .in_set(GameSet::Spawning)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            elite_affix_orb_system
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combat::DamageModifierRegistry;
+
+    #[test]
+    fn test_plugin_registers_fracture_death_event() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().get_resource::<Messages<FractureDeathEvent>>().is_some());
+    }
+
+    #[test]
+    fn test_plugin_registers_aura_damage_modifier() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().resource::<DamageModifierRegistry>().contains::<AuraInfused>());
+    }
+}
@@ -1,11 +1,22 @@
+use std::f32::consts::TAU;
+
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::combat::{CheckDeath, Health};
+use crate::combat::{CheckDeath, DamageEvent, DamageShield, DeathEvent, EntityType, Health};
+use crate::element::Element;
 use crate::enemies::components::*;
+use crate::enemies::waves::{Formation, WaveEvent, WaveScript, ENEMY_LEVEL_COUNT};
+use crate::game::events::WaveEventStarted;
 use crate::game::components::Level;
 use crate::game::resources::*;
+use crate::movement::components::{from_xz, to_xz};
 use crate::player::components::*;
+use crate::reactions::components::ReactionBurst;
+use crate::spells::chaos::fracture::FractureEffect;
+use crate::spells::dark::wraith_form::WraithForm;
+use crate::spells::frost::permafrost::FrozenStatus;
+use crate::spells::psychic::synapse_shock::StunnedEnemy;
 
 /// Distance from player to spawn enemies (scaled for 3D world units)
 /// With orthographic camera viewport of ~20x35 units, spawn just outside view
@@ -14,105 +25,805 @@ pub const ENEMY_SPAWN_DISTANCE: f32 = 18.0;
 /// Height of enemy rectangular prism center above ground (half of 1.5 height)
 pub const ENEMY_Y_HEIGHT: f32 = 0.75;
 
-/// Determine enemy level based on game level with weighted random selection.
-/// Higher game levels increase the chance of spawning higher-tier enemies.
-/// At game level 1, legendary (level 5) has 0.1% chance.
-pub fn select_enemy_level(game_level: u32, rng: &mut impl Rng) -> u8 {
-    // Level bonus increases with game level: +5 per level, capped at 50 (game level 11)
-    let level_bonus = ((game_level.saturating_sub(1) as f32) * 5.0).min(50.0);
-
-    // Base spawn chances at game level 1 (percentages that sum to ~100):
-    // L1: 90.4%, L2: 7%, L3: 2%, L4: 0.5%, L5: 0.1%
-    // At max bonus (game level 11+):
-    // L1: 40.4%, L2: 25%, L3: 20%, L4: 10%, L5: 5%
-    let chances = [
-        (1u8, 90.4 - level_bonus),              // Level 1 (Common): 90.4% -> 40.4%
-        (2u8, 7.0 + level_bonus * 0.36),        // Level 2 (Uncommon): 7% -> 25%
-        (3u8, 2.0 + level_bonus * 0.36),        // Level 3 (Rare): 2% -> 20%
-        (4u8, 0.5 + level_bonus * 0.19),        // Level 4 (Epic): 0.5% -> 10%
-        (5u8, 0.1 + level_bonus * 0.098),       // Level 5 (Legendary): 0.1% -> 5%
-    ];
-
-    let total: f32 = chances.iter().map(|(_, c)| c).sum();
+/// Sideways spacing between members of a Line formation (e.g. Swarm packs)
+pub const ENEMY_PACK_SPACING: f32 = 1.2;
+
+/// Sideways spacing between members of a stampede
+pub const STAMPEDE_SPACING: f32 = 2.0;
+
+/// Distance from the player at which elite ambushes are marked
+pub const ELITE_AMBUSH_DISTANCE: f32 = 7.0;
+
+/// Size of the ambush warning marker relative to the target_marker mesh
+pub const AMBUSH_MARKER_SCALE: f32 = 10.0;
+
+/// Radius of a Cluster formation
+pub const ENEMY_CLUSTER_RADIUS: f32 = 1.5;
+
+/// Height at which ranged enemy projectiles travel
+pub const ENEMY_PROJECTILE_HEIGHT: f32 = 0.75;
+
+/// Distance on the XZ plane at which an enemy projectile hits the player
+pub const ENEMY_PROJECTILE_HIT_RADIUS: f32 = 0.7;
+
+/// Length of the target_marker mesh, used to stretch charge telegraphs
+pub const CHARGE_TELEGRAPH_MESH_LENGTH: f32 = 0.3;
+
+/// Pick an enemy level (1-5) weighted by a level script's rarity weights.
+pub fn select_enemy_level(rarity: &[f32; ENEMY_LEVEL_COUNT], rng: &mut impl Rng) -> u8 {
+    let total: f32 = rarity.iter().sum();
     let roll = rng.gen_range(0.0..total);
 
     let mut cumulative = 0.0;
-    for (level, chance) in chances {
-        cumulative += chance;
+    for (index, weight) in rarity.iter().enumerate() {
+        cumulative += weight;
         if roll < cumulative {
-            return level;
+            return index as u8 + 1;
         }
     }
     1 // Fallback
 }
 
+/// Random spawn direction and distance, just outside the camera viewport
+fn random_spawn_offset(rng: &mut impl Rng) -> (Vec2, f32) {
+    let angle = rng.gen_range(0.0..TAU);
+    let distance = ENEMY_SPAWN_DISTANCE + rng.gen_range(0.0..5.0);
+    (Vec2::from_angle(angle), distance)
+}
+
+/// Positions of `count` members standing side by side, `spacing` apart,
+/// centered on `center` and facing along `direction`.
+pub fn line_positions(center: Vec2, direction: Vec2, count: u32, spacing: f32) -> Vec<Vec2> {
+    let tangent = direction.perp();
+    (0..count)
+        .map(|member| {
+            let spread = member as f32 - (count - 1) as f32 / 2.0;
+            center + tangent * spread * spacing
+        })
+        .collect()
+}
+
+/// Positions on the XZ plane of a formation's members around the player.
+/// Every member is at least `ENEMY_SPAWN_DISTANCE` away from the player.
+pub fn formation_positions(
+    formation: Formation,
+    count: u32,
+    player_xz: Vec2,
+    rng: &mut impl Rng,
+) -> Vec<Vec2> {
+    match formation {
+        Formation::Line => {
+            // Members spread sideways so every one stays at least `distance` away
+            let (spawn_dir, distance) = random_spawn_offset(rng);
+            line_positions(player_xz + spawn_dir * distance, spawn_dir, count, ENEMY_PACK_SPACING)
+        }
+        Formation::Ring => {
+            let (spawn_dir, distance) = random_spawn_offset(rng);
+            (0..count)
+                .map(|member| {
+                    let turn = Vec2::from_angle(TAU * member as f32 / count as f32);
+                    player_xz + turn.rotate(spawn_dir) * distance
+                })
+                .collect()
+        }
+        Formation::Cluster => {
+            // Pushed out by the cluster radius so no member lands inside `distance`
+            let (spawn_dir, distance) = random_spawn_offset(rng);
+            let center = player_xz + spawn_dir * (distance + ENEMY_CLUSTER_RADIUS);
+            (0..count)
+                .map(|_| {
+                    let offset_dir = Vec2::from_angle(rng.gen_range(0.0..TAU));
+                    center + offset_dir * ENEMY_CLUSTER_RADIUS * rng.gen_range(0.0f32..1.0).sqrt()
+                })
+                .collect()
+        }
+        Formation::Surround => (0..count)
+            .map(|_| {
+                let (spawn_dir, distance) = random_spawn_offset(rng);
+                player_xz + spawn_dir * distance
+            })
+            .collect(),
+    }
+}
+
+/// What to spawn, apart from where
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnemySpawn {
+    pub kind: EnemyKind,
+    /// Enemy level (1-5)
+    pub level: u8,
+    pub elite: bool,
+}
+
+/// Mesh and materials enemies are spawned with. Headless runs have none and
+/// spawn enemies without visuals.
+#[derive(Clone, Copy)]
+pub struct EnemyVisuals<'a> {
+    pub meshes: &'a GameMeshes,
+    pub level_materials: &'a EnemyLevelMaterials,
+    pub kind_materials: Option<&'a EnemyKindMaterials>,
+}
+
+/// Scale of a spawned enemy: larger with level, then adjusted for kind and elites
+pub fn enemy_spawn_scale(spawn: EnemySpawn) -> f32 {
+    let elite_multiplier = if spawn.elite { Elite::SCALE_MULTIPLIER } else { 1.0 };
+    enemy_scale_for_level(spawn.level) * spawn.kind.scale_multiplier() * elite_multiplier
+}
+
+/// Spawn one enemy at `position` (XZ plane) with its kind's stats from the
+/// wave script and its kind's behaviour components.
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    position: Vec2,
+    spawn: EnemySpawn,
+    game_level: u32,
+    wave_script: &WaveScript,
+    visuals: Option<EnemyVisuals>,
+) -> Entity {
+    let EnemySpawn { kind, level, elite } = spawn;
+    let scaling = wave_script.stats(kind);
+    let (health_multiplier, damage_multiplier) = if elite {
+        (Elite::HEALTH_MULTIPLIER, Elite::DAMAGE_MULTIPLIER)
+    } else {
+        (1.0, 1.0)
+    };
+
+    // Spawn enemy as 3D mesh on XZ plane with Y height scaled for mesh center
+    let scale = enemy_spawn_scale(spawn);
+    let mut enemy = commands.spawn((
+        Transform::from_translation(Vec3::new(position.x, ENEMY_Y_HEIGHT * scale, position.y))
+            .with_scale(Vec3::splat(scale)),
+        Enemy {
+            speed: kind.speed(),
+            strength: scaling.damage_for_level(level) * damage_multiplier,
+        },
+        Health::new(scaling.health_for_level(level, game_level) * health_multiplier),
+        Level::new(level),
+        CheckDeath,
+        kind.elemental_resistances(),
+        kind.armor(),
+        kind,
+    ));
+    if elite {
+        enemy.insert(Elite);
+    }
+    if let Some(visuals) = visuals {
+        let material = visuals
+            .kind_materials
+            .and_then(|materials| materials.for_kind(kind))
+            .unwrap_or_else(|| visuals.level_materials.for_level(level));
+        enemy.insert((Mesh3d(visuals.meshes.enemy_for_kind(kind)), MeshMaterial3d(material)));
+    }
+
+    match kind {
+        EnemyKind::Ranged => {
+            enemy.insert(RangedAttacker::default());
+        }
+        EnemyKind::Charger => {
+            enemy.insert(Charger::default());
+        }
+        EnemyKind::Splitter => {
+            // Reuses the Fracture spell's split-on-death handling
+            enemy.insert(FractureEffect);
+        }
+        EnemyKind::Grunt | EnemyKind::Tank | EnemyKind::Swarm => {}
+    }
+    enemy.id()
+}
+
+/// Spawn an ambush warning marker at `position` (XZ plane)
+pub fn spawn_ambush_warning(
+    commands: &mut Commands,
+    position: Vec2,
+    warning: AmbushWarning,
+    game_meshes: Option<&GameMeshes>,
+    kind_materials: Option<&EnemyKindMaterials>,
+) {
+    let transform = Transform::from_translation(to_xz(position) + Vec3::new(0.0, 0.05, 0.0))
+        .with_scale(Vec3::new(AMBUSH_MARKER_SCALE, 1.0, AMBUSH_MARKER_SCALE));
+
+    if let (Some(meshes), Some(materials)) = (game_meshes, kind_materials) {
+        commands.spawn((
+            Mesh3d(meshes.target_marker.clone()),
+            MeshMaterial3d(materials.charge_telegraph.clone()),
+            transform,
+            warning,
+        ));
+    } else {
+        // Fallback for tests without mesh resources
+        commands.spawn((transform, warning));
+    }
+}
+
+/// Run the wave script for the current game level: every open window spawns
+/// its formations at its rate, with enemy levels rolled from the script's
+/// rarity weights and each enemy rolled for elite. Scripted events start
+/// when the level clock reaches them and are announced with `WaveEventStarted`.
+#[allow(clippy::too_many_arguments)]
 pub fn enemy_spawning_system(
     mut commands: Commands,
     player_query: Query<&Transform, With<Player>>,
     mut spawn_state: ResMut<EnemySpawnState>,
     time: Res<Time>,
-    game_meshes: Res<GameMeshes>,
-    enemy_materials: Res<EnemyLevelMaterials>,
+    game_meshes: Option<Res<GameMeshes>>,
+    enemy_materials: Option<Res<EnemyLevelMaterials>>,
+    kind_materials: Option<Res<EnemyKindMaterials>>,
     game_level: Res<GameLevel>,
+    wave_script: Res<WaveScript>,
+    mut rng: ResMut<GameRng>,
+    mut wave_events: MessageWriter<WaveEventStarted>,
 ) {
     let Ok(player_transform) = player_query.single() else {
         return;
     };
 
-    // Update spawn timer
+    // A new level, or a reloaded script with other windows, starts from the top
+    let waves = wave_script.level(game_level.level);
+    if spawn_state.level != game_level.level || spawn_state.window_progress.len() != waves.windows().len() {
+        spawn_state.restart(game_level.level, waves.windows().len());
+    }
+
+    // Advance the level clock by all unspent time
     spawn_state.time_since_last_spawn += time.delta_secs();
+    let from = spawn_state.level_elapsed;
+    let to = from + spawn_state.time_since_last_spawn;
+    spawn_state.level_elapsed = to;
+    spawn_state.time_since_last_spawn = 0.0;
+
+    let player_xz = from_xz(player_transform.translation);
+    let visuals = game_meshes
+        .as_deref()
+        .zip(enemy_materials.as_deref())
+        .map(|(meshes, level_materials)| EnemyVisuals {
+            meshes,
+            level_materials,
+            kind_materials: kind_materials.as_deref(),
+        });
+
+    // Events first, so a horde starting now already speeds up this frame
+    for scheduled in waves.script.events.iter().filter(|scheduled| scheduled.starts_during(from, to)) {
+        match scheduled.event {
+            WaveEvent::ClosingRing { kind, count } => {
+                for position in formation_positions(Formation::Ring, count, player_xz, &mut *rng) {
+                    let spawn = EnemySpawn {
+                        kind,
+                        level: select_enemy_level(&waves.script.rarity, &mut *rng),
+                        elite: false,
+                    };
+                    spawn_enemy(&mut commands, position, spawn, game_level.level, &wave_script, visuals);
+                }
+            }
+            WaveEvent::Stampede { kind, count } => {
+                // Lined up on one side, running past the player to the other
+                let (spawn_dir, distance) = random_spawn_offset(&mut *rng);
+                let center = player_xz + spawn_dir * distance;
+                for position in line_positions(center, spawn_dir, count, STAMPEDE_SPACING) {
+                    let spawn = EnemySpawn {
+                        kind,
+                        level: select_enemy_level(&waves.script.rarity, &mut *rng),
+                        elite: false,
+                    };
+                    let enemy = spawn_enemy(&mut commands, position, spawn, game_level.level, &wave_script, visuals);
+                    commands.entity(enemy).insert(Stampeding {
+                        direction: -spawn_dir,
+                        distance_left: 2.0 * distance,
+                    });
+                }
+            }
+            WaveEvent::EliteAmbush { kind, count } => {
+                let direction = Vec2::from_angle(rng.gen_range(0.0..TAU));
+                spawn_ambush_warning(
+                    &mut commands,
+                    player_xz + direction * ELITE_AMBUSH_DISTANCE,
+                    AmbushWarning::new(kind, count),
+                    game_meshes.as_deref(),
+                    kind_materials.as_deref(),
+                );
+            }
+            WaveEvent::Horde { secs, rate_multiplier } => {
+                spawn_state.horde_until = scheduled.at_secs + secs;
+                spawn_state.horde_rate_multiplier = rate_multiplier;
+            }
+        }
+        wave_events.write(WaveEventStarted { event: scheduled.event });
+    }
+
+    let rate_multiplier = waves.rate_multiplier * spawn_state.rate_multiplier_at(from);
+    for (index, window) in waves.windows().iter().enumerate() {
+        // Whole formations owed for the time the window was open, keeping the remainder
+        let progress = spawn_state.window_progress[index]
+            + window.open_secs(from, to) * window.rate * rate_multiplier;
+        let formations = progress.floor();
+        spawn_state.window_progress[index] = progress - formations;
+
+        for _ in 0..formations as u32 {
+            let entry = window.pick_spawn(&mut *rng);
+            for position in formation_positions(entry.formation, entry.count, player_xz, &mut *rng) {
+                let spawn = EnemySpawn {
+                    kind: entry.kind,
+                    level: select_enemy_level(&waves.script.rarity, &mut *rng),
+                    elite: rng.gen_bool(window.elite_chance as f64),
+                };
+                spawn_enemy(&mut commands, position, spawn, game_level.level, &wave_script, visuals);
+            }
+        }
+    }
+}
+
+/// Run stampeding enemies along their line. Once they have crossed the arena
+/// they lose `Stampeding` and chase the player like the rest.
+/// Frozen and stunned stampeders stop in their tracks.
+#[allow(clippy::type_complexity)]
+pub fn stampede_movement_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<
+        (Entity, &mut Transform, &Enemy, &mut Stampeding),
+        (Without<FrozenStatus>, Without<StunnedEnemy>),
+    >,
+) {
+    for (entity, mut transform, enemy, mut stampeding) in query.iter_mut() {
+        let step = (enemy.speed * Stampeding::SPEED_MULTIPLIER * time.delta_secs()).min(stampeding.distance_left);
+        transform.translation += to_xz(stampeding.direction * step);
+        stampeding.distance_left -= step;
+
+        if stampeding.distance_left <= 0.0 {
+            commands.entity(entity).remove::<Stampeding>();
+        }
+    }
+}
+
+/// Count down ambush warnings and spawn their elites, bunched on the marker.
+#[allow(clippy::too_many_arguments)]
+pub fn elite_ambush_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut warning_query: Query<(Entity, &Transform, &mut AmbushWarning)>,
+    game_meshes: Option<Res<GameMeshes>>,
+    enemy_materials: Option<Res<EnemyLevelMaterials>>,
+    kind_materials: Option<Res<EnemyKindMaterials>>,
+    game_level: Res<GameLevel>,
+    wave_script: Res<WaveScript>,
+    mut rng: ResMut<GameRng>,
+) {
+    let visuals = game_meshes
+        .as_deref()
+        .zip(enemy_materials.as_deref())
+        .map(|(meshes, level_materials)| EnemyVisuals {
+            meshes,
+            level_materials,
+            kind_materials: kind_materials.as_deref(),
+        });
+    let rarity = wave_script.level(game_level.level).script.rarity;
+
+    for (entity, transform, mut warning) in warning_query.iter_mut() {
+        warning.timer.tick(time.delta());
+        if !warning.timer.is_finished() {
+            continue;
+        }
+
+        let center = from_xz(transform.translation);
+        for _ in 0..warning.count {
+            let offset = Vec2::from_angle(rng.gen_range(0.0..TAU)) * rng.gen_range(0.0..ENEMY_CLUSTER_RADIUS);
+            let spawn = EnemySpawn {
+                kind: warning.kind,
+                level: select_enemy_level(&rarity, &mut *rng),
+                elite: true,
+            };
+            spawn_enemy(&mut commands, center + offset, spawn, game_level.level, &wave_script, visuals);
+        }
+        commands.entity(entity).despawn();
+    }
+}
 
-    // Calculate spawn rate based on game level (0.25 * 1.25^(level-1))
-    let spawn_rate = EnemySpawnState::spawn_rate_for_level(game_level.level);
-    let spawn_interval = 1.0 / spawn_rate;
-    let enemies_to_spawn = (spawn_state.time_since_last_spawn / spawn_interval) as usize;
+/// System that fires projectiles from ranged enemies at the player.
+/// Frozen and stunned enemies hold their fire.
+#[allow(clippy::type_complexity)]
+pub fn ranged_enemy_attack_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut ranged_query: Query<
+        (&Transform, &Enemy, &mut RangedAttacker),
+        (Without<FrozenStatus>, Without<StunnedEnemy>),
+    >,
+    player_position: Res<PlayerPosition>,
+    game_meshes: Option<Res<GameMeshes>>,
+    kind_materials: Option<Res<EnemyKindMaterials>>,
+) {
+    let player_pos = player_position.0;
+
+    for (transform, enemy, mut ranged) in ranged_query.iter_mut() {
+        ranged.fire_timer.tick(time.delta());
+        if !ranged.fire_timer.just_finished() {
+            continue;
+        }
 
-    if enemies_to_spawn > 0 {
-        let mut rng = rand::thread_rng();
-        let scaling = EnemyScaling::default();
+        let enemy_pos = from_xz(transform.translation);
+        if enemy_pos.distance(player_pos) > ranged.attack_range {
+            continue;
+        }
 
-        // Player position on XZ plane
-        let player_xz = Vec2::new(
-            player_transform.translation.x,
-            player_transform.translation.z,
+        let projectile = EnemyProjectile::new(
+            player_pos - enemy_pos,
+            ranged.projectile_speed,
+            enemy.strength,
+        );
+        spawn_enemy_projectile(
+            &mut commands,
+            enemy_pos,
+            projectile,
+            game_meshes.as_deref(),
+            kind_materials.as_deref(),
         );
+    }
+}
 
-        for _ in 0..enemies_to_spawn {
-            // Generate random angle and distance for spawning outside view
-            // ENEMY_SPAWN_DISTANCE is set to just outside the camera viewport (~18 units)
-            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-            let distance = ENEMY_SPAWN_DISTANCE + rng.gen_range(0.0..5.0);
-
-            // Calculate spawn position on XZ plane relative to player
-            let spawn_offset = Vec2::new(angle.cos(), angle.sin()) * distance;
-            let spawn_xz = player_xz + spawn_offset;
-
-            // Determine enemy level based on current game level
-            let enemy_level = select_enemy_level(game_level.level, &mut rng);
-
-            // Calculate scale based on enemy level (higher level = larger)
-            let scale = enemy_scale_for_level(enemy_level);
-
-            // Spawn enemy as 3D mesh on XZ plane with Y height scaled for cube center
-            // Y position needs to account for scaled cube height
-            let y_height = ENEMY_Y_HEIGHT * scale;
-            commands.spawn((
-                Mesh3d(game_meshes.enemy.clone()),
-                MeshMaterial3d(enemy_materials.for_level(enemy_level)),
-                Transform::from_translation(Vec3::new(spawn_xz.x, y_height, spawn_xz.y))
-                    .with_scale(Vec3::splat(scale)),
-                Enemy {
-                    speed: 2.6, // 3D world units/sec (+30% from 2.0)
-                    strength: scaling.damage_for_level(enemy_level),
-                },
-                Health::new(scaling.health_for_level(enemy_level, game_level.level)),
-                Level::new(enemy_level),
-                CheckDeath,
-            ));
+/// Spawn an enemy projectile at `origin` (XZ plane), at projectile height.
+/// Shared by ranged enemies and bosses.
+pub fn spawn_enemy_projectile(
+    commands: &mut Commands,
+    origin: Vec2,
+    projectile: EnemyProjectile,
+    game_meshes: Option<&GameMeshes>,
+    kind_materials: Option<&EnemyKindMaterials>,
+) {
+    let spawn_pos = to_xz(origin) + Vec3::new(0.0, ENEMY_PROJECTILE_HEIGHT, 0.0);
+
+    if let (Some(meshes), Some(materials)) = (game_meshes, kind_materials) {
+        commands.spawn((
+            Mesh3d(meshes.enemy_projectile.clone()),
+            MeshMaterial3d(materials.projectile.clone()),
+            Transform::from_translation(spawn_pos),
+            projectile,
+        ));
+    } else {
+        // Fallback for tests without mesh resources
+        commands.spawn((Transform::from_translation(spawn_pos), projectile));
+    }
+}
+
+/// System that moves enemy projectiles and damages the player on contact.
+/// Projectiles pass through the player while Wraith Form is active.
+#[allow(clippy::type_complexity)]
+pub fn enemy_projectile_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut EnemyProjectile)>,
+    player_query: Query<
+        (Entity, &Transform, Option<&WraithForm>),
+        (With<Player>, Without<EnemyProjectile>),
+    >,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    let player = player_query.single().ok();
+
+    for (entity, mut transform, mut projectile) in projectile_query.iter_mut() {
+        transform.translation += to_xz(projectile.direction * projectile.speed * time.delta_secs());
+        projectile.lifetime.tick(time.delta());
+
+        if let Some((player_entity, player_transform, wraith_form)) = player {
+            let distance = from_xz(transform.translation).distance(from_xz(player_transform.translation));
+            if wraith_form.is_none() && distance < ENEMY_PROJECTILE_HIT_RADIUS {
+                damage_events.write(DamageEvent::new(player_entity, projectile.damage));
+                commands.entity(entity).despawn();
+                continue;
+            }
+        }
+
+        if projectile.lifetime.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// System that runs the charger attack cycle: approach, telegraph, dash, recover.
+/// Regular chase movement only applies while approaching (see `enemy_movement_system`).
+#[allow(clippy::type_complexity)]
+pub fn charger_behavior_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut charger_query: Query<(
+        Entity,
+        &mut Transform,
+        &Enemy,
+        &mut Charger,
+        Option<&FrozenStatus>,
+        Option<&StunnedEnemy>,
+    )>,
+    telegraph_query: Query<(Entity, &ChargeTelegraph)>,
+    player_position: Res<PlayerPosition>,
+    game_meshes: Option<Res<GameMeshes>>,
+    kind_materials: Option<Res<EnemyKindMaterials>>,
+) {
+    let player_pos = player_position.0;
+
+    for (entity, mut transform, enemy, mut charger, frozen, stunned) in charger_query.iter_mut() {
+        // Frozen and stunned chargers pause mid-cycle
+        if frozen.is_some() || stunned.is_some() {
+            continue;
+        }
+
+        let enemy_pos = from_xz(transform.translation);
+        charger.timer.tick(time.delta());
+
+        match charger.state {
+            ChargerState::Approaching => {
+                if enemy_pos.distance(player_pos) > charger.trigger_distance {
+                    continue;
+                }
+
+                charger.direction = (player_pos - enemy_pos).normalize_or_zero();
+                charger.enter(ChargerState::Telegraphing);
+
+                // Show the dash path on the ground, centered halfway along it
+                let dash_length = Charger::dash_length(enemy.speed);
+                let telegraph_pos =
+                    to_xz(enemy_pos + charger.direction * dash_length / 2.0) + Vec3::new(0.0, 0.05, 0.0);
+                let telegraph_transform = Transform::from_translation(telegraph_pos)
+                    .with_rotation(Quat::from_rotation_y(charger.direction.x.atan2(charger.direction.y)))
+                    .with_scale(Vec3::new(3.0, 1.0, dash_length / CHARGE_TELEGRAPH_MESH_LENGTH));
+                let telegraph = ChargeTelegraph { owner: entity };
+
+                if let (Some(meshes), Some(materials)) = (game_meshes.as_ref(), kind_materials.as_ref()) {
+                    commands.spawn((
+                        Mesh3d(meshes.target_marker.clone()),
+                        MeshMaterial3d(materials.charge_telegraph.clone()),
+                        telegraph_transform,
+                        telegraph,
+                    ));
+                } else {
+                    // Fallback for tests without mesh resources
+                    commands.spawn((telegraph_transform, telegraph));
+                }
+            }
+            ChargerState::Telegraphing => {
+                if charger.timer.is_finished() {
+                    charger.enter(ChargerState::Dashing);
+                }
+            }
+            ChargerState::Dashing => {
+                let dash_speed = enemy.speed * Charger::DASH_SPEED_MULTIPLIER;
+                transform.translation += to_xz(charger.direction * dash_speed * time.delta_secs());
+                if charger.timer.is_finished() {
+                    charger.enter(ChargerState::Recovering);
+                }
+            }
+            ChargerState::Recovering => {
+                if charger.timer.is_finished() {
+                    charger.enter(ChargerState::Approaching);
+                }
+            }
+        }
+    }
+
+    // Remove telegraphs once their charger dashes or dies
+    for (telegraph_entity, telegraph) in telegraph_query.iter() {
+        let still_telegraphing = charger_query
+            .get(telegraph.owner)
+            .is_ok_and(|(_, _, _, charger, _, _)| charger.state == ChargerState::Telegraphing);
+        if !still_telegraphing {
+            commands.entity(telegraph_entity).despawn();
+        }
+    }
+}
+
+/// Roll `EliteAffix::count_for_level(level)` different affixes for an elite.
+/// Ranged elites never touch the player, so they don't roll Vampiric.
+pub fn roll_elite_affixes(kind: EnemyKind, level: u8, rng: &mut impl Rng) -> Vec<EliteAffix> {
+    let mut pool: Vec<EliteAffix> = EliteAffix::BASIC
+        .into_iter()
+        .filter(|affix| !(kind == EnemyKind::Ranged && *affix == EliteAffix::Vampiric))
+        .collect();
+    let elements = Element::all();
+    pool.push(EliteAffix::ElementalAura(elements[rng.gen_range(0..elements.len())]));
+
+    let count = EliteAffix::count_for_level(level).min(pool.len());
+    (0..count)
+        .map(|_| pool.swap_remove(rng.gen_range(0..pool.len())))
+        .collect()
+}
+
+/// System that rolls affixes onto newly spawned elites and gives each affix
+/// its behaviour component.
+#[allow(clippy::type_complexity)]
+pub fn elite_affix_system(
+    mut commands: Commands,
+    mut elite_query: Query<
+        (Entity, &EnemyKind, &Level, &mut Enemy, &Health),
+        (Added<Elite>, Without<EliteAffixes>),
+    >,
+    mut rng: ResMut<GameRng>,
+) {
+    for (entity, kind, level, mut enemy, health) in elite_query.iter_mut() {
+        let affixes = roll_elite_affixes(*kind, level.value(), &mut *rng);
+        let mut elite = commands.entity(entity);
+        for affix in &affixes {
+            match affix {
+                EliteAffix::Shielded => {
+                    elite.insert(DamageShield::new(health.max * EliteAffix::SHIELD_HEALTH_FRACTION));
+                }
+                EliteAffix::Vampiric => {
+                    elite.insert(Vampiric::default());
+                }
+                EliteAffix::Explosive => {
+                    elite.insert(Explosive::new(enemy.strength));
+                }
+                EliteAffix::Hasted => {
+                    enemy.speed *= EliteAffix::HASTE_SPEED_MULTIPLIER;
+                }
+                EliteAffix::Teleporting => {
+                    elite.insert(Teleporting::default());
+                }
+                EliteAffix::ElementalAura(element) => {
+                    elite.insert(ElementalAura::new(*element));
+                }
+            }
+        }
+        elite.insert(EliteAffixes(affixes));
+    }
+}
+
+/// System that gives elites one glowing orb per affix, circling above them.
+/// Headless runs have no visuals and skip the orbs.
+pub fn spawn_elite_affix_orbs_system(
+    mut commands: Commands,
+    elite_query: Query<(Entity, &EliteAffixes), Added<EliteAffixes>>,
+    game_meshes: Option<Res<GameMeshes>>,
+    affix_materials: Option<Res<EliteAffixMaterials>>,
+) {
+    let (Some(meshes), Some(materials)) = (game_meshes, affix_materials) else {
+        return;
+    };
+
+    for (entity, affixes) in elite_query.iter() {
+        let count = affixes.0.len();
+        commands.entity(entity).with_children(|parent| {
+            for (index, affix) in affixes.0.iter().enumerate() {
+                let orb = EliteAffixOrb { index, count };
+                parent.spawn((
+                    Mesh3d(meshes.enemy_projectile.clone()),
+                    MeshMaterial3d(materials.for_affix(*affix)),
+                    Transform::from_translation(orb.position(0.0)),
+                    orb,
+                ));
+            }
+        });
+    }
+}
+
+/// System that circles affix orbs above their elite
+pub fn elite_affix_orb_system(time: Res<Time>, mut orb_query: Query<(&mut Transform, &EliteAffixOrb)>) {
+    let elapsed = time.elapsed_secs();
+    for (mut transform, orb) in orb_query.iter_mut() {
+        transform.translation = orb.position(elapsed);
+    }
+}
+
+/// Spawn an expanding burst at `center` (XZ plane), with the given mesh and
+/// material when visuals are available.
+fn spawn_affix_burst(
+    commands: &mut Commands,
+    center: Vec2,
+    radius: f32,
+    visuals: Option<(Handle<Mesh>, Handle<StandardMaterial>)>,
+) {
+    let burst = ReactionBurst::new(radius);
+    let burst_transform = Transform::from_translation(Vec3::new(center.x, 0.5, center.y))
+        .with_scale(Vec3::splat(burst.radius()));
+    if let Some((mesh, material)) = visuals {
+        commands.spawn((Mesh3d(mesh), MeshMaterial3d(material), burst_transform, burst));
+    } else {
+        // Fallback for tests without mesh resources
+        commands.spawn((burst_transform, burst));
+    }
+}
+
+/// System that blinks Teleporting elites next to the player whenever they
+/// have fallen too far behind. Frozen and stunned elites stay put.
+#[allow(clippy::type_complexity)]
+pub fn teleporting_elite_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut elite_query: Query<
+        (&mut Transform, &mut Teleporting),
+        (Without<FrozenStatus>, Without<StunnedEnemy>),
+    >,
+    player_position: Res<PlayerPosition>,
+    game_meshes: Option<Res<GameMeshes>>,
+    affix_materials: Option<Res<EliteAffixMaterials>>,
+    mut rng: ResMut<GameRng>,
+) {
+    let player_pos = player_position.0;
+    let visuals = game_meshes.as_deref().zip(affix_materials.as_deref()).map(|(meshes, materials)| {
+        (meshes.explosion.clone(), materials.for_affix(EliteAffix::Teleporting))
+    });
+
+    for (mut transform, mut teleporting) in elite_query.iter_mut() {
+        teleporting.timer.tick(time.delta());
+        if !teleporting.timer.just_finished() {
+            continue;
+        }
+
+        let from = from_xz(transform.translation);
+        if from.distance(player_pos) <= Teleporting::MIN_DISTANCE {
+            continue;
         }
 
-        // Reset the spawn timer (subtract the time we've accounted for)
-        spawn_state.time_since_last_spawn -= enemies_to_spawn as f32 * spawn_interval;
+        let to = player_pos + Vec2::from_angle(rng.gen_range(0.0..TAU)) * Teleporting::BLINK_DISTANCE;
+        transform.translation.x = to.x;
+        transform.translation.z = to.y;
+        spawn_affix_burst(&mut commands, from, 1.0, visuals.clone());
+        spawn_affix_burst(&mut commands, to, 1.0, visuals.clone());
+    }
+}
+
+/// System that pulses elemental auras onto nearby enemies. Infusions wear
+/// off `AuraInfused::DURATION_SECS` after the last pulse that reached them.
+pub fn elemental_aura_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut aura_query: Query<(&Transform, &mut ElementalAura)>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    mut infused_query: Query<(Entity, &mut AuraInfused)>,
+) {
+    for (entity, mut infused) in infused_query.iter_mut() {
+        infused.remaining -= time.delta_secs();
+        if infused.remaining <= 0.0 {
+            commands.entity(entity).try_remove::<AuraInfused>();
+        }
+    }
+
+    for (aura_transform, mut aura) in aura_query.iter_mut() {
+        aura.pulse_timer.tick(time.delta());
+        if !aura.pulse_timer.just_finished() {
+            continue;
+        }
+
+        let center = from_xz(aura_transform.translation);
+        for (entity, transform) in enemy_query.iter() {
+            if from_xz(transform.translation).distance(center) <= ElementalAura::RADIUS {
+                commands.entity(entity).try_insert(AuraInfused::new(aura.element));
+            }
+        }
+    }
+}
+
+/// System that detonates Explosive elites where they die, hurting the player
+/// if caught in the blast. Must run before handle_enemy_death_system despawns them.
+pub fn explosive_on_death_system(
+    mut commands: Commands,
+    mut death_events: MessageReader<DeathEvent>,
+    explosive_query: Query<&Explosive>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    mut damage_events: MessageWriter<DamageEvent>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+) {
+    let visuals = game_meshes
+        .as_deref()
+        .zip(game_materials.as_deref())
+        .map(|(meshes, materials)| (meshes.explosion.clone(), materials.fire_nova.clone()));
+
+    for event in death_events.read() {
+        if event.entity_type != EntityType::Enemy {
+            continue;
+        }
+        let Ok(explosive) = explosive_query.get(event.entity) else {
+            continue;
+        };
+
+        let center = from_xz(event.position);
+        if let Ok((player_entity, player_transform)) = player_query.single() {
+            if from_xz(player_transform.translation).distance(center) <= explosive.radius {
+                damage_events.write(DamageEvent::with_source_and_element(
+                    player_entity,
+                    explosive.damage,
+                    event.entity,
+                    Element::Fire,
+                ));
+            }
+        }
+        spawn_affix_burst(&mut commands, center, explosive.radius, visuals.clone());
     }
 }
 
@@ -138,6 +849,9 @@ mod tests {
         app.init_asset::<StandardMaterial>();
         app.init_resource::<EnemySpawnState>();
         app.init_resource::<GameLevel>();
+        app.init_resource::<GameRng>();
+        app.init_resource::<WaveScript>();
+        app.add_message::<WaveEventStarted>();
         app
     }
 
@@ -148,18 +862,25 @@ mod tests {
 
         let mut materials = app.world_mut().resource_mut::<Assets<StandardMaterial>>();
         let enemy_materials = EnemyLevelMaterials::new(&mut materials);
+        let kind_materials = EnemyKindMaterials::new(&mut materials);
         app.world_mut().insert_resource(enemy_materials);
+        app.world_mut().insert_resource(kind_materials);
     }
 
     mod select_enemy_level_tests {
         use super::*;
 
+        /// Rarity weights of the built-in script at a game level
+        fn rarity(game_level: u32) -> [f32; ENEMY_LEVEL_COUNT] {
+            WaveScript::default().level(game_level).script.rarity
+        }
+
         #[test]
         fn returns_valid_level_range() {
             let mut rng = StdRng::seed_from_u64(12345);
             for game_level in 1..=10 {
                 for _ in 0..100 {
-                    let enemy_level = select_enemy_level(game_level, &mut rng);
+                    let enemy_level = select_enemy_level(&rarity(game_level), &mut rng);
                     assert!(enemy_level >= 1 && enemy_level <= 5,
                         "Enemy level {} should be between 1 and 5", enemy_level);
                 }
@@ -172,7 +893,7 @@ mod tests {
             let mut level_counts = [0u32; 5];
 
             for _ in 0..1000 {
-                let enemy_level = select_enemy_level(1, &mut rng);
+                let enemy_level = select_enemy_level(&rarity(1), &mut rng);
                 level_counts[(enemy_level - 1) as usize] += 1;
             }
 
@@ -188,7 +909,7 @@ mod tests {
             // Count level 3+ enemies at game level 1
             let mut high_tier_at_level_1 = 0;
             for _ in 0..1000 {
-                let enemy_level = select_enemy_level(1, &mut rng);
+                let enemy_level = select_enemy_level(&rarity(1), &mut rng);
                 if enemy_level >= 3 {
                     high_tier_at_level_1 += 1;
                 }
@@ -200,7 +921,7 @@ mod tests {
             // Count level 3+ enemies at game level 9 (max level bonus)
             let mut high_tier_at_level_9 = 0;
             for _ in 0..1000 {
-                let enemy_level = select_enemy_level(9, &mut rng);
+                let enemy_level = select_enemy_level(&rarity(9), &mut rng);
                 if enemy_level >= 3 {
                     high_tier_at_level_9 += 1;
                 }
@@ -218,7 +939,7 @@ mod tests {
 
             // At high game level, all enemy levels should be possible
             for _ in 0..10000 {
-                let enemy_level = select_enemy_level(10, &mut rng);
+                let enemy_level = select_enemy_level(&rarity(10), &mut rng);
                 spawned_levels[(enemy_level - 1) as usize] = true;
 
                 if spawned_levels.iter().all(|&x| x) {
@@ -232,10 +953,8 @@ mod tests {
         }
 
         #[test]
-        fn level_bonus_caps_at_50_percent() {
-            // At game level 11+, level_bonus should be capped at 50.0
-            // This means: (11-1) * 5.0 = 50.0 (capped)
-            // Level 12 would be: (12-1) * 5.0 = 55.0 but capped to 50.0
+        fn rarity_stops_shifting_at_game_level_11() {
+            // The last level script (game level 11) is reused for every later level
             let mut rng1 = StdRng::seed_from_u64(42);
             let mut rng2 = StdRng::seed_from_u64(42);
 
@@ -244,8 +963,8 @@ mod tests {
             let mut results_level_20 = Vec::new();
 
             for _ in 0..100 {
-                results_level_11.push(select_enemy_level(11, &mut rng1));
-                results_level_20.push(select_enemy_level(20, &mut rng2));
+                results_level_11.push(select_enemy_level(&rarity(11), &mut rng1));
+                results_level_20.push(select_enemy_level(&rarity(20), &mut rng2));
             }
 
             // Should be identical due to cap
@@ -437,9 +1156,9 @@ mod tests {
         // Verify enemy Y position is scaled (ENEMY_Y_HEIGHT * scale)
         // Level 1 scale = 0.75, so Y = 0.75 * 0.75 = 0.5625
         // Level 5 scale = 1.35, so Y = 0.75 * 1.35 = 1.0125
-        let mut query = app.world_mut().query::<(&Transform, &Enemy, &Level)>();
-        for (transform, _, level) in query.iter(app.world()) {
-            let scale = enemy_scale_for_level(level.value());
+        let mut query = app.world_mut().query::<(&Transform, &EnemyKind, &Level, Has<Elite>)>();
+        for (transform, kind, level, elite) in query.iter(app.world()) {
+            let scale = enemy_spawn_scale(EnemySpawn { kind: *kind, level: level.value(), elite });
             let expected_y = ENEMY_Y_HEIGHT * scale;
             assert!(
                 (transform.translation.y - expected_y).abs() < 0.01,
@@ -682,13 +1401,19 @@ mod tests {
 
         let _ = app.world_mut().run_system_once(enemy_spawning_system);
 
-        // Verify enemy stats match their level (game level is 25 for this test)
-        let scaling = EnemyScaling::default();
+        // Verify enemy stats match their kind, level and elite status (game level is 25 for this test)
         let game_level = 25u32;
-        let mut query = app.world_mut().query::<(&Enemy, &Health, &Level)>();
-        for (enemy, health, level) in query.iter(app.world()) {
-            let expected_health = scaling.health_for_level(level.value(), game_level);
-            let expected_damage = scaling.damage_for_level(level.value());
+        let wave_script = WaveScript::default();
+        let mut query = app.world_mut().query::<(&Enemy, &Health, &Level, &EnemyKind, Has<Elite>)>();
+        for (enemy, health, level, kind, elite) in query.iter(app.world()) {
+            let scaling = wave_script.stats(*kind);
+            let (health_multiplier, damage_multiplier) = if elite {
+                (Elite::HEALTH_MULTIPLIER, Elite::DAMAGE_MULTIPLIER)
+            } else {
+                (1.0, 1.0)
+            };
+            let expected_health = scaling.health_for_level(level.value(), game_level) * health_multiplier;
+            let expected_damage = scaling.damage_for_level(level.value()) * damage_multiplier;
 
             assert!(
                 (health.max - expected_health).abs() < 0.01,
@@ -738,10 +1463,10 @@ mod tests {
 
         let _ = app.world_mut().run_system_once(enemy_spawning_system);
 
-        // Verify enemy scale matches their level
-        let mut query = app.world_mut().query::<(&Transform, &Enemy, &Level)>();
-        for (transform, _, level) in query.iter(app.world()) {
-            let expected_scale = enemy_scale_for_level(level.value());
+        // Verify enemy scale matches their level, kind and elite status
+        let mut query = app.world_mut().query::<(&Transform, &EnemyKind, &Level, Has<Elite>)>();
+        for (transform, kind, level, elite) in query.iter(app.world()) {
+            let expected_scale = enemy_spawn_scale(EnemySpawn { kind: *kind, level: level.value(), elite });
             assert!(
                 (transform.scale.x - expected_scale).abs() < 0.01,
                 "Enemy level {} should have scale {}, got {}",
@@ -795,22 +1520,641 @@ mod tests {
         let _ = app.world_mut().run_system_once(enemy_spawning_system);
 
         // Collect enemy data first to avoid borrow issues
-        let enemy_data: Vec<(Handle<StandardMaterial>, u8)> = {
-            let mut query = app.world_mut().query::<(&MeshMaterial3d<StandardMaterial>, &Level)>();
+        let enemy_data: Vec<(Handle<StandardMaterial>, u8, EnemyKind)> = {
+            let mut query = app.world_mut().query::<(&MeshMaterial3d<StandardMaterial>, &Level, &EnemyKind)>();
             query.iter(app.world())
-                .map(|(mat, level)| (mat.0.clone(), level.value()))
+                .map(|(mat, level, kind)| (mat.0.clone(), level.value(), *kind))
                 .collect()
         };
 
-        // Verify enemies have material handles that match their level
+        // Verify grunts use their level material and other kinds use their kind material
         let enemy_materials = app.world().resource::<EnemyLevelMaterials>();
-        for (material, level) in enemy_data {
-            let expected_material = enemy_materials.for_level(level);
+        let kind_materials = app.world().resource::<EnemyKindMaterials>();
+        for (material, level, kind) in enemy_data {
+            let expected_material = kind_materials
+                .for_kind(kind)
+                .unwrap_or_else(|| enemy_materials.for_level(level));
             assert_eq!(
                 material, expected_material,
-                "Enemy level {} should have correct material",
-                level
+                "{:?} enemy level {} should have correct material",
+                kind, level
+            );
+        }
+    }
+
+    mod formation_tests {
+        use super::*;
+
+        const FORMATIONS: [Formation; 4] =
+            [Formation::Line, Formation::Ring, Formation::Cluster, Formation::Surround];
+
+        #[test]
+        fn every_formation_spawns_count_enemies_outside_the_viewport() {
+            let mut rng = StdRng::seed_from_u64(5);
+            let player_xz = Vec2::new(3.0, -4.0);
+            for formation in FORMATIONS {
+                for count in [1, 3, 8] {
+                    let positions = formation_positions(formation, count, player_xz, &mut rng);
+                    assert_eq!(positions.len(), count as usize, "{:?}", formation);
+                    for position in positions {
+                        assert!(
+                            position.distance(player_xz) >= ENEMY_SPAWN_DISTANCE - 0.001,
+                            "{:?} member at {} is too close",
+                            formation, position.distance(player_xz)
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn ring_surrounds_the_player_evenly() {
+            let mut rng = StdRng::seed_from_u64(5);
+            let positions = formation_positions(Formation::Ring, 4, Vec2::ZERO, &mut rng);
+            let center: Vec2 = positions.iter().copied().sum::<Vec2>() / 4.0;
+            assert!(center.length() < 0.001, "Ring should be centered on the player, got {}", center);
+            let radius = positions[0].length();
+            assert!(positions.iter().all(|position| (position.length() - radius).abs() < 0.001));
+        }
+
+        #[test]
+        fn line_members_are_spaced_side_by_side() {
+            let mut rng = StdRng::seed_from_u64(5);
+            let positions = formation_positions(Formation::Line, 3, Vec2::ZERO, &mut rng);
+            assert!((positions[0].distance(positions[1]) - ENEMY_PACK_SPACING).abs() < 0.001);
+            assert!((positions[0].distance(positions[2]) - 2.0 * ENEMY_PACK_SPACING).abs() < 0.001);
+        }
+
+        #[test]
+        fn cluster_members_stay_close_together() {
+            let mut rng = StdRng::seed_from_u64(5);
+            let positions = formation_positions(Formation::Cluster, 6, Vec2::ZERO, &mut rng);
+            for a in &positions {
+                for b in &positions {
+                    assert!(a.distance(*b) <= 2.0 * ENEMY_CLUSTER_RADIUS + 0.001);
+                }
+            }
+        }
+
+        #[test]
+        fn all_kinds_can_spawn_at_high_game_level() {
+            let wave_script = WaveScript::default();
+            let window = &wave_script.level(15).windows()[0];
+            let mut rng = StdRng::seed_from_u64(7);
+            let mut seen = std::collections::HashSet::new();
+            for _ in 0..2000 {
+                seen.insert(window.pick_spawn(&mut rng).kind);
+            }
+            assert_eq!(seen.len(), EnemyKind::all().len());
+        }
+    }
+
+    mod enemy_kind_spawn_tests {
+        use super::*;
+
+        fn spawn_with_seed(seed: u64) -> Vec<(EnemyKind, Vec3)> {
+            let mut app = setup_test_app();
+            setup_game_resources(&mut app);
+            app.insert_resource(GameRng::from_seed(seed));
+            app.world_mut().spawn((
+                Transform::default(),
+                Player {
+                    speed: 200.0,
+                    regen_rate: 1.0,
+                    pickup_radius: 50.0,
+                    last_movement_direction: Vec3::ZERO,
+                },
+                Health::new(100.0),
+            ));
+            app.world_mut().resource_mut::<GameLevel>().level = 25;
+            app.world_mut().resource_mut::<EnemySpawnState>().time_since_last_spawn = 1.0;
+
+            let _ = app.world_mut().run_system_once(enemy_spawning_system);
+
+            let mut query = app.world_mut().query::<(Entity, &EnemyKind, &Transform)>();
+            let mut spawned: Vec<_> = query
+                .iter(app.world())
+                .map(|(entity, kind, transform)| (entity, *kind, transform.translation))
+                .collect();
+            spawned.sort_by_key(|(entity, _, _)| *entity);
+            spawned.into_iter().map(|(_, kind, position)| (kind, position)).collect()
+        }
+
+        #[test]
+        fn same_seed_spawns_identical_enemies() {
+            let first = spawn_with_seed(1234);
+            assert!(!first.is_empty());
+            assert_eq!(first, spawn_with_seed(1234));
+        }
+
+        #[test]
+        fn spawned_enemies_carry_kind_behaviour_components() {
+            let mut app = setup_test_app();
+            setup_game_resources(&mut app);
+            app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+                Player {
+                    speed: 200.0,
+                    regen_rate: 1.0,
+                    pickup_radius: 50.0,
+                    last_movement_direction: Vec3::ZERO,
+                },
+                Health::new(100.0),
+            ));
+            app.world_mut().resource_mut::<GameLevel>().level = 25;
+            app.world_mut().resource_mut::<EnemySpawnState>().time_since_last_spawn = 1.0;
+
+            let _ = app.world_mut().run_system_once(enemy_spawning_system);
+
+            let mut query = app.world_mut().query::<(Entity, &EnemyKind, &Enemy)>();
+            let spawned: Vec<_> = query.iter(app.world()).map(|(e, k, en)| (e, *k, en.speed)).collect();
+            assert!(!spawned.is_empty());
+            for (entity, kind, speed) in spawned {
+                assert_eq!(speed, kind.speed());
+                let world = app.world();
+                assert_eq!(world.get::<RangedAttacker>(entity).is_some(), kind == EnemyKind::Ranged);
+                assert_eq!(world.get::<Charger>(entity).is_some(), kind == EnemyKind::Charger);
+                assert_eq!(world.get::<FractureEffect>(entity).is_some(), kind == EnemyKind::Splitter);
+            }
+        }
+    }
+
+    mod wave_event_tests {
+        use super::*;
+        use std::time::Duration;
+
+        fn spawn_player(app: &mut App) {
+            app.world_mut().spawn((
+                Transform::default(),
+                Player {
+                    speed: 200.0,
+                    regen_rate: 1.0,
+                    pickup_radius: 50.0,
+                    last_movement_direction: Vec3::ZERO,
+                },
+                Health::new(100.0),
+            ));
+        }
+
+        /// Put the spawn clock of `game_level` at `level_elapsed`, with `secs` to spend
+        fn set_level_clock(app: &mut App, game_level: u32, level_elapsed: f32, secs: f32) {
+            app.world_mut().resource_mut::<GameLevel>().level = game_level;
+            let window_count = app.world().resource::<WaveScript>().level(game_level).windows().len();
+            let mut spawn_state = app.world_mut().resource_mut::<EnemySpawnState>();
+            spawn_state.restart(game_level, window_count);
+            spawn_state.level_elapsed = level_elapsed;
+            spawn_state.time_since_last_spawn = secs;
+        }
+
+        fn started_events(app: &App) -> Vec<WaveEvent> {
+            let messages = app.world().resource::<Messages<WaveEventStarted>>();
+            messages.iter_current_update_messages().map(|started| started.event).collect()
+        }
+
+        #[test]
+        fn scripted_event_starts_once_and_is_announced() {
+            let mut app = setup_test_app();
+            spawn_player(&mut app);
+
+            // The built-in level 2 script closes a ring of 10 Grunts in at 60 seconds
+            set_level_clock(&mut app, 2, 59.5, 1.0);
+            let _ = app.world_mut().run_system_once(enemy_spawning_system);
+            assert_eq!(
+                started_events(&app),
+                vec![WaveEvent::ClosingRing { kind: EnemyKind::Grunt, count: 10 }]
             );
+            let mut query = app.world_mut().query::<&EnemyKind>();
+            let grunts = query.iter(app.world()).filter(|kind| **kind == EnemyKind::Grunt).count();
+            assert!(grunts >= 10, "Ring should spawn 10 Grunts, got {}", grunts);
+
+            app.world_mut().resource_mut::<Messages<WaveEventStarted>>().update();
+            app.world_mut().resource_mut::<EnemySpawnState>().time_since_last_spawn = 1.0;
+            let _ = app.world_mut().run_system_once(enemy_spawning_system);
+            assert!(started_events(&app).is_empty());
+        }
+
+        #[test]
+        fn stampede_enemies_start_on_one_side_and_run_past_the_player() {
+            let mut app = setup_test_app();
+            spawn_player(&mut app);
+
+            // The built-in level 3 script stampedes 12 Swarms at 75 seconds
+            set_level_clock(&mut app, 3, 75.0, 0.01);
+            let _ = app.world_mut().run_system_once(enemy_spawning_system);
+
+            let mut query = app.world_mut().query::<(&Transform, &Stampeding)>();
+            let stampeders: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(stampeders.len(), 12);
+            for (transform, stampeding) in stampeders {
+                let position = from_xz(transform.translation);
+                assert!(position.length() >= ENEMY_SPAWN_DISTANCE - 0.001);
+                // Heading toward the player's side of the arena
+                assert!(stampeding.direction.dot(position) < 0.0);
+                assert!(stampeding.distance_left >= 2.0 * ENEMY_SPAWN_DISTANCE);
+            }
+        }
+
+        #[test]
+        fn stampeders_join_the_chase_after_their_run() {
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin);
+            let enemy = app
+                .world_mut()
+                .spawn((
+                    Transform::default(),
+                    Enemy { speed: 2.0, strength: 5.0 },
+                    Stampeding { direction: Vec2::X, distance_left: 1.0 },
+                ))
+                .id();
+
+            app.world_mut().resource_mut::<Time<()>>().advance_by(Duration::from_secs_f32(0.1));
+            let _ = app.world_mut().run_system_once(stampede_movement_system);
+            let x = app.world().get::<Transform>(enemy).unwrap().translation.x;
+            assert!((x - 2.0 * Stampeding::SPEED_MULTIPLIER * 0.1).abs() < 0.001);
+
+            app.world_mut().resource_mut::<Time<()>>().advance_by(Duration::from_secs_f32(1.0));
+            let _ = app.world_mut().run_system_once(stampede_movement_system);
+            assert!((app.world().get::<Transform>(enemy).unwrap().translation.x - 1.0).abs() < 0.001);
+            assert!(app.world().get::<Stampeding>(enemy).is_none());
+        }
+
+        #[test]
+        fn ambush_warns_before_spawning_elites() {
+            let mut app = setup_test_app();
+            let warning = app
+                .world_mut()
+                .spawn((Transform::from_xyz(4.0, 0.05, 3.0), AmbushWarning::new(EnemyKind::Grunt, 2)))
+                .id();
+
+            let _ = app.world_mut().run_system_once(elite_ambush_system);
+            let mut query = app.world_mut().query::<&Enemy>();
+            assert_eq!(query.iter(app.world()).count(), 0, "Elites should wait for the warning");
+
+            app.world_mut()
+                .get_mut::<AmbushWarning>(warning)
+                .unwrap()
+                .timer
+                .tick(Duration::from_secs_f32(AmbushWarning::WARNING_SECS));
+            let _ = app.world_mut().run_system_once(elite_ambush_system);
+
+            let mut query = app.world_mut().query::<(&Transform, &EnemyKind, Has<Elite>)>();
+            let spawned: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(spawned.len(), 2);
+            for (transform, kind, elite) in spawned {
+                assert_eq!(*kind, EnemyKind::Grunt);
+                assert!(elite);
+                assert!(from_xz(transform.translation).distance(Vec2::new(4.0, 3.0)) <= ENEMY_CLUSTER_RADIUS);
+            }
+            assert!(app.world().get_entity(warning).is_err());
+        }
+
+        #[test]
+        fn horde_doubles_the_spawn_rate() {
+            let progress_after_half_second = |horde: bool| {
+                let mut app = setup_test_app();
+                spawn_player(&mut app);
+                set_level_clock(&mut app, 1, 10.0, 0.5);
+                if horde {
+                    let mut spawn_state = app.world_mut().resource_mut::<EnemySpawnState>();
+                    spawn_state.horde_until = 60.0;
+                    spawn_state.horde_rate_multiplier = 2.0;
+                }
+                let _ = app.world_mut().run_system_once(enemy_spawning_system);
+                app.world().resource::<EnemySpawnState>().window_progress[0]
+            };
+
+            // Level 1 spawns 0.504 formations per second
+            assert!((progress_after_half_second(false) - 0.252).abs() < 0.001);
+            assert!((progress_after_half_second(true) - 0.504).abs() < 0.001);
         }
     }
-}
\ No newline at end of file
+
+    mod elite_affix_tests {
+        use super::*;
+        use std::time::Duration;
+
+        fn spawn_elite(app: &mut App, kind: EnemyKind, level: u8) -> Entity {
+            app.world_mut()
+                .spawn((
+                    Transform::default(),
+                    Enemy { speed: 2.0, strength: 10.0 },
+                    Health::new(100.0),
+                    Level::new(level),
+                    kind,
+                    Elite,
+                ))
+                .id()
+        }
+
+        #[test]
+        fn rolls_distinct_affixes_by_level() {
+            let mut rng = StdRng::seed_from_u64(3);
+            for level in 1..=5 {
+                for _ in 0..50 {
+                    let affixes = roll_elite_affixes(EnemyKind::Grunt, level, &mut rng);
+                    assert_eq!(affixes.len(), EliteAffix::count_for_level(level));
+                    for (index, affix) in affixes.iter().enumerate() {
+                        assert!(!affixes[index + 1..].contains(affix), "Duplicate affix in {:?}", affixes);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn ranged_elites_are_never_vampiric() {
+            let mut rng = StdRng::seed_from_u64(5);
+            for _ in 0..200 {
+                let affixes = roll_elite_affixes(EnemyKind::Ranged, 5, &mut rng);
+                assert!(!affixes.contains(&EliteAffix::Vampiric));
+            }
+        }
+
+        #[test]
+        fn elites_get_affix_components() {
+            let mut app = setup_test_app();
+            let elites: Vec<Entity> = (0..20).map(|_| spawn_elite(&mut app, EnemyKind::Grunt, 5)).collect();
+            let normal = app
+                .world_mut()
+                .spawn((Transform::default(), Enemy { speed: 2.0, strength: 10.0 }, Health::new(100.0), Level::new(5)))
+                .id();
+
+            let _ = app.world_mut().run_system_once(elite_affix_system);
+
+            assert!(app.world().get::<EliteAffixes>(normal).is_none());
+            for elite in elites {
+                let affixes = app.world().get::<EliteAffixes>(elite).unwrap().clone();
+                assert_eq!(affixes.count(), 3);
+                let world = app.world();
+                assert_eq!(affixes.has(EliteAffix::Shielded), world.get::<DamageShield>(elite).is_some());
+                assert_eq!(affixes.has(EliteAffix::Vampiric), world.get::<Vampiric>(elite).is_some());
+                assert_eq!(affixes.has(EliteAffix::Explosive), world.get::<Explosive>(elite).is_some());
+                assert_eq!(affixes.has(EliteAffix::Teleporting), world.get::<Teleporting>(elite).is_some());
+                let has_aura = affixes.0.iter().any(|affix| matches!(affix, EliteAffix::ElementalAura(_)));
+                assert_eq!(has_aura, world.get::<ElementalAura>(elite).is_some());
+                let expected_speed = if affixes.has(EliteAffix::Hasted) {
+                    2.0 * EliteAffix::HASTE_SPEED_MULTIPLIER
+                } else {
+                    2.0
+                };
+                assert_eq!(world.get::<Enemy>(elite).unwrap().speed, expected_speed);
+                if let Some(shield) = world.get::<DamageShield>(elite) {
+                    assert_eq!(shield.remaining, 100.0 * EliteAffix::SHIELD_HEALTH_FRACTION);
+                }
+            }
+        }
+
+        #[test]
+        fn affix_orbs_follow_the_affixes() {
+            let mut app = setup_test_app();
+            setup_game_resources(&mut app);
+            let mut materials = app.world_mut().resource_mut::<Assets<StandardMaterial>>();
+            let affix_materials = EliteAffixMaterials::new(&mut materials);
+            app.world_mut().insert_resource(affix_materials);
+            let elite = app
+                .world_mut()
+                .spawn((Transform::default(), EliteAffixes(vec![EliteAffix::Shielded, EliteAffix::Hasted])))
+                .id();
+
+            let _ = app.world_mut().run_system_once(spawn_elite_affix_orbs_system);
+
+            let mut query = app.world_mut().query::<(&EliteAffixOrb, &ChildOf)>();
+            let orbs: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(orbs.len(), 2);
+            assert!(orbs.iter().all(|(orb, child_of)| orb.count == 2 && child_of.parent() == elite));
+        }
+
+        #[test]
+        fn teleporting_elite_blinks_next_to_a_distant_player() {
+            let mut app = setup_test_app();
+            app.insert_resource(PlayerPosition(Vec2::ZERO));
+            let far = app
+                .world_mut()
+                .spawn((Transform::from_xyz(20.0, ENEMY_Y_HEIGHT, 0.0), Teleporting::default()))
+                .id();
+            let near = app
+                .world_mut()
+                .spawn((Transform::from_xyz(5.0, ENEMY_Y_HEIGHT, 0.0), Teleporting::default()))
+                .id();
+            for entity in [far, near] {
+                app.world_mut()
+                    .get_mut::<Teleporting>(entity)
+                    .unwrap()
+                    .timer
+                    .set_elapsed(Duration::from_secs_f32(Teleporting::INTERVAL_SECS - 0.001));
+            }
+            app.world_mut().resource_mut::<Time<()>>().advance_by(Duration::from_secs_f32(0.01));
+
+            let _ = app.world_mut().run_system_once(teleporting_elite_system);
+
+            let far_transform = app.world().get::<Transform>(far).unwrap();
+            assert!((from_xz(far_transform.translation).length() - Teleporting::BLINK_DISTANCE).abs() < 0.001);
+            assert_eq!(far_transform.translation.y, ENEMY_Y_HEIGHT);
+            assert_eq!(app.world().get::<Transform>(near).unwrap().translation.x, 5.0);
+        }
+
+        #[test]
+        fn aura_infuses_nearby_enemies_until_it_wears_off() {
+            let mut app = setup_test_app();
+            let mut aura = ElementalAura::new(Element::Frost);
+            aura.pulse_timer.set_elapsed(Duration::from_secs_f32(ElementalAura::PULSE_SECS - 0.001));
+            app.world_mut().spawn((Transform::default(), Enemy { speed: 2.0, strength: 5.0 }, aura));
+            let nearby = app
+                .world_mut()
+                .spawn((Transform::from_xyz(2.0, 0.0, 0.0), Enemy { speed: 2.0, strength: 5.0 }))
+                .id();
+            let distant = app
+                .world_mut()
+                .spawn((Transform::from_xyz(10.0, 0.0, 0.0), Enemy { speed: 2.0, strength: 5.0 }))
+                .id();
+            app.world_mut().resource_mut::<Time<()>>().advance_by(Duration::from_secs_f32(0.01));
+
+            let _ = app.world_mut().run_system_once(elemental_aura_system);
+            assert_eq!(app.world().get::<AuraInfused>(nearby).unwrap().element, Element::Frost);
+            assert!(app.world().get::<AuraInfused>(distant).is_none());
+
+            app.world_mut().entity_mut(nearby).insert(Transform::from_xyz(10.0, 0.0, 2.0));
+            app.world_mut().get_mut::<AuraInfused>(nearby).unwrap().remaining = 0.005;
+            let _ = app.world_mut().run_system_once(elemental_aura_system);
+            assert!(app.world().get::<AuraInfused>(nearby).is_none());
+        }
+
+        #[test]
+        fn explosive_elite_hurts_the_player_in_range() {
+            let mut app = setup_test_app();
+            app.add_message::<DeathEvent>();
+            app.add_message::<DamageEvent>();
+            let player = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(2.0, 0.5, 0.0),
+                    Player {
+                        speed: 200.0,
+                        regen_rate: 1.0,
+                        pickup_radius: 50.0,
+                        last_movement_direction: Vec3::ZERO,
+                    },
+                ))
+                .id();
+            let near = app.world_mut().spawn((Transform::default(), Explosive::new(10.0))).id();
+            let far = app
+                .world_mut()
+                .spawn((Transform::from_xyz(20.0, 0.0, 0.0), Explosive::new(10.0)))
+                .id();
+            app.world_mut().write_message(DeathEvent::new(near, Vec3::ZERO, EntityType::Enemy));
+            app.world_mut()
+                .write_message(DeathEvent::new(far, Vec3::new(20.0, 0.0, 0.0), EntityType::Enemy));
+
+            let _ = app.world_mut().run_system_once(explosive_on_death_system);
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let hits: Vec<_> = messages.iter_current_update_messages().collect();
+            assert_eq!(hits.len(), 1);
+            assert_eq!(hits[0].target, player);
+            assert_eq!(hits[0].amount, 10.0 * Explosive::DAMAGE_MULTIPLIER);
+            let mut bursts = app.world_mut().query::<&ReactionBurst>();
+            assert_eq!(bursts.iter(app.world()).count(), 2, "Both explosions should show");
+        }
+    }
+
+    mod ranged_enemy_tests {
+        use super::*;
+        use std::time::Duration;
+
+        #[test]
+        fn ranged_enemy_fires_at_player_in_range() {
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin::default());
+            app.insert_resource(PlayerPosition(Vec2::new(5.0, 0.0)));
+
+            let mut ranged = RangedAttacker::default();
+            ranged.fire_timer.tick(Duration::from_secs_f32(ranged.fire_timer.duration().as_secs_f32() - 0.001));
+            app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.75, 0.0)),
+                Enemy { speed: 2.0, strength: 12.0 },
+                ranged,
+            ));
+
+            {
+                let mut time = app.world_mut().resource_mut::<Time>();
+                time.advance_by(Duration::from_millis(10));
+            }
+            let _ = app.world_mut().run_system_once(ranged_enemy_attack_system);
+
+            let mut query = app.world_mut().query::<&EnemyProjectile>();
+            let projectiles: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(projectiles.len(), 1);
+            assert_eq!(projectiles[0].damage, 12.0);
+            assert!(projectiles[0].direction.x > 0.99);
+        }
+
+        #[test]
+        fn projectile_damages_player_on_contact() {
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin::default());
+            app.add_message::<DamageEvent>();
+            app.add_systems(Update, enemy_projectile_system);
+
+            let player = app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.3, 0.5, 0.0)),
+                Player {
+                    speed: 200.0,
+                    regen_rate: 1.0,
+                    pickup_radius: 50.0,
+                    last_movement_direction: Vec3::ZERO,
+                },
+            )).id();
+            let projectile = app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.75, 0.0)),
+                EnemyProjectile::new(Vec2::X, 9.0, 15.0),
+            )).id();
+
+            app.update();
+
+            assert!(app.world().get_entity(projectile).is_err(), "Projectile should despawn on hit");
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let mut cursor = messages.get_cursor();
+            let events: Vec<_> = cursor.read(messages).collect();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].target, player);
+            assert_eq!(events[0].amount, 15.0);
+        }
+    }
+
+    mod charger_behavior_tests {
+        use super::*;
+        use std::time::Duration;
+
+        fn setup_charger_app() -> App {
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin::default());
+            app.insert_resource(PlayerPosition(Vec2::new(5.0, 0.0)));
+            app
+        }
+
+        #[test]
+        fn charger_telegraphs_when_player_is_close() {
+            let mut app = setup_charger_app();
+            let charger = app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.75, 0.0)),
+                Enemy { speed: 2.0, strength: 10.0 },
+                Charger::default(),
+            )).id();
+
+            let _ = app.world_mut().run_system_once(charger_behavior_system);
+
+            let state = app.world().get::<Charger>(charger).unwrap();
+            assert_eq!(state.state, ChargerState::Telegraphing);
+            assert_eq!(state.direction, Vec2::X);
+
+            let mut query = app.world_mut().query::<&ChargeTelegraph>();
+            let telegraphs: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(telegraphs.len(), 1);
+            assert_eq!(telegraphs[0].owner, charger);
+        }
+
+        #[test]
+        fn dashing_charger_moves_along_locked_direction_and_clears_telegraph() {
+            let mut app = setup_charger_app();
+            let mut state = Charger {
+                direction: Vec2::X,
+                ..default()
+            };
+            state.enter(ChargerState::Dashing);
+            let charger = app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.75, 0.0)),
+                Enemy { speed: 2.0, strength: 10.0 },
+                state,
+            )).id();
+            let telegraph = app.world_mut().spawn(ChargeTelegraph { owner: charger }).id();
+
+            {
+                let mut time = app.world_mut().resource_mut::<Time>();
+                time.advance_by(Duration::from_millis(100));
+            }
+            let _ = app.world_mut().run_system_once(charger_behavior_system);
+
+            let transform = app.world().get::<Transform>(charger).unwrap();
+            let expected = 2.0 * Charger::DASH_SPEED_MULTIPLIER * 0.1;
+            assert!((transform.translation.x - expected).abs() < 0.01);
+            assert_eq!(transform.translation.z, 0.0);
+            assert!(app.world().get_entity(telegraph).is_err(), "Telegraph should clear once dashing");
+        }
+
+        #[test]
+        fn charger_recovers_then_approaches_again() {
+            let mut app = setup_charger_app();
+            let mut state = Charger::default();
+            state.enter(ChargerState::Recovering);
+            state.timer.tick(Duration::from_secs_f32(Charger::RECOVER_SECS));
+            let charger = app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(-20.0, 0.75, 0.0)),
+                Enemy { speed: 2.0, strength: 10.0 },
+                state,
+            )).id();
+
+            let _ = app.world_mut().run_system_once(charger_behavior_system);
+
+            assert!(app.world().get::<Charger>(charger).unwrap().is_approaching());
+        }
+    }
+}
@@ -0,0 +1,743 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::enemies::components::{EnemyKind, EnemyScaling};
+
+/// Asset path of the wave script the game plays
+pub const WAVE_SCRIPT_PATH: &str = "waves/default.ron";
+
+/// Wave script compiled in, used until the asset file has loaded
+/// (and by tests and apps without an asset server).
+const BUILT_IN_WAVE_SCRIPT: &str = include_str!("../../assets/waves/default.ron");
+
+/// Number of enemy levels (rarities) a script weights
+pub const ENEMY_LEVEL_COUNT: usize = 5;
+
+/// Highest spawn rate (formations per second) a window may have
+pub const MAX_SPAWN_RATE: f32 = 50.0;
+
+/// Most enemies a single formation may spawn
+pub const MAX_FORMATION_COUNT: u32 = 32;
+
+/// Highest spawn rate multiplier a horde may have
+pub const MAX_HORDE_RATE_MULTIPLIER: f32 = 10.0;
+
+/// How the enemies of one spawn are arranged around the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Formation {
+    /// Side by side, across the direction they come from
+    #[default]
+    Line,
+    /// Evenly spaced on a circle around the player
+    Ring,
+    /// Bunched together at one spot
+    Cluster,
+    /// Each enemy at its own random angle around the player
+    Surround,
+}
+
+/// One option a wave window picks from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpawnEntry {
+    pub kind: EnemyKind,
+    /// Relative chance of this entry being picked
+    pub weight: f32,
+    #[serde(default)]
+    pub formation: Formation,
+    /// Enemies in the formation
+    #[serde(default = "default_count")]
+    pub count: u32,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+/// Spawns formations at a steady rate during part of a level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveWindow {
+    /// Seconds into the level the window opens
+    #[serde(default)]
+    pub start_secs: f32,
+    /// Seconds into the level the window closes; open until the level ends when omitted
+    #[serde(default)]
+    pub end_secs: Option<f32>,
+    /// Formations spawned per second
+    pub rate: f32,
+    /// Chance of each spawned enemy being an elite
+    #[serde(default)]
+    pub elite_chance: f32,
+    pub spawns: Vec<SpawnEntry>,
+}
+
+impl WaveWindow {
+    /// Seconds of `from..to` (level time) during which the window is open
+    pub fn open_secs(&self, from: f32, to: f32) -> f32 {
+        let start = from.max(self.start_secs);
+        let end = self.end_secs.map_or(to, |end_secs| to.min(end_secs));
+        (end - start).max(0.0)
+    }
+
+    /// Pick a spawn entry, weighted by `SpawnEntry::weight`.
+    pub fn pick_spawn(&self, rng: &mut impl Rng) -> &SpawnEntry {
+        let total: f32 = self.spawns.iter().map(|entry| entry.weight).sum();
+        let roll = rng.gen_range(0.0..total);
+
+        let mut cumulative = 0.0;
+        for entry in &self.spawns {
+            cumulative += entry.weight;
+            if roll < cumulative {
+                return entry;
+            }
+        }
+        &self.spawns[self.spawns.len() - 1] // Fallback for rounding
+    }
+
+    /// Check the values are usable, describing the first one that isn't.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.start_secs >= 0.0 && self.start_secs.is_finite()) {
+            return Err(format!("start_secs {} must be 0 or more", self.start_secs));
+        }
+        if let Some(end_secs) = self.end_secs {
+            if !(end_secs > self.start_secs && end_secs.is_finite()) {
+                return Err(format!("end_secs {} must be after start_secs {}", end_secs, self.start_secs));
+            }
+        }
+        if !(self.rate > 0.0 && self.rate <= MAX_SPAWN_RATE) {
+            return Err(format!("rate {} must be above 0 and at most {}", self.rate, MAX_SPAWN_RATE));
+        }
+        if !(0.0..=1.0).contains(&self.elite_chance) {
+            return Err(format!("elite_chance {} is outside 0..=1", self.elite_chance));
+        }
+        if self.spawns.is_empty() {
+            return Err("has no spawns".to_string());
+        }
+        for entry in &self.spawns {
+            if !(entry.weight > 0.0 && entry.weight.is_finite()) {
+                return Err(format!("{:?} weight {} must be above 0", entry.kind, entry.weight));
+            }
+            if !(1..=MAX_FORMATION_COUNT).contains(&entry.count) {
+                return Err(format!(
+                    "{:?} count {} must be 1 to {}",
+                    entry.kind, entry.count, MAX_FORMATION_COUNT
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A surprise layered on top of a level's wave windows, announced on screen
+/// when it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum WaveEvent {
+    /// A ring of enemies all around the player that closes in together
+    ClosingRing { kind: EnemyKind, count: u32 },
+    /// A line of enemies that runs straight across the arena before joining the chase
+    Stampede { kind: EnemyKind, count: u32 },
+    /// Elites that appear next to the player after a warning marker
+    EliteAmbush { kind: EnemyKind, count: u32 },
+    /// Every wave window spawns `rate_multiplier` times as fast for `secs`
+    Horde { secs: f32, rate_multiplier: f32 },
+}
+
+impl WaveEvent {
+    /// Text shown on screen when the event starts
+    pub fn announcement(&self) -> &'static str {
+        match self {
+            WaveEvent::ClosingRing { .. } => "Surrounded!",
+            WaveEvent::Stampede { .. } => "Stampede!",
+            WaveEvent::EliteAmbush { .. } => "Ambush!",
+            WaveEvent::Horde { .. } => "The horde approaches!",
+        }
+    }
+
+    /// Check the values are usable, describing the first one that isn't.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            WaveEvent::ClosingRing { count, .. }
+            | WaveEvent::Stampede { count, .. }
+            | WaveEvent::EliteAmbush { count, .. } => {
+                if !(1..=MAX_FORMATION_COUNT).contains(count) {
+                    return Err(format!("count {} must be 1 to {}", count, MAX_FORMATION_COUNT));
+                }
+            }
+            WaveEvent::Horde { secs, rate_multiplier } => {
+                if !(*secs > 0.0 && secs.is_finite()) {
+                    return Err(format!("secs {} must be above 0", secs));
+                }
+                if !(*rate_multiplier > 0.0 && *rate_multiplier <= MAX_HORDE_RATE_MULTIPLIER) {
+                    return Err(format!(
+                        "rate_multiplier {} must be above 0 and at most {}",
+                        rate_multiplier, MAX_HORDE_RATE_MULTIPLIER
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A wave event and when it starts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledWaveEvent {
+    /// Seconds into the level the event starts
+    pub at_secs: f32,
+    pub event: WaveEvent,
+}
+
+impl ScheduledWaveEvent {
+    /// Whether the event starts during `from..to` (level time)
+    pub fn starts_during(&self, from: f32, to: f32) -> bool {
+        from <= self.at_secs && self.at_secs < to
+    }
+}
+
+/// What spawns during one game level.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LevelScript {
+    /// First game level this script is used for
+    pub level: u32,
+    /// Relative chance of spawning enemy levels 1 to 5
+    pub rarity: [f32; ENEMY_LEVEL_COUNT],
+    pub windows: Vec<WaveWindow>,
+    #[serde(default)]
+    pub events: Vec<ScheduledWaveEvent>,
+}
+
+/// Contents of a wave script file.
+#[derive(Asset, TypePath, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveScriptFile {
+    /// Stats of every enemy kind
+    pub enemies: HashMap<EnemyKind, EnemyScaling>,
+    /// Spawn rate multiplier per game level past a script's own level
+    pub endless_rate_growth: f32,
+    /// Scripts in increasing level order, starting at level 1
+    pub levels: Vec<LevelScript>,
+}
+
+/// Why a wave script file could not be loaded.
+#[derive(Debug)]
+pub enum WaveScriptFileError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not valid RON or names an unknown enemy kind or formation
+    Parse(String),
+}
+
+impl fmt::Display for WaveScriptFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaveScriptFileError::Io(source) => write!(f, "could not read wave script: {}", source),
+            WaveScriptFileError::Parse(reason) => write!(f, "wave script is invalid: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for WaveScriptFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WaveScriptFileError::Io(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for WaveScriptFileError {
+    fn from(source: std::io::Error) -> Self {
+        WaveScriptFileError::Io(source)
+    }
+}
+
+/// Parse the contents of a wave script file.
+pub fn parse_wave_script_file(contents: &str) -> Result<WaveScriptFile, WaveScriptFileError> {
+    ron::from_str(contents).map_err(|err| WaveScriptFileError::Parse(err.to_string()))
+}
+
+/// Loads `waves/*.ron` scripts through the asset server.
+#[derive(Default, TypePath)]
+pub struct WaveScriptLoader;
+
+impl AssetLoader for WaveScriptLoader {
+    type Asset = WaveScriptFile;
+    type Settings = ();
+    type Error = WaveScriptFileError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let contents = std::str::from_utf8(&bytes).map_err(|err| WaveScriptFileError::Parse(err.to_string()))?;
+        parse_wave_script_file(contents)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+/// Every problem found in a wave script.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaveScriptError {
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for WaveScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "wave script is invalid: {}", self.problems.join("; "))
+    }
+}
+
+impl std::error::Error for WaveScriptError {}
+
+/// The level script in effect at a game level, with its rates scaled for
+/// how far past the script's own level the game is.
+#[derive(Debug, Clone, Copy)]
+pub struct LevelWaves<'a> {
+    pub script: &'a LevelScript,
+    /// Multiplier for every window's `rate`
+    pub rate_multiplier: f32,
+}
+
+impl LevelWaves<'_> {
+    pub fn windows(&self) -> &[WaveWindow] {
+        &self.script.windows
+    }
+}
+
+/// A validated wave script: enemy stats and what spawns at each game level.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct WaveScript {
+    file: WaveScriptFile,
+}
+
+impl Default for WaveScript {
+    fn default() -> Self {
+        let file = parse_wave_script_file(BUILT_IN_WAVE_SCRIPT).expect("built-in wave script parses");
+        WaveScript::from_file(WAVE_SCRIPT_PATH, file).expect("built-in wave script is valid")
+    }
+}
+
+impl WaveScript {
+    /// Check that every enemy kind has usable stats and that the level
+    /// scripts start at level 1, are in order and have usable values.
+    pub fn from_file(path: &str, file: WaveScriptFile) -> Result<Self, WaveScriptError> {
+        let mut problems = Vec::new();
+
+        for kind in EnemyKind::all() {
+            match file.enemies.get(kind) {
+                None => problems.push(format!("{}: {:?} has no stats", path, kind)),
+                Some(stats) => {
+                    let values = [
+                        stats.base_health,
+                        stats.health_per_enemy_level,
+                        stats.health_per_game_level_percent,
+                        stats.base_damage,
+                        stats.damage_per_level,
+                    ];
+                    if stats.base_health <= 0.0 || values.iter().any(|value| !(*value >= 0.0 && value.is_finite())) {
+                        problems.push(format!("{}: {:?} stats must be 0 or more, with base_health above 0", path, kind));
+                    }
+                }
+            }
+        }
+
+        if !(file.endless_rate_growth > 0.0 && file.endless_rate_growth.is_finite()) {
+            problems.push(format!(
+                "{}: endless_rate_growth {} must be above 0",
+                path, file.endless_rate_growth
+            ));
+        }
+
+        match file.levels.first() {
+            None => problems.push(format!("{}: has no level scripts", path)),
+            Some(first) if first.level != 1 => {
+                problems.push(format!("{}: first level script is for level {}, not 1", path, first.level))
+            }
+            Some(_) => {}
+        }
+        for pair in file.levels.windows(2) {
+            if pair[1].level <= pair[0].level {
+                problems.push(format!(
+                    "{}: level {} script comes after level {}",
+                    path, pair[1].level, pair[0].level
+                ));
+            }
+        }
+
+        for script in &file.levels {
+            if script.rarity.iter().any(|weight| !(*weight >= 0.0 && weight.is_finite()))
+                || script.rarity.iter().sum::<f32>() <= 0.0
+            {
+                problems.push(format!(
+                    "{}: level {} rarity weights must be 0 or more and not all 0",
+                    path, script.level
+                ));
+            }
+            for (index, window) in script.windows.iter().enumerate() {
+                if let Err(reason) = window.validate() {
+                    problems.push(format!("{}: level {} window {} {}", path, script.level, index + 1, reason));
+                }
+            }
+            for (index, scheduled) in script.events.iter().enumerate() {
+                let result = if scheduled.at_secs >= 0.0 && scheduled.at_secs.is_finite() {
+                    scheduled.event.validate()
+                } else {
+                    Err(format!("at_secs {} must be 0 or more", scheduled.at_secs))
+                };
+                if let Err(reason) = result {
+                    problems.push(format!("{}: level {} event {} {}", path, script.level, index + 1, reason));
+                }
+            }
+        }
+
+        if !problems.is_empty() {
+            return Err(WaveScriptError { problems });
+        }
+        Ok(Self { file })
+    }
+
+    /// Stats of an enemy kind
+    pub fn stats(&self, kind: EnemyKind) -> &EnemyScaling {
+        &self.file.enemies[&kind]
+    }
+
+    /// The script for a game level: the last one starting at or before it.
+    /// Rates grow by `endless_rate_growth` for every level past its start.
+    pub fn level(&self, game_level: u32) -> LevelWaves<'_> {
+        let game_level = game_level.max(1);
+        let script = self
+            .file
+            .levels
+            .iter()
+            .rev()
+            .find(|script| script.level <= game_level)
+            .unwrap_or(&self.file.levels[0]);
+        LevelWaves {
+            script,
+            rate_multiplier: self.file.endless_rate_growth.powi((game_level - script.level) as i32),
+        }
+    }
+}
+
+/// Handle keeping the wave script loaded (and watched for changes).
+#[derive(Resource, Debug, Default)]
+pub struct WaveScriptHandle(pub Handle<WaveScriptFile>);
+
+/// Start loading the wave script
+pub fn load_wave_script(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(WaveScriptHandle(asset_server.load(WAVE_SCRIPT_PATH)));
+}
+
+/// Once the script has loaded, and again whenever it changes on disk,
+/// validate it and put it into effect. Invalid scripts are reported and ignored.
+pub fn apply_wave_script(
+    mut asset_events: MessageReader<AssetEvent<WaveScriptFile>>,
+    handle: Option<Res<WaveScriptHandle>>,
+    files: Res<Assets<WaveScriptFile>>,
+    mut wave_script: ResMut<WaveScript>,
+) {
+    let Some(handle) = handle else {
+        return;
+    };
+    let changed = asset_events.read().fold(false, |changed, event| {
+        changed
+            || matches!(
+                event,
+                AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } if *id == handle.0.id()
+            )
+    });
+    let Some(file) = files.get(&handle.0).filter(|_| changed) else {
+        return;
+    };
+
+    match WaveScript::from_file(WAVE_SCRIPT_PATH, file.clone()) {
+        Ok(script) => {
+            *wave_script = script;
+            info!("Loaded wave script from assets/{}", WAVE_SCRIPT_PATH);
+        }
+        Err(err) => warn!("Keeping previous wave script: {}", err),
+    }
+}
+
+/// Wave script plugin: loads `assets/waves/default.ron` through the asset
+/// server and reloads it when it changes. Needs the `AssetPlugin`.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<WaveScript>()
+        .init_asset::<WaveScriptFile>()
+        .init_asset_loader::<WaveScriptLoader>()
+        .add_systems(Startup, load_wave_script)
+        .add_systems(Update, apply_wave_script);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn built_in_file() -> WaveScriptFile {
+        parse_wave_script_file(BUILT_IN_WAVE_SCRIPT).unwrap()
+    }
+
+    fn window(rate: f32) -> WaveWindow {
+        WaveWindow {
+            start_secs: 0.0,
+            end_secs: None,
+            rate,
+            elite_chance: 0.0,
+            spawns: vec![SpawnEntry {
+                kind: EnemyKind::Grunt,
+                weight: 1.0,
+                formation: Formation::Line,
+                count: 1,
+            }],
+        }
+    }
+
+    /// Summed spawn weight of a kind in a level's main window
+    fn kind_weight(script: &WaveScript, game_level: u32, kind: EnemyKind) -> f32 {
+        script.level(game_level).windows()[0]
+            .spawns
+            .iter()
+            .filter(|entry| entry.kind == kind)
+            .map(|entry| entry.weight)
+            .sum()
+    }
+
+    mod wave_window_tests {
+        use super::*;
+
+        #[test]
+        fn open_secs_clips_to_the_window() {
+            let window = WaveWindow {
+                start_secs: 10.0,
+                end_secs: Some(20.0),
+                ..window(1.0)
+            };
+            assert_eq!(window.open_secs(0.0, 5.0), 0.0);
+            assert_eq!(window.open_secs(8.0, 12.0), 2.0);
+            assert_eq!(window.open_secs(12.0, 14.0), 2.0);
+            assert_eq!(window.open_secs(19.0, 25.0), 1.0);
+            assert_eq!(window.open_secs(30.0, 31.0), 0.0);
+        }
+
+        #[test]
+        fn open_ended_window_stays_open() {
+            assert_eq!(window(1.0).open_secs(1000.0, 1001.5), 1.5);
+        }
+
+        #[test]
+        fn pick_spawn_follows_weights() {
+            let mut window = window(1.0);
+            window.spawns.push(SpawnEntry {
+                kind: EnemyKind::Tank,
+                weight: 1000.0,
+                formation: Formation::Ring,
+                count: 4,
+            });
+            let mut rng = StdRng::seed_from_u64(3);
+            let tanks = (0..1000)
+                .filter(|_| window.pick_spawn(&mut rng).kind == EnemyKind::Tank)
+                .count();
+            assert!(tanks > 950, "Tank should dominate, got {} of 1000", tanks);
+        }
+
+        #[test]
+        fn rejects_out_of_range_values() {
+            assert!(window(0.0).validate().unwrap_err().contains("rate"));
+            assert!(WaveWindow { elite_chance: 1.5, ..window(1.0) }.validate().unwrap_err().contains("elite_chance"));
+            assert!(WaveWindow { end_secs: Some(0.0), ..window(1.0) }.validate().unwrap_err().contains("end_secs"));
+            assert!(WaveWindow { spawns: vec![], ..window(1.0) }.validate().is_err());
+
+            let mut too_many = window(1.0);
+            too_many.spawns[0].count = MAX_FORMATION_COUNT + 1;
+            assert!(too_many.validate().unwrap_err().contains("count"));
+        }
+    }
+
+    mod wave_script_tests {
+        use super::*;
+
+        #[test]
+        fn built_in_script_is_valid() {
+            assert!(WaveScript::from_file(WAVE_SCRIPT_PATH, built_in_file()).is_ok());
+        }
+
+        #[test]
+        fn built_in_grunt_stats() {
+            let scaling = WaveScript::default().stats(EnemyKind::Grunt).clone();
+            assert_eq!(scaling.base_health, 25.0);
+            assert_eq!(scaling.health_per_enemy_level, 15.0);
+            assert_eq!(scaling.health_per_game_level_percent, 0.10);
+            assert_eq!(scaling.base_damage, 10.0);
+            assert_eq!(scaling.damage_per_level, 5.0);
+        }
+
+        #[test]
+        fn tank_is_tougher_than_swarm() {
+            let script = WaveScript::default();
+            let tank = script.stats(EnemyKind::Tank).health_for_level(1, 1);
+            let swarm = script.stats(EnemyKind::Swarm).health_for_level(1, 1);
+            assert!(tank > swarm);
+        }
+
+        #[test]
+        fn spawn_rate_starts_at_level_1_rate_and_grows() {
+            let script = WaveScript::default();
+            let rate = |level: u32| {
+                let waves = script.level(level);
+                waves.windows()[0].rate * waves.rate_multiplier
+            };
+
+            assert!((rate(1) - 0.504).abs() < 0.001, "got {}", rate(1));
+            assert!((rate(2) - 0.6804).abs() < 0.001, "got {}", rate(2));
+            for level in [5, 10, 15, 20] {
+                let expected = 0.504 * 1.35_f32.powi(level as i32 - 1);
+                assert!(
+                    (rate(level) - expected).abs() / expected < 0.001,
+                    "Level {} should spawn {} formations/sec, got {}",
+                    level, expected, rate(level)
+                );
+            }
+            assert_eq!(rate(0), rate(1));
+        }
+
+        #[test]
+        fn only_grunts_and_swarms_spawn_at_level_one() {
+            let script = WaveScript::default();
+            for window in script.level(1).windows() {
+                for entry in &window.spawns {
+                    assert!(matches!(entry.kind, EnemyKind::Grunt | EnemyKind::Swarm), "{:?}", entry.kind);
+                }
+            }
+        }
+
+        #[test]
+        fn special_kinds_grow_more_common_with_game_level() {
+            let script = WaveScript::default();
+            for kind in EnemyKind::all().iter().filter(|k| **k != EnemyKind::Grunt) {
+                assert!(kind_weight(&script, 10, *kind) > kind_weight(&script, 4, *kind), "{:?}", kind);
+            }
+            assert!(kind_weight(&script, 10, EnemyKind::Grunt) < kind_weight(&script, 1, EnemyKind::Grunt));
+        }
+
+        #[test]
+        fn swarms_spawn_in_packs() {
+            let script = WaveScript::default();
+            let swarm = script.level(1).windows()[0]
+                .spawns
+                .iter()
+                .find(|entry| entry.kind == EnemyKind::Swarm)
+                .unwrap();
+            assert_eq!(swarm.formation, Formation::Line);
+            assert_eq!(swarm.count, 3);
+        }
+
+        #[test]
+        fn later_levels_reuse_the_last_script() {
+            let script = WaveScript::default();
+            let level_50 = script.level(50);
+            let level_100 = script.level(100);
+            assert_eq!(level_50.script, level_100.script);
+            assert!(level_100.rate_multiplier > level_50.rate_multiplier);
+        }
+
+        #[test]
+        fn no_elites_on_the_first_level() {
+            let script = WaveScript::default();
+            assert!(script.level(1).windows().iter().all(|window| window.elite_chance == 0.0));
+            assert!(script.level(10).windows().iter().any(|window| window.elite_chance > 0.0));
+        }
+
+        #[test]
+        fn reports_missing_stats_and_bad_level_order() {
+            let mut file = built_in_file();
+            file.enemies.remove(&EnemyKind::Tank);
+            file.levels.swap(1, 2);
+
+            let err = WaveScript::from_file("waves/test.ron", file).unwrap_err();
+            assert!(err.problems.iter().any(|problem| problem == "waves/test.ron: Tank has no stats"));
+            assert!(err.problems.iter().any(|problem| problem.contains("level 2 script comes after level 3")));
+        }
+
+        #[test]
+        fn reports_bad_windows_with_their_level() {
+            let mut file = built_in_file();
+            file.levels[2].windows[0].rate = -1.0;
+            file.levels[0].rarity = [0.0; ENEMY_LEVEL_COUNT];
+
+            let err = WaveScript::from_file(WAVE_SCRIPT_PATH, file).unwrap_err();
+            assert_eq!(err.problems.len(), 2);
+            assert!(err.problems[0].contains("level 1 rarity"));
+            assert!(err.problems[1].starts_with("waves/default.ron: level 3 window 1 rate"));
+        }
+
+        #[test]
+        fn first_script_must_be_level_one() {
+            let mut file = built_in_file();
+            file.levels.remove(0);
+
+            let err = WaveScript::from_file(WAVE_SCRIPT_PATH, file).unwrap_err();
+            assert!(err.problems[0].contains("not 1"));
+        }
+
+        #[test]
+        fn built_in_events_start_on_level_two() {
+            let script = WaveScript::default();
+            assert!(script.level(1).script.events.is_empty());
+            assert_eq!(
+                script.level(2).script.events,
+                vec![ScheduledWaveEvent {
+                    at_secs: 60.0,
+                    event: WaveEvent::ClosingRing { kind: EnemyKind::Grunt, count: 10 },
+                }]
+            );
+        }
+
+        #[test]
+        fn event_starts_once_across_frames() {
+            let scheduled = ScheduledWaveEvent {
+                at_secs: 30.0,
+                event: WaveEvent::Horde { secs: 10.0, rate_multiplier: 2.0 },
+            };
+            assert!(!scheduled.starts_during(29.0, 29.9));
+            assert!(scheduled.starts_during(29.9, 30.0 + f32::EPSILON));
+            assert!(!scheduled.starts_during(30.0 + f32::EPSILON, 31.0));
+        }
+
+        #[test]
+        fn reports_bad_events_with_their_level() {
+            let mut file = built_in_file();
+            file.levels[1].events[0].event = WaveEvent::Stampede { kind: EnemyKind::Swarm, count: 0 };
+            file.levels[4].events.push(ScheduledWaveEvent {
+                at_secs: 10.0,
+                event: WaveEvent::Horde { secs: 30.0, rate_multiplier: 50.0 },
+            });
+
+            let err = WaveScript::from_file(WAVE_SCRIPT_PATH, file).unwrap_err();
+            assert_eq!(err.problems.len(), 2);
+            assert!(err.problems[0].starts_with("waves/default.ron: level 2 event 1 count 0"));
+            assert!(err.problems[1].contains("level 5 event 3 rate_multiplier 50"));
+        }
+
+        #[test]
+        fn unknown_formation_fails_to_parse() {
+            let result = parse_wave_script_file(
+                r#"(enemies: {}, endless_rate_growth: 1.0, levels: [(level: 1, rarity: (1.0, 0.0, 0.0, 0.0, 0.0),
+                    windows: [(rate: 1.0, spawns: [(kind: Grunt, weight: 1.0, formation: Spiral)])])])"#,
+            );
+            assert!(matches!(result, Err(WaveScriptFileError::Parse(_))));
+        }
+    }
+}
@@ -51,6 +51,7 @@ mod tests {
             enemy_entity: Entity::PLACEHOLDER,
             position: bevy::math::Vec3::new(100.0, 0.0, 200.0),
             enemy_level: 1,
+            affix_count: 0,
         });
 
         // Run the system
@@ -40,6 +40,8 @@ pub fn enemy_death_system(
         loot_drop_events.write(LootDropEvent {
             position: event.position,
             enemy_level: event.enemy_level,
+            affix_count: event.affix_count,
+            guaranteed_loot: false,
         });
     }
 
@@ -30,6 +30,16 @@ impl Rarity {
         }
     }
 
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Epic => "Epic",
+            Rarity::Legendary => "Legendary",
+        }
+    }
+
     pub fn color(&self) -> Color {
         match self {
             Rarity::Common => Color::srgb(0.6, 0.6, 0.6),      // Grey
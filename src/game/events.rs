@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::enemies::waves::WaveEvent;
+
 /// Message fired when an enemy dies
 #[derive(Message)]
 pub struct EnemyDeathEvent {
@@ -7,6 +9,8 @@ pub struct EnemyDeathEvent {
     pub position: Vec3,
     /// Level of the enemy that died (1-5)
     pub enemy_level: u8,
+    /// Number of elite affixes the enemy carried
+    pub affix_count: u8,
 }
 
 /// Message fired when loot should be dropped (typically when an enemy dies)
@@ -15,6 +19,10 @@ pub struct LootDropEvent {
     pub position: Vec3,
     /// Level of the enemy that died (1-5), determines XP orb quality
     pub enemy_level: u8,
+    /// Elite affixes of the enemy that died; each one improves the drop
+    pub affix_count: u8,
+    /// Skip the drop rolls: drop legendary XP orbs, a spell and a health pack (boss kills)
+    pub guaranteed_loot: bool,
 }
 
 /// Event fired when player collides with an enemy
@@ -51,6 +59,19 @@ pub struct GameLevelUpEvent {
     pub new_level: u32,
 }
 
+/// Fired when a level boss dies; completes the level
+#[derive(Message, Debug)]
+pub struct BossDefeatedEvent {
+    pub position: Vec3,
+    pub game_level: u32,
+}
+
+/// Fired when a scripted wave event (ring, stampede, ambush, horde) starts
+#[derive(Message, Debug, Clone)]
+pub struct WaveEventStarted {
+    pub event: WaveEvent,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,4 +108,12 @@ mod tests {
         // Should not panic
         app.update();
     }
+
+    #[test]
+    fn test_boss_events_can_be_registered() {
+        let mut app = App::new();
+        app.add_message::<BossDefeatedEvent>();
+        // Should not panic
+        app.update();
+    }
 }
\ No newline at end of file
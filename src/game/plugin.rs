@@ -1,29 +1,41 @@
 use bevy::prelude::*;
 use crate::states::*;
 use crate::arena::plugin as arena_plugin;
+use crate::arena::plugin::layouts_plugin as arena_layouts_plugin;
+use crate::boss::plugin as boss_plugin;
+use crate::boss::systems::no_boss_alive;
 use crate::camera::plugin as camera_plugin;
+use crate::collision::plugin as collision_plugin;
 use crate::enemies::systems::*;
 use crate::game::systems::{
-    cleanup_game, mark_fresh_game_start, player_death_system, player_enemy_collision_detection,
-    player_enemy_damage_system, player_enemy_effect_system, reset_game_level, reset_level_stats_system,
-    reset_survival_time, setup_game, setup_game_assets, track_enemy_kills_system,
-    track_level_kills_system, track_level_xp_system, update_level_time_system,
-    update_screen_tint_timer, update_survival_time,
+    cleanup_game, is_fresh_game_start, mark_fresh_game_start, player_death_system, player_enemy_collision_detection,
+    player_enemy_damage_system, player_enemy_effect_system, reset_enemy_spawn_state, reset_game_level, reset_level_spell_stats_system,
+    reset_level_stats_system, reset_run_survival_time, reset_spell_stats_system, reset_survival_time, restart_game_rng, setup_game, setup_game_assets,
+    track_enemy_kills_system, track_level_kills_system, track_level_xp_system, track_spell_stats_system,
+    update_level_time_system, update_screen_tint_timer, update_survival_time,
 };
 use crate::game::sets::GameSet;
 use crate::inventory::systems::inventory_initialization_system;
+use crate::enemies::plugin as enemies_plugin;
+use crate::enemies::waves::plugin as wave_script_plugin;
 use crate::enemy_death::plugin as enemy_death_plugin;
+use crate::hazards::plugin as hazards_plugin;
+use crate::input::plugin as input_plugin;
+use crate::level_up::plugin as level_up_plugin;
+use crate::profile::plugin as profile_plugin;
+use crate::reactions::plugin as reactions_plugin;
 use crate::loot::plugin as loot_plugin;
 use crate::movement::plugin as movement_plugin;
 use crate::player::plugin as player_plugin;
 use crate::powerup::plugin as powerup_plugin;
 use crate::spell::plugin as spell_plugin;
+use crate::spell::definitions::plugin as spell_definitions_plugin;
 use crate::whisper::plugin as whisper_plugin;
 use crate::player::systems::{camera_follow_player, update_slow_modifiers, player_health_regeneration_system};
 use crate::whisper::systems::spawn_whisper_drop;
-use crate::game::resources::{FreshGameStart, GameLevel, LevelStats, PlayerPosition, EnemySpawnState, PlayerDamageTimer, ScreenTintEffect, SurvivalTime};
+use crate::game::resources::{FreshGameStart, GameLevel, GameRng, LevelStats, PlayerPosition, EnemySpawnState, PlayerDamageTimer, RunSurvivalTime, ScreenTintEffect, SpellStats, SurvivalTime};
 use crate::score::*;
-use crate::game::events::{PlayerEnemyCollisionEvent, GameOverEvent, GameLevelUpEvent};
+use crate::game::events::{PlayerEnemyCollisionEvent, GameOverEvent, GameLevelUpEvent, WaveEventStarted};
 use crate::spells::fire::fireball_effects::init_fireball_effects;
 use crate::spells::fire::materials::{
     FireballCoreMaterial, FireballChargeMaterial, FireballChargeParticlesMaterial,
@@ -52,13 +64,18 @@ pub fn plugin(app: &mut App) {
         .init_resource::<PlayerDamageTimer>()
         .init_resource::<ScreenTintEffect>()
         .init_resource::<SurvivalTime>()
+        .init_resource::<RunSurvivalTime>()
         .init_resource::<GameLevel>()
         .init_resource::<LevelStats>()
+        .init_resource::<SpellStats>()
+        .init_resource::<GameRng>()
         .insert_resource(FreshGameStart::new())
         .add_message::<PlayerEnemyCollisionEvent>()
         .add_message::<GameOverEvent>()
         .add_message::<GameLevelUpEvent>()
-        .add_plugins((arena_plugin, camera_plugin, enemy_death_plugin, loot_plugin, movement_plugin, player_plugin, powerup_plugin, spell_plugin, whisper_plugin))
+        .add_message::<WaveEventStarted>()
+        .add_plugins((arena_plugin, boss_plugin, camera_plugin, enemies_plugin, enemy_death_plugin, input_plugin, level_up_plugin, loot_plugin, movement_plugin, player_plugin, powerup_plugin, profile_plugin, reactions_plugin, spell_plugin, whisper_plugin))
+        .add_plugins((arena_layouts_plugin, collision_plugin, hazards_plugin, spell_definitions_plugin, wave_script_plugin))
         // Configure GameSet ordering: Input -> Movement -> Combat -> Spawning -> Effects -> Cleanup
         // Most sets only run in InGame, but Effects also runs in VisualTest for visual testing
         .configure_sets(
@@ -66,6 +83,7 @@ pub fn plugin(app: &mut App) {
             (
                 GameSet::Input,
                 GameSet::Movement,
+                GameSet::Spatial,
                 GameSet::Combat,
                 GameSet::Spawning,
             )
@@ -85,15 +103,27 @@ pub fn plugin(app: &mut App) {
                 .run_if(in_state(GameState::InGame)),
         )
         .add_systems(OnEnter(GameState::InGame), (
+            restart_game_rng,
             setup_game_assets,
             setup_game,
             init_fireball_effects,
             spawn_whisper_drop,
             inventory_initialization_system,
-            reset_survival_time,
+            // Resuming from a pause or overlay keeps the level's clock and stats
+            reset_survival_time.run_if(is_fresh_game_start),
+            reset_level_stats_system.run_if(is_fresh_game_start),
+            reset_run_survival_time,
+            reset_spell_stats_system,
+            reset_enemy_spawn_state,
             reset_game_level,
-            reset_level_stats_system,
         ).chain())
+        .add_systems(
+            OnTransition {
+                exited: GameState::LevelComplete,
+                entered: GameState::InGame,
+            },
+            (reset_survival_time, reset_level_stats_system, reset_level_spell_stats_system),
+        )
         // Movement systems (player_movement and enemy_movement_system are in movement_plugin)
         // spell_follow_player_system is now in spell_plugin
         .add_systems(
@@ -113,12 +143,13 @@ pub fn plugin(app: &mut App) {
                 .in_set(GameSet::Combat)
                 .run_if(in_state(GameState::InGame)),
         )
-        // Spawning systems
+        // Spawning systems (regular waves pause during boss fights)
         .add_systems(
             Update,
             enemy_spawning_system
                 .in_set(GameSet::Spawning)
-                .run_if(in_state(GameState::InGame)),
+                .run_if(in_state(GameState::InGame))
+                .run_if(no_boss_alive),
         )
         // Effects systems
         .add_systems(
@@ -133,6 +164,7 @@ pub fn plugin(app: &mut App) {
                 update_level_time_system,
                 track_level_kills_system,
                 track_level_xp_system,
+                track_spell_stats_system,
                 update_fireball_core_material_time,
                 update_fireball_charge_material_time,
                 update_fireball_trail_material_time,
@@ -184,6 +216,7 @@ mod tests {
             (
                 GameSet::Input,
                 GameSet::Movement,
+                GameSet::Spatial,
                 GameSet::Combat,
                 GameSet::Spawning,
                 GameSet::Effects,
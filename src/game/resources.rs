@@ -1,4 +1,12 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use crate::element::Element;
+use crate::enemies::components::{EliteAffix, EnemyKind};
+use crate::spell::SpellType;
 
 /// Tracks whether the next InGame entry should reset game state.
 /// Set to true when starting a fresh game (from Intro/GameOver).
@@ -12,6 +20,78 @@ impl FreshGameStart {
     }
 }
 
+/// The single source of gameplay randomness. Every system that rolls dice
+/// draws from this so a run can be replayed from its seed (`--seed`).
+/// Purely cosmetic randomness (torch flicker, sound variation, falling ice
+/// shards, lightning bolt shapes) stays on `thread_rng` so it can't shift
+/// gameplay rolls.
+#[derive(Resource, Debug)]
+pub struct GameRng {
+    seed: u64,
+    /// Seed given explicitly; every run restarts from it
+    fixed: bool,
+    rng: StdRng,
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+impl GameRng {
+    /// Deterministic rng: every run replays the same sequence
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            fixed: true,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Rng with a random seed; each run gets a new one
+    pub fn from_entropy() -> Self {
+        let seed = rand::random();
+        Self {
+            seed,
+            fixed: false,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Seed of the current run (log it to reproduce the run with `--seed`)
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Start the sequence for a new run: from the same seed when it was
+    /// given explicitly, from a fresh random seed otherwise.
+    pub fn restart(&mut self) {
+        if !self.fixed {
+            self.seed = rand::random();
+        }
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
 /// Configuration for game level progression
 #[derive(Debug, Clone)]
 pub struct LevelConfig {
@@ -39,6 +119,8 @@ pub struct GameLevel {
     pub kills_this_level: u32,
     /// Total enemies killed this game
     pub total_kills: u32,
+    /// Whether this level's boss has already been summoned
+    pub boss_spawned: bool,
     /// Configuration for progression
     pub config: LevelConfig,
 }
@@ -55,6 +137,7 @@ impl GameLevel {
             level: 1,
             kills_this_level: 0,
             total_kills: 0,
+            boss_spawned: false,
             config: LevelConfig::default(),
         }
     }
@@ -65,47 +148,70 @@ impl GameLevel {
         (self.config.base_kills as f32 * multiplier).ceil() as u32
     }
 
-    /// Register a kill and return true if level advanced
-    pub fn register_kill(&mut self) -> bool {
+    /// Register a kill. Once enough kills are in, `boss_due` reports the boss;
+    /// the level itself only advances once the boss is defeated (see `advance_level`).
+    pub fn register_kill(&mut self) {
         self.kills_this_level += 1;
         self.total_kills += 1;
+    }
 
-        if self.kills_this_level >= self.kills_to_advance() {
-            self.level += 1;
-            self.kills_this_level = 0;
-            true
-        } else {
-            false
-        }
+    /// True once the kill threshold is reached and the boss hasn't been summoned yet.
+    /// Stays true until `spawn_boss_system` summons it, so the boss can't be missed
+    /// while the game is paused or showing an overlay.
+    pub fn boss_due(&self) -> bool {
+        !self.boss_spawned && self.kills_this_level >= self.kills_to_advance()
+    }
+
+    /// Advance to the next level and reset the per-level kill count
+    pub fn advance_level(&mut self) {
+        self.level += 1;
+        self.kills_this_level = 0;
+        self.boss_spawned = false;
     }
 
     /// Progress percentage toward next level (0.0 - 1.0)
     pub fn progress(&self) -> f32 {
-        self.kills_this_level as f32 / self.kills_to_advance() as f32
+        (self.kills_this_level as f32 / self.kills_to_advance() as f32).min(1.0)
     }
 }
 
 #[derive(Resource, Default)]
 pub struct PlayerPosition(pub Vec2);
 
-#[derive(Resource)]
+/// Progress through the current game level's wave script
+/// (see `enemies::waves::WaveScript`).
+#[derive(Resource, Debug, Default)]
 pub struct EnemySpawnState {
+    /// Game time not yet turned into spawns
     pub time_since_last_spawn: f32,
+    /// Game level the progress below belongs to
+    pub level: u32,
+    /// Seconds of spawning into the level; wave windows open and close on this clock
+    pub level_elapsed: f32,
+    /// Unspent fraction of a formation for each of the level's wave windows
+    pub window_progress: Vec<f32>,
+    /// Level time at which the current horde ends
+    pub horde_until: f32,
+    /// Spawn rate multiplier while a horde lasts
+    pub horde_rate_multiplier: f32,
 }
 
-impl Default for EnemySpawnState {
-    fn default() -> Self {
-        Self {
-            time_since_last_spawn: 0.0,
-        }
+impl EnemySpawnState {
+    /// Start `level`'s script from the beginning
+    pub fn restart(&mut self, level: u32, window_count: usize) {
+        self.level = level;
+        self.level_elapsed = 0.0;
+        self.window_progress = vec![0.0; window_count];
+        self.horde_until = 0.0;
     }
-}
 
-impl EnemySpawnState {
-    /// Calculate spawn rate based on game level.
-    /// Level 1: 0.504 enemies/second (+20% from 0.42), then 1.35x per level.
-    pub fn spawn_rate_for_level(game_level: u32) -> f32 {
-        0.504 * 1.35_f32.powi(game_level.saturating_sub(1) as i32)
+    /// Spawn rate multiplier at a level time: the horde's while one lasts, 1.0 otherwise
+    pub fn rate_multiplier_at(&self, level_time: f32) -> f32 {
+        if level_time < self.horde_until {
+            self.horde_rate_multiplier
+        } else {
+            1.0
+        }
     }
 }
 
@@ -121,10 +227,15 @@ pub struct ScreenTintEffect {
     pub color: Color,
 }
 
-/// Tracks how long the player has survived in the current game session
+/// Tracks how long the player has survived in the current level
 #[derive(Resource, Default)]
 pub struct SurvivalTime(pub f32);
 
+/// Tracks how long the player has survived in the current run.
+/// Unlike `SurvivalTime` it only restarts on a fresh game, so it covers every level.
+#[derive(Resource, Default)]
+pub struct RunSurvivalTime(pub f32);
+
 /// Tracks whether the camera is in free-look mode (right mouse button held)
 #[derive(Resource, Default)]
 pub struct FreeCameraState {
@@ -154,6 +265,20 @@ pub struct GameMeshes {
     pub player: Handle<Mesh>,
     /// Enemy mesh (0.75 x 1.5 x 0.75 rectangular prism - double height)
     pub enemy: Handle<Mesh>,
+    /// Ranged enemy mesh (cylinder, 1.5 tall)
+    pub enemy_ranged: Handle<Mesh>,
+    /// Charger enemy mesh (cone, 1.5 tall)
+    pub enemy_charger: Handle<Mesh>,
+    /// Splitter enemy mesh (capsule, 1.5 tall)
+    pub enemy_splitter: Handle<Mesh>,
+    /// Tank enemy mesh (wide 1.1 x 1.5 x 1.1 block)
+    pub enemy_tank: Handle<Mesh>,
+    /// Swarm enemy mesh (sphere, 1.5 diameter)
+    pub enemy_swarm: Handle<Mesh>,
+    /// Ranged enemy projectile mesh (small sphere)
+    pub enemy_projectile: Handle<Mesh>,
+    /// Boss mesh (1.5 x 3.0 x 1.5 block - twice the height of a regular enemy)
+    pub boss: Handle<Mesh>,
     /// Bullet mesh (0.3 x 0.3 x 0.3 cube)
     pub bullet: Handle<Mesh>,
     /// Fireball mesh (sphere with radius 0.3)
@@ -193,6 +318,14 @@ impl GameMeshes {
         Self {
             player: meshes.add(Cuboid::new(1.0, 1.0, 1.0)),
             enemy: meshes.add(Cuboid::new(0.75, 1.5, 0.75)),
+            // Enemy kind meshes all share the 1.5 height so ENEMY_Y_HEIGHT still applies
+            enemy_ranged: meshes.add(Cylinder::new(0.35, 1.5)),
+            enemy_charger: meshes.add(Cone::new(0.55, 1.5)),
+            enemy_splitter: meshes.add(Capsule3d::new(0.4, 0.7)),
+            enemy_tank: meshes.add(Cuboid::new(1.1, 1.5, 1.1)),
+            enemy_swarm: meshes.add(Sphere::new(0.75)),
+            enemy_projectile: meshes.add(Sphere::new(0.2)),
+            boss: meshes.add(Cuboid::new(1.5, 3.0, 1.5)),
             bullet: meshes.add(Cuboid::new(0.3, 0.3, 0.3)),
             // High-poly sphere for vertex displacement in fire shader
             fireball: meshes.add(Sphere::new(0.3).mesh().ico(5).unwrap()),
@@ -214,6 +347,18 @@ impl GameMeshes {
             glacial_spike: meshes.add(Cone::new(1.0, 1.0)),
         }
     }
+
+    /// Get the body mesh for an enemy kind
+    pub fn enemy_for_kind(&self, kind: EnemyKind) -> Handle<Mesh> {
+        match kind {
+            EnemyKind::Grunt => self.enemy.clone(),
+            EnemyKind::Ranged => self.enemy_ranged.clone(),
+            EnemyKind::Charger => self.enemy_charger.clone(),
+            EnemyKind::Splitter => self.enemy_splitter.clone(),
+            EnemyKind::Tank => self.enemy_tank.clone(),
+            EnemyKind::Swarm => self.enemy_swarm.clone(),
+        }
+    }
 }
 
 /// Calculate enemy scale based on level
@@ -278,6 +423,122 @@ impl EnemyLevelMaterials {
     }
 }
 
+/// Materials for the special enemy kinds.
+/// Grunts keep the rarity colors from `EnemyLevelMaterials`.
+#[derive(Resource)]
+pub struct EnemyKindMaterials {
+    /// Ranged caster (teal with emissive glow)
+    pub ranged: Handle<StandardMaterial>,
+    /// Charger (burnt orange)
+    pub charger: Handle<StandardMaterial>,
+    /// Splitter (magenta, hints at the chaos fracture it carries)
+    pub splitter: Handle<StandardMaterial>,
+    /// Tank (dark steel, slightly metallic)
+    pub tank: Handle<StandardMaterial>,
+    /// Swarm (sickly yellow-green)
+    pub swarm: Handle<StandardMaterial>,
+    /// Ranged enemy projectile (teal with strong emissive glow)
+    pub projectile: Handle<StandardMaterial>,
+    /// Charger dash telegraph (translucent red ground marker)
+    pub charge_telegraph: Handle<StandardMaterial>,
+    /// Level boss (deep crimson with a molten glow)
+    pub boss: Handle<StandardMaterial>,
+}
+
+impl EnemyKindMaterials {
+    pub fn new(materials: &mut Assets<StandardMaterial>) -> Self {
+        Self {
+            ranged: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.0, 0.7, 0.7),
+                emissive: bevy::color::LinearRgba::rgb(0.0, 0.4, 0.4),
+                ..default()
+            }),
+            charger: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.85, 0.4, 0.1),
+                ..default()
+            }),
+            splitter: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.8, 0.2, 0.6),
+                emissive: bevy::color::LinearRgba::rgb(0.3, 0.0, 0.2),
+                ..default()
+            }),
+            tank: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.3, 0.32, 0.38),
+                metallic: 0.6,
+                perceptual_roughness: 0.5,
+                ..default()
+            }),
+            swarm: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.7, 0.8, 0.1),
+                ..default()
+            }),
+            projectile: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.2, 1.0, 1.0),
+                emissive: bevy::color::LinearRgba::rgb(0.5, 3.0, 3.0),
+                unlit: true,
+                ..default()
+            }),
+            charge_telegraph: materials.add(StandardMaterial {
+                base_color: Color::srgba(1.0, 0.1, 0.0, 0.4),
+                emissive: bevy::color::LinearRgba::rgb(1.5, 0.1, 0.0),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+            boss: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.55, 0.05, 0.1),
+                emissive: bevy::color::LinearRgba::rgb(1.2, 0.15, 0.05),
+                ..default()
+            }),
+        }
+    }
+
+    /// Get the body material for an enemy kind.
+    /// Returns None for Grunts, which use the level-based rarity material.
+    pub fn for_kind(&self, kind: EnemyKind) -> Option<Handle<StandardMaterial>> {
+        match kind {
+            EnemyKind::Grunt => None,
+            EnemyKind::Ranged => Some(self.ranged.clone()),
+            EnemyKind::Charger => Some(self.charger.clone()),
+            EnemyKind::Splitter => Some(self.splitter.clone()),
+            EnemyKind::Tank => Some(self.tank.clone()),
+            EnemyKind::Swarm => Some(self.swarm.clone()),
+        }
+    }
+}
+
+/// Glowing orb materials for elite affixes, in each affix's color
+#[derive(Resource)]
+pub struct EliteAffixMaterials {
+    materials: HashMap<EliteAffix, Handle<StandardMaterial>>,
+}
+
+impl EliteAffixMaterials {
+    pub fn new(materials: &mut Assets<StandardMaterial>) -> Self {
+        let auras = Element::all().iter().map(|element| EliteAffix::ElementalAura(*element));
+        Self {
+            materials: EliteAffix::BASIC
+                .into_iter()
+                .chain(auras)
+                .map(|affix| {
+                    let color = affix.color();
+                    let material = materials.add(StandardMaterial {
+                        base_color: color,
+                        emissive: color.to_linear() * 2.0,
+                        unlit: true,
+                        ..default()
+                    });
+                    (affix, material)
+                })
+                .collect(),
+        }
+    }
+
+    pub fn for_affix(&self, affix: EliteAffix) -> Handle<StandardMaterial> {
+        self.materials[&affix].clone()
+    }
+}
+
 /// Materials for each XP orb rarity level (1-5)
 /// Higher level orbs have emissive glow for visual distinction
 #[derive(Resource)]
@@ -385,6 +646,190 @@ impl LevelStats {
     }
 }
 
+/// Damage, kills and casts credited to a single spell
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SpellTotals {
+    /// Damage dealt after crits and modifiers
+    pub damage: f32,
+    /// Killing blows landed
+    pub kills: u32,
+    /// Times the spell was cast
+    pub casts: u32,
+}
+
+/// Column of the per-spell stats table, used as its sort key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpellStatColumn {
+    #[default]
+    Damage,
+    Kills,
+    Casts,
+    Dps,
+}
+
+impl SpellStatColumn {
+    /// All columns in display order
+    pub const ALL: [SpellStatColumn; 4] = [
+        SpellStatColumn::Damage,
+        SpellStatColumn::Kills,
+        SpellStatColumn::Casts,
+        SpellStatColumn::Dps,
+    ];
+
+    /// Column header text
+    pub fn label(&self) -> &'static str {
+        match self {
+            SpellStatColumn::Damage => "Damage",
+            SpellStatColumn::Kills => "Kills",
+            SpellStatColumn::Casts => "Casts",
+            SpellStatColumn::Dps => "DPS",
+        }
+    }
+}
+
+/// One row of the per-spell stats table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpellStatRow {
+    pub spell_type: SpellType,
+    pub damage: f32,
+    pub kills: u32,
+    pub casts: u32,
+    /// Damage per second over the table's elapsed time
+    pub dps: f32,
+}
+
+impl SpellStatRow {
+    /// Value of the given column, for sorting
+    pub fn value(&self, column: SpellStatColumn) -> f32 {
+        match column {
+            SpellStatColumn::Damage => self.damage,
+            SpellStatColumn::Kills => self.kills as f32,
+            SpellStatColumn::Casts => self.casts as f32,
+            SpellStatColumn::Dps => self.dps,
+        }
+    }
+
+    /// Formatted cell text for the given column
+    pub fn cell(&self, column: SpellStatColumn) -> String {
+        match column {
+            SpellStatColumn::Damage => format!("{:.0}", self.damage),
+            SpellStatColumn::Kills => self.kills.to_string(),
+            SpellStatColumn::Casts => self.casts.to_string(),
+            SpellStatColumn::Dps => format!("{:.1}", self.dps),
+        }
+    }
+}
+
+/// Per-spell totals over one span of play (a level or a whole run)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpellStatTable {
+    totals: HashMap<SpellType, SpellTotals>,
+    /// Time covered by this table (seconds), used for DPS
+    pub time_elapsed: f32,
+}
+
+impl SpellStatTable {
+    /// Credit damage to a spell, counting a kill if it was the killing blow
+    pub fn record_damage(&mut self, spell: SpellType, amount: f32, killing_blow: bool) {
+        let totals = self.totals.entry(spell).or_default();
+        totals.damage += amount;
+        if killing_blow {
+            totals.kills += 1;
+        }
+    }
+
+    /// Record one cast of a spell
+    pub fn record_cast(&mut self, spell: SpellType) {
+        self.totals.entry(spell).or_default().casts += 1;
+    }
+
+    /// Totals for a spell (zero if it never did anything)
+    pub fn get(&self, spell: SpellType) -> SpellTotals {
+        self.totals.get(&spell).copied().unwrap_or_default()
+    }
+
+    /// Number of spells with recorded totals
+    pub fn len(&self) -> usize {
+        self.totals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.totals.is_empty()
+    }
+
+    /// Clear all totals and the elapsed time
+    pub fn clear(&mut self) {
+        self.totals.clear();
+        self.time_elapsed = 0.0;
+    }
+
+    /// Rows sorted by the given column, highest first.
+    /// Ties fall back to damage, then to spell id so the order is stable.
+    pub fn rows(&self, sort: SpellStatColumn) -> Vec<SpellStatRow> {
+        let mut rows: Vec<SpellStatRow> = self
+            .totals
+            .iter()
+            .map(|(&spell_type, totals)| SpellStatRow {
+                spell_type,
+                damage: totals.damage,
+                kills: totals.kills,
+                casts: totals.casts,
+                dps: if self.time_elapsed > 0.0 {
+                    totals.damage / self.time_elapsed
+                } else {
+                    0.0
+                },
+            })
+            .collect();
+        rows.sort_by(|a, b| {
+            b.value(sort)
+                .total_cmp(&a.value(sort))
+                .then_with(|| b.damage.total_cmp(&a.damage))
+                .then_with(|| a.spell_type.id().cmp(&b.spell_type.id()))
+        });
+        rows
+    }
+}
+
+/// Per-spell damage, kills and casts for the current level and the whole run.
+/// Shown on the LevelComplete (level) and GameOver (run) screens.
+#[derive(Resource, Debug, Default)]
+pub struct SpellStats {
+    pub level: SpellStatTable,
+    pub run: SpellStatTable,
+}
+
+impl SpellStats {
+    /// Credit damage to a spell in both tables
+    pub fn record_damage(&mut self, spell: SpellType, amount: f32, killing_blow: bool) {
+        self.level.record_damage(spell, amount, killing_blow);
+        self.run.record_damage(spell, amount, killing_blow);
+    }
+
+    /// Record a cast in both tables
+    pub fn record_cast(&mut self, spell: SpellType) {
+        self.level.record_cast(spell);
+        self.run.record_cast(spell);
+    }
+
+    /// Advance the elapsed time of both tables
+    pub fn tick(&mut self, delta: f32) {
+        self.level.time_elapsed += delta;
+        self.run.time_elapsed += delta;
+    }
+
+    /// Start a new level, keeping the run totals
+    pub fn reset_level(&mut self) {
+        self.level.clear();
+    }
+
+    /// Start a new run
+    pub fn reset(&mut self) {
+        self.level.clear();
+        self.run.clear();
+    }
+}
+
 /// Materials for spell loot drops by element
 /// Each element has its own emissive material for visual distinction
 #[derive(Resource)]
@@ -766,6 +1211,44 @@ impl GameMaterials {
 mod tests {
     use super::*;
 
+    mod game_rng_tests {
+        use super::*;
+        use rand::Rng;
+
+        fn draws(rng: &mut GameRng) -> Vec<u32> {
+            (0..8).map(|_| rng.gen_range(0..1000)).collect()
+        }
+
+        #[test]
+        fn same_seed_gives_same_sequence() {
+            let mut a = GameRng::from_seed(42);
+            let mut b = GameRng::from_seed(42);
+            assert_eq!(draws(&mut a), draws(&mut b));
+            assert_ne!(draws(&mut a), draws(&mut GameRng::from_seed(43)));
+        }
+
+        #[test]
+        fn restart_replays_a_fixed_seed() {
+            let mut rng = GameRng::from_seed(7);
+            let first = draws(&mut rng);
+            rng.restart();
+            assert_eq!(rng.seed(), 7);
+            assert_eq!(draws(&mut rng), first);
+        }
+
+        #[test]
+        fn restart_picks_a_new_seed_when_unseeded() {
+            let mut rng = GameRng::from_entropy();
+            let seeds: std::collections::HashSet<u64> = (0..4)
+                .map(|_| {
+                    rng.restart();
+                    rng.seed()
+                })
+                .collect();
+            assert!(seeds.len() > 1);
+        }
+    }
+
     mod game_level_tests {
         use super::*;
 
@@ -830,36 +1313,75 @@ mod tests {
         }
 
         #[test]
-        fn register_kill_returns_false_before_threshold() {
+        fn boss_not_due_before_threshold() {
             let mut level = GameLevel::new();
             for _ in 0..9 {
-                assert!(!level.register_kill());
+                level.register_kill();
+                assert!(!level.boss_due());
             }
             assert_eq!(level.kills_this_level, 9);
             assert_eq!(level.level, 1);
         }
 
         #[test]
-        fn register_kill_advances_level_at_threshold() {
+        fn boss_due_at_threshold_without_advancing() {
             let mut level = GameLevel::new();
             let threshold = level.kills_to_advance();
             for _ in 0..threshold - 1 {
-                assert!(!level.register_kill());
+                level.register_kill();
+                assert!(!level.boss_due());
             }
-            // This should be the 10th kill, advancing the level
-            assert!(level.register_kill());
+            // The 10th kill reaches the boss threshold, but the level stays put
+            level.register_kill();
+            assert!(level.boss_due());
+            assert_eq!(level.level, 1);
+            assert_eq!(level.kills_this_level, 10);
+            assert_eq!(level.total_kills, 10);
+
+            // Kills past the threshold keep reporting it until the boss is summoned
+            level.register_kill();
+            assert!(level.boss_due());
+            level.boss_spawned = true;
+            level.register_kill();
+            assert!(!level.boss_due());
+        }
+
+        #[test]
+        fn boss_due_survives_overshooting_the_threshold() {
+            let mut level = GameLevel::new();
+            level.kills_this_level = level.kills_to_advance() + 5;
+            assert!(level.boss_due());
+        }
+
+        #[test]
+        fn advance_level_clears_boss_spawned() {
+            let mut level = GameLevel::new();
+            level.boss_spawned = true;
+            level.advance_level();
+            assert!(!level.boss_spawned);
+            assert!(!level.boss_due());
+        }
+
+        #[test]
+        fn advance_level_resets_kills_this_level() {
+            let mut level = GameLevel::new();
+            for _ in 0..12 {
+                level.register_kill();
+            }
+            level.advance_level();
             assert_eq!(level.level, 2);
             assert_eq!(level.kills_this_level, 0);
-            assert_eq!(level.total_kills, 10);
+            assert_eq!(level.total_kills, 12);
         }
 
         #[test]
         fn register_kill_tracks_total_across_levels() {
             let mut level = GameLevel::new();
-            // Advance to level 2 (10 kills)
+            // Reach the level 1 threshold (10 kills), then beat the boss
             for _ in 0..10 {
                 level.register_kill();
             }
+            level.advance_level();
             assert_eq!(level.level, 2);
             assert_eq!(level.total_kills, 10);
 
@@ -884,6 +1406,10 @@ mod tests {
             level.kills_this_level = 10;
             // 10/10 = 1.0
             assert!((level.progress() - 1.0).abs() < 0.01);
+
+            // Kills while the boss is alive don't overflow the bar
+            level.kills_this_level = 14;
+            assert!((level.progress() - 1.0).abs() < 0.01);
         }
 
         #[test]
@@ -921,56 +1447,34 @@ mod tests {
         assert_eq!(time.0, 1.5);
     }
 
-    mod spawn_rate_tests {
-        use super::*;
-
-        #[test]
-        fn spawn_rate_at_level_1() {
-            let rate = EnemySpawnState::spawn_rate_for_level(1);
-            // +20% from 0.42 = 0.504
-            assert!((rate - 0.504).abs() < 0.001, "Level 1 should have 0.504 enemies/sec, got {}", rate);
-        }
-
-        #[test]
-        fn spawn_rate_at_level_2() {
-            let rate = EnemySpawnState::spawn_rate_for_level(2);
-            // 0.504 * 1.35 = 0.6804
-            assert!((rate - 0.6804).abs() < 0.001, "Level 2 should have 0.6804 enemies/sec, got {}", rate);
-        }
-
-        #[test]
-        fn spawn_rate_at_level_5() {
-            let rate = EnemySpawnState::spawn_rate_for_level(5);
-            // 0.504 * 1.35^4
-            let expected = 0.504 * 1.35_f32.powi(4);
-            assert!((rate - expected).abs() < 0.001, "Level 5 should have {} enemies/sec, got {}", expected, rate);
-        }
-
-        #[test]
-        fn spawn_rate_at_level_10() {
-            let rate = EnemySpawnState::spawn_rate_for_level(10);
-            // 0.504 * 1.35^9
-            let expected = 0.504 * 1.35_f32.powi(9);
-            assert!((rate - expected).abs() < 0.001, "Level 10 should have {} enemies/sec, got {}", expected, rate);
-        }
-
-        #[test]
-        fn spawn_rate_increases_with_level() {
-            let rate_1 = EnemySpawnState::spawn_rate_for_level(1);
-            let rate_5 = EnemySpawnState::spawn_rate_for_level(5);
-            let rate_10 = EnemySpawnState::spawn_rate_for_level(10);
+    #[test]
+    fn enemy_spawn_state_restart_keeps_unspent_time() {
+        let mut state = EnemySpawnState {
+            time_since_last_spawn: 0.5,
+            level: 2,
+            level_elapsed: 40.0,
+            window_progress: vec![0.3, 0.9],
+            horde_until: 60.0,
+            horde_rate_multiplier: 2.0,
+        };
+        state.restart(3, 3);
 
-            assert!(rate_5 > rate_1, "Level 5 should have higher rate than level 1");
-            assert!(rate_10 > rate_5, "Level 10 should have higher rate than level 5");
-        }
+        assert_eq!(state.level, 3);
+        assert_eq!(state.rate_multiplier_at(0.0), 1.0);
+        assert_eq!(state.level_elapsed, 0.0);
+        assert_eq!(state.window_progress, vec![0.0; 3]);
+        assert_eq!(state.time_since_last_spawn, 0.5);
+    }
 
-        #[test]
-        fn spawn_rate_at_level_0_equals_level_1() {
-            // Edge case: level 0 should behave like level 1
-            let rate_0 = EnemySpawnState::spawn_rate_for_level(0);
-            let rate_1 = EnemySpawnState::spawn_rate_for_level(1);
-            assert!((rate_0 - rate_1).abs() < 0.001, "Level 0 should equal level 1");
-        }
+    #[test]
+    fn enemy_spawn_state_horde_multiplies_rate_until_it_ends() {
+        let state = EnemySpawnState {
+            horde_until: 60.0,
+            horde_rate_multiplier: 2.0,
+            ..default()
+        };
+        assert_eq!(state.rate_multiplier_at(30.0), 2.0);
+        assert_eq!(state.rate_multiplier_at(60.0), 1.0);
     }
 
     #[test]
@@ -1017,6 +1521,13 @@ mod tests {
             // Verify all handles can retrieve their assets (are strong handles)
             assert!(meshes.get(&game_meshes.player).is_some());
             assert!(meshes.get(&game_meshes.enemy).is_some());
+            assert!(meshes.get(&game_meshes.enemy_ranged).is_some());
+            assert!(meshes.get(&game_meshes.enemy_charger).is_some());
+            assert!(meshes.get(&game_meshes.enemy_splitter).is_some());
+            assert!(meshes.get(&game_meshes.enemy_tank).is_some());
+            assert!(meshes.get(&game_meshes.enemy_swarm).is_some());
+            assert!(meshes.get(&game_meshes.enemy_projectile).is_some());
+            assert!(meshes.get(&game_meshes.boss).is_some());
             assert!(meshes.get(&game_meshes.bullet).is_some());
             assert!(meshes.get(&game_meshes.laser).is_some());
             assert!(meshes.get(&game_meshes.rocket).is_some());
@@ -1378,6 +1889,61 @@ mod tests {
         }
     }
 
+    mod enemy_kind_materials_tests {
+        use super::*;
+        use bevy::asset::Assets;
+        use bevy::pbr::StandardMaterial;
+
+        #[test]
+        fn grunts_fall_back_to_level_materials() {
+            let mut app = App::new();
+            app.add_plugins(bevy::asset::AssetPlugin::default());
+            app.init_asset::<StandardMaterial>();
+            let mut materials = app.world_mut().resource_mut::<Assets<StandardMaterial>>();
+
+            let kind_materials = EnemyKindMaterials::new(&mut materials);
+
+            assert!(kind_materials.for_kind(EnemyKind::Grunt).is_none());
+            for kind in EnemyKind::all().iter().filter(|k| **k != EnemyKind::Grunt) {
+                let handle = kind_materials.for_kind(*kind).expect("special kinds have a material");
+                assert!(materials.get(&handle).is_some(), "{:?}", kind);
+            }
+        }
+
+        #[test]
+        fn every_affix_has_a_material() {
+            let mut app = App::new();
+            app.add_plugins(bevy::asset::AssetPlugin::default());
+            app.init_asset::<StandardMaterial>();
+            let mut materials = app.world_mut().resource_mut::<Assets<StandardMaterial>>();
+
+            let affix_materials = EliteAffixMaterials::new(&mut materials);
+
+            for affix in EliteAffix::BASIC {
+                assert!(materials.get(&affix_materials.for_affix(affix)).is_some(), "{:?}", affix);
+            }
+            for element in Element::all() {
+                let handle = affix_materials.for_affix(EliteAffix::ElementalAura(*element));
+                assert!(materials.get(&handle).is_some(), "{:?}", element);
+            }
+        }
+
+        #[test]
+        fn every_kind_has_a_distinct_mesh() {
+            let mut app = App::new();
+            app.add_plugins(bevy::asset::AssetPlugin::default());
+            app.init_asset::<Mesh>();
+            let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
+            let game_meshes = GameMeshes::new(&mut meshes);
+
+            let handles: std::collections::HashSet<_> = EnemyKind::all()
+                .iter()
+                .map(|kind| game_meshes.enemy_for_kind(*kind).id())
+                .collect();
+            assert_eq!(handles.len(), EnemyKind::all().len());
+        }
+    }
+
     mod xp_orb_materials_tests {
         use super::*;
         use bevy::asset::Assets;
@@ -1615,6 +2181,96 @@ mod tests {
         }
     }
 
+    mod spell_stats_tests {
+        use super::*;
+
+        #[test]
+        fn records_damage_kills_and_casts_per_spell() {
+            let mut table = SpellStatTable::default();
+            table.record_cast(SpellType::Fireball);
+            table.record_damage(SpellType::Fireball, 30.0, false);
+            table.record_damage(SpellType::Fireball, 12.5, true);
+            table.record_damage(SpellType::IceShard, 8.0, true);
+
+            assert_eq!(
+                table.get(SpellType::Fireball),
+                SpellTotals { damage: 42.5, kills: 1, casts: 1 }
+            );
+            assert_eq!(table.get(SpellType::IceShard).kills, 1);
+            assert_eq!(table.get(SpellType::Spark), SpellTotals::default());
+            assert_eq!(table.len(), 2);
+        }
+
+        #[test]
+        fn rows_sort_by_column_descending() {
+            let mut table = SpellStatTable::default();
+            table.record_damage(SpellType::Fireball, 100.0, true);
+            for _ in 0..5 {
+                table.record_cast(SpellType::Spark);
+                table.record_damage(SpellType::Spark, 10.0, true);
+            }
+            table.record_cast(SpellType::Fireball);
+
+            let by_damage: Vec<_> = table.rows(SpellStatColumn::Damage).iter().map(|r| r.spell_type).collect();
+            assert_eq!(by_damage, vec![SpellType::Fireball, SpellType::Spark]);
+
+            let by_kills: Vec<_> = table.rows(SpellStatColumn::Kills).iter().map(|r| r.spell_type).collect();
+            assert_eq!(by_kills, vec![SpellType::Spark, SpellType::Fireball]);
+
+            let by_casts: Vec<_> = table.rows(SpellStatColumn::Casts).iter().map(|r| r.spell_type).collect();
+            assert_eq!(by_casts, vec![SpellType::Spark, SpellType::Fireball]);
+        }
+
+        #[test]
+        fn rows_tie_break_on_damage_then_spell_id() {
+            let mut table = SpellStatTable::default();
+            table.record_damage(SpellType::IceShard, 5.0, false);
+            table.record_damage(SpellType::Fireball, 5.0, false);
+            table.record_damage(SpellType::Spark, 9.0, false);
+
+            let rows: Vec<_> = table.rows(SpellStatColumn::Kills).iter().map(|r| r.spell_type).collect();
+            assert_eq!(rows, vec![SpellType::Spark, SpellType::Fireball, SpellType::IceShard]);
+        }
+
+        #[test]
+        fn dps_uses_table_elapsed_time() {
+            let mut table = SpellStatTable::default();
+            table.record_damage(SpellType::Fireball, 50.0, false);
+            assert_eq!(table.rows(SpellStatColumn::Dps)[0].dps, 0.0);
+
+            table.time_elapsed = 10.0;
+            let row = table.rows(SpellStatColumn::Dps)[0];
+            assert_eq!(row.dps, 5.0);
+            assert_eq!(row.cell(SpellStatColumn::Dps), "5.0");
+            assert_eq!(row.cell(SpellStatColumn::Damage), "50");
+        }
+
+        #[test]
+        fn reset_level_keeps_run_totals() {
+            let mut stats = SpellStats::default();
+            stats.record_cast(SpellType::Fireball);
+            stats.record_damage(SpellType::Fireball, 20.0, true);
+            stats.tick(2.0);
+
+            stats.reset_level();
+            assert!(stats.level.is_empty());
+            assert_eq!(stats.level.time_elapsed, 0.0);
+            assert_eq!(stats.run.get(SpellType::Fireball).damage, 20.0);
+            assert_eq!(stats.run.time_elapsed, 2.0);
+
+            stats.reset();
+            assert!(stats.run.is_empty());
+            assert_eq!(stats.run.time_elapsed, 0.0);
+        }
+
+        #[test]
+        fn columns_have_labels() {
+            let labels: Vec<_> = SpellStatColumn::ALL.iter().map(|c| c.label()).collect();
+            assert_eq!(labels, vec!["Damage", "Kills", "Casts", "DPS"]);
+            assert_eq!(SpellStatColumn::default(), SpellStatColumn::Damage);
+        }
+    }
+
     mod spell_loot_materials_tests {
         use super::*;
         use bevy::asset::Assets;
@@ -8,6 +8,8 @@ pub enum GameSet {
     Input,
     /// Movement systems - player, enemy, projectile movement
     Movement,
+    /// Spatial index rebuild - after movement so combat queries see this frame's positions
+    Spatial,
     /// Combat systems - damage, health, collisions
     Combat,
     /// Spawning systems - enemies, loot, projectiles
@@ -52,6 +54,7 @@ mod tests {
         // Verify all expected variants exist
         let _input = GameSet::Input;
         let _movement = GameSet::Movement;
+        let _spatial = GameSet::Spatial;
         let _combat = GameSet::Combat;
         let _spawning = GameSet::Spawning;
         let _effects = GameSet::Effects;
@@ -69,6 +72,7 @@ mod tests {
             (
                 GameSet::Input,
                 GameSet::Movement,
+                GameSet::Spatial,
                 GameSet::Combat,
                 GameSet::Spawning,
                 GameSet::Effects,
@@ -2,18 +2,21 @@ use bevy::prelude::*;
 use bevy::camera::ScalingMode;
 use bevy::core_pipeline::tonemapping::Tonemapping;
 use bevy::ecs::world::World;
-use bevy::image::{ImageLoaderSettings, ImageSampler, ImageAddressMode, ImageSamplerDescriptor};
 use bevy::post_process::bloom::Bloom;
 use bevy::render::view::Hdr;
-use rand::Rng;
 
-use crate::combat::components::Health;
+use crate::arena::layout::Biome;
+use crate::arena::systems::ground_material;
+use crate::combat::components::{Armor, Health};
+use crate::combat::{DamageDealt, DamageEvent, DamageModifierRegistry};
 use crate::enemies::components::*;
 use crate::game::components::*;
-use crate::game::resources::{DamageFlashMaterial, EnemyLevelMaterials, GameLevel, GameMaterials, GameMeshes, LevelStats, PlayerDamageTimer, ScreenTintEffect, SpellLootMaterials, SurvivalTime, XpOrbMaterials};
+use crate::game::resources::{DamageFlashMaterial, EliteAffixMaterials, EnemyKindMaterials, EnemyLevelMaterials, EnemySpawnState, GameLevel, GameRng, GameMaterials, GameMeshes, LevelStats, PlayerDamageTimer, RunSurvivalTime, ScreenTintEffect, SpellLootMaterials, SpellStats, SurvivalTime, XpOrbMaterials};
 use crate::game::events::*;
+use crate::level_up::resources::RerollTokens;
 use crate::movement::components::from_xz;
 use crate::player::components::*;
+use crate::profile::resources::{MetaProfile, MetaUpgrade, RunUpgrades};
 use crate::states::*;
 use crate::whisper::components::WhisperCompanion;
 
@@ -21,14 +24,13 @@ use crate::whisper::components::WhisperCompanion;
 #[allow(clippy::too_many_arguments)]
 pub fn setup_game(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    asset_server: Res<AssetServer>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+    asset_server: Option<Res<AssetServer>>,
     camera_query: Query<Entity, With<Camera>>,
     player_query: Query<Entity, With<Player>>,
-    game_meshes: Res<GameMeshes>,
-    game_materials: Res<GameMaterials>,
     fresh_start: Res<crate::game::resources::FreshGameStart>,
+    profile: Option<Res<MetaProfile>>,
 ) {
     // Reuse existing camera if available, otherwise spawn new one
     if camera_query.is_empty() {
@@ -65,33 +67,37 @@ pub fn setup_game(
             affects_lightmapped_meshes: false,
         });
 
-        // Add ground plane with concrete texture (tiled) and physics collider
-        let ground_texture = asset_server.load_with_settings(
-            "textures/concrete-01.png",
-            |settings: &mut ImageLoaderSettings| {
-                settings.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
-                    address_mode_u: ImageAddressMode::Repeat,
-                    address_mode_v: ImageAddressMode::Repeat,
-                    ..default()
-                });
-            },
-        );
-        commands.spawn((
-            Mesh3d(meshes.add(Plane3d::new(Vec3::Y, Vec2::new(100.0, 100.0)))),
-            MeshMaterial3d(materials.add(StandardMaterial {
-                base_color_texture: Some(ground_texture),
-                // Scale UVs to tile the texture across the ground plane
-                uv_transform: bevy::math::Affine2::from_scale(Vec2::new(20.0, 20.0)),
-                ..default()
-            })),
-            Transform::from_translation(Vec3::ZERO),
-            GroundPlane,
-        ));
+        // Add ground plane; the arena re-skins it with each layout's biome
+        if let (Some(mut meshes), Some(mut materials), Some(asset_server)) =
+            (meshes, materials, asset_server)
+        {
+            commands.spawn((
+                Mesh3d(meshes.add(Plane3d::new(Vec3::Y, Vec2::new(100.0, 100.0)))),
+                MeshMaterial3d(materials.add(ground_material(&asset_server, &Biome::default()))),
+                Transform::from_translation(Vec3::ZERO),
+                GroundPlane,
+            ));
+        }
     }
     // If camera exists, we reuse it (no action needed)
 
-    // Only spawn player and rocks on a fresh game start (not when continuing from level complete)
+    // Only spawn the player on a fresh game start (not when continuing from level complete).
+    // Rocks belong to the arena layout and are rebuilt per level by the arena plugin.
     if fresh_start.0 && player_query.is_empty() {
+        // Meta shop upgrades bought between runs take effect from here
+        let base_stats = profile
+            .as_ref()
+            .map(|profile| profile.player_base_stats())
+            .unwrap_or_default();
+        let run_upgrades = RunUpgrades(
+            profile
+                .as_ref()
+                .map(|profile| profile.upgrades.clone())
+                .unwrap_or_default(),
+        );
+        commands.insert_resource(RerollTokens(run_upgrades.level(MetaUpgrade::RerollToken)));
+        commands.insert_resource(run_upgrades);
+
         // Spawn player in the center of the screen (on XZ plane, Y=0 since model has its own height)
         // The 3D model will be attached as a child by the player plugin
         commands.spawn((
@@ -103,34 +109,29 @@ pub fn setup_game(
                 pickup_radius: 2.0, // 3D world units (was 50 pixels in 2D)
                 last_movement_direction: Vec3::ZERO,
             },
-            Health::new(100.0), // Player health as separate component
+            Health::new(base_stats.max_health), // Player health as separate component
+            Armor(PLAYER_BASE_ARMOR),
+            base_stats,
             crate::experience::components::PlayerExperience::new(),
         ));
-
-        // Spawn random rocks scattered throughout the scene (on XZ plane)
-        let mut rng = rand::thread_rng();
-        for _ in 0..15 {
-            let x = rng.gen_range(-40.0..40.0);
-            let z = rng.gen_range(-30.0..30.0);
-            commands.spawn((
-                Mesh3d(game_meshes.rock.clone()),
-                MeshMaterial3d(game_materials.rock.clone()),
-                Transform::from_translation(Vec3::new(x, 0.25, z)),
-                Rock,
-            ));
-        }
     }
 }
 
-/// Sets up shared game asset resources (meshes and materials) for efficient entity spawning
+/// Sets up shared game asset resources (meshes and materials) for efficient entity spawning.
+/// Headless apps without mesh/material assets get none, and gameplay spawns without visuals.
 pub fn setup_game_assets(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
 ) {
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
     commands.insert_resource(GameMeshes::new(&mut meshes));
     commands.insert_resource(GameMaterials::new(&mut materials));
     commands.insert_resource(EnemyLevelMaterials::new(&mut materials));
+    commands.insert_resource(EnemyKindMaterials::new(&mut materials));
+    commands.insert_resource(EliteAffixMaterials::new(&mut materials));
     commands.insert_resource(XpOrbMaterials::new(&mut materials));
     commands.insert_resource(SpellLootMaterials::new(&mut materials));
 
@@ -147,7 +148,7 @@ pub fn setup_game_assets(
 #[allow(clippy::type_complexity)]
 pub fn cleanup_game(
     mut commands: Commands,
-    query: Query<Entity, Or<(With<Player>, With<Rock>, With<Enemy>, With<crate::loot::components::DroppedItem>, With<crate::spells::light::radiant_beam::RadiantBeam>, With<crate::experience::components::ExperienceOrb>, With<WhisperCompanion>, With<crate::player::components::PlayerModel>)>>,
+    query: Query<Entity, Or<(With<Player>, With<Rock>, With<Enemy>, With<crate::enemies::components::EnemyProjectile>, With<crate::enemies::components::ChargeTelegraph>, With<crate::enemies::components::AmbushWarning>, With<crate::loot::components::DroppedItem>, With<crate::spells::light::radiant_beam::RadiantBeam>, With<crate::experience::components::ExperienceOrb>, With<WhisperCompanion>, With<crate::player::components::PlayerModel>)>>,
 ) {
     // Don't despawn the camera - let the UI system reuse it
     // Collect entities first to avoid iterator invalidation issues
@@ -201,36 +202,80 @@ pub fn player_enemy_collision_detection(
     }
 }
 
-/// System that applies damage when player collides with enemies
+/// System that applies damage when player collides with enemies.
+/// Contact hits are direct hits resolved through the `DamageModifierRegistry`
+/// (so the player's armor reduces them) and reported as `DamageDealt`.
+/// Vampiric elites heal when their hit lands.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn player_enemy_damage_system(
     mut collision_events: MessageReader<PlayerEnemyCollisionEvent>,
     enemy_query: Query<&Enemy>,
-    mut player_query: Query<&mut Health, With<Player>>,
+    mut vampiric_query: Query<(&Vampiric, &mut Health), Without<Player>>,
+    mut player_query: ParamSet<(Query<EntityRef, With<Player>>, Query<&mut Health, With<Player>>)>,
+    registry: Option<Res<DamageModifierRegistry>>,
+    mut dealt: Option<MessageWriter<DamageDealt>>,
     mut damage_timer: ResMut<PlayerDamageTimer>,
     time: Res<Time>,
 ) {
-    let Ok(mut health) = player_query.single_mut() else {
+    let Ok(player_entity) = player_query.p0().single().map(|player| player.id()) else {
         return;
     };
 
-    let mut should_apply_damage = false;
+    let mut attacker = None;
     let mut damage_amount = 0.0;
 
     // Process collision events
     for event in collision_events.read() {
         if let Ok(enemy) = enemy_query.get(event.enemy_entity) {
-            should_apply_damage = true;
+            attacker = Some(event.enemy_entity);
             damage_amount = enemy.strength;
             break; // Only take damage from one enemy per frame
         }
     }
 
     // Apply damage with cooldown logic
-    if should_apply_damage {
+    if let Some(attacker) = attacker {
         let can_damage = !damage_timer.has_taken_damage || damage_timer.time_since_last_damage >= 0.5;
 
         if can_damage {
-            health.take_damage(damage_amount);
+            let hit = DamageEvent::with_source(player_entity, damage_amount, attacker);
+            let final_damage = {
+                let players = player_query.p0();
+                let Ok(player) = players.get(player_entity) else {
+                    return;
+                };
+                match registry.as_deref() {
+                    Some(registry) => registry.resolve(&player, &hit, hit.amount),
+                    None => DamageModifierRegistry::default().resolve(&player, &hit, hit.amount),
+                }
+            };
+
+            let mut killing_blow = false;
+            if let Ok(mut health) = player_query.p1().get_mut(player_entity) {
+                let was_alive = !health.is_dead();
+                health.take_damage(final_damage);
+                killing_blow = was_alive && health.is_dead();
+            }
+
+            if let Some(dealt) = dealt.as_mut() {
+                dealt.write(DamageDealt {
+                    target: player_entity,
+                    amount: final_damage,
+                    base_amount: hit.amount,
+                    source: hit.source,
+                    element: hit.element,
+                    kind: hit.kind,
+                    critical: false,
+                    killing_blow,
+                    reaction: hit.reaction,
+                    spell: hit.spell,
+                });
+            }
+
+            if let Ok((vampiric, mut enemy_health)) = vampiric_query.get_mut(attacker) {
+                let heal = enemy_health.max * vampiric.heal_fraction;
+                enemy_health.heal(heal);
+            }
 
             // Mark that we've taken damage
             damage_timer.has_taken_damage = true;
@@ -275,9 +320,14 @@ pub fn player_enemy_effect_system(
     }
 }
 
-/// System that updates the survival time tracker
-pub fn update_survival_time(time: Res<Time>, mut survival_time: ResMut<SurvivalTime>) {
+/// System that updates the level and run survival time trackers
+pub fn update_survival_time(
+    time: Res<Time>,
+    mut survival_time: ResMut<SurvivalTime>,
+    mut run_survival_time: ResMut<RunSurvivalTime>,
+) {
     survival_time.0 += time.delta_secs();
+    run_survival_time.0 += time.delta_secs();
 }
 
 /// System that resets survival time when entering the game
@@ -285,6 +335,16 @@ pub fn reset_survival_time(mut survival_time: ResMut<SurvivalTime>) {
     survival_time.0 = 0.0;
 }
 
+/// Reset the run's survival time on a fresh game (must run before `reset_game_level`)
+pub fn reset_run_survival_time(
+    mut run_survival_time: ResMut<RunSurvivalTime>,
+    fresh_start: Res<crate::game::resources::FreshGameStart>,
+) {
+    if fresh_start.0 {
+        run_survival_time.0 = 0.0;
+    }
+}
+
 pub fn player_death_system(
     player_query: Query<&Health, With<Player>>,
     mut next_state: ResMut<NextState<GameState>>,
@@ -317,20 +377,37 @@ pub fn update_screen_tint_timer(
     }
 }
 
-/// Tracks enemy kills and advances game level internally.
-/// Game level affects spawn rate and enemy difficulty but doesn't interrupt gameplay.
+/// Tracks enemy kills toward the level's boss threshold.
+/// The boss plugin summons the boss once `GameLevel::boss_due` and only advances
+/// the level when it is defeated.
 pub fn track_enemy_kills_system(
     mut death_events: MessageReader<EnemyDeathEvent>,
     mut game_level: ResMut<GameLevel>,
-    mut level_up_writer: MessageWriter<GameLevelUpEvent>,
 ) {
     for _ in death_events.read() {
-        if game_level.register_kill() {
-            level_up_writer.write(GameLevelUpEvent {
-                new_level: game_level.level,
-            });
-            // Game level advances silently - no screen transition
-        }
+        game_level.register_kill();
+    }
+}
+
+/// Restart the game rng for a fresh run (not when continuing from LevelComplete)
+/// and log the seed so the run can be reproduced with `--seed`.
+pub fn restart_game_rng(
+    mut rng: ResMut<GameRng>,
+    fresh_start: Res<crate::game::resources::FreshGameStart>,
+) {
+    if fresh_start.0 {
+        rng.restart();
+        info!("Run seed: {}", rng.seed());
+    }
+}
+
+/// Start the wave script over for a fresh run (not when continuing from LevelComplete)
+pub fn reset_enemy_spawn_state(
+    mut spawn_state: ResMut<EnemySpawnState>,
+    fresh_start: Res<crate::game::resources::FreshGameStart>,
+) {
+    if fresh_start.0 {
+        *spawn_state = EnemySpawnState::default();
     }
 }
 
@@ -345,6 +422,12 @@ pub fn reset_game_level(
     }
 }
 
+/// Run condition: the game is entering InGame for a new run rather than
+/// resuming from a pause, overlay or LevelComplete
+pub fn is_fresh_game_start(fresh_start: Res<crate::game::resources::FreshGameStart>) -> bool {
+    fresh_start.0
+}
+
 /// Mark the next game start as fresh (called when entering Intro or GameOver)
 pub fn mark_fresh_game_start(mut fresh_start: ResMut<crate::game::resources::FreshGameStart>) {
     fresh_start.0 = true;
@@ -385,6 +468,37 @@ pub fn reset_level_stats_system(mut level_stats: ResMut<LevelStats>) {
     level_stats.reset();
 }
 
+/// Credit resolved damage and killing blows to the spell that dealt them,
+/// and advance the time the per-spell tables cover
+pub fn track_spell_stats_system(
+    time: Res<Time>,
+    mut damage_dealt: MessageReader<DamageDealt>,
+    mut spell_stats: ResMut<SpellStats>,
+) {
+    spell_stats.tick(time.delta_secs());
+    for dealt in damage_dealt.read() {
+        if let Some(spell) = dealt.spell {
+            spell_stats.record_damage(spell, dealt.amount, dealt.killing_blow);
+        }
+    }
+}
+
+/// Reset all per-spell stats on a fresh game (must run before `reset_game_level`)
+pub fn reset_spell_stats_system(
+    mut spell_stats: ResMut<SpellStats>,
+    fresh_start: Res<crate::game::resources::FreshGameStart>,
+) {
+    if fresh_start.0 {
+        spell_stats.reset();
+    }
+}
+
+/// Start a new per-spell level table when continuing from LevelComplete,
+/// keeping the run totals
+pub fn reset_level_spell_stats_system(mut spell_stats: ResMut<SpellStats>) {
+    spell_stats.reset_level();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -433,6 +547,113 @@ mod tests {
         assert_eq!(health.current, 90.0, "Player should take 10 damage immediately");
     }
 
+    #[test]
+    fn test_player_armor_reduces_contact_damage() {
+        let mut app = App::new();
+        app.init_resource::<PlayerDamageTimer>();
+        app.add_plugins(bevy::time::TimePlugin);
+        app.add_message::<PlayerEnemyCollisionEvent>();
+        app.add_systems(Update, (player_enemy_collision_detection, player_enemy_damage_system).chain());
+
+        let player_entity = app.world_mut().spawn((
+            Player {
+                speed: 200.0,
+                regen_rate: 1.0,
+                pickup_radius: 50.0,
+                last_movement_direction: Vec3::ZERO,
+            },
+            Health::new(100.0),
+            Armor(3.0),
+            Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+        )).id();
+        app.world_mut().spawn((
+            Enemy { speed: 50.0, strength: 10.0 },
+            Transform::from_translation(Vec3::new(1.0, 0.375, 0.0)),
+        ));
+
+        app.update();
+
+        let health = app.world().get::<Health>(player_entity).unwrap();
+        assert_eq!(health.current, 93.0, "Armor should absorb 3 of the 10 damage");
+    }
+
+    #[test]
+    fn test_contact_damage_goes_through_modifier_registry_and_is_reported() {
+        let mut app = App::new();
+        app.init_resource::<PlayerDamageTimer>();
+        app.add_plugins(bevy::time::TimePlugin);
+        app.add_message::<PlayerEnemyCollisionEvent>();
+        app.add_message::<DamageDealt>();
+        app.insert_resource(DamageModifierRegistry::empty());
+        app.add_systems(Update, (player_enemy_collision_detection, player_enemy_damage_system).chain());
+
+        let player_entity = app.world_mut().spawn((
+            Player {
+                speed: 200.0,
+                regen_rate: 1.0,
+                pickup_radius: 50.0,
+                last_movement_direction: Vec3::ZERO,
+            },
+            Health::new(100.0),
+            Armor(3.0),
+            Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+        )).id();
+        let enemy = app.world_mut().spawn((
+            Enemy { speed: 50.0, strength: 10.0 },
+            Transform::from_translation(Vec3::new(1.0, 0.375, 0.0)),
+        )).id();
+
+        app.update();
+
+        // Armor only applies when registered with the pipeline
+        let health = app.world().get::<Health>(player_entity).unwrap();
+        assert_eq!(health.current, 90.0);
+
+        let dealt: Vec<_> = app
+            .world()
+            .resource::<Messages<DamageDealt>>()
+            .iter_current_update_messages()
+            .cloned()
+            .collect();
+        assert_eq!(dealt.len(), 1);
+        assert_eq!(dealt[0].target, player_entity);
+        assert_eq!(dealt[0].source, Some(enemy));
+        assert_eq!(dealt[0].amount, 10.0);
+        assert_eq!(dealt[0].base_amount, 10.0);
+        assert!(!dealt[0].killing_blow);
+    }
+
+    #[test]
+    fn test_vampiric_elite_heals_when_hitting_player() {
+        let mut app = App::new();
+        app.init_resource::<PlayerDamageTimer>();
+        app.add_plugins(bevy::time::TimePlugin);
+        app.add_message::<PlayerEnemyCollisionEvent>();
+        app.add_systems(Update, (player_enemy_collision_detection, player_enemy_damage_system).chain());
+
+        app.world_mut().spawn((
+            Player {
+                speed: 200.0,
+                regen_rate: 1.0,
+                pickup_radius: 50.0,
+                last_movement_direction: Vec3::ZERO,
+            },
+            Health::new(100.0),
+            Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+        ));
+        let vampire = app.world_mut().spawn((
+            Enemy { speed: 50.0, strength: 10.0 },
+            Vampiric { heal_fraction: 0.1 },
+            Health { current: 50.0, max: 200.0 },
+            Transform::from_translation(Vec3::new(1.0, 0.375, 0.0)),
+        )).id();
+
+        app.update();
+
+        let health = app.world().get::<Health>(vampire).unwrap();
+        assert_eq!(health.current, 70.0, "Vampiric elite should heal 10% of its max health");
+    }
+
     #[test]
     fn test_player_enemy_collision_no_damage_when_not_touching() {
         let mut app = App::new();
@@ -649,6 +870,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(bevy::time::TimePlugin::default());
         app.init_resource::<SurvivalTime>();
+        app.init_resource::<RunSurvivalTime>();
         app.add_systems(Update, update_survival_time);
 
         // Initial survival time should be 0
@@ -681,6 +903,33 @@ mod tests {
         assert_eq!(time.0, 0.0);
     }
 
+    #[test]
+    fn run_survival_time_spans_levels() {
+        use bevy::ecs::system::RunSystemOnce;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.init_resource::<SurvivalTime>();
+        app.insert_resource(RunSurvivalTime(30.0));
+        app.insert_resource(crate::game::resources::FreshGameStart(false));
+
+        // Starting the next level restarts the level clock only
+        let _ = app.world_mut().run_system_once(reset_survival_time);
+        let _ = app.world_mut().run_system_once(reset_run_survival_time);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(2.0));
+        let _ = app.world_mut().run_system_once(update_survival_time);
+
+        assert_eq!(app.world().resource::<SurvivalTime>().0, 2.0);
+        assert_eq!(app.world().resource::<RunSurvivalTime>().0, 32.0);
+
+        app.insert_resource(crate::game::resources::FreshGameStart(true));
+        let _ = app.world_mut().run_system_once(reset_run_survival_time);
+        assert_eq!(app.world().resource::<RunSurvivalTime>().0, 0.0);
+    }
+
     #[test]
     fn test_player_death_fires_game_over_event() {
         use std::sync::{Arc, atomic::{AtomicU32, AtomicBool, Ordering}};
@@ -874,7 +1123,6 @@ mod tests {
             app.init_state::<GameState>();
             app.init_resource::<GameLevel>();
             app.add_message::<EnemyDeathEvent>();
-            app.add_message::<GameLevelUpEvent>();
             app.add_systems(Update, track_enemy_kills_system);
 
             // Write a death event
@@ -882,6 +1130,7 @@ mod tests {
                 enemy_entity: Entity::PLACEHOLDER,
                 position: Vec3::ZERO,
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             app.update();
@@ -893,29 +1142,13 @@ mod tests {
         }
 
         #[test]
-        fn track_enemy_kills_advances_level_at_threshold() {
-            use std::sync::{Arc, atomic::{AtomicBool, AtomicU32, Ordering}};
-
+        fn track_enemy_kills_marks_boss_due_at_threshold() {
             let mut app = App::new();
             app.add_plugins(bevy::state::app::StatesPlugin);
             app.init_state::<GameState>();
             app.init_resource::<GameLevel>();
             app.add_message::<EnemyDeathEvent>();
-            app.add_message::<GameLevelUpEvent>();
-
-            let event_received = Arc::new(AtomicBool::new(false));
-            let new_level = Arc::new(AtomicU32::new(0));
-            let event_received_clone = event_received.clone();
-            let new_level_clone = new_level.clone();
-
-            let event_reader = move |mut events: MessageReader<GameLevelUpEvent>| {
-                for event in events.read() {
-                    event_received_clone.store(true, Ordering::SeqCst);
-                    new_level_clone.store(event.new_level, Ordering::SeqCst);
-                }
-            };
-
-            app.add_systems(Update, (track_enemy_kills_system, event_reader).chain());
+            app.add_systems(Update, track_enemy_kills_system);
 
             // Get the kills needed to advance (default is 10)
             let kills_to_advance = {
@@ -923,32 +1156,33 @@ mod tests {
                 level.kills_to_advance()
             };
 
-            // Write enough death events to advance level
-            for _ in 0..kills_to_advance {
+            // Write enough death events to reach the threshold, plus a few more
+            for _ in 0..kills_to_advance + 3 {
                 app.world_mut().write_message(EnemyDeathEvent {
                     enemy_entity: Entity::PLACEHOLDER,
                     position: Vec3::ZERO,
                     enemy_level: 1,
+                    affix_count: 0,
                 });
                 app.update();
             }
 
-            assert!(event_received.load(Ordering::SeqCst), "Should have received GameLevelUpEvent");
-            assert_eq!(new_level.load(Ordering::SeqCst), 2);
-
+            // Overshooting the threshold still leaves the boss due
             let game_level = app.world().resource::<GameLevel>();
-            assert_eq!(game_level.level, 2);
-            assert_eq!(game_level.kills_this_level, 0);
+            assert!(game_level.boss_due(), "Boss should be due once the threshold is reached");
+
+            // Level doesn't advance until the boss dies
+            assert_eq!(game_level.level, 1);
+            assert_eq!(game_level.kills_this_level, kills_to_advance + 3);
         }
 
         #[test]
-        fn track_enemy_kills_advances_level_without_state_change() {
+        fn track_enemy_kills_threshold_keeps_in_game_state() {
             let mut app = App::new();
             app.add_plugins(bevy::state::app::StatesPlugin);
             app.init_state::<GameState>();
             app.init_resource::<GameLevel>();
             app.add_message::<EnemyDeathEvent>();
-            app.add_message::<GameLevelUpEvent>();
             app.add_systems(Update, track_enemy_kills_system);
 
             // Set game to InGame state
@@ -965,21 +1199,21 @@ mod tests {
                 game_level.total_kills = threshold - 1;
             }
 
-            // Write the final death event to trigger level up
+            // Write the final death event to summon the boss
             app.world_mut().write_message(EnemyDeathEvent {
                 enemy_entity: Entity::PLACEHOLDER,
                 position: Vec3::ZERO,
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             app.update();
             app.update();
 
-            // Game level should have advanced
             let game_level = app.world().resource::<GameLevel>();
-            assert_eq!(game_level.level, 2, "Game level should advance to 2");
+            assert_eq!(game_level.level, 1, "Game level waits for the boss");
 
-            // State should remain InGame (no transition to LevelComplete)
+            // The boss fight happens in InGame
             let current_state = app.world().resource::<bevy::state::state::State<GameState>>();
             assert_eq!(*current_state.get(), GameState::InGame, "Should stay in InGame state");
         }
@@ -1039,6 +1273,40 @@ mod tests {
             assert_eq!(game_level.total_kills, 100);
         }
 
+        #[test]
+        fn restart_game_rng_replays_seed_on_fresh_start() {
+            use crate::game::resources::{FreshGameStart, GameRng};
+            use rand::Rng;
+
+            let mut app = App::new();
+            app.insert_resource(GameRng::from_seed(99));
+            app.insert_resource(FreshGameStart(true));
+            let first: u64 = app.world_mut().resource_mut::<GameRng>().gen();
+
+            app.add_systems(Update, restart_game_rng);
+            app.update();
+
+            let replayed: u64 = app.world_mut().resource_mut::<GameRng>().gen();
+            assert_eq!(replayed, first, "Fresh run should restart the seeded sequence");
+        }
+
+        #[test]
+        fn restart_game_rng_keeps_sequence_when_continuing() {
+            use crate::game::resources::{FreshGameStart, GameRng};
+            use rand::Rng;
+
+            let mut app = App::new();
+            app.insert_resource(GameRng::from_seed(99));
+            app.insert_resource(FreshGameStart(false));
+            let first: u64 = app.world_mut().resource_mut::<GameRng>().gen();
+
+            app.add_systems(Update, restart_game_rng);
+            app.update();
+
+            let next: u64 = app.world_mut().resource_mut::<GameRng>().gen();
+            assert_ne!(next, first, "Continuing a run must not replay earlier rolls");
+        }
+
         #[test]
         fn track_enemy_kills_no_level_up_without_events() {
             let mut app = App::new();
@@ -1046,7 +1314,6 @@ mod tests {
             app.init_state::<GameState>();
             app.init_resource::<GameLevel>();
             app.add_message::<EnemyDeathEvent>();
-            app.add_message::<GameLevelUpEvent>();
             app.add_systems(Update, track_enemy_kills_system);
 
             app.update();
@@ -1105,6 +1372,7 @@ mod tests {
                 enemy_entity: Entity::PLACEHOLDER,
                 position: Vec3::ZERO,
                 enemy_level: 1,
+                affix_count: 0,
             });
             app.update();
 
@@ -1125,6 +1393,7 @@ mod tests {
                     enemy_entity: Entity::PLACEHOLDER,
                     position: Vec3::ZERO,
                     enemy_level: 1,
+                    affix_count: 0,
                 });
             }
             app.update();
@@ -1226,6 +1495,76 @@ mod tests {
             assert_eq!(stats.xp_gained, 0);
         }
 
+        fn level_entry_app() -> App {
+            let mut app = App::new();
+            app.add_plugins(bevy::state::app::StatesPlugin);
+            app.init_state::<GameState>();
+            app.init_resource::<SurvivalTime>();
+            app.init_resource::<LevelStats>();
+            app.insert_resource(crate::game::resources::FreshGameStart(false));
+            // Same wiring as the game plugin
+            app.add_systems(OnEnter(GameState::InGame), (
+                reset_survival_time.run_if(is_fresh_game_start),
+                reset_level_stats_system.run_if(is_fresh_game_start),
+            ));
+            app.add_systems(
+                OnTransition {
+                    exited: GameState::LevelComplete,
+                    entered: GameState::InGame,
+                },
+                (reset_survival_time, reset_level_stats_system),
+            );
+            app
+        }
+
+        fn enter(app: &mut App, state: GameState) {
+            app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+            app.update();
+        }
+
+        fn play_some_of_the_level(app: &mut App) {
+            app.world_mut().resource_mut::<SurvivalTime>().0 = 42.0;
+            let mut stats = app.world_mut().resource_mut::<LevelStats>();
+            stats.time_elapsed = 42.0;
+            stats.enemies_killed = 7;
+        }
+
+        #[test]
+        fn resuming_from_overlays_keeps_level_clock_and_stats() {
+            let mut app = level_entry_app();
+            enter(&mut app, GameState::InGame);
+            play_some_of_the_level(&mut app);
+
+            for overlay in [GameState::LevelUpChoice, GameState::Paused, GameState::InventoryOpen] {
+                enter(&mut app, overlay);
+                enter(&mut app, GameState::InGame);
+            }
+
+            assert_eq!(app.world().resource::<SurvivalTime>().0, 42.0);
+            let stats = app.world().resource::<LevelStats>();
+            assert_eq!(stats.time_elapsed, 42.0);
+            assert_eq!(stats.enemies_killed, 7);
+        }
+
+        #[test]
+        fn next_level_and_fresh_start_reset_level_clock_and_stats() {
+            let mut app = level_entry_app();
+            enter(&mut app, GameState::InGame);
+            play_some_of_the_level(&mut app);
+
+            enter(&mut app, GameState::LevelComplete);
+            enter(&mut app, GameState::InGame);
+            assert_eq!(app.world().resource::<SurvivalTime>().0, 0.0);
+            assert_eq!(app.world().resource::<LevelStats>().enemies_killed, 0);
+
+            play_some_of_the_level(&mut app);
+            enter(&mut app, GameState::GameOver);
+            app.world_mut().resource_mut::<crate::game::resources::FreshGameStart>().0 = true;
+            enter(&mut app, GameState::InGame);
+            assert_eq!(app.world().resource::<SurvivalTime>().0, 0.0);
+            assert_eq!(app.world().resource::<LevelStats>().time_elapsed, 0.0);
+        }
+
         #[test]
         fn level_stats_systems_work_together() {
             use std::time::Duration;
@@ -1250,6 +1589,7 @@ mod tests {
                 enemy_entity: Entity::PLACEHOLDER,
                 position: Vec3::ZERO,
                 enemy_level: 1,
+                affix_count: 0,
             });
             let _ = app.world_mut().run_system_once(track_level_kills_system);
 
@@ -1267,6 +1607,145 @@ mod tests {
         }
     }
 
+    mod spell_stats_system_tests {
+        use super::*;
+        use bevy::ecs::system::RunSystemOnce;
+        use crate::combat::DamageKind;
+        use crate::game::resources::{FreshGameStart, SpellStats};
+        use crate::spell::SpellType;
+
+        fn dealt(amount: f32, spell: Option<SpellType>, killing_blow: bool) -> DamageDealt {
+            DamageDealt {
+                target: Entity::PLACEHOLDER,
+                amount,
+                base_amount: amount,
+                source: None,
+                element: None,
+                kind: DamageKind::Direct,
+                critical: false,
+                killing_blow,
+                reaction: None,
+                spell,
+            }
+        }
+
+        #[test]
+        fn track_spell_stats_credits_tagged_damage_and_kills() {
+            use std::time::Duration;
+
+            let mut app = App::new();
+            app.init_resource::<Time>();
+            app.init_resource::<SpellStats>();
+            app.add_message::<DamageDealt>();
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(0.5));
+
+            app.world_mut().write_message(dealt(30.0, Some(SpellType::Fireball), false));
+            app.world_mut().write_message(dealt(12.0, Some(SpellType::Fireball), true));
+            app.world_mut().write_message(dealt(50.0, None, true));
+            let _ = app.world_mut().run_system_once(track_spell_stats_system);
+
+            let stats = app.world().resource::<SpellStats>();
+            let fireball = stats.level.get(SpellType::Fireball);
+            assert_eq!(fireball.damage, 42.0);
+            assert_eq!(fireball.kills, 1);
+            assert_eq!(stats.level.len(), 1, "Untagged damage is not credited");
+            assert_eq!(stats.run.get(SpellType::Fireball), fireball);
+            assert!((stats.level.time_elapsed - 0.5).abs() < 0.001);
+            assert!((stats.run.time_elapsed - 0.5).abs() < 0.001);
+        }
+
+        #[test]
+        fn reset_spell_stats_keeps_tables_when_not_fresh() {
+            let mut app = App::new();
+            app.init_resource::<SpellStats>();
+            app.insert_resource(FreshGameStart(false));
+            app.world_mut()
+                .resource_mut::<SpellStats>()
+                .record_damage(SpellType::Spark, 10.0, true);
+
+            let _ = app.world_mut().run_system_once(reset_spell_stats_system);
+
+            let stats = app.world().resource::<SpellStats>();
+            assert_eq!(stats.level.get(SpellType::Spark).kills, 1);
+            assert_eq!(stats.run.get(SpellType::Spark).kills, 1);
+        }
+
+        #[test]
+        fn reset_level_spell_stats_keeps_run() {
+            let mut app = App::new();
+            app.init_resource::<SpellStats>();
+            app.world_mut()
+                .resource_mut::<SpellStats>()
+                .record_damage(SpellType::Spark, 10.0, true);
+
+            let _ = app.world_mut().run_system_once(reset_level_spell_stats_system);
+
+            let stats = app.world().resource::<SpellStats>();
+            assert!(stats.level.is_empty());
+            assert_eq!(stats.run.get(SpellType::Spark).kills, 1);
+        }
+
+        #[test]
+        fn spell_stats_survive_pause_and_reset_on_next_level() {
+            let mut app = App::new();
+            app.add_plugins(bevy::state::app::StatesPlugin);
+            app.init_state::<GameState>();
+            app.init_resource::<SpellStats>();
+            app.insert_resource(FreshGameStart(false));
+            // Same wiring as the game plugin
+            app.add_systems(OnEnter(GameState::InGame), reset_spell_stats_system);
+            app.add_systems(
+                OnTransition {
+                    exited: GameState::LevelComplete,
+                    entered: GameState::InGame,
+                },
+                reset_level_spell_stats_system,
+            );
+            let enter = |app: &mut App, state: GameState| {
+                app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+                app.update();
+            };
+
+            enter(&mut app, GameState::InGame);
+            app.world_mut()
+                .resource_mut::<SpellStats>()
+                .record_damage(SpellType::Fireball, 25.0, true);
+
+            enter(&mut app, GameState::Paused);
+            enter(&mut app, GameState::InGame);
+
+            let stats = app.world().resource::<SpellStats>();
+            let fireball = stats.level.get(SpellType::Fireball);
+            assert_eq!(fireball.damage, 25.0, "Pausing must not clear the level table");
+            assert_eq!(fireball.kills, 1);
+
+            enter(&mut app, GameState::LevelComplete);
+            enter(&mut app, GameState::InGame);
+
+            let stats = app.world().resource::<SpellStats>();
+            assert!(stats.level.is_empty(), "A new level starts a new table");
+            assert_eq!(stats.run.get(SpellType::Fireball).damage, 25.0);
+        }
+
+        #[test]
+        fn reset_spell_stats_clears_run_on_fresh_start() {
+            let mut app = App::new();
+            app.init_resource::<SpellStats>();
+            app.insert_resource(FreshGameStart(true));
+            app.world_mut()
+                .resource_mut::<SpellStats>()
+                .record_damage(SpellType::Spark, 10.0, true);
+
+            let _ = app.world_mut().run_system_once(reset_spell_stats_system);
+
+            let stats = app.world().resource::<SpellStats>();
+            assert!(stats.level.is_empty());
+            assert!(stats.run.is_empty());
+        }
+    }
+
     mod camera_hdr_bloom_tests {
         use super::*;
         use bevy::core_pipeline::tonemapping::Tonemapping;
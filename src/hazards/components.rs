@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::element::Element;
+
+/// Footprint radius of an explosive barrel (it is also an obstacle)
+pub const BARREL_RADIUS: f32 = 0.8;
+
+/// Fire hits landing this close to a barrel set it off
+pub const BARREL_TRIGGER_RADIUS: f32 = 3.0;
+
+/// Radius of a barrel explosion; other barrels inside it go off too
+pub const BARREL_EXPLOSION_RADIUS: f32 = 6.0;
+
+/// Fire damage dealt to everything inside a barrel explosion
+pub const BARREL_EXPLOSION_DAMAGE: f32 = 45.0;
+
+/// Radius of a water puddle
+pub const PUDDLE_RADIUS: f32 = 4.0;
+
+/// How long Lightning keeps a puddle electrified
+pub const ELECTRIFIED_DURATION: f32 = 4.0;
+
+/// Lightning damage per second to anything standing in an electrified puddle
+pub const ELECTRIFIED_DAMAGE_PER_SECOND: f32 = 12.0;
+
+/// How long Frost keeps a puddle frozen into ice
+pub const FROZEN_DURATION: f32 = 8.0;
+
+/// Radius of a toxic vent's fumes
+pub const VENT_RADIUS: f32 = 3.5;
+
+/// Poison damage per second to anything standing in a vent's fumes
+pub const VENT_DAMAGE_PER_SECOND: f32 = 3.0;
+
+/// How long Poison keeps a vent amplified
+pub const VENT_AMPLIFIED_DURATION: f32 = 5.0;
+
+/// Damage multiplier of an amplified vent
+pub const VENT_AMPLIFIED_DAMAGE_MULTIPLIER: f32 = 3.0;
+
+/// Fume radius multiplier of an amplified vent
+pub const VENT_AMPLIFIED_RADIUS_MULTIPLIER: f32 = 1.75;
+
+/// Element-reactive prop a layout can place in the arena
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PropKind {
+    /// Blocks movement; Fire detonates it
+    ExplosiveBarrel,
+    /// Lightning electrifies it, Frost freezes it into slippery ice
+    WaterPuddle,
+    /// Leaks poison fumes; Poison spells amplify them
+    ToxicVent,
+}
+
+impl PropKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PropKind::ExplosiveBarrel => "Explosive Barrel",
+            PropKind::WaterPuddle => "Water Puddle",
+            PropKind::ToxicVent => "Toxic Vent",
+        }
+    }
+
+    /// Whether hits of this element set the prop off
+    pub fn reacts_to(&self, element: Element) -> bool {
+        match self {
+            PropKind::ExplosiveBarrel => element == Element::Fire,
+            PropKind::WaterPuddle => matches!(element, Element::Lightning | Element::Frost),
+            PropKind::ToxicVent => element == Element::Poison,
+        }
+    }
+
+    /// Distance from the prop within which elemental hits reach it
+    pub fn trigger_radius(&self) -> f32 {
+        match self {
+            PropKind::ExplosiveBarrel => BARREL_TRIGGER_RADIUS,
+            PropKind::WaterPuddle => PUDDLE_RADIUS,
+            PropKind::ToxicVent => VENT_RADIUS,
+        }
+    }
+}
+
+/// An element-reactive prop. Elemental hits nearby raise `HazardTriggered`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HazardProp(pub PropKind);
+
+/// Current state of a water puddle
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum PuddleState {
+    #[default]
+    Still,
+    /// Shocks everything standing in it
+    Electrified { remaining: f32 },
+    /// Slippery ice; `iced_cells` are the `HazardMap` cells the freeze added
+    Frozen { remaining: f32, iced_cells: Vec<IVec2> },
+}
+
+/// Water puddle that reacts to Lightning and Frost
+#[derive(Component, Debug, Clone, Default)]
+pub struct WaterPuddle {
+    pub state: PuddleState,
+}
+
+impl WaterPuddle {
+    pub fn is_electrified(&self) -> bool {
+        matches!(self.state, PuddleState::Electrified { .. })
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        matches!(self.state, PuddleState::Frozen { .. })
+    }
+}
+
+/// Toxic vent whose fumes Poison hits amplify for a while
+#[derive(Component, Debug, Clone, Default)]
+pub struct ToxicVent {
+    /// Seconds of amplification left
+    pub amplified_remaining: f32,
+}
+
+impl ToxicVent {
+    pub fn is_amplified(&self) -> bool {
+        self.amplified_remaining > 0.0
+    }
+
+    /// Radius of the fumes right now
+    pub fn radius(&self) -> f32 {
+        if self.is_amplified() {
+            VENT_RADIUS * VENT_AMPLIFIED_RADIUS_MULTIPLIER
+        } else {
+            VENT_RADIUS
+        }
+    }
+
+    /// Poison damage per second right now
+    pub fn damage_per_second(&self) -> f32 {
+        if self.is_amplified() {
+            VENT_DAMAGE_PER_SECOND * VENT_AMPLIFIED_DAMAGE_MULTIPLIER
+        } else {
+            VENT_DAMAGE_PER_SECOND
+        }
+    }
+}
+
+/// Message fired when an elemental hit reaches a prop that reacts to its element
+#[derive(Message, Debug, Clone, Copy)]
+pub struct HazardTriggered {
+    pub prop: Entity,
+    pub kind: PropKind,
+    pub element: Element,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn props_react_to_their_elements_only() {
+        assert!(PropKind::ExplosiveBarrel.reacts_to(Element::Fire));
+        assert!(!PropKind::ExplosiveBarrel.reacts_to(Element::Frost));
+        assert!(PropKind::WaterPuddle.reacts_to(Element::Lightning));
+        assert!(PropKind::WaterPuddle.reacts_to(Element::Frost));
+        assert!(!PropKind::WaterPuddle.reacts_to(Element::Fire));
+        assert!(PropKind::ToxicVent.reacts_to(Element::Poison));
+        assert!(!PropKind::ToxicVent.reacts_to(Element::Lightning));
+    }
+
+    #[test]
+    fn puddle_starts_still() {
+        let puddle = WaterPuddle::default();
+        assert_eq!(puddle.state, PuddleState::Still);
+        assert!(!puddle.is_electrified());
+        assert!(!puddle.is_frozen());
+    }
+
+    #[test]
+    fn amplified_vent_is_larger_and_stronger() {
+        let mut vent = ToxicVent::default();
+        assert_eq!(vent.radius(), VENT_RADIUS);
+        assert_eq!(vent.damage_per_second(), VENT_DAMAGE_PER_SECOND);
+
+        vent.amplified_remaining = 1.0;
+        assert!(vent.radius() > VENT_RADIUS);
+        assert_eq!(
+            vent.damage_per_second(),
+            VENT_DAMAGE_PER_SECOND * VENT_AMPLIFIED_DAMAGE_MULTIPLIER
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::collision::events::ProjectileObstacleHit;
+use crate::combat::DamageEvent;
+use crate::game::sets::GameSet;
+use crate::hazards::components::HazardTriggered;
+use crate::hazards::resources::HazardPulseTimer;
+use crate::hazards::systems::*;
+use crate::states::GameState;
+
+/// Element-reactive arena props: barrels, puddles and vents placed by the
+/// arena layout react to elemental hits and damage whatever stands nearby.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<HazardPulseTimer>()
+        .add_message::<DamageEvent>()
+        .add_message::<ProjectileObstacleHit>()
+        .add_message::<HazardTriggered>()
+        .add_systems(Startup, setup_hazard_prop_assets)
+        .add_systems(
+            Update,
+            (
+                detect_hazard_triggers_system,
+                (
+                    explode_barrels_system,
+                    update_water_puddles_system,
+                    update_toxic_vents_system,
+                ),
+                hazard_pulse_damage_system,
+            )
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plugin_registers_pulse_timer_and_messages() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().get_resource::<HazardPulseTimer>().is_some());
+        assert!(app.world().get_resource::<Messages<HazardTriggered>>().is_some());
+    }
+}
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+
+use crate::element::Element;
+use crate::hazards::components::{PropKind, BARREL_RADIUS, PUDDLE_RADIUS, VENT_RADIUS};
+
+/// Height of an explosive barrel
+pub const BARREL_HEIGHT: f32 = 1.4;
+
+/// Interval between damage ticks of electrified puddles and vent fumes
+#[derive(Resource, Debug, Clone)]
+pub struct HazardPulseTimer(pub Timer);
+
+impl Default for HazardPulseTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(0.5, TimerMode::Repeating))
+    }
+}
+
+/// Shared meshes and materials for hazard props
+#[derive(Resource)]
+pub struct HazardPropAssets {
+    pub barrel: Handle<Mesh>,
+    pub barrel_material: Handle<StandardMaterial>,
+    /// Flat disc for puddles
+    pub puddle: Handle<Mesh>,
+    pub water: Handle<StandardMaterial>,
+    pub electrified_water: Handle<StandardMaterial>,
+    pub frozen_water: Handle<StandardMaterial>,
+    /// Flat disc for vent fumes, scaled with the vent radius
+    pub vent: Handle<Mesh>,
+    pub vent_material: Handle<StandardMaterial>,
+    pub amplified_vent_material: Handle<StandardMaterial>,
+}
+
+impl HazardPropAssets {
+    pub fn new(meshes: &mut Assets<Mesh>, materials: &mut Assets<StandardMaterial>) -> Self {
+        let glow = |element: Element, strength: f32| {
+            let linear = element.color().to_linear();
+            bevy::color::LinearRgba::rgb(
+                linear.red * strength,
+                linear.green * strength,
+                linear.blue * strength,
+            )
+        };
+
+        Self {
+            barrel: meshes.add(Cylinder::new(BARREL_RADIUS, BARREL_HEIGHT)),
+            barrel_material: materials.add(StandardMaterial {
+                base_color: Color::srgb(0.7, 0.12, 0.08),
+                perceptual_roughness: 0.6,
+                ..default()
+            }),
+            puddle: meshes.add(Circle::new(PUDDLE_RADIUS)),
+            water: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.15, 0.3, 0.55, 0.7),
+                alpha_mode: AlphaMode::Blend,
+                perceptual_roughness: 0.05,
+                ..default()
+            }),
+            electrified_water: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.4, 0.5, 0.9, 0.8),
+                emissive: glow(Element::Lightning, 3.0),
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            }),
+            frozen_water: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.75, 0.92, 1.0, 0.85),
+                alpha_mode: AlphaMode::Blend,
+                perceptual_roughness: 0.1,
+                ..default()
+            }),
+            vent: meshes.add(Circle::new(VENT_RADIUS)),
+            vent_material: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.25, 0.4, 0.15, 0.5),
+                emissive: glow(Element::Poison, 0.5),
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            }),
+            amplified_vent_material: materials.add(StandardMaterial {
+                base_color: Color::srgba(0.4, 0.75, 0.2, 0.65),
+                emissive: glow(Element::Poison, 2.5),
+                alpha_mode: AlphaMode::Blend,
+                ..default()
+            }),
+        }
+    }
+
+    /// Mesh and resting material of a prop
+    pub fn visuals_for(&self, kind: PropKind) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+        match kind {
+            PropKind::ExplosiveBarrel => (self.barrel.clone(), self.barrel_material.clone()),
+            PropKind::WaterPuddle => (self.puddle.clone(), self.water.clone()),
+            PropKind::ToxicVent => (self.vent.clone(), self.vent_material.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pulse_timer_repeats_every_half_second() {
+        let timer = HazardPulseTimer::default();
+        assert_eq!(timer.0.duration().as_secs_f32(), 0.5);
+        assert_eq!(timer.0.mode(), TimerMode::Repeating);
+    }
+
+    #[test]
+    fn each_prop_kind_has_visuals() {
+        let mut meshes = Assets::<Mesh>::default();
+        let mut materials = Assets::<StandardMaterial>::default();
+        let assets = HazardPropAssets::new(&mut meshes, &mut materials);
+
+        let (barrel_mesh, _) = assets.visuals_for(PropKind::ExplosiveBarrel);
+        let (puddle_mesh, water) = assets.visuals_for(PropKind::WaterPuddle);
+        let (_, vent) = assets.visuals_for(PropKind::ToxicVent);
+        assert_eq!(barrel_mesh, assets.barrel);
+        assert_eq!(puddle_mesh, assets.puddle);
+        assert_eq!(water, assets.water);
+        assert_eq!(vent, assets.vent_material);
+    }
+}
@@ -0,0 +1,698 @@
+use std::collections::{HashSet, VecDeque};
+
+use bevy::prelude::*;
+
+use crate::arena::components::HazardKind;
+use crate::arena::resources::HazardMap;
+use crate::collision::components::Obstacle;
+use crate::collision::events::ProjectileObstacleHit;
+use crate::combat::DamageEvent;
+use crate::element::Element;
+use crate::enemies::components::Enemy;
+use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::hazards::components::*;
+use crate::hazards::resources::{HazardPropAssets, HazardPulseTimer, BARREL_HEIGHT};
+use crate::movement::components::from_xz;
+use crate::player::components::Player;
+use crate::reactions::components::ReactionBurst;
+
+/// Create the shared prop meshes and materials
+pub fn setup_hazard_prop_assets(
+    mut commands: Commands,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    if let (Some(mut meshes), Some(mut materials)) = (meshes, materials) {
+        commands.insert_resource(HazardPropAssets::new(&mut meshes, &mut materials));
+    }
+}
+
+/// Spawn a prop at a position on the XZ plane. Barrels also become obstacles.
+pub fn spawn_hazard_prop<'a>(
+    commands: &'a mut Commands,
+    kind: PropKind,
+    position: Vec2,
+    assets: Option<&HazardPropAssets>,
+) -> EntityCommands<'a> {
+    let mut entity_commands = match kind {
+        PropKind::ExplosiveBarrel => commands.spawn((
+            HazardProp(kind),
+            Obstacle::circle(BARREL_RADIUS),
+            Transform::from_translation(Vec3::new(position.x, BARREL_HEIGHT / 2.0, position.y)),
+        )),
+        // Flat discs just above the ground and hazard tiles
+        PropKind::WaterPuddle => commands.spawn((
+            HazardProp(kind),
+            WaterPuddle::default(),
+            Transform::from_translation(Vec3::new(position.x, 0.02, position.y))
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        )),
+        PropKind::ToxicVent => commands.spawn((
+            HazardProp(kind),
+            ToxicVent::default(),
+            Transform::from_translation(Vec3::new(position.x, 0.03, position.y))
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        )),
+    };
+    entity_commands.insert(Visibility::default());
+    if let Some(assets) = assets {
+        let (mesh, material) = assets.visuals_for(kind);
+        entity_commands.insert((Mesh3d(mesh), MeshMaterial3d(material)));
+    }
+    entity_commands
+}
+
+/// Finds props reached by elemental hits: damage landing on something near the
+/// prop, or a spell projectile stopped by it. Damage dealt by props themselves
+/// never re-triggers props, so a puddle can't keep itself electrified.
+pub fn detect_hazard_triggers_system(
+    mut damage_events: MessageReader<DamageEvent>,
+    mut obstacle_hits: MessageReader<ProjectileObstacleHit>,
+    props: Query<(Entity, &Transform, &HazardProp)>,
+    targets: Query<&Transform>,
+    mut triggered: MessageWriter<HazardTriggered>,
+) {
+    let mut hits: Vec<(Vec2, Element)> = Vec::new();
+    for event in damage_events.read() {
+        let Some(element) = event.element else {
+            continue;
+        };
+        if event.source.is_some_and(|source| props.contains(source)) {
+            continue;
+        }
+        if let Ok(transform) = targets.get(event.target) {
+            hits.push((from_xz(transform.translation), element));
+        }
+    }
+    for hit in obstacle_hits.read() {
+        hits.push((from_xz(hit.position), hit.spell_type.element()));
+    }
+    if hits.is_empty() {
+        return;
+    }
+
+    let mut fired: HashSet<(Entity, Element)> = HashSet::new();
+    for (position, element) in hits {
+        for (entity, transform, prop) in props.iter() {
+            let kind = prop.0;
+            if !kind.reacts_to(element)
+                || from_xz(transform.translation).distance(position) > kind.trigger_radius()
+                || !fired.insert((entity, element))
+            {
+                continue;
+            }
+            triggered.write(HazardTriggered { prop: entity, kind, element });
+        }
+    }
+}
+
+/// Detonates triggered barrels. Each explosion deals Fire damage to the player
+/// and enemies inside it and sets off any other barrel in range.
+#[allow(clippy::type_complexity)]
+pub fn explode_barrels_system(
+    mut commands: Commands,
+    mut triggered: MessageReader<HazardTriggered>,
+    barrels: Query<(Entity, &Transform, &HazardProp)>,
+    bodies: Query<(Entity, &Transform), Or<(With<Player>, With<Enemy>)>>,
+    mut damage_events: MessageWriter<DamageEvent>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+) {
+    let mut pending: VecDeque<Entity> = triggered
+        .read()
+        .filter(|event| event.kind == PropKind::ExplosiveBarrel)
+        .map(|event| event.prop)
+        .collect();
+    let mut exploded: HashSet<Entity> = HashSet::new();
+
+    while let Some(barrel) = pending.pop_front() {
+        let Ok((_, transform, _)) = barrels.get(barrel) else {
+            continue;
+        };
+        if !exploded.insert(barrel) {
+            continue;
+        }
+        let center = from_xz(transform.translation);
+
+        for (entity, body_transform) in bodies.iter() {
+            if from_xz(body_transform.translation).distance(center) <= BARREL_EXPLOSION_RADIUS {
+                damage_events.write(DamageEvent::with_source_and_element(
+                    entity,
+                    BARREL_EXPLOSION_DAMAGE,
+                    barrel,
+                    Element::Fire,
+                ));
+            }
+        }
+
+        // Chain reaction
+        for (other, other_transform, prop) in barrels.iter() {
+            if prop.0 == PropKind::ExplosiveBarrel
+                && !exploded.contains(&other)
+                && from_xz(other_transform.translation).distance(center) <= BARREL_EXPLOSION_RADIUS
+            {
+                pending.push_back(other);
+            }
+        }
+
+        let burst = ReactionBurst::new(BARREL_EXPLOSION_RADIUS);
+        let burst_transform = Transform::from_translation(Vec3::new(center.x, 0.5, center.y))
+            .with_scale(Vec3::splat(burst.radius()));
+        if let (Some(meshes), Some(materials)) = (game_meshes.as_ref(), game_materials.as_ref()) {
+            commands.spawn((
+                Mesh3d(meshes.explosion.clone()),
+                MeshMaterial3d(materials.fire_nova.clone()),
+                burst_transform,
+                burst,
+            ));
+        } else {
+            // Fallback for tests without mesh resources
+            commands.spawn((burst_transform, burst));
+        }
+        commands.entity(barrel).try_despawn();
+    }
+}
+
+/// Applies Lightning and Frost triggers to puddles and counts their states down.
+/// Frozen puddles stamp ice onto the `HazardMap` so bodies slide over them, and
+/// remove only the ice they added when they thaw.
+pub fn update_water_puddles_system(
+    time: Res<Time>,
+    mut triggered: MessageReader<HazardTriggered>,
+    mut puddles: Query<(Entity, &Transform, &mut WaterPuddle)>,
+    mut hazard_map: ResMut<HazardMap>,
+    assets: Option<Res<HazardPropAssets>>,
+    mut commands: Commands,
+) {
+    for event in triggered.read() {
+        if event.kind != PropKind::WaterPuddle {
+            continue;
+        }
+        let Ok((_, transform, mut puddle)) = puddles.get_mut(event.prop) else {
+            continue;
+        };
+
+        match (event.element, &mut puddle.state) {
+            (Element::Frost, PuddleState::Frozen { remaining, .. }) => {
+                *remaining = FROZEN_DURATION;
+            }
+            // Freezing also grounds an electrified puddle
+            (Element::Frost, _) => {
+                let iced_cells: Vec<IVec2> =
+                    HazardMap::cells_touching(from_xz(transform.translation), PUDDLE_RADIUS)
+                        .filter(|cell| hazard_map.kind_at(HazardMap::cell_center(*cell)).is_none())
+                        .collect();
+                for cell in &iced_cells {
+                    hazard_map.set(*cell, HazardKind::Ice);
+                }
+                puddle.state = PuddleState::Frozen {
+                    remaining: FROZEN_DURATION,
+                    iced_cells,
+                };
+            }
+            (Element::Lightning, PuddleState::Frozen { .. }) => {}
+            (Element::Lightning, _) => {
+                puddle.state = PuddleState::Electrified {
+                    remaining: ELECTRIFIED_DURATION,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    let delta = time.delta_secs();
+    for (entity, _, mut puddle) in puddles.iter_mut() {
+        let previous = std::mem::discriminant(&puddle.state);
+        match &mut puddle.state {
+            PuddleState::Still => {}
+            PuddleState::Electrified { remaining } => {
+                *remaining -= delta;
+                if *remaining <= 0.0 {
+                    puddle.state = PuddleState::Still;
+                }
+            }
+            PuddleState::Frozen { remaining, iced_cells } => {
+                *remaining -= delta;
+                if *remaining <= 0.0 {
+                    for cell in iced_cells.iter() {
+                        if hazard_map.kind_at(HazardMap::cell_center(*cell)) == Some(HazardKind::Ice) {
+                            hazard_map.clear_cell(*cell);
+                        }
+                    }
+                    puddle.state = PuddleState::Still;
+                }
+            }
+        }
+
+        // Change detection can't tell a timer tick from a state change, so compare variants
+        if puddle.is_added() || std::mem::discriminant(&puddle.state) != previous {
+            if let Some(assets) = &assets {
+                let material = match puddle.state {
+                    PuddleState::Still => assets.water.clone(),
+                    PuddleState::Electrified { .. } => assets.electrified_water.clone(),
+                    PuddleState::Frozen { .. } => assets.frozen_water.clone(),
+                };
+                commands.entity(entity).insert(MeshMaterial3d(material));
+            }
+        }
+    }
+}
+
+/// Amplifies vents hit by Poison and counts the amplification down
+pub fn update_toxic_vents_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut triggered: MessageReader<HazardTriggered>,
+    mut vents: Query<(Entity, &mut Transform, &mut ToxicVent)>,
+    assets: Option<Res<HazardPropAssets>>,
+) {
+    let mut amplified: HashSet<Entity> = HashSet::new();
+    for event in triggered.read() {
+        if event.kind == PropKind::ToxicVent {
+            amplified.insert(event.prop);
+        }
+    }
+
+    let delta = time.delta_secs();
+    for (entity, mut transform, mut vent) in vents.iter_mut() {
+        let was_amplified = vent.is_amplified();
+        if amplified.contains(&entity) {
+            vent.amplified_remaining = VENT_AMPLIFIED_DURATION;
+        } else if was_amplified {
+            vent.amplified_remaining = (vent.amplified_remaining - delta).max(0.0);
+        }
+
+        if vent.is_amplified() != was_amplified {
+            transform.scale = Vec3::splat(vent.radius() / VENT_RADIUS);
+            if let Some(assets) = &assets {
+                let material = if vent.is_amplified() {
+                    assets.amplified_vent_material.clone()
+                } else {
+                    assets.vent_material.clone()
+                };
+                commands.entity(entity).insert(MeshMaterial3d(material));
+            }
+        }
+    }
+}
+
+/// Electrified puddles shock and vent fumes poison the player and enemies inside them
+#[allow(clippy::type_complexity)]
+pub fn hazard_pulse_damage_system(
+    time: Res<Time>,
+    mut timer: ResMut<HazardPulseTimer>,
+    puddles: Query<(Entity, &Transform, &WaterPuddle)>,
+    vents: Query<(Entity, &Transform, &ToxicVent)>,
+    bodies: Query<(Entity, &Transform), Or<(With<Player>, With<Enemy>)>>,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    timer.0.tick(time.delta());
+    if !timer.0.just_finished() {
+        return;
+    }
+    let interval = timer.0.duration().as_secs_f32();
+
+    let zones = puddles
+        .iter()
+        .filter(|(_, _, puddle)| puddle.is_electrified())
+        .map(|(entity, transform, _)| {
+            (entity, transform, PUDDLE_RADIUS, ELECTRIFIED_DAMAGE_PER_SECOND, Element::Lightning)
+        })
+        .chain(vents.iter().map(|(entity, transform, vent)| {
+            (entity, transform, vent.radius(), vent.damage_per_second(), Element::Poison)
+        }));
+
+    for (source, transform, radius, damage_per_second, element) in zones {
+        let center = from_xz(transform.translation);
+        for (entity, body_transform) in bodies.iter() {
+            if from_xz(body_transform.translation).distance(center) <= radius {
+                damage_events.write(
+                    DamageEvent::with_source_and_element(
+                        entity,
+                        damage_per_second * interval,
+                        source,
+                        element,
+                    )
+                    .over_time(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    use crate::spell::SpellType;
+    use crate::collision::components::ObstacleResponse;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>();
+        app.add_message::<ProjectileObstacleHit>();
+        app.add_message::<HazardTriggered>();
+        app.init_resource::<Time>();
+        app.init_resource::<HazardMap>();
+        app.init_resource::<HazardPulseTimer>();
+        app
+    }
+
+    fn spawn_prop(app: &mut App, kind: PropKind, position: Vec2) -> Entity {
+        let world = app.world_mut();
+        let entity = {
+            let mut commands = world.commands();
+            spawn_hazard_prop(&mut commands, kind, position, None).id()
+        };
+        world.flush();
+        entity
+    }
+
+    fn spawn_enemy(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy { speed: 2.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(position.x, 0.375, position.y)),
+            ))
+            .id()
+    }
+
+    fn spawn_player(app: &mut App, position: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Player {
+                    speed: 7.0,
+                    regen_rate: 1.0,
+                    pickup_radius: 2.0,
+                    last_movement_direction: Vec3::ZERO,
+                },
+                Transform::from_translation(Vec3::new(position.x, 0.5, position.y)),
+            ))
+            .id()
+    }
+
+    fn hit(app: &mut App, target: Entity, element: Element) {
+        app.world_mut()
+            .write_message(DamageEvent::with_element(target, 10.0, element));
+    }
+
+    fn trigger(app: &mut App, prop: Entity, kind: PropKind, element: Element) {
+        app.world_mut()
+            .write_message(HazardTriggered { prop, kind, element });
+    }
+
+    fn read_triggers(app: &App) -> Vec<HazardTriggered> {
+        let messages = app.world().resource::<Messages<HazardTriggered>>();
+        messages.get_cursor().read(messages).copied().collect()
+    }
+
+    fn read_damage(app: &App) -> Vec<DamageEvent> {
+        let messages = app.world().resource::<Messages<DamageEvent>>();
+        messages.get_cursor().read(messages).cloned().collect()
+    }
+
+    /// Advance time and drop last frame's triggers (each `run_system_once` reads from the start)
+    fn advance(app: &mut App, seconds: f32) {
+        app.world_mut().resource_mut::<Messages<HazardTriggered>>().clear();
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(seconds));
+    }
+
+    mod spawn_tests {
+        use super::*;
+
+        #[test]
+        fn barrels_are_obstacles_and_flat_props_are_not() {
+            let mut app = setup_app();
+            let barrel = spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::ZERO);
+            let puddle = spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::X * 10.0);
+            let vent = spawn_prop(&mut app, PropKind::ToxicVent, Vec2::X * 20.0);
+
+            assert!(app.world().get::<Obstacle>(barrel).is_some());
+            assert!(app.world().get::<Obstacle>(puddle).is_none());
+            assert!(app.world().get::<WaterPuddle>(puddle).is_some());
+            assert!(app.world().get::<ToxicVent>(vent).is_some());
+        }
+    }
+
+    mod trigger_tests {
+        use super::*;
+
+        #[test]
+        fn fire_hit_near_barrel_triggers_it() {
+            let mut app = setup_app();
+            let barrel = spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::ZERO);
+            let enemy = spawn_enemy(&mut app, Vec2::new(2.0, 0.0));
+            hit(&mut app, enemy, Element::Fire);
+
+            let _ = app.world_mut().run_system_once(detect_hazard_triggers_system);
+
+            let triggers = read_triggers(&app);
+            assert_eq!(triggers.len(), 1);
+            assert_eq!(triggers[0].prop, barrel);
+            assert_eq!(triggers[0].element, Element::Fire);
+        }
+
+        #[test]
+        fn other_elements_and_distant_hits_do_not_trigger() {
+            let mut app = setup_app();
+            spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::ZERO);
+            let near = spawn_enemy(&mut app, Vec2::new(2.0, 0.0));
+            let far = spawn_enemy(&mut app, Vec2::new(20.0, 0.0));
+            hit(&mut app, near, Element::Frost);
+            hit(&mut app, far, Element::Fire);
+
+            let _ = app.world_mut().run_system_once(detect_hazard_triggers_system);
+
+            assert!(read_triggers(&app).is_empty());
+        }
+
+        #[test]
+        fn damage_from_props_does_not_retrigger_them() {
+            let mut app = setup_app();
+            let puddle = spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::ZERO);
+            let enemy = spawn_enemy(&mut app, Vec2::ZERO);
+            app.world_mut().write_message(DamageEvent::with_source_and_element(
+                enemy,
+                6.0,
+                puddle,
+                Element::Lightning,
+            ));
+
+            let _ = app.world_mut().run_system_once(detect_hazard_triggers_system);
+
+            assert!(read_triggers(&app).is_empty());
+        }
+
+        #[test]
+        fn projectile_stopped_by_barrel_triggers_it() {
+            let mut app = setup_app();
+            let barrel = spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::ZERO);
+            app.world_mut().write_message(ProjectileObstacleHit {
+                projectile: Entity::PLACEHOLDER,
+                spell_type: SpellType::Fireball,
+                response: ObstacleResponse::Explode,
+                position: Vec3::new(0.9, 0.5, 0.0),
+            });
+
+            let _ = app.world_mut().run_system_once(detect_hazard_triggers_system);
+
+            let triggers = read_triggers(&app);
+            assert_eq!(triggers.len(), 1);
+            assert_eq!(triggers[0].prop, barrel);
+        }
+
+        #[test]
+        fn many_hits_trigger_each_prop_once_per_element() {
+            let mut app = setup_app();
+            spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::ZERO);
+            let enemy = spawn_enemy(&mut app, Vec2::ZERO);
+            hit(&mut app, enemy, Element::Lightning);
+            hit(&mut app, enemy, Element::Lightning);
+            hit(&mut app, enemy, Element::Frost);
+
+            let _ = app.world_mut().run_system_once(detect_hazard_triggers_system);
+
+            assert_eq!(read_triggers(&app).len(), 2);
+        }
+    }
+
+    mod barrel_tests {
+        use super::*;
+
+        #[test]
+        fn explosion_damages_player_and_enemies_in_range() {
+            let mut app = setup_app();
+            let barrel = spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::ZERO);
+            let enemy = spawn_enemy(&mut app, Vec2::new(3.0, 0.0));
+            let player = spawn_player(&mut app, Vec2::new(0.0, -4.0));
+            let far = spawn_enemy(&mut app, Vec2::new(30.0, 0.0));
+            trigger(&mut app, barrel, PropKind::ExplosiveBarrel, Element::Fire);
+
+            let _ = app.world_mut().run_system_once(explode_barrels_system);
+
+            let damage = read_damage(&app);
+            let targets: Vec<Entity> = damage.iter().map(|event| event.target).collect();
+            assert!(targets.contains(&enemy));
+            assert!(targets.contains(&player));
+            assert!(!targets.contains(&far));
+            assert!(damage.iter().all(|event| event.is_element(Element::Fire)
+                && event.amount == BARREL_EXPLOSION_DAMAGE
+                && event.source == Some(barrel)));
+            assert!(!app.world().entities().contains(barrel));
+        }
+
+        #[test]
+        fn explosions_chain_to_nearby_barrels() {
+            let mut app = setup_app();
+            let first = spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::ZERO);
+            let second = spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::new(5.0, 0.0));
+            let third = spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::new(10.0, 0.0));
+            let isolated = spawn_prop(&mut app, PropKind::ExplosiveBarrel, Vec2::new(40.0, 0.0));
+            trigger(&mut app, first, PropKind::ExplosiveBarrel, Element::Fire);
+
+            let _ = app.world_mut().run_system_once(explode_barrels_system);
+
+            assert!(!app.world().entities().contains(second));
+            assert!(!app.world().entities().contains(third));
+            assert!(app.world().entities().contains(isolated));
+        }
+    }
+
+    mod puddle_tests {
+        use super::*;
+
+        #[test]
+        fn lightning_electrifies_and_wears_off() {
+            let mut app = setup_app();
+            let puddle = spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::ZERO);
+            trigger(&mut app, puddle, PropKind::WaterPuddle, Element::Lightning);
+
+            let _ = app.world_mut().run_system_once(update_water_puddles_system);
+            assert!(app.world().get::<WaterPuddle>(puddle).unwrap().is_electrified());
+
+            advance(&mut app, ELECTRIFIED_DURATION + 0.1);
+            let _ = app.world_mut().run_system_once(update_water_puddles_system);
+            assert_eq!(app.world().get::<WaterPuddle>(puddle).unwrap().state, PuddleState::Still);
+        }
+
+        #[test]
+        fn frost_freezes_puddle_into_ice_and_thaws_it() {
+            let mut app = setup_app();
+            let puddle = spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::new(2.5, 2.5));
+            trigger(&mut app, puddle, PropKind::WaterPuddle, Element::Frost);
+
+            let _ = app.world_mut().run_system_once(update_water_puddles_system);
+            assert!(app.world().get::<WaterPuddle>(puddle).unwrap().is_frozen());
+            let map = app.world().resource::<HazardMap>();
+            assert_eq!(map.kind_at(Vec2::new(2.5, 2.5)), Some(HazardKind::Ice));
+
+            advance(&mut app, FROZEN_DURATION + 0.1);
+            let _ = app.world_mut().run_system_once(update_water_puddles_system);
+            assert!(!app.world().get::<WaterPuddle>(puddle).unwrap().is_frozen());
+            assert!(app.world().resource::<HazardMap>().is_empty());
+        }
+
+        #[test]
+        fn thawing_keeps_tiles_from_the_layout() {
+            let mut app = setup_app();
+            app.world_mut()
+                .resource_mut::<HazardMap>()
+                .set(IVec2::new(0, 0), HazardKind::Lava);
+            let puddle = spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::new(2.5, 2.5));
+            trigger(&mut app, puddle, PropKind::WaterPuddle, Element::Frost);
+
+            let _ = app.world_mut().run_system_once(update_water_puddles_system);
+            assert_eq!(
+                app.world().resource::<HazardMap>().kind_at(Vec2::new(2.5, 2.5)),
+                Some(HazardKind::Lava)
+            );
+
+            advance(&mut app, FROZEN_DURATION + 0.1);
+            let _ = app.world_mut().run_system_once(update_water_puddles_system);
+            assert_eq!(
+                app.world().resource::<HazardMap>().kind_at(Vec2::new(2.5, 2.5)),
+                Some(HazardKind::Lava)
+            );
+        }
+
+        #[test]
+        fn frozen_puddle_ignores_lightning() {
+            let mut app = setup_app();
+            let puddle = spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::ZERO);
+            trigger(&mut app, puddle, PropKind::WaterPuddle, Element::Frost);
+            let _ = app.world_mut().run_system_once(update_water_puddles_system);
+
+            trigger(&mut app, puddle, PropKind::WaterPuddle, Element::Lightning);
+            let _ = app.world_mut().run_system_once(update_water_puddles_system);
+
+            assert!(app.world().get::<WaterPuddle>(puddle).unwrap().is_frozen());
+        }
+    }
+
+    mod vent_tests {
+        use super::*;
+
+        #[test]
+        fn poison_amplifies_vent_until_it_wears_off() {
+            let mut app = setup_app();
+            let vent = spawn_prop(&mut app, PropKind::ToxicVent, Vec2::ZERO);
+            trigger(&mut app, vent, PropKind::ToxicVent, Element::Poison);
+
+            let _ = app.world_mut().run_system_once(update_toxic_vents_system);
+            assert!(app.world().get::<ToxicVent>(vent).unwrap().is_amplified());
+            assert!(app.world().get::<Transform>(vent).unwrap().scale.x > 1.0);
+
+            advance(&mut app, VENT_AMPLIFIED_DURATION + 0.1);
+            let _ = app.world_mut().run_system_once(update_toxic_vents_system);
+            assert!(!app.world().get::<ToxicVent>(vent).unwrap().is_amplified());
+            assert_eq!(app.world().get::<Transform>(vent).unwrap().scale, Vec3::ONE);
+        }
+    }
+
+    mod pulse_tests {
+        use super::*;
+
+        #[test]
+        fn vents_and_electrified_puddles_damage_bodies_each_pulse() {
+            let mut app = setup_app();
+            let vent = spawn_prop(&mut app, PropKind::ToxicVent, Vec2::ZERO);
+            let puddle = spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::new(30.0, 0.0));
+            let still = spawn_prop(&mut app, PropKind::WaterPuddle, Vec2::new(-30.0, 0.0));
+            app.world_mut().get_mut::<WaterPuddle>(puddle).unwrap().state =
+                PuddleState::Electrified { remaining: 1.0 };
+            let poisoned = spawn_enemy(&mut app, Vec2::new(1.0, 0.0));
+            let player = spawn_player(&mut app, Vec2::new(30.0, 1.0));
+            let dry = spawn_enemy(&mut app, Vec2::new(-30.0, 0.0));
+
+            advance(&mut app, 0.5);
+            let _ = app.world_mut().run_system_once(hazard_pulse_damage_system);
+
+            let damage = read_damage(&app);
+            assert!(damage.iter().any(|event| event.target == poisoned
+                && event.source == Some(vent)
+                && event.is_poison()));
+            assert!(damage.iter().any(|event| event.target == player
+                && event.source == Some(puddle)
+                && event.is_element(Element::Lightning)));
+            assert!(!damage.iter().any(|event| event.target == dry));
+            assert!(!damage.iter().any(|event| event.source == Some(still)));
+            assert!(damage.iter().all(DamageEvent::is_over_time));
+        }
+
+        #[test]
+        fn no_damage_between_pulses() {
+            let mut app = setup_app();
+            spawn_prop(&mut app, PropKind::ToxicVent, Vec2::ZERO);
+            spawn_enemy(&mut app, Vec2::ZERO);
+
+            advance(&mut app, 0.1);
+            let _ = app.world_mut().run_system_once(hazard_pulse_damage_system);
+
+            assert!(read_damage(&app).is_empty());
+        }
+    }
+}
@@ -0,0 +1,671 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Something the player can do with a button press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InputAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    /// Walk toward the cursor while held (click-to-move)
+    MoveToCursor,
+    /// Pause the game; also backs out of the inventory, meta shop and game over screens
+    Pause,
+    Inventory,
+    ToggleDebugHud,
+    /// Hold to look around with the free camera
+    FreeCamera,
+    /// Start a new run from the game over screen
+    Restart,
+    /// Pick the first, second or third card on the level-up screen
+    ChooseFirst,
+    ChooseSecond,
+    ChooseThird,
+    /// Spend a reroll token on the level-up screen
+    Reroll,
+}
+
+impl InputAction {
+    /// Every action, in controls screen order
+    pub const ALL: [InputAction; 14] = [
+        InputAction::MoveUp,
+        InputAction::MoveDown,
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::MoveToCursor,
+        InputAction::Pause,
+        InputAction::Inventory,
+        InputAction::ToggleDebugHud,
+        InputAction::FreeCamera,
+        InputAction::Restart,
+        InputAction::ChooseFirst,
+        InputAction::ChooseSecond,
+        InputAction::ChooseThird,
+        InputAction::Reroll,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputAction::MoveUp => "Move Up",
+            InputAction::MoveDown => "Move Down",
+            InputAction::MoveLeft => "Move Left",
+            InputAction::MoveRight => "Move Right",
+            InputAction::MoveToCursor => "Move to Cursor",
+            InputAction::Pause => "Pause / Back",
+            InputAction::Inventory => "Inventory",
+            InputAction::ToggleDebugHud => "Debug HUD",
+            InputAction::FreeCamera => "Free Camera",
+            InputAction::Restart => "Restart",
+            InputAction::ChooseFirst => "Level-Up Choice 1",
+            InputAction::ChooseSecond => "Level-Up Choice 2",
+            InputAction::ChooseThird => "Level-Up Choice 3",
+            InputAction::Reroll => "Reroll",
+        }
+    }
+
+    pub fn default_bindings(&self) -> ActionBindings {
+        use Binding::{Gamepad as Pad, Key, Mouse};
+        let (primary, secondary, gamepad) = match self {
+            InputAction::MoveUp => (Some(Key(KeyCode::KeyW)), Some(Key(KeyCode::ArrowUp)), Some(Pad(GamepadButton::DPadUp))),
+            InputAction::MoveDown => (Some(Key(KeyCode::KeyS)), Some(Key(KeyCode::ArrowDown)), Some(Pad(GamepadButton::DPadDown))),
+            InputAction::MoveLeft => (Some(Key(KeyCode::KeyA)), Some(Key(KeyCode::ArrowLeft)), Some(Pad(GamepadButton::DPadLeft))),
+            InputAction::MoveRight => (Some(Key(KeyCode::KeyD)), Some(Key(KeyCode::ArrowRight)), Some(Pad(GamepadButton::DPadRight))),
+            InputAction::MoveToCursor => (Some(Mouse(MouseButton::Left)), None, None),
+            InputAction::Pause => (Some(Key(KeyCode::Escape)), None, Some(Pad(GamepadButton::Start))),
+            InputAction::Inventory => (Some(Key(KeyCode::KeyI)), None, Some(Pad(GamepadButton::North))),
+            InputAction::ToggleDebugHud => (Some(Key(KeyCode::F3)), None, None),
+            InputAction::FreeCamera => (Some(Mouse(MouseButton::Right)), None, None),
+            InputAction::Restart => (Some(Key(KeyCode::KeyR)), None, Some(Pad(GamepadButton::West))),
+            InputAction::ChooseFirst => (Some(Key(KeyCode::Digit1)), None, None),
+            InputAction::ChooseSecond => (Some(Key(KeyCode::Digit2)), None, None),
+            InputAction::ChooseThird => (Some(Key(KeyCode::Digit3)), None, None),
+            // R is taken by Restart, and bindings are unique across screens
+            InputAction::Reroll => (Some(Key(KeyCode::KeyE)), None, Some(Pad(GamepadButton::East))),
+        };
+        ActionBindings {
+            primary,
+            secondary,
+            gamepad,
+        }
+    }
+}
+
+/// Keys that can be bound. Anything else is refused on the controls screen.
+pub const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE, KeyCode::KeyF,
+    KeyCode::KeyG, KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ, KeyCode::KeyK, KeyCode::KeyL,
+    KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO, KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR,
+    KeyCode::KeyS, KeyCode::KeyT, KeyCode::KeyU, KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX,
+    KeyCode::KeyY, KeyCode::KeyZ,
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6,
+    KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12,
+    KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight,
+    KeyCode::Escape, KeyCode::Space, KeyCode::Enter, KeyCode::Tab, KeyCode::Backspace,
+    KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight,
+    KeyCode::AltLeft, KeyCode::AltRight,
+    KeyCode::Backquote, KeyCode::Minus, KeyCode::Equal, KeyCode::BracketLeft,
+    KeyCode::BracketRight, KeyCode::Backslash, KeyCode::Semicolon, KeyCode::Quote,
+    KeyCode::Comma, KeyCode::Period, KeyCode::Slash,
+];
+
+/// Mouse buttons that can be bound.
+pub const BINDABLE_MOUSE_BUTTONS: &[MouseButton] = &[
+    MouseButton::Left,
+    MouseButton::Right,
+    MouseButton::Middle,
+    MouseButton::Back,
+    MouseButton::Forward,
+];
+
+/// Gamepad buttons that can be bound.
+pub const BINDABLE_GAMEPAD_BUTTONS: &[GamepadButton] = &[
+    GamepadButton::South,
+    GamepadButton::East,
+    GamepadButton::North,
+    GamepadButton::West,
+    GamepadButton::LeftTrigger,
+    GamepadButton::LeftTrigger2,
+    GamepadButton::RightTrigger,
+    GamepadButton::RightTrigger2,
+    GamepadButton::Select,
+    GamepadButton::Start,
+    GamepadButton::LeftThumb,
+    GamepadButton::RightThumb,
+    GamepadButton::DPadUp,
+    GamepadButton::DPadDown,
+    GamepadButton::DPadLeft,
+    GamepadButton::DPadRight,
+];
+
+/// A single physical input an action can be bound to.
+/// Stored in the controls file by name, e.g. "KeyW", "MouseRight", "GamepadStart".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+impl Binding {
+    /// Stable name used in the controls file
+    pub fn name(&self) -> String {
+        match self {
+            Binding::Key(key) => format!("{:?}", key),
+            Binding::Mouse(button) => format!("Mouse{:?}", button),
+            Binding::Gamepad(button) => format!("Gamepad{:?}", button),
+        }
+    }
+
+    /// Inverse of `name`; `None` for unknown or unbindable inputs
+    pub fn from_name(name: &str) -> Option<Self> {
+        fn find<T: Copy + fmt::Debug>(candidates: &[T], name: &str) -> Option<T> {
+            candidates.iter().copied().find(|candidate| format!("{:?}", candidate) == name)
+        }
+
+        if let Some(button) = name.strip_prefix("Mouse") {
+            return find(BINDABLE_MOUSE_BUTTONS, button).map(Binding::Mouse);
+        }
+        if let Some(button) = name.strip_prefix("Gamepad") {
+            return find(BINDABLE_GAMEPAD_BUTTONS, button).map(Binding::Gamepad);
+        }
+        find(BINDABLE_KEYS, name).map(Binding::Key)
+    }
+
+    /// Short text for the controls screen, e.g. "W", "Right Mouse", "Pad Start"
+    pub fn label(&self) -> String {
+        match self {
+            Binding::Key(key) => {
+                let name = format!("{:?}", key);
+                ["Key", "Digit", "Arrow"]
+                    .iter()
+                    .find_map(|prefix| name.strip_prefix(prefix))
+                    .unwrap_or(&name)
+                    .to_string()
+            }
+            Binding::Mouse(button) => format!("{:?} Mouse", button),
+            Binding::Gamepad(button) => format!("Pad {:?}", button),
+        }
+    }
+
+    pub fn is_gamepad(&self) -> bool {
+        matches!(self, Binding::Gamepad(_))
+    }
+}
+
+impl TryFrom<String> for Binding {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Binding::from_name(&name).ok_or_else(|| format!("unknown binding `{}`", name))
+    }
+}
+
+impl From<Binding> for String {
+    fn from(binding: Binding) -> Self {
+        binding.name()
+    }
+}
+
+/// Which of an action's bindings is being changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BindingSlot {
+    Primary,
+    Secondary,
+    Gamepad,
+}
+
+impl BindingSlot {
+    pub const ALL: [BindingSlot; 3] = [BindingSlot::Primary, BindingSlot::Secondary, BindingSlot::Gamepad];
+
+    /// Keyboard slots take keys and mouse buttons; the gamepad slot only gamepad buttons
+    pub fn accepts(&self, binding: Binding) -> bool {
+        binding.is_gamepad() == (*self == BindingSlot::Gamepad)
+    }
+}
+
+/// Bindings of one action: two keyboard/mouse slots and one gamepad slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ActionBindings {
+    #[serde(default)]
+    pub primary: Option<Binding>,
+    #[serde(default)]
+    pub secondary: Option<Binding>,
+    #[serde(default)]
+    pub gamepad: Option<Binding>,
+}
+
+impl ActionBindings {
+    pub fn get(&self, slot: BindingSlot) -> Option<Binding> {
+        match slot {
+            BindingSlot::Primary => self.primary,
+            BindingSlot::Secondary => self.secondary,
+            BindingSlot::Gamepad => self.gamepad,
+        }
+    }
+
+    pub fn set(&mut self, slot: BindingSlot, binding: Option<Binding>) {
+        match slot {
+            BindingSlot::Primary => self.primary = binding,
+            BindingSlot::Secondary => self.secondary = binding,
+            BindingSlot::Gamepad => self.gamepad = binding,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Binding> + '_ {
+        [self.primary, self.secondary, self.gamepad].into_iter().flatten()
+    }
+}
+
+/// A binding already claimed by another action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BindingConflict {
+    pub binding: Binding,
+    pub existing: InputAction,
+    pub requested: InputAction,
+}
+
+impl fmt::Display for BindingConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is already bound to {}",
+            self.binding.label(),
+            self.existing.label()
+        )
+    }
+}
+
+/// Maps every `InputAction` to its bindings.
+/// Loaded from and saved to the controls file by the input plugin.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct InputMap {
+    bindings: BTreeMap<InputAction, ActionBindings>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self {
+            bindings: InputAction::ALL
+                .iter()
+                .map(|action| (*action, action.default_bindings()))
+                .collect(),
+        }
+    }
+}
+
+impl InputMap {
+    /// Build a map from saved bindings; actions missing from `saved` (such as
+    /// ones added since the file was written) get their defaults. A default
+    /// the file already uses for another action stays with that action, and
+    /// the new action is left unbound in that slot.
+    pub fn from_saved(saved: BTreeMap<InputAction, ActionBindings>) -> Self {
+        let mut map = Self { bindings: saved };
+        for action in InputAction::ALL {
+            if map.bindings.contains_key(&action) {
+                continue;
+            }
+            let mut bindings = action.default_bindings();
+            for slot in BindingSlot::ALL {
+                let Some(binding) = bindings.get(slot) else {
+                    continue;
+                };
+                if let Some((existing, _)) = map.find(binding) {
+                    let conflict = BindingConflict {
+                        binding,
+                        existing,
+                        requested: action,
+                    };
+                    warn!("Controls: {}, leaving {} unbound there", conflict, action.label());
+                    bindings.set(slot, None);
+                }
+            }
+            map.bindings.insert(action, bindings);
+        }
+        map
+    }
+
+    /// All bindings by action, for saving
+    pub fn to_saved(&self) -> BTreeMap<InputAction, ActionBindings> {
+        self.bindings.clone()
+    }
+
+    pub fn bindings(&self, action: InputAction) -> ActionBindings {
+        self.bindings.get(&action).copied().unwrap_or_default()
+    }
+
+    /// Action and slot currently using `binding`
+    pub fn find(&self, binding: Binding) -> Option<(InputAction, BindingSlot)> {
+        self.bindings.iter().find_map(|(action, bindings)| {
+            BindingSlot::ALL
+                .into_iter()
+                .find(|slot| bindings.get(*slot) == Some(binding))
+                .map(|slot| (*action, slot))
+        })
+    }
+
+    /// Bind `binding` to `action` in `slot`. A binding already used by the
+    /// same action moves to the new slot; one used by another action is refused.
+    pub fn rebind(
+        &mut self,
+        action: InputAction,
+        slot: BindingSlot,
+        binding: Binding,
+    ) -> Result<(), BindingConflict> {
+        match self.find(binding) {
+            Some((existing, _)) if existing != action => {
+                return Err(BindingConflict {
+                    binding,
+                    existing,
+                    requested: action,
+                });
+            }
+            Some((_, old_slot)) => self.clear(action, old_slot),
+            None => {}
+        }
+        self.bindings.entry(action).or_default().set(slot, Some(binding));
+        Ok(())
+    }
+
+    pub fn clear(&mut self, action: InputAction, slot: BindingSlot) {
+        if let Some(bindings) = self.bindings.get_mut(&action) {
+            bindings.set(slot, None);
+        }
+    }
+
+    /// Bindings shared by more than one action (only possible in hand-edited files)
+    pub fn conflicts(&self) -> Vec<BindingConflict> {
+        let mut seen: Vec<(Binding, InputAction)> = Vec::new();
+        let mut conflicts = Vec::new();
+        for (action, bindings) in &self.bindings {
+            for binding in bindings.iter() {
+                match seen.iter().find(|(used, _)| *used == binding) {
+                    Some((_, existing)) if existing != action => conflicts.push(BindingConflict {
+                        binding,
+                        existing: *existing,
+                        requested: *action,
+                    }),
+                    Some(_) => {}
+                    None => seen.push((binding, *action)),
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// Action state read through the `InputMap`, across keyboard, mouse and gamepads.
+#[derive(SystemParam)]
+pub struct ActionInput<'w, 's> {
+    map: Res<'w, InputMap>,
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Option<Res<'w, ButtonInput<MouseButton>>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+#[derive(Clone, Copy)]
+enum Edge {
+    Pressed,
+    JustPressed,
+    JustReleased,
+}
+
+fn button_edge<T>(input: &ButtonInput<T>, button: T, edge: Edge) -> bool
+where
+    T: Copy + Eq + std::hash::Hash + Send + Sync + 'static,
+{
+    match edge {
+        Edge::Pressed => input.pressed(button),
+        Edge::JustPressed => input.just_pressed(button),
+        Edge::JustReleased => input.just_released(button),
+    }
+}
+
+impl ActionInput<'_, '_> {
+    pub fn pressed(&self, action: InputAction) -> bool {
+        self.check(action, Edge::Pressed)
+    }
+
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.check(action, Edge::JustPressed)
+    }
+
+    pub fn just_released(&self, action: InputAction) -> bool {
+        self.check(action, Edge::JustReleased)
+    }
+
+    pub fn map(&self) -> &InputMap {
+        &self.map
+    }
+
+    pub fn keyboard(&self) -> &ButtonInput<KeyCode> {
+        &self.keyboard
+    }
+
+    pub fn gamepads(&self) -> impl Iterator<Item = &Gamepad> + '_ {
+        self.gamepads.iter()
+    }
+
+    fn check(&self, action: InputAction, edge: Edge) -> bool {
+        self.map.bindings(action).iter().any(|binding| match binding {
+            Binding::Key(key) => button_edge(&*self.keyboard, key, edge),
+            Binding::Mouse(button) => self
+                .mouse
+                .as_ref()
+                .is_some_and(|mouse| button_edge(&**mouse, button, edge)),
+            Binding::Gamepad(button) => self.gamepads.iter().any(|gamepad| match edge {
+                Edge::Pressed => gamepad.pressed(button),
+                Edge::JustPressed => gamepad.just_pressed(button),
+                Edge::JustReleased => gamepad.just_released(button),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    mod binding_tests {
+        use super::*;
+
+        #[test]
+        fn names_round_trip_for_every_bindable_input() {
+            let all = BINDABLE_KEYS
+                .iter()
+                .map(|key| Binding::Key(*key))
+                .chain(BINDABLE_MOUSE_BUTTONS.iter().map(|button| Binding::Mouse(*button)))
+                .chain(BINDABLE_GAMEPAD_BUTTONS.iter().map(|button| Binding::Gamepad(*button)));
+            for binding in all {
+                assert_eq!(Binding::from_name(&binding.name()), Some(binding));
+            }
+        }
+
+        #[test]
+        fn unknown_names_are_rejected() {
+            assert_eq!(Binding::from_name("KeyNope"), None);
+            assert_eq!(Binding::from_name("MouseKeyW"), None);
+            assert!(Binding::try_from("GamepadMode".to_string()).is_err());
+        }
+
+        #[test]
+        fn labels_are_short() {
+            assert_eq!(Binding::Key(KeyCode::KeyW).label(), "W");
+            assert_eq!(Binding::Key(KeyCode::Digit1).label(), "1");
+            assert_eq!(Binding::Key(KeyCode::ArrowUp).label(), "Up");
+            assert_eq!(Binding::Mouse(MouseButton::Right).label(), "Right Mouse");
+            assert_eq!(Binding::Gamepad(GamepadButton::Start).label(), "Pad Start");
+        }
+
+        #[test]
+        fn slots_accept_matching_devices() {
+            assert!(BindingSlot::Primary.accepts(Binding::Key(KeyCode::KeyW)));
+            assert!(BindingSlot::Secondary.accepts(Binding::Mouse(MouseButton::Left)));
+            assert!(!BindingSlot::Primary.accepts(Binding::Gamepad(GamepadButton::South)));
+            assert!(BindingSlot::Gamepad.accepts(Binding::Gamepad(GamepadButton::South)));
+            assert!(!BindingSlot::Gamepad.accepts(Binding::Key(KeyCode::KeyW)));
+        }
+    }
+
+    mod input_map_tests {
+        use super::*;
+
+        #[test]
+        fn defaults_have_no_conflicts() {
+            assert!(InputMap::default().conflicts().is_empty());
+        }
+
+        #[test]
+        fn defaults_bind_every_action() {
+            let map = InputMap::default();
+            for action in InputAction::ALL {
+                assert!(map.bindings(action).iter().next().is_some(), "{:?} unbound", action);
+            }
+        }
+
+        #[test]
+        fn rebind_replaces_slot() {
+            let mut map = InputMap::default();
+            map.rebind(InputAction::MoveUp, BindingSlot::Primary, Binding::Key(KeyCode::KeyK))
+                .unwrap();
+            assert_eq!(map.bindings(InputAction::MoveUp).primary, Some(Binding::Key(KeyCode::KeyK)));
+            assert_eq!(map.find(Binding::Key(KeyCode::KeyW)), None);
+        }
+
+        #[test]
+        fn rebind_to_binding_of_another_action_is_refused() {
+            let mut map = InputMap::default();
+            let err = map
+                .rebind(InputAction::MoveUp, BindingSlot::Primary, Binding::Key(KeyCode::KeyI))
+                .unwrap_err();
+            assert_eq!(err.existing, InputAction::Inventory);
+            assert_eq!(err.to_string(), "I is already bound to Inventory");
+            // Nothing changed
+            assert_eq!(map, InputMap::default());
+        }
+
+        #[test]
+        fn rebind_within_same_action_moves_the_binding() {
+            let mut map = InputMap::default();
+            map.rebind(InputAction::MoveUp, BindingSlot::Primary, Binding::Key(KeyCode::ArrowUp))
+                .unwrap();
+            let bindings = map.bindings(InputAction::MoveUp);
+            assert_eq!(bindings.primary, Some(Binding::Key(KeyCode::ArrowUp)));
+            assert_eq!(bindings.secondary, None);
+        }
+
+        #[test]
+        fn from_saved_keeps_defaults_for_missing_actions() {
+            let mut saved = BTreeMap::new();
+            saved.insert(
+                InputAction::Pause,
+                ActionBindings {
+                    primary: Some(Binding::Key(KeyCode::KeyP)),
+                    ..default()
+                },
+            );
+            let map = InputMap::from_saved(saved);
+            assert_eq!(map.bindings(InputAction::Pause).primary, Some(Binding::Key(KeyCode::KeyP)));
+            assert_eq!(map.bindings(InputAction::Inventory), InputAction::Inventory.default_bindings());
+        }
+
+        #[test]
+        fn from_saved_leaves_a_default_used_by_a_saved_action_unbound() {
+            let mut saved = BTreeMap::new();
+            saved.insert(
+                InputAction::Inventory,
+                ActionBindings {
+                    primary: Some(Binding::Key(KeyCode::Digit1)),
+                    ..default()
+                },
+            );
+            let map = InputMap::from_saved(saved);
+            assert_eq!(map.bindings(InputAction::Inventory).primary, Some(Binding::Key(KeyCode::Digit1)));
+            assert_eq!(map.bindings(InputAction::ChooseFirst).primary, None);
+            assert!(map.conflicts().is_empty());
+        }
+
+        #[test]
+        fn conflicts_reports_shared_bindings() {
+            let mut saved = BTreeMap::new();
+            for action in [InputAction::Inventory, InputAction::Restart] {
+                saved.insert(
+                    action,
+                    ActionBindings {
+                        primary: Some(Binding::Key(KeyCode::KeyI)),
+                        ..default()
+                    },
+                );
+            }
+            let conflicts = InputMap::from_saved(saved).conflicts();
+            assert_eq!(conflicts.len(), 1);
+            assert_eq!(conflicts[0].binding, Binding::Key(KeyCode::KeyI));
+        }
+    }
+
+    mod action_input_tests {
+        use super::*;
+
+        fn setup_test_app() -> App {
+            let mut app = App::new();
+            app.init_resource::<InputMap>();
+            app.init_resource::<ButtonInput<KeyCode>>();
+            app.init_resource::<ButtonInput<MouseButton>>();
+            app
+        }
+
+        #[test]
+        fn any_bound_key_triggers_the_action() {
+            let mut app = setup_test_app();
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(KeyCode::ArrowUp);
+
+            let pressed = app
+                .world_mut()
+                .run_system_once(|input: ActionInput| input.pressed(InputAction::MoveUp))
+                .unwrap();
+            assert!(pressed);
+        }
+
+        #[test]
+        fn mouse_bindings_are_read() {
+            let mut app = setup_test_app();
+            app.world_mut()
+                .resource_mut::<ButtonInput<MouseButton>>()
+                .press(MouseButton::Right);
+
+            let just_pressed = app
+                .world_mut()
+                .run_system_once(|input: ActionInput| input.just_pressed(InputAction::FreeCamera))
+                .unwrap();
+            assert!(just_pressed);
+        }
+
+        #[test]
+        fn rebound_key_replaces_the_default() {
+            let mut app = setup_test_app();
+            app.world_mut()
+                .resource_mut::<InputMap>()
+                .rebind(InputAction::Inventory, BindingSlot::Primary, Binding::Key(KeyCode::Tab))
+                .unwrap();
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(KeyCode::KeyI);
+
+            let pressed = app
+                .world_mut()
+                .run_system_once(|input: ActionInput| input.pressed(InputAction::Inventory))
+                .unwrap();
+            assert!(!pressed);
+        }
+    }
+}
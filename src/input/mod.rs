@@ -0,0 +1,11 @@
+pub mod bindings;
+pub mod plugin;
+pub mod resources;
+pub mod storage;
+pub mod systems;
+
+pub use bindings::*;
+pub use plugin::*;
+pub use resources::*;
+pub use storage::*;
+pub use systems::*;
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+use crate::game::sets::GameSet;
+use crate::input::bindings::InputMap;
+use crate::input::resources::{MenuFocus, MovementIntent, MovementMode};
+use crate::input::storage::ControlsStorage;
+use crate::input::systems::*;
+use crate::states::GameState;
+
+/// Input plugin: turns keyboard, gamepad and mouse into a `MovementIntent`
+/// during play, and drives keyboard/gamepad focus on menu buttons elsewhere.
+/// Bindings and the movement mode are kept in memory unless a
+/// `ControlsStorage` with a path is inserted before the plugin.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<ControlsStorage>()
+        .init_resource::<InputMap>()
+        .init_resource::<MovementMode>()
+        .init_resource::<MovementIntent>()
+        .init_resource::<MenuFocus>()
+        .add_systems(Startup, load_controls_system)
+        .add_systems(Update, save_controls_system)
+        .add_systems(
+            Update,
+            read_movement_intent
+                .in_set(GameSet::Input)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(OnExit(GameState::InGame), clear_movement_intent)
+        // PreUpdate so a confirm press is visible to every Update handler this frame
+        .add_systems(
+            PreUpdate,
+            navigate_menus.run_if(not(in_state(GameState::InGame))),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_registers_input_resources() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().get_resource::<InputMap>().is_some());
+        assert!(app.world().resource::<ControlsStorage>().path.is_none());
+        assert!(app.world().get_resource::<MovementMode>().is_some());
+        assert!(app.world().get_resource::<MovementIntent>().is_some());
+        assert!(app.world().get_resource::<MenuFocus>().is_some());
+    }
+}
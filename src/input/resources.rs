@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How the player steers their character.
+/// Chosen from the pause menu and saved with the controls;
+/// `Both` lets either scheme drive at any time.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MovementMode {
+    /// Hold Move to Cursor (left mouse by default) to walk toward the cursor
+    ClickToMove,
+    /// WASD / arrow keys or the left stick, relative to the camera
+    Direct,
+    /// Direct input wins while held, click-to-move otherwise
+    #[default]
+    Both,
+}
+
+impl MovementMode {
+    pub fn allows_click(&self) -> bool {
+        matches!(self, MovementMode::ClickToMove | MovementMode::Both)
+    }
+
+    pub fn allows_direct(&self) -> bool {
+        matches!(self, MovementMode::Direct | MovementMode::Both)
+    }
+
+    /// Next mode when cycling through the setting
+    pub fn next(&self) -> Self {
+        match self {
+            MovementMode::ClickToMove => MovementMode::Direct,
+            MovementMode::Direct => MovementMode::Both,
+            MovementMode::Both => MovementMode::ClickToMove,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MovementMode::ClickToMove => "Click to Move",
+            MovementMode::Direct => "Direct",
+            MovementMode::Both => "Both",
+        }
+    }
+}
+
+/// Where the player wants to move this frame, on the XZ plane.
+/// Written in `GameSet::Input`, consumed by movement and animation.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default)]
+pub enum MovementIntent {
+    #[default]
+    Idle,
+    /// World-space direction; analog input keeps its magnitude (at most 1.0)
+    Direction(Vec2),
+    /// Ground point under the cursor
+    Toward(Vec2),
+}
+
+impl MovementIntent {
+    pub fn is_moving(&self) -> bool {
+        !matches!(self, MovementIntent::Idle)
+    }
+
+    /// Scaled direction for an entity standing at `position`
+    pub fn direction_from(&self, position: Vec2) -> Vec2 {
+        match *self {
+            MovementIntent::Idle => Vec2::ZERO,
+            MovementIntent::Direction(direction) => direction.clamp_length_max(1.0),
+            MovementIntent::Toward(target) => (target - position).normalize_or_zero(),
+        }
+    }
+}
+
+/// Keyboard / gamepad focus on menu buttons.
+#[derive(Resource, Debug, Default)]
+pub struct MenuFocus {
+    /// Button currently highlighted
+    pub focused: Option<Entity>,
+    /// Button pressed by a confirm input last frame, released this frame
+    pub pressed: Option<Entity>,
+    /// Stick is still deflected from the last navigation step
+    pub stick_held: bool,
+    /// Navigation is suspended, e.g. while the controls screen captures a rebind
+    pub locked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod movement_mode_tests {
+        use super::*;
+
+        #[test]
+        fn default_allows_both_schemes() {
+            let mode = MovementMode::default();
+            assert!(mode.allows_click());
+            assert!(mode.allows_direct());
+        }
+
+        #[test]
+        fn single_modes_allow_only_their_scheme() {
+            assert!(MovementMode::ClickToMove.allows_click());
+            assert!(!MovementMode::ClickToMove.allows_direct());
+            assert!(MovementMode::Direct.allows_direct());
+            assert!(!MovementMode::Direct.allows_click());
+        }
+
+        #[test]
+        fn next_cycles_through_every_mode() {
+            let start = MovementMode::ClickToMove;
+            assert_eq!(start.next(), MovementMode::Direct);
+            assert_eq!(start.next().next(), MovementMode::Both);
+            assert_eq!(start.next().next().next(), start);
+        }
+    }
+
+    mod movement_intent_tests {
+        use super::*;
+
+        #[test]
+        fn idle_is_not_moving() {
+            assert!(!MovementIntent::Idle.is_moving());
+            assert_eq!(MovementIntent::Idle.direction_from(Vec2::ZERO), Vec2::ZERO);
+        }
+
+        #[test]
+        fn toward_points_at_target_from_position() {
+            let intent = MovementIntent::Toward(Vec2::new(10.0, 0.0));
+            assert!(intent.is_moving());
+            assert_eq!(intent.direction_from(Vec2::new(5.0, 0.0)), Vec2::X);
+        }
+
+        #[test]
+        fn direction_keeps_analog_magnitude_but_caps_at_one() {
+            let half = MovementIntent::Direction(Vec2::new(0.0, 0.5));
+            assert_eq!(half.direction_from(Vec2::ZERO), Vec2::new(0.0, 0.5));
+
+            let over = MovementIntent::Direction(Vec2::new(3.0, 4.0));
+            assert!((over.direction_from(Vec2::ZERO).length() - 1.0).abs() < 0.0001);
+        }
+    }
+}
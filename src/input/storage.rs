@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::input::bindings::{ActionBindings, InputAction, InputMap};
+use crate::input::resources::MovementMode;
+use crate::profile::storage::user_data_dir;
+
+/// Current controls file format version
+pub const CONTROLS_VERSION: u32 = 1;
+
+/// Controls file name inside the data directory
+pub const CONTROLS_FILE_NAME: &str = "controls.ron";
+
+/// Contents of the controls file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlsConfig {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub movement_mode: MovementMode,
+    #[serde(default)]
+    pub bindings: BTreeMap<InputAction, ActionBindings>,
+}
+
+impl ControlsConfig {
+    pub fn new(input_map: &InputMap, movement_mode: MovementMode) -> Self {
+        Self {
+            version: CONTROLS_VERSION,
+            movement_mode,
+            bindings: input_map.to_saved(),
+        }
+    }
+
+    /// Input map with defaults for any action the file doesn't mention,
+    /// except where the file already uses that default elsewhere
+    pub fn input_map(&self) -> InputMap {
+        InputMap::from_saved(self.bindings.clone())
+    }
+}
+
+/// Why a controls file could not be loaded or saved.
+#[derive(Debug)]
+pub enum ControlsError {
+    /// The file exists but could not be read or written
+    Io { path: PathBuf, source: std::io::Error },
+    /// The file is not a valid controls file
+    Corrupt { path: PathBuf, reason: String },
+    /// The file was written by a newer version of the game
+    UnsupportedVersion { path: PathBuf, found: u32 },
+}
+
+impl fmt::Display for ControlsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ControlsError::Io { path, source } => {
+                write!(f, "could not access controls {}: {}", path.display(), source)
+            }
+            ControlsError::Corrupt { path, reason } => {
+                write!(f, "controls {} are corrupt: {}", path.display(), reason)
+            }
+            ControlsError::UnsupportedVersion { path, found } => write!(
+                f,
+                "controls {} have version {} but this build only supports up to version {}",
+                path.display(),
+                found,
+                CONTROLS_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ControlsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ControlsError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Parse controls file contents. Unversioned files are treated as version 1.
+pub fn parse_controls(contents: &str, path: &Path) -> Result<ControlsConfig, ControlsError> {
+    let mut config: ControlsConfig = ron::from_str(contents).map_err(|err| ControlsError::Corrupt {
+        path: path.to_path_buf(),
+        reason: err.to_string(),
+    })?;
+    if config.version > CONTROLS_VERSION {
+        return Err(ControlsError::UnsupportedVersion {
+            path: path.to_path_buf(),
+            found: config.version,
+        });
+    }
+    config.version = CONTROLS_VERSION;
+    Ok(config)
+}
+
+/// Serialize controls for writing to disk.
+pub fn serialize_controls(config: &ControlsConfig) -> String {
+    ron::ser::to_string_pretty(config, ron::ser::PrettyConfig::default())
+        .expect("ControlsConfig always serializes")
+}
+
+/// Where the controls are persisted. Without a path (the default, used by
+/// tests) rebinds only last for the session.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ControlsStorage {
+    pub path: Option<PathBuf>,
+}
+
+impl ControlsStorage {
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+        }
+    }
+
+    /// Controls file next to the profile in the platform data directory
+    pub fn in_user_data_dir() -> Self {
+        Self {
+            path: user_data_dir().map(|dir| dir.join(CONTROLS_FILE_NAME)),
+        }
+    }
+
+    /// Load the controls. `Ok(None)` when there is no file yet.
+    pub fn load(&self) -> Result<Option<ControlsConfig>, ControlsError> {
+        let Some(path) = &self.path else {
+            return Ok(None);
+        };
+        match fs::read_to_string(path) {
+            Ok(contents) => parse_controls(&contents, path).map(Some),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(ControlsError::Io {
+                path: path.clone(),
+                source,
+            }),
+        }
+    }
+
+    /// Save the controls via a temporary file, like the profile.
+    pub fn save(&self, config: &ControlsConfig) -> Result<(), ControlsError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let io_error = |source| ControlsError::Io {
+            path: path.clone(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let tmp_path = path.with_extension("ron.tmp");
+        fs::write(&tmp_path, serialize_controls(config)).map_err(io_error)?;
+        fs::rename(&tmp_path, path).map_err(io_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::bindings::{Binding, BindingSlot};
+
+    fn test_path() -> PathBuf {
+        PathBuf::from("controls.ron")
+    }
+
+    mod parse_controls_tests {
+        use super::*;
+
+        #[test]
+        fn round_trips_through_ron() {
+            let mut map = InputMap::default();
+            map.rebind(InputAction::Pause, BindingSlot::Primary, Binding::Key(KeyCode::KeyP))
+                .unwrap();
+            map.clear(InputAction::MoveUp, BindingSlot::Gamepad);
+            let config = ControlsConfig::new(&map, MovementMode::Direct);
+
+            let parsed = parse_controls(&serialize_controls(&config), &test_path()).unwrap();
+            assert_eq!(parsed, config);
+            assert_eq!(parsed.input_map(), map);
+        }
+
+        #[test]
+        fn partial_file_keeps_other_defaults() {
+            let contents = r#"(bindings: { Inventory: (primary: Some("Tab")) })"#;
+            let parsed = parse_controls(contents, &test_path()).unwrap();
+            let map = parsed.input_map();
+
+            assert_eq!(parsed.version, CONTROLS_VERSION);
+            assert_eq!(parsed.movement_mode, MovementMode::default());
+            assert_eq!(map.bindings(InputAction::Inventory).primary, Some(Binding::Key(KeyCode::Tab)));
+            assert_eq!(map.bindings(InputAction::Inventory).gamepad, None);
+            assert_eq!(map.bindings(InputAction::Pause), InputAction::Pause.default_bindings());
+        }
+
+        #[test]
+        fn older_file_keeps_its_binding_over_a_new_default() {
+            // Written before Reroll existed, with E on the inventory
+            let contents = r#"(version: 1, bindings: { Inventory: (primary: Some("KeyE")) })"#;
+            let map = parse_controls(contents, &test_path()).unwrap().input_map();
+
+            assert_eq!(map.bindings(InputAction::Inventory).primary, Some(Binding::Key(KeyCode::KeyE)));
+            assert_eq!(map.bindings(InputAction::Reroll).primary, None);
+            assert_eq!(map.bindings(InputAction::Reroll).gamepad, InputAction::Reroll.default_bindings().gamepad);
+            assert!(map.conflicts().is_empty());
+        }
+
+        #[test]
+        fn unknown_binding_is_rejected_as_corrupt() {
+            let contents = r#"(bindings: { Pause: (primary: Some("KeyNope")) })"#;
+            let err = parse_controls(contents, &test_path()).unwrap_err();
+            assert!(matches!(err, ControlsError::Corrupt { .. }));
+        }
+
+        #[test]
+        fn newer_version_is_rejected() {
+            let contents = format!("(version: {})", CONTROLS_VERSION + 1);
+            let err = parse_controls(&contents, &test_path()).unwrap_err();
+            assert!(matches!(err, ControlsError::UnsupportedVersion { .. }));
+        }
+    }
+
+    mod controls_storage_tests {
+        use super::*;
+
+        #[test]
+        fn in_memory_storage_loads_nothing_and_saves_nowhere() {
+            let storage = ControlsStorage::default();
+            assert!(storage.load().unwrap().is_none());
+            assert!(storage
+                .save(&ControlsConfig::new(&InputMap::default(), MovementMode::Both))
+                .is_ok());
+        }
+
+        #[test]
+        fn saves_and_loads_from_disk() {
+            let dir = std::env::temp_dir().join(format!("dts-controls-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            let storage = ControlsStorage::at(dir.join(CONTROLS_FILE_NAME));
+            let config = ControlsConfig::new(&InputMap::default(), MovementMode::ClickToMove);
+
+            storage.save(&config).unwrap();
+            assert_eq!(storage.load().unwrap(), Some(config));
+
+            let _ = fs::remove_dir_all(&dir);
+        }
+    }
+}
@@ -0,0 +1,616 @@
+use bevy::prelude::*;
+use bevy::ui::UiGlobalTransform;
+
+use crate::game::resources::FreeCameraState;
+use crate::input::bindings::{ActionInput, InputAction, InputMap};
+use crate::input::resources::{MenuFocus, MovementIntent, MovementMode};
+use crate::input::storage::{ControlsConfig, ControlsStorage};
+use crate::movement::components::from_xz;
+use crate::movement::systems::ray_ground_intersection;
+
+/// Stick deflection below this is treated as centered
+pub const STICK_DEADZONE: f32 = 0.2;
+
+/// Stick deflection needed for one menu navigation step
+const MENU_STICK_THRESHOLD: f32 = 0.6;
+/// Stick must fall back under this before it can step again
+const MENU_STICK_RELEASE: f32 = 0.3;
+
+/// Focus outline drawn around the keyboard/gamepad-selected button
+const FOCUS_OUTLINE_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+
+/// Direction held on the four move actions, screen-space with +Y meaning "up".
+/// Opposite directions cancel; diagonals are normalized.
+pub fn digital_direction(up: bool, down: bool, left: bool, right: bool) -> Vec2 {
+    let axis = |negative: bool, positive: bool| positive as i8 as f32 - negative as i8 as f32;
+    Vec2::new(axis(left, right), axis(down, up)).normalize_or_zero()
+}
+
+/// Direction held on the move actions (WASD, arrows and d-pad by default)
+pub fn move_action_direction(input: &ActionInput) -> Vec2 {
+    digital_direction(
+        input.pressed(InputAction::MoveUp),
+        input.pressed(InputAction::MoveDown),
+        input.pressed(InputAction::MoveLeft),
+        input.pressed(InputAction::MoveRight),
+    )
+}
+
+/// Left stick direction outside the deadzone, keeping its analog magnitude
+/// so a light push walks slower.
+pub fn stick_direction(stick: Vec2) -> Vec2 {
+    if stick.length() >= STICK_DEADZONE {
+        stick.clamp_length_max(1.0)
+    } else {
+        Vec2::ZERO
+    }
+}
+
+/// Maps a screen-space input direction onto the XZ ground plane as seen
+/// from `camera`, so "up" always walks away from the viewer.
+/// Without a camera, up is -Z (the default isometric view).
+pub fn camera_relative_direction(input: Vec2, camera: Option<&GlobalTransform>) -> Vec2 {
+    let Some(camera) = camera else {
+        return Vec2::new(input.x, -input.y);
+    };
+
+    let right = from_xz(*camera.right()).normalize_or_zero();
+    // forward + up stays horizontal-ish for any downward pitch, even top-down
+    let up = from_xz(*camera.forward() + *camera.up()).normalize_or_zero();
+    right * input.x + up * input.y
+}
+
+/// Ground point under the cursor, if the cursor is over the window.
+fn cursor_ground_point(
+    windows: &Query<&Window>,
+    camera: Option<(&Camera, &GlobalTransform)>,
+) -> Option<Vec2> {
+    let (camera, camera_transform) = camera?;
+    let cursor_position = windows.single().ok()?.cursor_position()?;
+    let ray = camera.viewport_to_world(camera_transform, cursor_position).ok()?;
+    ray_ground_intersection(ray)
+}
+
+/// System that turns the move actions, the left stick and click-to-move into
+/// a `MovementIntent`. Direct input takes priority over click-to-move when the
+/// mode allows both. Move bindings are ignored while free-look owns them.
+pub fn read_movement_intent(
+    mode: Res<MovementMode>,
+    input: ActionInput,
+    free_camera: Option<Res<FreeCameraState>>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut intent: ResMut<MovementIntent>,
+) {
+    let camera = camera_query.single().ok();
+    let mut next = MovementIntent::Idle;
+
+    if mode.allows_direct() {
+        let free_look = free_camera.is_some_and(|state| state.active);
+        let mut direction = if free_look {
+            Vec2::ZERO
+        } else {
+            move_action_direction(&input)
+        };
+        if direction == Vec2::ZERO {
+            direction = input
+                .gamepads()
+                .map(|gamepad| stick_direction(gamepad.left_stick()))
+                .find(|stick| *stick != Vec2::ZERO)
+                .unwrap_or(Vec2::ZERO);
+        }
+        if direction != Vec2::ZERO {
+            let transform = camera.map(|(_, transform)| transform);
+            next = MovementIntent::Direction(camera_relative_direction(direction, transform));
+        }
+    }
+
+    if !next.is_moving() && mode.allows_click() && input.pressed(InputAction::MoveToCursor) {
+        if let Some(target) = cursor_ground_point(&windows, camera) {
+            next = MovementIntent::Toward(target);
+        }
+    }
+
+    intent.set_if_neq(next);
+}
+
+/// Stops the player when gameplay is left (pause, menus, game over).
+pub fn clear_movement_intent(mut intent: ResMut<MovementIntent>) {
+    intent.set_if_neq(MovementIntent::Idle);
+}
+
+/// Index of the button to focus after stepping in `direction` (UI space, +Y down).
+/// Picks the closest button on that side, weighting sideways offset double;
+/// wraps around in reading order when nothing lies that way.
+pub fn next_focus_index(current: Option<usize>, positions: &[Vec2], direction: Vec2) -> Option<usize> {
+    if positions.is_empty() {
+        return None;
+    }
+    let Some(current) = current.filter(|index| *index < positions.len()) else {
+        return Some(0);
+    };
+
+    let origin = positions[current];
+    let across = direction.perp();
+    let closest = positions
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != current)
+        .filter_map(|(index, position)| {
+            let offset = *position - origin;
+            let along = offset.dot(direction);
+            (along > 0.5).then(|| (index, along + offset.dot(across).abs() * 2.0))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(index, _)| index);
+
+    closest.or_else(|| {
+        let forward = direction.x + direction.y > 0.0;
+        Some(if forward {
+            (current + 1) % positions.len()
+        } else {
+            (current + positions.len() - 1) % positions.len()
+        })
+    })
+}
+
+/// One menu navigation step from the move actions, arrow keys or stick,
+/// in UI space (+Y down). Arrows always work so menus survive any rebind.
+fn menu_step(input: &ActionInput, stick_held: &mut bool) -> Option<Vec2> {
+    const STEPS: [(InputAction, KeyCode, Vec2); 4] = [
+        (InputAction::MoveUp, KeyCode::ArrowUp, Vec2::NEG_Y),
+        (InputAction::MoveDown, KeyCode::ArrowDown, Vec2::Y),
+        (InputAction::MoveLeft, KeyCode::ArrowLeft, Vec2::NEG_X),
+        (InputAction::MoveRight, KeyCode::ArrowRight, Vec2::X),
+    ];
+
+    for (action, arrow, step) in STEPS {
+        if input.just_pressed(action) || input.keyboard().just_pressed(arrow) {
+            return Some(step);
+        }
+    }
+
+    let stick = input
+        .gamepads()
+        .map(|gamepad| gamepad.left_stick())
+        .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+        .unwrap_or(Vec2::ZERO);
+    if stick.length() < MENU_STICK_RELEASE {
+        *stick_held = false;
+        return None;
+    }
+    if *stick_held || stick.length() < MENU_STICK_THRESHOLD {
+        return None;
+    }
+    *stick_held = true;
+    // Stick +Y is up, UI +Y is down
+    Some(if stick.x.abs() > stick.y.abs() {
+        Vec2::new(stick.x.signum(), 0.0)
+    } else {
+        Vec2::new(0.0, -stick.y.signum())
+    })
+}
+
+/// System that moves keyboard/gamepad focus between menu buttons and
+/// presses the focused one on Enter, Space or the gamepad's South button.
+/// A press sets `Interaction::Pressed` for one frame, so the menus'
+/// `Changed<Interaction>` handlers work unchanged.
+pub fn navigate_menus(
+    mut commands: Commands,
+    input: ActionInput,
+    mut focus: ResMut<MenuFocus>,
+    mut buttons: Query<(Entity, &mut Interaction, Option<&UiGlobalTransform>), With<Button>>,
+) {
+    // Release last frame's press before anything else
+    if let Some(pressed) = focus.pressed.take() {
+        if let Ok((_, mut interaction, _)) = buttons.get_mut(pressed) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    if focus.focused.is_some_and(|entity| !buttons.contains(entity)) {
+        focus.focused = None;
+    }
+    if focus.locked {
+        return;
+    }
+
+    let mut entries: Vec<(Entity, Vec2)> = buttons
+        .iter()
+        .map(|(entity, _, transform)| (entity, transform.map(|t| t.translation).unwrap_or_default()))
+        .collect();
+    entries.sort_by(|a, b| {
+        a.1.y
+            .total_cmp(&b.1.y)
+            .then(a.1.x.total_cmp(&b.1.x))
+            .then(a.0.index().cmp(&b.0.index()))
+    });
+
+    let step = menu_step(&input, &mut focus.stick_held);
+    let confirm = input
+        .keyboard()
+        .any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space])
+        || input.gamepads().any(|gamepad| gamepad.just_pressed(GamepadButton::South));
+
+    let current = focus
+        .focused
+        .and_then(|focused| entries.iter().position(|(entity, _)| *entity == focused));
+    let target = match (step, current) {
+        (Some(direction), _) => {
+            let positions: Vec<Vec2> = entries.iter().map(|(_, position)| *position).collect();
+            next_focus_index(current, &positions, direction)
+        }
+        // Confirm with nothing focused only selects the first button
+        (None, None) if confirm => (!entries.is_empty()).then_some(0),
+        _ => current,
+    };
+    let target = target.map(|index| entries[index].0);
+
+    if target != focus.focused {
+        if let Some(previous) = focus.focused {
+            commands.entity(previous).remove::<Outline>();
+        }
+        if let Some(entity) = target {
+            commands
+                .entity(entity)
+                .insert(Outline::new(Val::Px(3.0), Val::Px(2.0), FOCUS_OUTLINE_COLOR));
+        }
+        focus.focused = target;
+        return;
+    }
+
+    if confirm {
+        if let Some(entity) = target {
+            if let Ok((_, mut interaction, _)) = buttons.get_mut(entity) {
+                *interaction = Interaction::Pressed;
+                focus.pressed = Some(entity);
+            }
+        }
+    }
+}
+
+/// Load saved controls at startup. An unreadable file is reported and the
+/// defaults are used; it is overwritten by the next rebind.
+pub fn load_controls_system(
+    storage: Res<ControlsStorage>,
+    mut input_map: ResMut<InputMap>,
+    mut movement_mode: ResMut<MovementMode>,
+) {
+    match storage.load() {
+        Ok(Some(config)) => {
+            *input_map = config.input_map();
+            *movement_mode = config.movement_mode;
+            for conflict in input_map.conflicts() {
+                warn!("Controls conflict: {} (also wanted by {})", conflict, conflict.requested.label());
+            }
+        }
+        Ok(None) => {}
+        Err(err) => error!("{}; using default controls", err),
+    }
+}
+
+/// Save the controls whenever a binding or the movement mode changes.
+pub fn save_controls_system(
+    storage: Res<ControlsStorage>,
+    input_map: Res<InputMap>,
+    movement_mode: Res<MovementMode>,
+) {
+    // The startup load counts as a change; only save real edits
+    if input_map.is_added() || !(input_map.is_changed() || movement_mode.is_changed()) {
+        return;
+    }
+    if let Err(err) = storage.save(&ControlsConfig::new(&input_map, *movement_mode)) {
+        error!("Failed to save controls: {}", err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::bindings::{Binding, BindingSlot};
+    use bevy::app::App;
+
+    mod digital_direction_tests {
+        use super::*;
+
+        #[test]
+        fn single_directions_map_to_screen_axes() {
+            assert_eq!(digital_direction(true, false, false, false), Vec2::Y);
+            assert_eq!(digital_direction(false, false, true, false), Vec2::NEG_X);
+        }
+
+        #[test]
+        fn opposite_directions_cancel_and_diagonals_normalize() {
+            assert_eq!(digital_direction(false, false, true, true), Vec2::ZERO);
+            assert!((digital_direction(true, false, false, true).length() - 1.0).abs() < 0.0001);
+        }
+    }
+
+    mod stick_direction_tests {
+        use super::*;
+
+        #[test]
+        fn stick_inside_deadzone_is_centered() {
+            assert_eq!(stick_direction(Vec2::new(0.1, 0.0)), Vec2::ZERO);
+        }
+
+        #[test]
+        fn stick_keeps_analog_magnitude() {
+            assert_eq!(stick_direction(Vec2::new(0.5, 0.0)), Vec2::new(0.5, 0.0));
+        }
+    }
+
+    mod camera_relative_direction_tests {
+        use super::*;
+
+        fn isometric_camera() -> GlobalTransform {
+            GlobalTransform::from(Transform::from_xyz(0.0, 20.0, 15.0).looking_at(Vec3::ZERO, Vec3::Y))
+        }
+
+        #[test]
+        fn up_walks_away_from_the_camera() {
+            let direction = camera_relative_direction(Vec2::Y, Some(&isometric_camera()));
+            assert!((direction - Vec2::new(0.0, -1.0)).length() < 0.0001);
+        }
+
+        #[test]
+        fn right_follows_camera_right() {
+            let direction = camera_relative_direction(Vec2::X, Some(&isometric_camera()));
+            assert!((direction - Vec2::X).length() < 0.0001);
+        }
+
+        #[test]
+        fn rotated_camera_rotates_input() {
+            // Camera on the +X side looking back at the origin
+            let camera = GlobalTransform::from(Transform::from_xyz(15.0, 20.0, 0.0).looking_at(Vec3::ZERO, Vec3::Y));
+            let direction = camera_relative_direction(Vec2::Y, Some(&camera));
+            assert!((direction - Vec2::NEG_X).length() < 0.0001);
+        }
+
+        #[test]
+        fn no_camera_treats_up_as_negative_z() {
+            assert_eq!(camera_relative_direction(Vec2::Y, None), Vec2::new(0.0, -1.0));
+        }
+    }
+
+    mod read_movement_intent_tests {
+        use super::*;
+
+        fn setup_test_app(mode: MovementMode) -> App {
+            let mut app = App::new();
+            app.init_resource::<ButtonInput<KeyCode>>();
+            app.init_resource::<ButtonInput<MouseButton>>();
+            app.init_resource::<InputMap>();
+            app.init_resource::<FreeCameraState>();
+            app.init_resource::<MovementIntent>();
+            app.insert_resource(mode);
+            app.add_systems(Update, read_movement_intent);
+            app
+        }
+
+        #[test]
+        fn keyboard_sets_direction_intent() {
+            let mut app = setup_test_app(MovementMode::Both);
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyD);
+
+            app.update();
+
+            assert_eq!(*app.world().resource::<MovementIntent>(), MovementIntent::Direction(Vec2::X));
+        }
+
+        #[test]
+        fn rebound_key_drives_movement() {
+            let mut app = setup_test_app(MovementMode::Both);
+            app.world_mut()
+                .resource_mut::<InputMap>()
+                .rebind(InputAction::MoveRight, BindingSlot::Primary, Binding::Key(KeyCode::KeyL))
+                .unwrap();
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyL);
+
+            app.update();
+
+            assert_eq!(*app.world().resource::<MovementIntent>(), MovementIntent::Direction(Vec2::X));
+        }
+
+        #[test]
+        fn keyboard_ignored_in_click_to_move_mode() {
+            let mut app = setup_test_app(MovementMode::ClickToMove);
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyD);
+
+            app.update();
+
+            assert_eq!(*app.world().resource::<MovementIntent>(), MovementIntent::Idle);
+        }
+
+        #[test]
+        fn keyboard_ignored_during_free_look() {
+            let mut app = setup_test_app(MovementMode::Direct);
+            app.world_mut().resource_mut::<FreeCameraState>().active = true;
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyW);
+
+            app.update();
+
+            assert_eq!(*app.world().resource::<MovementIntent>(), MovementIntent::Idle);
+        }
+
+        #[test]
+        fn releasing_keys_returns_to_idle() {
+            let mut app = setup_test_app(MovementMode::Both);
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyS);
+            app.update();
+            assert!(app.world().resource::<MovementIntent>().is_moving());
+
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().release(KeyCode::KeyS);
+            app.update();
+
+            assert_eq!(*app.world().resource::<MovementIntent>(), MovementIntent::Idle);
+        }
+    }
+
+    mod next_focus_index_tests {
+        use super::*;
+
+        #[test]
+        fn first_step_focuses_first_button() {
+            let positions = [Vec2::ZERO, Vec2::new(0.0, 50.0)];
+            assert_eq!(next_focus_index(None, &positions, Vec2::Y), Some(0));
+            assert_eq!(next_focus_index(None, &[], Vec2::Y), None);
+        }
+
+        #[test]
+        fn steps_to_closest_button_in_direction() {
+            // Column of three with a button to the right of the middle one
+            let positions = [
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.0, 50.0),
+                Vec2::new(0.0, 100.0),
+                Vec2::new(200.0, 50.0),
+            ];
+            assert_eq!(next_focus_index(Some(0), &positions, Vec2::Y), Some(1));
+            assert_eq!(next_focus_index(Some(1), &positions, Vec2::Y), Some(2));
+            assert_eq!(next_focus_index(Some(1), &positions, Vec2::X), Some(3));
+            assert_eq!(next_focus_index(Some(2), &positions, Vec2::NEG_Y), Some(1));
+        }
+
+        #[test]
+        fn wraps_in_reading_order_at_the_edges() {
+            let positions = [Vec2::new(0.0, 0.0), Vec2::new(0.0, 50.0)];
+            assert_eq!(next_focus_index(Some(1), &positions, Vec2::Y), Some(0));
+            assert_eq!(next_focus_index(Some(0), &positions, Vec2::NEG_Y), Some(1));
+        }
+
+        #[test]
+        fn unlaid_out_buttons_step_in_order() {
+            // Before layout every button sits at the origin
+            let positions = [Vec2::ZERO; 3];
+            assert_eq!(next_focus_index(Some(0), &positions, Vec2::Y), Some(1));
+            assert_eq!(next_focus_index(Some(0), &positions, Vec2::NEG_Y), Some(2));
+        }
+    }
+
+    mod navigate_menus_tests {
+        use super::*;
+
+        fn setup_test_app() -> App {
+            let mut app = App::new();
+            app.init_resource::<ButtonInput<KeyCode>>();
+            app.init_resource::<InputMap>();
+            app.init_resource::<MenuFocus>();
+            app.add_systems(Update, navigate_menus);
+            app
+        }
+
+        fn tap(app: &mut App, key: KeyCode) {
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(key);
+            app.update();
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(key);
+            keyboard.clear();
+        }
+
+        #[test]
+        fn arrow_keys_move_focus_and_outline() {
+            let mut app = setup_test_app();
+            let first = app.world_mut().spawn(Button).id();
+            let second = app.world_mut().spawn(Button).id();
+
+            tap(&mut app, KeyCode::ArrowDown);
+            assert_eq!(app.world().resource::<MenuFocus>().focused, Some(first));
+            assert!(app.world().get::<Outline>(first).is_some());
+
+            tap(&mut app, KeyCode::ArrowDown);
+            assert_eq!(app.world().resource::<MenuFocus>().focused, Some(second));
+            assert!(app.world().get::<Outline>(first).is_none());
+            assert!(app.world().get::<Outline>(second).is_some());
+        }
+
+        #[test]
+        fn confirm_presses_focused_button_for_one_frame() {
+            let mut app = setup_test_app();
+            let button = app.world_mut().spawn(Button).id();
+
+            tap(&mut app, KeyCode::ArrowDown);
+            tap(&mut app, KeyCode::Enter);
+            assert_eq!(app.world().get::<Interaction>(button), Some(&Interaction::Pressed));
+
+            app.update();
+            assert_eq!(app.world().get::<Interaction>(button), Some(&Interaction::None));
+        }
+
+        #[test]
+        fn locked_focus_ignores_navigation() {
+            let mut app = setup_test_app();
+            app.world_mut().spawn(Button);
+            app.world_mut().resource_mut::<MenuFocus>().locked = true;
+
+            tap(&mut app, KeyCode::ArrowDown);
+
+            assert_eq!(app.world().resource::<MenuFocus>().focused, None);
+        }
+
+        #[test]
+        fn despawned_focus_is_forgotten() {
+            let mut app = setup_test_app();
+            let button = app.world_mut().spawn(Button).id();
+
+            tap(&mut app, KeyCode::ArrowDown);
+            app.world_mut().despawn(button);
+            app.update();
+
+            assert_eq!(app.world().resource::<MenuFocus>().focused, None);
+        }
+    }
+
+    mod controls_persistence_tests {
+        use super::*;
+        use crate::input::storage::CONTROLS_FILE_NAME;
+        use std::fs;
+
+        fn setup_test_app(name: &str) -> (App, std::path::PathBuf) {
+            let dir = std::env::temp_dir().join(format!("dts-controls-sys-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            let mut app = App::new();
+            app.insert_resource(ControlsStorage::at(dir.join(CONTROLS_FILE_NAME)));
+            app.init_resource::<InputMap>();
+            app.init_resource::<MovementMode>();
+            app.add_systems(Startup, load_controls_system);
+            app.add_systems(Update, save_controls_system);
+            (app, dir)
+        }
+
+        #[test]
+        fn startup_does_not_write_a_file() {
+            let (mut app, dir) = setup_test_app("startup");
+            app.update();
+            assert!(!dir.join(CONTROLS_FILE_NAME).exists());
+        }
+
+        #[test]
+        fn rebinds_are_saved_and_reloaded() {
+            let (mut app, dir) = setup_test_app("reload");
+            app.update();
+            app.world_mut()
+                .resource_mut::<InputMap>()
+                .rebind(InputAction::Pause, BindingSlot::Primary, Binding::Key(KeyCode::KeyP))
+                .unwrap();
+            *app.world_mut().resource_mut::<MovementMode>() = MovementMode::Direct;
+            app.update();
+
+            let mut reloaded = App::new();
+            reloaded.insert_resource(ControlsStorage::at(dir.join(CONTROLS_FILE_NAME)));
+            reloaded.init_resource::<InputMap>();
+            reloaded.init_resource::<MovementMode>();
+            reloaded.add_systems(Startup, load_controls_system);
+            reloaded.update();
+
+            assert_eq!(
+                reloaded.world().resource::<InputMap>().bindings(InputAction::Pause).primary,
+                Some(Binding::Key(KeyCode::KeyP))
+            );
+            assert_eq!(*reloaded.world().resource::<MovementMode>(), MovementMode::Direct);
+            let _ = fs::remove_dir_all(&dir);
+        }
+    }
+}
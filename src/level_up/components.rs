@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+
+use crate::element::Element;
+use crate::game::components::Rarity;
+use crate::powerup::components::PowerupType;
+use crate::spell::definitions::SpellCatalog;
+use crate::spell::SpellType;
+
+/// A reward offered on the level-up choice screen.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LevelUpReward {
+    /// Learn a spell the player doesn't own yet
+    NewSpell(SpellType),
+    /// +1 level on the spell equipped in `slot`
+    SpellLevel { slot: usize, spell_type: SpellType },
+    /// One more stack of a permanent powerup
+    Powerup(PowerupType),
+}
+
+impl LevelUpReward {
+    /// Heading shown on the choice card, naming spells from `catalog`
+    pub fn title(&self, catalog: &SpellCatalog) -> String {
+        match self {
+            LevelUpReward::NewSpell(spell_type) => format!("New Spell: {}", spell_type.name(catalog)),
+            LevelUpReward::SpellLevel { spell_type, .. } => format!("{} +1", spell_type.name(catalog)),
+            LevelUpReward::Powerup(powerup_type) => powerup_type.display_name().to_string(),
+        }
+    }
+
+    /// Body text shown on the choice card
+    pub fn description(&self, catalog: &SpellCatalog) -> String {
+        match self {
+            LevelUpReward::NewSpell(spell_type) => spell_type.description(catalog).to_string(),
+            LevelUpReward::SpellLevel { .. } => {
+                "Raise this spell's level: more damage and faster casts".to_string()
+            }
+            LevelUpReward::Powerup(_) => "Permanent +25% stack".to_string(),
+        }
+    }
+
+    /// Element of the spell involved, if any
+    pub fn element(&self) -> Option<Element> {
+        match self {
+            LevelUpReward::NewSpell(spell_type) | LevelUpReward::SpellLevel { spell_type, .. } => {
+                Some(spell_type.element())
+            }
+            LevelUpReward::Powerup(_) => None,
+        }
+    }
+}
+
+/// A rolled reward together with the rarity it was weighted by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelUpChoice {
+    pub reward: LevelUpReward,
+    pub rarity: Rarity,
+}
+
+/// Root marker for the level-up choice screen.
+/// Used for cleanup on state exit.
+#[derive(Component)]
+pub struct LevelUpChoiceScreen;
+
+/// Button for one of the offered choices (index into `LevelUpChoices`).
+#[derive(Component)]
+pub struct LevelUpChoiceButton {
+    pub index: usize,
+}
+
+/// Button that spends a reroll token to roll new choices.
+#[derive(Component)]
+pub struct LevelUpRerollButton;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reward_titles_name_the_spell_or_powerup() {
+        let catalog = SpellCatalog::built_in();
+        assert_eq!(
+            LevelUpReward::NewSpell(SpellType::Fireball).title(catalog),
+            format!("New Spell: {}", SpellType::Fireball.name(catalog))
+        );
+        assert_eq!(
+            LevelUpReward::SpellLevel { slot: 0, spell_type: SpellType::IceShard }.title(catalog),
+            format!("{} +1", SpellType::IceShard.name(catalog))
+        );
+        assert_eq!(
+            LevelUpReward::Powerup(PowerupType::MaxHealth).title(catalog),
+            PowerupType::MaxHealth.display_name()
+        );
+    }
+
+    #[test]
+    fn only_spell_rewards_have_an_element() {
+        assert_eq!(LevelUpReward::NewSpell(SpellType::Fireball).element(), Some(Element::Fire));
+        assert_eq!(LevelUpReward::Powerup(PowerupType::HealthRegen).element(), None);
+    }
+}
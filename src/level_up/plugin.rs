@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+use crate::boss::systems::complete_level_on_boss_defeat;
+use crate::experience::components::PlayerLevelUpEvent;
+use crate::game::resources::GameRng;
+use crate::game::sets::GameSet;
+use crate::level_up::resources::*;
+use crate::level_up::systems::*;
+use crate::states::GameState;
+
+/// Level-up plugin: every player level gained pauses the game on the
+/// `LevelUpChoice` screen (set up by the UI plugin) until a reward is picked.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<PendingLevelUps>()
+        .init_resource::<LevelUpChoices>()
+        .init_resource::<RerollTokens>()
+        .init_resource::<GameRng>()
+        .add_message::<PlayerLevelUpEvent>()
+        // Boss defeat runs last so LevelComplete wins; the choice opens after it
+        .add_systems(
+            Update,
+            (queue_level_up_choices, open_level_up_choice)
+                .chain()
+                .in_set(GameSet::Effects)
+                .before(complete_level_on_boss_defeat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(OnEnter(GameState::LevelUpChoice), prepare_level_up_choices)
+        .add_systems(OnEnter(GameState::Intro), reset_pending_level_ups)
+        .add_systems(OnEnter(GameState::GameOver), reset_pending_level_ups);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_registers_level_up_resources() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().get_resource::<PendingLevelUps>().is_some());
+        assert!(app.world().get_resource::<LevelUpChoices>().is_some());
+        assert!(app.world().get_resource::<RerollTokens>().is_some());
+        assert!(app.world().get_resource::<Messages<PlayerLevelUpEvent>>().is_some());
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::level_up::components::LevelUpChoice;
+
+/// Player levels gained that still need a reward choice.
+/// Each choice on the level-up screen consumes one.
+#[derive(Resource, Default, Debug)]
+pub struct PendingLevelUps(pub u32);
+
+/// The choices currently offered on the level-up screen.
+/// Rolled when entering `GameState::LevelUpChoice`.
+#[derive(Resource, Default, Debug)]
+pub struct LevelUpChoices {
+    pub choices: Vec<LevelUpChoice>,
+}
+
+/// Level-up rerolls left this run (from the `RerollToken` meta upgrade).
+#[derive(Resource, Default, Debug)]
+pub struct RerollTokens(pub u32);
@@ -0,0 +1,502 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::element::Element;
+use crate::experience::components::PlayerLevelUpEvent;
+use crate::game::components::Rarity;
+use crate::game::resources::GameRng;
+use crate::inventory::bag::InventoryBag;
+use crate::inventory::resources::SpellList;
+use crate::level_up::components::*;
+use crate::level_up::resources::*;
+use crate::powerup::components::{ActivePowerups, PowerupType};
+use crate::spell::{Spell, SpellType};
+use crate::spell::definitions::SpellCatalog;
+use crate::states::GameState;
+use crate::whisper::resources::WhisperAttunement;
+
+/// Number of rewards offered per level-up
+pub const LEVEL_UP_CHOICE_COUNT: usize = 3;
+
+/// Weight multiplier for spell rewards matching the player's attunement
+pub const ATTUNED_CHOICE_WEIGHT_MULTIPLIER: f32 = 3.0;
+
+/// Permanent powerups that can be offered as level-up rewards
+pub const LEVEL_UP_POWERUPS: [PowerupType; 3] = [
+    PowerupType::MaxHealth,
+    PowerupType::HealthRegen,
+    PowerupType::PickupRadius,
+];
+
+/// Relative chance of a reward being offered, by rarity
+pub fn rarity_weight(rarity: Rarity) -> f32 {
+    match rarity {
+        Rarity::Common => 100.0,
+        Rarity::Uncommon => 60.0,
+        Rarity::Rare => 30.0,
+        Rarity::Epic => 12.0,
+        Rarity::Legendary => 4.0,
+    }
+}
+
+/// Rarity of leveling a spell to `next_level` (1-10): every two levels is one tier.
+pub fn spell_level_rarity(next_level: u32) -> Rarity {
+    Rarity::from_level((next_level / 2).clamp(1, 5) as u8)
+}
+
+fn powerup_rarity(powerup_type: &PowerupType) -> Rarity {
+    match powerup_type {
+        PowerupType::MaxHealth => Rarity::Uncommon,
+        _ => Rarity::Common,
+    }
+}
+
+fn attunement_multiplier(element: Option<Element>, attuned: Option<Element>) -> f32 {
+    match (element, attuned) {
+        (Some(element), Some(attuned)) if element == attuned => ATTUNED_CHOICE_WEIGHT_MULTIPLIER,
+        _ => 1.0,
+    }
+}
+
+/// Candidate rewards with their weights, one pool per reward category:
+/// new spells, spell level-ups and powerup stacks.
+pub fn level_up_candidate_pools(
+    spell_list: &SpellList,
+    inventory_bag: Option<&InventoryBag>,
+    attuned: Option<Element>,
+) -> [Vec<(LevelUpChoice, f32)>; 3] {
+    let weighted = |reward: LevelUpReward, rarity: Rarity| {
+        let weight = rarity_weight(rarity) * attunement_multiplier(reward.element(), attuned);
+        (LevelUpChoice { reward, rarity }, weight)
+    };
+
+    // New spells need somewhere to go, otherwise the pick would be lost
+    let has_room = spell_list.find_empty_slot().is_some()
+        || inventory_bag.is_some_and(|bag| bag.find_empty_slot().is_some());
+    let new_spells = if has_room {
+        SpellType::all()
+            .iter()
+            .filter(|spell_type| !spell_list.has_spell(spell_type))
+            .filter(|spell_type| inventory_bag.is_none_or(|bag| bag.find_spell(spell_type).is_none()))
+            .map(|spell_type| weighted(LevelUpReward::NewSpell(*spell_type), spell_type.rarity()))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let spell_levels = spell_list
+        .iter_spells()
+        .filter(|(_, spell)| spell.can_level_up())
+        .map(|(slot, spell)| {
+            weighted(
+                LevelUpReward::SpellLevel { slot, spell_type: spell.spell_type },
+                spell_level_rarity(spell.level + 1),
+            )
+        })
+        .collect();
+
+    let powerups = LEVEL_UP_POWERUPS
+        .iter()
+        .map(|powerup_type| {
+            weighted(LevelUpReward::Powerup(powerup_type.clone()), powerup_rarity(powerup_type))
+        })
+        .collect();
+
+    [new_spells, spell_levels, powerups]
+}
+
+/// Roll the rewards offered for one level-up.
+/// Each choice first picks a category (evenly, among non-empty ones) and then a
+/// reward within it weighted by rarity and attunement. Rewards are never repeated.
+pub fn roll_level_up_choices(
+    spell_list: &SpellList,
+    inventory_bag: Option<&InventoryBag>,
+    attuned: Option<Element>,
+    rng: &mut impl Rng,
+) -> Vec<LevelUpChoice> {
+    let mut pools = level_up_candidate_pools(spell_list, inventory_bag, attuned);
+    let mut choices = Vec::with_capacity(LEVEL_UP_CHOICE_COUNT);
+
+    while choices.len() < LEVEL_UP_CHOICE_COUNT {
+        let available: Vec<usize> = (0..pools.len()).filter(|i| !pools[*i].is_empty()).collect();
+        if available.is_empty() {
+            break;
+        }
+        let pool = &mut pools[available[rng.gen_range(0..available.len())]];
+
+        let total: f32 = pool.iter().map(|(_, weight)| weight).sum();
+        let roll = rng.gen_range(0.0..total);
+        let mut cumulative = 0.0;
+        let mut picked = pool.len() - 1;
+        for (i, (_, weight)) in pool.iter().enumerate() {
+            cumulative += weight;
+            if roll < cumulative {
+                picked = i;
+                break;
+            }
+        }
+        choices.push(pool.swap_remove(picked).0);
+    }
+
+    choices
+}
+
+/// Apply a chosen reward. Returns false if it couldn't be applied
+/// (e.g. the spell slot was emptied in the meantime).
+pub fn apply_level_up_reward(
+    reward: &LevelUpReward,
+    spell_list: &mut SpellList,
+    inventory_bag: Option<&mut InventoryBag>,
+    active_powerups: &mut ActivePowerups,
+    spell_catalog: &SpellCatalog,
+) -> bool {
+    match reward {
+        LevelUpReward::NewSpell(spell_type) => {
+            let spell = Spell::from_catalog(*spell_type, spell_catalog);
+            spell_list.equip(spell.clone()).is_some()
+                || inventory_bag.is_some_and(|bag| bag.add(spell).is_some())
+        }
+        LevelUpReward::SpellLevel { slot, spell_type } => {
+            let slot = match spell_list.get_spell(*slot) {
+                Some(spell) if spell.spell_type == *spell_type => Some(*slot),
+                _ => spell_list.find_spell_slot(spell_type),
+            };
+            match slot.and_then(|slot| spell_list.get_spell_mut(slot)) {
+                Some(spell) if spell.can_level_up() => {
+                    spell.level_up();
+                    true
+                }
+                _ => false,
+            }
+        }
+        LevelUpReward::Powerup(powerup_type) => {
+            active_powerups.add_powerup(powerup_type.clone());
+            true
+        }
+    }
+}
+
+/// Queue a reward choice for every player level gained.
+pub fn queue_level_up_choices(
+    mut level_up_events: MessageReader<PlayerLevelUpEvent>,
+    mut pending: ResMut<PendingLevelUps>,
+) {
+    for event in level_up_events.read() {
+        pending.0 += event.levels_gained;
+    }
+}
+
+/// Pause into the choice screen while level-ups are pending.
+/// Never overrides a transition another system already requested
+/// (e.g. attunement selection); the choice opens once play resumes.
+pub fn open_level_up_choice(
+    pending: Res<PendingLevelUps>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if pending.0 > 0 && matches!(*next_state, NextState::Unchanged) {
+        next_state.set(GameState::LevelUpChoice);
+    }
+}
+
+/// Roll the rewards shown on the choice screen.
+pub fn prepare_level_up_choices(
+    mut choices: ResMut<LevelUpChoices>,
+    spell_list: Option<Res<SpellList>>,
+    inventory_bag: Option<Res<InventoryBag>>,
+    attunement: Option<Res<WhisperAttunement>>,
+    mut rng: ResMut<GameRng>,
+) {
+    let empty_list = SpellList::default();
+    let spell_list = spell_list.as_deref().unwrap_or(&empty_list);
+    let attuned = attunement.and_then(|attunement| attunement.element());
+
+    choices.choices = roll_level_up_choices(
+        spell_list,
+        inventory_bag.as_deref(),
+        attuned,
+        &mut *rng,
+    );
+}
+
+/// Drop pending level-ups when a run ends.
+pub fn reset_pending_level_ups(
+    mut pending: ResMut<PendingLevelUps>,
+    mut choices: ResMut<LevelUpChoices>,
+) {
+    pending.0 = 0;
+    choices.choices.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+
+    fn spell_list_with(spells: &[SpellType]) -> SpellList {
+        let mut spell_list = SpellList::default();
+        for spell_type in spells {
+            spell_list.equip(Spell::new(*spell_type));
+        }
+        spell_list
+    }
+
+    mod candidate_pool_tests {
+        use super::*;
+
+        #[test]
+        fn owned_spells_are_not_offered_as_new() {
+            let spell_list = spell_list_with(&[SpellType::Fireball]);
+            let mut bag = InventoryBag::default();
+            bag.add(Spell::new(SpellType::IceShard));
+
+            let [new_spells, spell_levels, powerups] =
+                level_up_candidate_pools(&spell_list, Some(&bag), None);
+
+            assert_eq!(new_spells.len(), 62);
+            assert!(!new_spells.iter().any(|(choice, _)| {
+                choice.reward == LevelUpReward::NewSpell(SpellType::Fireball)
+                    || choice.reward == LevelUpReward::NewSpell(SpellType::IceShard)
+            }));
+            assert_eq!(spell_levels.len(), 1);
+            assert_eq!(powerups.len(), LEVEL_UP_POWERUPS.len());
+        }
+
+        #[test]
+        fn no_new_spells_when_slots_and_bag_are_full() {
+            let spell_list = spell_list_with(&[
+                SpellType::Fireball,
+                SpellType::IceShard,
+                SpellType::Spark,
+                SpellType::VenomBolt,
+                SpellType::ShadowBolt,
+            ]);
+
+            let [new_spells, spell_levels, _] = level_up_candidate_pools(&spell_list, None, None);
+
+            assert!(new_spells.is_empty());
+            assert_eq!(spell_levels.len(), 5);
+        }
+
+        #[test]
+        fn max_level_spells_are_not_offered() {
+            let mut spell_list = spell_list_with(&[SpellType::Fireball]);
+            spell_list.get_spell_mut(0).unwrap().level = 10;
+
+            let [_, spell_levels, _] = level_up_candidate_pools(&spell_list, None, None);
+
+            assert!(spell_levels.is_empty());
+        }
+
+        #[test]
+        fn attuned_element_is_weighted_up_and_rarity_down() {
+            let spell_list = SpellList::default();
+            let [new_spells, _, _] =
+                level_up_candidate_pools(&spell_list, None, Some(Element::Fire));
+
+            let weight_of = |spell_type: SpellType| {
+                new_spells
+                    .iter()
+                    .find(|(choice, _)| choice.reward == LevelUpReward::NewSpell(spell_type))
+                    .map(|(_, weight)| *weight)
+                    .unwrap()
+            };
+
+            // Same rarity tier, attuned element is three times as likely
+            assert_eq!(weight_of(SpellType::Fireball), 3.0 * weight_of(SpellType::IceShard));
+            // Legendary spells are rarer than common ones of the same element
+            assert!(weight_of(SpellType::Hellfire) < weight_of(SpellType::Fireball));
+        }
+
+        #[test]
+        fn spell_level_rarity_rises_every_two_levels() {
+            assert_eq!(spell_level_rarity(2), Rarity::Common);
+            assert_eq!(spell_level_rarity(4), Rarity::Uncommon);
+            assert_eq!(spell_level_rarity(7), Rarity::Rare);
+            assert_eq!(spell_level_rarity(9), Rarity::Epic);
+            assert_eq!(spell_level_rarity(10), Rarity::Legendary);
+        }
+    }
+
+    mod roll_tests {
+        use super::*;
+
+        #[test]
+        fn rolls_three_distinct_choices() {
+            let spell_list = spell_list_with(&[SpellType::Fireball]);
+            let mut rng = rand::thread_rng();
+
+            for _ in 0..50 {
+                let choices = roll_level_up_choices(&spell_list, None, None, &mut rng);
+                assert_eq!(choices.len(), LEVEL_UP_CHOICE_COUNT);
+                for (i, choice) in choices.iter().enumerate() {
+                    assert!(!choices[i + 1..].contains(choice), "Choices should not repeat");
+                }
+            }
+        }
+
+        #[test]
+        fn falls_back_to_remaining_categories() {
+            // Full slots at max level: only powerups are left
+            let mut spell_list = spell_list_with(&[
+                SpellType::Fireball,
+                SpellType::IceShard,
+                SpellType::Spark,
+                SpellType::VenomBolt,
+                SpellType::ShadowBolt,
+            ]);
+            for slot in 0..5 {
+                spell_list.get_spell_mut(slot).unwrap().level = 10;
+            }
+
+            let choices = roll_level_up_choices(&spell_list, None, None, &mut rand::thread_rng());
+
+            assert_eq!(choices.len(), LEVEL_UP_POWERUPS.len());
+            assert!(choices.iter().all(|c| matches!(c.reward, LevelUpReward::Powerup(_))));
+        }
+    }
+
+    mod apply_reward_tests {
+        use super::*;
+
+        #[test]
+        fn new_spell_is_equipped_then_bagged() {
+            let mut spell_list = spell_list_with(&[
+                SpellType::Fireball,
+                SpellType::IceShard,
+                SpellType::Spark,
+                SpellType::VenomBolt,
+            ]);
+            let mut bag = InventoryBag::default();
+            let mut powerups = ActivePowerups::default();
+
+            assert!(apply_level_up_reward(
+                &LevelUpReward::NewSpell(SpellType::HolyBeam),
+                &mut spell_list,
+                Some(&mut bag),
+                &mut powerups,
+                SpellCatalog::built_in(),
+            ));
+            assert!(spell_list.has_spell(&SpellType::HolyBeam));
+
+            assert!(apply_level_up_reward(
+                &LevelUpReward::NewSpell(SpellType::MindBlast),
+                &mut spell_list,
+                Some(&mut bag),
+                &mut powerups,
+                SpellCatalog::built_in(),
+            ));
+            assert!(bag.find_spell(&SpellType::MindBlast).is_some());
+        }
+
+        #[test]
+        fn spell_level_raises_equipped_spell() {
+            let mut spell_list = spell_list_with(&[SpellType::Fireball]);
+            let mut powerups = ActivePowerups::default();
+
+            assert!(apply_level_up_reward(
+                &LevelUpReward::SpellLevel { slot: 0, spell_type: SpellType::Fireball },
+                &mut spell_list,
+                None,
+                &mut powerups,
+                SpellCatalog::built_in(),
+            ));
+            assert_eq!(spell_list.get_spell(0).unwrap().level, 2);
+        }
+
+        #[test]
+        fn spell_level_fails_for_missing_spell() {
+            let mut spell_list = SpellList::default();
+            let mut powerups = ActivePowerups::default();
+
+            assert!(!apply_level_up_reward(
+                &LevelUpReward::SpellLevel { slot: 0, spell_type: SpellType::Fireball },
+                &mut spell_list,
+                None,
+                &mut powerups,
+                SpellCatalog::built_in(),
+            ));
+        }
+
+        #[test]
+        fn powerup_adds_a_permanent_stack() {
+            let mut spell_list = SpellList::default();
+            let mut powerups = ActivePowerups::default();
+
+            apply_level_up_reward(
+                &LevelUpReward::Powerup(PowerupType::MaxHealth),
+                &mut spell_list,
+                None,
+                &mut powerups,
+                SpellCatalog::built_in(),
+            );
+
+            assert_eq!(powerups.get_stack_count(&PowerupType::MaxHealth), 1);
+            assert!(powerups.get_remaining_duration(&PowerupType::MaxHealth).is_none());
+        }
+    }
+
+    mod level_up_state_tests {
+        use super::*;
+
+        fn setup_app() -> App {
+            let mut app = App::new();
+            app.add_plugins(StatesPlugin);
+            app.init_state::<GameState>();
+            app.add_message::<PlayerLevelUpEvent>();
+            app.init_resource::<PendingLevelUps>();
+            app.init_resource::<LevelUpChoices>();
+            app.init_resource::<GameRng>();
+            app
+        }
+
+        #[test]
+        fn level_up_events_queue_choices() {
+            let mut app = setup_app();
+            app.world_mut().write_message(PlayerLevelUpEvent { new_level: 3, levels_gained: 2 });
+
+            let _ = app.world_mut().run_system_once(queue_level_up_choices);
+
+            assert_eq!(app.world().resource::<PendingLevelUps>().0, 2);
+        }
+
+        #[test]
+        fn pending_level_up_opens_choice_screen() {
+            let mut app = setup_app();
+            app.world_mut().resource_mut::<PendingLevelUps>().0 = 1;
+
+            let _ = app.world_mut().run_system_once(open_level_up_choice);
+            app.update();
+
+            let state = app.world().resource::<State<GameState>>();
+            assert_eq!(*state.get(), GameState::LevelUpChoice);
+        }
+
+        #[test]
+        fn does_not_override_other_transitions() {
+            let mut app = setup_app();
+            app.world_mut().resource_mut::<PendingLevelUps>().0 = 1;
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::AttunementSelect);
+
+            let _ = app.world_mut().run_system_once(open_level_up_choice);
+            app.update();
+
+            let state = app.world().resource::<State<GameState>>();
+            assert_eq!(*state.get(), GameState::AttunementSelect);
+            assert_eq!(app.world().resource::<PendingLevelUps>().0, 1);
+        }
+
+        #[test]
+        fn prepare_rolls_choices_from_resources() {
+            let mut app = setup_app();
+            app.insert_resource(spell_list_with(&[SpellType::Fireball]));
+            app.insert_resource(WhisperAttunement::with_element(Element::Fire));
+
+            let _ = app.world_mut().run_system_once(prepare_level_up_choices);
+
+            assert_eq!(app.world().resource::<LevelUpChoices>().choices.len(), LEVEL_UP_CHOICE_COUNT);
+        }
+    }
+}
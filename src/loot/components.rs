@@ -131,9 +131,7 @@ impl FallingAnimation {
     }
 
     /// Create with random direction
-    pub fn random() -> Self {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+    pub fn random(rng: &mut impl rand::Rng) -> Self {
         let angle = rng.gen_range(0.0..std::f32::consts::TAU);
         let direction = Vec2::new(angle.cos(), angle.sin());
         Self::new(direction)
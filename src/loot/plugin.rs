@@ -4,6 +4,7 @@ use crate::states::*;
 use crate::loot::systems::*;
 use crate::loot::events::*;
 use crate::game::events::LootDropEvent;
+use crate::game::resources::GameRng;
 
 /// Temporary resource to hold the GLTF handle while it loads
 #[derive(Resource)]
@@ -125,6 +126,7 @@ pub fn plugin(app: &mut App) {
         .add_message::<PickupEvent>()
         .add_message::<ItemEffectEvent>()
         .init_resource::<LootSoundCooldown>()
+        .init_resource::<GameRng>()
         // Load XP orb model when entering game
         .add_systems(OnEnter(GameState::InGame), setup_xp_orb_model)
         // Cleanup when exiting
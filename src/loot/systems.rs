@@ -5,13 +5,14 @@ use crate::loot::components::{DroppedItem, FallingAnimation, ItemData, PickupSta
 use crate::loot::events::*;
 use crate::loot::plugin::XpOrbModel;
 use crate::spell::{Spell, SpellType};
+use crate::spell::definitions::SpellCatalog;
 use crate::player::components::*;
 use crate::inventory::resources::*;
 use crate::inventory::bag::InventoryBag;
 use bevy_kira_audio::prelude::*;
 use crate::audio::plugin::*;
 use crate::game::components::Level;
-use crate::game::resources::{GameMaterials, GameMeshes, ScreenTintEffect, SpellLootMaterials, XpOrbMaterials};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng, ScreenTintEffect, SpellLootMaterials, XpOrbMaterials};
 use crate::game::events::LootDropEvent;
 use crate::states::GameState;
 use crate::whisper::components::{LightningSpawnTimer, WhisperCompanion, WhisperOuterGlow};
@@ -25,6 +26,18 @@ pub const LOOT_SMALL_Y_HEIGHT: f32 = 0.2;
 pub const LOOT_LARGE_Y_HEIGHT: f32 = 0.3;
 /// Height at which XP orbs spawn before falling
 pub const XP_ORB_SPAWN_HEIGHT: f32 = 1.0;
+/// Number of XP orbs dropped by a guaranteed (boss) loot drop
+pub const GUARANTEED_LOOT_ORB_COUNT: u32 = 8;
+/// Chance for a regular enemy to drop a spell
+pub const SPELL_DROP_CHANCE: f64 = 0.05;
+/// Chance for a regular enemy to drop a health pack
+pub const HEALTH_PACK_DROP_CHANCE: f64 = 0.03;
+/// Extra XP orbs dropped per elite affix
+pub const AFFIX_BONUS_ORBS: u32 = 1;
+/// Extra spell drop chance per elite affix
+pub const AFFIX_SPELL_DROP_BONUS: f64 = 0.1;
+/// Extra health pack drop chance per elite affix
+pub const AFFIX_HEALTH_PACK_DROP_BONUS: f64 = 0.05;
 
 /// XP value scaling by level
 /// Higher level orbs give exponentially more XP
@@ -52,26 +65,48 @@ pub fn select_xp_level(enemy_level: u8, rng: &mut impl Rng) -> u8 {
     orb_level
 }
 
+/// Spell drop chance of an enemy with `affix_count` elite affixes
+pub fn spell_drop_chance(affix_count: u8) -> f64 {
+    (SPELL_DROP_CHANCE + AFFIX_SPELL_DROP_BONUS * affix_count as f64).min(1.0)
+}
+
+/// Health pack drop chance of an enemy with `affix_count` elite affixes
+pub fn health_pack_drop_chance(affix_count: u8) -> f64 {
+    (HEALTH_PACK_DROP_CHANCE + AFFIX_HEALTH_PACK_DROP_BONUS * affix_count as f64).min(1.0)
+}
+
+/// Each elite affix drops an extra orb, rolls orbs a level higher and
+/// raises the spell and health pack chances.
+#[allow(clippy::too_many_arguments)]
 pub fn loot_drop_system(
     mut commands: Commands,
     mut loot_drop_events: MessageReader<LootDropEvent>,
-    game_meshes: Res<GameMeshes>,
-    game_materials: Res<GameMaterials>,
-    spell_loot_materials: Res<SpellLootMaterials>,
-    xp_orb_model: Res<XpOrbModel>,
-    xp_materials: Res<XpOrbMaterials>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+    spell_loot_materials: Option<Res<SpellLootMaterials>>,
+    xp_orb_model: Option<Res<XpOrbModel>>,
+    xp_materials: Option<Res<XpOrbMaterials>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for event in loot_drop_events.read() {
         let enemy_pos = event.position;
-        let enemy_level = event.enemy_level;
+        let enemy_level = event.enemy_level.saturating_add(event.affix_count);
 
         // Spawn experience orbs for each enemy killed
-        let mut rng = rand::thread_rng();
-        let orb_count = rng.gen_range(1..=3);
+        let orb_count = if event.guaranteed_loot {
+            GUARANTEED_LOOT_ORB_COUNT
+        } else {
+            rng.gen_range(1..=3) + AFFIX_BONUS_ORBS * event.affix_count as u32
+        };
 
         for _ in 0..orb_count {
             // Determine orb level based on enemy level (with upgrade chance)
-            let orb_level = select_xp_level(enemy_level, &mut rng);
+            // Guaranteed drops are always legendary
+            let orb_level = if event.guaranteed_loot {
+                5
+            } else {
+                select_xp_level(enemy_level, &mut *rng)
+            };
             let xp_value = xp_value_for_level(orb_level);
 
             // Offsets scaled for 3D world units (smaller than 2D pixel values)
@@ -79,7 +114,10 @@ pub fn loot_drop_system(
             let offset_z = rng.gen_range(-1.0..=1.0);
 
             // Get material based on orb level (using code-defined rarity colors with emissive glow)
-            let orb_material = xp_materials.for_level(orb_level);
+            let orb_visuals = xp_orb_model
+                .as_ref()
+                .zip(xp_materials.as_ref())
+                .map(|(model, materials)| (model.mesh.clone(), materials.for_level(orb_level)));
 
             // Generate random rotation for visual variety
             let random_rotation = Quat::from_euler(
@@ -91,9 +129,7 @@ pub fn loot_drop_system(
 
             // Spawn XP orb using the GLB mesh with level-appropriate material
             // Uses custom falling animation for realistic falling and tumbling
-            commands.spawn((
-                Mesh3d(xp_orb_model.mesh.clone()),
-                MeshMaterial3d(orb_material),
+            let mut orb = commands.spawn((
                 Transform::from_translation(Vec3::new(
                     enemy_pos.x + offset_x,
                     XP_ORB_SPAWN_HEIGHT,
@@ -102,7 +138,7 @@ pub fn loot_drop_system(
                 .with_rotation(random_rotation)
                 .with_scale(Vec3::splat(0.1)),
                 // Custom falling animation
-                FallingAnimation::random(),
+                FallingAnimation::random(&mut *rng),
                 // Game components
                 DroppedItem {
                     pickup_state: PickupState::Idle,
@@ -113,21 +149,24 @@ pub fn loot_drop_system(
                 },
                 Level::new(orb_level),
             ));
+            if let Some((mesh, material)) = orb_visuals {
+                orb.insert((Mesh3d(mesh), MeshMaterial3d(material)));
+            }
         }
 
         // Spawn loot drops with specified probabilities
         let mut loot_drops: Vec<ItemData> = Vec::new();
 
-        // 5% chance to drop a random spell (equal chance for all 64 spells)
-        if rng.gen_bool(0.05) {
+        // 5% chance to drop a random spell (equal chance for all 64 spells), more from elites
+        if event.guaranteed_loot || rng.gen_bool(spell_drop_chance(event.affix_count)) {
             let spell_index = rng.gen_range(0..64);
             // from_index is guaranteed to return Some for 0..64
             let spell_type = SpellType::from_index(spell_index).unwrap();
             loot_drops.push(ItemData::Spell(spell_type));
         }
 
-        // 3% chance to drop health regen (health pack)
-        if rng.gen_bool(0.03) {
+        // 3% chance to drop health regen (health pack), more from elites
+        if event.guaranteed_loot || rng.gen_bool(health_pack_drop_chance(event.affix_count)) {
             loot_drops.push(ItemData::HealthPack { heal_amount: 25.0 });
         }
 
@@ -138,38 +177,23 @@ pub fn loot_drop_system(
             let offset_x = angle.cos() * spacing;
             let offset_z = angle.sin() * spacing;
 
-            // Select mesh and material based on item type (emissive materials handle glow via bloom)
-            let (mesh, material, y_height) = match &item_data {
-                ItemData::Spell(spell_type) => {
-                    // Use element-based coloring for spell drops (all 8 elements have distinct materials)
-                    let material = spell_loot_materials.for_element(spell_type.element());
-                    (game_meshes.loot_large.clone(), material, LOOT_LARGE_Y_HEIGHT)
+            let y_height = match &item_data {
+                ItemData::Experience { .. } => LOOT_SMALL_Y_HEIGHT,
+                ItemData::Whisper => 1.0, // Whisper floats higher
+                _ => LOOT_LARGE_Y_HEIGHT,
+            };
+            let visuals = match (
+                game_meshes.as_deref(),
+                game_materials.as_deref(),
+                spell_loot_materials.as_deref(),
+            ) {
+                (Some(meshes), Some(materials), Some(spell_materials)) => {
+                    Some(loot_visuals(&item_data, meshes, materials, spell_materials))
                 }
-                ItemData::HealthPack { .. } => (
-                    game_meshes.loot_medium.clone(),
-                    game_materials.health_pack.clone(),
-                    LOOT_LARGE_Y_HEIGHT,
-                ),
-                ItemData::Experience { .. } => (
-                    game_meshes.loot_small.clone(),
-                    game_materials.xp_orb.clone(),
-                    LOOT_SMALL_Y_HEIGHT,
-                ),
-                ItemData::Powerup(_) => (
-                    game_meshes.loot_medium.clone(),
-                    game_materials.powerup.clone(),
-                    LOOT_LARGE_Y_HEIGHT,
-                ),
-                ItemData::Whisper => (
-                    game_meshes.whisper_core.clone(),
-                    game_materials.whisper_drop.clone(),
-                    1.0, // Whisper floats higher
-                ),
+                _ => None,
             };
 
-            commands.spawn((
-                Mesh3d(mesh),
-                MeshMaterial3d(material),
+            let mut item = commands.spawn((
                 Transform::from_translation(Vec3::new(
                     enemy_pos.x + offset_x,
                     y_height,
@@ -183,10 +207,45 @@ pub fn loot_drop_system(
                     rotation_direction: 1.0,
                 },
             ));
+            if let Some((mesh, material)) = visuals {
+                item.insert((Mesh3d(mesh), MeshMaterial3d(material)));
+            }
         }
     }
 }
 
+/// Mesh and material of a dropped item (emissive materials handle glow via bloom)
+fn loot_visuals(
+    item_data: &ItemData,
+    game_meshes: &GameMeshes,
+    game_materials: &GameMaterials,
+    spell_loot_materials: &SpellLootMaterials,
+) -> (Handle<Mesh>, Handle<StandardMaterial>) {
+    match item_data {
+        // Element-based coloring for spell drops (all 8 elements have distinct materials)
+        ItemData::Spell(spell_type) => (
+            game_meshes.loot_large.clone(),
+            spell_loot_materials.for_element(spell_type.element()),
+        ),
+        ItemData::HealthPack { .. } => (
+            game_meshes.loot_medium.clone(),
+            game_materials.health_pack.clone(),
+        ),
+        ItemData::Experience { .. } => (
+            game_meshes.loot_small.clone(),
+            game_materials.xp_orb.clone(),
+        ),
+        ItemData::Powerup(_) => (
+            game_meshes.loot_medium.clone(),
+            game_materials.powerup.clone(),
+        ),
+        ItemData::Whisper => (
+            game_meshes.whisper_core.clone(),
+            game_materials.whisper_drop.clone(),
+        ),
+    }
+}
+
 // ECS-based pickup systems
 
 /// System that detects when dropped items enter pickup range and starts attraction
@@ -497,14 +556,17 @@ pub fn apply_item_effects(
     mut effect_events: MessageReader<ItemEffectEvent>,
     mut player_query: Query<(&Transform, &Player, &mut Health)>,
     mut player_exp_query: Query<&mut crate::experience::components::PlayerExperience>,
-    mut spell_list: Option<ResMut<SpellList>>,
-    mut inventory_bag: Option<ResMut<InventoryBag>>,
+    mut level_up_events: MessageWriter<crate::experience::components::PlayerLevelUpEvent>,
+    (mut spell_list, mut inventory_bag, spell_catalog): (
+        Option<ResMut<SpellList>>,
+        Option<ResMut<InventoryBag>>,
+        Res<SpellCatalog>,
+    ),
     mut active_powerups: ResMut<crate::powerup::components::ActivePowerups>,
     mut screen_tint: ResMut<ScreenTintEffect>,
     mut whisper_state: ResMut<WhisperState>,
     mut next_state: ResMut<NextState<GameState>>,
-    game_meshes: Option<Res<GameMeshes>>,
-    game_materials: Option<Res<GameMaterials>>,
+    (game_meshes, game_materials): (Option<Res<GameMeshes>>, Option<Res<GameMaterials>>),
     asset_server: Option<Res<AssetServer>>,
     mut audio_channel: Option<ResMut<AudioChannel<LootSoundChannel>>>,
     mut sound_limiter: Option<ResMut<SoundLimiter>>,
@@ -539,7 +601,7 @@ pub fn apply_item_effects(
                     play_powerup_sound(&asset_server, &mut audio_channel, &mut sound_limiter, &mut loot_cooldown);
                 } else {
                     // Try to equip to SpellList or add to bag
-                    let new_spell = Spell::new(*spell_type);
+                    let new_spell = Spell::from_catalog(*spell_type, &spell_catalog);
                     if spell_list.equip(new_spell.clone()).is_some()
                         || inventory_bag.add(new_spell).is_some()
                     {
@@ -560,8 +622,13 @@ pub fn apply_item_effects(
             ItemData::Experience { amount } => {
                 // Add experience and handle level-ups
                 if let Ok(mut player_exp) = player_exp_query.get_mut(event.player_entity) {
-                    let _levels_gained = player_exp.add_xp(*amount);
-                    // TODO: Fire PlayerLevelUpEvent if levels_gained > 0
+                    let levels_gained = player_exp.add_xp(*amount);
+                    if levels_gained > 0 {
+                        level_up_events.write(crate::experience::components::PlayerLevelUpEvent {
+                            new_level: player_exp.level,
+                            levels_gained,
+                        });
+                    }
                 }
                 play_pickup_sound(&asset_server, &mut audio_channel, &mut sound_limiter, &mut loot_cooldown);
             }
@@ -1611,7 +1678,7 @@ mod tests {
                     rotation_direction: 1.0,
                 },
                 Transform::from_translation(Vec3::new(10.0, 1.0, 10.0)),
-                FallingAnimation::random(),
+                FallingAnimation::random(&mut rand::thread_rng()),
             )).id();
 
             // Run pickup detection and animation start
@@ -1709,7 +1776,7 @@ mod tests {
                     rotation_direction: 1.0,
                 },
                 Transform::from_translation(Vec3::new(10.0, 1.0, 10.0)),
-                FallingAnimation::random(),
+                FallingAnimation::random(&mut rand::thread_rng()),
             )).id();
 
             app.update();
@@ -1790,7 +1857,7 @@ mod tests {
             app.add_systems(Update, animate_falling);
 
             // Create falling item that's already settled
-            let mut anim = FallingAnimation::random();
+            let mut anim = FallingAnimation::random(&mut rand::thread_rng());
             anim.settled = true;
 
             let item_entity = app.world_mut().spawn((
@@ -1831,7 +1898,7 @@ mod tests {
                     rotation_direction: 1.0,
                 },
                 Transform::from_translation(Vec3::new(0.0, XP_ORB_SPAWN_HEIGHT, 0.0)),
-                FallingAnimation::random(),
+                FallingAnimation::random(&mut rand::thread_rng()),
             )).id();
 
             let initial_rotation = app.world().get::<Transform>(item_entity).unwrap().rotation;
@@ -2044,6 +2111,98 @@ mod tests {
         }
     }
 
+    mod guaranteed_loot_tests {
+        use super::*;
+        use bevy::ecs::system::RunSystemOnce;
+
+        #[test]
+        fn guaranteed_drop_spawns_legendary_orbs_spell_and_health_pack() {
+            let mut app = App::new();
+            app.add_plugins(bevy::asset::AssetPlugin::default());
+            app.init_asset::<Mesh>();
+            app.init_asset::<StandardMaterial>();
+            app.add_message::<LootDropEvent>();
+
+            let mut meshes = app.world_mut().resource_mut::<Assets<Mesh>>();
+            let game_meshes = GameMeshes::new(&mut meshes);
+            let orb_mesh = meshes.add(Sphere::new(1.0));
+            app.insert_resource(game_meshes);
+            app.insert_resource(XpOrbModel { mesh: orb_mesh });
+            let mut materials = app.world_mut().resource_mut::<Assets<StandardMaterial>>();
+            let game_materials = GameMaterials::new(&mut materials);
+            let spell_materials = SpellLootMaterials::new(&mut materials);
+            let xp_materials = XpOrbMaterials::new(&mut materials);
+            app.insert_resource(game_materials);
+            app.insert_resource(spell_materials);
+            app.insert_resource(xp_materials);
+            app.init_resource::<GameRng>();
+
+            app.world_mut().write_message(LootDropEvent {
+                position: Vec3::ZERO,
+                enemy_level: 1,
+                affix_count: 0,
+                guaranteed_loot: true,
+            });
+            let _ = app.world_mut().run_system_once(loot_drop_system);
+
+            let mut query = app.world_mut().query::<(&DroppedItem, Option<&Level>)>();
+            let mut orbs = 0;
+            let mut spells = 0;
+            let mut health_packs = 0;
+            for (item, level) in query.iter(app.world()) {
+                match item.item_data {
+                    ItemData::Experience { .. } => {
+                        orbs += 1;
+                        assert_eq!(level.map(|l| l.value()), Some(5), "Guaranteed orbs are legendary");
+                    }
+                    ItemData::Spell(_) => spells += 1,
+                    ItemData::HealthPack { .. } => health_packs += 1,
+                    _ => {}
+                }
+            }
+            assert_eq!(orbs, GUARANTEED_LOOT_ORB_COUNT);
+            assert_eq!(spells, 1);
+            assert_eq!(health_packs, 1);
+        }
+    }
+
+    mod elite_loot_tests {
+        use super::*;
+        use bevy::ecs::system::RunSystemOnce;
+
+        #[test]
+        fn affixes_raise_drop_chances() {
+            assert_eq!(spell_drop_chance(0), SPELL_DROP_CHANCE);
+            assert!(spell_drop_chance(2) > spell_drop_chance(1));
+            assert!(health_pack_drop_chance(3) > HEALTH_PACK_DROP_CHANCE);
+            assert_eq!(spell_drop_chance(u8::MAX), 1.0);
+        }
+
+        #[test]
+        fn affixed_enemies_drop_more_and_better_orbs() {
+            let mut app = App::new();
+            app.add_message::<LootDropEvent>();
+            app.init_resource::<GameRng>();
+
+            app.world_mut().write_message(LootDropEvent {
+                position: Vec3::ZERO,
+                enemy_level: 1,
+                affix_count: 2,
+                guaranteed_loot: false,
+            });
+            let _ = app.world_mut().run_system_once(loot_drop_system);
+
+            let mut query = app.world_mut().query::<(&DroppedItem, &Level)>();
+            let orb_levels: Vec<u8> = query
+                .iter(app.world())
+                .filter(|(item, _)| matches!(item.item_data, ItemData::Experience { .. }))
+                .map(|(_, level)| level.value())
+                .collect();
+            assert!(orb_levels.len() >= 3, "Two affixes add two orbs, got {}", orb_levels.len());
+            assert!(orb_levels.iter().all(|level| *level >= 3), "Orbs should roll from level 3: {:?}", orb_levels);
+        }
+    }
+
     mod spell_drop_distribution_tests {
         use super::*;
         use rand::SeedableRng;
@@ -2181,6 +2340,7 @@ mod tests {
             let mut app = App::new();
             app.add_plugins(StatesPlugin);
             app.add_message::<ItemEffectEvent>();
+            app.add_message::<crate::experience::components::PlayerLevelUpEvent>();
 
             // Set up required resources
             let mut spell_list = SpellList::default();
@@ -2188,6 +2348,7 @@ mod tests {
             app.insert_resource(spell_list);
             app.insert_resource(InventoryBag::default());
             app.insert_resource(crate::powerup::components::ActivePowerups::default());
+            app.init_resource::<SpellCatalog>();
             app.insert_resource(ScreenTintEffect::default());
             app.insert_resource(WhisperState::default());
             app.init_state::<GameState>();
@@ -2238,11 +2399,13 @@ mod tests {
             let mut app = App::new();
             app.add_plugins(StatesPlugin);
             app.add_message::<ItemEffectEvent>();
+            app.add_message::<crate::experience::components::PlayerLevelUpEvent>();
 
             // Set up required resources with empty spell list
             app.insert_resource(SpellList::default());
             app.insert_resource(InventoryBag::default());
             app.insert_resource(crate::powerup::components::ActivePowerups::default());
+            app.init_resource::<SpellCatalog>();
             app.insert_resource(ScreenTintEffect::default());
             app.insert_resource(WhisperState::default());
             app.init_state::<GameState>();
@@ -2292,6 +2455,7 @@ mod tests {
             let mut app = App::new();
             app.add_plugins(StatesPlugin);
             app.add_message::<ItemEffectEvent>();
+            app.add_message::<crate::experience::components::PlayerLevelUpEvent>();
 
             // Set up required resources with spell in bag
             app.insert_resource(SpellList::default());
@@ -2299,6 +2463,7 @@ mod tests {
             bag.add(Spell::new(SpellType::IceShard));
             app.insert_resource(bag);
             app.insert_resource(crate::powerup::components::ActivePowerups::default());
+            app.init_resource::<SpellCatalog>();
             app.insert_resource(ScreenTintEffect::default());
             app.insert_resource(WhisperState::default());
             app.init_state::<GameState>();
@@ -2341,6 +2506,58 @@ mod tests {
                 item.pickup_state
             );
         }
+
+        #[test]
+        fn test_xp_pickup_fires_player_level_up_event() {
+            use crate::experience::components::{PlayerExperience, PlayerLevelUpEvent};
+
+            let mut app = App::new();
+            app.add_plugins(StatesPlugin);
+            app.add_message::<ItemEffectEvent>();
+            app.add_message::<PlayerLevelUpEvent>();
+
+            app.insert_resource(SpellList::default());
+            app.insert_resource(InventoryBag::default());
+            app.insert_resource(crate::powerup::components::ActivePowerups::default());
+            app.init_resource::<SpellCatalog>();
+            app.insert_resource(ScreenTintEffect::default());
+            app.insert_resource(WhisperState::default());
+            app.init_state::<GameState>();
+
+            app.add_systems(Update, (complete_pickup_when_close, apply_item_effects).chain());
+
+            let xp_to_level = PlayerExperience::new().xp_to_next_level();
+            app.world_mut().spawn((
+                Player {
+                    speed: 200.0,
+                    regen_rate: 1.0,
+                    pickup_radius: 50.0,
+                    last_movement_direction: Vec3::ZERO,
+                },
+                PlayerExperience::new(),
+                Transform::from_translation(Vec3::ZERO),
+                Health::new(100.0),
+            ));
+            app.world_mut().spawn((
+                DroppedItem {
+                    pickup_state: PickupState::BeingAttracted,
+                    item_data: ItemData::Experience { amount: xp_to_level },
+                    velocity: Vec3::ZERO,
+                    rotation_speed: 0.0,
+                    rotation_direction: 1.0,
+                },
+                Transform::from_translation(Vec3::new(0.0, PLAYER_HEIGHT * 0.5, 0.0)),
+            ));
+
+            app.update();
+
+            let messages = app.world().resource::<Messages<PlayerLevelUpEvent>>();
+            let mut cursor = messages.get_cursor();
+            let events: Vec<_> = cursor.read(messages).collect();
+            assert_eq!(events.len(), 1, "Crossing the XP threshold should fire a level-up");
+            assert_eq!(events[0].new_level, 2);
+            assert_eq!(events[0].levels_gained, 1);
+        }
     }
 
 }
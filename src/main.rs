@@ -6,9 +6,14 @@ use donny_tango_survivor::{
     audio_plugin,
     combat_plugin,
     experience_plugin,
+    game::resources::GameRng,
     game_plugin,
+    input::ControlsStorage,
     inventory_plugin,
     pause_plugin,
+    profile::{ProfileStorage, RunHistoryStorage},
+    simulation::{run_simulations, SimulationConfig},
+    spell::definitions::SpellCatalog,
     ui_plugin,
     visual_test::{self, TestScene, ScreenshotState},
     states::GameState
@@ -26,6 +31,23 @@ struct Args {
     /// Use 'list' to see available scenes.
     #[arg(long)]
     screenshot: Option<String>,
+
+    /// Seed the run rng so the same inputs replay the same spawns and drops
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Write the recorded run history to a CSV file and exit
+    #[arg(long, value_name = "PATH")]
+    export_runs: Option<std::path::PathBuf>,
+
+    /// Play RUNS runs headless with a scripted player and print a summary.
+    /// Seeds start at --seed (default 0).
+    #[arg(long, value_name = "RUNS")]
+    simulate: Option<u32>,
+
+    /// Game seconds after which a simulated run still alive is stopped
+    #[arg(long, value_name = "SECS", default_value_t = 600.0)]
+    sim_max_secs: f32,
 }
 
 fn main() {
@@ -42,6 +64,30 @@ fn main() {
         }
     }
 
+    // Handle --export-runs
+    if let Some(ref csv_path) = args.export_runs {
+        match RunHistoryStorage::in_user_data_dir().export_csv(csv_path, &SpellCatalog::default()) {
+            Ok(count) => println!("Exported {} runs to {}", count, csv_path.display()),
+            Err(err) => {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    // Handle --simulate
+    if let Some(runs) = args.simulate {
+        let config = SimulationConfig {
+            runs,
+            first_seed: args.seed.unwrap_or(0),
+            max_secs: args.sim_max_secs,
+            ..SimulationConfig::default()
+        };
+        print!("{}", run_simulations(&config).summary(&SpellCatalog::default()));
+        return;
+    }
+
     // Get the current directory and construct the assets path
     let current_dir = std::env::current_dir().expect("Failed to get current directory");
     let assets_path = current_dir.join("assets");
@@ -100,6 +146,10 @@ fn main() {
         });
     } else {
         // Normal game mode
+        // A fixed seed replaces the entropy-seeded rng the plugins would init
+        if let Some(seed) = args.seed {
+            app.insert_resource(GameRng::from_seed(seed));
+        }
         app.add_plugins(DefaultPlugins.build()
                 .disable::<bevy::audio::AudioPlugin>()
                 .set(AssetPlugin {
@@ -109,6 +159,10 @@ fn main() {
             .add_plugins(AudioPlugin)
             .add_plugins(HanabiPlugin)
             .init_state::<GameState>()
+            // Persist meta-progression in the user data dir (tests keep it in memory)
+            .insert_resource(ProfileStorage::in_user_data_dir())
+            .insert_resource(ControlsStorage::in_user_data_dir())
+            .insert_resource(RunHistoryStorage::in_user_data_dir())
             .add_plugins((audio_plugin, combat_plugin, experience_plugin, game_plugin, inventory_plugin, pause_plugin, ui_plugin));
 
         // If auto-start flag is set, add a system to skip to InGame state
@@ -134,6 +188,7 @@ mod tests {
     use donny_tango_survivor::inventory::resources::SpellList;
     use donny_tango_survivor::game::ScreenTintEffect;
     use donny_tango_survivor::enemies::components::Enemy;
+    use donny_tango_survivor::collision::spatial::rebuild_enemy_spatial_hash;
     use donny_tango_survivor::ui::components::RadialCooldownOverlay;
     use donny_tango_survivor::ui::spell_slot::{SpellSlotVisual, SpellIconImage, SlotSource};
     use bevy::app::App;
@@ -481,6 +536,7 @@ mod tests {
         }
 
         // Run spell casting system
+        let _ = app.world_mut().run_system_once(rebuild_enemy_spatial_hash);
         let _ = app.world_mut().run_system_once(spell_casting_system);
 
         // Check that charging fireballs were spawned (charge phase)
@@ -606,6 +662,7 @@ mod tests {
         )).id();
 
         // Run collision and combat systems in sequence
+        let _ = app.world_mut().run_system_once(rebuild_enemy_spatial_hash);
         let _ = app.world_mut().run_system_once(fireball_collision_detection);
         let _ = app.world_mut().run_system_once(fireball_collision_effects);
         let _ = app.world_mut().run_system_once(apply_damage_system);
@@ -693,6 +750,7 @@ mod tests {
         }
 
         // Run collision and combat systems in sequence
+        let _ = app.world_mut().run_system_once(rebuild_enemy_spatial_hash);
         let _ = app.world_mut().run_system_once(fireball_collision_detection);
         let _ = app.world_mut().run_system_once(fireball_collision_effects);
         let _ = app.world_mut().run_system_once(apply_damage_system);
@@ -122,6 +122,11 @@ impl Knockback {
     pub fn remaining_fraction(&self) -> f32 {
         1.0 - self.duration.fraction()
     }
+
+    /// Get the remaining duration in seconds.
+    pub fn remaining_secs(&self) -> f32 {
+        self.duration.remaining_secs()
+    }
 }
 
 #[cfg(test)]
@@ -282,6 +287,13 @@ mod tests {
         assert!(knockback.is_finished());
     }
 
+    #[test]
+    fn test_knockback_remaining_secs() {
+        let mut knockback = Knockback::new(Vec2::X, 100.0, 0.5);
+        knockback.tick(Duration::from_secs_f32(0.2));
+        assert!((knockback.remaining_secs() - 0.3).abs() < 0.001);
+    }
+
     #[test]
     fn test_knockback_can_be_added_to_entity() {
         use bevy::app::App;
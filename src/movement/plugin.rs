@@ -1,23 +1,41 @@
 use bevy::prelude::*;
 
+use crate::game::resources::GameRng;
 use crate::game::sets::GameSet;
-use crate::movement::systems::{apply_knockback, apply_velocity, enemy_movement_system, player_movement};
+use crate::input::resources::MovementIntent;
+use crate::movement::systems::{
+    apply_knockback, apply_velocity, confused_enemy_movement_system, enemy_movement_system,
+    enemy_separation_system, player_movement,
+};
 use crate::states::GameState;
 
 /// Plugin that adds the movement module's systems to the app.
 /// Systems run in the GameSet::Movement set during InGame state.
 pub fn plugin(app: &mut App) {
-    app.add_systems(
-        Update,
-        (
-            player_movement,
-            apply_velocity,
-            apply_knockback,
-            enemy_movement_system,
+    app.init_resource::<GameRng>()
+        .init_resource::<MovementIntent>()
+        .add_systems(
+            Update,
+            (
+                player_movement,
+                apply_velocity,
+                apply_knockback,
+                enemy_movement_system,
+                confused_enemy_movement_system,
+            )
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
         )
-            .in_set(GameSet::Movement)
-            .run_if(in_state(GameState::InGame)),
-    );
+        // Crowd separation once every enemy has moved this frame
+        .add_systems(
+            Update,
+            enemy_separation_system
+                .after(enemy_movement_system)
+                .after(confused_enemy_movement_system)
+                .after(apply_knockback)
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        );
 }
 
 #[cfg(test)]
@@ -2,8 +2,12 @@ use bevy::prelude::*;
 use bevy::math::Ray3d;
 use rand::Rng;
 
-use crate::enemies::components::Enemy;
-use crate::game::resources::PlayerPosition;
+use crate::collision::components::{Obstacle, ENEMY_BODY_RADIUS, PLAYER_BODY_RADIUS};
+use crate::collision::grid::SpatialGrid;
+use crate::collision::systems::steer_around_obstacles;
+use crate::enemies::components::{Charger, Enemy, RangedAttacker, Stampeding};
+use crate::game::resources::{GameRng, PlayerPosition};
+use crate::input::resources::MovementIntent;
 use crate::movement::components::{from_xz, to_xz, Knockback, Velocity};
 use crate::player::components::{Player, SlowModifier};
 use crate::spells::chaos::pandemonium::ConfusedEnemy;
@@ -70,58 +74,39 @@ pub fn apply_knockback(
     }
 }
 
-/// System that moves the player towards the mouse cursor when left mouse button is pressed.
+/// System that moves the player according to the frame's `MovementIntent`.
 /// Takes into account slow modifiers when calculating effective speed.
-/// Uses 3D ray casting to intersect mouse position with Y=0 ground plane.
+/// Click-to-move heads for the ground point under the cursor; direct input
+/// moves along the camera-relative direction read in `GameSet::Input`.
 pub fn player_movement(
-    mouse_button_input: Res<ButtonInput<MouseButton>>,
-    windows: Query<&Window>,
+    intent: Res<MovementIntent>,
     mut player_query: Query<(&mut Transform, &mut Player, Option<&SlowModifier>)>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
     time: Res<Time>,
 ) {
-    if !mouse_button_input.pressed(MouseButton::Left) {
+    if !intent.is_moving() {
         return;
     }
 
-    let Ok((camera, camera_transform)) = camera_query.single() else {
-        return;
-    };
-    let Ok(window) = windows.single() else {
-        return;
-    };
+    for (mut transform, mut player, slow_modifier) in player_query.iter_mut() {
+        let player_pos = from_xz(transform.translation);
+        let direction = intent.direction_from(player_pos);
 
-    if let Some(cursor_position) = window.cursor_position() {
-        // Cast ray from camera through cursor and intersect with Y=0 ground plane
-        let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_position) else {
-            return;
-        };
+        // Track movement direction for loot rotation effects
+        if direction.length_squared() > 0.0 {
+            let facing = direction.normalize();
+            player.last_movement_direction = Vec3::new(facing.x, 0.0, facing.y);
+        }
 
-        // Use the ray_ground_intersection helper to get XZ coordinates
-        let Some(world_position) = ray_ground_intersection(ray) else {
-            return;
+        // Calculate effective speed considering slow modifiers
+        let effective_speed = if let Some(slow) = slow_modifier {
+            player.speed * slow.speed_multiplier
+        } else {
+            player.speed
         };
 
-        for (mut transform, mut player, slow_modifier) in player_query.iter_mut() {
-            let player_pos = from_xz(transform.translation);
-            let direction = (world_position - player_pos).normalize_or_zero();
-
-            // Track movement direction for loot rotation effects
-            if direction.length_squared() > 0.0 {
-                player.last_movement_direction = Vec3::new(direction.x, 0.0, direction.y);
-            }
-
-            // Calculate effective speed considering slow modifiers
-            let effective_speed = if let Some(slow) = slow_modifier {
-                player.speed * slow.speed_multiplier
-            } else {
-                player.speed
-            };
-
-            // Move player towards cursor on XZ plane
-            let movement = direction * effective_speed * time.delta_secs();
-            transform.translation += to_xz(movement);
-        }
+        // Move player on XZ plane
+        let movement = direction * effective_speed * time.delta_secs();
+        transform.translation += to_xz(movement);
     }
 }
 
@@ -132,6 +117,10 @@ pub fn player_movement(
 /// and ConfusedEnemy which uses different AI.
 /// When multiple slow effects are present, uses the stronger slow (lower multiplier).
 /// Also applies any Velocity component (e.g., from spell pull effects like Void Rift).
+/// Ranged enemies hold their preferred distance instead of closing in, and chargers
+/// only walk while approaching (charger_behavior_system drives the dash).
+/// Stampeding enemies are left to stampede_movement_system.
+/// Enemies steer around obstacles between them and the player.
 #[allow(clippy::type_complexity)]
 pub fn enemy_movement_system(
     mut enemy_query: Query<(
@@ -143,13 +132,16 @@ pub fn enemy_movement_system(
         Option<&ConfusedEnemy>,
         Option<&StunnedEnemy>,
         Option<&Velocity>,
-    )>,
+        Option<&RangedAttacker>,
+        Option<&Charger>,
+    ), Without<Stampeding>>,
+    obstacles: Query<(&Transform, &Obstacle), Without<Enemy>>,
     player_position: Res<PlayerPosition>,
     time: Res<Time>,
 ) {
     let player_pos = player_position.0; // Vec2 representing XZ coordinates
 
-    for (mut transform, enemy, slowed_debuff, in_hoarfrost, frozen_status, confused, stunned, velocity) in enemy_query.iter_mut()
+    for (mut transform, enemy, slowed_debuff, in_hoarfrost, frozen_status, confused, stunned, velocity, ranged, charger) in enemy_query.iter_mut()
     {
         // Frozen enemies cannot move at all
         if frozen_status.is_some() {
@@ -166,8 +158,25 @@ pub fn enemy_movement_system(
             continue;
         }
 
+        // Chargers telegraphing, dashing or recovering are moved by their own system
+        if charger.is_some_and(|charger| !charger.is_approaching()) {
+            continue;
+        }
+
         let enemy_pos = from_xz(transform.translation);
-        let direction = (player_pos - enemy_pos).normalize_or_zero();
+        let mut direction = (player_pos - enemy_pos).normalize_or_zero();
+        if let Some(ranged) = ranged {
+            direction *= ranged.approach_factor(enemy_pos.distance(player_pos));
+        }
+        direction = steer_around_obstacles(
+            enemy_pos,
+            direction,
+            ENEMY_BODY_RADIUS,
+            enemy_pos.distance(player_pos),
+            obstacles
+                .iter()
+                .map(|(transform, obstacle)| (from_xz(transform.translation), obstacle.shape)),
+        );
 
         // Calculate effective speed considering slow effects
         // If multiple slows are present, use the stronger one (lower multiplier)
@@ -192,15 +201,123 @@ pub fn enemy_movement_system(
     }
 }
 
+/// Fraction of each overlap resolved per frame; below 1.0 keeps crowds from jittering
+pub const SEPARATION_STRENGTH: f32 = 0.5;
+
+/// Share of a knockback's force passed on to each enemy it shoves
+pub const KNOCKBACK_TRANSFER: f32 = 0.6;
+
+/// Knockbacks weaker than this stop spreading through the crowd
+pub const MIN_PROPAGATED_KNOCKBACK: f32 = 40.0;
+
+/// System that pushes overlapping enemies apart so waves spread out instead of
+/// stacking, and keeps them out of the player's body so they surround the player.
+/// Neighbours come from a spatial grid, so the cost stays linear in the enemy count.
+/// An enemy under knockback shoves the enemies ahead of it, passing on a weaker
+/// knockback so the push ripples through the crowd.
+pub fn enemy_separation_system(
+    mut commands: Commands,
+    mut enemy_query: Query<(Entity, &mut Transform, Option<&Knockback>), With<Enemy>>,
+    player_position: Res<PlayerPosition>,
+    mut grid: Local<SpatialGrid>,
+) {
+    let spacing = ENEMY_BODY_RADIUS * 2.0;
+    if grid.cell_size() != spacing {
+        *grid = SpatialGrid::new(spacing);
+    }
+    grid.rebuild(
+        enemy_query
+            .iter()
+            .map(|(entity, transform, _)| (entity, from_xz(transform.translation))),
+    );
+
+    let player_spacing = ENEMY_BODY_RADIUS + PLAYER_BODY_RADIUS;
+    let mut pushes: Vec<(Entity, Vec2)> = Vec::new();
+    let mut shoves: Vec<(Entity, Knockback)> = Vec::new();
+
+    for (entity, transform, knockback) in enemy_query.iter() {
+        let position = from_xz(transform.translation);
+        let mut push = Vec2::ZERO;
+
+        for (other, other_pos) in grid.neighbors(position) {
+            if other == entity {
+                continue;
+            }
+            let offset = position - other_pos;
+            let distance = offset.length();
+            if distance >= spacing {
+                continue;
+            }
+
+            let away = if distance > 0.0001 {
+                offset / distance
+            } else {
+                tie_break_direction(entity, other)
+            };
+            // Each side of the pair resolves half the overlap
+            push += away * (spacing - distance) * 0.5;
+
+            if let Some(knockback) = knockback {
+                let force = knockback.force() * KNOCKBACK_TRANSFER;
+                if force >= MIN_PROPAGATED_KNOCKBACK && knockback.direction().dot(-away) > 0.0 {
+                    shoves.push((
+                        other,
+                        Knockback::new(knockback.direction(), force, knockback.remaining_secs()),
+                    ));
+                }
+            }
+        }
+
+        // Flow around the player instead of piling onto them
+        let from_player = position - player_position.0;
+        let player_distance = from_player.length();
+        if player_distance > 0.0001 && player_distance < player_spacing {
+            push += from_player / player_distance * (player_spacing - player_distance);
+        }
+
+        if push != Vec2::ZERO {
+            pushes.push((entity, push * SEPARATION_STRENGTH));
+        }
+    }
+
+    for (entity, push) in pushes {
+        if let Ok((_, mut transform, _)) = enemy_query.get_mut(entity) {
+            transform.translation += to_xz(push);
+        }
+    }
+
+    for (entity, knockback) in shoves {
+        // Enemies already being knocked back keep their own push
+        if enemy_query
+            .get(entity)
+            .is_ok_and(|(_, _, existing)| existing.is_none())
+        {
+            commands.entity(entity).try_insert(knockback);
+        }
+    }
+}
+
+/// Direction splitting two enemies standing on exactly the same spot.
+/// Derived from both entities so the pair always gets opposite directions.
+fn tie_break_direction(entity: Entity, other: Entity) -> Vec2 {
+    let (low, high) = if entity < other { (entity, other) } else { (other, entity) };
+    let seed = low.to_bits().wrapping_mul(31).wrapping_add(high.to_bits());
+    let direction = Vec2::from_angle((seed % 360) as f32 * std::f32::consts::PI / 180.0);
+    if entity == low {
+        direction
+    } else {
+        -direction
+    }
+}
+
 /// System that moves confused enemies towards their current target (another enemy).
 /// Confused enemies move erratically towards their target, or wander randomly if no target.
 pub fn confused_enemy_movement_system(
     mut confused_query: Query<(&mut Transform, &Enemy, &ConfusedEnemy)>,
     enemy_query: Query<&Transform, (With<Enemy>, Without<ConfusedEnemy>)>,
     time: Res<Time>,
+    mut rng: ResMut<GameRng>,
 ) {
-    let mut rng = rand::thread_rng();
-
     for (mut transform, enemy, confused) in confused_query.iter_mut() {
         let my_pos = from_xz(transform.translation);
 
@@ -211,11 +328,11 @@ pub fn confused_enemy_movement_system(
                 (target_pos - my_pos).normalize_or_zero()
             } else {
                 // Target no longer exists, wander randomly
-                random_direction(&mut rng)
+                random_direction(&mut *rng)
             }
         } else {
             // No target, wander randomly
-            random_direction(&mut rng)
+            random_direction(&mut *rng)
         };
 
         let effective_speed = enemy.speed * confused.speed_multiplier;
@@ -574,6 +691,70 @@ mod tests {
     }
 
     // enemy_movement_system tests
+    #[test]
+    fn test_ranged_enemy_backs_off_when_player_too_close() {
+        let mut app = App::new();
+        app.add_plugins(bevy::time::TimePlugin::default());
+        app.init_resource::<PlayerPosition>();
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(Vec3::new(2.0, 0.5, 0.0)),
+                Enemy {
+                    speed: 1.0,
+                    strength: 10.0,
+                },
+                RangedAttacker::default(),
+            ))
+            .id();
+
+        {
+            let mut time = app.world_mut().get_resource_mut::<Time>().unwrap();
+            time.advance_by(Duration::from_secs(1));
+        }
+
+        let _ = app.world_mut().run_system_once(enemy_movement_system);
+
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert!(
+            transform.translation.x > 2.0,
+            "Ranged enemy should move away from a close player, got x={}",
+            transform.translation.x
+        );
+    }
+
+    #[test]
+    fn test_dashing_charger_ignores_chase_movement() {
+        let mut app = App::new();
+        app.add_plugins(bevy::time::TimePlugin::default());
+        app.init_resource::<PlayerPosition>();
+
+        let mut charger = Charger::default();
+        charger.enter(crate::enemies::components::ChargerState::Dashing);
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(Vec3::new(10.0, 0.5, 0.0)),
+                Enemy {
+                    speed: 5.0,
+                    strength: 10.0,
+                },
+                charger,
+            ))
+            .id();
+
+        {
+            let mut time = app.world_mut().get_resource_mut::<Time>().unwrap();
+            time.advance_by(Duration::from_secs(1));
+        }
+
+        let _ = app.world_mut().run_system_once(enemy_movement_system);
+
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation.x, 10.0);
+    }
+
     #[test]
     fn test_enemy_movement_towards_player_on_xz_plane() {
         let mut app = App::new();
@@ -625,6 +806,146 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_enemy_movement_steers_around_rock() {
+        let mut app = App::new();
+        app.add_plugins(bevy::time::TimePlugin::default());
+        app.init_resource::<PlayerPosition>();
+        app.world_mut().resource_mut::<PlayerPosition>().0 = Vec2::new(10.0, 0.0);
+
+        // Rock sits on the straight line to the player, slightly to one side
+        app.world_mut().spawn((
+            Transform::from_translation(Vec3::new(2.0, 0.25, 0.2)),
+            Obstacle::aabb(Vec2::splat(0.5)),
+        ));
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+                Enemy {
+                    speed: 1.0,
+                    strength: 10.0,
+                },
+            ))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        let _ = app.world_mut().run_system_once(enemy_movement_system);
+
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert!(transform.translation.x > 0.0, "still closes in on the player");
+        assert!(transform.translation.z < 0.0, "veers away from the rock's side");
+    }
+
+    mod enemy_separation_tests {
+        use super::*;
+
+        fn spawn_enemy(app: &mut App, x: f32, z: f32) -> Entity {
+            app.world_mut()
+                .spawn((
+                    Transform::from_translation(Vec3::new(x, 0.5, z)),
+                    Enemy {
+                        speed: 3.0,
+                        strength: 10.0,
+                    },
+                ))
+                .id()
+        }
+
+        fn setup_app() -> App {
+            let mut app = App::new();
+            app.init_resource::<PlayerPosition>();
+            app.world_mut().resource_mut::<PlayerPosition>().0 = Vec2::new(50.0, 50.0);
+            app
+        }
+
+        fn xz(app: &App, entity: Entity) -> Vec2 {
+            from_xz(app.world().get::<Transform>(entity).unwrap().translation)
+        }
+
+        #[test]
+        fn overlapping_enemies_are_pushed_apart() {
+            let mut app = setup_app();
+            let a = spawn_enemy(&mut app, 0.0, 0.0);
+            let b = spawn_enemy(&mut app, 0.4, 0.0);
+
+            let _ = app.world_mut().run_system_once(enemy_separation_system);
+
+            assert!(xz(&app, a).x < 0.0);
+            assert!(xz(&app, b).x > 0.4);
+        }
+
+        #[test]
+        fn stacked_enemies_split_in_opposite_directions() {
+            let mut app = setup_app();
+            let a = spawn_enemy(&mut app, 3.0, 3.0);
+            let b = spawn_enemy(&mut app, 3.0, 3.0);
+
+            let _ = app.world_mut().run_system_once(enemy_separation_system);
+
+            let offset_a = xz(&app, a) - Vec2::splat(3.0);
+            let offset_b = xz(&app, b) - Vec2::splat(3.0);
+            assert!(offset_a.length() > 0.0);
+            assert!((offset_a + offset_b).length() < 0.0001);
+        }
+
+        #[test]
+        fn distant_enemies_are_untouched() {
+            let mut app = setup_app();
+            let a = spawn_enemy(&mut app, 0.0, 0.0);
+            let b = spawn_enemy(&mut app, 5.0, 0.0);
+
+            let _ = app.world_mut().run_system_once(enemy_separation_system);
+
+            assert_eq!(xz(&app, a), Vec2::ZERO);
+            assert_eq!(xz(&app, b), Vec2::new(5.0, 0.0));
+        }
+
+        #[test]
+        fn enemies_are_kept_out_of_the_player() {
+            let mut app = setup_app();
+            let enemy = spawn_enemy(&mut app, 50.3, 50.0);
+
+            let _ = app.world_mut().run_system_once(enemy_separation_system);
+
+            assert!(xz(&app, enemy).x > 50.3);
+        }
+
+        #[test]
+        fn knockback_propagates_to_enemies_ahead() {
+            let mut app = setup_app();
+            let pushed = spawn_enemy(&mut app, 0.0, 0.0);
+            let ahead = spawn_enemy(&mut app, 0.8, 0.0);
+            let behind = spawn_enemy(&mut app, -0.8, 0.0);
+            app.world_mut()
+                .entity_mut(pushed)
+                .insert(Knockback::new(Vec2::X, 300.0, 0.2));
+
+            let _ = app.world_mut().run_system_once(enemy_separation_system);
+
+            let transferred = app.world().get::<Knockback>(ahead).expect("shoved enemy is knocked back");
+            assert!((transferred.force() - 300.0 * KNOCKBACK_TRANSFER).abs() < 0.001);
+            assert_eq!(transferred.direction(), Vec2::X);
+            assert!(app.world().get::<Knockback>(behind).is_none());
+        }
+
+        #[test]
+        fn weak_knockback_stops_spreading() {
+            let mut app = setup_app();
+            let pushed = spawn_enemy(&mut app, 0.0, 0.0);
+            let ahead = spawn_enemy(&mut app, 0.8, 0.0);
+            app.world_mut()
+                .entity_mut(pushed)
+                .insert(Knockback::new(Vec2::X, MIN_PROPAGATED_KNOCKBACK, 0.2));
+
+            let _ = app.world_mut().run_system_once(enemy_separation_system);
+
+            assert!(app.world().get::<Knockback>(ahead).is_none());
+        }
+    }
+
     #[test]
     fn test_enemy_movement_respects_speed() {
         let mut app = App::new();
@@ -1241,6 +1562,77 @@ mod tests {
     }
 
     // ray_ground_intersection tests
+    fn spawn_test_player(app: &mut App, speed: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+                Player {
+                    speed,
+                    regen_rate: 0.0,
+                    pickup_radius: 0.0,
+                    last_movement_direction: Vec3::ZERO,
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_player_movement_follows_direction_intent() {
+        let mut app = App::new();
+        app.add_plugins(bevy::time::TimePlugin::default());
+        app.insert_resource(MovementIntent::Direction(Vec2::new(0.0, -1.0)));
+        let player = spawn_test_player(&mut app, 100.0);
+
+        {
+            let mut time = app.world_mut().get_resource_mut::<Time>().unwrap();
+            time.advance_by(Duration::from_millis(100));
+        }
+
+        let _ = app.world_mut().run_system_once(player_movement);
+
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert!((transform.translation.z + 10.0).abs() < 0.01);
+        assert_eq!(transform.translation.y, 0.5);
+        let player = app.world().get::<Player>(player).unwrap();
+        assert_eq!(player.last_movement_direction, Vec3::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn test_player_movement_walks_toward_click_target() {
+        let mut app = App::new();
+        app.add_plugins(bevy::time::TimePlugin::default());
+        app.insert_resource(MovementIntent::Toward(Vec2::new(50.0, 0.0)));
+        let player = spawn_test_player(&mut app, 100.0);
+
+        {
+            let mut time = app.world_mut().get_resource_mut::<Time>().unwrap();
+            time.advance_by(Duration::from_millis(100));
+        }
+
+        let _ = app.world_mut().run_system_once(player_movement);
+
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert!((transform.translation.x - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_player_movement_idle_intent_stays_put() {
+        let mut app = App::new();
+        app.add_plugins(bevy::time::TimePlugin::default());
+        app.init_resource::<MovementIntent>();
+        let player = spawn_test_player(&mut app, 100.0);
+
+        {
+            let mut time = app.world_mut().get_resource_mut::<Time>().unwrap();
+            time.advance_by(Duration::from_millis(100));
+        }
+
+        let _ = app.world_mut().run_system_once(player_movement);
+
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert_eq!(transform.translation, Vec3::new(0.0, 0.5, 0.0));
+    }
+
     #[test]
     fn test_ray_ground_intersection_basic() {
         use bevy::math::{Dir3, Ray3d};
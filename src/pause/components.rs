@@ -16,6 +16,14 @@ pub struct NewGameButton;
 #[derive(Component)]
 pub struct ExitGameButton;
 
+/// Marker for the button cycling the movement mode
+#[derive(Component)]
+pub struct MovementModeButton;
+
+/// Marker for the button opening the controls screen
+#[derive(Component)]
+pub struct ControlsButton;
+
 /// Marker for debug section container
 #[derive(Component)]
 pub struct DebugSection;
@@ -80,6 +88,16 @@ mod tests {
         let _button = ExitGameButton;
     }
 
+    #[test]
+    fn movement_mode_button_component_can_be_created() {
+        let _button = MovementModeButton;
+    }
+
+    #[test]
+    fn controls_button_component_can_be_created() {
+        let _button = ControlsButton;
+    }
+
     #[test]
     fn debug_section_component_can_be_created() {
         let _section = DebugSection;
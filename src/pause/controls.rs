@@ -0,0 +1,611 @@
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+
+use crate::input::bindings::{
+    ActionInput, Binding, BindingSlot, InputAction, InputMap, BINDABLE_GAMEPAD_BUTTONS,
+    BINDABLE_KEYS, BINDABLE_MOUSE_BUTTONS,
+};
+use crate::input::resources::MenuFocus;
+use crate::states::GameState;
+
+/// Rebind button background: idle, hovered, waiting for input, conflicting
+const SLOT_COLOR: Color = Color::srgba(0.15, 0.15, 0.2, 0.95);
+const SLOT_HOVER_COLOR: Color = Color::srgba(0.25, 0.25, 0.35, 0.95);
+const SLOT_LISTENING_COLOR: Color = Color::srgb(0.7, 0.55, 0.1);
+const SLOT_CONFLICT_COLOR: Color = Color::srgb(0.6, 0.15, 0.15);
+
+/// Footer button backgrounds
+const RESET_COLOR: Color = Color::srgb(0.2, 0.4, 0.7);
+const BACK_COLOR: Color = Color::srgb(0.6, 0.2, 0.2);
+const FOOTER_HOVER_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+
+/// Status line colors
+const STATUS_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+const STATUS_ERROR_COLOR: Color = Color::srgb(1.0, 0.4, 0.4);
+
+/// Root marker for the controls screen.
+/// Used for cleanup on state exit.
+#[derive(Component)]
+pub struct ControlsScreen;
+
+/// Button rebinding one slot of an action.
+#[derive(Component)]
+pub struct RebindButton {
+    pub action: InputAction,
+    pub slot: BindingSlot,
+}
+
+/// Button restoring the default bindings.
+#[derive(Component)]
+pub struct ResetControlsButton;
+
+/// Button returning to the pause menu.
+#[derive(Component)]
+pub struct ControlsBackButton;
+
+/// Feedback line for the last rebind attempt.
+#[derive(Component)]
+pub struct ControlsStatusText;
+
+/// Rebind in progress on the controls screen.
+#[derive(Resource, Debug, Default)]
+pub struct RebindCapture {
+    /// Slot waiting for the next key or button press
+    pub listening: Option<(InputAction, BindingSlot)>,
+    /// Set one frame after listening starts, so the press that opened
+    /// the capture isn't captured itself
+    pub armed: bool,
+    /// Feedback shown under the table
+    pub status: String,
+    /// Whether `status` reports a refused binding
+    pub error: bool,
+}
+
+impl RebindCapture {
+    fn finish(&mut self, status: String, error: bool) {
+        self.listening = None;
+        self.armed = false;
+        self.status = status;
+        self.error = error;
+    }
+}
+
+/// Text on a rebind button: the bound input, "..." while listening, "-" when empty
+pub fn slot_label(binding: Option<Binding>, listening: bool) -> String {
+    if listening {
+        "...".to_string()
+    } else {
+        binding.map(|binding| binding.label()).unwrap_or_else(|| "-".to_string())
+    }
+}
+
+/// First bindable input pressed this frame that fits `slot`.
+pub fn captured_binding<'a>(
+    slot: BindingSlot,
+    keyboard: &ButtonInput<KeyCode>,
+    mouse: Option<&ButtonInput<MouseButton>>,
+    gamepads: impl IntoIterator<Item = &'a Gamepad>,
+) -> Option<Binding> {
+    if slot == BindingSlot::Gamepad {
+        return gamepads.into_iter().find_map(|gamepad| {
+            BINDABLE_GAMEPAD_BUTTONS
+                .iter()
+                .find(|button| gamepad.just_pressed(**button))
+                .map(|button| Binding::Gamepad(*button))
+        });
+    }
+
+    BINDABLE_KEYS
+        .iter()
+        .find(|key| keyboard.just_pressed(**key))
+        .map(|key| Binding::Key(*key))
+        .or_else(|| {
+            let mouse = mouse?;
+            BINDABLE_MOUSE_BUTTONS
+                .iter()
+                .find(|button| mouse.just_pressed(**button))
+                .map(|button| Binding::Mouse(*button))
+        })
+}
+
+fn slot_color(input_map: &InputMap, capture: &RebindCapture, button: &RebindButton) -> Color {
+    let binding = input_map.bindings(button.action).get(button.slot);
+    if capture.listening == Some((button.action, button.slot)) {
+        SLOT_LISTENING_COLOR
+    } else if binding.is_some_and(|binding| {
+        input_map.conflicts().iter().any(|conflict| conflict.binding == binding)
+    }) {
+        SLOT_CONFLICT_COLOR
+    } else {
+        SLOT_COLOR
+    }
+}
+
+/// Sets up the controls screen: one row per action with primary, secondary
+/// and gamepad bindings.
+pub fn setup_controls_screen(
+    mut commands: Commands,
+    input_map: Res<InputMap>,
+    mut capture: ResMut<RebindCapture>,
+) {
+    *capture = RebindCapture::default();
+
+    commands
+        .spawn((
+            ControlsScreen,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("CONTROLS"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            parent.spawn((
+                Text::new("Select a binding, then press a key or button. Esc cancels, Delete clears."),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(STATUS_COLOR),
+                Node {
+                    margin: UiRect::bottom(Val::Px(12.0)),
+                    ..default()
+                },
+            ));
+
+            spawn_header_row(parent);
+            for action in InputAction::ALL {
+                spawn_action_row(parent, action, &input_map);
+            }
+
+            parent.spawn((
+                ControlsStatusText,
+                Text::new(""),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(STATUS_COLOR),
+                Node {
+                    margin: UiRect::vertical(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(15.0),
+                    ..default()
+                })
+                .with_children(|footer| {
+                    spawn_footer_button(footer, "Reset Defaults", RESET_COLOR, ResetControlsButton);
+                    spawn_footer_button(footer, "Back", BACK_COLOR, ControlsBackButton);
+                });
+        });
+}
+
+fn spawn_header_row(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|row| {
+            for (label, width) in [("Action", 180.0), ("Primary", 150.0), ("Secondary", 150.0), ("Gamepad", 150.0)] {
+                row.spawn((
+                    Text::new(label),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.3)),
+                    Node {
+                        width: Val::Px(width),
+                        ..default()
+                    },
+                ));
+            }
+        });
+}
+
+fn spawn_action_row(parent: &mut ChildSpawnerCommands, action: InputAction, input_map: &InputMap) {
+    let bindings = input_map.bindings(action);
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(action.label()),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    width: Val::Px(180.0),
+                    ..default()
+                },
+            ));
+            for slot in BindingSlot::ALL {
+                row.spawn((
+                    Button,
+                    RebindButton { action, slot },
+                    Node {
+                        width: Val::Px(150.0),
+                        height: Val::Px(32.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(SLOT_COLOR),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new(slot_label(bindings.get(slot), false)),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+}
+
+fn spawn_footer_button<T: Component>(parent: &mut ChildSpawnerCommands, label: &str, color: Color, marker: T) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(200.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(color),
+            marker,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Pause action (Escape by default) returns to the pause menu, unless a
+/// rebind is waiting for input (then it cancels the rebind instead).
+pub fn controls_back_input(
+    input: ActionInput,
+    capture: Res<RebindCapture>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if capture.listening.is_none() && input.just_pressed(InputAction::Pause) {
+        next_state.set(GameState::Paused);
+    }
+}
+
+/// Handles controls screen buttons. Presses are ignored while a rebind is
+/// waiting, since that click is the input being captured.
+#[allow(clippy::type_complexity)]
+pub fn handle_controls_buttons(
+    mut interaction_query: Query<
+        (
+            &Interaction,
+            &mut BackgroundColor,
+            Option<&RebindButton>,
+            Option<&ResetControlsButton>,
+            Option<&ControlsBackButton>,
+        ),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut capture: ResMut<RebindCapture>,
+    mut input_map: ResMut<InputMap>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, mut background_color, rebind, reset, back) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if capture.listening.is_some() {
+                    continue;
+                }
+                if let Some(rebind) = rebind {
+                    capture.listening = Some((rebind.action, rebind.slot));
+                    capture.armed = false;
+                    capture.status = format!("Press an input for {}...", rebind.action.label());
+                    capture.error = false;
+                } else if reset.is_some() {
+                    *input_map = InputMap::default();
+                    capture.status = "Controls reset to defaults".to_string();
+                    capture.error = false;
+                } else if back.is_some() {
+                    next_state.set(GameState::Paused);
+                }
+            }
+            Interaction::Hovered => {
+                if rebind.is_some() {
+                    *background_color = BackgroundColor(SLOT_HOVER_COLOR);
+                } else if reset.is_some() || back.is_some() {
+                    *background_color = BackgroundColor(FOOTER_HOVER_COLOR);
+                }
+            }
+            Interaction::None => {
+                if let Some(rebind) = rebind {
+                    *background_color = BackgroundColor(slot_color(&input_map, &capture, rebind));
+                } else if reset.is_some() {
+                    *background_color = BackgroundColor(RESET_COLOR);
+                } else if back.is_some() {
+                    *background_color = BackgroundColor(BACK_COLOR);
+                }
+            }
+        }
+    }
+}
+
+/// Captures the next key or button for the slot being rebound.
+/// Bindings used by another action are refused with a conflict message.
+pub fn capture_rebind_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    gamepads: Query<&Gamepad>,
+    mut capture: ResMut<RebindCapture>,
+    mut input_map: ResMut<InputMap>,
+    mut focus: ResMut<MenuFocus>,
+) {
+    focus.locked = capture.listening.is_some();
+    let Some((action, slot)) = capture.listening else {
+        return;
+    };
+    if !capture.armed {
+        capture.armed = true;
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        capture.finish("Rebind cancelled".to_string(), false);
+    } else if keyboard.just_pressed(KeyCode::Delete) {
+        input_map.clear(action, slot);
+        capture.finish(format!("{} cleared", action.label()), false);
+    } else if let Some(binding) = captured_binding(slot, &keyboard, mouse.as_deref(), gamepads) {
+        match input_map.rebind(action, slot, binding) {
+            Ok(()) => capture.finish(format!("{} bound to {}", action.label(), binding.label()), false),
+            Err(conflict) => capture.finish(conflict.to_string(), true),
+        }
+    } else if slot != BindingSlot::Gamepad && keyboard.get_just_pressed().next().is_some() {
+        capture.status = "That key can't be bound".to_string();
+        capture.error = true;
+    }
+
+    // Unlock as soon as the capture ends so the next frame navigates normally
+    focus.locked = capture.listening.is_some();
+}
+
+/// Refreshes binding labels, slot colors and the status line.
+#[allow(clippy::type_complexity)]
+pub fn update_controls_labels(
+    input_map: Res<InputMap>,
+    capture: Res<RebindCapture>,
+    mut buttons: Query<(&RebindButton, &Children, &Interaction, &mut BackgroundColor)>,
+    mut texts: Query<&mut Text, Without<ControlsStatusText>>,
+    mut status: Query<(&mut Text, &mut TextColor), With<ControlsStatusText>>,
+) {
+    if !input_map.is_changed() && !capture.is_changed() {
+        return;
+    }
+
+    for (button, children, interaction, mut background_color) in buttons.iter_mut() {
+        let listening = capture.listening == Some((button.action, button.slot));
+        let label = slot_label(input_map.bindings(button.action).get(button.slot), listening);
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                if text.0 != label {
+                    text.0 = label.clone();
+                }
+            }
+        }
+        if *interaction != Interaction::Hovered {
+            *background_color = BackgroundColor(slot_color(&input_map, &capture, button));
+        }
+    }
+
+    for (mut text, mut color) in status.iter_mut() {
+        text.0 = capture.status.clone();
+        color.0 = if capture.error { STATUS_ERROR_COLOR } else { STATUS_COLOR };
+    }
+}
+
+/// Cleans up the controls screen and any unfinished rebind.
+pub fn cleanup_controls_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<ControlsScreen>>,
+    mut capture: ResMut<RebindCapture>,
+    mut focus: ResMut<MenuFocus>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+    *capture = RebindCapture::default();
+    focus.locked = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::app::App;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<ButtonInput<MouseButton>>();
+        app.init_resource::<InputMap>();
+        app.init_resource::<RebindCapture>();
+        app.init_resource::<MenuFocus>();
+        app.add_systems(
+            Update,
+            (
+                controls_back_input,
+                handle_controls_buttons,
+                capture_rebind_input,
+                update_controls_labels,
+            )
+                .chain(),
+        );
+        app
+    }
+
+    fn rebind_button(app: &mut App, action: InputAction, slot: BindingSlot) -> Entity {
+        app.world_mut()
+            .query::<(Entity, &RebindButton)>()
+            .iter(app.world())
+            .find(|(_, button)| button.action == action && button.slot == slot)
+            .map(|(entity, _)| entity)
+            .expect("rebind button exists")
+    }
+
+    fn press_key(app: &mut App, key: KeyCode) {
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(key);
+        app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(key);
+        keyboard.clear();
+        app.update();
+    }
+
+    #[test]
+    fn setup_creates_a_button_per_action_and_slot() {
+        let mut app = setup_test_app();
+        let _ = app.world_mut().run_system_once(setup_controls_screen);
+
+        let count = app
+            .world_mut()
+            .query::<&RebindButton>()
+            .iter(app.world())
+            .count();
+        assert_eq!(count, InputAction::ALL.len() * BindingSlot::ALL.len());
+    }
+
+    #[test]
+    fn slot_label_shows_binding_listening_or_empty() {
+        assert_eq!(slot_label(Some(Binding::Key(KeyCode::KeyW)), false), "W");
+        assert_eq!(slot_label(Some(Binding::Key(KeyCode::KeyW)), true), "...");
+        assert_eq!(slot_label(None, false), "-");
+    }
+
+    #[test]
+    fn captured_binding_respects_slot_device() {
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(KeyCode::KeyK);
+        let mut mouse = ButtonInput::<MouseButton>::default();
+        mouse.press(MouseButton::Middle);
+
+        assert_eq!(
+            captured_binding(BindingSlot::Primary, &keyboard, Some(&mouse), []),
+            Some(Binding::Key(KeyCode::KeyK))
+        );
+        assert_eq!(
+            captured_binding(BindingSlot::Primary, &ButtonInput::default(), Some(&mouse), []),
+            Some(Binding::Mouse(MouseButton::Middle))
+        );
+        assert_eq!(captured_binding(BindingSlot::Gamepad, &keyboard, Some(&mouse), []), None);
+    }
+
+    #[test]
+    fn rebinding_captures_the_next_key() {
+        let mut app = setup_test_app();
+        let _ = app.world_mut().run_system_once(setup_controls_screen);
+        let button = rebind_button(&mut app, InputAction::Inventory, BindingSlot::Primary);
+
+        app.world_mut().entity_mut(button).insert(Interaction::Pressed);
+        app.update();
+        assert!(app.world().resource::<MenuFocus>().locked);
+
+        press_key(&mut app, KeyCode::KeyB);
+
+        let map = app.world().resource::<InputMap>();
+        assert_eq!(map.bindings(InputAction::Inventory).primary, Some(Binding::Key(KeyCode::KeyB)));
+        let capture = app.world().resource::<RebindCapture>();
+        assert!(capture.listening.is_none());
+        assert!(!capture.error);
+        assert!(!app.world().resource::<MenuFocus>().locked);
+
+        let children = app.world().get::<Children>(button).unwrap();
+        assert_eq!(app.world().get::<Text>(children[0]).unwrap().0, "B");
+    }
+
+    #[test]
+    fn conflicting_key_is_refused() {
+        let mut app = setup_test_app();
+        let _ = app.world_mut().run_system_once(setup_controls_screen);
+        let button = rebind_button(&mut app, InputAction::Inventory, BindingSlot::Primary);
+
+        app.world_mut().entity_mut(button).insert(Interaction::Pressed);
+        app.update();
+        press_key(&mut app, KeyCode::KeyW);
+
+        let map = app.world().resource::<InputMap>();
+        assert_eq!(map.bindings(InputAction::Inventory).primary, Some(Binding::Key(KeyCode::KeyI)));
+        let capture = app.world().resource::<RebindCapture>();
+        assert!(capture.error);
+        assert_eq!(capture.status, "W is already bound to Move Up");
+    }
+
+    #[test]
+    fn escape_cancels_rebind_without_leaving_screen() {
+        let mut app = setup_test_app();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Controls);
+        app.update();
+        let _ = app.world_mut().run_system_once(setup_controls_screen);
+        let button = rebind_button(&mut app, InputAction::Pause, BindingSlot::Primary);
+
+        app.world_mut().entity_mut(button).insert(Interaction::Pressed);
+        app.update();
+        press_key(&mut app, KeyCode::Escape);
+
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Controls);
+        assert_eq!(app.world().resource::<InputMap>().bindings(InputAction::Pause), InputAction::Pause.default_bindings());
+        assert_eq!(app.world().resource::<RebindCapture>().status, "Rebind cancelled");
+    }
+
+    #[test]
+    fn escape_returns_to_pause_menu_when_idle() {
+        let mut app = setup_test_app();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Controls);
+        app.update();
+
+        press_key(&mut app, KeyCode::Escape);
+
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Paused);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod components;
+pub mod controls;
 pub mod plugin;
 pub mod systems;
 
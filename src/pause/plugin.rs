@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use crate::input::bindings::InputMap;
+use crate::input::resources::{MenuFocus, MovementMode};
+use crate::pause::controls::*;
 use crate::pause::components::{SpellCooldownsVisible, WallLightsEnabled};
 use crate::pause::systems::*;
 use crate::states::GameState;
@@ -7,6 +10,10 @@ use crate::states::GameState;
 pub fn plugin(app: &mut App) {
     app.init_resource::<WallLightsEnabled>()
         .init_resource::<SpellCooldownsVisible>()
+        .init_resource::<MovementMode>()
+        .init_resource::<InputMap>()
+        .init_resource::<MenuFocus>()
+        .init_resource::<RebindCapture>()
         // ESC key to enter pause from InGame
         .add_systems(
             Update,
@@ -26,7 +33,21 @@ pub fn plugin(app: &mut App) {
                 .run_if(in_state(GameState::Paused)),
         )
         // Cleanup when exiting Paused state
-        .add_systems(OnExit(GameState::Paused), cleanup_pause_menu);
+        .add_systems(OnExit(GameState::Paused), cleanup_pause_menu)
+        // Controls screen opened from the pause menu
+        .add_systems(OnEnter(GameState::Controls), setup_controls_screen)
+        .add_systems(
+            Update,
+            (
+                controls_back_input,
+                handle_controls_buttons,
+                capture_rebind_input,
+                update_controls_labels,
+            )
+                .chain()
+                .run_if(in_state(GameState::Controls)),
+        )
+        .add_systems(OnExit(GameState::Controls), cleanup_controls_screen);
 }
 
 #[cfg(test)]
@@ -3,6 +3,8 @@ use bevy::prelude::*;
 
 use crate::arena::components::TorchLight;
 use crate::enemies::components::Enemy;
+use crate::input::bindings::{ActionInput, InputAction};
+use crate::input::resources::MovementMode;
 use crate::loot::components::DroppedItem;
 use crate::pause::components::*;
 use crate::states::GameState;
@@ -14,6 +16,7 @@ const BUTTON_GREEN: Color = Color::srgb(0.2, 0.6, 0.2);
 const BUTTON_BLUE: Color = Color::srgb(0.2, 0.4, 0.7);
 const BUTTON_RED: Color = Color::srgb(0.6, 0.2, 0.2);
 const BUTTON_ORANGE: Color = Color::srgb(0.7, 0.4, 0.1);
+const BUTTON_PURPLE: Color = Color::srgb(0.45, 0.25, 0.6);
 const BUTTON_HOVER: Color = Color::srgb(0.4, 0.4, 0.4);
 
 /// Sets up the pause menu UI
@@ -22,6 +25,7 @@ pub fn setup_pause_menu(
     debug_visible: Res<DebugHudVisible>,
     wall_lights_enabled: Res<WallLightsEnabled>,
     spell_cooldowns_visible: Res<SpellCooldownsVisible>,
+    movement_mode: Res<MovementMode>,
 ) {
     // Create pause menu UI root with semi-transparent overlay
     commands
@@ -64,6 +68,17 @@ pub fn setup_pause_menu(
                     // Continue button
                     spawn_menu_button(menu, "Continue", BUTTON_GREEN, ContinueButton);
 
+                    // Movement mode setting (cycles on press)
+                    spawn_menu_button(
+                        menu,
+                        &movement_mode_label(*movement_mode),
+                        BUTTON_PURPLE,
+                        MovementModeButton,
+                    );
+
+                    // Controls screen (rebinding)
+                    spawn_menu_button(menu, "Controls", BUTTON_PURPLE, ControlsButton);
+
                     // New Game button
                     spawn_menu_button(menu, "New Game", BUTTON_BLUE, NewGameButton);
 
@@ -128,6 +143,11 @@ pub fn setup_pause_menu(
         });
 }
 
+/// Label for the movement mode button, e.g. "Move: Both"
+pub fn movement_mode_label(mode: MovementMode) -> String {
+    format!("Move: {}", mode.label())
+}
+
 /// Helper to spawn a main menu button
 fn spawn_menu_button<T: Component>(parent: &mut ChildSpawnerCommands, label: &str, color: Color, marker: T) {
     parent
@@ -192,13 +212,16 @@ pub fn pause_menu_interactions(
             Option<&ContinueButton>,
             Option<&NewGameButton>,
             Option<&ExitGameButton>,
+            Option<&MovementModeButton>,
+            Option<&ControlsButton>,
         ),
         (Changed<Interaction>, With<Button>),
     >,
     mut next_state: ResMut<NextState<GameState>>,
     mut app_exit: MessageWriter<AppExit>,
+    mut movement_mode: ResMut<MovementMode>,
 ) {
-    for (interaction, mut background_color, continue_btn, new_game_btn, exit_btn) in
+    for (interaction, mut background_color, continue_btn, new_game_btn, exit_btn, movement_btn, controls_btn) in
         &mut interaction_query
     {
         match *interaction {
@@ -210,6 +233,10 @@ pub fn pause_menu_interactions(
                     next_state.set(GameState::Intro);
                 } else if exit_btn.is_some() {
                     app_exit.write(AppExit::Success);
+                } else if movement_btn.is_some() {
+                    *movement_mode = movement_mode.next();
+                } else if controls_btn.is_some() {
+                    next_state.set(GameState::Controls);
                 }
             }
             Interaction::Hovered => {
@@ -222,6 +249,8 @@ pub fn pause_menu_interactions(
                     *background_color = BackgroundColor(BUTTON_BLUE);
                 } else if exit_btn.is_some() {
                     *background_color = BackgroundColor(BUTTON_RED);
+                } else if movement_btn.is_some() || controls_btn.is_some() {
+                    *background_color = BackgroundColor(BUTTON_PURPLE);
                 }
             }
         }
@@ -308,6 +337,8 @@ pub fn update_toggle_button_text(
     spell_cooldowns_visible: Res<SpellCooldownsVisible>,
     lights_btn_query: Query<&Children, With<ToggleWallLightsButton>>,
     cooldowns_btn_query: Query<&Children, With<ToggleSpellCooldownsButton>>,
+    movement_mode: Res<MovementMode>,
+    movement_btn_query: Query<&Children, With<MovementModeButton>>,
     mut text_query: Query<&mut Text>,
 ) {
     // Update wall lights button text
@@ -333,24 +364,36 @@ pub fn update_toggle_button_text(
             }
         }
     }
+
+    // Update movement mode button text
+    let movement_label = movement_mode_label(*movement_mode);
+    for children in movement_btn_query.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                if text.0 != movement_label {
+                    text.0 = movement_label.clone();
+                }
+            }
+        }
+    }
 }
 
-/// Handles ESC key to resume game from pause
+/// Handles the Pause action (ESC or gamepad Start by default) to resume game from pause
 pub fn pause_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if input.just_pressed(InputAction::Pause) {
         next_state.set(GameState::InGame);
     }
 }
 
-/// Handles ESC key to pause the game from InGame state
+/// Handles the Pause action to pause the game from InGame state
 pub fn enter_pause_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if input.just_pressed(InputAction::Pause) {
         next_state.set(GameState::Paused);
     }
 }
@@ -380,6 +423,8 @@ mod tests {
         app.init_resource::<DebugHudVisible>();
         app.init_resource::<WallLightsEnabled>();
         app.init_resource::<SpellCooldownsVisible>();
+        app.init_resource::<MovementMode>();
+        app.init_resource::<crate::input::bindings::InputMap>();
         app
     }
 
@@ -511,4 +556,27 @@ mod tests {
         assert_eq!(after_count, 0, "PauseMenu should be removed after cleanup");
     }
 
+    #[test]
+    fn movement_mode_button_cycles_mode_and_label() {
+        let mut app = setup_test_app();
+        app.add_message::<AppExit>();
+        app.world_mut().insert_resource(MovementMode::Direct);
+
+        let _ = app.world_mut().run_system_once(setup_pause_menu);
+
+        let button = app
+            .world_mut()
+            .query_filtered::<Entity, With<MovementModeButton>>()
+            .single(app.world())
+            .expect("Should create MovementModeButton");
+        app.world_mut().entity_mut(button).insert(Interaction::Pressed);
+
+        let _ = app.world_mut().run_system_once(pause_menu_interactions);
+        let _ = app.world_mut().run_system_once(update_toggle_button_text);
+
+        assert_eq!(*app.world().resource::<MovementMode>(), MovementMode::Both);
+        let children = app.world().get::<Children>(button).unwrap();
+        let text = app.world().get::<Text>(children[0]).unwrap();
+        assert_eq!(text.0, movement_mode_label(MovementMode::Both));
+    }
 }
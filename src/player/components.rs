@@ -14,6 +14,30 @@ pub struct Player {
     pub last_movement_direction: Vec3,
 }
 
+/// Flat armor the player starts every run with
+pub const PLAYER_BASE_ARMOR: f32 = 1.0;
+
+/// Player stats before in-run powerups are applied.
+/// Raised by meta shop upgrades when a fresh run starts.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PlayerBaseStats {
+    pub max_health: f32,
+    pub regen_rate: f32,
+    pub pickup_radius: f32,
+    pub speed: f32,
+}
+
+impl Default for PlayerBaseStats {
+    fn default() -> Self {
+        Self {
+            max_health: 100.0,
+            regen_rate: 1.0,
+            pickup_radius: 2.0, // 3D world units (was 50 pixels)
+            speed: 7.0,         // 3D world units/sec (was 200 pixels/sec)
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct SlowModifier {
     pub remaining_duration: f32,
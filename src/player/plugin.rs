@@ -5,6 +5,7 @@ use crate::player::components::{
     Player, PlayerAnimationState, PlayerAnimations, PlayerModel, PlayerSpotlight,
 };
 use crate::game::sets::GameSet;
+use crate::input::resources::MovementIntent;
 use crate::states::GameState;
 
 /// Marker component for the player's AnimationPlayer entity
@@ -111,12 +112,12 @@ pub fn setup_animation_player(
 
 /// Switches player animation based on movement state
 pub fn update_player_animation(
-    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    intent: Res<MovementIntent>,
     animations: Res<PlayerAnimations>,
     mut player_query: Query<&mut PlayerAnimationState, With<Player>>,
     mut animation_players: Query<&mut AnimationPlayer, With<PlayerAnimationPlayer>>,
 ) {
-    let is_moving = mouse_button_input.pressed(MouseButton::Left);
+    let is_moving = intent.is_moving();
 
     for mut anim_state in player_query.iter_mut() {
         let new_state = if is_moving {
@@ -167,7 +168,7 @@ pub fn rotate_player_model(
 }
 
 pub fn plugin(app: &mut App) {
-    app
+    app.init_resource::<MovementIntent>()
         // Setup animations when entering the game
         .add_systems(
             OnEnter(GameState::InGame),
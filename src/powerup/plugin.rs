@@ -2,11 +2,13 @@ use bevy::prelude::*;
 use crate::states::*;
 use crate::powerup::systems::*;
 use crate::powerup::components::ActivePowerups;
+use crate::game::resources::GameRng;
 
 pub fn plugin(app: &mut App) {
     app
         .init_resource::<ActivePowerups>()
         .init_resource::<SpellFireRateMultiplier>()
+        .init_resource::<GameRng>()
         .add_systems(
             Update,
             (
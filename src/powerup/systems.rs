@@ -4,7 +4,7 @@ use crate::combat::components::Health;
 use crate::powerup::components::*;
 use crate::player::components::*;
 use crate::game::events::EnemyDeathEvent;
-use crate::game::resources::{GameMeshes, GameMaterials};
+use crate::game::resources::{GameMeshes, GameMaterials, GameRng};
 use crate::loot::components::{DroppedItem, ItemData, PickupState};
 use crate::loot::systems::LOOT_LARGE_Y_HEIGHT;
 
@@ -16,6 +16,7 @@ pub fn powerup_spawning_system(
     mut enemy_death_events: MessageReader<EnemyDeathEvent>,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
+    mut rng: ResMut<GameRng>,
 ) {
     let (Some(game_meshes), Some(game_materials)) = (game_meshes, game_materials) else {
         return;
@@ -25,7 +26,7 @@ pub fn powerup_spawning_system(
         let enemy_pos = event.position;
 
         // 2% chance to drop a powerup
-        if rand::thread_rng().gen_bool(0.02) {
+        if rng.gen_bool(0.02) {
             // Randomly select one of the 5 powerup types
             let powerup_types = [
                 PowerupType::MaxHealth,
@@ -35,7 +36,7 @@ pub fn powerup_spawning_system(
                 PowerupType::MovementSpeed,
             ];
 
-            let selected_type = powerup_types[rand::thread_rng().gen_range(0..powerup_types.len())].clone();
+            let selected_type = powerup_types[rng.gen_range(0..powerup_types.len())].clone();
 
             // Spawn the powerup as a DroppedItem so it uses the loot pickup system
             // with popup animation and magnetic attraction (emissive material provides glow via bloom)
@@ -76,14 +77,11 @@ pub fn powerup_pulse_system(
 /// System to apply powerup effects to the player
 pub fn apply_player_powerup_effects(
     active_powerups: Res<ActivePowerups>,
-    mut player_query: Query<(&mut Player, &mut Health)>,
+    mut player_query: Query<(&mut Player, &mut Health, Option<&PlayerBaseStats>)>,
 ) {
-    if let Ok((mut player, mut health)) = player_query.single_mut() {
-        // Calculate base values (3D world units)
-        let base_max_health = 100.0;
-        let base_regen_rate = 1.0;
-        let base_pickup_radius = 2.0; // 3D world units (was 50 pixels)
-        let base_speed = 7.0; // 3D world units/sec (was 200 pixels/sec)
+    if let Ok((mut player, mut health, base_stats)) = player_query.single_mut() {
+        // Base values (3D world units), raised by meta upgrades when present
+        let base = base_stats.copied().unwrap_or_default();
 
         // Apply permanent powerup effects
         let max_health_stacks = active_powerups.get_stack_count(&PowerupType::MaxHealth);
@@ -97,10 +95,10 @@ pub fn apply_player_powerup_effects(
         let pickup_multiplier = 1.0 + (pickup_stacks as f32 * 0.25);
         let speed_multiplier = 1.0 + (speed_stacks as f32 * 0.25);
 
-        health.max = base_max_health * max_health_multiplier;
-        player.regen_rate = base_regen_rate * regen_multiplier;
-        player.pickup_radius = base_pickup_radius * pickup_multiplier;
-        player.speed = base_speed * speed_multiplier;
+        health.max = base.max_health * max_health_multiplier;
+        player.regen_rate = base.regen_rate * regen_multiplier;
+        player.pickup_radius = base.pickup_radius * pickup_multiplier;
+        player.speed = base.speed * speed_multiplier;
 
         // Ensure health doesn't exceed new max
         if health.current > health.max {
@@ -151,6 +151,40 @@ mod tests {
         assert_eq!(player.speed, 8.75, "Speed should be 7.0 * 1.25 = 8.75");
     }
 
+    #[test]
+    fn test_player_powerup_effects_build_on_base_stats() {
+        let mut app = App::new();
+        app.init_resource::<ActivePowerups>();
+        app.add_systems(Update, apply_player_powerup_effects);
+
+        let player_entity = app.world_mut().spawn((
+            Player {
+                speed: 7.0,
+                regen_rate: 1.0,
+                pickup_radius: 2.0,
+                last_movement_direction: Vec3::ZERO,
+            },
+            Health::new(120.0),
+            PlayerBaseStats {
+                max_health: 120.0,
+                regen_rate: 1.0,
+                pickup_radius: 3.0,
+                speed: 8.0,
+            },
+        )).id();
+        app.world_mut()
+            .resource_mut::<ActivePowerups>()
+            .add_powerup(PowerupType::MovementSpeed);
+
+        app.update();
+
+        let player = app.world().get::<Player>(player_entity).unwrap();
+        let health = app.world().get::<Health>(player_entity).unwrap();
+        assert_eq!(health.max, 120.0, "Meta max health is kept without powerups");
+        assert_eq!(player.pickup_radius, 3.0);
+        assert_eq!(player.speed, 10.0, "Speed should be 8.0 * 1.25 = 10.0");
+    }
+
     #[test]
     fn test_spell_powerup_effects() {
         let mut app = App::new();
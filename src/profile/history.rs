@@ -0,0 +1,387 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::element::Element;
+use crate::game::resources::LevelStats;
+use crate::inventory::resources::SpellList;
+use crate::profile::storage::user_data_dir;
+use crate::spell::SpellType;
+use crate::spell::definitions::SpellCatalog;
+
+/// Run history file name inside the data directory
+pub const RUN_HISTORY_FILE_NAME: &str = "run_history.ron";
+
+/// Header row of the CSV export, one row per level played
+pub const RUN_HISTORY_CSV_HEADER: &str = "run,seed,attunement,level_reached,survival_secs,kills,spells,level,level_time_secs,level_kills,level_xp";
+
+/// A spell equipped when the run ended
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpellRecord {
+    pub spell: SpellType,
+    pub level: u32,
+}
+
+/// Stats of one level played during a run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LevelRecord {
+    pub level: u32,
+    pub time_secs: f32,
+    pub kills: u32,
+    pub xp: u32,
+}
+
+impl LevelRecord {
+    pub fn new(level: u32, stats: &LevelStats) -> Self {
+        Self {
+            level,
+            time_secs: stats.time_elapsed,
+            kills: stats.enemies_killed,
+            xp: stats.xp_gained,
+        }
+    }
+}
+
+/// A finished run, appended to the run history when the game ends.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// Seed to replay the run with `--seed`
+    pub seed: u64,
+    pub attunement: Option<Element>,
+    /// Equipped spells in slot order
+    pub spells: Vec<SpellRecord>,
+    /// `GameLevel` the run ended on
+    pub level_reached: u32,
+    pub survival_secs: f32,
+    pub kills: u32,
+    /// `LevelStats` of every level played, the last one cut short by death
+    pub levels: Vec<LevelRecord>,
+}
+
+impl RunRecord {
+    /// Loadout snapshot of the equipped spells
+    pub fn spells_from(spell_list: &SpellList) -> Vec<SpellRecord> {
+        spell_list
+            .iter_spells()
+            .map(|(_, spell)| SpellRecord {
+                spell: spell.spell_type,
+                level: spell.level,
+            })
+            .collect()
+    }
+}
+
+/// Levels completed so far in the current run.
+/// Cleared when a fresh run starts.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct RunTimeline(pub Vec<LevelRecord>);
+
+/// Why the run history could not be read or written.
+#[derive(Debug)]
+pub enum HistoryError {
+    /// The file could not be read or written
+    Io { path: PathBuf, source: std::io::Error },
+    /// A line of the file is not a valid run record
+    Corrupt { path: PathBuf, line: usize, reason: String },
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Io { path, source } => {
+                write!(f, "could not access run history {}: {}", path.display(), source)
+            }
+            HistoryError::Corrupt { path, line, reason } => write!(
+                f,
+                "run history {} is corrupt at line {}: {}",
+                path.display(),
+                line,
+                reason
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HistoryError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Parse run history contents: one RON run record per line, blank lines ignored.
+pub fn parse_run_history(contents: &str, path: &Path) -> Result<Vec<RunRecord>, HistoryError> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            ron::from_str(line).map_err(|err| HistoryError::Corrupt {
+                path: path.to_path_buf(),
+                line: index + 1,
+                reason: err.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Serialize a run as a single history line (without the newline).
+pub fn serialize_run(run: &RunRecord) -> String {
+    ron::to_string(run).expect("RunRecord always serializes")
+}
+
+/// Quote a CSV field if it contains a separator, quote or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Format runs as CSV with one row per level played. Run columns repeat on
+/// every row of the run; a run without levels gets a single row with empty
+/// level columns.
+pub fn runs_to_csv(runs: &[RunRecord], catalog: &SpellCatalog) -> String {
+    let mut csv = String::from(RUN_HISTORY_CSV_HEADER);
+    csv.push('\n');
+
+    for (index, run) in runs.iter().enumerate() {
+        let spells = run
+            .spells
+            .iter()
+            .map(|record| format!("{}:{}", record.spell.name(catalog), record.level))
+            .collect::<Vec<_>>()
+            .join(";");
+        let run_columns = [
+            (index + 1).to_string(),
+            run.seed.to_string(),
+            run.attunement.map_or(String::new(), |element| element.name().to_string()),
+            run.level_reached.to_string(),
+            format!("{:.1}", run.survival_secs),
+            run.kills.to_string(),
+            spells,
+        ]
+        .iter()
+        .map(|value| csv_field(value))
+        .collect::<Vec<_>>()
+        .join(",");
+
+        if run.levels.is_empty() {
+            csv.push_str(&format!("{},,,,\n", run_columns));
+        }
+        for level in &run.levels {
+            csv.push_str(&format!(
+                "{},{},{:.1},{},{}\n",
+                run_columns, level.level, level.time_secs, level.kills, level.xp
+            ));
+        }
+    }
+    csv
+}
+
+/// Where finished runs are appended. Without a path (the default, used by
+/// tests) runs are not recorded.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RunHistoryStorage {
+    pub path: Option<PathBuf>,
+}
+
+impl RunHistoryStorage {
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+        }
+    }
+
+    /// History file next to the profile in the platform data directory
+    pub fn in_user_data_dir() -> Self {
+        Self {
+            path: user_data_dir().map(|dir| dir.join(RUN_HISTORY_FILE_NAME)),
+        }
+    }
+
+    /// Load every recorded run, oldest first. Empty when there is no file yet.
+    pub fn load(&self) -> Result<Vec<RunRecord>, HistoryError> {
+        let Some(path) = &self.path else {
+            return Ok(Vec::new());
+        };
+        match fs::read_to_string(path) {
+            Ok(contents) => parse_run_history(&contents, path),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(source) => Err(HistoryError::Io {
+                path: path.clone(),
+                source,
+            }),
+        }
+    }
+
+    /// Append a run as one line, so earlier runs are never rewritten.
+    pub fn append(&self, run: &RunRecord) -> Result<(), HistoryError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let io_error = |source| HistoryError::Io {
+            path: path.clone(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_error)?;
+        writeln!(file, "{}", serialize_run(run)).map_err(io_error)
+    }
+
+    /// Write every recorded run to `csv_path` as CSV, naming spells from
+    /// `catalog`. Returns the number of runs.
+    pub fn export_csv(&self, csv_path: &Path, catalog: &SpellCatalog) -> Result<usize, HistoryError> {
+        let runs = self.load()?;
+        fs::write(csv_path, runs_to_csv(&runs, catalog)).map_err(|source| HistoryError::Io {
+            path: csv_path.to_path_buf(),
+            source,
+        })?;
+        Ok(runs.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_run(seed: u64) -> RunRecord {
+        RunRecord {
+            seed,
+            attunement: Some(Element::Fire),
+            spells: vec![
+                SpellRecord {
+                    spell: SpellType::Fireball,
+                    level: 3,
+                },
+                SpellRecord {
+                    spell: SpellType::IceShard,
+                    level: 1,
+                },
+            ],
+            level_reached: 2,
+            survival_secs: 95.5,
+            kills: 40,
+            levels: vec![
+                LevelRecord {
+                    level: 1,
+                    time_secs: 60.0,
+                    kills: 30,
+                    xp: 300,
+                },
+                LevelRecord {
+                    level: 2,
+                    time_secs: 35.5,
+                    kills: 10,
+                    xp: 120,
+                },
+            ],
+        }
+    }
+
+    /// Fresh scratch directory for file tests
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("dts-history-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    mod parse_run_history_tests {
+        use super::*;
+
+        #[test]
+        fn round_trips_one_run_per_line() {
+            let runs = vec![sample_run(1), sample_run(2)];
+            let contents = runs.iter().map(serialize_run).collect::<Vec<_>>().join("\n\n");
+
+            let parsed = parse_run_history(&contents, Path::new(RUN_HISTORY_FILE_NAME)).unwrap();
+            assert_eq!(parsed, runs);
+            assert!(!serialize_run(&runs[0]).contains('\n'));
+        }
+
+        #[test]
+        fn corrupt_line_is_reported_with_its_number() {
+            let contents = format!("{}\nnot a run", serialize_run(&sample_run(1)));
+            let err = parse_run_history(&contents, Path::new(RUN_HISTORY_FILE_NAME)).unwrap_err();
+            assert!(matches!(err, HistoryError::Corrupt { line: 2, .. }));
+            assert!(err.to_string().contains("line 2"));
+        }
+    }
+
+    mod csv_tests {
+        use super::*;
+
+        #[test]
+        fn one_row_per_level_with_run_columns() {
+            let csv = runs_to_csv(&[sample_run(7)], SpellCatalog::built_in());
+            let lines: Vec<_> = csv.lines().collect();
+
+            assert_eq!(lines[0], RUN_HISTORY_CSV_HEADER);
+            assert_eq!(lines[1], "1,7,Fire,2,95.5,40,Fireball:3;Ice Shard:1,1,60.0,30,300");
+            assert_eq!(lines[2], "1,7,Fire,2,95.5,40,Fireball:3;Ice Shard:1,2,35.5,10,120");
+            assert_eq!(lines.len(), 3);
+        }
+
+        #[test]
+        fn run_without_levels_keeps_a_row() {
+            let run = RunRecord {
+                attunement: None,
+                spells: Vec::new(),
+                levels: Vec::new(),
+                ..sample_run(3)
+            };
+            let csv = runs_to_csv(&[run], SpellCatalog::built_in());
+            assert_eq!(csv.lines().nth(1), Some("1,3,,2,95.5,40,,,,,"));
+        }
+
+        #[test]
+        fn fields_with_commas_are_quoted() {
+            assert_eq!(csv_field("a,b"), "\"a,b\"");
+            assert_eq!(csv_field("say \"hi\", ok"), "\"say \"\"hi\"\", ok\"");
+            assert_eq!(csv_field("plain"), "plain");
+        }
+    }
+
+    mod run_history_storage_tests {
+        use super::*;
+
+        #[test]
+        fn in_memory_storage_records_nothing() {
+            let storage = RunHistoryStorage::default();
+            assert!(storage.append(&sample_run(1)).is_ok());
+            assert!(storage.load().unwrap().is_empty());
+        }
+
+        #[test]
+        fn appends_runs_and_exports_csv() {
+            let dir = scratch_dir("append");
+            let storage = RunHistoryStorage::at(dir.join(RUN_HISTORY_FILE_NAME));
+            assert!(storage.load().unwrap().is_empty());
+
+            storage.append(&sample_run(1)).unwrap();
+            storage.append(&sample_run(2)).unwrap();
+            assert_eq!(storage.load().unwrap(), vec![sample_run(1), sample_run(2)]);
+
+            let csv_path = dir.join("runs.csv");
+            assert_eq!(storage.export_csv(&csv_path, SpellCatalog::built_in()).unwrap(), 2);
+            let csv = fs::read_to_string(&csv_path).unwrap();
+            assert_eq!(csv.lines().count(), 5);
+
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+}
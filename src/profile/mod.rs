@@ -0,0 +1,11 @@
+pub mod history;
+pub mod plugin;
+pub mod resources;
+pub mod storage;
+pub mod systems;
+
+pub use history::*;
+pub use plugin::*;
+pub use resources::*;
+pub use storage::*;
+pub use systems::*;
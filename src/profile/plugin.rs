@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::game::systems::reset_game_level;
+use crate::profile::history::{RunHistoryStorage, RunTimeline};
+use crate::profile::resources::{LastRunReward, MetaProfile};
+use crate::profile::storage::ProfileStorage;
+use crate::profile::systems::*;
+use crate::states::GameState;
+
+/// Meta-progression plugin. The profile is kept in memory unless a
+/// `ProfileStorage` with a path is inserted before the plugin (the game uses
+/// the user data dir), in which case it is loaded at startup and saved at the
+/// end of every level and run. A run ends on the game over screen or when it
+/// is abandoned from the pause menu; both count towards the profile. Finished
+/// runs are likewise appended to the run history only when a
+/// `RunHistoryStorage` with a path is inserted.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<ProfileStorage>()
+        .init_resource::<RunHistoryStorage>()
+        .init_resource::<MetaProfile>()
+        .init_resource::<LastRunReward>()
+        .init_resource::<RunTimeline>()
+        .add_systems(Startup, load_profile_system)
+        .add_systems(
+            OnEnter(GameState::InGame),
+            reset_run_timeline_system.before(reset_game_level),
+        )
+        .add_systems(
+            Update,
+            discover_spells_system.run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            OnEnter(GameState::LevelComplete),
+            (save_profile_system, record_level_timeline_system),
+        )
+        .add_systems(
+            OnEnter(GameState::GameOver),
+            (record_run_system, save_profile_system, append_run_history_system).chain(),
+        )
+        // "New Game" from the pause menu abandons the run through the intro
+        .add_systems(
+            OnTransition {
+                exited: GameState::Paused,
+                entered: GameState::Intro,
+            },
+            (record_run_system, save_profile_system, append_run_history_system).chain(),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_defaults_to_in_memory_profile() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+        app.update();
+
+        assert!(app.world().resource::<ProfileStorage>().path.is_none());
+        assert_eq!(*app.world().resource::<MetaProfile>(), MetaProfile::default());
+    }
+
+    fn set_state(app: &mut App, state: GameState) {
+        app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+        app.update();
+    }
+
+    #[test]
+    fn run_abandoned_from_pause_is_recorded() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.init_resource::<crate::score::Score>();
+        app.init_resource::<crate::game::resources::RunSurvivalTime>();
+        app.init_resource::<crate::game::resources::GameLevel>();
+        app.init_resource::<crate::game::resources::GameRng>();
+        app.init_resource::<crate::game::resources::LevelStats>();
+        app.add_plugins(plugin);
+        app.update();
+
+        app.world_mut().resource_mut::<crate::game::resources::GameLevel>().total_kills = 30;
+        set_state(&mut app, GameState::Paused);
+        assert_eq!(app.world().resource::<MetaProfile>().runs_played, 0);
+
+        set_state(&mut app, GameState::Intro);
+        let profile = app.world().resource::<MetaProfile>();
+        assert_eq!(profile.runs_played, 1);
+        assert_eq!(profile.total_kills, 30);
+
+        // Leaving the intro for the meta shop and back is not a run
+        set_state(&mut app, GameState::MetaShop);
+        set_state(&mut app, GameState::Intro);
+        assert_eq!(app.world().resource::<MetaProfile>().runs_played, 1);
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::element::Element;
+use crate::player::components::PlayerBaseStats;
+use crate::spell::SpellType;
+
+/// Current profile file format version. Bump it whenever the saved format
+/// changes; `validate_profile` rejects every other version until it learns to
+/// migrate them.
+pub const PROFILE_VERSION: u32 = 1;
+
+/// Display name of the persistent currency
+pub const CURRENCY_NAME: &str = "Souls";
+
+/// Kills needed to earn one unit of currency
+pub const KILLS_PER_CURRENCY: u32 = 10;
+
+/// Seconds survived needed to earn one unit of currency
+pub const SECONDS_PER_CURRENCY: f32 = 30.0;
+
+/// Currency earned by a run
+pub fn run_currency(kills: u32, survival_secs: f32) -> u32 {
+    kills / KILLS_PER_CURRENCY + (survival_secs.max(0.0) / SECONDS_PER_CURRENCY) as u32
+}
+
+/// Max health bonus per `MetaUpgrade::MaxHealth` level
+pub const MAX_HEALTH_PER_UPGRADE: f32 = 0.10;
+
+/// Movement speed bonus per `MetaUpgrade::MoveSpeed` level
+pub const MOVE_SPEED_PER_UPGRADE: f32 = 0.05;
+
+/// Pickup radius bonus per `MetaUpgrade::PickupRadius` level
+pub const PICKUP_RADIUS_PER_UPGRADE: f32 = 0.15;
+
+/// Permanent upgrades bought in the meta shop with currency.
+/// Applied when a fresh run starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum MetaUpgrade {
+    MaxHealth,
+    MoveSpeed,
+    PickupRadius,
+    /// One more spell equipped when the player attunes
+    StartingSpell,
+    /// One more level-up reroll per run
+    RerollToken,
+}
+
+impl MetaUpgrade {
+    /// All upgrades in shop order
+    pub fn all() -> &'static [MetaUpgrade; 5] {
+        &[
+            MetaUpgrade::MaxHealth,
+            MetaUpgrade::MoveSpeed,
+            MetaUpgrade::PickupRadius,
+            MetaUpgrade::StartingSpell,
+            MetaUpgrade::RerollToken,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetaUpgrade::MaxHealth => "Vitality",
+            MetaUpgrade::MoveSpeed => "Swiftness",
+            MetaUpgrade::PickupRadius => "Magnetism",
+            MetaUpgrade::StartingSpell => "Spell Slot",
+            MetaUpgrade::RerollToken => "Reroll Token",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            MetaUpgrade::MaxHealth => "+10% starting max health",
+            MetaUpgrade::MoveSpeed => "+5% base movement speed",
+            MetaUpgrade::PickupRadius => "+15% pickup radius",
+            MetaUpgrade::StartingSpell => "Start with an extra spell equipped",
+            MetaUpgrade::RerollToken => "Reroll level-up choices once per run",
+        }
+    }
+
+    pub fn max_level(&self) -> u32 {
+        match self {
+            MetaUpgrade::MaxHealth | MetaUpgrade::MoveSpeed | MetaUpgrade::PickupRadius => 5,
+            MetaUpgrade::StartingSpell => 2,
+            MetaUpgrade::RerollToken => 3,
+        }
+    }
+
+    fn base_cost(&self) -> u32 {
+        match self {
+            MetaUpgrade::MaxHealth | MetaUpgrade::PickupRadius => 5,
+            MetaUpgrade::MoveSpeed => 8,
+            MetaUpgrade::StartingSpell => 25,
+            MetaUpgrade::RerollToken => 10,
+        }
+    }
+
+    /// Price of the next level when `current_level` is owned, None once maxed.
+    /// Each level costs one base cost more than the last.
+    pub fn cost(&self, current_level: u32) -> Option<u32> {
+        (current_level < self.max_level()).then(|| self.base_cost() * (current_level + 1))
+    }
+}
+
+/// Why a meta shop purchase was refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaseError {
+    MaxLevel,
+    InsufficientCurrency { cost: u32, available: u32 },
+}
+
+impl fmt::Display for PurchaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PurchaseError::MaxLevel => write!(f, "upgrade is already at max level"),
+            PurchaseError::InsufficientCurrency { cost, available } => {
+                write!(f, "costs {} {} but only {} available", cost, CURRENCY_NAME, available)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PurchaseError {}
+
+/// Outcome of a finished run, folded into the profile.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunSummary {
+    pub score: u32,
+    pub survival_secs: f32,
+    pub kills: u32,
+}
+
+/// Meta-progression that persists between runs (see `ProfileStorage`).
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetaProfile {
+    /// Format version; files written before versioning read as 0
+    #[serde(default)]
+    pub version: u32,
+    pub best_score: u32,
+    pub longest_survival_secs: f32,
+    pub total_kills: u64,
+    pub runs_played: u32,
+    /// Persistent currency earned from kills and survival
+    pub currency: u32,
+    pub spells_discovered: BTreeSet<SpellType>,
+    pub elements_discovered: BTreeSet<Element>,
+    /// Owned meta shop upgrade levels (missing = level 0)
+    pub upgrades: BTreeMap<MetaUpgrade, u32>,
+}
+
+impl Default for MetaProfile {
+    fn default() -> Self {
+        Self {
+            version: PROFILE_VERSION,
+            best_score: 0,
+            longest_survival_secs: 0.0,
+            total_kills: 0,
+            runs_played: 0,
+            currency: 0,
+            spells_discovered: BTreeSet::new(),
+            elements_discovered: BTreeSet::new(),
+            upgrades: BTreeMap::new(),
+        }
+    }
+}
+
+impl MetaProfile {
+    /// Record a finished run. Returns the currency it earned.
+    pub fn record_run(&mut self, run: RunSummary) -> u32 {
+        self.runs_played += 1;
+        self.best_score = self.best_score.max(run.score);
+        self.longest_survival_secs = self.longest_survival_secs.max(run.survival_secs);
+        self.total_kills += run.kills as u64;
+
+        let earned = run_currency(run.kills, run.survival_secs);
+        self.currency = self.currency.saturating_add(earned);
+        earned
+    }
+
+    /// Mark a spell (and its element) as discovered.
+    /// Returns true if either was new.
+    pub fn discover_spell(&mut self, spell_type: SpellType) -> bool {
+        let new_spell = self.spells_discovered.insert(spell_type);
+        let new_element = self.elements_discovered.insert(spell_type.element());
+        new_spell || new_element
+    }
+
+    /// Owned level of a meta upgrade
+    pub fn upgrade_level(&self, upgrade: MetaUpgrade) -> u32 {
+        self.upgrades.get(&upgrade).copied().unwrap_or(0)
+    }
+
+    /// Spend currency on the next level of an upgrade. Returns the new level.
+    pub fn purchase_upgrade(&mut self, upgrade: MetaUpgrade) -> Result<u32, PurchaseError> {
+        let level = self.upgrade_level(upgrade);
+        let cost = upgrade.cost(level).ok_or(PurchaseError::MaxLevel)?;
+        if self.currency < cost {
+            return Err(PurchaseError::InsufficientCurrency {
+                cost,
+                available: self.currency,
+            });
+        }
+
+        self.currency -= cost;
+        self.upgrades.insert(upgrade, level + 1);
+        Ok(level + 1)
+    }
+
+    /// Player stats a fresh run starts from, before in-run powerups
+    pub fn player_base_stats(&self) -> PlayerBaseStats {
+        let defaults = PlayerBaseStats::default();
+        let bonus = |upgrade: MetaUpgrade, per_level: f32| {
+            1.0 + self.upgrade_level(upgrade) as f32 * per_level
+        };
+        PlayerBaseStats {
+            max_health: defaults.max_health * bonus(MetaUpgrade::MaxHealth, MAX_HEALTH_PER_UPGRADE),
+            speed: defaults.speed * bonus(MetaUpgrade::MoveSpeed, MOVE_SPEED_PER_UPGRADE),
+            pickup_radius: defaults.pickup_radius
+                * bonus(MetaUpgrade::PickupRadius, PICKUP_RADIUS_PER_UPGRADE),
+            ..defaults
+        }
+    }
+}
+
+/// Meta upgrade levels in effect for the current run, snapshotted from the
+/// profile when a fresh run starts.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct RunUpgrades(pub BTreeMap<MetaUpgrade, u32>);
+
+impl RunUpgrades {
+    pub fn level(&self, upgrade: MetaUpgrade) -> u32 {
+        self.0.get(&upgrade).copied().unwrap_or(0)
+    }
+}
+
+/// Currency earned by the last finished run (shown on the game over screen).
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LastRunReward(pub u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod run_currency_tests {
+        use super::*;
+
+        #[test]
+        fn currency_from_kills_and_survival() {
+            assert_eq!(run_currency(0, 0.0), 0);
+            assert_eq!(run_currency(25, 0.0), 2);
+            assert_eq!(run_currency(0, 95.0), 3);
+            assert_eq!(run_currency(10, 30.0), 2);
+        }
+
+        #[test]
+        fn negative_survival_earns_nothing() {
+            assert_eq!(run_currency(0, -50.0), 0);
+        }
+    }
+
+    mod meta_profile_tests {
+        use super::*;
+
+        #[test]
+        fn default_profile_uses_current_version() {
+            let profile = MetaProfile::default();
+            assert_eq!(profile.version, PROFILE_VERSION);
+            assert_eq!(profile.currency, 0);
+        }
+
+        #[test]
+        fn record_run_keeps_bests_and_accumulates() {
+            let mut profile = MetaProfile::default();
+            let earned = profile.record_run(RunSummary {
+                score: 50,
+                survival_secs: 120.0,
+                kills: 40,
+            });
+            assert_eq!(earned, 8);
+            profile.record_run(RunSummary {
+                score: 20,
+                survival_secs: 200.0,
+                kills: 10,
+            });
+
+            assert_eq!(profile.runs_played, 2);
+            assert_eq!(profile.best_score, 50);
+            assert_eq!(profile.longest_survival_secs, 200.0);
+            assert_eq!(profile.total_kills, 50);
+            assert_eq!(profile.currency, 8 + 7);
+        }
+
+        #[test]
+        fn discover_spell_records_spell_and_element() {
+            let mut profile = MetaProfile::default();
+            assert!(profile.discover_spell(SpellType::Fireball));
+            assert!(!profile.discover_spell(SpellType::Fireball));
+            assert!(profile.spells_discovered.contains(&SpellType::Fireball));
+            assert!(profile.elements_discovered.contains(&Element::Fire));
+        }
+    }
+
+    mod meta_upgrade_tests {
+        use super::*;
+
+        #[test]
+        fn cost_rises_per_level_until_max() {
+            let upgrade = MetaUpgrade::MaxHealth;
+            assert_eq!(upgrade.cost(0), Some(5));
+            assert_eq!(upgrade.cost(1), Some(10));
+            assert_eq!(upgrade.cost(upgrade.max_level()), None);
+        }
+
+        #[test]
+        fn purchase_spends_currency_and_levels_up() {
+            let mut profile = MetaProfile {
+                currency: 20,
+                ..default()
+            };
+            assert_eq!(profile.purchase_upgrade(MetaUpgrade::MaxHealth), Ok(1));
+            assert_eq!(profile.purchase_upgrade(MetaUpgrade::MaxHealth), Ok(2));
+            assert_eq!(profile.currency, 5);
+            assert_eq!(profile.upgrade_level(MetaUpgrade::MaxHealth), 2);
+        }
+
+        #[test]
+        fn purchase_refused_without_enough_currency() {
+            let mut profile = MetaProfile {
+                currency: 3,
+                ..default()
+            };
+            assert_eq!(
+                profile.purchase_upgrade(MetaUpgrade::MoveSpeed),
+                Err(PurchaseError::InsufficientCurrency { cost: 8, available: 3 })
+            );
+            assert_eq!(profile.currency, 3);
+            assert_eq!(profile.upgrade_level(MetaUpgrade::MoveSpeed), 0);
+        }
+
+        #[test]
+        fn purchase_refused_at_max_level() {
+            let mut profile = MetaProfile {
+                currency: 1000,
+                ..default()
+            };
+            profile.upgrades.insert(MetaUpgrade::StartingSpell, 2);
+            assert_eq!(
+                profile.purchase_upgrade(MetaUpgrade::StartingSpell),
+                Err(PurchaseError::MaxLevel)
+            );
+            assert_eq!(profile.currency, 1000);
+        }
+
+        #[test]
+        fn base_stats_scale_with_upgrades() {
+            let mut profile = MetaProfile::default();
+            assert_eq!(profile.player_base_stats(), PlayerBaseStats::default());
+
+            profile.upgrades.insert(MetaUpgrade::MaxHealth, 2);
+            profile.upgrades.insert(MetaUpgrade::PickupRadius, 1);
+            let stats = profile.player_base_stats();
+            let defaults = PlayerBaseStats::default();
+            assert!((stats.max_health - defaults.max_health * 1.2).abs() < 0.001);
+            assert!((stats.pickup_radius - defaults.pickup_radius * 1.15).abs() < 0.001);
+            assert_eq!(stats.speed, defaults.speed);
+            assert_eq!(stats.regen_rate, defaults.regen_rate);
+        }
+    }
+}
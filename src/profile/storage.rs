@@ -0,0 +1,318 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+
+use crate::profile::resources::{MetaProfile, PROFILE_VERSION};
+
+/// Directory name used under the platform data directory
+pub const DATA_DIR_NAME: &str = "donny-tango-survivor";
+
+/// Profile file name inside the data directory
+pub const PROFILE_FILE_NAME: &str = "profile.ron";
+
+/// Why a profile file could not be loaded or saved.
+#[derive(Debug)]
+pub enum ProfileError {
+    /// The file exists but could not be read or written
+    Io { path: PathBuf, source: std::io::Error },
+    /// The file is not a valid profile
+    Corrupt { path: PathBuf, reason: String },
+    /// The file's format version is not the one this build reads
+    UnsupportedVersion { path: PathBuf, found: u32 },
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::Io { path, source } => {
+                write!(f, "could not access profile {}: {}", path.display(), source)
+            }
+            ProfileError::Corrupt { path, reason } => {
+                write!(f, "profile {} is corrupt: {}", path.display(), reason)
+            }
+            ProfileError::UnsupportedVersion { path, found } => write!(
+                f,
+                "profile {} has version {} but this build only supports version {}",
+                path.display(),
+                found,
+                PROFILE_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ProfileError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Check a parsed profile is in the current format with usable values.
+/// There are no older formats to migrate yet, so any other version is rejected.
+pub fn validate_profile(profile: MetaProfile, path: &Path) -> Result<MetaProfile, ProfileError> {
+    if profile.version != PROFILE_VERSION {
+        return Err(ProfileError::UnsupportedVersion {
+            path: path.to_path_buf(),
+            found: profile.version,
+        });
+    }
+
+    if !profile.longest_survival_secs.is_finite() || profile.longest_survival_secs < 0.0 {
+        return Err(ProfileError::Corrupt {
+            path: path.to_path_buf(),
+            reason: format!("invalid longest survival {}", profile.longest_survival_secs),
+        });
+    }
+
+    Ok(profile)
+}
+
+/// Parse and validate profile file contents.
+pub fn parse_profile(contents: &str, path: &Path) -> Result<MetaProfile, ProfileError> {
+    let profile: MetaProfile = ron::from_str(contents).map_err(|err| ProfileError::Corrupt {
+        path: path.to_path_buf(),
+        reason: err.to_string(),
+    })?;
+    validate_profile(profile, path)
+}
+
+/// Serialize a profile for writing to disk.
+pub fn serialize_profile(profile: &MetaProfile) -> String {
+    ron::ser::to_string_pretty(profile, ron::ser::PrettyConfig::default())
+        .expect("MetaProfile always serializes")
+}
+
+/// Platform data directory for the game, e.g. `~/.local/share/donny-tango-survivor`.
+pub fn user_data_dir() -> Option<PathBuf> {
+    let env_dir = |name: &str| std::env::var_os(name).filter(|v| !v.is_empty()).map(PathBuf::from);
+
+    let base = if cfg!(target_os = "windows") {
+        env_dir("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME").map(|home| home.join("Library/Application Support"))
+    } else {
+        env_dir("XDG_DATA_HOME").or_else(|| env_dir("HOME").map(|home| home.join(".local/share")))
+    };
+    base.map(|dir| dir.join(DATA_DIR_NAME))
+}
+
+/// Where the profile is persisted. Without a path (the default, used by tests)
+/// the profile only lives in memory.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ProfileStorage {
+    pub path: Option<PathBuf>,
+}
+
+impl ProfileStorage {
+    pub fn at(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+        }
+    }
+
+    /// Profile file in the platform data directory
+    pub fn in_user_data_dir() -> Self {
+        Self {
+            path: user_data_dir().map(|dir| dir.join(PROFILE_FILE_NAME)),
+        }
+    }
+
+    /// Load the profile. `Ok(None)` when there is no file yet.
+    pub fn load(&self) -> Result<Option<MetaProfile>, ProfileError> {
+        let Some(path) = &self.path else {
+            return Ok(None);
+        };
+        match fs::read_to_string(path) {
+            Ok(contents) => parse_profile(&contents, path).map(Some),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(ProfileError::Io {
+                path: path.clone(),
+                source,
+            }),
+        }
+    }
+
+    /// Save the profile, writing a temporary file first so a crash mid-write
+    /// never leaves a truncated profile behind.
+    pub fn save(&self, profile: &MetaProfile) -> Result<(), ProfileError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let io_error = |source| ProfileError::Io {
+            path: path.clone(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let tmp_path = path.with_extension("ron.tmp");
+        fs::write(&tmp_path, serialize_profile(profile)).map_err(io_error)?;
+        fs::rename(&tmp_path, path).map_err(io_error)
+    }
+
+    /// Move an unreadable profile aside so it isn't overwritten by the next
+    /// save. Returns the backup path.
+    pub fn quarantine(&self) -> Option<PathBuf> {
+        let path = self.path.as_ref()?;
+        let backup = path.with_extension("ron.rejected");
+        fs::rename(path, &backup).ok()?;
+        Some(backup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::resources::{MetaUpgrade, RunSummary};
+    use crate::spell::SpellType;
+
+    fn test_path() -> PathBuf {
+        PathBuf::from("profile.ron")
+    }
+
+    /// Fresh scratch directory for file tests
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("dts-profile-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    mod parse_profile_tests {
+        use super::*;
+
+        #[test]
+        fn round_trips_through_ron() {
+            let mut profile = MetaProfile::default();
+            profile.record_run(RunSummary {
+                score: 12,
+                survival_secs: 64.0,
+                kills: 30,
+            });
+            profile.discover_spell(SpellType::IceShard);
+            profile.upgrades.insert(MetaUpgrade::RerollToken, 2);
+
+            let parsed = parse_profile(&serialize_profile(&profile), &test_path()).unwrap();
+            assert_eq!(parsed, profile);
+        }
+
+        #[test]
+        fn unversioned_file_is_rejected() {
+            let err = parse_profile("(best_score: 40, currency: 3)", &test_path()).unwrap_err();
+            assert!(matches!(err, ProfileError::UnsupportedVersion { found: 0, .. }));
+        }
+
+        #[test]
+        fn missing_fields_default_in_current_version() {
+            let contents = format!("(version: {}, best_score: 40)", PROFILE_VERSION);
+            let parsed = parse_profile(&contents, &test_path()).unwrap();
+            assert_eq!(parsed.best_score, 40);
+            assert_eq!(parsed.total_kills, 0);
+        }
+
+        #[test]
+        fn profile_without_upgrades_loads_with_none_owned() {
+            let contents = format!("(version: {}, currency: 12)", PROFILE_VERSION);
+            let parsed = parse_profile(&contents, &test_path()).unwrap();
+            assert_eq!(parsed.currency, 12);
+            assert!(parsed.upgrades.is_empty());
+        }
+
+        #[test]
+        fn newer_version_is_rejected() {
+            let contents = format!("(version: {})", PROFILE_VERSION + 1);
+            let err = parse_profile(&contents, &test_path()).unwrap_err();
+            assert!(matches!(err, ProfileError::UnsupportedVersion { found, .. } if found == PROFILE_VERSION + 1));
+            assert!(err.to_string().contains("only supports version"));
+        }
+
+        #[test]
+        fn garbage_is_rejected_as_corrupt() {
+            let err = parse_profile("not a profile {{", &test_path()).unwrap_err();
+            assert!(matches!(err, ProfileError::Corrupt { .. }));
+            assert!(err.to_string().contains("corrupt"));
+        }
+
+        #[test]
+        fn invalid_values_are_rejected_as_corrupt() {
+            let err = parse_profile("(version: 1, longest_survival_secs: -5.0)", &test_path()).unwrap_err();
+            assert!(matches!(err, ProfileError::Corrupt { .. }));
+        }
+    }
+
+    mod profile_storage_tests {
+        use super::*;
+        use bevy::ecs::system::RunSystemOnce;
+
+        #[test]
+        fn in_memory_storage_loads_nothing_and_saves_nowhere() {
+            let storage = ProfileStorage::default();
+            assert!(storage.load().unwrap().is_none());
+            assert!(storage.save(&MetaProfile::default()).is_ok());
+        }
+
+        #[test]
+        fn missing_file_loads_as_none() {
+            let storage = ProfileStorage::at(scratch_dir("missing").join(PROFILE_FILE_NAME));
+            assert!(storage.load().unwrap().is_none());
+        }
+
+        #[test]
+        fn save_then_load_round_trips() {
+            let dir = scratch_dir("roundtrip");
+            let storage = ProfileStorage::at(dir.join(PROFILE_FILE_NAME));
+            let profile = MetaProfile {
+                currency: 42,
+                ..default()
+            };
+
+            storage.save(&profile).unwrap();
+            assert_eq!(storage.load().unwrap(), Some(profile));
+
+            let _ = fs::remove_dir_all(dir);
+        }
+
+        #[test]
+        fn unknown_version_is_quarantined_on_load() {
+            let dir = scratch_dir("old-version");
+            fs::create_dir_all(&dir).unwrap();
+            let path = dir.join(PROFILE_FILE_NAME);
+            fs::write(&path, "(version: 0, currency: 7)").unwrap();
+            let storage = ProfileStorage::at(&path);
+
+            let mut app = App::new();
+            app.insert_resource(storage);
+            app.init_resource::<MetaProfile>();
+            let _ = app
+                .world_mut()
+                .run_system_once(crate::profile::systems::load_profile_system);
+
+            assert_eq!(*app.world().resource::<MetaProfile>(), MetaProfile::default());
+            assert!(!path.exists());
+            assert!(path.with_extension("ron.rejected").exists());
+
+            let _ = fs::remove_dir_all(dir);
+        }
+
+        #[test]
+        fn quarantine_moves_rejected_file_aside() {
+            let dir = scratch_dir("quarantine");
+            fs::create_dir_all(&dir).unwrap();
+            let path = dir.join(PROFILE_FILE_NAME);
+            fs::write(&path, "garbage").unwrap();
+            let storage = ProfileStorage::at(&path);
+
+            assert!(storage.load().is_err());
+            let backup = storage.quarantine().unwrap();
+            assert!(backup.exists());
+            assert!(!path.exists());
+
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use bevy::prelude::*;
+
+use crate::game::resources::{FreshGameStart, GameLevel, GameRng, LevelStats, RunSurvivalTime};
+use crate::inventory::bag::InventoryBag;
+use crate::inventory::resources::SpellList;
+use crate::profile::history::{LevelRecord, RunHistoryStorage, RunRecord, RunTimeline};
+use crate::profile::resources::{LastRunReward, MetaProfile, RunSummary};
+use crate::profile::storage::ProfileStorage;
+use crate::score::Score;
+use crate::whisper::resources::WhisperAttunement;
+
+/// Load the saved profile at startup. Unreadable profiles are reported,
+/// moved aside and replaced by a fresh one instead of crashing the game.
+pub fn load_profile_system(storage: Res<ProfileStorage>, mut profile: ResMut<MetaProfile>) {
+    match storage.load() {
+        Ok(Some(loaded)) => *profile = loaded,
+        Ok(None) => {}
+        Err(err) => {
+            error!("{}; starting with a fresh profile", err);
+            if let Some(backup) = storage.quarantine() {
+                warn!("Rejected profile moved to {}", backup.display());
+            }
+        }
+    }
+}
+
+/// Write the profile to disk, logging (not panicking) on failure.
+pub fn save_profile_system(storage: Res<ProfileStorage>, profile: Res<MetaProfile>) {
+    if let Err(err) = storage.save(&profile) {
+        error!("Failed to save profile: {}", err);
+    }
+}
+
+/// Record every spell the player has equipped or carries as discovered.
+pub fn discover_spells_system(
+    spell_list: Option<Res<SpellList>>,
+    inventory_bag: Option<Res<InventoryBag>>,
+    mut profile: ResMut<MetaProfile>,
+) {
+    let mut owned: Vec<_> = spell_list
+        .iter()
+        .flat_map(|list| list.iter_spells().map(|(_, spell)| spell.spell_type))
+        .collect();
+    if let Some(bag) = inventory_bag.as_ref() {
+        owned.extend(bag.iter().map(|(_, spell)| spell.spell_type));
+    }
+
+    for spell_type in owned {
+        // Only touch the profile for new discoveries to keep change detection quiet
+        if !profile.spells_discovered.contains(&spell_type) {
+            profile.discover_spell(spell_type);
+        }
+    }
+}
+
+/// Fold the finished run into the profile when the game ends.
+pub fn record_run_system(
+    score: Res<Score>,
+    survival_time: Res<RunSurvivalTime>,
+    game_level: Res<GameLevel>,
+    mut profile: ResMut<MetaProfile>,
+    mut last_reward: ResMut<LastRunReward>,
+) {
+    let earned = profile.record_run(RunSummary {
+        score: score.0,
+        survival_secs: survival_time.0,
+        kills: game_level.total_kills,
+    });
+    last_reward.0 = earned;
+}
+
+/// Clear the level timeline when a fresh run starts
+/// (must run before `reset_game_level` clears the fresh flag).
+pub fn reset_run_timeline_system(
+    fresh_start: Res<FreshGameStart>,
+    mut timeline: ResMut<RunTimeline>,
+) {
+    if fresh_start.0 {
+        timeline.0.clear();
+    }
+}
+
+/// Add the level that was just completed to the run's timeline.
+/// `GameLevel` has already advanced, so the completed level is the previous one.
+pub fn record_level_timeline_system(
+    game_level: Res<GameLevel>,
+    level_stats: Res<LevelStats>,
+    mut timeline: ResMut<RunTimeline>,
+) {
+    let completed_level = game_level.level.saturating_sub(1).max(1);
+    timeline.0.push(LevelRecord::new(completed_level, &level_stats));
+}
+
+/// Append the finished run to the run history, logging (not panicking) on failure.
+#[allow(clippy::too_many_arguments)]
+pub fn append_run_history_system(
+    storage: Res<RunHistoryStorage>,
+    rng: Res<GameRng>,
+    attunement: Option<Res<WhisperAttunement>>,
+    spell_list: Option<Res<SpellList>>,
+    game_level: Res<GameLevel>,
+    survival_time: Res<RunSurvivalTime>,
+    level_stats: Res<LevelStats>,
+    timeline: Res<RunTimeline>,
+) {
+    let mut levels = timeline.0.clone();
+    levels.push(LevelRecord::new(game_level.level, &level_stats));
+
+    let run = RunRecord {
+        seed: rng.seed(),
+        attunement: attunement.and_then(|attunement| attunement.element()),
+        spells: spell_list.map_or_else(Vec::new, |list| RunRecord::spells_from(&list)),
+        level_reached: game_level.level,
+        survival_secs: survival_time.0,
+        kills: game_level.total_kills,
+        levels,
+    };
+    if let Err(err) = storage.append(&run) {
+        error!("Failed to record run history: {}", err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::storage::{serialize_profile, PROFILE_FILE_NAME};
+    use crate::spell::{Spell, SpellType};
+    use bevy::ecs::system::RunSystemOnce;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("dts-profile-sys-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    mod load_profile_system_tests {
+        use super::*;
+
+        #[test]
+        fn loads_saved_profile() {
+            let dir = scratch_dir("load");
+            let path = dir.join(PROFILE_FILE_NAME);
+            let saved = MetaProfile {
+                currency: 17,
+                ..default()
+            };
+            fs::write(&path, serialize_profile(&saved)).unwrap();
+
+            let mut app = App::new();
+            app.insert_resource(ProfileStorage::at(&path));
+            app.init_resource::<MetaProfile>();
+            let _ = app.world_mut().run_system_once(load_profile_system);
+
+            assert_eq!(app.world().resource::<MetaProfile>().currency, 17);
+            let _ = fs::remove_dir_all(dir);
+        }
+
+        #[test]
+        fn corrupt_profile_falls_back_to_fresh_and_is_kept_aside() {
+            let dir = scratch_dir("corrupt");
+            let path = dir.join(PROFILE_FILE_NAME);
+            fs::write(&path, "(currency: \"lots\")").unwrap();
+
+            let mut app = App::new();
+            app.insert_resource(ProfileStorage::at(&path));
+            app.init_resource::<MetaProfile>();
+            let _ = app.world_mut().run_system_once(load_profile_system);
+
+            assert_eq!(*app.world().resource::<MetaProfile>(), MetaProfile::default());
+            assert!(!path.exists(), "Rejected file should not be overwritten by the next save");
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+
+    mod record_run_system_tests {
+        use super::*;
+
+        #[test]
+        fn records_run_and_reward() {
+            let mut app = App::new();
+            app.insert_resource(Score(30));
+            app.insert_resource(RunSurvivalTime(90.0));
+            let mut game_level = GameLevel::new();
+            game_level.total_kills = 20;
+            app.insert_resource(game_level);
+            app.init_resource::<MetaProfile>();
+            app.init_resource::<LastRunReward>();
+
+            let _ = app.world_mut().run_system_once(record_run_system);
+
+            let profile = app.world().resource::<MetaProfile>();
+            assert_eq!(profile.runs_played, 1);
+            assert_eq!(profile.best_score, 30);
+            assert_eq!(profile.total_kills, 20);
+            assert_eq!(profile.currency, 5);
+            assert_eq!(app.world().resource::<LastRunReward>().0, 5);
+        }
+    }
+
+    mod run_history_system_tests {
+        use super::*;
+        use crate::element::Element;
+        use crate::profile::history::{SpellRecord, RUN_HISTORY_FILE_NAME};
+
+        fn level_stats(time_elapsed: f32, enemies_killed: u32, xp_gained: u32) -> LevelStats {
+            LevelStats {
+                time_elapsed,
+                enemies_killed,
+                xp_gained,
+            }
+        }
+
+        #[test]
+        fn completed_levels_are_added_to_timeline() {
+            let mut app = App::new();
+            let mut game_level = GameLevel::new();
+            game_level.level = 2;
+            app.insert_resource(game_level);
+            app.insert_resource(level_stats(60.0, 30, 300));
+            app.init_resource::<RunTimeline>();
+
+            let _ = app.world_mut().run_system_once(record_level_timeline_system);
+
+            let timeline = app.world().resource::<RunTimeline>();
+            assert_eq!(timeline.0, vec![LevelRecord::new(1, &level_stats(60.0, 30, 300))]);
+        }
+
+        #[test]
+        fn timeline_is_only_cleared_on_fresh_start() {
+            let mut app = App::new();
+            app.insert_resource(RunTimeline(vec![LevelRecord::new(1, &LevelStats::new())]));
+            app.insert_resource(FreshGameStart(false));
+
+            let _ = app.world_mut().run_system_once(reset_run_timeline_system);
+            assert_eq!(app.world().resource::<RunTimeline>().0.len(), 1);
+
+            app.insert_resource(FreshGameStart(true));
+            let _ = app.world_mut().run_system_once(reset_run_timeline_system);
+            assert!(app.world().resource::<RunTimeline>().0.is_empty());
+        }
+
+        #[test]
+        fn finished_run_is_appended_with_final_level() {
+            let dir = scratch_dir("history");
+            let storage = RunHistoryStorage::at(dir.join(RUN_HISTORY_FILE_NAME));
+
+            let mut app = App::new();
+            app.insert_resource(storage.clone());
+            app.insert_resource(GameRng::from_seed(42));
+            app.insert_resource(WhisperAttunement::with_element(Element::Frost));
+            let mut spell_list = SpellList::default();
+            let mut fireball = Spell::new(SpellType::Fireball);
+            fireball.level = 4;
+            spell_list.equip(fireball);
+            app.insert_resource(spell_list);
+            let mut game_level = GameLevel::new();
+            game_level.level = 2;
+            game_level.total_kills = 45;
+            app.insert_resource(game_level);
+            app.insert_resource(RunSurvivalTime(100.0));
+            app.insert_resource(level_stats(40.0, 15, 90));
+            app.insert_resource(RunTimeline(vec![LevelRecord::new(1, &level_stats(60.0, 30, 300))]));
+
+            let _ = app.world_mut().run_system_once(append_run_history_system);
+
+            let runs = storage.load().unwrap();
+            assert_eq!(runs.len(), 1);
+            let run = &runs[0];
+            assert_eq!(run.seed, 42);
+            assert_eq!(run.attunement, Some(Element::Frost));
+            assert_eq!(run.spells, vec![SpellRecord { spell: SpellType::Fireball, level: 4 }]);
+            assert_eq!(run.level_reached, 2);
+            assert_eq!(run.survival_secs, 100.0);
+            assert_eq!(run.kills, 45);
+            let levels: Vec<_> = run.levels.iter().map(|level| (level.level, level.kills)).collect();
+            assert_eq!(levels, vec![(1, 30), (2, 15)]);
+
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+
+    mod discover_spells_system_tests {
+        use super::*;
+
+        #[test]
+        fn discovers_equipped_and_bagged_spells() {
+            let mut app = App::new();
+            let mut spell_list = SpellList::default();
+            spell_list.equip(Spell::new(SpellType::Fireball));
+            app.insert_resource(spell_list);
+            let mut bag = InventoryBag::default();
+            bag.add(Spell::new(SpellType::IceShard));
+            app.insert_resource(bag);
+            app.init_resource::<MetaProfile>();
+
+            let _ = app.world_mut().run_system_once(discover_spells_system);
+
+            let profile = app.world().resource::<MetaProfile>();
+            assert!(profile.spells_discovered.contains(&SpellType::Fireball));
+            assert!(profile.spells_discovered.contains(&SpellType::IceShard));
+            assert_eq!(profile.elements_discovered.len(), 2);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use bevy::prelude::*;
+
+use crate::element::Element;
+use crate::spell::SpellType;
+
+/// Enemy status an element can react with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReactionStatus {
+    /// `FrozenStatus` (Permafrost)
+    Frozen,
+    /// `SlowedDebuff` (frost hits)
+    Slowed,
+    /// `PoisonStack` damage over time (Venom Spray)
+    Poisoned,
+    /// `ConfusedEnemy` (Pandemonium)
+    Confused,
+    /// `StunnedEnemy` (Synapse Shock)
+    Stunned,
+}
+
+impl ReactionStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReactionStatus::Frozen => "Frozen",
+            ReactionStatus::Slowed => "Slowed",
+            ReactionStatus::Poisoned => "Poisoned",
+            ReactionStatus::Confused => "Confused",
+            ReactionStatus::Stunned => "Stunned",
+        }
+    }
+}
+
+/// Statuses currently on an enemy, gathered from its components.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusFlags {
+    pub frozen: bool,
+    pub slowed: bool,
+    pub poisoned: bool,
+    pub confused: bool,
+    pub stunned: bool,
+}
+
+impl StatusFlags {
+    pub fn has(&self, status: ReactionStatus) -> bool {
+        match status {
+            ReactionStatus::Frozen => self.frozen,
+            ReactionStatus::Slowed => self.slowed,
+            ReactionStatus::Poisoned => self.poisoned,
+            ReactionStatus::Confused => self.confused,
+            ReactionStatus::Stunned => self.stunned,
+        }
+    }
+}
+
+/// What a reaction does beyond its bonus damage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReactionEffect {
+    /// Reaction damage hits the target only
+    Burst,
+    /// Reaction damage and the status jump to up to `max_targets` other
+    /// enemies within `radius`
+    Spread { radius: f32, max_targets: usize },
+    /// The player is healed for `heal_fraction` of the reaction damage
+    Drain { heal_fraction: f32 },
+}
+
+/// One row of the reaction table: hitting an enemy carrying `status` with
+/// `trigger` damage fires the reaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementReaction {
+    /// Display name, also used to tag the reaction's `DamageEvent`s
+    pub name: &'static str,
+    pub trigger: Element,
+    pub status: ReactionStatus,
+    /// Reaction damage as a multiple of the triggering hit
+    pub damage_multiplier: f32,
+    pub effect: ReactionEffect,
+    /// Remove the status from the target when the reaction fires
+    pub consumes_status: bool,
+    /// Element whose colors are used for the reaction burst
+    pub visual: Element,
+}
+
+/// Fired when a hit triggers an element reaction on an enemy.
+#[derive(Message, Debug, Clone)]
+pub struct ElementReactionEvent {
+    pub reaction: ElementReaction,
+    pub target: Entity,
+    /// Target position at the time of the hit
+    pub position: Vec3,
+    /// Damage of the triggering hit
+    pub trigger_damage: f32,
+    /// Spell whose hit triggered the reaction, credited with its damage
+    pub spell: Option<SpellType>,
+}
+
+impl ElementReactionEvent {
+    /// Damage dealt by the reaction itself
+    pub fn reaction_damage(&self) -> f32 {
+        self.trigger_damage * self.reaction.damage_multiplier
+    }
+}
+
+/// Expanding flash shown where a reaction fires.
+#[derive(Component, Debug, Clone)]
+pub struct ReactionBurst {
+    pub lifetime: Timer,
+    pub max_radius: f32,
+}
+
+impl ReactionBurst {
+    pub fn new(max_radius: f32) -> Self {
+        Self {
+            lifetime: Timer::from_seconds(REACTION_BURST_DURATION, TimerMode::Once),
+            max_radius,
+        }
+    }
+
+    /// Current visual radius (grows over the lifetime)
+    pub fn radius(&self) -> f32 {
+        self.max_radius * (0.3 + 0.7 * self.lifetime.fraction())
+    }
+}
+
+/// How long a reaction burst stays visible
+pub const REACTION_BURST_DURATION: f32 = 0.35;
+
+/// Burst radius for reactions that only hit their target
+pub const REACTION_BURST_RADIUS: f32 = 1.5;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_flags_report_each_status() {
+        let flags = StatusFlags {
+            frozen: true,
+            confused: true,
+            ..default()
+        };
+        assert!(flags.has(ReactionStatus::Frozen));
+        assert!(flags.has(ReactionStatus::Confused));
+        assert!(!flags.has(ReactionStatus::Poisoned));
+        assert!(!flags.has(ReactionStatus::Slowed));
+        assert!(!flags.has(ReactionStatus::Stunned));
+    }
+
+    #[test]
+    fn reaction_damage_scales_trigger_damage() {
+        let event = ElementReactionEvent {
+            reaction: ElementReaction {
+                name: "Test",
+                trigger: Element::Fire,
+                status: ReactionStatus::Frozen,
+                damage_multiplier: 1.5,
+                effect: ReactionEffect::Burst,
+                consumes_status: true,
+                visual: Element::Frost,
+            },
+            target: Entity::PLACEHOLDER,
+            position: Vec3::ZERO,
+            trigger_damage: 20.0,
+            spell: None,
+        };
+        assert_eq!(event.reaction_damage(), 30.0);
+    }
+
+    #[test]
+    fn burst_grows_over_lifetime() {
+        let mut burst = ReactionBurst::new(2.0);
+        let start = burst.radius();
+        burst.lifetime.tick(std::time::Duration::from_secs_f32(REACTION_BURST_DURATION));
+        assert!(burst.radius() > start);
+        assert_eq!(burst.radius(), 2.0);
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use crate::combat::DamageEvent;
+use crate::game::sets::GameSet;
+use crate::reactions::components::ElementReactionEvent;
+use crate::reactions::resources::ReactionTable;
+use crate::reactions::systems::*;
+use crate::states::GameState;
+
+/// Cross-element reaction plugin: elemental hits on enemies carrying another
+/// element's status trigger the combos listed in `ReactionTable`.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<ReactionTable>()
+        .add_message::<DamageEvent>()
+        .add_message::<ElementReactionEvent>()
+        .add_systems(
+            Update,
+            (detect_element_reactions_system, apply_element_reactions_system)
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            update_reaction_bursts_system
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_registers_reaction_table_and_messages() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().get_resource::<ReactionTable>().is_some());
+        assert!(app.world().get_resource::<Messages<ElementReactionEvent>>().is_some());
+    }
+}
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+
+use crate::element::Element;
+use crate::reactions::components::*;
+
+/// Cross-element reactions, checked in order for every elemental hit on an
+/// enemy. Add rows here (or insert a custom table) to create new combos.
+#[derive(Resource, Debug, Clone)]
+pub struct ReactionTable {
+    pub reactions: Vec<ElementReaction>,
+}
+
+impl Default for ReactionTable {
+    fn default() -> Self {
+        Self {
+            reactions: vec![
+                // Fire on a frozen enemy breaks the ice for a big hit
+                ElementReaction {
+                    name: "Shatter",
+                    trigger: Element::Fire,
+                    status: ReactionStatus::Frozen,
+                    damage_multiplier: 2.0,
+                    effect: ReactionEffect::Burst,
+                    consumes_status: true,
+                    visual: Element::Frost,
+                },
+                // Fire on a slowed enemy: smaller thermal shock, thaws the slow
+                ElementReaction {
+                    name: "Thermal Shock",
+                    trigger: Element::Fire,
+                    status: ReactionStatus::Slowed,
+                    damage_multiplier: 0.5,
+                    effect: ReactionEffect::Burst,
+                    consumes_status: true,
+                    visual: Element::Fire,
+                },
+                // Lightning carries the poison to nearby enemies
+                ElementReaction {
+                    name: "Conduction",
+                    trigger: Element::Lightning,
+                    status: ReactionStatus::Poisoned,
+                    damage_multiplier: 0.5,
+                    effect: ReactionEffect::Spread {
+                        radius: 4.0,
+                        max_targets: 3,
+                    },
+                    consumes_status: false,
+                    visual: Element::Poison,
+                },
+                // Dark feeds on a confused mind and heals the player
+                ElementReaction {
+                    name: "Siphon",
+                    trigger: Element::Dark,
+                    status: ReactionStatus::Confused,
+                    damage_multiplier: 0.5,
+                    effect: ReactionEffect::Drain { heal_fraction: 1.0 },
+                    consumes_status: false,
+                    visual: Element::Dark,
+                },
+            ],
+        }
+    }
+}
+
+impl ReactionTable {
+    /// Reactions fired by a hit of `element` on an enemy with `statuses`
+    pub fn matching(
+        &self,
+        element: Element,
+        statuses: StatusFlags,
+    ) -> impl Iterator<Item = &ElementReaction> {
+        self.reactions
+            .iter()
+            .filter(move |reaction| reaction.trigger == element && statuses.has(reaction.status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_table_has_core_combos() {
+        let table = ReactionTable::default();
+        let find = |element, status| {
+            table
+                .reactions
+                .iter()
+                .find(|r| r.trigger == element && r.status == status)
+                .map(|r| r.name)
+        };
+        assert_eq!(find(Element::Fire, ReactionStatus::Frozen), Some("Shatter"));
+        assert_eq!(find(Element::Lightning, ReactionStatus::Poisoned), Some("Conduction"));
+        assert_eq!(find(Element::Dark, ReactionStatus::Confused), Some("Siphon"));
+    }
+
+    #[test]
+    fn matching_requires_element_and_status() {
+        let table = ReactionTable::default();
+        let frozen = StatusFlags {
+            frozen: true,
+            ..default()
+        };
+
+        let names: Vec<_> = table.matching(Element::Fire, frozen).map(|r| r.name).collect();
+        assert_eq!(names, vec!["Shatter"]);
+        assert_eq!(table.matching(Element::Frost, frozen).count(), 0);
+        assert_eq!(table.matching(Element::Fire, StatusFlags::default()).count(), 0);
+    }
+
+    #[test]
+    fn custom_rows_are_matched() {
+        let mut table = ReactionTable::default();
+        table.reactions.push(ElementReaction {
+            name: "Overcharge",
+            trigger: Element::Lightning,
+            status: ReactionStatus::Stunned,
+            damage_multiplier: 1.0,
+            effect: ReactionEffect::Burst,
+            consumes_status: true,
+            visual: Element::Lightning,
+        });
+        let stunned = StatusFlags {
+            stunned: true,
+            ..default()
+        };
+        let names: Vec<_> = table.matching(Element::Lightning, stunned).map(|r| r.name).collect();
+        assert_eq!(names, vec!["Overcharge"]);
+    }
+}
@@ -0,0 +1,468 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::collision::spatial::SpatialEnemies;
+use crate::combat::{DamageEvent, Health};
+use crate::element::Element;
+use crate::enemies::components::Enemy;
+use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::movement::components::from_xz;
+use crate::player::components::Player;
+use crate::reactions::components::*;
+use crate::reactions::resources::ReactionTable;
+use crate::spells::chaos::pandemonium::{ConfusedEnemy, PANDEMONIUM_DURATION};
+use crate::spells::frost::ice_shard::SlowedDebuff;
+use crate::spells::frost::permafrost::FrozenStatus;
+use crate::spells::poison::venom_spray::PoisonStack;
+use crate::spells::psychic::synapse_shock::{StunnedEnemy, SYNAPSE_SHOCK_STUN_DURATION};
+
+/// Presence of each reaction status component, in `StatusFlags` field order
+pub type StatusQuery = (
+    Has<FrozenStatus>,
+    Has<SlowedDebuff>,
+    Has<PoisonStack>,
+    Has<ConfusedEnemy>,
+    Has<StunnedEnemy>,
+);
+
+impl From<(bool, bool, bool, bool, bool)> for StatusFlags {
+    fn from((frozen, slowed, poisoned, confused, stunned): (bool, bool, bool, bool, bool)) -> Self {
+        Self {
+            frozen,
+            slowed,
+            poisoned,
+            confused,
+            stunned,
+        }
+    }
+}
+
+/// Remove a status component from an enemy
+pub fn remove_status(commands: &mut Commands, entity: Entity, status: ReactionStatus) {
+    let mut entity_commands = commands.entity(entity);
+    match status {
+        ReactionStatus::Frozen => entity_commands.try_remove::<FrozenStatus>(),
+        ReactionStatus::Slowed => entity_commands.try_remove::<SlowedDebuff>(),
+        ReactionStatus::Poisoned => entity_commands.try_remove::<PoisonStack>(),
+        ReactionStatus::Confused => entity_commands.try_remove::<ConfusedEnemy>(),
+        ReactionStatus::Stunned => entity_commands.try_remove::<StunnedEnemy>(),
+    };
+}
+
+/// Give an enemy a fresh instance of a status (default strength and duration)
+pub fn apply_status(commands: &mut Commands, entity: Entity, enemy: &Enemy, status: ReactionStatus) {
+    let mut entity_commands = commands.entity(entity);
+    match status {
+        ReactionStatus::Frozen => entity_commands.try_insert(FrozenStatus::default()),
+        ReactionStatus::Slowed => entity_commands.try_insert(SlowedDebuff::default()),
+        ReactionStatus::Poisoned => entity_commands.try_insert(PoisonStack::new()),
+        ReactionStatus::Confused => {
+            entity_commands.try_insert(ConfusedEnemy::new(PANDEMONIUM_DURATION))
+        }
+        ReactionStatus::Stunned => entity_commands
+            .try_insert(StunnedEnemy::new(SYNAPSE_SHOCK_STUN_DURATION, enemy.speed)),
+    };
+}
+
+/// Material used for a reaction burst of the given element
+pub fn reaction_material(element: Element, materials: &GameMaterials) -> Handle<StandardMaterial> {
+    match element {
+        Element::Fire => materials.fire_nova.clone(),
+        Element::Frost => materials.glacial_pulse.clone(),
+        Element::Poison => materials.poison_cloud.clone(),
+        Element::Lightning => materials.thunder_strike.clone(),
+        Element::Light => materials.judgment_aoe.clone(),
+        Element::Dark => materials.shadow_bolt.clone(),
+        Element::Chaos => materials.chaos_aoe.clone(),
+        Element::Psychic => materials.psychic_aoe.clone(),
+    }
+}
+
+/// Checks every elemental hit on an enemy against the `ReactionTable`.
+/// Each reaction fires at most once per enemy per frame, and reaction damage
+/// never triggers further reactions.
+pub fn detect_element_reactions_system(
+    mut commands: Commands,
+    table: Res<ReactionTable>,
+    mut damage_events: MessageReader<DamageEvent>,
+    enemy_query: Query<(&Transform, StatusQuery), With<Enemy>>,
+    mut reaction_events: MessageWriter<ElementReactionEvent>,
+) {
+    let mut fired: HashSet<(Entity, &'static str)> = HashSet::new();
+    let mut consumed: HashSet<(Entity, ReactionStatus)> = HashSet::new();
+
+    for event in damage_events.read() {
+        if event.is_reaction() {
+            continue;
+        }
+        let Some(element) = event.element else {
+            continue;
+        };
+        let Ok((transform, statuses)) = enemy_query.get(event.target) else {
+            continue;
+        };
+
+        for reaction in table.matching(element, StatusFlags::from(statuses)) {
+            if consumed.contains(&(event.target, reaction.status))
+                || !fired.insert((event.target, reaction.name))
+            {
+                continue;
+            }
+
+            if reaction.consumes_status {
+                consumed.insert((event.target, reaction.status));
+                remove_status(&mut commands, event.target, reaction.status);
+            }
+
+            reaction_events.write(ElementReactionEvent {
+                reaction: reaction.clone(),
+                target: event.target,
+                position: transform.translation,
+                trigger_damage: event.amount,
+                spell: event.spell,
+            });
+        }
+    }
+}
+
+/// Resolves reactions: deals the reaction-tagged damage, spreads statuses,
+/// heals the player for drains and spawns the reaction burst.
+#[allow(clippy::type_complexity)]
+pub fn apply_element_reactions_system(
+    mut commands: Commands,
+    mut reaction_events: MessageReader<ElementReactionEvent>,
+    mut damage_events: MessageWriter<DamageEvent>,
+    spatial_enemies: SpatialEnemies,
+    enemy_query: Query<(Entity, &Enemy, StatusQuery)>,
+    mut player_query: Query<&mut Health, With<Player>>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+) {
+    for event in reaction_events.read() {
+        let reaction = &event.reaction;
+        let damage = event.reaction_damage();
+
+        damage_events.write(
+            DamageEvent::with_element(event.target, damage, reaction.trigger)
+                .as_reaction(reaction.name)
+                .by_spell(event.spell),
+        );
+
+        let mut burst_radius = REACTION_BURST_RADIUS;
+        match reaction.effect {
+            ReactionEffect::Burst => {}
+            ReactionEffect::Spread { radius, max_targets } => {
+                burst_radius = radius;
+                let center = from_xz(event.position);
+                // One extra in case the reacting enemy itself ranks among the closest
+                let neighbors = spatial_enemies
+                    .nearest_k(center, max_targets + 1)
+                    .into_iter()
+                    .filter(|(entity, position)| {
+                        *entity != event.target && center.distance(*position) <= radius
+                    })
+                    .take(max_targets)
+                    .filter_map(|(entity, _)| enemy_query.get(entity).ok());
+
+                for (entity, enemy, statuses) in neighbors {
+                    let statuses = StatusFlags::from(statuses);
+                    if !statuses.has(reaction.status) {
+                        apply_status(&mut commands, entity, enemy, reaction.status);
+                    }
+                    damage_events.write(
+                        DamageEvent::with_element(entity, damage, reaction.trigger)
+                            .as_reaction(reaction.name)
+                            .by_spell(event.spell),
+                    );
+                }
+            }
+            ReactionEffect::Drain { heal_fraction } => {
+                for mut health in player_query.iter_mut() {
+                    health.heal(damage * heal_fraction);
+                }
+            }
+        }
+
+        let burst = ReactionBurst::new(burst_radius);
+        let transform = Transform::from_translation(event.position)
+            .with_scale(Vec3::splat(burst.radius()));
+        if let (Some(meshes), Some(materials)) = (game_meshes.as_ref(), game_materials.as_ref()) {
+            commands.spawn((
+                Mesh3d(meshes.explosion.clone()),
+                MeshMaterial3d(reaction_material(reaction.visual, materials)),
+                transform,
+                burst,
+            ));
+        } else {
+            // Fallback for tests without mesh resources
+            commands.spawn((transform, burst));
+        }
+    }
+}
+
+/// Grows reaction bursts and despawns them when they finish.
+pub fn update_reaction_bursts_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut burst_query: Query<(Entity, &mut ReactionBurst, &mut Transform)>,
+) {
+    for (entity, mut burst, mut transform) in burst_query.iter_mut() {
+        burst.lifetime.tick(time.delta());
+        if burst.lifetime.is_finished() {
+            commands.entity(entity).despawn();
+        } else {
+            transform.scale = Vec3::splat(burst.radius());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+    use crate::spell::SpellType;
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_message::<DamageEvent>();
+        app.add_message::<ElementReactionEvent>();
+        app.init_resource::<ReactionTable>();
+        app
+    }
+
+    fn spawn_enemy(app: &mut App, position: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy { speed: 2.0, strength: 10.0 },
+                Transform::from_translation(position),
+                Health::new(100.0),
+            ))
+            .id()
+    }
+
+    fn read_reactions(app: &App) -> Vec<ElementReactionEvent> {
+        let messages = app.world().resource::<Messages<ElementReactionEvent>>();
+        let mut cursor = messages.get_cursor();
+        cursor.read(messages).cloned().collect()
+    }
+
+    fn read_damage(app: &App) -> Vec<DamageEvent> {
+        let messages = app.world().resource::<Messages<DamageEvent>>();
+        let mut cursor = messages.get_cursor();
+        cursor.read(messages).cloned().collect()
+    }
+
+    mod detect_element_reactions_tests {
+        use super::*;
+
+        #[test]
+        fn fire_on_frozen_shatters_and_thaws() {
+            let mut app = setup_app();
+            let enemy = spawn_enemy(&mut app, Vec3::ZERO);
+            app.world_mut().entity_mut(enemy).insert(FrozenStatus::default());
+
+            app.world_mut().write_message(
+                DamageEvent::with_element(enemy, 20.0, Element::Fire).by_spell(SpellType::Fireball),
+            );
+            let _ = app.world_mut().run_system_once(detect_element_reactions_system);
+
+            let reactions = read_reactions(&app);
+            assert_eq!(reactions.len(), 1);
+            assert_eq!(reactions[0].reaction.name, "Shatter");
+            assert_eq!(reactions[0].trigger_damage, 20.0);
+            assert_eq!(reactions[0].spell, Some(SpellType::Fireball));
+            assert!(app.world().get::<FrozenStatus>(enemy).is_none());
+        }
+
+        #[test]
+        fn consumed_status_reacts_once_per_frame() {
+            let mut app = setup_app();
+            let enemy = spawn_enemy(&mut app, Vec3::ZERO);
+            app.world_mut().entity_mut(enemy).insert(FrozenStatus::default());
+
+            for _ in 0..3 {
+                app.world_mut()
+                    .write_message(DamageEvent::with_element(enemy, 20.0, Element::Fire));
+            }
+            let _ = app.world_mut().run_system_once(detect_element_reactions_system);
+
+            assert_eq!(read_reactions(&app).len(), 1);
+        }
+
+        #[test]
+        fn no_reaction_without_matching_status_or_element() {
+            let mut app = setup_app();
+            let plain = spawn_enemy(&mut app, Vec3::ZERO);
+            let frozen = spawn_enemy(&mut app, Vec3::X);
+            app.world_mut().entity_mut(frozen).insert(FrozenStatus::default());
+
+            app.world_mut()
+                .write_message(DamageEvent::with_element(plain, 20.0, Element::Fire));
+            app.world_mut()
+                .write_message(DamageEvent::with_element(frozen, 20.0, Element::Frost));
+            app.world_mut().write_message(DamageEvent::new(frozen, 20.0));
+            let _ = app.world_mut().run_system_once(detect_element_reactions_system);
+
+            assert!(read_reactions(&app).is_empty());
+            assert!(app.world().get::<FrozenStatus>(frozen).is_some());
+        }
+
+        #[test]
+        fn reaction_damage_does_not_chain() {
+            let mut app = setup_app();
+            let enemy = spawn_enemy(&mut app, Vec3::ZERO);
+            app.world_mut().entity_mut(enemy).insert(FrozenStatus::default());
+
+            app.world_mut().write_message(
+                DamageEvent::with_element(enemy, 20.0, Element::Fire).as_reaction("Shatter"),
+            );
+            let _ = app.world_mut().run_system_once(detect_element_reactions_system);
+
+            assert!(read_reactions(&app).is_empty());
+        }
+    }
+
+    mod apply_element_reactions_tests {
+        use super::*;
+
+        fn reaction_event(app: &App, name: &str, target: Entity, trigger_damage: f32) -> ElementReactionEvent {
+            let reaction = app
+                .world()
+                .resource::<ReactionTable>()
+                .reactions
+                .iter()
+                .find(|r| r.name == name)
+                .unwrap()
+                .clone();
+            ElementReactionEvent {
+                reaction,
+                target,
+                position: Vec3::ZERO,
+                trigger_damage,
+                spell: None,
+            }
+        }
+
+        #[test]
+        fn burst_deals_tagged_damage_and_spawns_visual() {
+            let mut app = setup_app();
+            let enemy = spawn_enemy(&mut app, Vec3::ZERO);
+            let mut event = reaction_event(&app, "Shatter", enemy, 20.0);
+            event.spell = Some(SpellType::Fireball);
+            app.world_mut().write_message(event);
+
+            let _ = app.world_mut().run_system_once(apply_element_reactions_system);
+
+            let damage = read_damage(&app);
+            assert_eq!(damage.len(), 1);
+            assert_eq!(damage[0].target, enemy);
+            assert_eq!(damage[0].amount, 40.0);
+            assert_eq!(damage[0].reaction, Some("Shatter"));
+            assert_eq!(damage[0].element, Some(Element::Fire));
+            assert_eq!(damage[0].spell, Some(SpellType::Fireball));
+
+            let mut bursts = app.world_mut().query::<&ReactionBurst>();
+            assert_eq!(bursts.iter(app.world()).count(), 1);
+        }
+
+        #[test]
+        fn spread_poisons_nearest_neighbors_in_radius() {
+            let mut app = setup_app();
+            let target = spawn_enemy(&mut app, Vec3::ZERO);
+            let near: Vec<Entity> = (1..=4)
+                .map(|i| spawn_enemy(&mut app, Vec3::new(i as f32 * 0.8, 0.0, 0.0)))
+                .collect();
+            let far = spawn_enemy(&mut app, Vec3::new(50.0, 0.0, 0.0));
+            let event = reaction_event(&app, "Conduction", target, 20.0);
+            app.world_mut().write_message(event);
+
+            let _ = app.world_mut().run_system_once(apply_element_reactions_system);
+
+            // Max 3 targets, nearest first
+            for entity in &near[..3] {
+                assert!(app.world().get::<PoisonStack>(*entity).is_some());
+            }
+            assert!(app.world().get::<PoisonStack>(near[3]).is_none());
+            assert!(app.world().get::<PoisonStack>(far).is_none());
+
+            let damage = read_damage(&app);
+            assert_eq!(damage.len(), 4, "Target plus three neighbors");
+            assert!(damage.iter().all(|d| d.reaction == Some("Conduction")));
+        }
+
+        #[test]
+        fn spread_finds_neighbors_through_enemy_hash() {
+            use crate::collision::spatial::{rebuild_enemy_spatial_hash, EnemySpatialHash};
+
+            let mut app = setup_app();
+            app.init_resource::<EnemySpatialHash>();
+            let target = spawn_enemy(&mut app, Vec3::ZERO);
+            let near = spawn_enemy(&mut app, Vec3::new(0.0, 0.0, 1.5));
+            let far = spawn_enemy(&mut app, Vec3::new(40.0, 0.0, 40.0));
+            let _ = app.world_mut().run_system_once(rebuild_enemy_spatial_hash);
+            let event = reaction_event(&app, "Conduction", target, 20.0);
+            app.world_mut().write_message(event);
+
+            let _ = app.world_mut().run_system_once(apply_element_reactions_system);
+
+            assert!(app.world().get::<PoisonStack>(near).is_some());
+            assert!(app.world().get::<PoisonStack>(far).is_none());
+            assert!(app.world().get::<PoisonStack>(target).is_none());
+        }
+
+        #[test]
+        fn drain_heals_player() {
+            let mut app = setup_app();
+            let enemy = spawn_enemy(&mut app, Vec3::ZERO);
+            let mut health = Health::new(100.0);
+            health.take_damage(50.0);
+            let player = app
+                .world_mut()
+                .spawn((
+                    Player {
+                        speed: 5.0,
+                        regen_rate: 1.0,
+                        pickup_radius: 2.0,
+                        last_movement_direction: Vec3::ZERO,
+                    },
+                    health,
+                ))
+                .id();
+            let event = reaction_event(&app, "Siphon", enemy, 20.0);
+            app.world_mut().write_message(event);
+
+            let _ = app.world_mut().run_system_once(apply_element_reactions_system);
+
+            // 20 * 0.5 reaction damage, healed in full
+            assert_eq!(app.world().get::<Health>(player).unwrap().current, 60.0);
+        }
+    }
+
+    mod update_reaction_bursts_tests {
+        use super::*;
+
+        #[test]
+        fn burst_despawns_after_duration() {
+            let mut app = App::new();
+            app.init_resource::<Time>();
+            let burst = app
+                .world_mut()
+                .spawn((Transform::default(), ReactionBurst::new(2.0)))
+                .id();
+
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(REACTION_BURST_DURATION / 2.0));
+            let _ = app.world_mut().run_system_once(update_reaction_bursts_system);
+            assert!(app.world().get_entity(burst).is_ok());
+
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(REACTION_BURST_DURATION));
+            let _ = app.world_mut().run_system_once(update_reaction_bursts_system);
+            assert!(app.world().get_entity(burst).is_err());
+        }
+    }
+}
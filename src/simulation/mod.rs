@@ -0,0 +1,9 @@
+pub mod plugin;
+pub mod resources;
+pub mod runner;
+pub mod systems;
+
+pub use plugin::*;
+pub use resources::*;
+pub use runner::*;
+pub use systems::*;
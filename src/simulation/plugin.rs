@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+use crate::game::sets::GameSet;
+use crate::input::systems::read_movement_intent;
+use crate::simulation::resources::*;
+use crate::simulation::systems::*;
+use crate::states::GameState;
+
+/// Simulation plugin: stands in for the player and the menus so a run plays
+/// itself out. Steers during play, attunes, takes the first level-up reward
+/// and continues past every completed level.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<SimulationConfig>()
+        .init_resource::<SimulationRun>()
+        .add_systems(Startup, start_simulation)
+        .add_systems(
+            Update,
+            simulated_player_system
+                .in_set(GameSet::Input)
+                .after(read_movement_intent)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            track_simulation_run_system
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Menus are answered the frame after they open, once their OnEnter setup ran
+        .add_systems(Update, auto_attune_system.run_if(in_state(GameState::AttunementSelect)))
+        .add_systems(Update, auto_pick_level_up_system.run_if(in_state(GameState::LevelUpChoice)))
+        .add_systems(Update, auto_continue_level_system.run_if(in_state(GameState::LevelComplete)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_registers_simulation_resources() {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.add_plugins(plugin);
+
+        assert!(app.world().get_resource::<SimulationConfig>().is_some());
+        assert_eq!(app.world().resource::<SimulationRun>().elapsed_secs, 0.0);
+    }
+}
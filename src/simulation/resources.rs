@@ -0,0 +1,222 @@
+use std::fmt::Write;
+
+use bevy::prelude::*;
+
+use crate::element::Element;
+use crate::spell::SpellType;
+use crate::spell::definitions::SpellCatalog;
+
+/// Settings shared by every simulated run.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SimulationConfig {
+    /// Number of runs; run `i` uses seed `first_seed + i`
+    pub runs: u32,
+    pub first_seed: u64,
+    /// Runs still alive after this much game time are stopped
+    pub max_secs: f32,
+    /// Fixed game time advanced per update (seconds)
+    pub timestep: f32,
+    /// Element picked when the Whisper is collected.
+    /// `None` cycles through every element by seed.
+    pub attunement: Option<Element>,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            runs: 1,
+            first_seed: 0,
+            max_secs: 600.0,
+            timestep: 1.0 / 30.0,
+            attunement: None,
+        }
+    }
+}
+
+impl SimulationConfig {
+    /// Element the simulated player attunes to in the run with this seed
+    pub fn attunement_for(&self, seed: u64) -> Element {
+        self.attunement.unwrap_or_else(|| {
+            let elements = Element::all();
+            elements[(seed % elements.len() as u64) as usize]
+        })
+    }
+}
+
+/// Progress of the run being simulated. `SurvivalTime` restarts on every
+/// level, so the run keeps its own clock.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct SimulationRun {
+    /// Element the simulated player attunes to
+    pub attunement: Element,
+    /// Game time spent in `GameState::InGame`
+    pub elapsed_secs: f32,
+    pub kills: u32,
+}
+
+/// Result of one simulated run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationOutcome {
+    pub seed: u64,
+    pub attunement: Element,
+    pub survived_secs: f32,
+    /// `GameLevel` the run ended on
+    pub level_reached: u32,
+    pub kills: u32,
+    /// False if the run hit the time limit
+    pub died: bool,
+    /// Damage dealt by each spell over the run, highest first
+    pub damage_by_spell: Vec<(SpellType, f32)>,
+}
+
+/// Outcomes of every simulated run, in seed order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimulationReport {
+    pub outcomes: Vec<SimulationOutcome>,
+}
+
+impl SimulationReport {
+    /// Human-readable summary: one line per run, then averages and the
+    /// damage of each spell summed over all runs, named from `catalog`.
+    pub fn summary(&self, catalog: &SpellCatalog) -> String {
+        let mut summary = String::new();
+        if self.outcomes.is_empty() {
+            summary.push_str("No runs simulated\n");
+            return summary;
+        }
+
+        for outcome in &self.outcomes {
+            let _ = writeln!(
+                summary,
+                "seed {:>6} {:<9} survived {:>7.1}s  level {:>2}  kills {:>5}  {}",
+                outcome.seed,
+                outcome.attunement.name(),
+                outcome.survived_secs,
+                outcome.level_reached,
+                outcome.kills,
+                if outcome.died { "died" } else { "time limit" }
+            );
+        }
+
+        let runs = self.outcomes.len() as f32;
+        let deaths = self.outcomes.iter().filter(|outcome| outcome.died).count();
+        let _ = writeln!(
+            summary,
+            "\n{} runs, {} died. Average: survived {:.1}s, level {:.1}, kills {:.1}",
+            self.outcomes.len(),
+            deaths,
+            self.outcomes.iter().map(|outcome| outcome.survived_secs).sum::<f32>() / runs,
+            self.outcomes.iter().map(|outcome| outcome.level_reached as f32).sum::<f32>() / runs,
+            self.outcomes.iter().map(|outcome| outcome.kills as f32).sum::<f32>() / runs,
+        );
+
+        let damage = self.total_damage_by_spell();
+        if !damage.is_empty() {
+            summary.push_str("\nDamage per spell:\n");
+            for (spell, total) in damage {
+                let _ = writeln!(
+                    summary,
+                    "  {:<18} {:>12.0} total  {:>10.0} per run",
+                    spell.name(catalog),
+                    total,
+                    total / runs
+                );
+            }
+        }
+        summary
+    }
+
+    /// Damage of each spell summed over all runs, highest first
+    pub fn total_damage_by_spell(&self) -> Vec<(SpellType, f32)> {
+        let mut totals: Vec<(SpellType, f32)> = Vec::new();
+        for (spell, damage) in self.outcomes.iter().flat_map(|outcome| &outcome.damage_by_spell) {
+            match totals.iter_mut().find(|(total_spell, _)| total_spell == spell) {
+                Some((_, total)) => *total += damage,
+                None => totals.push((*spell, *damage)),
+            }
+        }
+        totals.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.id().cmp(&b.0.id())));
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(seed: u64, died: bool, damage_by_spell: Vec<(SpellType, f32)>) -> SimulationOutcome {
+        SimulationOutcome {
+            seed,
+            attunement: Element::Fire,
+            survived_secs: 100.0,
+            level_reached: 2,
+            kills: 50,
+            died,
+            damage_by_spell,
+        }
+    }
+
+    mod simulation_config_tests {
+        use super::*;
+
+        #[test]
+        fn fixed_attunement_is_used_for_every_seed() {
+            let config = SimulationConfig {
+                attunement: Some(Element::Frost),
+                ..default()
+            };
+            assert_eq!(config.attunement_for(0), Element::Frost);
+            assert_eq!(config.attunement_for(5), Element::Frost);
+        }
+
+        #[test]
+        fn no_attunement_cycles_through_elements() {
+            let config = SimulationConfig::default();
+            let elements = Element::all();
+            for (seed, element) in elements.iter().enumerate() {
+                assert_eq!(config.attunement_for(seed as u64), *element);
+            }
+            assert_eq!(config.attunement_for(elements.len() as u64), elements[0]);
+        }
+    }
+
+    mod simulation_report_tests {
+        use super::*;
+
+        #[test]
+        fn spell_damage_is_summed_across_runs() {
+            let report = SimulationReport {
+                outcomes: vec![
+                    outcome(1, true, vec![(SpellType::Fireball, 100.0), (SpellType::IceShard, 50.0)]),
+                    outcome(2, false, vec![(SpellType::IceShard, 300.0)]),
+                ],
+            };
+            assert_eq!(
+                report.total_damage_by_spell(),
+                vec![(SpellType::IceShard, 350.0), (SpellType::Fireball, 100.0)]
+            );
+        }
+
+        #[test]
+        fn summary_lists_runs_averages_and_spells() {
+            let report = SimulationReport {
+                outcomes: vec![
+                    outcome(1, true, vec![(SpellType::Fireball, 100.0)]),
+                    outcome(2, false, Vec::new()),
+                ],
+            };
+            let summary = report.summary(SpellCatalog::built_in());
+
+            assert!(summary.contains("seed      1"));
+            assert!(summary.contains("time limit"));
+            assert!(summary.contains("2 runs, 1 died"));
+            assert!(summary.contains("kills 50.0"));
+            assert!(summary.contains("Fireball"));
+        }
+
+        #[test]
+        fn empty_report_says_so() {
+            assert_eq!(SimulationReport::default().summary(SpellCatalog::built_in()), "No runs simulated\n");
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::game::resources::{GameLevel, GameRng, SpellStatColumn, SpellStats};
+use crate::simulation::resources::*;
+use crate::states::GameState;
+use crate::{combat_plugin, experience_plugin, game_plugin, inventory_plugin, simulation_plugin};
+
+/// Headless app playing one run with the given seed. Nothing is rendered:
+/// `StandardMaterial` is never registered, so `GameMeshes` and
+/// `GameMaterials` are never created and entities spawn without visuals.
+pub fn simulation_app(seed: u64, config: &SimulationConfig) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        bevy::state::app::StatesPlugin,
+        bevy::input::InputPlugin,
+        AssetPlugin {
+            watch_for_changes_override: Some(false),
+            ..default()
+        },
+        bevy::mesh::MeshPlugin,
+        bevy::image::ImagePlugin::default(),
+    ));
+    // Player and wall models are still requested through the asset server
+    app.init_asset::<Scene>();
+    app.init_asset::<bevy::shader::Shader>();
+
+    app.insert_resource(GameRng::from_seed(seed))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            config.timestep,
+        )))
+        .insert_resource(config.clone())
+        .insert_resource(SimulationRun {
+            attunement: config.attunement_for(seed),
+            ..default()
+        })
+        .init_state::<GameState>()
+        .add_plugins((combat_plugin, experience_plugin, game_plugin, inventory_plugin))
+        .add_plugins(simulation_plugin);
+    app.finish();
+    app.cleanup();
+    app
+}
+
+/// Play one run until the player dies or `max_secs` of game time pass.
+pub fn run_simulation(seed: u64, config: &SimulationConfig) -> SimulationOutcome {
+    let mut app = simulation_app(seed, config);
+
+    let died = loop {
+        app.update();
+        if *app.world().resource::<State<GameState>>() == GameState::GameOver {
+            break true;
+        }
+        if app.world().resource::<SimulationRun>().elapsed_secs >= config.max_secs {
+            break false;
+        }
+    };
+
+    let world = app.world();
+    let run = world.resource::<SimulationRun>();
+    SimulationOutcome {
+        seed,
+        attunement: run.attunement,
+        survived_secs: run.elapsed_secs,
+        level_reached: world.resource::<GameLevel>().level,
+        kills: run.kills,
+        died,
+        damage_by_spell: world
+            .resource::<SpellStats>()
+            .run
+            .rows(SpellStatColumn::Damage)
+            .into_iter()
+            .map(|row| (row.spell_type, row.damage))
+            .collect(),
+    }
+}
+
+/// Play `config.runs` runs on consecutive seeds starting at `config.first_seed`.
+pub fn run_simulations(config: &SimulationConfig) -> SimulationReport {
+    SimulationReport {
+        outcomes: (0..config.runs as u64)
+            .map(|offset| run_simulation(config.first_seed.wrapping_add(offset), config))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_run_plays_out_headless() {
+        let config = SimulationConfig {
+            max_secs: 3.0,
+            attunement: Some(crate::element::Element::Fire),
+            ..default()
+        };
+        let outcome = run_simulation(7, &config);
+
+        assert_eq!(outcome.seed, 7);
+        assert!(!outcome.died);
+        assert!(outcome.survived_secs >= 3.0);
+        assert_eq!(outcome.level_reached, 1);
+    }
+}
@@ -0,0 +1,262 @@
+use bevy::prelude::*;
+
+use crate::arena::resources::ArenaBounds;
+use crate::enemies::components::Enemy;
+use crate::game::events::EnemyDeathEvent;
+use crate::game::resources::LevelStats;
+use crate::input::resources::MovementIntent;
+use crate::inventory::bag::InventoryBag;
+use crate::inventory::resources::SpellList;
+use crate::level_up::resources::{LevelUpChoices, PendingLevelUps};
+use crate::level_up::systems::apply_level_up_reward;
+use crate::loot::components::{DroppedItem, PickupState};
+use crate::movement::components::from_xz;
+use crate::player::components::Player;
+use crate::powerup::components::ActivePowerups;
+use crate::profile::resources::{MetaUpgrade, RunUpgrades};
+use crate::simulation::resources::SimulationRun;
+use crate::spell::Spell;
+use crate::spell::definitions::SpellCatalog;
+use crate::states::GameState;
+use crate::ui::attunement::starting_spells;
+use crate::whisper::resources::WhisperAttunement;
+
+/// Enemies closer than this push the simulated player away
+pub const SIM_DANGER_RADIUS: f32 = 6.0;
+
+/// Pull toward the nearest loot relative to a point-blank enemy
+const SIM_LOOT_WEIGHT: f32 = 0.6;
+
+/// Pull toward the arena center when standing on the wall
+const SIM_CENTER_WEIGHT: f32 = 0.8;
+
+/// Direction the simulated player moves in: away from nearby enemies
+/// (closer ones push harder), toward the nearest loot, and back toward the
+/// middle as it nears the walls. Zero when nothing pulls either way.
+pub fn simulated_steering(
+    player: Vec2,
+    threats: &[Vec2],
+    loot: Option<Vec2>,
+    bounds: &ArenaBounds,
+) -> Vec2 {
+    let mut steering = Vec2::ZERO;
+
+    for threat in threats {
+        let away = player - *threat;
+        let distance = away.length();
+        if distance < SIM_DANGER_RADIUS {
+            steering += away.normalize_or(Vec2::X) * (1.0 - distance / SIM_DANGER_RADIUS);
+        }
+    }
+
+    if let Some(loot) = loot {
+        steering += (loot - player).normalize_or_zero() * SIM_LOOT_WEIGHT;
+    }
+
+    let center = Vec2::new(
+        (bounds.min_x + bounds.max_x) / 2.0,
+        (bounds.min_z + bounds.max_z) / 2.0,
+    );
+    let half_extent = Vec2::new(bounds.width(), bounds.depth()) / 2.0;
+    let offset = (player - center) / half_extent.max(Vec2::ONE);
+    steering -= offset * offset.length() * SIM_CENTER_WEIGHT;
+
+    steering.normalize_or_zero()
+}
+
+/// Start the first simulated run without the intro menu
+pub fn start_simulation(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::InGame);
+}
+
+/// Steer the player in place of keyboard and mouse input
+/// (runs after `read_movement_intent` so it has the last word).
+pub fn simulated_player_system(
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    loot_query: Query<(&Transform, &DroppedItem)>,
+    bounds: Option<Res<ArenaBounds>>,
+    mut intent: ResMut<MovementIntent>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player = from_xz(player_transform.translation);
+
+    let threats: Vec<Vec2> = enemy_query
+        .iter()
+        .map(|transform| from_xz(transform.translation))
+        .collect();
+    let loot = loot_query
+        .iter()
+        .filter(|(_, item)| item.pickup_state == PickupState::Idle)
+        .map(|(transform, _)| from_xz(transform.translation))
+        .min_by(|a, b| a.distance_squared(player).total_cmp(&b.distance_squared(player)));
+    let bounds = bounds.map(|bounds| *bounds).unwrap_or_default();
+
+    let direction = simulated_steering(player, &threats, loot, &bounds);
+    intent.set_if_neq(if direction == Vec2::ZERO {
+        MovementIntent::Idle
+    } else {
+        MovementIntent::Direction(direction)
+    });
+}
+
+/// Advance the run clock and count kills
+pub fn track_simulation_run_system(
+    time: Res<Time>,
+    mut death_events: MessageReader<EnemyDeathEvent>,
+    mut run: ResMut<SimulationRun>,
+) {
+    run.elapsed_secs += time.delta_secs();
+    run.kills += death_events.read().count() as u32;
+}
+
+/// Attune to the configured element, like clicking it on the attunement screen
+pub fn auto_attune_system(
+    run: Res<SimulationRun>,
+    mut attunement: ResMut<WhisperAttunement>,
+    mut spell_list: ResMut<SpellList>,
+    spell_catalog: Res<SpellCatalog>,
+    run_upgrades: Option<Res<RunUpgrades>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let extra_spells = run_upgrades
+        .map(|upgrades| upgrades.level(MetaUpgrade::StartingSpell))
+        .unwrap_or(0);
+
+    attunement.set_element(run.attunement);
+    for spell_type in starting_spells(run.attunement, extra_spells) {
+        spell_list.equip(Spell::from_catalog(spell_type, &spell_catalog));
+    }
+    next_state.set(GameState::InGame);
+}
+
+/// Take the first reward offered on the level-up screen
+pub fn auto_pick_level_up_system(
+    choices: Res<LevelUpChoices>,
+    mut pending: ResMut<PendingLevelUps>,
+    spell_list: Option<ResMut<SpellList>>,
+    inventory_bag: Option<ResMut<InventoryBag>>,
+    mut active_powerups: ResMut<ActivePowerups>,
+    spell_catalog: Res<SpellCatalog>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let (Some(choice), Some(mut spell_list)) = (choices.choices.first(), spell_list) {
+        apply_level_up_reward(
+            &choice.reward,
+            &mut spell_list,
+            inventory_bag.map(|bag| bag.into_inner()),
+            &mut active_powerups,
+            &spell_catalog,
+        );
+    }
+
+    pending.0 = pending.0.saturating_sub(1);
+    next_state.set(GameState::InGame);
+}
+
+/// Move on to the next level, like the Continue button on the level complete screen
+pub fn auto_continue_level_system(
+    mut commands: Commands,
+    enemies_query: Query<Entity, With<Enemy>>,
+    loot_query: Query<Entity, With<DroppedItem>>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+    mut level_stats: ResMut<LevelStats>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for entity in enemies_query.iter().chain(loot_query.iter()) {
+        commands.entity(entity).try_despawn();
+    }
+    for mut transform in player_query.iter_mut() {
+        transform.translation = Vec3::ZERO;
+    }
+    level_stats.reset();
+    next_state.set(GameState::InGame);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod simulated_steering_tests {
+        use super::*;
+
+        #[test]
+        fn flees_from_a_nearby_enemy() {
+            let direction = simulated_steering(
+                Vec2::ZERO,
+                &[Vec2::new(2.0, 0.0)],
+                None,
+                &ArenaBounds::default(),
+            );
+            assert!(direction.x < -0.99);
+        }
+
+        #[test]
+        fn ignores_distant_enemies_and_walks_to_loot() {
+            let direction = simulated_steering(
+                Vec2::ZERO,
+                &[Vec2::new(SIM_DANGER_RADIUS + 1.0, 0.0)],
+                Some(Vec2::new(0.0, 5.0)),
+                &ArenaBounds::default(),
+            );
+            assert!((direction - Vec2::Y).length() < 0.001);
+        }
+
+        #[test]
+        fn idles_in_the_center_with_nothing_around() {
+            let direction = simulated_steering(Vec2::ZERO, &[], None, &ArenaBounds::default());
+            assert_eq!(direction, Vec2::ZERO);
+        }
+
+        #[test]
+        fn heads_back_from_the_wall() {
+            let bounds = ArenaBounds::new(20.0);
+            let direction = simulated_steering(Vec2::new(19.0, 0.0), &[], None, &bounds);
+            assert!(direction.x < -0.99);
+        }
+    }
+
+    mod auto_pick_level_up_tests {
+        use super::*;
+        use bevy::ecs::system::RunSystemOnce;
+        use bevy::state::app::StatesPlugin;
+        use crate::level_up::components::{LevelUpChoice, LevelUpReward};
+        use crate::game::components::Rarity;
+        use crate::powerup::components::PowerupType;
+
+        #[test]
+        fn applies_first_choice_and_resumes() {
+            let mut app = App::new();
+            app.add_plugins(StatesPlugin);
+            app.init_state::<GameState>();
+            app.init_resource::<SpellList>();
+            app.init_resource::<ActivePowerups>();
+            app.init_resource::<SpellCatalog>();
+            app.insert_resource(PendingLevelUps(2));
+            app.insert_resource(LevelUpChoices {
+                choices: vec![
+                    LevelUpChoice {
+                        reward: LevelUpReward::NewSpell(crate::spell::SpellType::IceShard),
+                        rarity: Rarity::Common,
+                    },
+                    LevelUpChoice {
+                        reward: LevelUpReward::Powerup(PowerupType::MaxHealth),
+                        rarity: Rarity::Common,
+                    },
+                ],
+            });
+
+            let _ = app.world_mut().run_system_once(auto_pick_level_up_system);
+
+            let spell_list = app.world().resource::<SpellList>();
+            assert_eq!(spell_list.iter_spells().count(), 1);
+            assert_eq!(app.world().resource::<PendingLevelUps>().0, 1);
+            assert!(matches!(
+                *app.world().resource::<NextState<GameState>>(),
+                NextState::Pending(GameState::InGame)
+            ));
+        }
+    }
+}
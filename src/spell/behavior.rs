@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use bevy_kira_audio::prelude::*;
+
+use crate::audio::plugin::{SoundLimiter, WeaponSoundChannel};
+use crate::collision::spatial::SpatialEnemies;
+use crate::combat::DamageEvent;
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
+use crate::player::components::Player;
+use crate::spell::components::Spell;
+use crate::spell::resources::SpellBehaviorRegistry;
+use crate::spell::SpellId;
+use crate::spells::fire::fireball_effects::FireballEffects;
+use crate::spells::fire::materials::FireballChargeMaterial;
+
+/// Everything a spell needs to know about a single cast.
+///
+/// Built once per cast by `spell_casting_system` and handed to the handler
+/// registered for the spell's `SpellId`. Optional fields are `None` when the
+/// matching resource is missing (e.g. in tests or headless runs).
+pub struct SpellCastContext<'a, 'w> {
+    /// The spell being cast
+    pub spell: &'a Spell,
+    /// Final damage after attunement multiplier
+    pub damage: f32,
+    /// Whisper's full 3D position (spell origin)
+    pub origin: Vec3,
+    /// Target enemy position on the XZ plane
+    pub target_pos: Vec2,
+    /// Target enemy entity
+    pub target_entity: Entity,
+    /// Spatial enemy queries, for spells that pick their own targets
+    pub enemies: &'a SpatialEnemies<'a, 'a>,
+    /// The player, for spells that attach effects to them
+    pub player: Option<(Entity, &'a Transform, &'a Player)>,
+    /// Run rng for any roll made while casting (see `GameRng`)
+    pub rng: &'a mut GameRng,
+    /// Writer for spells that deal damage immediately on cast
+    pub damage_events: Option<&'a mut MessageWriter<'w, DamageEvent>>,
+    pub game_meshes: Option<&'a GameMeshes>,
+    pub game_materials: Option<&'a GameMaterials>,
+    pub asset_server: Option<&'a Res<'a, AssetServer>>,
+    pub weapon_channel: Option<&'a mut AudioChannel<WeaponSoundChannel>>,
+    pub sound_limiter: Option<&'a mut SoundLimiter>,
+    /// Particle effects for spells using Hanabi (Fireball)
+    pub fireball_effects: Option<&'a FireballEffects>,
+    /// Shader materials for the fireball charge phase
+    pub fireball_charge_materials: Option<&'a mut Assets<FireballChargeMaterial>>,
+}
+
+/// Cast handler signature shared by every spell.
+pub type SpellCastHandler = fn(&mut Commands, &mut SpellCastContext);
+
+/// Extension trait so spell plugins can register their cast handlers on the App.
+pub trait SpellBehaviorAppExt {
+    /// Register (or replace) the cast handler for a spell id.
+    /// Built-in spells pass their `SpellType`, which converts to its id.
+    fn register_spell_behavior(
+        &mut self,
+        id: impl Into<SpellId>,
+        handler: SpellCastHandler,
+    ) -> &mut Self;
+}
+
+impl SpellBehaviorAppExt for App {
+    fn register_spell_behavior(
+        &mut self,
+        id: impl Into<SpellId>,
+        handler: SpellCastHandler,
+    ) -> &mut Self {
+        self.init_resource::<SpellBehaviorRegistry>();
+        self.world_mut()
+            .resource_mut::<SpellBehaviorRegistry>()
+            .register(id, handler);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spell::SpellType;
+
+    fn noop_handler(_commands: &mut Commands, _ctx: &mut SpellCastContext) {}
+
+    #[test]
+    fn register_spell_behavior_creates_registry() {
+        let mut app = App::new();
+        app.register_spell_behavior(SpellType::Fireball, noop_handler);
+
+        let registry = app.world().resource::<SpellBehaviorRegistry>();
+        assert!(registry.contains(&SpellId::from(SpellType::Fireball)));
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn register_spell_behavior_keeps_existing_entries() {
+        let mut app = App::new();
+        app.register_spell_behavior(SpellType::Fireball, noop_handler)
+            .register_spell_behavior(SpellType::IceShard, noop_handler);
+
+        let registry = app.world().resource::<SpellBehaviorRegistry>();
+        assert!(registry.contains(&SpellId::from(SpellType::Fireball)));
+        assert!(registry.contains(&SpellId::from(SpellType::IceShard)));
+    }
+}
@@ -1,13 +1,19 @@
 use bevy::prelude::*;
 use crate::element::Element;
+use crate::spell::definitions::SpellCatalog;
+use crate::spell::spell_id::SpellId;
 use crate::spell::spell_type::SpellType;
 
 #[derive(Component, Clone, Debug)]
 pub struct Spell {
     pub spell_type: SpellType,
+    /// Id of the cast behavior this spell uses (see `SpellBehaviorRegistry`)
+    pub id: SpellId,
     pub element: Element,
     pub name: String,
     pub description: String,
+    /// Icon texture; spells without one use their element's texture
+    pub icon: Option<String>,
     pub level: u32,       // 1-10
     pub fire_rate: f32,   // seconds between casts (1/shots_per_second)
     pub base_damage: f32, // base damage at level 1
@@ -15,26 +21,53 @@ pub struct Spell {
 }
 
 impl Spell {
-    /// Create a new spell with the given type and default values from SpellType.
+    /// Create a new spell with the given type and its built-in definition.
+    /// Spells the player gains in a game come from the active catalog
+    /// through `from_catalog` instead.
     pub fn new(spell_type: SpellType) -> Self {
-        let element = spell_type.element();
-        let name = spell_type.name().to_string();
-        let description = spell_type.description().to_string();
-        let base_damage = spell_type.base_damage();
+        Self::from_catalog(spell_type, SpellCatalog::built_in())
+    }
+
+    /// Create a new spell with the given type and its definition in `catalog`.
+    pub fn from_catalog(spell_type: SpellType, catalog: &SpellCatalog) -> Self {
+        let definition = spell_type.definition(catalog);
         // Convert fire_rate (shots/sec) to seconds between casts
-        let fire_rate = 1.0 / spell_type.fire_rate();
+        let fire_rate = 1.0 / definition.fire_rate;
         Self {
             spell_type,
-            element,
-            name,
-            description,
+            id: SpellId::from(spell_type),
+            element: spell_type.element(),
+            name: definition.name.clone(),
+            description: definition.description.clone(),
+            icon: definition.icon.clone(),
             level: 1,
             fire_rate,
-            base_damage,
+            base_damage: definition.base_damage,
             last_fired: 0.0,
         }
     }
 
+    /// Create a spell cast by the behavior registered under `id`, such as one
+    /// a plugin adds without a `SpellType` variant. `spell_type` supplies its
+    /// element, starting values and the type its stats are recorded under.
+    pub fn with_id(id: impl Into<SpellId>, spell_type: SpellType) -> Self {
+        Self {
+            id: id.into(),
+            ..Self::new(spell_type)
+        }
+    }
+
+    /// Re-read name, description, icon, damage and fire rate from `catalog`
+    /// (after the definitions change), keeping level and cooldown.
+    pub fn refresh_definition(&mut self, catalog: &SpellCatalog) {
+        let fresh = Spell::from_catalog(self.spell_type, catalog);
+        self.name = fresh.name;
+        self.description = fresh.description;
+        self.icon = fresh.icon;
+        self.base_damage = fresh.base_damage;
+        self.fire_rate = fresh.fire_rate;
+    }
+
     /// Calculate actual damage based on spell level.
     ///
     /// # Formula
@@ -100,6 +133,19 @@ impl Spell {
         }
     }
 
+    /// Critical hit chance based on spell level.
+    ///
+    /// Each level above 1 adds 1% to the spell type's base chance:
+    /// a 5% spell crits 14% of the time at level 10.
+    pub fn crit_chance(&self) -> f32 {
+        self.spell_type.crit_chance() + (self.level - 1) as f32 * 0.01
+    }
+
+    /// Damage multiplier of this spell's critical hits.
+    pub fn crit_multiplier(&self) -> f32 {
+        self.spell_type.crit_multiplier()
+    }
+
     pub fn can_level_up(&self) -> bool {
         self.level < 10
     }
@@ -130,6 +176,20 @@ mod tests {
             assert_eq!(spell.element, Element::Fire);
         }
 
+        #[test]
+        fn spell_new_uses_spell_type_id() {
+            let spell = Spell::new(SpellType::IceShard);
+            assert_eq!(spell.id, SpellId::new("IceShard"));
+        }
+
+        #[test]
+        fn spell_with_id_keeps_spell_type_values() {
+            let spell = Spell::with_id("ArcaneSurge", SpellType::IceShard);
+            assert_eq!(spell.id, SpellId::new("ArcaneSurge"));
+            assert_eq!(spell.spell_type, SpellType::IceShard);
+            assert_eq!(spell.element, Element::Frost);
+        }
+
         #[test]
         fn spell_new_creates_with_correct_name() {
             let spell = Spell::new(SpellType::RadiantBeam);
@@ -148,14 +208,14 @@ mod tests {
         #[test]
         fn spell_new_sets_base_damage_from_spell_type() {
             let spell = Spell::new(SpellType::Fireball);
-            assert_eq!(spell.base_damage, SpellType::Fireball.base_damage());
+            assert_eq!(spell.base_damage, SpellType::Fireball.base_damage(SpellCatalog::built_in()));
         }
 
         #[test]
         fn spell_new_sets_fire_rate_from_spell_type() {
             let spell = Spell::new(SpellType::Fireball);
             // fire_rate in Spell is seconds between casts (inverse of shots/sec)
-            let expected = 1.0 / SpellType::Fireball.fire_rate();
+            let expected = 1.0 / SpellType::Fireball.fire_rate(SpellCatalog::built_in());
             assert!((spell.fire_rate - expected).abs() < 0.001);
         }
 
@@ -197,6 +257,22 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn refresh_definition_keeps_level_and_cooldown() {
+            let mut spell = Spell::new(SpellType::Fireball);
+            spell.level = 4;
+            spell.last_fired = 12.0;
+            spell.base_damage = 999.0;
+            spell.fire_rate = 9.0;
+
+            spell.refresh_definition(SpellCatalog::built_in());
+
+            assert_eq!(spell.base_damage, SpellType::Fireball.base_damage(SpellCatalog::built_in()));
+            assert_eq!(spell.fire_rate, 1.0 / SpellType::Fireball.fire_rate(SpellCatalog::built_in()));
+            assert_eq!(spell.level, 4);
+            assert_eq!(spell.last_fired, 12.0);
+        }
     }
 
     mod spell_damage_tests {
@@ -375,18 +451,18 @@ mod tests {
 
         #[test]
         fn spell_type_name_returns_display_name() {
-            assert_eq!(SpellType::Fireball.name(), "Fireball");
-            assert_eq!(SpellType::RadiantBeam.name(), "Radiant Beam");
-            assert_eq!(SpellType::ThunderStrike.name(), "Thunder Strike");
-            assert_eq!(SpellType::FrostNova.name(), "Frost Nova");
+            assert_eq!(SpellType::Fireball.name(SpellCatalog::built_in()), "Fireball");
+            assert_eq!(SpellType::RadiantBeam.name(SpellCatalog::built_in()), "Radiant Beam");
+            assert_eq!(SpellType::ThunderStrike.name(SpellCatalog::built_in()), "Thunder Strike");
+            assert_eq!(SpellType::FrostNova.name(SpellCatalog::built_in()), "Frost Nova");
         }
 
         #[test]
         fn spell_type_description_returns_text() {
-            assert!(!SpellType::Fireball.description().is_empty());
-            assert!(!SpellType::RadiantBeam.description().is_empty());
-            assert!(!SpellType::ThunderStrike.description().is_empty());
-            assert!(!SpellType::FrostNova.description().is_empty());
+            assert!(!SpellType::Fireball.description(SpellCatalog::built_in()).is_empty());
+            assert!(!SpellType::RadiantBeam.description(SpellCatalog::built_in()).is_empty());
+            assert!(!SpellType::ThunderStrike.description(SpellCatalog::built_in()).is_empty());
+            assert!(!SpellType::FrostNova.description(SpellCatalog::built_in()).is_empty());
         }
     }
 
@@ -544,4 +620,22 @@ mod tests {
             );
         }
     }
+
+    mod crit_tests {
+        use super::*;
+
+        #[test]
+        fn crit_chance_grows_with_level() {
+            let level_1 = create_spell(SpellType::Fireball, 1);
+            let level_10 = create_spell(SpellType::Fireball, 10);
+            assert_eq!(level_1.crit_chance(), SpellType::Fireball.crit_chance());
+            assert!((level_10.crit_chance() - (level_1.crit_chance() + 0.09)).abs() < 1e-6);
+        }
+
+        #[test]
+        fn crit_multiplier_comes_from_spell_type() {
+            let spell = create_spell(SpellType::ChaosBolt, 7);
+            assert_eq!(spell.crit_multiplier(), SpellType::ChaosBolt.crit_multiplier());
+        }
+    }
 }
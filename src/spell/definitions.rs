@@ -0,0 +1,540 @@
+use std::fmt;
+use std::sync::LazyLock;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::element::Element;
+use crate::inventory::bag::InventoryBag;
+use crate::inventory::resources::SpellList;
+use crate::spell::SpellType;
+
+/// Directory under `assets/` holding one definition file per element
+pub const SPELL_DEFINITIONS_DIR: &str = "spells";
+
+/// Definition files compiled in, used until the asset files have loaded
+/// (and by tests and apps without an asset server).
+const BUILT_IN_DEFINITIONS: [(Element, &str); 8] = [
+    (Element::Fire, include_str!("../../assets/spells/fire.ron")),
+    (Element::Frost, include_str!("../../assets/spells/frost.ron")),
+    (Element::Poison, include_str!("../../assets/spells/poison.ron")),
+    (Element::Lightning, include_str!("../../assets/spells/lightning.ron")),
+    (Element::Light, include_str!("../../assets/spells/light.ron")),
+    (Element::Dark, include_str!("../../assets/spells/dark.ron")),
+    (Element::Chaos, include_str!("../../assets/spells/chaos.ron")),
+    (Element::Psychic, include_str!("../../assets/spells/psychic.ron")),
+];
+
+/// Highest base damage a definition may have
+pub const MAX_BASE_DAMAGE: f32 = 1000.0;
+
+/// Highest fire rate (casts per second) a definition may have
+pub const MAX_FIRE_RATE: f32 = 20.0;
+
+/// Longest abbreviation that fits a spell slot without an icon
+pub const MAX_ABBREVIATION_LEN: usize = 3;
+
+/// Asset path of an element's definition file, e.g. `spells/fire.ron`
+pub fn spell_definition_path(element: Element) -> String {
+    format!("{}/{}.ron", SPELL_DEFINITIONS_DIR, element.name().to_lowercase())
+}
+
+/// Display text and balance values of one spell.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpellDefinition {
+    pub spell: SpellType,
+    pub name: String,
+    /// Shown in the spell slot when there is no icon
+    pub abbreviation: String,
+    pub description: String,
+    /// Damage at level 1, before level scaling
+    pub base_damage: f32,
+    /// Casts per second at level 1
+    pub fire_rate: f32,
+    /// Icon texture; spells without one show their abbreviation
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+impl SpellDefinition {
+    /// Check the values are usable, describing the first one that isn't.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err(format!("{:?} has an empty name", self.spell));
+        }
+        let abbreviation_len = self.abbreviation.chars().count();
+        if abbreviation_len == 0 || abbreviation_len > MAX_ABBREVIATION_LEN {
+            return Err(format!(
+                "{:?} abbreviation \"{}\" must be 1 to {} characters",
+                self.spell, self.abbreviation, MAX_ABBREVIATION_LEN
+            ));
+        }
+        if !(0.0..=MAX_BASE_DAMAGE).contains(&self.base_damage) {
+            return Err(format!(
+                "{:?} base_damage {} is outside 0..={}",
+                self.spell, self.base_damage, MAX_BASE_DAMAGE
+            ));
+        }
+        if !(self.fire_rate > 0.0 && self.fire_rate <= MAX_FIRE_RATE) {
+            return Err(format!(
+                "{:?} fire_rate {} must be above 0 and at most {}",
+                self.spell, self.fire_rate, MAX_FIRE_RATE
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Contents of one definition file: the spells of one element.
+#[derive(Asset, TypePath, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpellDefinitionFile {
+    pub spells: Vec<SpellDefinition>,
+}
+
+/// Why a definition file could not be loaded.
+#[derive(Debug)]
+pub enum SpellDefinitionError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not valid RON or names an unknown spell
+    Parse(String),
+}
+
+impl fmt::Display for SpellDefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpellDefinitionError::Io(source) => write!(f, "could not read spell definitions: {}", source),
+            SpellDefinitionError::Parse(reason) => write!(f, "spell definitions are invalid: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SpellDefinitionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SpellDefinitionError::Io(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for SpellDefinitionError {
+    fn from(source: std::io::Error) -> Self {
+        SpellDefinitionError::Io(source)
+    }
+}
+
+/// Parse the contents of a definition file.
+pub fn parse_spell_definition_file(contents: &str) -> Result<SpellDefinitionFile, SpellDefinitionError> {
+    ron::from_str(contents).map_err(|err| SpellDefinitionError::Parse(err.to_string()))
+}
+
+/// Loads `spells/*.ron` definition files through the asset server.
+#[derive(Default, TypePath)]
+pub struct SpellDefinitionLoader;
+
+impl AssetLoader for SpellDefinitionLoader {
+    type Asset = SpellDefinitionFile;
+    type Settings = ();
+    type Error = SpellDefinitionError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let contents = std::str::from_utf8(&bytes).map_err(|err| SpellDefinitionError::Parse(err.to_string()))?;
+        parse_spell_definition_file(contents)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+/// Every problem found while combining the definition files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellCatalogError {
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for SpellCatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "spell definitions are invalid: {}", self.problems.join("; "))
+    }
+}
+
+impl std::error::Error for SpellCatalogError {}
+
+/// A validated definition for every spell, indexed by `SpellType::id`.
+/// The active catalog is a resource that `apply_spell_definitions` replaces
+/// whenever the definition files load or change.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SpellCatalog {
+    definitions: Vec<SpellDefinition>,
+}
+
+/// Catalog of the compiled-in definition files, parsed on first use
+static BUILT_IN_CATALOG: LazyLock<SpellCatalog> = LazyLock::new(|| {
+    let files: Vec<(Element, String, SpellDefinitionFile)> = BUILT_IN_DEFINITIONS
+        .iter()
+        .map(|(element, contents)| {
+            let file = parse_spell_definition_file(contents).expect("built-in spell definitions parse");
+            (*element, spell_definition_path(*element), file)
+        })
+        .collect();
+    SpellCatalog::from_files(files.iter().map(|(element, path, file)| (*element, path.as_str(), file)))
+        .expect("built-in spell definitions are valid")
+});
+
+impl Default for SpellCatalog {
+    fn default() -> Self {
+        Self::built_in().clone()
+    }
+}
+
+impl SpellCatalog {
+    /// The compiled-in definitions. Used until the asset files have loaded,
+    /// and by `Spell::new` for spells made outside a running game.
+    pub fn built_in() -> &'static SpellCatalog {
+        &BUILT_IN_CATALOG
+    }
+
+    /// Combine per-element definition files, checking that every spell is
+    /// defined exactly once, in its element's file, with usable values.
+    pub fn from_files<'a>(
+        files: impl IntoIterator<Item = (Element, &'a str, &'a SpellDefinitionFile)>,
+    ) -> Result<Self, SpellCatalogError> {
+        let mut slots: Vec<Option<SpellDefinition>> = vec![None; SpellType::all().len()];
+        let mut problems = Vec::new();
+
+        for (element, path, file) in files {
+            for definition in &file.spells {
+                if definition.spell.element() != element {
+                    problems.push(format!(
+                        "{}: {:?} belongs in the {} file",
+                        path,
+                        definition.spell,
+                        definition.spell.element().name()
+                    ));
+                }
+                if let Err(reason) = definition.validate() {
+                    problems.push(format!("{}: {}", path, reason));
+                }
+                let slot = &mut slots[definition.spell.id() as usize];
+                if slot.is_some() {
+                    problems.push(format!("{}: {:?} is defined more than once", path, definition.spell));
+                } else {
+                    *slot = Some(definition.clone());
+                }
+            }
+        }
+
+        for spell in SpellType::all().iter().filter(|spell| slots[spell.id() as usize].is_none()) {
+            problems.push(format!("{:?} has no definition", spell));
+        }
+
+        if !problems.is_empty() {
+            return Err(SpellCatalogError { problems });
+        }
+        Ok(Self {
+            definitions: slots.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn get(&self, spell: SpellType) -> &SpellDefinition {
+        &self.definitions[spell.id() as usize]
+    }
+}
+
+/// Handles keeping the definition files loaded (and watched for changes).
+#[derive(Resource, Debug, Default)]
+pub struct SpellDefinitionHandles(pub Vec<(Element, Handle<SpellDefinitionFile>)>);
+
+/// Start loading every element's definition file
+pub fn load_spell_definitions(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let handles = Element::all()
+        .iter()
+        .map(|element| (*element, asset_server.load(spell_definition_path(*element))))
+        .collect();
+    commands.insert_resource(SpellDefinitionHandles(handles));
+}
+
+/// Once every file has loaded, and again whenever one changes on disk,
+/// validate the files and put them into effect. Equipped and bagged spells
+/// pick up the new values; invalid files are reported and ignored.
+pub fn apply_spell_definitions(
+    mut asset_events: MessageReader<AssetEvent<SpellDefinitionFile>>,
+    handles: Option<Res<SpellDefinitionHandles>>,
+    files: Res<Assets<SpellDefinitionFile>>,
+    mut spell_catalog: ResMut<SpellCatalog>,
+    spell_list: Option<ResMut<SpellList>>,
+    inventory_bag: Option<ResMut<InventoryBag>>,
+) {
+    let changed = asset_events.read().fold(false, |changed, event| {
+        changed
+            || matches!(
+                event,
+                AssetEvent::LoadedWithDependencies { .. } | AssetEvent::Modified { .. }
+            )
+    });
+    let Some(handles) = handles.filter(|_| changed) else {
+        return;
+    };
+    // Wait until every element's file is available
+    let Some(loaded) = handles
+        .0
+        .iter()
+        .map(|(element, handle)| files.get(handle).map(|file| (*element, spell_definition_path(*element), file)))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+
+    match SpellCatalog::from_files(loaded.iter().map(|(element, path, file)| (*element, path.as_str(), *file))) {
+        Ok(catalog) => {
+            *spell_catalog = catalog;
+            if let Some(mut spell_list) = spell_list {
+                spell_list
+                    .slots_mut()
+                    .iter_mut()
+                    .flatten()
+                    .for_each(|spell| spell.refresh_definition(&spell_catalog));
+            }
+            if let Some(mut inventory_bag) = inventory_bag {
+                inventory_bag
+                    .slots_mut()
+                    .iter_mut()
+                    .flatten()
+                    .for_each(|spell| spell.refresh_definition(&spell_catalog));
+            }
+            info!("Loaded spell definitions from assets/{}", SPELL_DEFINITIONS_DIR);
+        }
+        Err(err) => warn!("Keeping previous spell definitions: {}", err),
+    }
+}
+
+/// Spell definitions plugin: loads `assets/spells/*.ron` through the asset
+/// server and reloads them when they change. Needs the `AssetPlugin`.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<SpellCatalog>()
+        .init_asset::<SpellDefinitionFile>()
+        .init_asset_loader::<SpellDefinitionLoader>()
+        .add_systems(Startup, load_spell_definitions)
+        .add_systems(Update, apply_spell_definitions);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn built_in_files() -> Vec<(Element, String, SpellDefinitionFile)> {
+        BUILT_IN_DEFINITIONS
+            .iter()
+            .map(|(element, contents)| {
+                (*element, spell_definition_path(*element), parse_spell_definition_file(contents).unwrap())
+            })
+            .collect()
+    }
+
+    fn catalog_from(files: &[(Element, String, SpellDefinitionFile)]) -> Result<SpellCatalog, SpellCatalogError> {
+        SpellCatalog::from_files(files.iter().map(|(element, path, file)| (*element, path.as_str(), file)))
+    }
+
+    mod spell_definition_tests {
+        use super::*;
+
+        fn fireball() -> SpellDefinition {
+            SpellCatalog::built_in().get(SpellType::Fireball).clone()
+        }
+
+        #[test]
+        fn built_in_definition_is_valid() {
+            assert!(fireball().validate().is_ok());
+        }
+
+        #[test]
+        fn rejects_out_of_range_values() {
+            let zero_rate = SpellDefinition { fire_rate: 0.0, ..fireball() };
+            assert!(zero_rate.validate().unwrap_err().contains("fire_rate"));
+
+            let negative_damage = SpellDefinition { base_damage: -1.0, ..fireball() };
+            assert!(negative_damage.validate().unwrap_err().contains("base_damage"));
+
+            let nan_damage = SpellDefinition { base_damage: f32::NAN, ..fireball() };
+            assert!(nan_damage.validate().is_err());
+
+            let long_abbreviation = SpellDefinition { abbreviation: "FIRE".to_string(), ..fireball() };
+            assert!(long_abbreviation.validate().unwrap_err().contains("abbreviation"));
+        }
+
+        #[test]
+        fn icon_is_optional_in_files() {
+            let file = parse_spell_definition_file(
+                r#"(spells: [(spell: IceShard, name: "Ice Shard", abbreviation: "IS",
+                    description: "Cold.", base_damage: 8.0, fire_rate: 1.5)])"#,
+            )
+            .unwrap();
+            assert_eq!(file.spells[0].icon, None);
+        }
+
+        #[test]
+        fn unknown_spell_fails_to_parse() {
+            let result = parse_spell_definition_file(
+                r#"(spells: [(spell: NotASpell, name: "X", abbreviation: "X",
+                    description: "", base_damage: 1.0, fire_rate: 1.0)])"#,
+            );
+            assert!(matches!(result, Err(SpellDefinitionError::Parse(_))));
+        }
+    }
+
+    mod spell_catalog_tests {
+        use super::*;
+
+        #[test]
+        fn built_in_files_define_every_spell() {
+            let catalog = catalog_from(&built_in_files()).unwrap();
+            for spell in SpellType::all() {
+                assert_eq!(catalog.get(*spell).spell, *spell);
+            }
+        }
+
+        #[test]
+        fn definition_paths_are_per_element() {
+            assert_eq!(spell_definition_path(Element::Fire), "spells/fire.ron");
+            assert_eq!(spell_definition_path(Element::Psychic), "spells/psychic.ron");
+        }
+
+        #[test]
+        fn reports_missing_spells() {
+            let mut files = built_in_files();
+            files[0].2.spells.retain(|definition| definition.spell != SpellType::Hellfire);
+
+            let err = catalog_from(&files).unwrap_err();
+            assert_eq!(err.problems, vec!["Hellfire has no definition".to_string()]);
+        }
+
+        #[test]
+        fn reports_duplicates_and_wrong_element() {
+            let mut files = built_in_files();
+            let fireball = files[0].2.spells[0].clone();
+            files[1].2.spells.push(fireball);
+
+            let err = catalog_from(&files).unwrap_err();
+            assert!(err.problems.iter().any(|problem| problem.contains("belongs in the Fire file")));
+            assert!(err.problems.iter().any(|problem| problem.contains("more than once")));
+        }
+
+        #[test]
+        fn reports_out_of_range_values_with_the_file() {
+            let mut files = built_in_files();
+            files[3].2.spells[0].fire_rate = -2.0;
+
+            let err = catalog_from(&files).unwrap_err();
+            assert_eq!(err.problems.len(), 1);
+            assert!(err.problems[0].starts_with("spells/lightning.ron: Spark fire_rate"));
+        }
+    }
+
+    mod apply_spell_definitions_tests {
+        use super::*;
+        use crate::spell::Spell;
+
+        /// App running `apply_spell_definitions` over the built-in files,
+        /// added to `Assets` directly instead of through an asset server
+        fn setup_app() -> (App, Vec<Handle<SpellDefinitionFile>>) {
+            let mut app = App::new();
+            app.init_resource::<Assets<SpellDefinitionFile>>()
+                .add_message::<AssetEvent<SpellDefinitionFile>>()
+                .init_resource::<SpellCatalog>()
+                .init_resource::<SpellList>()
+                .add_systems(Update, apply_spell_definitions);
+
+            let handles: Vec<(Element, Handle<SpellDefinitionFile>)> = built_in_files()
+                .into_iter()
+                .map(|(element, _, file)| {
+                    (element, app.world_mut().resource_mut::<Assets<SpellDefinitionFile>>().add(file))
+                })
+                .collect();
+            app.insert_resource(SpellDefinitionHandles(handles.clone()));
+
+            let mut fireball = Spell::new(SpellType::Fireball);
+            fireball.level = 3;
+            app.world_mut().resource_mut::<SpellList>().equip(fireball);
+
+            (app, handles.into_iter().map(|(_, handle)| handle).collect())
+        }
+
+        fn edit_fireball(app: &mut App, fire_file: &Handle<SpellDefinitionFile>, edit: impl FnOnce(&mut SpellDefinition)) {
+            {
+                let mut files = app.world_mut().resource_mut::<Assets<SpellDefinitionFile>>();
+                edit(
+                    files
+                        .get_mut(fire_file)
+                        .unwrap()
+                        .spells
+                        .iter_mut()
+                        .find(|definition| definition.spell == SpellType::Fireball)
+                        .unwrap(),
+                );
+            }
+            app.world_mut().write_message(AssetEvent::Modified { id: fire_file.id() });
+        }
+
+        fn catalog_fireball(app: &App) -> SpellDefinition {
+            app.world().resource::<SpellCatalog>().get(SpellType::Fireball).clone()
+        }
+
+        fn equipped_fireball(app: &App) -> Spell {
+            app.world().resource::<SpellList>().get_spell(0).unwrap().clone()
+        }
+
+        #[test]
+        fn loads_then_applies_valid_changes_and_keeps_previous_on_invalid() {
+            let (mut app, handles) = setup_app();
+            for handle in &handles {
+                app.world_mut().write_message(AssetEvent::LoadedWithDependencies { id: handle.id() });
+            }
+            app.update();
+            assert_eq!(*app.world().resource::<SpellCatalog>(), *SpellCatalog::built_in());
+
+            // A valid edit goes into effect and refreshes the equipped spell
+            edit_fireball(&mut app, &handles[0], |fireball| {
+                fireball.name = "Greater Fireball".to_string();
+                fireball.base_damage = 40.0;
+            });
+            app.update();
+            assert_eq!(catalog_fireball(&app).name, "Greater Fireball");
+            assert_eq!(catalog_fireball(&app).base_damage, 40.0);
+            let equipped = equipped_fireball(&app);
+            assert_eq!(equipped.name, "Greater Fireball");
+            assert_eq!(equipped.base_damage, 40.0);
+            assert_eq!(equipped.level, 3);
+
+            // An invalid edit is ignored; the previous catalog stays in effect
+            edit_fireball(&mut app, &handles[0], |fireball| {
+                fireball.base_damage = 5.0;
+                fireball.fire_rate = -1.0;
+            });
+            app.update();
+            assert_eq!(catalog_fireball(&app).base_damage, 40.0);
+            assert!(catalog_fireball(&app).fire_rate > 0.0);
+            assert_eq!(equipped_fireball(&app).base_damage, 40.0);
+        }
+
+        #[test]
+        fn waits_for_every_file() {
+            let (mut app, handles) = setup_app();
+            edit_fireball(&mut app, &handles[0], |fireball| fireball.base_damage = 40.0);
+            app.world_mut().resource_mut::<Assets<SpellDefinitionFile>>().remove(&handles[7]);
+            app.update();
+
+            assert_eq!(*app.world().resource::<SpellCatalog>(), *SpellCatalog::built_in());
+        }
+    }
+}
@@ -1,11 +1,17 @@
+pub mod behavior;
 pub mod components;
+pub mod definitions;
 pub mod plugin;
 pub mod resources;
+pub mod spell_id;
 pub mod spell_type;
 pub mod systems;
 
 // Re-export public API
+pub use behavior::*;
 pub use components::*;
 pub use plugin::*;
+pub use resources::*;
+pub use spell_id::SpellId;
 pub use spell_type::SpellType;
 pub use systems::*;
\ No newline at end of file
@@ -12,7 +12,7 @@ use crate::spells::fire::fireball::{
     fireball_core_effect_update_system,
     fireball_charge_effect_update_system, fireball_trail_effect_update_system,
     fireball_explosion_spawn_system, fireball_explosion_cleanup_system,
-    fireball_ground_collision_system,
+    fireball_ground_collision_system, fireball_obstacle_explosion_system,
     explosion_core_effect_update_system, explosion_fire_effect_update_system,
     explosion_dark_impact_effect_update_system,
     explosion_embers_effect_update_system,
@@ -87,6 +87,7 @@ use crate::spells::poison::venom_spray::{
 use crate::spells::poison::toxic_glob::{
     poison_puddle_cleanup_system, poison_puddle_damage_system,
     toxic_glob_collision_system, toxic_glob_lifetime_system, toxic_glob_movement_system,
+    toxic_glob_obstacle_burst_system,
 };
 use crate::spells::fire::cinder_shot::{
     cinder_shot_collision_detection, cinder_shot_collision_effects,
@@ -237,6 +238,75 @@ use crate::spells::light::beacon::{
 use crate::spells::light::purify::{
     cleanup_purify_bursts, trigger_purify_burst, update_purify_cooldown,
 };
+use crate::spells::fire::flame_arc::{
+    flame_arc_impact_detection, flame_arc_impact_effects, flame_arc_movement_system,
+    flame_fragment_collision_detection, flame_fragment_collision_effects,
+    flame_fragment_lifetime_system, flame_fragment_movement_system,
+    FlameArcImpactEvent, FlameFragmentCollisionEvent,
+};
+use crate::spells::frost::permafrost::{
+    apply_freeze_buildup_system, check_freeze_threshold_system,
+    decay_freeze_stacks_system, update_frozen_status_system,
+};
+use crate::spells::poison::venom_bolt::{
+    venom_bolt_collision_detection, venom_bolt_collision_effects,
+    venom_bolt_lifetime_system, venom_bolt_movement_system,
+    VenomBoltEnemyCollisionEvent,
+};
+use crate::spells::poison::blight_zone::{
+    blight_zone_cleanup_system, blight_zone_damage_system,
+};
+use crate::spells::lightning::arc_surge::{
+    arc_surge_duration_system, arc_surge_pulse_system,
+};
+use crate::spells::lightning::static_orb::{
+    static_orb_duration_system, static_orb_zap_system,
+};
+use crate::spells::lightning::stormcall::{
+    stormcall_duration_system, stormcall_marker_move_system, stormcall_marker_strike_system,
+    stormcall_strike_cleanup_system, stormcall_strike_damage_system,
+};
+use crate::spells::dark::shadow_bolt::{
+    shadow_bolt_collision_detection, shadow_bolt_collision_effects,
+    shadow_bolt_lifetime_system, shadow_bolt_movement_system,
+    ShadowBoltEnemyCollisionEvent,
+};
+use crate::spells::dark::grim_tether::{
+    cleanup_grim_tether_visual_system, cleanup_tethered_enemy_system,
+    grim_tether_damage_share_system, grim_tether_visual_system, update_grim_tether_system,
+};
+use crate::spells::dark::soul_rend::{
+    soul_rend_collision_detection, soul_rend_collision_effects,
+    soul_rend_lifetime_system, soul_rend_movement_system,
+    SoulRendEnemyCollisionEvent,
+};
+use crate::spells::chaos::anomaly::{
+    anomaly_zone_cleanup_system, anomaly_zone_damage_system, anomaly_zone_tick_system,
+};
+use crate::spells::chaos::pandemonium::{
+    apply_pandemonium_to_enemies_system, cleanup_confusion_effect_system,
+    cleanup_pandemonium_burst_system, confused_enemy_attack_system,
+    update_confused_enemy_targeting_system,
+};
+use crate::spells::chaos::volatile_orb::{
+    volatile_orb_blast_cleanup_system, volatile_orb_detonation_system,
+};
+use crate::spells::chaos::cataclysm::{
+    cataclysm_blast_damage_system, update_cataclysm_blasts, update_cataclysm_markers,
+};
+use crate::collision::events::ProjectileObstacleHit;
+use crate::collision::systems::projectile_obstacle_system;
+use crate::spells::{
+    chaos::chaos_bolt::ChaosBoltProjectile,
+    dark::{shadow_bolt::ShadowBoltProjectile, soul_rend::SoulRendProjectile},
+    fire::{cinder_shot::CinderShotProjectile, fireball::FireballProjectile, flame_arc::FlameArcProjectile},
+    frost::{
+        frozen_orb::FrozenOrb, ice_lance::IceLanceProjectile, ice_shard::IceShardProjectile,
+        ice_shards::IceShardFragment, shatter::ShatterProjectile,
+    },
+    light::solar_flare::SolarFlareProjectile,
+    poison::{poison_cloud::PoisonCloudProjectile, toxic_glob::ToxicGlobProjectile, venom_bolt::VenomBoltProjectile},
+};
 use crate::whisper::resources::{SpellOrigin, WhisperAttunement};
 
 /// Re-export spell_follow_player_system from inventory for now
@@ -255,6 +325,8 @@ pub fn plugin(app: &mut App) {
         .init_resource::<WhisperAttunement>()
         // Initialize LastSpellCast for Echo Thought spell tracking
         .init_resource::<LastSpellCast>()
+        // Register cast handlers for every built-in spell (other plugins may add or override)
+        .add_plugins(crate::spells::plugin)
         // Register spell collision events
         .add_message::<FireballEnemyCollisionEvent>()
         .add_message::<IceShardEnemyCollisionEvent>()
@@ -264,6 +336,12 @@ pub fn plugin(app: &mut App) {
         .add_message::<ShatterEnemyCollisionEvent>()
         .add_message::<ChaosBoltEnemyCollisionEvent>()
         .add_message::<SolarFlareExplosionEvent>()
+        .add_message::<FlameArcImpactEvent>()
+        .add_message::<FlameFragmentCollisionEvent>()
+        .add_message::<VenomBoltEnemyCollisionEvent>()
+        .add_message::<ShadowBoltEnemyCollisionEvent>()
+        .add_message::<SoulRendEnemyCollisionEvent>()
+        .add_message::<ProjectileObstacleHit>()
         // Movement systems - spell follows player
         .add_systems(
             Update,
@@ -327,6 +405,34 @@ pub fn plugin(app: &mut App) {
                 .in_set(GameSet::Combat)
                 .run_if(in_state(GameState::InGame)),
         )
+        // Spell projectiles against rocks and arena walls (see SpellType::obstacle_response),
+        // then the spells that explode on them
+        .add_systems(
+            Update,
+            (
+                (
+                    projectile_obstacle_system::<FireballProjectile>,
+                    projectile_obstacle_system::<FlameArcProjectile>,
+                    projectile_obstacle_system::<CinderShotProjectile>,
+                    projectile_obstacle_system::<IceShardProjectile>,
+                    projectile_obstacle_system::<IceLanceProjectile>,
+                    projectile_obstacle_system::<IceShardFragment>,
+                    projectile_obstacle_system::<ShatterProjectile>,
+                    projectile_obstacle_system::<FrozenOrb>,
+                    projectile_obstacle_system::<VenomBoltProjectile>,
+                    projectile_obstacle_system::<ToxicGlobProjectile>,
+                    projectile_obstacle_system::<PoisonCloudProjectile>,
+                    projectile_obstacle_system::<ChaosBoltProjectile>,
+                    projectile_obstacle_system::<SolarFlareProjectile>,
+                    projectile_obstacle_system::<ShadowBoltProjectile>,
+                    projectile_obstacle_system::<SoulRendProjectile>,
+                ),
+                (fireball_obstacle_explosion_system, toxic_glob_obstacle_burst_system),
+            )
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
         // Shader material time updates (must run before effect updates to animate shaders)
         .add_systems(
             Update,
@@ -1517,6 +1623,285 @@ pub fn plugin(app: &mut App) {
             cleanup_purify_bursts
                 .in_set(GameSet::Cleanup)
                 .run_if(in_state(GameState::InGame)),
+        )
+        // Flame Arc (MeteorShower) systems - arc and fragment movement in Movement, impact and fragment collision in Combat
+        .add_systems(
+            Update,
+            (
+                flame_arc_movement_system,
+                flame_fragment_movement_system,
+                flame_fragment_lifetime_system,
+            )
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                flame_arc_impact_detection,
+                flame_arc_impact_effects,
+            )
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                flame_fragment_collision_detection,
+                flame_fragment_collision_effects,
+            )
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Permafrost (AbsoluteZero) systems - freeze buildup in Combat, decay/threshold/frozen tick in Effects
+        .add_systems(
+            Update,
+            apply_freeze_buildup_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                decay_freeze_stacks_system,
+                check_freeze_threshold_system,
+                update_frozen_status_system,
+            )
+                .chain()
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Venom Bolt systems - movement and lifetime in Movement, collision in Combat
+        .add_systems(
+            Update,
+            (
+                venom_bolt_movement_system,
+                venom_bolt_lifetime_system,
+            )
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                venom_bolt_collision_detection,
+                venom_bolt_collision_effects,
+            )
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Blight Zone (Blight) systems - damage in Combat, cleanup in Cleanup
+        .add_systems(
+            Update,
+            blight_zone_damage_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            blight_zone_cleanup_system
+                .in_set(GameSet::Cleanup)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Arc Surge (Spark) systems - duration tick in Effects, pulse damage in Combat
+        .add_systems(
+            Update,
+            arc_surge_duration_system
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            arc_surge_pulse_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Static Orb (StaticField) systems - duration tick in Effects, zap damage in Combat
+        .add_systems(
+            Update,
+            static_orb_duration_system
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            static_orb_zap_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Stormcall systems - duration in Effects, marker movement in Movement, strikes in Combat, cleanup in Cleanup
+        .add_systems(
+            Update,
+            stormcall_duration_system
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            stormcall_marker_move_system
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                stormcall_marker_strike_system,
+                stormcall_strike_damage_system,
+            )
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            stormcall_strike_cleanup_system
+                .in_set(GameSet::Cleanup)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Shadow Bolt systems - movement and lifetime in Movement, collision in Combat
+        .add_systems(
+            Update,
+            (
+                shadow_bolt_movement_system,
+                shadow_bolt_lifetime_system,
+            )
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                shadow_bolt_collision_detection,
+                shadow_bolt_collision_effects,
+            )
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Grim Tether (Corruption) systems - duration and visual in Effects, damage share in Combat, cleanup in Cleanup
+        .add_systems(
+            Update,
+            (
+                update_grim_tether_system,
+                grim_tether_visual_system,
+            )
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            grim_tether_damage_share_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                cleanup_tethered_enemy_system,
+                cleanup_grim_tether_visual_system,
+            )
+                .in_set(GameSet::Cleanup)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Soul Rend (Oblivion) systems - movement and lifetime in Movement, collision in Combat
+        .add_systems(
+            Update,
+            (
+                soul_rend_movement_system,
+                soul_rend_lifetime_system,
+            )
+                .in_set(GameSet::Movement)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                soul_rend_collision_detection,
+                soul_rend_collision_effects,
+            )
+                .chain()
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Anomaly (WildMagic) systems - tick in Effects, damage in Combat, cleanup in Cleanup
+        .add_systems(
+            Update,
+            anomaly_zone_tick_system
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            anomaly_zone_damage_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            anomaly_zone_cleanup_system
+                .in_set(GameSet::Cleanup)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Pandemonium (Randomize) systems - confusion and retargeting in Effects, infighting in Combat, cleanup in Cleanup
+        .add_systems(
+            Update,
+            (
+                apply_pandemonium_to_enemies_system,
+                update_confused_enemy_targeting_system,
+            )
+                .chain()
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            confused_enemy_attack_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            (
+                cleanup_confusion_effect_system,
+                cleanup_pandemonium_burst_system,
+            )
+                .in_set(GameSet::Cleanup)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Volatile Orb (Unstable) systems - detonation in Combat, blast cleanup in Cleanup
+        .add_systems(
+            Update,
+            volatile_orb_detonation_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            volatile_orb_blast_cleanup_system
+                .in_set(GameSet::Cleanup)
+                .run_if(in_state(GameState::InGame)),
+        )
+        // Cataclysm systems - marker countdown in Effects, blast damage in Combat, visual cleanup in Cleanup
+        .add_systems(
+            Update,
+            update_cataclysm_markers
+                .in_set(GameSet::Effects)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            cataclysm_blast_damage_system
+                .in_set(GameSet::Combat)
+                .run_if(in_state(GameState::InGame)),
+        )
+        .add_systems(
+            Update,
+            update_cataclysm_blasts
+                .in_set(GameSet::Cleanup)
+                .run_if(in_state(GameState::InGame)),
         );
 }
 
@@ -1 +1,85 @@
-// Spell resources will be added here as needed
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::spell::behavior::SpellCastHandler;
+use crate::spell::{SpellId, SpellType};
+
+/// Registry of cast handlers keyed by `SpellId`.
+/// Spell modules register their handlers from their plugin via
+/// `SpellBehaviorAppExt::register_spell_behavior`; `spell_casting_system`
+/// looks up the handler for each ready spell.
+#[derive(Resource, Default)]
+pub struct SpellBehaviorRegistry {
+    handlers: HashMap<SpellId, SpellCastHandler>,
+}
+
+impl SpellBehaviorRegistry {
+    /// Register a handler, returning the handler it replaced (if any).
+    pub fn register(
+        &mut self,
+        id: impl Into<SpellId>,
+        handler: SpellCastHandler,
+    ) -> Option<SpellCastHandler> {
+        self.handlers.insert(id.into(), handler)
+    }
+
+    pub fn get(&self, id: &SpellId) -> Option<SpellCastHandler> {
+        self.handlers.get(id).copied()
+    }
+
+    pub fn contains(&self, id: &SpellId) -> bool {
+        self.handlers.contains_key(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    /// Built-in spell types that have no registered handler.
+    pub fn missing(&self) -> Vec<SpellType> {
+        SpellType::all()
+            .iter()
+            .copied()
+            .filter(|spell_type| !self.contains(&SpellId::from(*spell_type)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spell::behavior::SpellCastContext;
+
+    fn noop_handler(_commands: &mut Commands, _ctx: &mut SpellCastContext) {}
+
+    #[test]
+    fn empty_registry_reports_all_spells_missing() {
+        let registry = SpellBehaviorRegistry::default();
+        assert!(registry.is_empty());
+        assert_eq!(registry.missing().len(), SpellType::all().len());
+    }
+
+    #[test]
+    fn register_returns_replaced_handler() {
+        let mut registry = SpellBehaviorRegistry::default();
+        assert!(registry.register(SpellType::Fireball, noop_handler).is_none());
+        assert!(registry.register(SpellType::Fireball, noop_handler).is_some());
+        assert_eq!(registry.len(), 1);
+        assert!(registry.get(&SpellId::from(SpellType::Fireball)).is_some());
+        assert!(!registry.missing().contains(&SpellType::Fireball));
+    }
+
+    #[test]
+    fn registers_ids_without_a_spell_type() {
+        let mut registry = SpellBehaviorRegistry::default();
+        registry.register("ArcaneSurge", noop_handler);
+
+        assert!(registry.contains(&SpellId::new("ArcaneSurge")));
+        assert_eq!(registry.missing().len(), SpellType::all().len());
+    }
+}
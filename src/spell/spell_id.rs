@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::spell::SpellType;
+
+/// Open identifier for a spell behavior.
+///
+/// `SpellBehaviorRegistry` is keyed by this rather than by `SpellType`, and
+/// every `Spell` carries one, so a plugin can register and equip a spell that
+/// has no enum variant (see `Spell::with_id`). Built-in spells use their
+/// `SpellType` variant name (e.g. `"Fireball"`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpellId(Cow<'static, str>);
+
+impl SpellId {
+    pub const fn new(id: &'static str) -> Self {
+        Self(Cow::Borrowed(id))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Ids of the built-in spells, indexed by `SpellType::id`.
+const BUILT_IN_IDS: [SpellId; 64] = [
+    // Fire
+    SpellId::new("Fireball"),
+    SpellId::new("FlameLance"),
+    SpellId::new("Ashfall"),
+    SpellId::new("MeteorShower"),
+    SpellId::new("PhoenixFlare"),
+    SpellId::new("Combustion"),
+    SpellId::new("Immolate"),
+    SpellId::new("Hellfire"),
+    // Frost
+    SpellId::new("IceShard"),
+    SpellId::new("FrostNova"),
+    SpellId::new("Blizzard"),
+    SpellId::new("FrozenRay"),
+    SpellId::new("GlacialSpike"),
+    SpellId::new("IceBarrier"),
+    SpellId::new("Shatter"),
+    SpellId::new("AbsoluteZero"),
+    // Poison
+    SpellId::new("VenomBolt"),
+    SpellId::new("PlagueCloud"),
+    SpellId::new("ToxicSpray"),
+    SpellId::new("Miasma"),
+    SpellId::new("CorrosivePool"),
+    SpellId::new("Pandemic"),
+    SpellId::new("Blight"),
+    SpellId::new("Necrosis"),
+    // Lightning
+    SpellId::new("Spark"),
+    SpellId::new("ChainLightning"),
+    SpellId::new("ThunderStrike"),
+    SpellId::new("StaticField"),
+    SpellId::new("Flashstep"),
+    SpellId::new("Overcharge"),
+    SpellId::new("Electrocute"),
+    SpellId::new("StormCall"),
+    // Light
+    SpellId::new("HolyBeam"),
+    SpellId::new("RadiantBeam"),
+    SpellId::new("Radiance"),
+    SpellId::new("Smite"),
+    SpellId::new("DivineLight"),
+    SpellId::new("Consecration"),
+    SpellId::new("Purify"),
+    SpellId::new("Judgment"),
+    // Dark
+    SpellId::new("ShadowBolt"),
+    SpellId::new("VoidRift"),
+    SpellId::new("DarkPulse"),
+    SpellId::new("Corruption"),
+    SpellId::new("SoulDrain"),
+    SpellId::new("Nightmare"),
+    SpellId::new("Eclipse"),
+    SpellId::new("Oblivion"),
+    // Chaos
+    SpellId::new("WildMagic"),
+    SpellId::new("Entropy"),
+    SpellId::new("ChaosBolt"),
+    SpellId::new("Randomize"),
+    SpellId::new("Unstable"),
+    SpellId::new("Paradox"),
+    SpellId::new("Mayhem"),
+    SpellId::new("Cataclysm"),
+    // Psychic
+    SpellId::new("MindBlast"),
+    SpellId::new("Telekinesis"),
+    SpellId::new("PsychicWave"),
+    SpellId::new("Confusion"),
+    SpellId::new("MentalSpike"),
+    SpellId::new("Hallucination"),
+    SpellId::new("Dominate"),
+    SpellId::new("PsychicShatter"),
+];
+
+impl From<SpellType> for SpellId {
+    fn from(spell_type: SpellType) -> Self {
+        BUILT_IN_IDS[spell_type.id() as usize].clone()
+    }
+}
+
+impl From<&'static str> for SpellId {
+    fn from(id: &'static str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<String> for SpellId {
+    fn from(id: String) -> Self {
+        Self(Cow::Owned(id))
+    }
+}
+
+impl fmt::Display for SpellId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spell_type_ids_use_variant_names() {
+        assert_eq!(SpellId::from(SpellType::Fireball), SpellId::new("Fireball"));
+        assert_eq!(SpellId::from(SpellType::PsychicShatter).as_str(), "PsychicShatter");
+    }
+
+    #[test]
+    fn built_in_ids_match_variant_names_without_allocating() {
+        for spell_type in SpellType::all() {
+            let id = SpellId::from(*spell_type);
+            assert_eq!(id.as_str(), format!("{:?}", spell_type));
+            assert!(matches!(id.0, Cow::Borrowed(_)));
+        }
+    }
+
+    #[test]
+    fn spell_type_ids_are_unique() {
+        let mut ids: Vec<SpellId> = SpellType::all().iter().copied().map(SpellId::from).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), SpellType::all().len());
+    }
+}
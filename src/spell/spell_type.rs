@@ -1,8 +1,12 @@
+use crate::collision::components::ObstacleResponse;
 use crate::element::Element;
+use crate::game::components::Rarity;
+use crate::spell::definitions::{SpellCatalog, SpellDefinition};
+use serde::{Deserialize, Serialize};
 
 /// All 64 spell types across 8 elements (8 spells per element).
 /// Each variant represents a unique spell with its own mechanics.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum SpellType {
     // Fire spells (8)
     Fireball,
@@ -186,446 +190,89 @@ impl SpellType {
         }
     }
 
-    /// Returns the display name for this spell.
-    pub fn name(&self) -> &'static str {
-        match self {
-            // Fire
-            SpellType::Fireball => "Fireball",
-            SpellType::FlameLance => "Flame Lance",
-            SpellType::Ashfall => "Ashfall",
-            SpellType::MeteorShower => "Meteor Shower",
-            SpellType::PhoenixFlare => "Phoenix Flare",
-            SpellType::Combustion => "Combustion",
-            SpellType::Immolate => "Immolate",
-            SpellType::Hellfire => "Hellfire",
-
-            // Frost
-            SpellType::IceShard => "Ice Shard",
-            SpellType::FrostNova => "Frost Nova",
-            SpellType::Blizzard => "Blizzard",
-            SpellType::FrozenRay => "Frozen Ray",
-            SpellType::GlacialSpike => "Glacial Spike",
-            SpellType::IceBarrier => "Ice Barrier",
-            SpellType::Shatter => "Shatter",
-            SpellType::AbsoluteZero => "Absolute Zero",
-
-            // Poison
-            SpellType::VenomBolt => "Venom Bolt",
-            SpellType::PlagueCloud => "Plague Cloud",
-            SpellType::ToxicSpray => "Toxic Spray",
-            SpellType::Miasma => "Miasma",
-            SpellType::CorrosivePool => "Corrosive Pool",
-            SpellType::Pandemic => "Pandemic",
-            SpellType::Blight => "Blight",
-            SpellType::Necrosis => "Necrosis",
-
-            // Lightning
-            SpellType::Spark => "Spark",
-            SpellType::ChainLightning => "Chain Lightning",
-            SpellType::ThunderStrike => "Thunder Strike",
-            SpellType::StaticField => "Static Field",
-            SpellType::Flashstep => "Flashstep",
-            SpellType::Overcharge => "Overcharge",
-            SpellType::Electrocute => "Electrocute",
-            SpellType::StormCall => "Storm Call",
-
-            // Light
-            SpellType::HolyBeam => "Holy Beam",
-            SpellType::RadiantBeam => "Radiant Beam",
-            SpellType::Radiance => "Radiance",
-            SpellType::Smite => "Smite",
-            SpellType::DivineLight => "Divine Light",
-            SpellType::Consecration => "Beacon",
-            SpellType::Purify => "Purify",
-            SpellType::Judgment => "Judgment",
-
-            // Dark
-            SpellType::ShadowBolt => "Shadow Bolt",
-            SpellType::VoidRift => "Void Rift",
-            SpellType::DarkPulse => "Dark Pulse",
-            SpellType::Corruption => "Corruption",
-            SpellType::SoulDrain => "Soul Drain",
-            SpellType::Nightmare => "Nightmare",
-            SpellType::Eclipse => "Eclipse",
-            SpellType::Oblivion => "Oblivion",
-
-            // Chaos
-            SpellType::WildMagic => "Wild Magic",
-            SpellType::Entropy => "Entropy",
-            SpellType::ChaosBolt => "Chaos Bolt",
-            SpellType::Randomize => "Randomize",
-            SpellType::Unstable => "Unstable",
-            SpellType::Paradox => "Paradox",
-            SpellType::Mayhem => "Mayhem",
-            SpellType::Cataclysm => "Cataclysm",
+    /// Definition of this spell in `catalog` (see `SpellCatalog`)
+    pub fn definition<'a>(&self, catalog: &'a SpellCatalog) -> &'a SpellDefinition {
+        catalog.get(*self)
+    }
 
-            // Psychic
-            SpellType::MindBlast => "Mind Cage",
-            SpellType::Telekinesis => "Synapse Shock",
-            SpellType::PsychicWave => "Psychic Wave",
-            SpellType::Confusion => "Brainburn",
-            SpellType::MentalSpike => "Mental Spike",
-            SpellType::Hallucination => "Hallucination",
-            SpellType::Dominate => "Dominate",
-            SpellType::PsychicShatter => "Psychic Shatter",
-        }
+    /// Returns the display name for this spell.
+    pub fn name<'a>(&self, catalog: &'a SpellCatalog) -> &'a str {
+        &self.definition(catalog).name
     }
 
     /// Returns the flavor description for this spell.
-    pub fn description(&self) -> &'static str {
-        match self {
-            // Fire
-            SpellType::Fireball => "A blazing projectile that ignites enemies on impact.",
-            SpellType::FlameLance => "A piercing lance of concentrated fire.",
-            SpellType::Ashfall => "Embers rain down over an area dealing sustained damage.",
-            SpellType::MeteorShower => "Summons falling meteors to devastate an area.",
-            SpellType::PhoenixFlare => "Releases a burst of phoenix fire that heals allies.",
-            SpellType::Combustion => "Causes enemies to spontaneously combust.",
-            SpellType::Immolate => "Sets the target ablaze with lingering flames.",
-            SpellType::Hellfire => "Calls upon infernal flames from the depths.",
-
-            // Frost
-            SpellType::IceShard => "Launches a razor-sharp shard of ice.",
-            SpellType::FrostNova => "An icy explosion that freezes nearby enemies.",
-            SpellType::Blizzard => "A fierce snowstorm that slows and damages.",
-            SpellType::FrozenRay => "A continuous beam of freezing cold.",
-            SpellType::GlacialSpike => "A massive spike of ice erupts from the ground.",
-            SpellType::IceBarrier => "Creates a protective barrier of ice.",
-            SpellType::Shatter => "Shatters frozen enemies for massive damage.",
-            SpellType::AbsoluteZero => "Drops temperature to lethal levels.",
-
-            // Poison
-            SpellType::VenomBolt => "A toxic projectile that poisons on contact.",
-            SpellType::PlagueCloud => "Creates a lingering cloud of deadly plague.",
-            SpellType::ToxicSpray => "Sprays a cone of corrosive poison.",
-            SpellType::Miasma => "A creeping mist that weakens all within.",
-            SpellType::CorrosivePool => "Creates a pool of acid on the ground.",
-            SpellType::Pandemic => "Spreads infection between nearby enemies.",
-            SpellType::Blight => "Withers the life force of the target.",
-            SpellType::Necrosis => "Causes flesh to decay and rot.",
-
-            // Lightning
-            SpellType::Spark => "A quick jolt of electricity.",
-            SpellType::ChainLightning => "Lightning that arcs between multiple targets.",
-            SpellType::ThunderStrike => "Lightning strikes from above, dealing area damage.",
-            SpellType::StaticField => "Creates a field that shocks nearby enemies.",
-            SpellType::Flashstep => "Brief teleport releasing lightning at origin and destination.",
-            SpellType::Overcharge => "Supercharges the caster with electric power.",
-            SpellType::Electrocute => "Channels continuous lightning into a target.",
-            SpellType::StormCall => "Summons a devastating electrical storm.",
-
-            // Light
-            SpellType::HolyBeam => "A beam of purifying holy light.",
-            SpellType::RadiantBeam => "A focused beam of pure light energy.",
-            SpellType::Radiance => "Emits blinding light in all directions.",
-            SpellType::Smite => "Calls down divine judgment on a target.",
-            SpellType::DivineLight => "Bathes an area in healing light.",
-            SpellType::Consecration => "Light source that draws enemies while damaging them.",
-            SpellType::Purify => "Cleanses corruption and heals wounds.",
-            SpellType::Judgment => "Delivers ultimate divine punishment.",
-
-            // Dark
-            SpellType::ShadowBolt => "A bolt of concentrated darkness.",
-            SpellType::VoidRift => "Opens a rift to the void that pulls enemies in.",
-            SpellType::DarkPulse => "Releases a wave of dark energy.",
-            SpellType::Corruption => "Infects the target with creeping darkness.",
-            SpellType::SoulDrain => "Steals life force from enemies.",
-            SpellType::Nightmare => "Traps enemies in terrifying visions.",
-            SpellType::Eclipse => "Blocks all light, empowering dark attacks.",
-            SpellType::Oblivion => "Erases targets from existence.",
-
-            // Chaos
-            SpellType::WildMagic => "Unpredictable magical energy with random effects.",
-            SpellType::Entropy => "Accelerates decay and disorder.",
-            SpellType::ChaosBolt => "A projectile with randomly changing properties.",
-            SpellType::Randomize => "Scrambles the properties of affected targets.",
-            SpellType::Unstable => "Creates volatile energy that may explode.",
-            SpellType::Paradox => "Warps reality in impossible ways.",
-            SpellType::Mayhem => "Causes widespread chaotic destruction.",
-            SpellType::Cataclysm => "Unleashes ultimate chaotic devastation.",
-
-            // Psychic
-            SpellType::MindBlast => "Traps enemies in a psychic field, preventing escape.",
-            SpellType::Telekinesis => "Stuns enemies briefly with mental overload.",
-            SpellType::PsychicWave => "A wave of mental energy that stuns.",
-            SpellType::Confusion => "Psychic aura that burns enemy minds with stacking damage.",
-            SpellType::MentalSpike => "Pierces mental defenses with focused thought.",
-            SpellType::Hallucination => "Makes enemies see things that aren't there.",
-            SpellType::Dominate => "Takes control of an enemy's mind.",
-            SpellType::PsychicShatter => "Breaks the mind completely.",
-        }
+    pub fn description<'a>(&self, catalog: &'a SpellCatalog) -> &'a str {
+        &self.definition(catalog).description
     }
 
     /// Returns the base damage for this spell (before level scaling).
-    pub fn base_damage(&self) -> f32 {
-        match self {
-            // Fire - generally high damage
-            SpellType::Fireball => 15.0,
-            SpellType::FlameLance => 25.0,
-            SpellType::Ashfall => 18.0, // Area DOT, moderate base for multiple ember hits
-            SpellType::MeteorShower => 30.0,
-            SpellType::PhoenixFlare => 18.0,
-            SpellType::Combustion => 22.0,
-            SpellType::Immolate => 12.0, // DOT spell, lower initial
-            SpellType::Hellfire => 35.0,
-
-            // Frost - moderate damage with control
-            SpellType::IceShard => 12.0,
-            SpellType::FrostNova => 15.0,
-            SpellType::Blizzard => 18.0,
-            SpellType::FrozenRay => 20.0,
-            SpellType::GlacialSpike => 28.0,
-            SpellType::IceBarrier => 5.0, // Defensive, low damage
-            SpellType::Shatter => 40.0,   // Bonus vs frozen
-            SpellType::AbsoluteZero => 35.0,
-
-            // Poison - DOT focused
-            SpellType::VenomBolt => 10.0,
-            SpellType::PlagueCloud => 8.0,
-            SpellType::ToxicSpray => 14.0,
-            SpellType::Miasma => 6.0,
-            SpellType::CorrosivePool => 12.0,
-            SpellType::Pandemic => 10.0,
-            SpellType::Blight => 16.0,
-            SpellType::Necrosis => 25.0,
-
-            // Lightning - fast, chain damage
-            SpellType::Spark => 8.0,
-            SpellType::ChainLightning => 15.0,
-            SpellType::ThunderStrike => 30.0,
-            SpellType::StaticField => 10.0,
-            SpellType::Flashstep => 20.0, // Mobility spell with AoE damage at origin/destination
-            SpellType::Overcharge => 5.0, // Buff, low direct damage
-            SpellType::Electrocute => 20.0,
-            SpellType::StormCall => 35.0,
-
-            // Light - healing/support focused
-            SpellType::HolyBeam => 18.0,
-            SpellType::RadiantBeam => 22.0,
-            SpellType::Radiance => 15.0,
-            SpellType::Smite => 28.0,
-            SpellType::DivineLight => 10.0,
-            SpellType::Consecration => 12.0,
-            SpellType::Purify => 8.0,
-            SpellType::Judgment => 40.0,
-
-            // Dark - life steal, debuffs
-            SpellType::ShadowBolt => 16.0,
-            SpellType::VoidRift => 25.0,
-            SpellType::DarkPulse => 20.0,
-            SpellType::Corruption => 10.0,
-            SpellType::SoulDrain => 15.0,
-            SpellType::Nightmare => 12.0,
-            SpellType::Eclipse => 18.0,
-            SpellType::Oblivion => 45.0,
-
-            // Chaos - unpredictable
-            SpellType::WildMagic => 20.0,
-            SpellType::Entropy => 15.0,
-            SpellType::ChaosBolt => 22.0,
-            SpellType::Randomize => 10.0,
-            SpellType::Unstable => 25.0,
-            SpellType::Paradox => 20.0,
-            SpellType::Mayhem => 28.0,
-            SpellType::Cataclysm => 50.0,
-
-            // Psychic - crowd control
-            SpellType::MindBlast => 20.0,
-            SpellType::Telekinesis => 12.0,
-            SpellType::PsychicWave => 15.0,
-            SpellType::Confusion => 8.0,
-            SpellType::MentalSpike => 25.0,
-            SpellType::Hallucination => 10.0,
-            SpellType::Dominate => 5.0,
-            SpellType::PsychicShatter => 35.0,
-        }
+    pub fn base_damage(&self, catalog: &SpellCatalog) -> f32 {
+        self.definition(catalog).base_damage
     }
 
     /// Returns the optional icon texture path for this spell.
     /// Spells without custom icons use element-colored backgrounds.
-    pub fn icon_path(&self) -> Option<&'static str> {
-        match self {
-            SpellType::Fireball => Some("textures/spell-fireball.png"),
-            SpellType::FlameLance => Some("textures/spell-flame-lance.png"),
-            SpellType::Ashfall => Some("textures/spell-ashfall.png"),
-            SpellType::MeteorShower => Some("textures/spell-meteor-shower.png"),
-            SpellType::PhoenixFlare => Some("textures/spell-phoenix-flare.png"),
-            SpellType::Combustion => Some("textures/spell-combustion.png"),
-            SpellType::Immolate => Some("textures/spell-immolate.png"),
-            SpellType::Hellfire => Some("textures/spell-hellfire.png"),
-            _ => None,
-        }
+    pub fn icon_path<'a>(&self, catalog: &'a SpellCatalog) -> Option<&'a str> {
+        self.definition(catalog).icon.as_deref()
     }
 
     /// Returns a short abbreviation for this spell (2-3 characters).
     /// Used when no icon texture is available.
-    pub fn abbreviation(&self) -> &'static str {
-        match self {
-            // Fire
-            SpellType::Fireball => "FB",
-            SpellType::FlameLance => "FL",
-            SpellType::Ashfall => "AF",
-            SpellType::MeteorShower => "MS",
-            SpellType::PhoenixFlare => "PF",
-            SpellType::Combustion => "CB",
-            SpellType::Immolate => "IM",
-            SpellType::Hellfire => "HF",
-
-            // Frost
-            SpellType::IceShard => "IS",
-            SpellType::FrostNova => "FN",
-            SpellType::Blizzard => "BZ",
-            SpellType::FrozenRay => "FR",
-            SpellType::GlacialSpike => "GS",
-            SpellType::IceBarrier => "IB",
-            SpellType::Shatter => "SH",
-            SpellType::AbsoluteZero => "AZ",
-
-            // Poison
-            SpellType::VenomBolt => "VB",
-            SpellType::PlagueCloud => "PC",
-            SpellType::ToxicSpray => "TS",
-            SpellType::Miasma => "MI",
-            SpellType::CorrosivePool => "CP",
-            SpellType::Pandemic => "PD",
-            SpellType::Blight => "BL",
-            SpellType::Necrosis => "NC",
-
-            // Lightning
-            SpellType::Spark => "SP",
-            SpellType::ChainLightning => "CL",
-            SpellType::ThunderStrike => "TH",
-            SpellType::StaticField => "SF",
-            SpellType::Flashstep => "FS",
-            SpellType::Overcharge => "OC",
-            SpellType::Electrocute => "EL",
-            SpellType::StormCall => "SC",
+    pub fn abbreviation<'a>(&self, catalog: &'a SpellCatalog) -> &'a str {
+        &self.definition(catalog).abbreviation
+    }
 
-            // Light
-            SpellType::HolyBeam => "HB",
-            SpellType::RadiantBeam => "RB",
-            SpellType::Radiance => "RA",
-            SpellType::Smite => "SM",
-            SpellType::DivineLight => "DL",
-            SpellType::Consecration => "CS",
-            SpellType::Purify => "PU",
-            SpellType::Judgment => "JD",
+    /// Returns the fire rate in shots per second.
+    pub fn fire_rate(&self, catalog: &SpellCatalog) -> f32 {
+        self.definition(catalog).fire_rate
+    }
 
-            // Dark
-            SpellType::ShadowBolt => "SB",
-            SpellType::VoidRift => "VR",
-            SpellType::DarkPulse => "DP",
-            SpellType::Corruption => "CR",
-            SpellType::SoulDrain => "SD",
-            SpellType::Nightmare => "NM",
-            SpellType::Eclipse => "EC",
-            SpellType::Oblivion => "OB",
+    /// Base chance (0-1) for a direct hit of this spell to be critical.
+    /// Lightning and Chaos are the spikiest elements.
+    pub fn crit_chance(&self) -> f32 {
+        match self.element() {
+            Element::Lightning => 0.12,
+            Element::Chaos => 0.1,
+            Element::Psychic => 0.08,
+            _ => 0.05,
+        }
+    }
 
-            // Chaos
-            SpellType::WildMagic => "WM",
-            SpellType::Entropy => "EN",
-            SpellType::ChaosBolt => "CH",
-            SpellType::Randomize => "RZ",
-            SpellType::Unstable => "UN",
-            SpellType::Paradox => "PX",
-            SpellType::Mayhem => "MY",
-            SpellType::Cataclysm => "CT",
+    /// Damage multiplier of a critical hit with this spell.
+    pub fn crit_multiplier(&self) -> f32 {
+        match self.element() {
+            Element::Chaos => 2.0,
+            Element::Dark => 1.75,
+            _ => 1.5,
+        }
+    }
 
-            // Psychic
-            SpellType::MindBlast => "MB",
-            SpellType::Telekinesis => "TK",
-            SpellType::PsychicWave => "PW",
-            SpellType::Confusion => "CF",
-            SpellType::MentalSpike => "MK",
-            SpellType::Hallucination => "HL",
-            SpellType::Dominate => "DM",
-            SpellType::PsychicShatter => "PS",
+    /// Returns the rarity tier of this spell.
+    /// Spells are listed from basic to ultimate within each element, so the
+    /// position within the element decides the tier (2 common, 2 uncommon,
+    /// 2 rare, 1 epic, 1 legendary per element).
+    pub fn rarity(&self) -> Rarity {
+        match self.id() % 8 {
+            0 | 1 => Rarity::Common,
+            2 | 3 => Rarity::Uncommon,
+            4 | 5 => Rarity::Rare,
+            6 => Rarity::Epic,
+            _ => Rarity::Legendary,
         }
     }
 
-    /// Returns the fire rate in shots per second.
-    /// All fire rates reduced by 50% from original values.
-    pub fn fire_rate(&self) -> f32 {
+    /// How this spell's projectiles react to rocks and arena walls.
+    /// Lobbed and spectral projectiles pass over or through them;
+    /// spells without projectiles never reach the check.
+    pub fn obstacle_response(&self) -> ObstacleResponse {
         match self {
-            // Fire - balanced fire rates (50% reduction)
-            SpellType::Fireball => 1.0,
-            SpellType::FlameLance => 0.75,
-            SpellType::Ashfall => 0.125, // Long duration zone spell, slow cast rate
-            SpellType::MeteorShower => 0.15,
-            SpellType::PhoenixFlare => 0.5,
-            SpellType::Combustion => 0.6,
-            SpellType::Immolate => 1.25,
-            SpellType::Hellfire => 0.125,
-
-            // Frost - slower but impactful (50% reduction)
-            SpellType::IceShard => 1.5,
-            SpellType::FrostNova => 0.25,
-            SpellType::Blizzard => 0.2,
-            SpellType::FrozenRay => 2.0, // Continuous
-            SpellType::GlacialSpike => 0.4,
-            SpellType::IceBarrier => 0.1,
-            SpellType::Shatter => 0.3,
-            SpellType::AbsoluteZero => 0.075,
-
-            // Poison - sustained damage (50% reduction)
-            SpellType::VenomBolt => 1.25,
-            SpellType::PlagueCloud => 0.2,
-            SpellType::ToxicSpray => 0.75,
-            SpellType::Miasma => 0.15,
-            SpellType::CorrosivePool => 0.25,
-            SpellType::Pandemic => 0.3,
-            SpellType::Blight => 0.5,
-            SpellType::Necrosis => 0.25,
-
-            // Lightning - fast attacks (50% reduction)
-            SpellType::Spark => 2.5,
-            SpellType::ChainLightning => 0.6,
-            SpellType::ThunderStrike => 0.2,
-            SpellType::StaticField => 0.25,
-            SpellType::Flashstep => 0.15, // Short cooldown mobility spell
-            SpellType::Overcharge => 0.1,
-            SpellType::Electrocute => 1.5,
-            SpellType::StormCall => 0.1,
-
-            // Light - moderate (50% reduction)
-            SpellType::HolyBeam => 1.0,
-            SpellType::RadiantBeam => 2.0,
-            SpellType::Radiance => 0.4,
-            SpellType::Smite => 0.3,
-            SpellType::DivineLight => 0.25,
-            SpellType::Consecration => 0.15,
-            SpellType::Purify => 0.2,
-            SpellType::Judgment => 0.1,
-
-            // Dark - medium speed (50% reduction)
-            SpellType::ShadowBolt => 1.0,
-            SpellType::VoidRift => 0.2,
-            SpellType::DarkPulse => 0.4,
-            SpellType::Corruption => 0.75,
-            SpellType::SoulDrain => 1.0,
-            SpellType::Nightmare => 0.25,
-            SpellType::Eclipse => 0.1,
-            SpellType::Oblivion => 0.05,
-
-            // Chaos - varied (50% reduction)
-            SpellType::WildMagic => 0.75,
-            SpellType::Entropy => 0.5,
-            SpellType::ChaosBolt => 0.9,
-            SpellType::Randomize => 0.3,
-            SpellType::Unstable => 0.4,
-            SpellType::Paradox => 0.2,
-            SpellType::Mayhem => 0.25,
-            SpellType::Cataclysm => 0.05,
-
-            // Psychic - control focused (50% reduction)
-            SpellType::MindBlast => 0.6,
-            SpellType::Telekinesis => 1.0,
-            SpellType::PsychicWave => 0.3,
-            SpellType::Confusion => 0.4,
-            SpellType::MentalSpike => 0.75,
-            SpellType::Hallucination => 0.2,
-            SpellType::Dominate => 0.1,
-            SpellType::PsychicShatter => 0.15,
+            SpellType::Fireball | SpellType::Miasma => ObstacleResponse::Explode,
+            SpellType::MeteorShower
+            | SpellType::PlagueCloud
+            | SpellType::Blizzard
+            | SpellType::FrozenRay
+            | SpellType::Oblivion => ObstacleResponse::Pierce,
+            _ => ObstacleResponse::Stop,
         }
     }
 
@@ -809,6 +456,10 @@ mod tests {
     use super::*;
     use std::collections::HashSet;
 
+    fn catalog() -> &'static SpellCatalog {
+        SpellCatalog::built_in()
+    }
+
     mod spell_count_tests {
         use super::*;
 
@@ -973,7 +624,7 @@ mod tests {
         fn all_spells_have_non_empty_names() {
             for spell in SpellType::all() {
                 assert!(
-                    !spell.name().is_empty(),
+                    !spell.name(catalog()).is_empty(),
                     "Spell {:?} has empty name",
                     spell
                 );
@@ -982,12 +633,12 @@ mod tests {
 
         #[test]
         fn fireball_name_is_fireball() {
-            assert_eq!(SpellType::Fireball.name(), "Fireball");
+            assert_eq!(SpellType::Fireball.name(catalog()), "Fireball");
         }
 
         #[test]
         fn chain_lightning_name_has_space() {
-            assert_eq!(SpellType::ChainLightning.name(), "Chain Lightning");
+            assert_eq!(SpellType::ChainLightning.name(catalog()), "Chain Lightning");
         }
     }
 
@@ -998,7 +649,7 @@ mod tests {
         fn all_spells_have_non_empty_descriptions() {
             for spell in SpellType::all() {
                 assert!(
-                    !spell.description().is_empty(),
+                    !spell.description(catalog()).is_empty(),
                     "Spell {:?} has empty description",
                     spell
                 );
@@ -1008,7 +659,7 @@ mod tests {
         #[test]
         fn descriptions_end_with_period() {
             for spell in SpellType::all() {
-                let desc = spell.description();
+                let desc = spell.description(catalog());
                 assert!(
                     desc.ends_with('.'),
                     "Spell {:?} description should end with period: {}",
@@ -1026,7 +677,7 @@ mod tests {
         fn all_spells_have_positive_damage() {
             for spell in SpellType::all() {
                 assert!(
-                    spell.base_damage() > 0.0,
+                    spell.base_damage(catalog()) > 0.0,
                     "Spell {:?} should have positive base damage",
                     spell
                 );
@@ -1035,7 +686,7 @@ mod tests {
 
         #[test]
         fn fireball_base_damage_is_15() {
-            assert_eq!(SpellType::Fireball.base_damage(), 15.0);
+            assert_eq!(SpellType::Fireball.base_damage(catalog()), 15.0);
         }
 
         #[test]
@@ -1043,9 +694,9 @@ mod tests {
             let chaos_spells = SpellType::by_element(Element::Chaos);
             let max_damage = chaos_spells
                 .iter()
-                .map(|s| s.base_damage())
+                .map(|s| s.base_damage(catalog()))
                 .fold(f32::NEG_INFINITY, f32::max);
-            assert_eq!(SpellType::Cataclysm.base_damage(), max_damage);
+            assert_eq!(SpellType::Cataclysm.base_damage(catalog()), max_damage);
         }
     }
 
@@ -1056,7 +707,7 @@ mod tests {
         fn all_spells_have_positive_fire_rate() {
             for spell in SpellType::all() {
                 assert!(
-                    spell.fire_rate() > 0.0,
+                    spell.fire_rate(catalog()) > 0.0,
                     "Spell {:?} should have positive fire rate",
                     spell
                 );
@@ -1067,7 +718,7 @@ mod tests {
         fn spark_has_high_fire_rate() {
             // Spark should be a fast, quick spell (threshold halved with 50% fire rate reduction)
             assert!(
-                SpellType::Spark.fire_rate() >= 2.0,
+                SpellType::Spark.fire_rate(catalog()) >= 2.0,
                 "Spark should have high fire rate"
             );
         }
@@ -1076,7 +727,7 @@ mod tests {
         fn cataclysm_has_low_fire_rate() {
             // Ultimate spells should be slow
             assert!(
-                SpellType::Cataclysm.fire_rate() <= 0.2,
+                SpellType::Cataclysm.fire_rate(catalog()) <= 0.2,
                 "Cataclysm should have low fire rate"
             );
         }
@@ -1087,56 +738,56 @@ mod tests {
 
         #[test]
         fn fireball_has_custom_icon() {
-            let icon_path = SpellType::Fireball.icon_path();
+            let icon_path = SpellType::Fireball.icon_path(catalog());
             assert!(icon_path.is_some(), "Fireball should have a custom icon");
             assert_eq!(icon_path.unwrap(), "textures/spell-fireball.png");
         }
 
         #[test]
         fn flame_lance_has_custom_icon() {
-            let icon_path = SpellType::FlameLance.icon_path();
+            let icon_path = SpellType::FlameLance.icon_path(catalog());
             assert!(icon_path.is_some(), "FlameLance should have a custom icon");
             assert_eq!(icon_path.unwrap(), "textures/spell-flame-lance.png");
         }
 
         #[test]
         fn ashfall_has_custom_icon() {
-            let icon_path = SpellType::Ashfall.icon_path();
+            let icon_path = SpellType::Ashfall.icon_path(catalog());
             assert!(icon_path.is_some(), "Ashfall should have a custom icon");
             assert_eq!(icon_path.unwrap(), "textures/spell-ashfall.png");
         }
 
         #[test]
         fn meteor_shower_has_custom_icon() {
-            let icon_path = SpellType::MeteorShower.icon_path();
+            let icon_path = SpellType::MeteorShower.icon_path(catalog());
             assert!(icon_path.is_some(), "MeteorShower should have a custom icon");
             assert_eq!(icon_path.unwrap(), "textures/spell-meteor-shower.png");
         }
 
         #[test]
         fn phoenix_flare_has_custom_icon() {
-            let icon_path = SpellType::PhoenixFlare.icon_path();
+            let icon_path = SpellType::PhoenixFlare.icon_path(catalog());
             assert!(icon_path.is_some(), "PhoenixFlare should have a custom icon");
             assert_eq!(icon_path.unwrap(), "textures/spell-phoenix-flare.png");
         }
 
         #[test]
         fn combustion_has_custom_icon() {
-            let icon_path = SpellType::Combustion.icon_path();
+            let icon_path = SpellType::Combustion.icon_path(catalog());
             assert!(icon_path.is_some(), "Combustion should have a custom icon");
             assert_eq!(icon_path.unwrap(), "textures/spell-combustion.png");
         }
 
         #[test]
         fn immolate_has_custom_icon() {
-            let icon_path = SpellType::Immolate.icon_path();
+            let icon_path = SpellType::Immolate.icon_path(catalog());
             assert!(icon_path.is_some(), "Immolate should have a custom icon");
             assert_eq!(icon_path.unwrap(), "textures/spell-immolate.png");
         }
 
         #[test]
         fn hellfire_has_custom_icon() {
-            let icon_path = SpellType::Hellfire.icon_path();
+            let icon_path = SpellType::Hellfire.icon_path(catalog());
             assert!(icon_path.is_some(), "Hellfire should have a custom icon");
             assert_eq!(icon_path.unwrap(), "textures/spell-hellfire.png");
         }
@@ -1144,13 +795,71 @@ mod tests {
         #[test]
         fn most_spells_have_no_custom_icon() {
             // Verify that other spells don't have custom icons (yet)
-            assert!(SpellType::IceShard.icon_path().is_none());
-            assert!(SpellType::VenomBolt.icon_path().is_none());
-            assert!(SpellType::ChainLightning.icon_path().is_none());
-            assert!(SpellType::RadiantBeam.icon_path().is_none());
-            assert!(SpellType::ShadowBolt.icon_path().is_none());
-            assert!(SpellType::WildMagic.icon_path().is_none());
-            assert!(SpellType::MindBlast.icon_path().is_none());
+            assert!(SpellType::IceShard.icon_path(catalog()).is_none());
+            assert!(SpellType::VenomBolt.icon_path(catalog()).is_none());
+            assert!(SpellType::ChainLightning.icon_path(catalog()).is_none());
+            assert!(SpellType::RadiantBeam.icon_path(catalog()).is_none());
+            assert!(SpellType::ShadowBolt.icon_path(catalog()).is_none());
+            assert!(SpellType::WildMagic.icon_path(catalog()).is_none());
+            assert!(SpellType::MindBlast.icon_path(catalog()).is_none());
+        }
+    }
+
+    mod rarity_tests {
+        use super::*;
+
+        #[test]
+        fn each_element_has_one_legendary_spell() {
+            for element in Element::all() {
+                let legendaries = SpellType::by_element(*element)
+                    .iter()
+                    .filter(|spell| spell.rarity() == Rarity::Legendary)
+                    .count();
+                assert_eq!(legendaries, 1, "{:?}", element);
+            }
+        }
+
+        #[test]
+        fn basic_and_ultimate_spells_have_expected_rarity() {
+            assert_eq!(SpellType::Fireball.rarity(), Rarity::Common);
+            assert_eq!(SpellType::Hellfire.rarity(), Rarity::Legendary);
+            assert_eq!(SpellType::Shatter.rarity(), Rarity::Epic);
+            assert_eq!(SpellType::Cataclysm.rarity(), Rarity::Legendary);
+        }
+    }
+
+    mod crit_tests {
+        use super::*;
+
+        #[test]
+        fn every_spell_has_sane_crit_stats() {
+            for spell in SpellType::all() {
+                assert!((0.0..1.0).contains(&spell.crit_chance()), "{:?}", spell);
+                assert!(spell.crit_multiplier() > 1.0, "{:?}", spell);
+            }
+        }
+
+        #[test]
+        fn lightning_crits_more_often_than_fire() {
+            assert!(SpellType::Spark.crit_chance() > SpellType::Fireball.crit_chance());
+            assert!(SpellType::ChaosBolt.crit_multiplier() > SpellType::Fireball.crit_multiplier());
+        }
+    }
+
+    mod obstacle_response_tests {
+        use super::*;
+
+        #[test]
+        fn bolts_stop_and_fireball_explodes() {
+            assert_eq!(SpellType::IceShard.obstacle_response(), ObstacleResponse::Stop);
+            assert_eq!(SpellType::ShadowBolt.obstacle_response(), ObstacleResponse::Stop);
+            assert_eq!(SpellType::Fireball.obstacle_response(), ObstacleResponse::Explode);
+        }
+
+        #[test]
+        fn lobbed_and_piercing_spells_pass_obstacles() {
+            assert_eq!(SpellType::PlagueCloud.obstacle_response(), ObstacleResponse::Pierce);
+            assert_eq!(SpellType::FrozenRay.obstacle_response(), ObstacleResponse::Pierce);
         }
     }
 
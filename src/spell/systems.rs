@@ -1,23 +1,145 @@
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
 use rand::Rng;
 use crate::combat::DamageEvent;
-use crate::spell::SpellType;
+use crate::spell::{SpellId, SpellType};
 
-use crate::enemies::components::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::audio::plugin::*;
 use crate::audio::plugin::SoundLimiter;
-use crate::game::resources::{GameMeshes, GameMaterials};
+use crate::game::resources::{GameMeshes, GameMaterials, GameRng, SpellStats};
 use crate::movement::components::from_xz;
 use crate::player::components::Player;
 use crate::whisper::resources::SpellOrigin;
 
+use crate::inventory::resources::SpellList;
+use crate::spell::behavior::SpellCastContext;
+use crate::spell::resources::SpellBehaviorRegistry;
+use crate::whisper::resources::WhisperAttunement;
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn spell_casting_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    registry: Res<SpellBehaviorRegistry>,
+    (asset_server, mut weapon_channel, mut sound_limiter): (
+        Option<Res<AssetServer>>,
+        Option<ResMut<AudioChannel<WeaponSoundChannel>>>,
+        Option<ResMut<SoundLimiter>>,
+    ),
+    spell_origin: Res<SpellOrigin>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+    spatial_enemies: SpatialEnemies,
+    mut spell_list: ResMut<SpellList>,
+    attunement: Res<WhisperAttunement>,
+    mut damage_events: Option<MessageWriter<DamageEvent>>,
+    player_query: Query<(Entity, &Transform, &Player)>,
+    (mut last_spell_cast, mut spell_stats): (
+        ResMut<crate::spells::psychic::echo_thought::LastSpellCast>,
+        Option<ResMut<SpellStats>>,
+    ),
+    fireball_effects: Option<Res<crate::spells::fire::fireball_effects::FireballEffects>>,
+    mut fireball_charge_materials: Option<ResMut<Assets<crate::spells::fire::materials::FireballChargeMaterial>>>,
+    mut rng: ResMut<GameRng>,
+    mut warned_missing: Local<HashSet<SpellId>>,
+) {
+    let current_time = time.elapsed_secs();
+
+    // Check if Whisper has been collected (spells enabled)
+    let Some(origin_pos) = spell_origin.position else {
+        return; // No Whisper = no spells
+    };
+
+    // Extract XZ plane position for targeting calculations
+    let origin_xz = from_xz(origin_pos);
+
+    // Player is optional - only spells that attach effects to the player need it
+    let player = player_query.single().ok();
+
+    // Find 5 closest enemies to the spell origin (Whisper)
+    // Use XZ plane for distance calculation in 3D world
+    let closest_enemies = spatial_enemies.nearest_k(origin_xz, 5);
+
+    // If no enemies, don't cast
+    if closest_enemies.is_empty() {
+        return;
+    }
+
+    // Cast spells from all 5 slots in SpellList
+    for slot in 0..5 {
+        // Get spell from slot, skip empty slots
+        let Some(spell) = spell_list.get_spell(slot) else {
+            continue;
+        };
+
+        // Check cooldown
+        if current_time - spell.last_fired < spell.effective_fire_rate() {
+            continue;
+        }
+
+        // Select random target from 5 closest
+        let target_index = rng.gen_range(0..closest_enemies.len());
+        let target_pos = closest_enemies[target_index].1;
+
+        // Calculate damage with attunement multiplier
+        let attunement_multiplier = attunement.damage_multiplier(spell.element);
+        let final_damage = spell.damage() * attunement_multiplier;
+
+        // Cast the spell through its registered behavior
+        let Some(handler) = registry.get(&spell.id) else {
+            if warned_missing.insert(spell.id.clone()) {
+                warn!("No cast behavior registered for spell {}; it will not be cast", spell.id);
+            }
+            continue;
+        };
+        let mut ctx = SpellCastContext {
+            spell,
+            damage: final_damage,
+            origin: origin_pos,
+            target_pos,
+            target_entity: closest_enemies[target_index].0,
+            enemies: &spatial_enemies,
+            player,
+            rng: &mut rng,
+            damage_events: damage_events.as_mut(),
+            game_meshes: game_meshes.as_deref(),
+            game_materials: game_materials.as_deref(),
+            asset_server: asset_server.as_ref(),
+            weapon_channel: weapon_channel.as_deref_mut(),
+            sound_limiter: sound_limiter.as_deref_mut(),
+            fireball_effects: fireball_effects.as_deref(),
+            fireball_charge_materials: fireball_charge_materials.as_deref_mut(),
+        };
+        handler(&mut commands, &mut ctx);
+
+        if let Some(spell_stats) = spell_stats.as_mut() {
+            spell_stats.record_cast(spell.spell_type);
+        }
+
+        // Record last spell cast for Echo Thought (but not Hallucination itself to avoid infinite echoing)
+        if spell.spell_type != SpellType::Hallucination {
+            let direction = (target_pos - origin_xz).normalize_or_zero();
+            last_spell_cast.record(spell.spell_type, origin_xz, direction, final_damage);
+        }
+
+        // Update last_fired time
+        if let Some(spell_mut) = spell_list.get_spell_mut(slot) {
+            spell_mut.last_fired = current_time;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::*;
     use crate::element::Element;
     use crate::inventory::resources::SpellList;
     use crate::spell::components::Spell;
+    use crate::spell::resources::SpellBehaviorRegistry;
     use crate::spells::psychic::echo_thought::LastSpellCast;
     use crate::whisper::resources::WhisperAttunement;
 
@@ -28,6 +150,7 @@ mod tests {
         fn spell_casting_uses_spell_list_resource() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -62,6 +185,7 @@ mod tests {
         fn spell_casting_iterates_all_5_spell_slots() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -106,10 +230,54 @@ mod tests {
             assert!(thunder_count >= 1, "Thunder strike should cast from slot 2");
         }
 
+        #[test]
+        fn spell_casting_records_casts_in_spell_stats() {
+            let mut app = App::new();
+            app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
+
+            app.insert_resource(SpellOrigin {
+                position: Some(Vec3::new(0.0, 3.0, 0.0)),
+            });
+            app.init_resource::<WhisperAttunement>();
+            app.init_resource::<LastSpellCast>();
+            app.init_resource::<SpellStats>();
+
+            let mut spell_list = SpellList::default();
+            for spell_type in [SpellType::Fireball, SpellType::RadiantBeam] {
+                let mut spell = Spell::new(spell_type);
+                spell.last_fired = -10.0;
+                spell_list.equip(spell);
+            }
+            app.insert_resource(spell_list);
+
+            app.world_mut().spawn((
+                Enemy { speed: 50.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(100.0, 0.375, 0.0)),
+            ));
+
+            app.init_resource::<Time>();
+            app.update();
+
+            let stats = app.world().resource::<SpellStats>();
+            assert_eq!(stats.level.get(SpellType::Fireball).casts, 1);
+            assert_eq!(stats.level.get(SpellType::RadiantBeam).casts, 1);
+            assert_eq!(stats.run.get(SpellType::RadiantBeam).casts, 1);
+            assert_eq!(stats.level.get(SpellType::IceShard).casts, 0);
+
+            let beam = app.world_mut()
+                .query::<&crate::spells::light::radiant_beam::RadiantBeam>()
+                .single(app.world())
+                .unwrap()
+                .clone();
+            assert_eq!(beam.spell, Some(SpellType::RadiantBeam), "Beam damage is credited to its spell");
+        }
+
         #[test]
         fn empty_spell_slots_are_skipped() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -134,6 +302,7 @@ mod tests {
         fn cooldown_prevents_spell_casting() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -166,6 +335,7 @@ mod tests {
         fn cooldown_resets_after_cast() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -198,6 +368,7 @@ mod tests {
 
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             // Whisper at origin
             let whisper_pos = Vec3::new(0.0, 3.0, 0.0);
@@ -269,6 +440,7 @@ mod tests {
         fn attunement_bonus_applied_to_matching_element() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -311,6 +483,7 @@ mod tests {
         fn no_attunement_uses_base_damage() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -352,6 +525,7 @@ mod tests {
         fn mismatched_attunement_uses_base_damage() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -397,6 +571,7 @@ mod tests {
         fn spells_disabled_without_whisper() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin { position: None });
             app.init_resource::<WhisperAttunement>();
@@ -431,6 +606,7 @@ mod tests {
         fn spell_targets_from_closest_5_enemies() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -473,6 +649,7 @@ mod tests {
         fn radiant_beam_spawns_with_correct_damage_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -483,9 +660,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let beam = Spell {
                 spell_type: SpellType::RadiantBeam,
+                id: SpellType::RadiantBeam.into(),
                 element: Element::Light,
                 name: "Radiant Beam".to_string(),
                 description: "A beam of light.".to_string(),
+                icon: None,
                 level: 5,
                 fire_rate: 0.1,
                 base_damage: 10.0,
@@ -512,6 +691,7 @@ mod tests {
         fn radiant_beam_with_light_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -522,9 +702,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let beam = Spell {
                 spell_type: SpellType::RadiantBeam,
+                id: SpellType::RadiantBeam.into(),
                 element: Element::Light,
                 name: "Radiant Beam".to_string(),
                 description: "A beam of light.".to_string(),
+                icon: None,
                 level: 5,
                 fire_rate: 0.1,
                 base_damage: 10.0,
@@ -563,6 +745,7 @@ mod tests {
         fn thunder_strike_spawns_with_correct_damage_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -573,9 +756,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::ThunderStrike,
+                id: SpellType::ThunderStrike.into(),
                 element: Element::Lightning,
                 name: "Thunder Strike".to_string(),
                 description: "Lightning from above.".to_string(),
+                icon: None,
                 level: 3,
                 fire_rate: 2.0,
                 base_damage: 30.0,
@@ -602,6 +787,7 @@ mod tests {
         fn thunder_strike_with_lightning_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -612,9 +798,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::ThunderStrike,
+                id: SpellType::ThunderStrike.into(),
                 element: Element::Lightning,
                 name: "Thunder Strike".to_string(),
                 description: "Lightning from above.".to_string(),
+                icon: None,
                 level: 3,
                 fire_rate: 2.0,
                 base_damage: 30.0,
@@ -652,6 +840,7 @@ mod tests {
         fn ashfall_spawns_zone_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -662,9 +851,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Ashfall,
+                id: SpellType::Ashfall.into(),
                 element: Element::Fire,
                 name: "Ashfall".to_string(),
                 description: "Embers rain down over an area.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 4.0, // 0.25 shots/sec = 4 sec cooldown
                 base_damage: 18.0,
@@ -698,6 +889,7 @@ mod tests {
         fn ashfall_with_fire_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -708,9 +900,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Ashfall,
+                id: SpellType::Ashfall.into(),
                 element: Element::Fire,
                 name: "Ashfall".to_string(),
                 description: "Embers rain down over an area.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 4.0,
                 base_damage: 18.0,
@@ -744,6 +938,7 @@ mod tests {
         fn ashfall_spawns_ahead_of_origin() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(0.0, 3.0, 0.0);
             app.insert_resource(SpellOrigin {
@@ -783,6 +978,7 @@ mod tests {
         fn plague_cloud_spawns_projectile_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -793,9 +989,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::PlagueCloud,
+                id: SpellType::PlagueCloud.into(),
                 element: Element::Poison,
                 name: "Plague Cloud".to_string(),
                 description: "Arcing poison cloud.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 25.0,
@@ -823,6 +1021,7 @@ mod tests {
         fn plague_cloud_with_poison_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -833,9 +1032,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::PlagueCloud,
+                id: SpellType::PlagueCloud.into(),
                 element: Element::Poison,
                 name: "Plague Cloud".to_string(),
                 description: "Arcing poison cloud.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 25.0,
@@ -869,6 +1070,7 @@ mod tests {
         fn plague_cloud_spawns_at_spell_origin() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(5.0, 3.0, 10.0);
             app.insert_resource(SpellOrigin {
@@ -907,6 +1109,7 @@ mod tests {
         fn chain_lightning_spawns_bolt_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -917,9 +1120,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::ChainLightning,
+                id: SpellType::ChainLightning.into(),
                 element: Element::Lightning,
                 name: "Chain Lightning".to_string(),
                 description: "Arcing lightning.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 15.0,
@@ -947,6 +1152,7 @@ mod tests {
         fn chain_lightning_with_lightning_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -957,9 +1163,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::ChainLightning,
+                id: SpellType::ChainLightning.into(),
                 element: Element::Lightning,
                 name: "Chain Lightning".to_string(),
                 description: "Arcing lightning.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 15.0,
@@ -993,6 +1201,7 @@ mod tests {
         fn chain_lightning_targets_enemy_entity() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1030,6 +1239,7 @@ mod tests {
         fn toxic_spray_spawns_cone_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1040,9 +1250,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::ToxicSpray,
+                id: SpellType::ToxicSpray.into(),
                 element: Element::Poison,
                 name: "Toxic Spray".to_string(),
                 description: "Cone of poison.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 14.0,
@@ -1070,6 +1282,7 @@ mod tests {
         fn toxic_spray_with_poison_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1080,9 +1293,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::ToxicSpray,
+                id: SpellType::ToxicSpray.into(),
                 element: Element::Poison,
                 name: "Toxic Spray".to_string(),
                 description: "Cone of poison.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 14.0,
@@ -1116,6 +1331,7 @@ mod tests {
         fn toxic_spray_spawns_at_spell_origin() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(5.0, 3.0, 10.0);
             app.insert_resource(SpellOrigin {
@@ -1154,6 +1370,7 @@ mod tests {
         fn frost_nova_spawns_glacial_pulse_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1164,9 +1381,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::FrostNova,
+                id: SpellType::FrostNova.into(),
                 element: Element::Frost,
                 name: "Frost Nova".to_string(),
                 description: "Expanding ring of frost.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 15.0,
@@ -1194,6 +1413,7 @@ mod tests {
         fn frost_nova_with_frost_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1204,9 +1424,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::FrostNova,
+                id: SpellType::FrostNova.into(),
                 element: Element::Frost,
                 name: "Frost Nova".to_string(),
                 description: "Expanding ring of frost.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 15.0,
@@ -1240,6 +1462,7 @@ mod tests {
         fn frost_nova_spawns_at_spell_origin() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(5.0, 3.0, 10.0);
             app.insert_resource(SpellOrigin {
@@ -1278,6 +1501,7 @@ mod tests {
         fn miasma_spawns_toxic_glob_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1288,9 +1512,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Miasma,
+                id: SpellType::Miasma.into(),
                 element: Element::Poison,
                 name: "Miasma".to_string(),
                 description: "Toxic glob.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 6.0,
@@ -1318,6 +1544,7 @@ mod tests {
         fn miasma_with_poison_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1328,9 +1555,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Miasma,
+                id: SpellType::Miasma.into(),
                 element: Element::Poison,
                 name: "Miasma".to_string(),
                 description: "Toxic glob.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 6.0,
@@ -1364,6 +1593,7 @@ mod tests {
         fn miasma_spawns_at_spell_origin() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(5.0, 3.0, 10.0);
             app.insert_resource(SpellOrigin {
@@ -1397,6 +1627,7 @@ mod tests {
         fn miasma_targets_enemy_direction() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1440,6 +1671,7 @@ mod tests {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1450,9 +1682,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Combustion,
+                id: SpellType::Combustion.into(),
                 element: Element::Fire,
                 name: "Combustion".to_string(),
                 description: "Ember swarm.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 1.0,
                 base_damage: 22.0,
@@ -1484,6 +1718,7 @@ mod tests {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1494,9 +1729,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Combustion,
+                id: SpellType::Combustion.into(),
                 element: Element::Fire,
                 name: "Combustion".to_string(),
                 description: "Ember swarm.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 1.0,
                 base_damage: 22.0,
@@ -1530,6 +1767,7 @@ mod tests {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(5.0, 3.0, 10.0);
             app.insert_resource(SpellOrigin {
@@ -1569,6 +1807,7 @@ mod tests {
             app.add_plugins(bevy::time::TimePlugin::default());
             app.add_message::<crate::combat::DamageEvent>();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1579,9 +1818,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Hellfire,
+                id: SpellType::Hellfire.into(),
                 element: Element::Fire,
                 name: "Hellfire".to_string(),
                 description: "Infernal pulse.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 18.0,
@@ -1609,6 +1850,7 @@ mod tests {
             app.add_plugins(bevy::time::TimePlugin::default());
             app.add_message::<crate::combat::DamageEvent>();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1619,9 +1861,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Hellfire,
+                id: SpellType::Hellfire.into(),
                 element: Element::Fire,
                 name: "Hellfire".to_string(),
                 description: "Infernal pulse.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 18.0,
@@ -1649,6 +1893,7 @@ mod tests {
             app.add_plugins(bevy::time::TimePlugin::default());
             app.add_message::<crate::combat::DamageEvent>();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(5.0, 3.0, 10.0);
             app.insert_resource(SpellOrigin {
@@ -1687,6 +1932,7 @@ mod tests {
         fn flashstep_queues_teleport_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1697,9 +1943,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Flashstep,
+                id: SpellType::Flashstep.into(),
                 element: Element::Lightning,
                 name: "Flashstep".to_string(),
                 description: "Teleport with lightning burst.".to_string(),
+                icon: None,
                 level: 1,
                 fire_rate: 3.0,
                 base_damage: 20.0,
@@ -1742,6 +1990,7 @@ mod tests {
         fn flashstep_uses_movement_direction() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1785,6 +2034,7 @@ mod tests {
         fn flashstep_with_lightning_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1795,9 +2045,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::Flashstep,
+                id: SpellType::Flashstep.into(),
                 element: Element::Lightning,
                 name: "Flashstep".to_string(),
                 description: "Teleport with lightning burst.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 3.0,
                 base_damage: 20.0,
@@ -1840,6 +2092,7 @@ mod tests {
         fn flashstep_targets_enemy_when_stationary() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1891,6 +2144,7 @@ mod tests {
         fn corrosive_pool_spawns_acid_rain_zone_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1901,9 +2155,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::CorrosivePool,
+                id: SpellType::CorrosivePool.into(),
                 element: Element::Poison,
                 name: "Corrosive Pool".to_string(),
                 description: "Creates a pool of acid.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 12.0,
@@ -1929,6 +2185,7 @@ mod tests {
         fn corrosive_pool_with_poison_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -1939,9 +2196,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::CorrosivePool,
+                id: SpellType::CorrosivePool.into(),
                 element: Element::Poison,
                 name: "Corrosive Pool".to_string(),
                 description: "Creates a pool of acid.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 2.0,
                 base_damage: 12.0,
@@ -1975,6 +2234,7 @@ mod tests {
         fn corrosive_pool_spawns_ahead_of_origin() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(0.0, 3.0, 0.0);
             app.insert_resource(SpellOrigin {
@@ -2015,6 +2275,7 @@ mod tests {
             let mut app = App::new();
             app.add_message::<crate::combat::DamageEvent>();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -2025,9 +2286,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::DarkPulse,
+                id: SpellType::DarkPulse.into(),
                 element: Element::Dark,
                 name: "Dark Pulse".to_string(),
                 description: "Releases a wave of dark energy.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 1.25, // 0.8 shots/sec
                 base_damage: 20.0,
@@ -2056,6 +2319,7 @@ mod tests {
             let mut app = App::new();
             app.add_message::<crate::combat::DamageEvent>();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -2066,9 +2330,11 @@ mod tests {
             let mut spell_list = SpellList::default();
             let spell = Spell {
                 spell_type: SpellType::DarkPulse,
+                id: SpellType::DarkPulse.into(),
                 element: Element::Dark,
                 name: "Dark Pulse".to_string(),
                 description: "Releases a wave of dark energy.".to_string(),
+                icon: None,
                 level: 2,
                 fire_rate: 1.25,
                 base_damage: 20.0,
@@ -2103,6 +2369,7 @@ mod tests {
             let mut app = App::new();
             app.add_message::<crate::combat::DamageEvent>();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(10.0, 3.0, 15.0);
             app.insert_resource(SpellOrigin {
@@ -2141,6 +2408,7 @@ mod tests {
         fn eclipse_spawns_nightfall_zone_from_spell_list() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -2171,6 +2439,7 @@ mod tests {
         fn eclipse_spawns_at_target_position() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             let origin_pos = Vec3::new(0.0, 3.0, 0.0);
             app.insert_resource(SpellOrigin {
@@ -2205,6 +2474,7 @@ mod tests {
         fn eclipse_with_dark_attunement() {
             let mut app = App::new();
             app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
             app.insert_resource(SpellOrigin {
                 position: Some(Vec3::new(0.0, 3.0, 0.0)),
@@ -2232,620 +2502,322 @@ mod tests {
             assert_eq!(zones.len(), 1, "Eclipse should fire with dark attunement");
         }
     }
-}
 
-use crate::inventory::resources::SpellList;
-use crate::whisper::resources::WhisperAttunement;
+    mod cast_coverage_tests {
+        use super::*;
+        use crate::combat::components::Health;
 
-#[allow(clippy::too_many_arguments)]
-pub fn spell_casting_system(
-    mut commands: Commands,
-    time: Res<Time>,
-    asset_server: Option<Res<AssetServer>>,
-    mut weapon_channel: Option<ResMut<AudioChannel<WeaponSoundChannel>>>,
-    mut sound_limiter: Option<ResMut<SoundLimiter>>,
-    spell_origin: Res<SpellOrigin>,
-    game_meshes: Option<Res<GameMeshes>>,
-    game_materials: Option<Res<GameMaterials>>,
-    enemy_query: Query<(Entity, &Transform, &Enemy)>,
-    mut spell_list: ResMut<SpellList>,
-    attunement: Res<WhisperAttunement>,
-    mut damage_events: Option<MessageWriter<DamageEvent>>,
-    player_query: Query<(Entity, &Transform, &Player)>,
-    mut last_spell_cast: ResMut<crate::spells::psychic::echo_thought::LastSpellCast>,
-    fireball_effects: Option<Res<crate::spells::fire::fireball_effects::FireballEffects>>,
-    mut fireball_charge_materials: Option<ResMut<Assets<crate::spells::fire::materials::FireballChargeMaterial>>>,
-) {
-    let current_time = time.elapsed_secs();
+        /// Builds an app with `spell_type` equipped off cooldown, a player and
+        /// three enemies clustered near the origin.
+        fn cast_setup(spell_type: SpellType) -> App {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
 
-    // Check if Whisper has been collected (spells enabled)
-    let Some(origin_pos) = spell_origin.position else {
-        return; // No Whisper = no spells
-    };
+            app.insert_resource(SpellOrigin {
+                position: Some(Vec3::new(0.0, 3.0, 0.0)),
+            });
+            app.init_resource::<WhisperAttunement>();
+            app.init_resource::<LastSpellCast>();
+            app.init_resource::<Time>();
 
-    // Extract XZ plane position for targeting calculations
-    let origin_xz = from_xz(origin_pos);
+            let mut spell_list = SpellList::default();
+            let mut spell = Spell::new(spell_type);
+            // Start off cooldown even for the slowest spells
+            spell.last_fired = -spell.effective_fire_rate() - 1.0;
+            spell_list.equip(spell);
+            app.insert_resource(spell_list);
 
-    // Find 5 closest enemies to the spell origin (Whisper)
-    // Use XZ plane for distance calculation in 3D world
-    let mut enemy_distances: Vec<(Entity, Vec2, f32)> = enemy_query
-        .iter()
-        .map(|(entity, transform, _)| {
-            let pos = from_xz(transform.translation);
-            let distance = origin_xz.distance(pos);
-            (entity, pos, distance)
-        })
-        .collect();
-
-    // Sort by distance and take first 5
-    enemy_distances.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
-    let closest_enemies: Vec<(Entity, Vec2)> = enemy_distances
-        .into_iter()
-        .take(5)
-        .map(|(entity, pos, _)| (entity, pos))
-        .collect();
+            app.world_mut().spawn((
+                Player {
+                    speed: 200.0,
+                    regen_rate: 1.0,
+                    pickup_radius: 50.0,
+                    last_movement_direction: Vec3::ZERO,
+                },
+                Health::new(100.0),
+                Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+            ));
 
-    // If no enemies, don't cast
-    if closest_enemies.is_empty() {
-        return;
-    }
+            // Cluster enemies close to the origin so range-limited spells find targets
+            for offset in [Vec3::new(2.0, 0.375, 0.0), Vec3::new(-2.0, 0.375, 1.0), Vec3::new(0.0, 0.375, 3.0)] {
+                app.world_mut().spawn((
+                    Enemy { speed: 50.0, strength: 10.0 },
+                    Transform::from_translation(offset),
+                ));
+            }
 
-    // Cast spells from all 5 slots in SpellList
-    for slot in 0..5 {
-        // Get spell from slot, skip empty slots
-        let Some(spell) = spell_list.get_spell(slot) else {
-            continue;
-        };
+            app
+        }
 
-        // Check cooldown
-        if current_time - spell.last_fired < spell.effective_fire_rate() {
-            continue;
+        fn spawned<C: Component>(world: &mut World) -> bool {
+            world.query::<&C>().iter(world).next().is_some()
         }
 
-        // Select random target from 5 closest
-        let mut rng = rand::thread_rng();
-        let target_index = rng.gen_range(0..closest_enemies.len());
-        let target_pos = closest_enemies[target_index].1;
+        fn on_enemy<C: Component>(world: &mut World) -> bool {
+            world.query_filtered::<(), (With<Enemy>, With<C>)>().iter(world).next().is_some()
+        }
 
-        // Calculate damage with attunement multiplier
-        let attunement_multiplier = attunement.damage_multiplier(spell.element);
-        let final_damage = spell.damage() * attunement_multiplier;
+        fn on_player<C: Component>(world: &mut World) -> bool {
+            world.query_filtered::<(), (With<Player>, With<C>)>().iter(world).next().is_some()
+        }
 
-        // Cast the spell based on type
-        match &spell.spell_type {
-            SpellType::Fireball => {
-                crate::spells::fire::fireball::fire_fireball_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    asset_server.as_ref(),
-                    weapon_channel.as_mut(),
-                    sound_limiter.as_mut(),
-                    game_meshes.as_deref(),
-                    fireball_effects.as_deref(),
-                    None, // Core materials added by separate system
-                    fireball_charge_materials.as_deref_mut(),
-                    None, // Trail materials handled by charge_to_flight system
-                );
+        /// The effect each spell type's own cast leaves in the world. The
+        /// match has no wildcard so a new spell type has to name its effect.
+        fn cast_effect(spell_type: SpellType) -> fn(&mut World) -> bool {
+            use crate::spells::chaos::{anomaly, cataclysm, chaos_bolt, disorder_pulse, entropy_field, pandemonium, volatile_orb, warp_rift};
+            use crate::spells::dark::{black_spiral, grim_tether, nightfall, shadow_bolt, soul_drain, soul_rend, void_pulse, wraith_form};
+            use crate::spells::fire::{ashfall, cinder_shot, ember_swarm, fire_nova, fireball, flame_arc, immolate, inferno_pulse};
+            use crate::spells::frost::{frozen_orb, glacial_pulse, glacial_spike, hoarfrost, ice_lance, ice_shard, ice_shards, permafrost, shatter};
+            use crate::spells::light::{beacon, halo_shield, judgment, purify, radiance, radiant_beam, solar_flare};
+            use crate::spells::lightning::{arc_surge, chain_lightning, electrocute, flashstep, overload, static_orb, stormcall, thunder_strike};
+            use crate::spells::poison::{acid_rain, blight_zone, neurotoxin, poison_cloud, toxic_glob, venom_bolt, venom_spray, virulence};
+            use crate::spells::psychic::{brainburn, dominate, echo_thought, mind_cage, mind_lash, psionic_burst, psychic_scream, synapse_shock};
+
+            match spell_type {
+                // Fire
+                SpellType::Fireball => spawned::<fireball::ChargingFireball>,
+                SpellType::FlameLance => spawned::<cinder_shot::CinderShotProjectile>,
+                SpellType::Ashfall => spawned::<ashfall::AshfallZone>,
+                SpellType::MeteorShower => spawned::<flame_arc::FlameArcProjectile>,
+                SpellType::PhoenixFlare => spawned::<fire_nova::FireNovaRing>,
+                SpellType::Combustion => spawned::<ember_swarm::EmberSwarmController>,
+                SpellType::Immolate => spawned::<immolate::ImmolateEffect>,
+                SpellType::Hellfire => spawned::<inferno_pulse::InfernoPulseWave>,
+                // Frost
+                SpellType::IceShard => spawned::<ice_shard::IceShardProjectile>,
+                SpellType::FrostNova => spawned::<glacial_pulse::GlacialPulseWave>,
+                SpellType::Blizzard => spawned::<frozen_orb::FrozenOrb>,
+                SpellType::FrozenRay => spawned::<ice_lance::IceLanceProjectile>,
+                SpellType::GlacialSpike => spawned::<glacial_spike::GlacialSpike>,
+                SpellType::IceBarrier => spawned::<hoarfrost::HoarfrostAura>,
+                SpellType::Shatter => spawned::<shatter::ShatterProjectile>,
+                SpellType::AbsoluteZero => |world| {
+                    spawned::<ice_shards::IceShardFragment>(world)
+                        && on_player::<permafrost::PermafrostEnabled>(world)
+                },
+                // Poison
+                SpellType::VenomBolt => spawned::<venom_bolt::VenomBoltProjectile>,
+                SpellType::PlagueCloud => spawned::<poison_cloud::PoisonCloudProjectile>,
+                SpellType::ToxicSpray => spawned::<venom_spray::VenomSprayCone>,
+                SpellType::Miasma => spawned::<toxic_glob::ToxicGlobProjectile>,
+                SpellType::CorrosivePool => spawned::<acid_rain::AcidRainZone>,
+                SpellType::Pandemic => on_enemy::<virulence::VirulentPoison>,
+                SpellType::Blight => spawned::<blight_zone::BlightZone>,
+                SpellType::Necrosis => on_enemy::<neurotoxin::NeurotoxinDebuff>,
+                // Lightning
+                SpellType::Spark => spawned::<arc_surge::ArcSurge>,
+                SpellType::ChainLightning => spawned::<chain_lightning::ChainLightningBolt>,
+                SpellType::ThunderStrike => spawned::<thunder_strike::ThunderStrikeMarker>,
+                SpellType::StaticField => spawned::<static_orb::StaticOrb>,
+                SpellType::Flashstep => spawned::<flashstep::FlashstepTeleport>,
+                SpellType::Overcharge => spawned::<overload::OverloadCharge>,
+                SpellType::Electrocute => spawned::<electrocute::Electrocute>,
+                SpellType::StormCall => spawned::<stormcall::StormcallController>,
+                // Light
+                SpellType::HolyBeam => spawned::<radiant_beam::RadiantBeam>,
+                SpellType::RadiantBeam => spawned::<radiant_beam::RadiantBeam>,
+                SpellType::Radiance => spawned::<radiance::RadianceAura>,
+                SpellType::Smite => spawned::<solar_flare::SolarFlareProjectile>,
+                SpellType::DivineLight => spawned::<halo_shield::HaloShield>,
+                SpellType::Consecration => spawned::<beacon::Beacon>,
+                SpellType::Purify => spawned::<purify::PurifyCaster>,
+                SpellType::Judgment => spawned::<judgment::JudgmentCaster>,
+                // Dark
+                SpellType::ShadowBolt => spawned::<shadow_bolt::ShadowBoltProjectile>,
+                SpellType::VoidRift => spawned::<black_spiral::BlackSpiral>,
+                SpellType::DarkPulse => spawned::<void_pulse::VoidPulseWave>,
+                SpellType::Corruption => |world| {
+                    spawned::<grim_tether::GrimTether>(world)
+                        && on_enemy::<grim_tether::TetheredEnemy>(world)
+                },
+                SpellType::SoulDrain => spawned::<soul_drain::SoulDrain>,
+                SpellType::Nightmare => spawned::<wraith_form::WraithForm>,
+                SpellType::Eclipse => spawned::<nightfall::NightfallZone>,
+                SpellType::Oblivion => spawned::<soul_rend::SoulRendProjectile>,
+                // Chaos
+                SpellType::WildMagic => spawned::<anomaly::AnomalyZone>,
+                SpellType::Entropy => spawned::<entropy_field::EntropyField>,
+                SpellType::ChaosBolt => spawned::<chaos_bolt::ChaosBoltProjectile>,
+                SpellType::Randomize => spawned::<pandemonium::PandemoniumBurst>,
+                SpellType::Unstable => spawned::<volatile_orb::VolatileOrb>,
+                SpellType::Paradox => spawned::<warp_rift::WarpRift>,
+                SpellType::Mayhem => spawned::<disorder_pulse::DisorderPulse>,
+                SpellType::Cataclysm => spawned::<cataclysm::CataclysmMarker>,
+                // Psychic
+                SpellType::MindBlast => spawned::<mind_cage::MindCage>,
+                SpellType::Telekinesis => spawned::<synapse_shock::SynapseShockBurst>,
+                SpellType::PsychicWave => spawned::<psionic_burst::PsionicBurstWave>,
+                SpellType::Confusion => spawned::<brainburn::BrainburnAura>,
+                SpellType::MentalSpike => spawned::<mind_lash::MindLash>,
+                SpellType::Hallucination => spawned::<echo_thought::EchoThoughtCaster>,
+                SpellType::Dominate => spawned::<dominate::DominateEffect>,
+                SpellType::PsychicShatter => spawned::<psychic_scream::PsychicScreamBurst>,
             }
-            SpellType::RadiantBeam | SpellType::HolyBeam => {
-                crate::spells::light::radiant_beam::fire_radiant_beam_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
+        }
 
-                // Play radiant beam sound effect
-                if let (Some(asset_server), Some(weapon_channel), Some(sound_limiter)) =
-                    (asset_server.as_ref(), weapon_channel.as_mut(), sound_limiter.as_mut()) {
-                    crate::audio::plugin::play_limited_sound_with_volume(
-                        weapon_channel.as_mut(),
-                        asset_server,
-                        "sounds/72639__chipfork71__laser01rev.wav",
-                        sound_limiter.as_mut(),
-                        0.7,
-                    );
-                }
-            }
-            SpellType::ThunderStrike => {
-                crate::spells::lightning::thunder_strike::fire_thunder_strike_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Ashfall => {
-                crate::spells::fire::ashfall::spawn_ashfall_zone_with_damage(
-                    &mut commands,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::PlagueCloud => {
-                crate::spells::poison::poison_cloud::fire_poison_cloud_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::ChainLightning => {
-                // Chain lightning targets a specific entity
-                let target_entity = closest_enemies[target_index].0;
-                crate::spells::lightning::chain_lightning::fire_chain_lightning_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_entity,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::IceShard => {
-                crate::spells::frost::ice_shard::fire_ice_shard_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::GlacialSpike => {
-                crate::spells::frost::glacial_spike::fire_glacial_spike_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::ToxicSpray => {
-                crate::spells::poison::venom_spray::fire_venom_spray_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::FlameLance => {
-                crate::spells::fire::cinder_shot::fire_cinder_shot_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Miasma => {
-                crate::spells::poison::toxic_glob::fire_toxic_glob_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::FrostNova => {
-                crate::spells::frost::glacial_pulse::fire_glacial_pulse_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Combustion => {
-                crate::spells::fire::ember_swarm::fire_ember_swarm_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Overcharge => {
-                crate::spells::lightning::overload::fire_overload_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Blizzard => {
-                crate::spells::frost::frozen_orb::fire_frozen_orb_with_damage(
-                    &mut commands,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::FrozenRay => {
-                crate::spells::frost::ice_lance::fire_ice_lance_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Hellfire => {
-                if let Some(ref mut events) = damage_events {
-                    crate::spells::fire::inferno_pulse::fire_inferno_pulse_with_damage(
-                        &mut commands,
-                        spell,
-                        final_damage,
-                        origin_pos,
-                        &enemy_query,
-                        events,
-                        game_meshes.as_deref(),
-                        game_materials.as_deref(),
-                    );
-                }
-            }
-            SpellType::Immolate => {
-                crate::spells::fire::immolate::fire_immolate_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    &enemy_query,
-                );
-            }
-            SpellType::Electrocute => {
-                crate::spells::lightning::electrocute::fire_electrocute_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    &enemy_query,
-                );
-            }
-            SpellType::Flashstep => {
-                // Flashstep requires player entity and position
-                if let Ok((player_entity, player_transform, player)) = player_query.single() {
-                    // Use player's last movement direction, or direction toward nearest enemy if stationary
-                    let direction = if player.last_movement_direction.length() > 0.1 {
-                        // Convert 3D direction to 2D on XZ plane
-                        Vec2::new(
-                            player.last_movement_direction.x,
-                            player.last_movement_direction.z,
-                        ).normalize()
-                    } else {
-                        // Stationary: teleport toward nearest enemy
-                        let player_pos_xz = from_xz(player_transform.translation);
-                        (target_pos - player_pos_xz).normalize()
-                    };
-
-                    crate::spells::lightning::flashstep::fire_flashstep_with_damage(
-                        &mut commands,
-                        spell,
-                        final_damage,
-                        player_entity,
-                        player_transform.translation,
-                        direction,
-                    );
-                }
-            }
-            SpellType::CorrosivePool => {
-                crate::spells::poison::acid_rain::spawn_acid_rain_zone_with_damage(
-                    &mut commands,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Shatter => {
-                crate::spells::frost::shatter::fire_shatter_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Radiance => {
-                crate::spells::light::radiance::fire_radiance_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::SoulDrain => {
-                crate::spells::dark::soul_drain::fire_soul_drain_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    &enemy_query,
-                );
-            }
-            SpellType::DarkPulse => {
-                crate::spells::dark::void_pulse::fire_void_pulse_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::MentalSpike => {
-                crate::spells::psychic::mind_lash::fire_mind_lash_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::PsychicWave => {
-                crate::spells::psychic::psionic_burst::fire_psionic_burst_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Dominate => {
-                crate::spells::psychic::dominate::fire_dominate(
-                    &mut commands,
-                    spell,
-                    origin_pos,
-                );
-            }
-            SpellType::Telekinesis => {
-                crate::spells::psychic::synapse_shock::fire_synapse_shock(
-                    &mut commands,
-                    spell,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::ChaosBolt => {
-                crate::spells::chaos::chaos_bolt::fire_chaos_bolt_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Entropy => {
-                crate::spells::chaos::entropy_field::fire_entropy_field_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Smite => {
-                crate::spells::light::solar_flare::fire_solar_flare_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Eclipse => {
-                crate::spells::dark::nightfall::fire_nightfall_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::DivineLight => {
-                crate::spells::light::halo_shield::fire_halo_shield_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Confusion => {
-                crate::spells::psychic::brainburn::fire_brainburn_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Judgment => {
-                crate::spells::light::judgment::fire_judgment_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Hallucination => {
-                crate::spells::psychic::echo_thought::fire_echo_thought(
-                    &mut commands,
-                    spell,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Paradox => {
-                crate::spells::chaos::warp_rift::spawn_warp_rift_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Consecration => {
-                crate::spells::light::beacon::fire_beacon_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::MindBlast => {
-                // Mind Cage spawns at target position to trap enemies where they are
-                crate::spells::psychic::mind_cage::fire_mind_cage(
-                    &mut commands,
-                    spell,
-                    Vec3::new(target_pos.x, origin_pos.y, target_pos.y),
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::Nightmare => {
-                // Wraith Form applies effect to the player (becomes intangible and damages enemies on pass-through)
-                if let Ok((player_entity, _, _)) = player_query.single() {
-                    crate::spells::dark::wraith_form::fire_wraith_form_with_damage(
-                        &mut commands,
-                        spell,
-                        final_damage,
-                        player_entity,
-                    );
-                }
-            }
-            SpellType::Purify => {
-                // Purify attaches a caster component to the player that cleanses debuffs and damages enemies
-                if let Ok((player_entity, _, _)) = player_query.single() {
-                    crate::spells::light::purify::fire_purify_with_damage(
-                        &mut commands,
-                        spell,
-                        final_damage,
-                        player_entity,
-                    );
-                }
-            }
-            SpellType::Mayhem => {
-                // Disorder Pulse creates a pulsing chaotic aura with random effects
-                crate::spells::chaos::disorder_pulse::fire_disorder_pulse_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::PsychicShatter => {
-                // Psychic Scream creates a large AOE burst that damages and disorients enemies
-                crate::spells::psychic::psychic_scream::fire_psychic_scream_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
-                );
-            }
-            SpellType::VoidRift => {
-                // Black Spiral creates a rotating vortex that pulls enemies inward and damages them
-                crate::spells::dark::black_spiral::spawn_black_spiral_with_damage(
-                    &mut commands,
-                    spell,
-                    final_damage,
-                    origin_pos,
-                    target_pos,
-                    game_meshes.as_deref(),
-                    game_materials.as_deref(),
+        #[test]
+        fn every_spell_type_casts_its_own_effect() {
+            for &spell_type in SpellType::all() {
+                let mut app = cast_setup(spell_type);
+                app.update();
+
+                assert!(
+                    cast_effect(spell_type)(app.world_mut()),
+                    "{:?} did not cast its own effect",
+                    spell_type
                 );
             }
-            SpellType::IceBarrier => {
-                // Hoarfrost creates a cold mist aura that slows enemies in range
-                if let Ok((player_entity, player_transform, _)) = player_query.single() {
-                    crate::spells::frost::hoarfrost::activate_hoarfrost(
-                        &mut commands,
-                        player_entity,
-                        player_transform.translation,
-                        spell,
-                        game_meshes.as_deref(),
-                        game_materials.as_deref(),
-                    );
-                }
-            }
-            _ => {
-                // Other spell types not implemented yet
-            }
         }
 
-        // Record last spell cast for Echo Thought (but not Hallucination itself to avoid infinite echoing)
-        if spell.spell_type != SpellType::Hallucination {
-            let direction = (target_pos - origin_xz).normalize_or_zero();
-            last_spell_cast.record(spell.spell_type, origin_xz, direction, final_damage);
+        #[test]
+        fn every_spell_type_has_a_registered_behavior() {
+            let mut app = App::new();
+            app.add_plugins(crate::spells::plugin);
+
+            let registry = app.world().resource::<SpellBehaviorRegistry>();
+            assert!(
+                registry.missing().is_empty(),
+                "Spell types without a registered behavior: {:?}",
+                registry.missing()
+            );
         }
+    }
 
-        // Update last_fired time
-        if let Some(spell_mut) = spell_list.get_spell_mut(slot) {
-            spell_mut.last_fired = current_time;
+    mod spell_behavior_registry_tests {
+        use super::*;
+        use crate::spell::behavior::{SpellBehaviorAppExt, SpellCastContext};
+
+        #[derive(Component)]
+        struct CustomCastMarker {
+            damage: f32,
+            target: Entity,
+        }
+
+        fn custom_fireball(commands: &mut Commands, ctx: &mut SpellCastContext) {
+            commands.spawn(CustomCastMarker {
+                damage: ctx.damage,
+                target: ctx.target_entity,
+            });
+        }
+
+        #[test]
+        fn registered_behavior_replaces_builtin_handler() {
+            let mut app = App::new();
+            app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
+            app.register_spell_behavior(SpellType::Fireball, custom_fireball);
+
+            app.insert_resource(SpellOrigin {
+                position: Some(Vec3::new(0.0, 3.0, 0.0)),
+            });
+            app.init_resource::<WhisperAttunement>();
+            app.init_resource::<LastSpellCast>();
+            app.init_resource::<Time>();
+
+            let mut spell_list = SpellList::default();
+            let mut fireball = Spell::new(SpellType::Fireball);
+            fireball.last_fired = -10.0;
+            let expected_damage = fireball.damage();
+            spell_list.equip(fireball);
+            app.insert_resource(spell_list);
+
+            let enemy = app.world_mut().spawn((
+                Enemy { speed: 50.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(10.0, 0.375, 0.0)),
+            )).id();
+
+            app.update();
+
+            let markers: Vec<(f32, Entity)> = app.world_mut()
+                .query::<&CustomCastMarker>()
+                .iter(app.world())
+                .map(|marker| (marker.damage, marker.target))
+                .collect();
+            assert_eq!(markers, vec![(expected_damage, enemy)]);
+
+            let builtin_count = app.world_mut()
+                .query::<&crate::spells::fire::fireball::ChargingFireball>()
+                .iter(app.world())
+                .count();
+            assert_eq!(builtin_count, 0, "Built-in fireball handler should be replaced");
+        }
+
+        #[test]
+        fn spell_registered_without_a_spell_type_is_cast() {
+            let mut app = App::new();
+            app.add_systems(Update, spell_casting_system);
+            app.add_plugins(crate::spells::plugin);
+            app.register_spell_behavior("ArcaneSurge", custom_fireball);
+
+            app.insert_resource(SpellOrigin {
+                position: Some(Vec3::new(0.0, 3.0, 0.0)),
+            });
+            app.init_resource::<WhisperAttunement>();
+            app.init_resource::<LastSpellCast>();
+            app.init_resource::<Time>();
+
+            let mut spell_list = SpellList::default();
+            let mut arcane_surge = Spell::with_id("ArcaneSurge", SpellType::Fireball);
+            arcane_surge.last_fired = -10.0;
+            spell_list.equip(arcane_surge);
+            app.insert_resource(spell_list);
+
+            let enemy = app.world_mut().spawn((
+                Enemy { speed: 50.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(10.0, 0.375, 0.0)),
+            )).id();
+
+            app.update();
+
+            let targets: Vec<Entity> = app.world_mut()
+                .query::<&CustomCastMarker>()
+                .iter(app.world())
+                .map(|marker| marker.target)
+                .collect();
+            assert_eq!(targets, vec![enemy]);
+
+            let fireball_count = app.world_mut()
+                .query::<&crate::spells::fire::fireball::ChargingFireball>()
+                .iter(app.world())
+                .count();
+            assert_eq!(fireball_count, 0, "Spell id should pick the behavior, not the spell type");
+        }
+
+        #[test]
+        fn spell_without_behavior_is_not_cast() {
+            let mut app = App::new();
+            app.add_systems(Update, spell_casting_system);
+            app.init_resource::<SpellBehaviorRegistry>();
+            app.init_resource::<GameRng>();
+
+            app.insert_resource(SpellOrigin {
+                position: Some(Vec3::new(0.0, 3.0, 0.0)),
+            });
+            app.init_resource::<WhisperAttunement>();
+            app.init_resource::<LastSpellCast>();
+            app.init_resource::<Time>();
+
+            let mut spell_list = SpellList::default();
+            let mut fireball = Spell::new(SpellType::Fireball);
+            fireball.last_fired = -10.0;
+            spell_list.equip(fireball);
+            app.insert_resource(spell_list);
+
+            app.world_mut().spawn((
+                Enemy { speed: 50.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(10.0, 0.375, 0.0)),
+            ));
+
+            app.update();
+
+            let spell_list = app.world().resource::<SpellList>();
+            assert_eq!(
+                spell_list.get_spell(0).unwrap().last_fired,
+                -10.0,
+                "Cooldown should not start for a spell with no behavior"
+            );
         }
     }
 }
@@ -5,16 +5,19 @@
 //! lightning - rotating the effect applied to enemies within.
 
 use bevy::prelude::*;
+use rand::Rng;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::fire::fireball::BurnEffect;
 use crate::spells::frost::ice_shard::SlowedDebuff;
 use crate::spells::poison::corrode::CorrodedDebuff;
 use crate::spells::chaos::chaos_bolt::StunnedEnemy;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Anomaly spell
 pub const ANOMALY_ZONE_RADIUS: f32 = 6.0;
@@ -165,24 +168,25 @@ pub fn anomaly_zone_tick_system(
 pub fn anomaly_zone_damage_system(
     mut commands: Commands,
     zone_query: Query<&AnomalyZone>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
     for zone in zone_query.iter() {
         if !zone.tick_timer.just_finished() {
             continue;
         }
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(zone.center, zone.radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
 
             if zone.is_in_zone(enemy_pos) {
                 // Apply base damage with the current effect's element
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    zone.damage_per_tick,
-                    zone.current_effect.element(),
-                ));
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, zone.damage_per_tick, zone.current_effect.element())
+                        .over_time()
+                        .by_spell(SpellType::WildMagic),
+                );
 
                 // Apply the current effect's debuff
                 apply_anomaly_effect(
@@ -190,6 +194,7 @@ pub fn anomaly_zone_damage_system(
                     enemy_entity,
                     zone.current_effect,
                     zone.damage_per_tick,
+                    &mut *rng,
                 );
             }
         }
@@ -202,12 +207,16 @@ fn apply_anomaly_effect(
     enemy_entity: Entity,
     effect: AnomalyEffect,
     base_damage: f32,
+    rng: &mut impl Rng,
 ) {
     match effect {
         AnomalyEffect::Fire => {
             commands
                 .entity(enemy_entity)
-                .insert(BurnEffect::new(base_damage * 0.2 + ANOMALY_BURN_TICK_DAMAGE));
+                .insert(
+                    BurnEffect::new(base_damage * 0.2 + ANOMALY_BURN_TICK_DAMAGE)
+                        .by_spell(SpellType::WildMagic),
+                );
         }
         AnomalyEffect::Frost => {
             commands
@@ -221,7 +230,7 @@ fn apply_anomaly_effect(
         }
         AnomalyEffect::Lightning => {
             // Lightning has a chance to stun
-            if rand::random::<f32>() < ANOMALY_STUN_CHANCE {
+            if rng.gen::<f32>() < ANOMALY_STUN_CHANCE {
                 commands
                     .entity(enemy_entity)
                     .insert(StunnedEnemy::new(ANOMALY_STUN_DURATION));
@@ -293,9 +302,23 @@ pub fn fire_anomaly_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::WildMagic.
+pub fn cast_anomaly(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_anomaly_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -547,6 +570,7 @@ mod tests {
             app.insert_resource(counter.clone());
 
             app.add_message::<DamageEvent>();
+            app.init_resource::<GameRng>();
             app.add_systems(Update, (anomaly_zone_damage_system, count_damage_events).chain());
 
             // Create zone at origin that should damage (tick timer just finished)
@@ -589,6 +613,7 @@ mod tests {
             app.insert_resource(counter.clone());
 
             app.add_message::<DamageEvent>();
+            app.init_resource::<GameRng>();
             app.add_systems(Update, (anomaly_zone_damage_system, count_damage_events).chain());
 
             // Create zone with tick timer ready
@@ -631,6 +656,7 @@ mod tests {
             app.insert_resource(counter.clone());
 
             app.add_message::<DamageEvent>();
+            app.init_resource::<GameRng>();
             app.add_systems(Update, (anomaly_zone_damage_system, count_damage_events).chain());
 
             // Create zone that hasn't ticked yet
@@ -673,6 +699,7 @@ mod tests {
             app.insert_resource(counter.clone());
 
             app.add_message::<DamageEvent>();
+            app.init_resource::<GameRng>();
             app.add_systems(Update, (anomaly_zone_damage_system, count_damage_events).chain());
 
             // Create zone
@@ -758,7 +785,7 @@ mod tests {
 
             {
                 let mut commands = app.world_mut().commands();
-                apply_anomaly_effect(&mut commands, enemy_entity, AnomalyEffect::Fire, 20.0);
+                apply_anomaly_effect(&mut commands, enemy_entity, AnomalyEffect::Fire, 20.0, &mut rand::thread_rng());
             }
             app.update();
 
@@ -777,7 +804,7 @@ mod tests {
 
             {
                 let mut commands = app.world_mut().commands();
-                apply_anomaly_effect(&mut commands, enemy_entity, AnomalyEffect::Frost, 20.0);
+                apply_anomaly_effect(&mut commands, enemy_entity, AnomalyEffect::Frost, 20.0, &mut rand::thread_rng());
             }
             app.update();
 
@@ -798,7 +825,7 @@ mod tests {
 
             {
                 let mut commands = app.world_mut().commands();
-                apply_anomaly_effect(&mut commands, enemy_entity, AnomalyEffect::Poison, 20.0);
+                apply_anomaly_effect(&mut commands, enemy_entity, AnomalyEffect::Poison, 20.0, &mut rand::thread_rng());
             }
             app.update();
 
@@ -823,7 +850,7 @@ mod tests {
 
                 {
                     let mut commands = app.world_mut().commands();
-                    apply_anomaly_effect(&mut commands, enemy_entity, AnomalyEffect::Lightning, 20.0);
+                    apply_anomaly_effect(&mut commands, enemy_entity, AnomalyEffect::Lightning, 20.0, &mut rand::thread_rng());
                 }
                 app.update();
 
@@ -0,0 +1,342 @@
+//! Cataclysm spell - Ultimate chaotic devastation.
+//!
+//! A Chaos element spell (Cataclysm SpellType) that marks several points scattered
+//! around the target. After a long wind-up each mark erupts in a large chaos blast,
+//! so enemies that stay clustered near the target take repeated hits.
+
+use bevy::prelude::*;
+use rand::Rng;
+use crate::collision::spatial::SpatialEnemies;
+use crate::combat::DamageEvent;
+use crate::element::Element;
+use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
+use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
+
+/// Number of blast marks spawned per cast
+pub const CATACLYSM_STRIKE_COUNT: usize = 3;
+
+/// Maximum distance from the target that a mark can land
+pub const CATACLYSM_SCATTER_RADIUS: f32 = 4.0;
+
+/// Base delay before a mark erupts (each mark adds a random stagger on top)
+pub const CATACLYSM_DELAY: f32 = 1.2;
+
+/// Maximum extra random stagger added to each mark's delay
+pub const CATACLYSM_DELAY_STAGGER: f32 = 0.6;
+
+/// Damage radius of each blast
+pub const CATACLYSM_RADIUS: f32 = 5.0;
+
+/// Visual effect lifetime after a blast erupts
+pub const CATACLYSM_VISUAL_LIFETIME: f32 = 0.5;
+
+/// Get the chaos element color for visual effects
+pub fn cataclysm_color() -> Color {
+    Element::Chaos.color()
+}
+
+/// Warning mark that erupts into a CataclysmBlast when its delay expires.
+#[derive(Component, Debug, Clone)]
+pub struct CataclysmMarker {
+    /// Center position on XZ plane where the blast will erupt
+    pub position: Vec2,
+    /// Radius of the blast area
+    pub radius: f32,
+    /// Timer counting down to the eruption
+    pub delay_timer: Timer,
+    /// Damage to deal when the blast erupts
+    pub damage: f32,
+}
+
+impl CataclysmMarker {
+    pub fn new(position: Vec2, damage: f32, delay_secs: f32) -> Self {
+        Self {
+            position,
+            radius: CATACLYSM_RADIUS,
+            delay_timer: Timer::from_seconds(delay_secs, TimerMode::Once),
+            damage,
+        }
+    }
+
+    /// Check if the blast should erupt (delay timer finished)
+    pub fn is_ready(&self) -> bool {
+        self.delay_timer.is_finished()
+    }
+}
+
+/// Blast spawned when a CataclysmMarker erupts.
+#[derive(Component, Debug, Clone)]
+pub struct CataclysmBlast {
+    /// Center position on XZ plane
+    pub center: Vec2,
+    /// Damage dealt to enemies in area
+    pub damage: f32,
+    /// Radius of damage area
+    pub radius: f32,
+    /// Lifetime timer for visual effect
+    pub lifetime: Timer,
+    /// Whether damage has been applied (only apply once)
+    pub damage_applied: bool,
+}
+
+impl CataclysmBlast {
+    pub fn new(center: Vec2, damage: f32, radius: f32) -> Self {
+        Self {
+            center,
+            damage,
+            radius,
+            lifetime: Timer::from_seconds(CATACLYSM_VISUAL_LIFETIME, TimerMode::Once),
+            damage_applied: false,
+        }
+    }
+
+    pub fn from_marker(marker: &CataclysmMarker) -> Self {
+        Self::new(marker.position, marker.damage, marker.radius)
+    }
+
+    /// Check if the visual effect has expired
+    pub fn is_expired(&self) -> bool {
+        self.lifetime.is_finished()
+    }
+}
+
+/// System that ticks cataclysm marks and spawns blasts when ready.
+pub fn update_cataclysm_markers(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut marker_query: Query<(Entity, &mut CataclysmMarker)>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+) {
+    for (entity, mut marker) in marker_query.iter_mut() {
+        marker.delay_timer.tick(time.delta());
+
+        if marker.is_ready() {
+            let blast = CataclysmBlast::from_marker(&marker);
+            let blast_pos = to_xz(marker.position) + Vec3::new(0.0, 0.2, 0.0);
+
+            if let (Some(ref meshes), Some(ref materials)) = (&game_meshes, &game_materials) {
+                commands.spawn((
+                    Mesh3d(meshes.explosion.clone()),
+                    MeshMaterial3d(materials.chaos_aoe.clone()),
+                    Transform::from_translation(blast_pos).with_scale(Vec3::splat(blast.radius)),
+                    blast,
+                ));
+            } else {
+                // Fallback for tests without mesh resources
+                commands.spawn((
+                    Transform::from_translation(blast_pos),
+                    blast,
+                ));
+            }
+
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// System that applies chaos damage once when a blast erupts.
+pub fn cataclysm_blast_damage_system(
+    mut blast_query: Query<&mut CataclysmBlast>,
+    spatial_enemies: SpatialEnemies,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    for mut blast in blast_query.iter_mut() {
+        if blast.damage_applied {
+            continue;
+        }
+
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(blast.center, blast.radius) {
+            let enemy_pos = from_xz(enemy_transform.translation);
+            if blast.center.distance(enemy_pos) <= blast.radius {
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, blast.damage, Element::Chaos)
+                        .by_spell(SpellType::Cataclysm),
+                );
+            }
+        }
+
+        blast.damage_applied = true;
+    }
+}
+
+/// System that updates blast lifetime and despawns expired blasts.
+pub fn update_cataclysm_blasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut blast_query: Query<(Entity, &mut CataclysmBlast, &mut Transform)>,
+) {
+    for (entity, mut blast, mut transform) in blast_query.iter_mut() {
+        blast.lifetime.tick(time.delta());
+
+        // Expand outward as the blast fades
+        let progress = blast.lifetime.elapsed_secs() / CATACLYSM_VISUAL_LIFETIME;
+        transform.scale = Vec3::splat(blast.radius * (1.0 + progress * 0.3));
+
+        if blast.is_expired() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Cast cataclysm spell - scatters delayed blast marks around the target.
+/// `spawn_position` is Whisper's full 3D position, `target_pos` is the target on XZ plane.
+pub fn fire_cataclysm(
+    commands: &mut Commands,
+    spell: &Spell,
+    spawn_position: Vec3,
+    target_pos: Vec2,
+    game_meshes: Option<&GameMeshes>,
+    game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
+) {
+    fire_cataclysm_with_damage(
+        commands,
+        spell,
+        spell.damage(),
+        spawn_position,
+        target_pos,
+        game_meshes,
+        game_materials,
+        rng,
+    );
+}
+
+/// Cast cataclysm spell with explicit damage - scatters delayed blast marks around the target.
+/// The first mark always lands on the target; the rest are scattered within
+/// `CATACLYSM_SCATTER_RADIUS`.
+/// `damage` is the pre-calculated final damage (including attunement multiplier)
+#[allow(clippy::too_many_arguments)]
+pub fn fire_cataclysm_with_damage(
+    commands: &mut Commands,
+    _spell: &Spell,
+    damage: f32,
+    _spawn_position: Vec3,
+    target_pos: Vec2,
+    game_meshes: Option<&GameMeshes>,
+    game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
+) {
+    for i in 0..CATACLYSM_STRIKE_COUNT {
+        let offset = if i == 0 {
+            Vec2::ZERO
+        } else {
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let distance = rng.gen_range(0.0..=CATACLYSM_SCATTER_RADIUS);
+            Vec2::new(angle.cos(), angle.sin()) * distance
+        };
+        let position = target_pos + offset;
+        let delay = CATACLYSM_DELAY + rng.gen_range(0.0..=CATACLYSM_DELAY_STAGGER);
+        let marker = CataclysmMarker::new(position, damage, delay);
+        let marker_pos = to_xz(position) + Vec3::new(0.0, 0.1, 0.0);
+
+        if let (Some(meshes), Some(materials)) = (game_meshes, game_materials) {
+            commands.spawn((
+                Mesh3d(meshes.target_marker.clone()),
+                MeshMaterial3d(materials.chaos_aoe.clone()),
+                Transform::from_translation(marker_pos).with_scale(Vec3::splat(CATACLYSM_RADIUS)),
+                marker,
+            ));
+        } else {
+            // Fallback for tests without mesh resources
+            commands.spawn((
+                Transform::from_translation(marker_pos),
+                marker,
+            ));
+        }
+    }
+}
+
+/// Cast handler registered for SpellType::Cataclysm.
+pub fn cast_cataclysm(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_cataclysm_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+        ctx.rng,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enemies::components::Enemy;
+    use std::time::Duration;
+    use bevy::app::App;
+    use crate::spell::SpellType;
+
+    mod cataclysm_marker_tests {
+        use super::*;
+
+        #[test]
+        fn test_marker_not_ready_before_delay() {
+            let mut marker = CataclysmMarker::new(Vec2::ZERO, 80.0, CATACLYSM_DELAY);
+            marker.delay_timer.tick(Duration::from_secs_f32(CATACLYSM_DELAY / 2.0));
+            assert!(!marker.is_ready());
+        }
+
+        #[test]
+        fn test_blast_from_marker_copies_values() {
+            let marker = CataclysmMarker::new(Vec2::new(2.0, 3.0), 80.0, CATACLYSM_DELAY);
+            let blast = CataclysmBlast::from_marker(&marker);
+            assert_eq!(blast.center, marker.position);
+            assert_eq!(blast.damage, marker.damage);
+            assert_eq!(blast.radius, CATACLYSM_RADIUS);
+            assert!(!blast.damage_applied);
+        }
+    }
+
+    mod cataclysm_system_tests {
+        use super::*;
+
+        #[test]
+        fn test_fire_cataclysm_scatters_markers_around_target() {
+            let mut app = App::new();
+            let spell = Spell::new(SpellType::Cataclysm);
+            let target = Vec2::new(10.0, -4.0);
+
+            {
+                let mut commands = app.world_mut().commands();
+                fire_cataclysm(&mut commands, &spell, Vec3::ZERO, target, None, None, &mut rand::thread_rng());
+            }
+            app.update();
+
+            let mut query = app.world_mut().query::<&CataclysmMarker>();
+            let markers: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(markers.len(), CATACLYSM_STRIKE_COUNT);
+            for marker in markers {
+                assert!(marker.position.distance(target) <= CATACLYSM_SCATTER_RADIUS + 0.001);
+            }
+        }
+
+        #[test]
+        fn test_blast_damages_enemies_once() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+            app.add_systems(Update, cataclysm_blast_damage_system);
+
+            let enemy = app.world_mut().spawn((
+                Enemy { speed: 2.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(2.0, 0.375, 0.0)),
+            )).id();
+            app.world_mut().spawn(CataclysmBlast::new(Vec2::ZERO, 80.0, CATACLYSM_RADIUS));
+
+            app.update();
+            app.update();
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let mut cursor = messages.get_cursor();
+            let events: Vec<_> = cursor.read(messages).collect();
+            assert_eq!(events.len(), 1, "Blast should only apply damage once");
+            assert_eq!(events[0].target, enemy);
+            assert_eq!(events[0].element, Some(Element::Chaos));
+        }
+    }
+}
@@ -7,15 +7,19 @@
 use std::collections::HashSet;
 use bevy::prelude::*;
 use rand::Rng;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, Knockback};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::chaos::fear::FearedEnemy;
 use crate::spells::fire::fireball::BurnEffect;
 use crate::spells::frost::ice_shard::SlowedDebuff;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Chaos Bolt spell
 pub const CHAOS_BOLT_SPEED: f32 = 20.0;
@@ -61,8 +65,7 @@ pub enum RandomEffect {
 
 impl RandomEffect {
     /// Select a random effect with balanced distribution.
-    pub fn random() -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn random(rng: &mut impl Rng) -> Self {
         match rng.gen_range(0..7) {
             0 => RandomEffect::ExtraDamage(EXTRA_DAMAGE_MULTIPLIER),
             1 => RandomEffect::Slow {
@@ -194,14 +197,18 @@ pub struct ChaosBoltProjectile {
     pub effect: RandomEffect,
 }
 
+impl ObstacleProjectile for ChaosBoltProjectile {
+    const SPELL_TYPE: SpellType = SpellType::ChaosBolt;
+}
+
 impl ChaosBoltProjectile {
-    pub fn new(direction: Vec2, damage: f32) -> Self {
+    pub fn new(direction: Vec2, damage: f32, rng: &mut impl Rng) -> Self {
         Self {
             direction: direction.normalize_or_zero(),
             speed: CHAOS_BOLT_SPEED,
             lifetime: Timer::from_seconds(CHAOS_BOLT_LIFETIME, TimerMode::Once),
             damage,
-            effect: RandomEffect::random(),
+            effect: RandomEffect::random(rng),
         }
     }
 
@@ -259,7 +266,7 @@ pub fn chaos_bolt_lifetime_system(
 /// System that detects Chaos Bolt-enemy collisions and fires events.
 pub fn chaos_bolt_collision_detection(
     chaos_bolt_query: Query<(Entity, &Transform), With<ChaosBoltProjectile>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<ChaosBoltEnemyCollisionEvent>,
 ) {
     for (bolt_entity, bolt_transform) in chaos_bolt_query.iter() {
@@ -268,7 +275,7 @@ pub fn chaos_bolt_collision_detection(
             bolt_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(bolt_xz, CHAOS_BOLT_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -335,7 +342,10 @@ pub fn chaos_bolt_collision_effects(
         };
 
         // Send damage event with Chaos element
-        damage_events.write(DamageEvent::with_element(enemy_entity, final_damage, Element::Chaos));
+        damage_events.write(
+            DamageEvent::with_element(enemy_entity, final_damage, Element::Chaos)
+                .by_spell(SpellType::ChaosBolt),
+        );
 
         // Apply the random effect
         match effect {
@@ -346,7 +356,9 @@ pub fn chaos_bolt_collision_effects(
                 commands.entity(enemy_entity).try_insert(SlowedDebuff::new(duration, factor));
             }
             RandomEffect::Burn { damage_per_tick, duration: _ } => {
-                commands.entity(enemy_entity).try_insert(BurnEffect::new(damage_per_tick));
+                commands
+                    .entity(enemy_entity)
+                    .try_insert(BurnEffect::new(damage_per_tick).by_spell(SpellType::ChaosBolt));
             }
             RandomEffect::Poison { damage_per_tick, duration } => {
                 commands.entity(enemy_entity).try_insert(ChaosPoisonDebuff::new(duration, damage_per_tick));
@@ -398,7 +410,11 @@ pub fn chaos_poison_damage_system(
         poison.tick(time.delta());
 
         if poison.should_damage() {
-            damage_events.write(DamageEvent::with_element(entity, poison.damage_per_tick, Element::Poison));
+            damage_events.write(
+                DamageEvent::with_element(entity, poison.damage_per_tick, Element::Poison)
+                    .over_time()
+                    .by_spell(SpellType::ChaosBolt),
+            );
         }
 
         if poison.is_expired() {
@@ -416,6 +432,7 @@ pub fn fire_chaos_bolt(
     target_pos: Vec2,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     fire_chaos_bolt_with_damage(
         commands,
@@ -425,6 +442,7 @@ pub fn fire_chaos_bolt(
         target_pos,
         game_meshes,
         game_materials,
+        rng,
     );
 }
 
@@ -438,6 +456,7 @@ pub fn fire_chaos_bolt_with_damage(
     target_pos: Vec2,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     let spawn_xz = from_xz(spawn_position);
     let base_direction = (target_pos - spawn_xz).normalize();
@@ -460,7 +479,7 @@ pub fn fire_chaos_bolt_with_damage(
             base_direction.x * sin_offset + base_direction.y * cos_offset,
         );
 
-        let bolt = ChaosBoltProjectile::new(direction, damage);
+        let bolt = ChaosBoltProjectile::new(direction, damage, rng);
 
         if let (Some(meshes), Some(materials)) = (game_meshes, game_materials) {
             commands.spawn((
@@ -479,6 +498,20 @@ pub fn fire_chaos_bolt_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::ChaosBolt.
+pub fn cast_chaos_bolt(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_chaos_bolt_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+        ctx.rng,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -542,7 +575,7 @@ mod tests {
         fn test_random_effect_random_returns_valid_effect() {
             // Call random multiple times to ensure it doesn't panic
             for _ in 0..20 {
-                let effect = RandomEffect::random();
+                let effect = RandomEffect::random(&mut rand::thread_rng());
                 // All variants should be valid
                 match effect {
                     RandomEffect::ExtraDamage(_)
@@ -562,7 +595,7 @@ mod tests {
 
         #[test]
         fn test_chaos_bolt_spawns_with_random_effect() {
-            let bolt = ChaosBoltProjectile::new(Vec2::X, 20.0);
+            let bolt = ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng());
 
             // Effect should be assigned (not checking specific value since it's random)
             assert!(!bolt.is_expired());
@@ -572,7 +605,7 @@ mod tests {
 
         #[test]
         fn test_chaos_bolt_with_specific_effect() {
-            let bolt = ChaosBoltProjectile::new(Vec2::X, 20.0)
+            let bolt = ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng())
                 .with_effect(RandomEffect::stun());
 
             assert!(matches!(bolt.effect, RandomEffect::Stun { .. }));
@@ -580,14 +613,14 @@ mod tests {
 
         #[test]
         fn test_chaos_bolt_normalizes_direction() {
-            let bolt = ChaosBoltProjectile::new(Vec2::new(3.0, 4.0), 20.0);
+            let bolt = ChaosBoltProjectile::new(Vec2::new(3.0, 4.0), 20.0, &mut rand::thread_rng());
 
             assert!((bolt.direction.length() - 1.0).abs() < 0.001);
         }
 
         #[test]
         fn test_chaos_bolt_lifetime_expires() {
-            let mut bolt = ChaosBoltProjectile::new(Vec2::X, 20.0);
+            let mut bolt = ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng());
             assert!(!bolt.is_expired());
 
             bolt.tick(Duration::from_secs_f32(CHAOS_BOLT_LIFETIME + 0.1));
@@ -662,7 +695,7 @@ mod tests {
 
             let entity = app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::X, 20.0),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()),
             )).id();
 
             // Advance time
@@ -689,7 +722,7 @@ mod tests {
 
             let entity = app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::new(0.0, 1.0), 20.0),
+                ChaosBoltProjectile::new(Vec2::new(0.0, 1.0), 20.0, &mut rand::thread_rng()),
             )).id();
 
             {
@@ -719,7 +752,7 @@ mod tests {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
 
-            let mut bolt = ChaosBoltProjectile::new(Vec2::X, 20.0);
+            let mut bolt = ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng());
             bolt.lifetime = Timer::from_seconds(0.0, TimerMode::Once);
             bolt.lifetime.tick(Duration::from_secs(1));
 
@@ -745,7 +778,7 @@ mod tests {
 
             let entity = app.world_mut().spawn((
                 Transform::from_translation(Vec3::ZERO),
-                ChaosBoltProjectile::new(Vec2::X, 20.0),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()),
             )).id();
 
             {
@@ -797,7 +830,7 @@ mod tests {
             // Spawn chaos bolt at origin
             app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::X, 20.0),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()),
             ));
 
             // Spawn enemy within collision radius
@@ -834,7 +867,7 @@ mod tests {
 
             app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::X, 20.0),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()),
             ));
 
             app.world_mut().spawn((
@@ -885,7 +918,7 @@ mod tests {
 
             app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::X, 20.0).with_effect(RandomEffect::slow()),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()).with_effect(RandomEffect::slow()),
             ));
 
             let enemy = app.world_mut().spawn((
@@ -917,7 +950,7 @@ mod tests {
 
             app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::X, 20.0).with_effect(RandomEffect::burn()),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()).with_effect(RandomEffect::burn()),
             ));
 
             let enemy = app.world_mut().spawn((
@@ -949,7 +982,7 @@ mod tests {
 
             app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::X, 20.0).with_effect(RandomEffect::poison()),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()).with_effect(RandomEffect::poison()),
             ));
 
             let enemy = app.world_mut().spawn((
@@ -981,7 +1014,7 @@ mod tests {
 
             app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::X, 20.0).with_effect(RandomEffect::stun()),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()).with_effect(RandomEffect::stun()),
             ));
 
             let enemy = app.world_mut().spawn((
@@ -1013,7 +1046,7 @@ mod tests {
 
             let bolt = app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ChaosBoltProjectile::new(Vec2::X, 20.0),
+                ChaosBoltProjectile::new(Vec2::X, 20.0, &mut rand::thread_rng()),
             )).id();
 
             app.world_mut().spawn((
@@ -1054,6 +1087,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1081,6 +1115,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1107,6 +1142,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1139,6 +1175,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1168,6 +1205,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1,16 +1,19 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::{from_xz, Knockback};
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::chaos::fear::FearedEnemy;
 use crate::spells::chaos::chaos_bolt::StunnedEnemy;
 use crate::spells::fire::fireball::BurnEffect;
 use crate::spells::frost::ice_shard::SlowedDebuff;
 use rand::Rng;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Disorder Pulse spell
 pub const DISORDER_PULSE_INTERVAL: f32 = 1.2;
@@ -51,8 +54,7 @@ pub enum PulseEffect {
 
 impl PulseEffect {
     /// Get a random effect
-    pub fn random() -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn random(rng: &mut impl Rng) -> Self {
         match rng.gen_range(0..6) {
             0 => PulseEffect::BonusDamage,
             1 => PulseEffect::Slow,
@@ -131,13 +133,16 @@ pub fn disorder_pulse_duration_system(
 }
 
 /// System that handles Disorder Pulse periodic effects on enemies
+#[allow(clippy::too_many_arguments)]
 pub fn disorder_pulse_effect_system(
     mut commands: Commands,
     time: Res<Time>,
     player_query: Query<&Transform, With<Player>>,
     mut pulse_query: Query<&mut DisorderPulse>,
-    enemy_query: Query<(Entity, &Transform, &Enemy), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    enemy_query: Query<&Enemy>,
     mut damage_events: MessageWriter<DamageEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
     let Ok(player_transform) = player_query.single() else {
         return;
@@ -150,16 +155,22 @@ pub fn disorder_pulse_effect_system(
 
         if pulse.pulse_timer.just_finished() {
             // Roll a random effect for this pulse
-            let effect = PulseEffect::random();
+            let effect = PulseEffect::random(&mut *rng);
 
             // Find all enemies within range
-            for (enemy_entity, enemy_transform, enemy) in enemy_query.iter() {
+            for (enemy_entity, enemy_transform) in spatial_enemies.near(player_pos, pulse.pulse_range) {
+                let Ok(enemy) = enemy_query.get(enemy_entity) else {
+                    continue;
+                };
                 let enemy_pos = from_xz(enemy_transform.translation);
                 let distance = player_pos.distance(enemy_pos);
 
                 if distance <= pulse.pulse_range {
                     // Always apply base damage
-                    damage_events.write(DamageEvent::new(enemy_entity, pulse.base_damage));
+                    damage_events.write(
+                        DamageEvent::new(enemy_entity, pulse.base_damage)
+                            .by_spell(SpellType::Mayhem),
+                    );
 
                     // Apply the random effect
                     apply_pulse_effect(
@@ -219,7 +230,10 @@ fn apply_pulse_effect(
         PulseEffect::Burn => {
             commands
                 .entity(enemy_entity)
-                .insert(BurnEffect::new(base_damage * 0.2 + DISORDER_BURN_DAMAGE));
+                .insert(
+                    BurnEffect::new(base_damage * 0.2 + DISORDER_BURN_DAMAGE)
+                        .by_spell(SpellType::Mayhem),
+                );
         }
     }
 }
@@ -272,6 +286,19 @@ pub fn fire_disorder_pulse_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Mayhem.
+pub fn cast_disorder_pulse(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Disorder Pulse creates a pulsing chaotic aura with random effects
+    fire_disorder_pulse_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,7 +332,7 @@ mod tests {
         fn test_pulse_effect_random_returns_valid() {
             // Run multiple times to increase confidence
             for _ in 0..20 {
-                let effect = PulseEffect::random();
+                let effect = PulseEffect::random(&mut rand::thread_rng());
                 // Just verify it matches one of the variants
                 let is_valid = matches!(
                     effect,
@@ -452,6 +479,7 @@ mod tests {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
             app.add_message::<DamageEvent>();
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -512,6 +540,7 @@ mod tests {
         #[test]
         fn test_pulse_affects_enemies_in_range() {
             let mut app = App::new();
+            app.init_resource::<GameRng>();
 
             use std::sync::atomic::{AtomicUsize, Ordering};
             use std::sync::Arc;
@@ -587,6 +616,7 @@ mod tests {
         #[test]
         fn test_pulse_does_not_affect_enemies_outside_range() {
             let mut app = App::new();
+            app.init_resource::<GameRng>();
 
             use std::sync::atomic::{AtomicUsize, Ordering};
             use std::sync::Arc;
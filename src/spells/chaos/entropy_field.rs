@@ -6,12 +6,14 @@
 
 use bevy::prelude::*;
 use rand::Rng;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Entropy Field spell
 pub const ENTROPY_FIELD_RADIUS: f32 = 5.0;
@@ -82,8 +84,7 @@ impl EntropyField {
     }
 
     /// Generate a random damage value within the configured range.
-    pub fn random_damage(&self) -> f32 {
-        let mut rng = rand::thread_rng();
+    pub fn random_damage(&self, rng: &mut impl Rng) -> f32 {
         let mult = rng.gen_range(self.min_damage_mult..=self.max_damage_mult);
         self.base_damage * mult
     }
@@ -107,24 +108,24 @@ pub fn entropy_field_tick_system(
 /// System that damages enemies within entropy fields with randomized damage.
 pub fn entropy_field_damage_system(
     field_query: Query<&EntropyField>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
     for field in field_query.iter() {
         if !field.should_damage() {
             continue;
         }
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(field.center, field.radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
 
             if field.is_in_field(enemy_pos) {
-                let damage = field.random_damage();
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    damage,
-                    Element::Chaos,
-                ));
+                let damage = field.random_damage(&mut *rng);
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, damage, Element::Chaos)
+                        .by_spell(SpellType::Entropy),
+                );
             }
         }
     }
@@ -203,9 +204,23 @@ pub fn fire_entropy_field_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Entropy.
+pub fn cast_entropy_field(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_entropy_field_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -238,7 +253,7 @@ mod tests {
             // Collect multiple random damage values
             let mut damages: Vec<f32> = Vec::new();
             for _ in 0..20 {
-                damages.push(field.random_damage());
+                damages.push(field.random_damage(&mut rand::thread_rng()));
             }
 
             // At least some values should be different (very unlikely to all be same)
@@ -255,7 +270,7 @@ mod tests {
 
             // Check many random samples are within bounds
             for _ in 0..100 {
-                let damage = field.random_damage();
+                let damage = field.random_damage(&mut rand::thread_rng());
                 let min_expected = 100.0 * ENTROPY_FIELD_MIN_DAMAGE_MULT;
                 let max_expected = 100.0 * ENTROPY_FIELD_MAX_DAMAGE_MULT;
                 assert!(
@@ -389,6 +404,7 @@ mod tests {
         #[test]
         fn test_entropy_field_damages_enemies_in_zone() {
             let mut app = App::new();
+            app.init_resource::<GameRng>();
 
             #[derive(Resource, Clone)]
             struct DamageEventCounter(Arc<AtomicUsize>);
@@ -432,6 +448,7 @@ mod tests {
         #[test]
         fn test_entropy_field_ignores_enemies_outside() {
             let mut app = App::new();
+            app.init_resource::<GameRng>();
 
             #[derive(Resource, Clone)]
             struct DamageEventCounter(Arc<AtomicUsize>);
@@ -474,6 +491,7 @@ mod tests {
         #[test]
         fn test_entropy_field_no_damage_before_tick() {
             let mut app = App::new();
+            app.init_resource::<GameRng>();
 
             #[derive(Resource, Clone)]
             struct DamageEventCounter(Arc<AtomicUsize>);
@@ -516,6 +534,7 @@ mod tests {
         #[test]
         fn test_entropy_field_damages_multiple_enemies() {
             let mut app = App::new();
+            app.init_resource::<GameRng>();
 
             #[derive(Resource, Clone)]
             struct DamageEventCounter(Arc<AtomicUsize>);
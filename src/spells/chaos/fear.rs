@@ -6,6 +6,7 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
@@ -144,7 +145,8 @@ pub fn spawn_fear_burst(
 pub fn apply_fear_to_enemies_system(
     mut commands: Commands,
     mut burst_query: Query<&mut FearBurst>,
-    mut enemy_query: Query<(Entity, &Transform, Option<&mut FearedEnemy>), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    mut fear_query: Query<Option<&mut FearedEnemy>, With<Enemy>>,
     player_query: Query<&Transform, (With<crate::player::components::Player>, Without<Enemy>)>,
 ) {
     let player_transform = match player_query.single() {
@@ -158,8 +160,11 @@ pub fn apply_fear_to_enemies_system(
             continue;
         }
 
-        for (enemy_entity, enemy_transform, existing_fear) in enemy_query.iter_mut() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(burst.center, burst.radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
+            let Ok(existing_fear) = fear_query.get_mut(enemy_entity) else {
+                continue;
+            };
 
             if burst.can_affect(enemy_entity, enemy_pos) {
                 // Calculate flee direction (away from player)
@@ -10,7 +10,7 @@ use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::enemies::systems::ENEMY_Y_HEIGHT;
 use crate::game::components::Level;
-use crate::game::resources::{enemy_scale_for_level, EnemyLevelMaterials, GameMeshes};
+use crate::game::resources::{enemy_scale_for_level, EnemyLevelMaterials, GameMeshes, GameRng};
 use crate::movement::components::Velocity;
 use rand::Rng;
 
@@ -105,6 +105,7 @@ pub fn fracture_on_death_system(
     mut death_events: MessageReader<DeathEvent>,
     fractured_query: Query<(&Enemy, &Health, &Level), (With<FractureEffect>, Without<Fragment>)>,
     mut fracture_death_events: MessageWriter<FractureDeathEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
     for event in death_events.read() {
         if event.entity_type != EntityType::Enemy {
@@ -113,7 +114,6 @@ pub fn fracture_on_death_system(
 
         // Check if the dead entity was fractured (and not already a fragment)
         if let Ok((enemy, health, level)) = fractured_query.get(event.entity) {
-            let mut rng = rand::thread_rng();
             let fragment_count = rng.gen_range(FRAGMENT_MIN_COUNT..=FRAGMENT_MAX_COUNT);
 
             fracture_death_events.write(FractureDeathEvent::new(
@@ -134,10 +134,9 @@ pub fn spawn_fragment_enemies_system(
     mut fracture_events: MessageReader<FractureDeathEvent>,
     game_meshes: Option<Res<GameMeshes>>,
     enemy_materials: Option<Res<EnemyLevelMaterials>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for event in fracture_events.read() {
-        let mut rng = rand::thread_rng();
-
         for i in 0..event.fragment_count {
             // Calculate spawn position with offset
             let angle = (i as f32 / event.fragment_count as f32) * std::f32::consts::TAU
@@ -441,6 +440,7 @@ mod tests {
             let mut app = App::new();
             app.add_message::<DeathEvent>();
             app.add_message::<FractureDeathEvent>();
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -596,6 +596,7 @@ mod tests {
         fn setup_test_app() -> App {
             let mut app = App::new();
             app.add_message::<FractureDeathEvent>();
+            app.init_resource::<GameRng>();
             app
         }
 
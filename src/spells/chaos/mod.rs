@@ -1,17 +1,38 @@
+use bevy::prelude::App;
+
+use crate::spell::{SpellBehaviorAppExt, SpellType};
+
 pub mod anomaly;
+pub mod cataclysm;
 pub mod chaos_bolt;
 pub mod disorder_pulse;
 pub mod entropy_field;
 pub mod fear;
 pub mod fracture;
 pub mod pandemonium;
+pub mod volatile_orb;
 pub mod warp_rift;
 
 pub use anomaly::*;
+pub use cataclysm::*;
 pub use chaos_bolt::*;
 pub use disorder_pulse::*;
 pub use entropy_field::*;
 pub use fear::*;
 pub use fracture::*;
 pub use pandemonium::*;
+pub use volatile_orb::*;
 pub use warp_rift::*;
+
+/// Registers cast handlers for Chaos spells.
+pub fn plugin(app: &mut App) {
+    app
+        .register_spell_behavior(SpellType::WildMagic, anomaly::cast_anomaly)
+        .register_spell_behavior(SpellType::Entropy, entropy_field::cast_entropy_field)
+        .register_spell_behavior(SpellType::ChaosBolt, chaos_bolt::cast_chaos_bolt)
+        .register_spell_behavior(SpellType::Randomize, pandemonium::cast_pandemonium)
+        .register_spell_behavior(SpellType::Unstable, volatile_orb::cast_volatile_orb)
+        .register_spell_behavior(SpellType::Paradox, warp_rift::cast_warp_rift)
+        .register_spell_behavior(SpellType::Mayhem, disorder_pulse::cast_disorder_pulse)
+        .register_spell_behavior(SpellType::Cataclysm, cataclysm::cast_cataclysm);
+}
@@ -7,12 +7,15 @@
 use std::collections::HashSet;
 use bevy::prelude::*;
 use rand::Rng;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Pandemonium spell
 pub const PANDEMONIUM_BURST_RADIUS: f32 = 7.0;
@@ -150,20 +153,36 @@ pub fn spawn_pandemonium_burst(
     }
 }
 
+/// Cast handler registered for SpellType::Randomize.
+pub fn cast_pandemonium(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Pandemonium confuses enemies so they wander and attack each other
+    spawn_pandemonium_burst(
+        commands,
+        ctx.spell,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// System that applies the confusion debuff to enemies within burst radius.
 /// This is a single-frame effect - once processed, enemies are marked and the burst is cleaned up.
 pub fn apply_pandemonium_to_enemies_system(
     mut commands: Commands,
     mut burst_query: Query<&mut PandemoniumBurst>,
-    mut enemy_query: Query<(Entity, &Transform, Option<&mut ConfusedEnemy>), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    mut confusion_query: Query<Option<&mut ConfusedEnemy>, With<Enemy>>,
 ) {
     for mut burst in burst_query.iter_mut() {
         if burst.processed {
             continue;
         }
 
-        for (enemy_entity, enemy_transform, existing_confusion) in enemy_query.iter_mut() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(burst.center, burst.radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
+            let Ok(existing_confusion) = confusion_query.get_mut(enemy_entity) else {
+                continue;
+            };
 
             if burst.can_affect(enemy_entity, enemy_pos) {
                 if let Some(mut confused) = existing_confusion {
@@ -188,19 +207,19 @@ pub fn apply_pandemonium_to_enemies_system(
 /// Periodically selects a random target from nearby enemies.
 pub fn update_confused_enemy_targeting_system(
     mut confused_query: Query<(Entity, &mut ConfusedEnemy, &Transform)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     time: Res<Time>,
+    mut rng: ResMut<GameRng>,
 ) {
-    let mut rng = rand::thread_rng();
-
     for (confused_entity, mut confused, transform) in confused_query.iter_mut() {
         confused.tick(time.delta());
 
         if confused.should_retarget() {
             // Collect nearby enemies (excluding self)
             let my_pos = from_xz(transform.translation);
-            let nearby_enemies: Vec<Entity> = enemy_query
-                .iter()
+            let nearby_enemies: Vec<Entity> = spatial_enemies
+                .near(my_pos, 15.0)
+                .into_iter()
                 .filter(|(e, _)| *e != confused_entity)
                 .filter(|(_, t)| {
                     let pos = from_xz(t.translation);
@@ -237,11 +256,10 @@ pub fn confused_enemy_attack_system(
 
                 // Attack range - close enough to deal damage
                 if distance < 1.5 {
-                    damage_events.write(DamageEvent::with_element(
-                        target,
-                        enemy.strength,
-                        Element::Chaos,
-                    ));
+                    damage_events.write(
+                        DamageEvent::with_element(target, enemy.strength, Element::Chaos)
+                            .by_spell(SpellType::Randomize),
+                    );
                 }
             }
         }
@@ -673,6 +691,7 @@ mod tests {
         fn setup_targeting_test_app() -> App {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -0,0 +1,306 @@
+//! Volatile Orb spell - An unstable orb of chaos energy that explodes unpredictably.
+//!
+//! A Chaos element spell (Unstable SpellType) that drops an orb at the target
+//! location. The orb's fuse length and explosion strength are rolled at cast time,
+//! so each detonation lands somewhere between a fizzle and a devastating blast.
+
+use bevy::prelude::*;
+use rand::Rng;
+use crate::collision::spatial::SpatialEnemies;
+use crate::combat::DamageEvent;
+use crate::element::Element;
+use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
+use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
+
+/// Default configuration for Volatile Orb spell
+pub const VOLATILE_ORB_FUSE_MIN: f32 = 0.5;
+pub const VOLATILE_ORB_FUSE_MAX: f32 = 1.5;
+pub const VOLATILE_ORB_RADIUS: f32 = 4.0;
+pub const VOLATILE_ORB_DAMAGE_MULTIPLIER_MIN: f32 = 0.5;
+pub const VOLATILE_ORB_DAMAGE_MULTIPLIER_MAX: f32 = 2.0;
+pub const VOLATILE_ORB_VISUAL_HEIGHT: f32 = 0.5;
+pub const VOLATILE_ORB_BLAST_LIFETIME: f32 = 0.3;
+
+/// Get the chaos element color for visual effects
+pub fn volatile_orb_color() -> Color {
+    Element::Chaos.color()
+}
+
+/// Volatile orb waiting to detonate at a fixed position.
+#[derive(Component, Debug, Clone)]
+pub struct VolatileOrb {
+    /// Center position on XZ plane
+    pub center: Vec2,
+    /// Fuse timer counting down to detonation
+    pub fuse: Timer,
+    /// Damage dealt to every enemy in the blast (already includes the rolled multiplier)
+    pub damage: f32,
+    /// Blast radius
+    pub radius: f32,
+}
+
+impl VolatileOrb {
+    pub fn new(center: Vec2, damage: f32, fuse_secs: f32) -> Self {
+        Self {
+            center,
+            fuse: Timer::from_seconds(fuse_secs, TimerMode::Once),
+            damage,
+            radius: VOLATILE_ORB_RADIUS,
+        }
+    }
+
+    /// Create an orb with a randomly rolled fuse and damage multiplier.
+    pub fn rolled(center: Vec2, base_damage: f32, rng: &mut impl Rng) -> Self {
+        let fuse = rng.gen_range(VOLATILE_ORB_FUSE_MIN..=VOLATILE_ORB_FUSE_MAX);
+        let multiplier =
+            rng.gen_range(VOLATILE_ORB_DAMAGE_MULTIPLIER_MIN..=VOLATILE_ORB_DAMAGE_MULTIPLIER_MAX);
+        Self::new(center, base_damage * multiplier, fuse)
+    }
+
+    /// Check if the fuse has burnt down
+    pub fn should_detonate(&self) -> bool {
+        self.fuse.is_finished()
+    }
+}
+
+/// Short-lived blast visual left behind after an orb detonates.
+#[derive(Component, Debug, Clone)]
+pub struct VolatileOrbBlast {
+    pub lifetime: Timer,
+    pub radius: f32,
+}
+
+impl VolatileOrbBlast {
+    pub fn new(radius: f32) -> Self {
+        Self {
+            lifetime: Timer::from_seconds(VOLATILE_ORB_BLAST_LIFETIME, TimerMode::Once),
+            radius,
+        }
+    }
+}
+
+/// System that ticks orb fuses, damages enemies on detonation and spawns the blast visual.
+pub fn volatile_orb_detonation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut orb_query: Query<(Entity, &mut VolatileOrb)>,
+    spatial_enemies: SpatialEnemies,
+    mut damage_events: MessageWriter<DamageEvent>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+) {
+    for (orb_entity, mut orb) in orb_query.iter_mut() {
+        orb.fuse.tick(time.delta());
+
+        if !orb.should_detonate() {
+            continue;
+        }
+
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(orb.center, orb.radius) {
+            let enemy_pos = from_xz(enemy_transform.translation);
+            if orb.center.distance(enemy_pos) <= orb.radius {
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, orb.damage, Element::Chaos)
+                        .by_spell(SpellType::Unstable),
+                );
+            }
+        }
+
+        let blast = VolatileOrbBlast::new(orb.radius);
+        let blast_pos = to_xz(orb.center) + Vec3::new(0.0, VOLATILE_ORB_VISUAL_HEIGHT, 0.0);
+        if let (Some(meshes), Some(materials)) = (game_meshes.as_ref(), game_materials.as_ref()) {
+            commands.spawn((
+                Mesh3d(meshes.explosion.clone()),
+                MeshMaterial3d(materials.chaos_aoe.clone()),
+                Transform::from_translation(blast_pos).with_scale(Vec3::splat(blast.radius)),
+                blast,
+            ));
+        } else {
+            commands.spawn((Transform::from_translation(blast_pos), blast));
+        }
+
+        commands.entity(orb_entity).despawn();
+    }
+}
+
+/// System that despawns blast visuals once their lifetime expires.
+pub fn volatile_orb_blast_cleanup_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut blast_query: Query<(Entity, &mut VolatileOrbBlast)>,
+) {
+    for (entity, mut blast) in blast_query.iter_mut() {
+        blast.lifetime.tick(time.delta());
+        if blast.lifetime.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Cast volatile orb spell - drops an unstable orb at the target position.
+pub fn fire_volatile_orb(
+    commands: &mut Commands,
+    spell: &Spell,
+    spawn_position: Vec3,
+    target_pos: Vec2,
+    game_meshes: Option<&GameMeshes>,
+    game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
+) {
+    fire_volatile_orb_with_damage(
+        commands,
+        spell,
+        spell.damage(),
+        spawn_position,
+        target_pos,
+        game_meshes,
+        game_materials,
+        rng,
+    );
+}
+
+/// Cast volatile orb spell with explicit damage.
+/// `target_pos` is the enemy position on XZ plane where the orb is dropped.
+/// `damage` is the pre-calculated final damage before the random blast multiplier.
+#[allow(clippy::too_many_arguments)]
+pub fn fire_volatile_orb_with_damage(
+    commands: &mut Commands,
+    _spell: &Spell,
+    damage: f32,
+    _spawn_position: Vec3,
+    target_pos: Vec2,
+    game_meshes: Option<&GameMeshes>,
+    game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
+) {
+    let orb = VolatileOrb::rolled(target_pos, damage, rng);
+    let orb_pos = to_xz(target_pos) + Vec3::new(0.0, VOLATILE_ORB_VISUAL_HEIGHT, 0.0);
+
+    if let (Some(meshes), Some(materials)) = (game_meshes, game_materials) {
+        commands.spawn((
+            Mesh3d(meshes.fireball.clone()),
+            MeshMaterial3d(materials.chaos_bolt.clone()),
+            Transform::from_translation(orb_pos),
+            orb,
+        ));
+    } else {
+        // Fallback for tests without mesh resources
+        commands.spawn((
+            Transform::from_translation(orb_pos),
+            orb,
+        ));
+    }
+}
+
+/// Cast handler registered for SpellType::Unstable.
+pub fn cast_volatile_orb(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_volatile_orb_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+        ctx.rng,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enemies::components::Enemy;
+    use std::time::Duration;
+    use bevy::app::App;
+    use crate::spell::SpellType;
+
+    mod volatile_orb_component_tests {
+        use super::*;
+
+        #[test]
+        fn test_volatile_orb_new() {
+            let orb = VolatileOrb::new(Vec2::new(3.0, 4.0), 20.0, 1.0);
+            assert_eq!(orb.center, Vec2::new(3.0, 4.0));
+            assert_eq!(orb.damage, 20.0);
+            assert_eq!(orb.radius, VOLATILE_ORB_RADIUS);
+            assert!(!orb.should_detonate());
+        }
+
+        #[test]
+        fn test_rolled_orb_stays_within_configured_ranges() {
+            let mut rng = rand::thread_rng();
+            for _ in 0..50 {
+                let orb = VolatileOrb::rolled(Vec2::ZERO, 10.0, &mut rng);
+                let fuse = orb.fuse.duration().as_secs_f32();
+                assert!((VOLATILE_ORB_FUSE_MIN..=VOLATILE_ORB_FUSE_MAX).contains(&fuse));
+                assert!(orb.damage >= 10.0 * VOLATILE_ORB_DAMAGE_MULTIPLIER_MIN);
+                assert!(orb.damage <= 10.0 * VOLATILE_ORB_DAMAGE_MULTIPLIER_MAX);
+            }
+        }
+    }
+
+    mod volatile_orb_system_tests {
+        use super::*;
+
+        #[test]
+        fn test_fire_volatile_orb_spawns_at_target() {
+            let mut app = App::new();
+            let spell = Spell::new(SpellType::Unstable);
+
+            {
+                let mut commands = app.world_mut().commands();
+                fire_volatile_orb(
+                    &mut commands,
+                    &spell,
+                    Vec3::ZERO,
+                    Vec2::new(5.0, -2.0),
+                    None,
+                    None,
+                    &mut rand::thread_rng(),
+                );
+            }
+            app.update();
+
+            let mut query = app.world_mut().query::<&VolatileOrb>();
+            let orbs: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(orbs.len(), 1);
+            assert_eq!(orbs[0].center, Vec2::new(5.0, -2.0));
+        }
+
+        #[test]
+        fn test_orb_detonation_damages_enemies_in_radius_only() {
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin::default());
+            app.add_message::<DamageEvent>();
+            app.add_systems(Update, volatile_orb_detonation_system);
+
+            let near = app.world_mut().spawn((
+                Enemy { speed: 2.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(1.0, 0.375, 0.0)),
+            )).id();
+            app.world_mut().spawn((
+                Enemy { speed: 2.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(50.0, 0.375, 0.0)),
+            ));
+
+            let mut orb = VolatileOrb::new(Vec2::ZERO, 30.0, 0.5);
+            orb.fuse.tick(Duration::from_secs(1));
+            let orb_entity = app.world_mut().spawn((Transform::default(), orb)).id();
+
+            app.update();
+
+            assert!(app.world().get_entity(orb_entity).is_err(), "Orb should despawn on detonation");
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let mut cursor = messages.get_cursor();
+            let events: Vec<_> = cursor.read(messages).collect();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].target, near);
+            assert_eq!(events[0].amount, 30.0);
+            assert_eq!(events[0].element, Some(Element::Chaos));
+        }
+    }
+}
@@ -5,12 +5,15 @@
 //! the center take damage. The pull strength falls off with distance.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, Velocity};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Warp Rift spell
 pub const WARP_RIFT_PULL_RADIUS: f32 = 8.0;
@@ -127,11 +130,15 @@ pub fn warp_rift_tick_system(
 /// System that applies pull force to enemies within the rift's pull radius.
 pub fn warp_rift_pull_system(
     rift_query: Query<&WarpRift>,
-    mut enemy_query: Query<(&Transform, &mut Velocity), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    mut velocity_query: Query<&mut Velocity, With<Enemy>>,
 ) {
     for rift in rift_query.iter() {
-        for (transform, mut velocity) in enemy_query.iter_mut() {
+        for (enemy_entity, transform) in spatial_enemies.near(rift.center, rift.pull_radius) {
             let enemy_pos = from_xz(transform.translation);
+            let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) else {
+                continue;
+            };
 
             if rift.is_in_pull_range(enemy_pos) {
                 let pull = rift.calculate_pull(enemy_pos);
@@ -145,7 +152,7 @@ pub fn warp_rift_pull_system(
 /// System that damages enemies within the rift's damage radius.
 pub fn warp_rift_damage_system(
     rift_query: Query<&WarpRift>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for rift in rift_query.iter() {
@@ -153,15 +160,15 @@ pub fn warp_rift_damage_system(
             continue;
         }
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(rift.center, rift.damage_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
 
             if rift.is_in_damage_range(enemy_pos) {
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    rift.damage_per_tick,
-                    Element::Chaos,
-                ));
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, rift.damage_per_tick, Element::Chaos)
+                        .over_time()
+                        .by_spell(SpellType::Paradox),
+                );
             }
         }
     }
@@ -230,6 +237,19 @@ pub fn spawn_warp_rift_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Paradox.
+pub fn cast_warp_rift(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    spawn_warp_rift_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,12 +6,15 @@
 //! and grouping enemies together.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, Velocity};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Black Spiral spell
 pub const BLACK_SPIRAL_PULL_RADIUS: f32 = 6.0;
@@ -120,11 +123,15 @@ pub fn black_spiral_tick_system(
 /// System that applies pull force to enemies within the spiral's pull radius.
 pub fn black_spiral_pull_system(
     spiral_query: Query<&BlackSpiral>,
-    mut enemy_query: Query<(&Transform, &mut Velocity), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    mut velocity_query: Query<&mut Velocity, With<Enemy>>,
 ) {
     for spiral in spiral_query.iter() {
-        for (transform, mut velocity) in enemy_query.iter_mut() {
+        for (enemy_entity, transform) in spatial_enemies.near(spiral.center, spiral.pull_radius) {
             let enemy_pos = from_xz(transform.translation);
+            let Ok(mut velocity) = velocity_query.get_mut(enemy_entity) else {
+                continue;
+            };
 
             if spiral.is_in_pull_range(enemy_pos) {
                 let pull = spiral.calculate_pull(enemy_pos);
@@ -138,7 +145,7 @@ pub fn black_spiral_pull_system(
 /// System that damages enemies within the spiral's radius.
 pub fn black_spiral_damage_system(
     spiral_query: Query<&BlackSpiral>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for spiral in spiral_query.iter() {
@@ -146,15 +153,15 @@ pub fn black_spiral_damage_system(
             continue;
         }
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(spiral.center, spiral.pull_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
 
             if spiral.is_in_pull_range(enemy_pos) {
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    spiral.damage_per_tick,
-                    Element::Dark,
-                ));
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, spiral.damage_per_tick, Element::Dark)
+                        .over_time()
+                        .by_spell(SpellType::VoidRift),
+                );
             }
         }
     }
@@ -233,6 +240,20 @@ pub fn spawn_black_spiral_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::VoidRift.
+pub fn cast_black_spiral(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Black Spiral creates a rotating vortex that pulls enemies inward and damages them
+    spawn_black_spiral_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,13 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Grim Tether spell
 pub const GRIM_TETHER_DURATION: f32 = 8.0;
@@ -131,19 +134,18 @@ pub fn cleanup_tethered_enemy_system(
 /// Reads DamageEvents, checks if target is tethered, and shares damage to others.
 #[allow(clippy::too_many_arguments)]
 pub fn grim_tether_damage_share_system(
-    mut damage_events: MessageReader<DamageEvent>,
-    mut damage_writer: MessageWriter<DamageEvent>,
+    mut damage_messages: ParamSet<(MessageReader<DamageEvent>, MessageWriter<DamageEvent>)>,
     tethered_query: Query<&TetheredEnemy>,
     tether_query: Query<&GrimTether>,
     shared_damage_query: Query<&SharedDamageMarker>,
 ) {
     // Collect damage to share (avoid borrow conflicts)
-    let mut shared_damages: Vec<(Entity, f32, u64)> = Vec::new();
+    let mut shared_damages: Vec<(Entity, f32, Entity)> = Vec::new();
 
-    for event in damage_events.read() {
-        // Skip if this is already shared damage (check via source entity having SharedDamageMarker)
+    for event in damage_messages.p0().read() {
+        // Skip if this is already shared damage (sourced from a tether or a SharedDamageMarker entity)
         if let Some(source) = event.source {
-            if shared_damage_query.contains(source) {
+            if shared_damage_query.contains(source) || tether_query.contains(source) {
                 continue;
             }
         }
@@ -164,16 +166,18 @@ pub fn grim_tether_damage_share_system(
         // Queue damage for all other linked enemies
         for &linked_entity in &tether.linked_enemies {
             if linked_entity != event.target {
-                shared_damages.push((linked_entity, shared_amount, tether.tether_id));
+                shared_damages.push((linked_entity, shared_amount, tethered.tether_entity));
             }
         }
     }
 
-    // Write shared damage events
-    for (target, amount, _tether_id) in shared_damages {
-        // Use a special source to mark this as shared damage
-        // We use the target itself as source to indicate shared damage
-        damage_writer.write(DamageEvent::with_element(target, amount, Element::Dark));
+    // Write shared damage events with the tether as source so they are not shared again
+    let mut damage_writer = damage_messages.p1();
+    for (target, amount, tether_entity) in shared_damages {
+        damage_writer.write(
+            DamageEvent::with_source_and_element(target, amount, tether_entity, Element::Dark)
+                .by_spell(SpellType::Corruption),
+        );
     }
 }
 
@@ -269,7 +273,7 @@ pub fn fire_grim_tether(
     commands: &mut Commands,
     spell: &Spell,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform), With<Enemy>>,
+    enemies: &SpatialEnemies,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
 ) {
@@ -278,7 +282,7 @@ pub fn fire_grim_tether(
         spell,
         spell.damage(),
         spawn_position,
-        enemy_query,
+        enemies,
         game_meshes,
         game_materials,
     );
@@ -293,15 +297,16 @@ pub fn fire_grim_tether_with_damage(
     _spell: &Spell,
     _damage: f32,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform), With<Enemy>>,
+    enemies: &SpatialEnemies,
     _game_meshes: Option<&GameMeshes>,
     _game_materials: Option<&GameMaterials>,
 ) {
     let center = from_xz(spawn_position);
 
     // Find enemies within link range
-    let mut nearby_enemies: Vec<(Entity, f32)> = enemy_query
-        .iter()
+    let mut nearby_enemies: Vec<(Entity, f32)> = enemies
+        .near(center, GRIM_TETHER_LINK_RANGE)
+        .into_iter()
         .filter_map(|(entity, transform)| {
             let pos = from_xz(transform.translation);
             let distance = center.distance(pos);
@@ -347,6 +352,20 @@ pub fn fire_grim_tether_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Corruption.
+pub fn cast_grim_tether(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Grim Tether links nearby enemies so damage to one is shared across the group
+    fire_grim_tether_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.enemies,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -654,6 +673,67 @@ mod tests {
 
             assert_eq!(tethered.tether_entity, tether_entity);
         }
+
+        #[test]
+        fn test_damage_share_system_does_not_reshare_shared_damage() {
+            use bevy::ecs::system::RunSystemOnce;
+
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+
+            let enemy1 = app.world_mut().spawn_empty().id();
+            let enemy2 = app.world_mut().spawn_empty().id();
+            let tether_entity = app
+                .world_mut()
+                .spawn(GrimTether::new(vec![enemy1, enemy2], 0.5, 8.0))
+                .id();
+            app.world_mut().entity_mut(enemy1).insert(TetheredEnemy::new(tether_entity));
+            app.world_mut().entity_mut(enemy2).insert(TetheredEnemy::new(tether_entity));
+
+            app.world_mut().write_message(DamageEvent::new(enemy1, 40.0));
+            let _ = app.world_mut().run_system_once(grim_tether_damage_share_system);
+
+            let shared: Vec<DamageEvent> = {
+                let messages = app.world().resource::<Messages<DamageEvent>>();
+                let mut cursor = messages.get_cursor();
+                cursor.read(messages).cloned().collect()
+            };
+            assert_eq!(shared.len(), 2, "Original event plus one shared event");
+            assert_eq!(shared[1].target, enemy2);
+            assert_eq!(shared[1].amount, 20.0);
+            assert_eq!(shared[1].source, Some(tether_entity));
+
+        }
+
+        #[test]
+        fn test_damage_share_system_ignores_damage_sourced_from_tether() {
+            use bevy::ecs::system::RunSystemOnce;
+
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+
+            let enemy1 = app.world_mut().spawn_empty().id();
+            let enemy2 = app.world_mut().spawn_empty().id();
+            let tether_entity = app
+                .world_mut()
+                .spawn(GrimTether::new(vec![enemy1, enemy2], 0.5, 8.0))
+                .id();
+            app.world_mut().entity_mut(enemy1).insert(TetheredEnemy::new(tether_entity));
+            app.world_mut().entity_mut(enemy2).insert(TetheredEnemy::new(tether_entity));
+
+            // Shared damage must not bounce back to the other linked enemy
+            app.world_mut().write_message(DamageEvent::with_source_and_element(
+                enemy2,
+                20.0,
+                tether_entity,
+                Element::Dark,
+            ));
+            let _ = app.world_mut().run_system_once(grim_tether_damage_share_system);
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let mut cursor = messages.get_cursor();
+            assert_eq!(cursor.read(messages).count(), 1);
+        }
     }
 
     mod fire_grim_tether_tests {
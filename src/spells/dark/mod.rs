@@ -1,3 +1,7 @@
+use bevy::prelude::App;
+
+use crate::spell::{SpellBehaviorAppExt, SpellType};
+
 pub mod black_spiral;
 pub mod grim_tether;
 pub mod nightfall;
@@ -15,3 +19,16 @@ pub use soul_drain::*;
 pub use soul_rend::*;
 pub use void_pulse::*;
 pub use wraith_form::*;
+
+/// Registers cast handlers for Dark spells.
+pub fn plugin(app: &mut App) {
+    app
+        .register_spell_behavior(SpellType::ShadowBolt, shadow_bolt::cast_shadow_bolt)
+        .register_spell_behavior(SpellType::VoidRift, black_spiral::cast_black_spiral)
+        .register_spell_behavior(SpellType::DarkPulse, void_pulse::cast_void_pulse)
+        .register_spell_behavior(SpellType::Corruption, grim_tether::cast_grim_tether)
+        .register_spell_behavior(SpellType::SoulDrain, soul_drain::cast_soul_drain)
+        .register_spell_behavior(SpellType::Nightmare, wraith_form::cast_wraith_form)
+        .register_spell_behavior(SpellType::Eclipse, nightfall::cast_nightfall)
+        .register_spell_behavior(SpellType::Oblivion, soul_rend::cast_soul_rend);
+}
@@ -4,13 +4,15 @@
 //! All Dark element spells deal bonus damage to enemies within the Nightfall zone.
 //! This implements the Eclipse SpellType from the Dark element.
 
+use std::collections::HashMap;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Nightfall spell
 pub const NIGHTFALL_RADIUS: f32 = 8.0;
@@ -97,42 +99,28 @@ pub fn nightfall_zone_duration_system(
 pub fn nightfall_zone_tracking_system(
     mut commands: Commands,
     zone_query: Query<&NightfallZone>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     marked_query: Query<Entity, With<InNightfallZone>>,
 ) {
-    // First, check which enemies are in any nightfall zone
-    let mut enemies_in_zones: Vec<(Entity, f32)> = Vec::new();
+    // First, check which enemies are in any nightfall zone,
+    // keeping the highest multiplier if they are in several
+    let mut enemies_in_zones: HashMap<Entity, f32> = HashMap::new();
 
-    for (enemy_entity, enemy_transform) in enemy_query.iter() {
-        let enemy_pos = from_xz(enemy_transform.translation);
-
-        // Check if enemy is in any nightfall zone
-        let mut max_multiplier: Option<f32> = None;
-        for zone in zone_query.iter() {
+    for zone in zone_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(zone.center, zone.radius) {
+            let enemy_pos = from_xz(enemy_transform.translation);
             if zone.contains(enemy_pos) {
-                // Use highest multiplier if in multiple zones
-                match max_multiplier {
-                    Some(current) => {
-                        if zone.dark_damage_multiplier > current {
-                            max_multiplier = Some(zone.dark_damage_multiplier);
-                        }
-                    }
-                    None => {
-                        max_multiplier = Some(zone.dark_damage_multiplier);
-                    }
-                }
+                let multiplier = enemies_in_zones
+                    .entry(enemy_entity)
+                    .or_insert(zone.dark_damage_multiplier);
+                *multiplier = multiplier.max(zone.dark_damage_multiplier);
             }
         }
-
-        if let Some(multiplier) = max_multiplier {
-            enemies_in_zones.push((enemy_entity, multiplier));
-        }
     }
 
     // Remove markers from enemies no longer in any zone
     for marked_entity in marked_query.iter() {
-        let still_in_zone = enemies_in_zones.iter().any(|(e, _)| *e == marked_entity);
-        if !still_in_zone {
+        if !enemies_in_zones.contains_key(&marked_entity) {
             commands.entity(marked_entity).remove::<InNightfallZone>();
         }
     }
@@ -162,7 +150,7 @@ pub fn nightfall_damage_bonus_system(
     // in the apply_damage_system itself.
     //
     // For now, this system is a placeholder - the actual damage boost is applied
-    // by checking InNightfallZone in the combat damage system.
+    // by InNightfallZone's DamageModifier impl in the combat damage pipeline.
     //
     // Alternative: Use a separate event channel for "pre-processed" damage.
     let _ = (&marked_query, &mut damage_events, &mut boosted_events);
@@ -234,9 +222,23 @@ pub fn fire_nightfall_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Eclipse.
+pub fn cast_nightfall(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_nightfall_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
     use bevy::ecs::system::RunSystemOnce;
@@ -2,13 +2,16 @@ use std::collections::HashSet;
 use bevy::prelude::*;
 use bevy_kira_audio::prelude::*;
 use crate::audio::plugin::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::{DamageEvent, Health};
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Shadow Bolt spell
 pub const SHADOW_BOLT_SPEED: f32 = 25.0;
@@ -38,6 +41,10 @@ pub struct ShadowBoltProjectile {
     pub lifesteal_percentage: f32,
 }
 
+impl ObstacleProjectile for ShadowBoltProjectile {
+    const SPELL_TYPE: SpellType = SpellType::ShadowBolt;
+}
+
 impl ShadowBoltProjectile {
     pub fn new(direction: Vec2, speed: f32, lifetime_secs: f32, damage: f32, lifesteal_percentage: f32) -> Self {
         Self {
@@ -97,7 +104,7 @@ pub fn shadow_bolt_lifetime_system(
 /// System that detects shadow bolt-enemy collisions and fires events
 pub fn shadow_bolt_collision_detection(
     shadow_bolt_query: Query<(Entity, &Transform), With<ShadowBoltProjectile>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<ShadowBoltEnemyCollisionEvent>,
 ) {
     for (shadow_bolt_entity, shadow_bolt_transform) in shadow_bolt_query.iter() {
@@ -106,7 +113,7 @@ pub fn shadow_bolt_collision_detection(
             shadow_bolt_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(shadow_bolt_xz, SHADOW_BOLT_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -153,7 +160,10 @@ pub fn shadow_bolt_collision_effects(
     // Apply damage and lifesteal effects
     for (enemy_entity, damage, lifesteal_percentage) in effects_to_apply {
         // Send damage event with Dark element
-        damage_events.write(DamageEvent::with_element(enemy_entity, damage, Element::Dark));
+        damage_events.write(
+            DamageEvent::with_element(enemy_entity, damage, Element::Dark)
+                .by_spell(SpellType::ShadowBolt),
+        );
 
         // Apply lifesteal healing to player
         let heal_amount = damage * lifesteal_percentage;
@@ -172,8 +182,8 @@ pub fn fire_shadow_bolt(
     spawn_position: Vec3,
     target_pos: Vec2,
     asset_server: Option<&Res<AssetServer>>,
-    weapon_channel: Option<&mut ResMut<AudioChannel<WeaponSoundChannel>>>,
-    sound_limiter: Option<&mut ResMut<SoundLimiter>>,
+    weapon_channel: Option<&mut AudioChannel<WeaponSoundChannel>>,
+    sound_limiter: Option<&mut SoundLimiter>,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
 ) {
@@ -202,8 +212,8 @@ pub fn fire_shadow_bolt_with_damage(
     spawn_position: Vec3,
     target_pos: Vec2,
     asset_server: Option<&Res<AssetServer>>,
-    weapon_channel: Option<&mut ResMut<AudioChannel<WeaponSoundChannel>>>,
-    sound_limiter: Option<&mut ResMut<SoundLimiter>>,
+    weapon_channel: Option<&mut AudioChannel<WeaponSoundChannel>>,
+    sound_limiter: Option<&mut SoundLimiter>,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
 ) {
@@ -269,9 +279,26 @@ pub fn fire_shadow_bolt_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::ShadowBolt.
+pub fn cast_shadow_bolt(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_shadow_bolt_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.asset_server,
+        ctx.weapon_channel.as_deref_mut(),
+        ctx.sound_limiter.as_deref_mut(),
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
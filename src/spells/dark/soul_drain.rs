@@ -6,13 +6,16 @@
 //! healing the player for a percentage of damage dealt.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::{DamageEvent, Health};
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::player::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Soul Drain spell
 pub const SOUL_DRAIN_DURATION: f32 = 3.0;
@@ -112,11 +115,11 @@ pub fn soul_drain_system(
         }
 
         if soul_drain.should_damage() {
-            damage_events.write(DamageEvent::with_element(
-                soul_drain.target,
-                soul_drain.damage_per_tick,
-                Element::Dark,
-            ));
+            damage_events.write(
+                DamageEvent::with_element(soul_drain.target, soul_drain.damage_per_tick, Element::Dark)
+                    .over_time()
+                    .by_spell(SpellType::SoulDrain),
+            );
 
             // Heal the player for a percentage of damage dealt
             let heal_amount = soul_drain.damage_per_tick * soul_drain.heal_percentage;
@@ -239,11 +242,11 @@ pub fn fire_soul_drain(
     commands: &mut Commands,
     spell: &Spell,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform, &Enemy)>,
+    enemies: &SpatialEnemies,
     _game_meshes: Option<&GameMeshes>,
     _game_materials: Option<&GameMaterials>,
 ) {
-    fire_soul_drain_with_damage(commands, spell, spell.damage(), spawn_position, enemy_query);
+    fire_soul_drain_with_damage(commands, spell, spell.damage(), spawn_position, enemies);
 }
 
 /// Cast Soul Drain spell with explicit damage
@@ -253,27 +256,12 @@ pub fn fire_soul_drain_with_damage(
     _spell: &Spell,
     damage: f32,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform, &Enemy)>,
+    enemies: &SpatialEnemies,
 ) {
     let spawn_xz = from_xz(spawn_position);
 
     // Find nearest enemy within range
-    let mut nearest_enemy: Option<(Entity, f32)> = None;
-
-    for (enemy_entity, enemy_transform, _enemy) in enemy_query.iter() {
-        let enemy_pos = from_xz(enemy_transform.translation);
-        let distance = spawn_xz.distance(enemy_pos);
-
-        if distance <= SOUL_DRAIN_RANGE {
-            match nearest_enemy {
-                None => nearest_enemy = Some((enemy_entity, distance)),
-                Some((_, nearest_dist)) if distance < nearest_dist => {
-                    nearest_enemy = Some((enemy_entity, distance));
-                }
-                _ => {}
-            }
-        }
-    }
+    let nearest_enemy = enemies.nearest(spawn_xz, SOUL_DRAIN_RANGE);
 
     // Create soul drain effect targeting the nearest enemy
     if let Some((enemy_entity, _)) = nearest_enemy {
@@ -285,6 +273,17 @@ pub fn fire_soul_drain_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::SoulDrain.
+pub fn cast_soul_drain(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_soul_drain_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.enemies,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
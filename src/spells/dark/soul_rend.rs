@@ -3,12 +3,16 @@ use bevy_kira_audio::prelude::*;
 use std::collections::HashSet;
 
 use crate::audio::plugin::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::{DamageEvent, Health};
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Soul Rend spell
 pub const SOUL_REND_SPEED: f32 = 22.0;
@@ -41,6 +45,10 @@ pub struct SoulRendProjectile {
     pub execute_multiplier: f32,
 }
 
+impl ObstacleProjectile for SoulRendProjectile {
+    const SPELL_TYPE: SpellType = SpellType::Oblivion;
+}
+
 impl SoulRendProjectile {
     pub fn new(
         direction: Vec2,
@@ -124,7 +132,7 @@ pub fn soul_rend_lifetime_system(
 /// System that detects soul rend-enemy collisions and fires events
 pub fn soul_rend_collision_detection(
     soul_rend_query: Query<(Entity, &Transform), With<SoulRendProjectile>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<SoulRendEnemyCollisionEvent>,
 ) {
     for (soul_rend_entity, soul_rend_transform) in soul_rend_query.iter() {
@@ -133,7 +141,7 @@ pub fn soul_rend_collision_detection(
             soul_rend_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(soul_rend_xz, SOUL_REND_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -183,7 +191,10 @@ pub fn soul_rend_collision_effects(
 
     // Apply damage with Dark element
     for (enemy_entity, damage) in effects_to_apply {
-        damage_events.write(DamageEvent::with_element(enemy_entity, damage, Element::Dark));
+        damage_events.write(
+            DamageEvent::with_element(enemy_entity, damage, Element::Dark)
+                .by_spell(SpellType::Oblivion),
+        );
     }
 }
 
@@ -196,8 +207,8 @@ pub fn fire_soul_rend(
     spawn_position: Vec3,
     target_pos: Vec2,
     asset_server: Option<&Res<AssetServer>>,
-    weapon_channel: Option<&mut ResMut<AudioChannel<WeaponSoundChannel>>>,
-    sound_limiter: Option<&mut ResMut<SoundLimiter>>,
+    weapon_channel: Option<&mut AudioChannel<WeaponSoundChannel>>,
+    sound_limiter: Option<&mut SoundLimiter>,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
 ) {
@@ -226,8 +237,8 @@ pub fn fire_soul_rend_with_damage(
     spawn_position: Vec3,
     target_pos: Vec2,
     asset_server: Option<&Res<AssetServer>>,
-    weapon_channel: Option<&mut ResMut<AudioChannel<WeaponSoundChannel>>>,
-    sound_limiter: Option<&mut ResMut<SoundLimiter>>,
+    weapon_channel: Option<&mut AudioChannel<WeaponSoundChannel>>,
+    sound_limiter: Option<&mut SoundLimiter>,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
 ) {
@@ -294,6 +305,22 @@ pub fn fire_soul_rend_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Oblivion.
+pub fn cast_soul_rend(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_soul_rend_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.asset_server,
+        ctx.weapon_channel.as_deref_mut(),
+        ctx.sound_limiter.as_deref_mut(),
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,12 +7,14 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Maximum radius the void pulse will expand to
 pub const VOID_PULSE_MAX_RADIUS: f32 = 8.0;
@@ -149,22 +151,21 @@ pub fn void_pulse_expansion_system(
 pub fn void_pulse_collision_system(
     mut commands: Commands,
     mut pulse_query: Query<&mut VoidPulseWave>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     existing_debuff_query: Query<&WeakenedDebuff>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut pulse in pulse_query.iter_mut() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(pulse.center, pulse.current_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = pulse.center.distance(enemy_pos);
 
             if pulse.should_affect(enemy_entity, distance) {
                 // Apply damage with Dark element
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    pulse.damage,
-                    Element::Dark,
-                ));
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, pulse.damage, Element::Dark)
+                        .by_spell(SpellType::DarkPulse),
+                );
 
                 // Apply or refresh WeakenedDebuff
                 if existing_debuff_query.get(enemy_entity).is_ok() {
@@ -273,9 +274,22 @@ pub fn fire_void_pulse_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::DarkPulse.
+pub fn cast_void_pulse(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_void_pulse_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
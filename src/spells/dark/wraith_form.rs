@@ -5,12 +5,15 @@
 //! once per activation. This implements the Nightmare SpellType from the Dark element.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::movement::components::from_xz;
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Wraith Form spell
 pub const WRAITH_FORM_DURATION: f32 = 3.0;
@@ -100,14 +103,11 @@ pub fn wraith_form_expiration_system(
 
 /// System that detects when the wraith-form player passes through enemies
 /// and applies damage to them (once per enemy per activation).
-#[allow(clippy::type_complexity)]
 pub fn wraith_form_damage_system(
     mut commands: Commands,
     player_query: Query<(&Transform, &WraithForm), With<Player>>,
-    enemy_query: Query<
-        (Entity, &Transform, Option<&WraithFormDamagedBy>),
-        With<Enemy>,
-    >,
+    spatial_enemies: SpatialEnemies,
+    marker_query: Query<Option<&WraithFormDamagedBy>, With<Enemy>>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     let Ok((player_transform, wraith_form)) = player_query.single() else {
@@ -116,7 +116,11 @@ pub fn wraith_form_damage_system(
 
     let player_pos = from_xz(player_transform.translation);
 
-    for (enemy_entity, enemy_transform, damaged_marker) in enemy_query.iter() {
+    for (enemy_entity, enemy_transform) in spatial_enemies.near(player_pos, WRAITH_FORM_COLLISION_RADIUS) {
+        let Ok(damaged_marker) = marker_query.get(enemy_entity) else {
+            continue;
+        };
+
         // Skip enemies already damaged by this activation
         if let Some(marker) = damaged_marker {
             if marker.activation_id == wraith_form.activation_id {
@@ -130,11 +134,10 @@ pub fn wraith_form_damage_system(
         // Check if player is passing through this enemy
         if distance < WRAITH_FORM_COLLISION_RADIUS {
             // Deal damage to the enemy
-            damage_events.write(DamageEvent::with_element(
-                enemy_entity,
-                wraith_form.damage_on_pass,
-                Element::Dark,
-            ));
+            damage_events.write(
+                DamageEvent::with_element(enemy_entity, wraith_form.damage_on_pass, Element::Dark)
+                    .by_spell(SpellType::Nightmare),
+            );
 
             // Mark enemy as damaged by this activation
             commands.entity(enemy_entity).insert(WraithFormDamagedBy {
@@ -183,6 +186,19 @@ pub fn fire_wraith_form_with_damage(
     commands.entity(player_entity).insert(wraith_form);
 }
 
+/// Cast handler registered for SpellType::Nightmare.
+pub fn cast_wraith_form(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Wraith Form applies effect to the player (becomes intangible and damages enemies on pass-through)
+    if let Some((player_entity, _, _)) = ctx.player {
+        fire_wraith_form_with_damage(
+            commands,
+            ctx.spell,
+            ctx.damage,
+            player_entity,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,12 +5,15 @@
 //! enemies on contact as they fall through the zone.
 
 use bevy::prelude::*;
+use rand::Rng;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Ashfall spell
 pub const ASHFALL_ZONE_RADIUS: f32 = 3.5;
@@ -154,6 +157,18 @@ pub fn spawn_ashfall_zone_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Ashfall.
+pub fn cast_ashfall(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    spawn_ashfall_zone_with_damage(
+        commands,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// System that ticks zone timers and spawns embers.
 pub fn ashfall_spawn_embers_system(
     mut commands: Commands,
@@ -161,14 +176,15 @@ pub fn ashfall_spawn_embers_system(
     mut zone_query: Query<(Entity, &mut AshfallZone, &Transform)>,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for (zone_entity, mut zone, _zone_transform) in zone_query.iter_mut() {
         zone.tick(time.delta());
 
         if zone.should_spawn_ember() {
             // Spawn ember at random position within zone radius
-            let angle = rand::random::<f32>() * std::f32::consts::TAU;
-            let distance = rand::random::<f32>().sqrt() * zone.radius;
+            let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+            let distance = rng.gen::<f32>().sqrt() * zone.radius;
             let offset = Vec2::new(angle.cos() * distance, angle.sin() * distance);
             let ember_xz = zone.center + offset;
 
@@ -207,14 +223,14 @@ pub fn ashfall_move_embers_system(
 pub fn ashfall_ember_collision_system(
     mut commands: Commands,
     ember_query: Query<(Entity, &FallingEmber, &Transform)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for (ember_entity, ember, ember_transform) in ember_query.iter() {
         let ember_pos = from_xz(ember_transform.translation);
         let ember_y = ember_transform.translation.y;
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(ember_pos, 1.0) {
             let enemy_pos = from_xz(enemy_transform.translation);
             // Enemy hitbox height check - embers pass through at enemy level
             let enemy_height = 0.75; // Approximate enemy height
@@ -226,11 +242,10 @@ pub fn ashfall_ember_collision_system(
                 let distance = ember_pos.distance(enemy_pos);
                 if distance < 1.0 {
                     // Deal damage
-                    damage_events.write(DamageEvent::with_element(
-                        enemy_entity,
-                        ember.damage,
-                        Element::Fire,
-                    ));
+                    damage_events.write(
+                        DamageEvent::with_element(enemy_entity, ember.damage, Element::Fire)
+                            .by_spell(SpellType::Ashfall),
+                    );
 
                     // Despawn ember on hit
                     commands.entity(ember_entity).despawn();
@@ -268,6 +283,7 @@ pub fn ashfall_cleanup_zone_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -444,6 +460,7 @@ mod tests {
         fn setup_spawn_test_app() -> App {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<GameRng>();
             app
         }
 
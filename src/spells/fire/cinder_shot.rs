@@ -6,12 +6,15 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Cinder Shot spell
 pub const CINDER_SHOT_SPEED: f32 = 28.0;
@@ -48,6 +51,10 @@ pub struct CinderShotProjectile {
     pub hit_enemies: HashSet<Entity>,
 }
 
+impl ObstacleProjectile for CinderShotProjectile {
+    const SPELL_TYPE: SpellType = SpellType::FlameLance;
+}
+
 impl CinderShotProjectile {
     pub fn new(direction: Vec2, speed: f32, lifetime_secs: f32, damage: f32) -> Self {
         Self {
@@ -155,7 +162,7 @@ pub fn cinder_shot_lifetime_system(
 /// but only if the enemy hasn't been hit by this projectile before.
 pub fn cinder_shot_collision_detection(
     cinder_shot_query: Query<(Entity, &Transform, &CinderShotProjectile)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<CinderShotEnemyCollisionEvent>,
 ) {
     for (cinder_shot_entity, cinder_shot_transform, cinder_shot) in cinder_shot_query.iter() {
@@ -164,7 +171,7 @@ pub fn cinder_shot_collision_detection(
             cinder_shot_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(cinder_shot_xz, CINDER_SHOT_COLLISION_RADIUS) {
             // Skip if already hit this enemy
             if !cinder_shot.can_damage(enemy_entity) {
                 continue;
@@ -222,7 +229,7 @@ pub fn cinder_shot_collision_effects(
         }
 
         // Direct damage
-        damage_events.write(DamageEvent::new(enemy_entity, damage));
+        damage_events.write(DamageEvent::new(enemy_entity, damage).by_spell(SpellType::FlameLance));
 
         // Apply or refresh weakened effect
         commands.entity(enemy_entity).try_insert(WeakenedDebuff::new(weakened_duration, weakened_multiplier));
@@ -323,9 +330,23 @@ pub fn fire_cinder_shot_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::FlameLance.
+pub fn cast_cinder_shot(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_cinder_shot_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
@@ -1,11 +1,15 @@
 use bevy::prelude::*;
+use rand::Rng;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use std::f32::consts::TAU;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Ember Swarm spell
 pub const EMBER_SWARM_WISP_COUNT_MIN: u8 = 5;
@@ -163,7 +167,7 @@ pub fn launch_ember_wisps_system(
     mut commands: Commands,
     mut controller_query: Query<(Entity, &mut EmberSwarmController, &Transform)>,
     wisp_query: Query<(Entity, &EmberWisp, &Transform), Without<LaunchingWisp>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
 ) {
     for (controller_entity, mut controller, controller_transform) in controller_query.iter_mut() {
         // Skip if already launched or orbit not complete
@@ -177,16 +181,8 @@ pub fn launch_ember_wisps_system(
         // Get controller position for targeting
         let controller_pos = from_xz(controller_transform.translation);
 
-        // Sort enemies by distance to find targets
-        let mut enemies: Vec<(Entity, f32)> = enemy_query
-            .iter()
-            .map(|(entity, transform)| {
-                let pos = from_xz(transform.translation);
-                let distance = controller_pos.distance(pos);
-                (entity, distance)
-            })
-            .collect();
-        enemies.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        // One target per wisp, closest first
+        let enemies = spatial_enemies.nearest_k(controller_pos, controller.wisp_count as usize);
 
         // Assign targets to wisps (cycle through closest enemies if more wisps than enemies)
         let mut target_index = 0;
@@ -205,12 +201,10 @@ pub fn launch_ember_wisps_system(
                 let outward = (wisp_pos - controller_pos).normalize_or_zero();
                 (None, if outward == Vec2::ZERO { Vec2::X } else { outward })
             } else {
-                let (target_entity, _) = enemies[target_index % enemies.len()];
+                let (target_entity, target_pos) = enemies[target_index % enemies.len()];
                 target_index += 1;
 
                 // Get direction to target
-                let target_transform = enemy_query.get(target_entity).unwrap().1;
-                let target_pos = from_xz(target_transform.translation);
                 let direction = (target_pos - wisp_pos).normalize_or_zero();
 
                 (Some(target_entity), direction)
@@ -266,19 +260,22 @@ pub fn ember_wisp_collision_system(
     mut commands: Commands,
     mut controller_query: Query<&mut EmberSwarmController>,
     wisp_query: Query<(Entity, &EmberWisp, &Transform), With<LaunchingWisp>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for (wisp_entity, wisp, wisp_transform) in wisp_query.iter() {
         let wisp_pos = from_xz(wisp_transform.translation);
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(wisp_pos, EMBER_SWARM_HIT_RADIUS) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = wisp_pos.distance(enemy_pos);
 
             if distance <= EMBER_SWARM_HIT_RADIUS {
                 // Deal damage
-                damage_events.write(DamageEvent::new(enemy_entity, wisp.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, wisp.damage)
+                        .by_spell(SpellType::Combustion),
+                );
 
                 // Remove wisp from controller tracking
                 if let Ok(mut controller) = controller_query.get_mut(wisp.controller) {
@@ -334,6 +331,7 @@ pub fn fire_ember_swarm(
     spawn_position: Vec3,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     fire_ember_swarm_with_damage(
         commands,
@@ -342,6 +340,7 @@ pub fn fire_ember_swarm(
         spawn_position,
         game_meshes,
         game_materials,
+        rng,
     );
 }
 
@@ -356,10 +355,10 @@ pub fn fire_ember_swarm_with_damage(
     spawn_position: Vec3,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     // Determine wisp count (random between min and max)
-    let wisp_count = rand::random::<u8>() % (EMBER_SWARM_WISP_COUNT_MAX - EMBER_SWARM_WISP_COUNT_MIN + 1)
-        + EMBER_SWARM_WISP_COUNT_MIN;
+    let wisp_count = rng.gen_range(EMBER_SWARM_WISP_COUNT_MIN..=EMBER_SWARM_WISP_COUNT_MAX);
 
     // Spawn controller at player position
     let controller_entity = commands.spawn((
@@ -405,6 +404,19 @@ pub fn fire_ember_swarm_with_damage(
     // and let the controller track them via query in systems
 }
 
+/// Cast handler registered for SpellType::Combustion.
+pub fn cast_ember_swarm(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_ember_swarm_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+        ctx.rng,
+    );
+}
+
 /// System to initialize controller's wisp list after spawn.
 /// This runs once to populate the wisp list from queries.
 pub fn initialize_ember_swarm_wisps_system(
@@ -552,6 +564,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -577,6 +590,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -604,6 +618,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -631,6 +646,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -656,6 +672,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1,11 +1,13 @@
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Fire Nova (Inferno) spell
 pub const FIRE_NOVA_MAX_RADIUS: f32 = 8.0;
@@ -88,16 +90,19 @@ pub fn fire_nova_expansion_system(
 /// and applies damage to enemies as the ring passes through them
 pub fn fire_nova_collision_system(
     mut nova_query: Query<&mut FireNovaRing>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut nova in nova_query.iter_mut() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(nova.center, nova.current_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = nova.center.distance(enemy_pos);
 
             if nova.should_hit(enemy_entity, distance) {
-                damage_events.write(DamageEvent::new(enemy_entity, nova.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, nova.damage)
+                        .by_spell(SpellType::PhoenixFlare),
+                );
                 nova.mark_hit(enemy_entity);
             }
         }
@@ -168,6 +173,18 @@ pub fn fire_fire_nova_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::PhoenixFlare.
+pub fn cast_fire_nova(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_fire_nova_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// System that updates the visual scale of fire novas based on their current radius
 pub fn fire_nova_visual_system(
     mut nova_query: Query<(&FireNovaRing, &mut Transform)>,
@@ -181,6 +198,7 @@ pub fn fire_nova_visual_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
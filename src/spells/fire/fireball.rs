@@ -3,13 +3,17 @@ use bevy::prelude::*;
 use bevy_hanabi::prelude::*;
 use bevy_kira_audio::prelude::*;
 use crate::audio::plugin::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::events::ProjectileObstacleHit;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::events::FireballEnemyCollisionEvent;
 use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use super::fireball_effects::FireballEffects;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Fireball spell
 pub const FIREBALL_SPREAD_ANGLE: f32 = 15.0;
@@ -54,6 +58,10 @@ pub struct FireballProjectile {
     pub travel_time: f32,
 }
 
+impl ObstacleProjectile for FireballProjectile {
+    const SPELL_TYPE: SpellType = SpellType::Fireball;
+}
+
 impl FireballProjectile {
     /// Create a new fireball projectile with default spawn position at origin.
     pub fn new(direction: Vec3, speed: f32, lifetime_secs: f32, damage: f32) -> Self {
@@ -109,6 +117,8 @@ pub struct BurnEffect {
     pub remaining_ticks: u32,
     /// Damage per tick
     pub tick_damage: f32,
+    /// Spell that applied the burn, credited with its ticks
+    pub spell: Option<SpellType>,
 }
 
 impl BurnEffect {
@@ -118,9 +128,16 @@ impl BurnEffect {
             tick_timer: Timer::from_seconds(BURN_TICK_INTERVAL, TimerMode::Repeating),
             remaining_ticks: total_ticks,
             tick_damage,
+            spell: None,
         }
     }
 
+    /// Attribute the burn's ticks to the spell that applied it
+    pub fn by_spell(mut self, spell: SpellType) -> Self {
+        self.spell = Some(spell);
+        self
+    }
+
     /// Tick the burn effect and return true if damage should be applied
     pub fn tick(&mut self, delta: std::time::Duration) -> bool {
         self.tick_timer.tick(delta);
@@ -497,7 +514,11 @@ pub fn burn_damage_system(
 ) {
     for (entity, mut burn) in burn_query.iter_mut() {
         if burn.tick(time.delta()) {
-            damage_events.write(DamageEvent::new(entity, burn.tick_damage));
+            damage_events.write(
+                DamageEvent::new(entity, burn.tick_damage)
+                    .over_time()
+                    .by_spell(burn.spell),
+            );
         }
 
         if burn.is_expired() {
@@ -520,8 +541,8 @@ pub fn fire_fireball(
     spawn_position: Vec3,
     target_pos: Vec2,
     asset_server: Option<&Res<AssetServer>>,
-    weapon_channel: Option<&mut ResMut<AudioChannel<WeaponSoundChannel>>>,
-    sound_limiter: Option<&mut ResMut<SoundLimiter>>,
+    weapon_channel: Option<&mut AudioChannel<WeaponSoundChannel>>,
+    sound_limiter: Option<&mut SoundLimiter>,
     game_meshes: Option<&GameMeshes>,
     _game_materials: Option<&GameMaterials>,
     fireball_effects: Option<&FireballEffects>,
@@ -562,8 +583,8 @@ pub fn fire_fireball_with_damage(
     spawn_position: Vec3,
     target_pos: Vec2,
     asset_server: Option<&Res<AssetServer>>,
-    weapon_channel: Option<&mut ResMut<AudioChannel<WeaponSoundChannel>>>,
-    sound_limiter: Option<&mut ResMut<SoundLimiter>>,
+    weapon_channel: Option<&mut AudioChannel<WeaponSoundChannel>>,
+    sound_limiter: Option<&mut SoundLimiter>,
     game_meshes: Option<&GameMeshes>,
     fireball_effects: Option<&FireballEffects>,
     core_materials: Option<&mut Assets<super::materials::FireballCoreMaterial>>,
@@ -709,6 +730,25 @@ pub fn fire_fireball_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Fireball.
+pub fn cast_fireball(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_fireball_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.asset_server,
+        ctx.weapon_channel.as_deref_mut(),
+        ctx.sound_limiter.as_deref_mut(),
+        ctx.game_meshes,
+        ctx.fireball_effects,
+        None, // Core materials added by separate system
+        ctx.fireball_charge_materials.as_deref_mut(),
+        None, // Trail materials handled by charge_to_flight system
+    );
+}
+
 /// System that moves fireball projectiles in 3D space
 pub fn fireball_movement_system(
     mut fireball_query: Query<(&mut Transform, &mut FireballProjectile)>,
@@ -744,7 +784,7 @@ pub const FIREBALL_COLLISION_RADIUS: f32 = 1.0;
 /// System that detects fireball-enemy collisions and fires events
 pub fn fireball_collision_detection(
     fireball_query: Query<(Entity, &Transform), With<FireballProjectile>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<FireballEnemyCollisionEvent>,
 ) {
     for (fireball_entity, fireball_transform) in fireball_query.iter() {
@@ -753,7 +793,7 @@ pub fn fireball_collision_detection(
             fireball_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(fireball_xz, FIREBALL_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -799,10 +839,12 @@ pub fn fireball_collision_effects(
     // Apply damage and burn effects
     for (enemy_entity, damage, burn_tick_damage) in effects_to_apply {
         // Direct damage
-        damage_events.write(DamageEvent::new(enemy_entity, damage));
+        damage_events.write(DamageEvent::new(enemy_entity, damage).by_spell(SpellType::Fireball));
 
         // Apply burn effect (if enemy doesn't already have one, it gets added)
-        commands.entity(enemy_entity).try_insert(BurnEffect::new(burn_tick_damage));
+        commands
+            .entity(enemy_entity)
+            .try_insert(BurnEffect::new(burn_tick_damage).by_spell(SpellType::Fireball));
     }
 }
 
@@ -1051,6 +1093,51 @@ fn spawn_dark_projectiles(
     }
 }
 
+/// Spawns the full fireball explosion at `pos`: core flash, fire blast,
+/// dark projectiles and billowing fire. Layers whose assets are missing are skipped.
+fn spawn_fireball_explosion(
+    commands: &mut Commands,
+    game_meshes: Option<&GameMeshes>,
+    explosion_core_materials: Option<&mut Assets<super::materials::ExplosionCoreMaterial>>,
+    explosion_fire_materials: Option<&mut Assets<super::materials::ExplosionFireMaterial>>,
+    explosion_embers_materials: Option<&mut Assets<super::materials::ExplosionEmbersMaterial>>,
+    pos: Vec3,
+) {
+    // Spawn shader-based explosion core flash effect (white-hot burst)
+    if let (Some(meshes), Some(core_mats)) = (game_meshes, explosion_core_materials) {
+        let core_material = super::materials::ExplosionCoreMaterial::new();
+        let core_handle = core_mats.add(core_material);
+
+        commands.spawn((
+            Mesh3d(meshes.fireball.clone()),
+            MeshMaterial3d(core_handle.clone()),
+            Transform::from_translation(pos).with_scale(Vec3::splat(2.5)),
+            ExplosionCoreEffect::new(core_handle),
+        ));
+    }
+
+    // Spawn shader-based explosion fire blast effect (main orange-red blast)
+    if let (Some(meshes), Some(fire_mats)) = (game_meshes, explosion_fire_materials) {
+        let fire_material = super::materials::ExplosionFireMaterial::new();
+        let fire_handle = fire_mats.add(fire_material);
+
+        commands.spawn((
+            Mesh3d(meshes.fireball.clone()),
+            MeshMaterial3d(fire_handle.clone()),
+            Transform::from_translation(pos).with_scale(Vec3::splat(3.5)),
+            ExplosionFireEffect::new(fire_handle),
+        ));
+    }
+
+    // Spawn 14 dark projectiles flying outward with velocity elongation
+    if let (Some(meshes), Some(embers_mats)) = (game_meshes, explosion_embers_materials) {
+        spawn_dark_projectiles(commands, meshes, embers_mats, pos);
+    }
+
+    // Spawn billowing fire spheres spawner (creates 8 fire spheres that expand outward)
+    commands.spawn(BillowingFireSpawner::new(pos));
+}
+
 /// System that spawns explosion shader effects at collision point
 /// Spawns all four explosion layers for maximum impact using shader materials
 #[allow(clippy::too_many_arguments)]
@@ -1063,47 +1150,20 @@ pub fn fireball_explosion_spawn_system(
     mut explosion_fire_materials: Option<ResMut<Assets<super::materials::ExplosionFireMaterial>>>,
     mut explosion_embers_materials: Option<ResMut<Assets<super::materials::ExplosionEmbersMaterial>>>,
 ) {
-    let _event_count = collision_events.len();
     for event in collision_events.read() {
         let Ok(transform) = fireball_query.get(event.fireball_entity) else {
             warn!("Could not find fireball transform for entity {:?}", event.fireball_entity);
             continue;
         };
-        let pos = transform.translation;
-
-        // Spawn shader-based explosion core flash effect (white-hot burst)
-        if let (Some(meshes), Some(ref mut core_mats)) = (&game_meshes, &mut explosion_core_materials) {
-            let core_material = super::materials::ExplosionCoreMaterial::new();
-            let core_handle = core_mats.add(core_material);
-
-            commands.spawn((
-                Mesh3d(meshes.fireball.clone()),
-                MeshMaterial3d(core_handle.clone()),
-                Transform::from_translation(pos).with_scale(Vec3::splat(2.5)),
-                ExplosionCoreEffect::new(core_handle),
-            ));
-        }
-
-        // Spawn shader-based explosion fire blast effect (main orange-red blast)
-        if let (Some(meshes), Some(ref mut fire_mats)) = (&game_meshes, &mut explosion_fire_materials) {
-            let fire_material = super::materials::ExplosionFireMaterial::new();
-            let fire_handle = fire_mats.add(fire_material);
 
-            commands.spawn((
-                Mesh3d(meshes.fireball.clone()),
-                MeshMaterial3d(fire_handle.clone()),
-                Transform::from_translation(pos).with_scale(Vec3::splat(3.5)),
-                ExplosionFireEffect::new(fire_handle),
-            ));
-        }
-
-        // Spawn 14 dark projectiles flying outward with velocity elongation
-        if let (Some(meshes), Some(ref mut embers_mats)) = (&game_meshes, &mut explosion_embers_materials) {
-            spawn_dark_projectiles(&mut commands, meshes, embers_mats, pos);
-        }
-
-        // Spawn billowing fire spheres spawner (creates 8 fire spheres that expand outward)
-        commands.spawn(BillowingFireSpawner::new(pos));
+        spawn_fireball_explosion(
+            &mut commands,
+            game_meshes.as_deref(),
+            explosion_core_materials.as_deref_mut(),
+            explosion_fire_materials.as_deref_mut(),
+            explosion_embers_materials.as_deref_mut(),
+            transform.translation,
+        );
     }
 }
 
@@ -1127,39 +1187,14 @@ pub fn fireball_ground_collision_system(
                 transform.translation.z,
             );
 
-            // Spawn shader-based explosion core flash effect (white-hot burst)
-            if let (Some(meshes), Some(ref mut core_mats)) = (&game_meshes, &mut explosion_core_materials) {
-                let core_material = super::materials::ExplosionCoreMaterial::new();
-                let core_handle = core_mats.add(core_material);
-
-                commands.spawn((
-                    Mesh3d(meshes.fireball.clone()),
-                    MeshMaterial3d(core_handle.clone()),
-                    Transform::from_translation(pos).with_scale(Vec3::splat(2.5)),
-                    ExplosionCoreEffect::new(core_handle),
-                ));
-            }
-
-            // Spawn shader-based explosion fire blast effect (main orange-red blast)
-            if let (Some(meshes), Some(ref mut fire_mats)) = (&game_meshes, &mut explosion_fire_materials) {
-                let fire_material = super::materials::ExplosionFireMaterial::new();
-                let fire_handle = fire_mats.add(fire_material);
-
-                commands.spawn((
-                    Mesh3d(meshes.fireball.clone()),
-                    MeshMaterial3d(fire_handle.clone()),
-                    Transform::from_translation(pos).with_scale(Vec3::splat(3.5)),
-                    ExplosionFireEffect::new(fire_handle),
-                ));
-            }
-
-            // Spawn 14 dark projectiles flying outward with velocity elongation
-            if let (Some(meshes), Some(ref mut embers_mats)) = (&game_meshes, &mut explosion_embers_materials) {
-                spawn_dark_projectiles(&mut commands, meshes, embers_mats, pos);
-            }
-
-            // Spawn billowing fire spheres spawner (creates 8 fire spheres that expand outward)
-            commands.spawn(BillowingFireSpawner::new(pos));
+            spawn_fireball_explosion(
+                &mut commands,
+                game_meshes.as_deref(),
+                explosion_core_materials.as_deref_mut(),
+                explosion_fire_materials.as_deref_mut(),
+                explosion_embers_materials.as_deref_mut(),
+                pos,
+            );
 
             // Despawn the fireball
             commands.entity(entity).despawn();
@@ -1167,6 +1202,33 @@ pub fn fireball_ground_collision_system(
     }
 }
 
+/// System that explodes fireballs reaching a rock or arena wall.
+/// Like ground hits, obstacle hits deal no damage.
+pub fn fireball_obstacle_explosion_system(
+    mut commands: Commands,
+    mut obstacle_hits: MessageReader<ProjectileObstacleHit>,
+    game_meshes: Option<Res<GameMeshes>>,
+    mut explosion_core_materials: Option<ResMut<Assets<super::materials::ExplosionCoreMaterial>>>,
+    mut explosion_fire_materials: Option<ResMut<Assets<super::materials::ExplosionFireMaterial>>>,
+    mut explosion_embers_materials: Option<ResMut<Assets<super::materials::ExplosionEmbersMaterial>>>,
+) {
+    for hit in obstacle_hits.read() {
+        if hit.spell_type != SpellType::Fireball {
+            continue;
+        }
+
+        spawn_fireball_explosion(
+            &mut commands,
+            game_meshes.as_deref(),
+            explosion_core_materials.as_deref_mut(),
+            explosion_fire_materials.as_deref_mut(),
+            explosion_embers_materials.as_deref_mut(),
+            hit.position,
+        );
+        commands.entity(hit.projectile).try_despawn();
+    }
+}
+
 /// System that cleans up explosion particles after their timer expires
 pub fn fireball_explosion_cleanup_system(
     mut commands: Commands,
@@ -1433,6 +1495,7 @@ pub fn billowing_fire_sphere_effect_update_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
 
     mod fireball_projectile_tests {
@@ -1,12 +1,15 @@
 use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use rand::Rng;
 use std::f32::consts::PI;
+use crate::spell::SpellCastContext;
 
 /// Configuration for Flame Arc spell
 pub const FLAME_ARC_SPEED: f32 = 15.0;
@@ -34,9 +37,12 @@ pub struct FlameArcProjectile {
     pub fragment_damage: f32,
 }
 
+impl ObstacleProjectile for FlameArcProjectile {
+    const SPELL_TYPE: SpellType = SpellType::MeteorShower;
+}
+
 impl FlameArcProjectile {
-    pub fn new(damage: f32) -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn new(damage: f32, rng: &mut impl Rng) -> Self {
         let fragment_count = rng.gen_range(FRAGMENT_COUNT_MIN..=FRAGMENT_COUNT_MAX);
         Self {
             damage,
@@ -149,6 +155,7 @@ pub fn fire_flame_arc(
     target_pos: Vec2,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     fire_flame_arc_with_damage(
         commands,
@@ -157,6 +164,7 @@ pub fn fire_flame_arc(
         target_pos,
         game_meshes,
         game_materials,
+        rng,
     );
 }
 
@@ -169,10 +177,11 @@ pub fn fire_flame_arc_with_damage(
     target_pos: Vec2,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     let spawn_xz = from_xz(spawn_position);
     let trajectory = FlameArcTrajectory::new(spawn_xz, target_pos, spawn_position.y);
-    let projectile = FlameArcProjectile::new(damage);
+    let projectile = FlameArcProjectile::new(damage, rng);
 
     if let (Some(meshes), Some(materials)) = (game_meshes, game_materials) {
         commands.spawn((
@@ -191,6 +200,20 @@ pub fn fire_flame_arc_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::MeteorShower.
+pub fn cast_flame_arc(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Flame Arc lobs a burning projectile that scatters fragments on impact
+    fire_flame_arc_with_damage(
+        commands,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+        ctx.rng,
+    );
+}
+
 /// System that moves Flame Arc projectiles along their parabolic trajectory
 pub fn flame_arc_movement_system(
     mut query: Query<(&mut Transform, &mut FlameArcTrajectory), With<FlameArcProjectile>>,
@@ -215,7 +238,7 @@ pub struct FlameArcImpactEvent {
 /// System that detects when Flame Arc reaches destination or hits an enemy
 pub fn flame_arc_impact_detection(
     query: Query<(Entity, &Transform, &FlameArcProjectile, &FlameArcTrajectory)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut impact_events: MessageWriter<FlameArcImpactEvent>,
 ) {
     for (entity, transform, projectile, trajectory) in query.iter() {
@@ -223,7 +246,7 @@ pub fn flame_arc_impact_detection(
 
         // Check for enemy collision
         let mut hit_enemy = None;
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(arc_xz, FLAME_ARC_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -255,12 +278,16 @@ pub fn flame_arc_impact_effects(
     mut damage_events: MessageWriter<DamageEvent>,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for event in impact_events.read() {
         // Apply damage if hit enemy
         if let Some(enemy_entity) = event.hit_enemy {
             if let Ok(projectile) = query.get(event.flame_arc_entity) {
-                damage_events.write(DamageEvent::new(enemy_entity, projectile.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, projectile.damage)
+                        .by_spell(SpellType::MeteorShower),
+                );
             }
         }
 
@@ -272,6 +299,7 @@ pub fn flame_arc_impact_effects(
             event.fragment_damage,
             game_meshes.as_deref(),
             game_materials.as_deref(),
+            &mut *rng,
         );
 
         // Despawn the flame arc projectile
@@ -287,9 +315,8 @@ pub fn spawn_flame_fragments(
     fragment_damage: f32,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
-    let mut rng = rand::thread_rng();
-
     for _ in 0..fragment_count {
         // Random direction on XZ plane
         let angle = rng.gen_range(0.0..2.0 * PI);
@@ -348,7 +375,7 @@ pub struct FlameFragmentCollisionEvent {
 /// System that detects flame fragment collisions with enemies
 pub fn flame_fragment_collision_detection(
     query: Query<(Entity, &Transform), With<FlameFragment>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<FlameFragmentCollisionEvent>,
 ) {
     for (fragment_entity, fragment_transform) in query.iter() {
@@ -357,7 +384,7 @@ pub fn flame_fragment_collision_detection(
             fragment_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(fragment_xz, FRAGMENT_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -383,7 +410,10 @@ pub fn flame_fragment_collision_effects(
 ) {
     for event in collision_events.read() {
         if let Ok(fragment) = query.get(event.fragment_entity) {
-            damage_events.write(DamageEvent::new(event.enemy_entity, fragment.damage));
+            damage_events.write(
+                DamageEvent::new(event.enemy_entity, fragment.damage)
+                    .by_spell(SpellType::MeteorShower),
+            );
         }
         commands.entity(event.fragment_entity).try_despawn();
     }
@@ -392,6 +422,7 @@ pub fn flame_fragment_collision_effects(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::app::App;
     use bevy::ecs::system::RunSystemOnce;
@@ -401,7 +432,7 @@ mod tests {
 
         #[test]
         fn test_flame_arc_projectile_new() {
-            let projectile = FlameArcProjectile::new(100.0);
+            let projectile = FlameArcProjectile::new(100.0, &mut rand::thread_rng());
             assert_eq!(projectile.damage, 100.0);
             assert!(projectile.fragment_count >= FRAGMENT_COUNT_MIN);
             assert!(projectile.fragment_count <= FRAGMENT_COUNT_MAX);
@@ -418,7 +449,7 @@ mod tests {
 
         #[test]
         fn test_flame_arc_fragment_damage_ratio() {
-            let projectile = FlameArcProjectile::new(200.0);
+            let projectile = FlameArcProjectile::new(200.0, &mut rand::thread_rng());
             // Fragment damage should be 35% of main damage
             assert_eq!(projectile.fragment_damage, 200.0 * 0.35);
         }
@@ -601,6 +632,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -625,6 +657,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -650,6 +683,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -737,6 +771,7 @@ mod tests {
             app.add_plugins(bevy::time::TimePlugin::default());
             app.add_message::<FlameArcImpactEvent>();
             app.add_message::<DamageEvent>();
+            app.init_resource::<GameRng>();
             app
         }
 
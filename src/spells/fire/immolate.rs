@@ -5,12 +5,15 @@
 //! fire-colored visual effects.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Immolate spell
 pub const IMMOLATE_DURATION: f32 = 4.0;
@@ -121,11 +124,11 @@ pub fn immolate_damage_system(
         effect.tick(time.delta());
 
         if effect.should_damage() {
-            damage_events.write(DamageEvent::with_element(
-                entity,
-                effect.damage_per_tick,
-                Element::Fire,
-            ));
+            damage_events.write(
+                DamageEvent::with_element(entity, effect.damage_per_tick, Element::Fire)
+                    .over_time()
+                    .by_spell(SpellType::Immolate),
+            );
         }
 
         if effect.is_expired() {
@@ -193,11 +196,11 @@ pub fn fire_immolate(
     commands: &mut Commands,
     spell: &Spell,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform, &Enemy)>,
+    enemies: &SpatialEnemies,
     _game_meshes: Option<&GameMeshes>,
     _game_materials: Option<&GameMaterials>,
 ) {
-    fire_immolate_with_damage(commands, spell, spell.damage(), spawn_position, enemy_query);
+    fire_immolate_with_damage(commands, spell, spell.damage(), spawn_position, enemies);
 }
 
 /// Cast Immolate spell with explicit damage
@@ -207,27 +210,12 @@ pub fn fire_immolate_with_damage(
     _spell: &Spell,
     damage: f32,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform, &Enemy)>,
+    enemies: &SpatialEnemies,
 ) {
     let spawn_xz = from_xz(spawn_position);
 
     // Find nearest enemy within range
-    let mut nearest_enemy: Option<(Entity, f32)> = None;
-
-    for (enemy_entity, enemy_transform, _enemy) in enemy_query.iter() {
-        let enemy_pos = from_xz(enemy_transform.translation);
-        let distance = spawn_xz.distance(enemy_pos);
-
-        if distance <= IMMOLATE_RANGE {
-            match nearest_enemy {
-                None => nearest_enemy = Some((enemy_entity, distance)),
-                Some((_, nearest_dist)) if distance < nearest_dist => {
-                    nearest_enemy = Some((enemy_entity, distance));
-                }
-                _ => {}
-            }
-        }
-    }
+    let nearest_enemy = enemies.nearest(spawn_xz, IMMOLATE_RANGE);
 
     // Apply immolate effect to nearest enemy
     if let Some((enemy_entity, _)) = nearest_enemy {
@@ -236,6 +224,17 @@ pub fn fire_immolate_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Immolate.
+pub fn cast_immolate(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_immolate_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.enemies,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
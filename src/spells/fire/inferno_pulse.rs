@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Inferno Pulse spell
 pub const INFERNO_PULSE_RADIUS: f32 = 6.0;
@@ -88,7 +90,7 @@ pub fn fire_inferno_pulse(
     commands: &mut Commands,
     spell: &Spell,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform, &Enemy)>,
+    enemies: &SpatialEnemies,
     damage_events: &mut MessageWriter<DamageEvent>,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
@@ -98,7 +100,7 @@ pub fn fire_inferno_pulse(
         spell,
         spell.damage(),
         spawn_position,
-        enemy_query,
+        enemies,
         damage_events,
         game_meshes,
         game_materials,
@@ -114,7 +116,7 @@ pub fn fire_inferno_pulse_with_damage(
     _spell: &Spell,
     damage: f32,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform, &Enemy)>,
+    enemies: &SpatialEnemies,
     damage_events: &mut MessageWriter<DamageEvent>,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
@@ -122,12 +124,15 @@ pub fn fire_inferno_pulse_with_damage(
     let pulse_center = from_xz(spawn_position);
 
     // Damage all enemies within pulse radius instantly
-    for (enemy_entity, enemy_transform, _) in enemy_query.iter() {
+    for (enemy_entity, enemy_transform) in enemies.near(pulse_center, INFERNO_PULSE_RADIUS) {
         let enemy_pos = from_xz(enemy_transform.translation);
         let distance = pulse_center.distance(enemy_pos);
 
         if distance <= INFERNO_PULSE_RADIUS {
-            damage_events.write(DamageEvent::new(enemy_entity, damage));
+            damage_events.write(
+                DamageEvent::new(enemy_entity, damage)
+                    .by_spell(SpellType::Hellfire),
+            );
         }
     }
 
@@ -150,9 +155,26 @@ pub fn fire_inferno_pulse_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Hellfire.
+pub fn cast_inferno_pulse(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    if let Some(events) = ctx.damage_events.as_deref_mut() {
+        fire_inferno_pulse_with_damage(
+            commands,
+            ctx.spell,
+            ctx.damage,
+            ctx.origin,
+            ctx.enemies,
+            events,
+            ctx.game_meshes,
+            ctx.game_materials,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
@@ -331,7 +353,7 @@ mod tests {
         fn test_fire_inferno_pulse_system(
             mut commands: Commands,
             spawn_pos: Res<TestSpawnPos>,
-            enemy_query: Query<(Entity, &Transform, &Enemy)>,
+            enemies: SpatialEnemies,
             mut damage_events: MessageWriter<DamageEvent>,
         ) {
             let spell = Spell::new(SpellType::Hellfire);
@@ -339,7 +361,7 @@ mod tests {
                 &mut commands,
                 &spell,
                 spawn_pos.0,
-                &enemy_query,
+                &enemies,
                 &mut damage_events,
                 None,
                 None,
@@ -351,7 +373,7 @@ mod tests {
             mut commands: Commands,
             spawn_pos: Res<TestSpawnPos>,
             test_damage: Res<TestDamage>,
-            enemy_query: Query<(Entity, &Transform, &Enemy)>,
+            enemies: SpatialEnemies,
             mut damage_events: MessageWriter<DamageEvent>,
         ) {
             let spell = Spell::new(SpellType::Hellfire);
@@ -360,7 +382,7 @@ mod tests {
                 &spell,
                 test_damage.0,
                 spawn_pos.0,
-                &enemy_query,
+                &enemies,
                 &mut damage_events,
                 None,
                 None,
@@ -1,3 +1,7 @@
+use bevy::prelude::App;
+
+use crate::spell::{SpellBehaviorAppExt, SpellType};
+
 pub mod ashfall;
 pub mod cinder_shot;
 pub mod ember_swarm;
@@ -21,3 +25,16 @@ pub use immolate::*;
 pub use inferno_pulse::*;
 pub use materials::*;
 pub use scorch_trail::*;
+
+/// Registers cast handlers for Fire spells.
+pub fn plugin(app: &mut App) {
+    app
+        .register_spell_behavior(SpellType::Fireball, fireball::cast_fireball)
+        .register_spell_behavior(SpellType::FlameLance, cinder_shot::cast_cinder_shot)
+        .register_spell_behavior(SpellType::Ashfall, ashfall::cast_ashfall)
+        .register_spell_behavior(SpellType::MeteorShower, flame_arc::cast_flame_arc)
+        .register_spell_behavior(SpellType::PhoenixFlare, fire_nova::cast_fire_nova)
+        .register_spell_behavior(SpellType::Combustion, ember_swarm::cast_ember_swarm)
+        .register_spell_behavior(SpellType::Immolate, immolate::cast_immolate)
+        .register_spell_behavior(SpellType::Hellfire, inferno_pulse::cast_inferno_pulse);
+}
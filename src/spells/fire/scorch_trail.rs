@@ -6,12 +6,13 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 
 /// Default configuration for Scorch Trail spell
@@ -257,12 +258,13 @@ pub fn tick_scorch_trail_system(
 /// Ensures an enemy only takes damage once per tick regardless of overlapping patches.
 pub fn scorch_patch_damage_system(
     mut patch_query: Query<&mut ScorchPatch>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     time: Res<Time>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     // Track which enemies have been damaged this tick cycle (across all patches)
     let mut enemies_damaged_this_tick: HashSet<Entity> = HashSet::new();
+    let mut damaged_positions: Vec<(Entity, Vec2)> = Vec::new();
 
     // First, tick all patches and collect info about which patches are ready to damage
     // We need to collect this info before we start iterating because we'll need to
@@ -284,23 +286,25 @@ pub fn scorch_patch_damage_system(
         return;
     }
 
-    // For each enemy, check if they're in range of any damage-ready patch
-    for (enemy_entity, enemy_transform) in enemy_query.iter() {
-        // Skip if already damaged this tick
-        if enemies_damaged_this_tick.contains(&enemy_entity) {
-            continue;
-        }
-
-        let enemy_pos = from_xz(enemy_transform.translation);
+    // Damage enemies in range of each damage-ready patch; the first patch reaching
+    // an enemy deals its damage
+    for (center, radius, damage_per_tick) in &patches_ready_to_damage {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(*center, *radius) {
+            // Skip if already damaged this tick
+            if enemies_damaged_this_tick.contains(&enemy_entity) {
+                continue;
+            }
 
-        // Check each patch that's ready to damage
-        for (center, radius, damage_per_tick) in &patches_ready_to_damage {
-            let distance = center.distance(enemy_pos);
-            if distance <= *radius {
+            let enemy_pos = from_xz(enemy_transform.translation);
+            if center.distance(enemy_pos) <= *radius {
                 // Enemy is in this patch - apply damage and mark as damaged
-                damage_events.write(DamageEvent::new(enemy_entity, *damage_per_tick));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, *damage_per_tick)
+                        .over_time()
+                        .by_spell(SpellType::Immolate),
+                );
                 enemies_damaged_this_tick.insert(enemy_entity);
-                break; // Only damage once per tick
+                damaged_positions.push((enemy_entity, enemy_pos));
             }
         }
     }
@@ -309,10 +313,7 @@ pub fn scorch_patch_damage_system(
     // We iterate again since we can't hold mutable references while doing other work
     for (patch_idx, mut patch) in patch_query.iter_mut().enumerate() {
         if patch_indices_ready.contains(&patch_idx) {
-            for &enemy_entity in &enemies_damaged_this_tick {
-                let enemy_pos = enemy_query.get(enemy_entity)
-                    .map(|(_, t)| from_xz(t.translation))
-                    .unwrap_or(Vec2::ZERO);
+            for &(enemy_entity, enemy_pos) in &damaged_positions {
                 if patch.center.distance(enemy_pos) <= patch.radius {
                     patch.mark_hit(enemy_entity);
                 }
@@ -336,6 +337,7 @@ pub fn scorch_patch_cleanup_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
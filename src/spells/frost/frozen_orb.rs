@@ -8,12 +8,15 @@
 
 use std::collections::HashMap;
 use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Frozen Orb spell
 pub const FROZEN_ORB_SPEED: f32 = 8.0;
@@ -59,6 +62,10 @@ pub struct FrozenOrb {
     pub shard_spawn_timer: Timer,
 }
 
+impl ObstacleProjectile for FrozenOrb {
+    const SPELL_TYPE: SpellType = SpellType::Blizzard;
+}
+
 /// Individual falling ice shard particle (purely visual).
 /// Falls from above at an angle toward the ground.
 #[derive(Component, Debug, Clone)]
@@ -178,7 +185,7 @@ pub fn frozen_orb_tick_system(
 /// System that applies damage to enemies within frozen orb aura.
 pub fn frozen_orb_damage_system(
     mut orb_query: Query<(&Transform, &mut FrozenOrb)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for (orb_transform, mut orb) in orb_query.iter_mut() {
@@ -188,12 +195,16 @@ pub fn frozen_orb_damage_system(
 
         let orb_pos = from_xz(orb_transform.translation);
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(orb_pos, orb.damage_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = orb_pos.distance(enemy_pos);
 
             if distance <= orb.damage_radius && orb.can_damage(enemy_entity) {
-                damage_events.write(DamageEvent::new(enemy_entity, orb.damage_per_tick));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, orb.damage_per_tick)
+                        .over_time()
+                        .by_spell(SpellType::Blizzard),
+                );
                 orb.mark_hit(enemy_entity);
             }
         }
@@ -335,9 +346,22 @@ pub fn fire_frozen_orb_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Blizzard.
+pub fn cast_frozen_orb(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_frozen_orb_with_damage(
+        commands,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -6,14 +6,16 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::fire::cinder_shot::WeakenedDebuff;
 use crate::spells::frost::ice_shard::SlowedDebuff;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Glacial Pulse spell
 pub const GLACIAL_PULSE_MAX_RADIUS: f32 = 10.0;
@@ -115,17 +117,20 @@ pub fn glacial_pulse_expansion_system(
 pub fn glacial_pulse_collision_system(
     mut commands: Commands,
     mut pulse_query: Query<&mut GlacialPulseWave>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut pulse in pulse_query.iter_mut() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(pulse.center, pulse.current_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = pulse.center.distance(enemy_pos);
 
             if pulse.should_hit(enemy_entity, distance) {
                 // Apply damage
-                damage_events.write(DamageEvent::new(enemy_entity, pulse.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, pulse.damage)
+                        .by_spell(SpellType::FrostNova),
+                );
 
                 // Apply SlowedDebuff
                 commands.entity(enemy_entity).try_insert(
@@ -207,6 +212,18 @@ pub fn fire_glacial_pulse_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::FrostNova.
+pub fn cast_glacial_pulse(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_glacial_pulse_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// System that updates the visual scale of glacial pulses based on their current radius
 pub fn glacial_pulse_visual_system(
     mut pulse_query: Query<(&GlacialPulseWave, &mut Transform)>,
@@ -220,6 +237,7 @@ pub fn glacial_pulse_visual_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
@@ -5,13 +5,15 @@
 //! in its collision radius.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::frost::ice_shard::SlowedDebuff;
+use crate::spell::SpellCastContext;
 
 /// Collision radius for the spike (enemies within this distance take damage)
 pub const GLACIAL_SPIKE_COLLISION_RADIUS: f32 = 1.5;
@@ -154,7 +156,7 @@ pub fn glacial_spike_eruption_system(
 pub fn glacial_spike_collision_system(
     mut commands: Commands,
     mut spike_query: Query<&mut GlacialSpike>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut spike in spike_query.iter_mut() {
@@ -169,12 +171,15 @@ pub fn glacial_spike_collision_system(
         }
 
         // Apply damage to all enemies within collision radius
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(spike.center, spike.collision_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = spike.center.distance(enemy_pos);
 
             if distance <= spike.collision_radius {
-                damage_events.write(DamageEvent::new(enemy_entity, spike.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, spike.damage)
+                        .by_spell(SpellType::GlacialSpike),
+                );
 
                 // Apply slow effect
                 commands.entity(enemy_entity).try_insert(SlowedDebuff::new(
@@ -256,9 +261,22 @@ pub fn fire_glacial_spike_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::GlacialSpike.
+pub fn cast_glacial_spike(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_glacial_spike_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
@@ -6,12 +6,14 @@
 //! immediately upon leaving the aura radius.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::player::components::Player;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Hoarfrost spell
 pub const HOARFROST_RADIUS: f32 = 5.0;
@@ -112,34 +114,32 @@ pub fn hoarfrost_duration_system(
 pub fn hoarfrost_tracking_system(
     mut commands: Commands,
     player_query: Query<(&Transform, &HoarfrostAura), With<Player>>,
-    enemy_query: Query<(Entity, &Transform, Option<&InHoarfrost>), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    marked_query: Query<(Entity, &Transform), (With<Enemy>, With<InHoarfrost>)>,
 ) {
     let Ok((player_transform, aura)) = player_query.single() else {
         // No active aura - remove all InHoarfrost markers
-        for (enemy_entity, _, in_hoarfrost) in enemy_query.iter() {
-            if in_hoarfrost.is_some() {
-                commands.entity(enemy_entity).remove::<InHoarfrost>();
-            }
+        for (enemy_entity, _) in marked_query.iter() {
+            commands.entity(enemy_entity).remove::<InHoarfrost>();
         }
         return;
     };
 
     let player_pos = from_xz(player_transform.translation);
 
-    for (enemy_entity, enemy_transform, in_hoarfrost) in enemy_query.iter() {
+    // Enemies that moved out of range lose the InHoarfrost marker
+    for (enemy_entity, enemy_transform) in marked_query.iter() {
         let enemy_pos = from_xz(enemy_transform.translation);
-        let distance = player_pos.distance(enemy_pos);
+        if player_pos.distance(enemy_pos) > aura.radius {
+            commands.entity(enemy_entity).remove::<InHoarfrost>();
+        }
+    }
 
-        if distance <= aura.radius {
-            // Enemy is in range - add or update InHoarfrost marker
-            if in_hoarfrost.is_none() {
-                commands.entity(enemy_entity).insert(InHoarfrost::new(aura.slow_multiplier));
-            }
-        } else {
-            // Enemy is out of range - remove InHoarfrost marker if present
-            if in_hoarfrost.is_some() {
-                commands.entity(enemy_entity).remove::<InHoarfrost>();
-            }
+    // Enemies that moved into range gain it
+    for (enemy_entity, enemy_transform) in spatial_enemies.near(player_pos, aura.radius) {
+        let enemy_pos = from_xz(enemy_transform.translation);
+        if player_pos.distance(enemy_pos) <= aura.radius && !marked_query.contains(enemy_entity) {
+            commands.entity(enemy_entity).insert(InHoarfrost::new(aura.slow_multiplier));
         }
     }
 }
@@ -188,6 +188,21 @@ pub fn activate_hoarfrost(
     spawn_hoarfrost_visual(commands, player_entity, player_position, aura.radius, game_meshes, game_materials);
 }
 
+/// Cast handler registered for SpellType::IceBarrier.
+pub fn cast_hoarfrost(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Hoarfrost creates a cold mist aura that slows enemies in range
+    if let Some((player_entity, player_transform, _)) = ctx.player {
+        activate_hoarfrost(
+            commands,
+            player_entity,
+            player_transform.translation,
+            ctx.spell,
+            ctx.game_meshes,
+            ctx.game_materials,
+        );
+    }
+}
+
 /// Activates the Hoarfrost aura with explicit parameters.
 #[allow(clippy::too_many_arguments)]
 pub fn activate_hoarfrost_with_params(
@@ -6,13 +6,16 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::frost::ice_shard::SlowedDebuff;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Ice Lance spell
 pub const ICE_LANCE_SPEED: f32 = 50.0; // Much faster than Ice Shard (25.0)
@@ -46,6 +49,10 @@ pub struct IceLanceProjectile {
     pub slow_multiplier: f32,
 }
 
+impl ObstacleProjectile for IceLanceProjectile {
+    const SPELL_TYPE: SpellType = SpellType::FrozenRay;
+}
+
 impl IceLanceProjectile {
     pub fn new(direction: Vec2, speed: f32, lifetime_secs: f32, damage: f32) -> Self {
         Self {
@@ -106,7 +113,7 @@ pub fn ice_lance_lifetime_system(
 pub fn ice_lance_collision_system(
     mut commands: Commands,
     mut ice_lance_query: Query<(&Transform, &mut IceLanceProjectile)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for (lance_transform, mut ice_lance) in ice_lance_query.iter_mut() {
@@ -115,7 +122,7 @@ pub fn ice_lance_collision_system(
             lance_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(lance_xz, ICE_LANCE_COLLISION_RADIUS) {
             // Skip if we've already hit this enemy
             if ice_lance.has_hit(enemy_entity) {
                 continue;
@@ -129,7 +136,10 @@ pub fn ice_lance_collision_system(
 
             if distance < ICE_LANCE_COLLISION_RADIUS {
                 // Apply damage
-                damage_events.write(DamageEvent::new(enemy_entity, ice_lance.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, ice_lance.damage)
+                        .by_spell(SpellType::FrozenRay),
+                );
 
                 // Apply slow effect
                 commands.entity(enemy_entity).try_insert(
@@ -207,9 +217,23 @@ pub fn fire_ice_lance_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::FrozenRay.
+pub fn cast_ice_lance(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_ice_lance_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
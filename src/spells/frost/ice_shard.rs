@@ -1,11 +1,14 @@
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Ice Shard spell
 pub const ICE_SHARD_SPEED: f32 = 25.0;
@@ -38,6 +41,10 @@ pub struct IceShardProjectile {
     pub slow_multiplier: f32,
 }
 
+impl ObstacleProjectile for IceShardProjectile {
+    const SPELL_TYPE: SpellType = SpellType::IceShard;
+}
+
 impl IceShardProjectile {
     pub fn new(direction: Vec2, speed: f32, lifetime_secs: f32, damage: f32) -> Self {
         Self {
@@ -132,7 +139,7 @@ pub fn ice_shard_lifetime_system(
 /// System that detects ice shard-enemy collisions and fires events
 pub fn ice_shard_collision_detection(
     ice_shard_query: Query<(Entity, &Transform), With<IceShardProjectile>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<IceShardEnemyCollisionEvent>,
 ) {
     for (ice_shard_entity, ice_shard_transform) in ice_shard_query.iter() {
@@ -141,7 +148,7 @@ pub fn ice_shard_collision_detection(
             ice_shard_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(ice_shard_xz, ICE_SHARD_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -193,7 +200,7 @@ pub fn ice_shard_collision_effects(
     // Apply damage and slow effects
     for (enemy_entity, damage, slow_duration, slow_multiplier) in effects_to_apply {
         // Direct damage
-        damage_events.write(DamageEvent::new(enemy_entity, damage));
+        damage_events.write(DamageEvent::new(enemy_entity, damage).by_spell(SpellType::IceShard));
 
         // Apply or refresh slow effect
         if slowed_query.get(enemy_entity).is_ok() {
@@ -298,9 +305,23 @@ pub fn fire_ice_shard_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::IceShard.
+pub fn cast_ice_shard(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_ice_shard_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
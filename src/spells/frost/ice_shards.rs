@@ -6,13 +6,16 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::frost::ice_shard::SlowedDebuff;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Ice Shards spell
 pub const ICE_SHARDS_SPEED: f32 = 20.0;
@@ -48,6 +51,10 @@ pub struct IceShardFragment {
     pub slow_multiplier: f32,
 }
 
+impl ObstacleProjectile for IceShardFragment {
+    const SPELL_TYPE: SpellType = SpellType::AbsoluteZero;
+}
+
 impl IceShardFragment {
     pub fn new(direction: Vec2, speed: f32, lifetime_secs: f32, damage: f32) -> Self {
         Self {
@@ -98,7 +105,7 @@ pub fn ice_shards_lifetime_system(
 /// System that detects ice shard fragment-enemy collisions and fires events
 pub fn ice_shards_collision_detection(
     fragment_query: Query<(Entity, &Transform), With<IceShardFragment>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<IceShardFragmentCollisionEvent>,
 ) {
     for (fragment_entity, fragment_transform) in fragment_query.iter() {
@@ -107,7 +114,7 @@ pub fn ice_shards_collision_detection(
             fragment_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(fragment_xz, ICE_SHARDS_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -158,7 +165,10 @@ pub fn ice_shards_collision_effects(
     // Apply damage and slow effects
     for (enemy_entity, damage, slow_duration, slow_multiplier) in effects_to_apply {
         // Direct damage
-        damage_events.write(DamageEvent::new(enemy_entity, damage));
+        damage_events.write(
+            DamageEvent::new(enemy_entity, damage)
+                .by_spell(SpellType::AbsoluteZero),
+        );
 
         // Apply or refresh slow effect
         commands.entity(enemy_entity).try_insert(SlowedDebuff::new(slow_duration, slow_multiplier));
@@ -260,9 +270,27 @@ pub fn fire_ice_shards_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::AbsoluteZero.
+pub fn cast_ice_shards(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Ice Shards volley, plus Permafrost on the player so frost hits build freeze stacks
+    fire_ice_shards_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+    if let Some((player_entity, _, _)) = ctx.player {
+        crate::spells::frost::permafrost::activate_permafrost(commands, player_entity);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
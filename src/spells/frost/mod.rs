@@ -1,3 +1,7 @@
+use bevy::prelude::App;
+
+use crate::spell::{SpellBehaviorAppExt, SpellType};
+
 pub mod frozen_orb;
 pub mod glacial_pulse;
 pub mod glacial_spike;
@@ -17,3 +21,16 @@ pub use ice_shard::*;
 pub use ice_shards::*;
 pub use permafrost::*;
 pub use shatter::*;
+
+/// Registers cast handlers for Frost spells.
+pub fn plugin(app: &mut App) {
+    app
+        .register_spell_behavior(SpellType::IceShard, ice_shard::cast_ice_shard)
+        .register_spell_behavior(SpellType::FrostNova, glacial_pulse::cast_glacial_pulse)
+        .register_spell_behavior(SpellType::Blizzard, frozen_orb::cast_frozen_orb)
+        .register_spell_behavior(SpellType::FrozenRay, ice_lance::cast_ice_lance)
+        .register_spell_behavior(SpellType::GlacialSpike, glacial_spike::cast_glacial_spike)
+        .register_spell_behavior(SpellType::IceBarrier, hoarfrost::cast_hoarfrost)
+        .register_spell_behavior(SpellType::Shatter, shatter::cast_shatter)
+        .register_spell_behavior(SpellType::AbsoluteZero, ice_shards::cast_ice_shards);
+}
@@ -6,14 +6,17 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::frost::ice_shard::SlowedDebuff;
 use crate::spells::frost::permafrost::FrozenStatus;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Shatter spell
 pub const SHATTER_SPEED: f32 = 30.0;
@@ -44,6 +47,10 @@ pub struct ShatterProjectile {
     pub frozen_multiplier: f32,
 }
 
+impl ObstacleProjectile for ShatterProjectile {
+    const SPELL_TYPE: SpellType = SpellType::Shatter;
+}
+
 impl ShatterProjectile {
     pub fn new(direction: Vec2, speed: f32, lifetime_secs: f32, base_damage: f32) -> Self {
         Self {
@@ -109,7 +116,7 @@ pub fn shatter_lifetime_system(
 /// System that detects shatter-enemy collisions and fires events
 pub fn shatter_collision_detection(
     shatter_query: Query<(Entity, &Transform), With<ShatterProjectile>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut collision_events: MessageWriter<ShatterEnemyCollisionEvent>,
 ) {
     for (shatter_entity, shatter_transform) in shatter_query.iter() {
@@ -118,7 +125,7 @@ pub fn shatter_collision_detection(
             shatter_transform.translation.z,
         );
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(shatter_xz, SHATTER_COLLISION_RADIUS) {
             let enemy_xz = Vec2::new(
                 enemy_transform.translation.x,
                 enemy_transform.translation.z,
@@ -169,7 +176,10 @@ pub fn shatter_collision_effects(
 
     // Apply damage
     for (enemy_entity, damage) in effects_to_apply {
-        damage_events.write(DamageEvent::with_element(enemy_entity, damage, Element::Frost));
+        damage_events.write(
+            DamageEvent::with_element(enemy_entity, damage, Element::Frost)
+                .by_spell(SpellType::Shatter),
+        );
     }
 }
 
@@ -231,9 +241,23 @@ pub fn fire_shatter_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Shatter.
+pub fn cast_shatter(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_shatter_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
     use bevy::app::App;
@@ -4,12 +4,15 @@
 //! enemies toward it. Enemies near the beacon take continuous damage.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default radius within which enemies are attracted to the beacon
 pub const BEACON_ATTRACT_RADIUS: f32 = 12.0;
@@ -106,18 +109,21 @@ pub fn update_beacon_timers(
 
 /// System to attract enemies toward beacons.
 /// Adds BeaconAttracted component to enemies within attract_radius.
-#[allow(clippy::type_complexity)]
 pub fn attract_enemies_to_beacon(
     mut commands: Commands,
     beacon_query: Query<(Entity, &Beacon)>,
-    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<BeaconAttracted>)>,
+    spatial_enemies: SpatialEnemies,
+    attracted_query: Query<(), With<BeaconAttracted>>,
 ) {
     for (beacon_entity, beacon) in beacon_query.iter() {
         if !beacon.is_active() {
             continue;
         }
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(beacon.position, beacon.attract_radius) {
+            if attracted_query.contains(enemy_entity) {
+                continue;
+            }
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = beacon.position.distance(enemy_pos);
 
@@ -133,7 +139,7 @@ pub fn attract_enemies_to_beacon(
 /// System to apply beacon damage to enemies within damage radius.
 pub fn apply_beacon_damage(
     beacon_query: Query<&Beacon>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for beacon in beacon_query.iter() {
@@ -141,12 +147,16 @@ pub fn apply_beacon_damage(
             continue;
         }
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(beacon.position, beacon.damage_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = beacon.position.distance(enemy_pos);
 
             if distance <= beacon.damage_radius {
-                damage_events.write(DamageEvent::new(enemy_entity, beacon.damage_per_tick));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, beacon.damage_per_tick)
+                        .over_time()
+                        .by_spell(SpellType::Consecration),
+                );
             }
         }
     }
@@ -242,6 +252,19 @@ pub fn fire_beacon_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Consecration.
+pub fn cast_beacon(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_beacon_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
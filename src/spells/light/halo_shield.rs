@@ -5,12 +5,14 @@
 
 use bevy::prelude::*;
 use std::collections::HashMap;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Halo Shield spell
 pub const HALO_SHIELD_RADIUS: f32 = 3.0;
@@ -104,16 +106,19 @@ pub fn halo_shield_update_system(
 /// System that detects enemies touching the shield ring and applies damage
 pub fn halo_shield_contact_damage_system(
     mut shield_query: Query<&mut HaloShield>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut shield in shield_query.iter_mut() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(shield.center, shield.radius + shield.ring_thickness) {
             let enemy_pos = from_xz(enemy_transform.translation);
 
             if shield.is_touching_ring(enemy_pos) && shield.can_hit(enemy_entity) {
                 // Apply damage and start cooldown
-                damage_events.write(DamageEvent::new(enemy_entity, shield.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, shield.damage)
+                        .by_spell(SpellType::DivineLight),
+                );
                 shield.start_cooldown(enemy_entity);
             }
         }
@@ -172,9 +177,22 @@ pub fn fire_halo_shield_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::DivineLight.
+pub fn cast_halo_shield(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_halo_shield_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
@@ -4,12 +4,14 @@
 //! vertical beams of holy light that strike after a short delay.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default targeting radius in world units
 pub const JUDGMENT_TARGET_RANGE: f32 = 15.0;
@@ -169,7 +171,7 @@ pub fn judgment_caster_system(
     mut commands: Commands,
     time: Res<Time>,
     mut caster_query: Query<(&mut JudgmentCaster, &Transform)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
 ) {
@@ -182,27 +184,8 @@ pub fn judgment_caster_system(
 
         let caster_pos = from_xz(caster_transform.translation);
 
-        // Find nearest enemy within range
-        let mut nearest_enemy: Option<(Entity, Vec2, f32)> = None;
-        for (entity, enemy_transform) in enemy_query.iter() {
-            let enemy_pos = from_xz(enemy_transform.translation);
-            let distance = caster_pos.distance(enemy_pos);
-
-            if distance <= caster.target_range {
-                match &nearest_enemy {
-                    Some((_, _, nearest_dist)) if distance < *nearest_dist => {
-                        nearest_enemy = Some((entity, enemy_pos, distance));
-                    }
-                    None => {
-                        nearest_enemy = Some((entity, enemy_pos, distance));
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        // If we found a target, spawn a strike marker
-        if let Some((_, target_pos, _)) = nearest_enemy {
+        // If there is an enemy within range, spawn a strike marker on the nearest
+        if let Some((_, target_pos)) = spatial_enemies.nearest(caster_pos, caster.target_range) {
             let strike = JudgmentStrike::new(target_pos, caster.damage, caster.strike_delay);
             let marker_pos = to_xz(target_pos) + Vec3::new(0.0, 0.1, 0.0);
 
@@ -281,7 +264,7 @@ pub fn update_judgment_strikes(
 /// System that applies damage when JudgmentBeam spawns.
 pub fn judgment_beam_damage_system(
     mut beam_query: Query<&mut JudgmentBeam>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut beam in beam_query.iter_mut() {
@@ -291,12 +274,15 @@ pub fn judgment_beam_damage_system(
 
         // Apply damage to enemies at the beam position (small radius for precision)
         let hit_radius = JUDGMENT_BEAM_WIDTH;
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(beam.position, hit_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = beam.position.distance(enemy_pos);
 
             if distance <= hit_radius {
-                damage_events.write(DamageEvent::new(enemy_entity, beam.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, beam.damage)
+                        .by_spell(SpellType::Judgment),
+                );
             }
         }
 
@@ -329,13 +315,13 @@ pub fn update_judgment_beams(
 pub fn judgment_aoe_damage_system(
     mut aoe_query: Query<&mut JudgmentAoE>,
     beam_query: Query<&JudgmentBeam>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     // Collect enemies already hit by beams (within beam radius)
     let mut enemies_hit_by_beam: Vec<Entity> = Vec::new();
     for beam in beam_query.iter() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(beam.position, JUDGMENT_BEAM_WIDTH) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = beam.position.distance(enemy_pos);
             if distance <= JUDGMENT_BEAM_WIDTH {
@@ -350,7 +336,7 @@ pub fn judgment_aoe_damage_system(
         }
 
         // Apply damage to enemies in AoE radius who weren't hit by the beam
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(aoe.position, aoe.radius) {
             // Skip enemies already hit by the beam
             if enemies_hit_by_beam.contains(&enemy_entity) {
                 continue;
@@ -360,7 +346,10 @@ pub fn judgment_aoe_damage_system(
             let distance = aoe.position.distance(enemy_pos);
 
             if distance <= aoe.radius {
-                damage_events.write(DamageEvent::new(enemy_entity, aoe.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, aoe.damage)
+                        .by_spell(SpellType::Judgment),
+                );
             }
         }
 
@@ -428,9 +417,22 @@ pub fn fire_judgment_with_damage(
     ));
 }
 
+/// Cast handler registered for SpellType::Judgment.
+pub fn cast_judgment(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_judgment_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
@@ -1,3 +1,7 @@
+use bevy::prelude::App;
+
+use crate::spell::{SpellBehaviorAppExt, SpellType};
+
 pub mod beacon;
 pub mod halo_shield;
 pub mod judgment;
@@ -15,3 +19,16 @@ pub use radiance::*;
 pub use radiant_beam::*;
 pub use sanctify::*;
 pub use solar_flare::*;
+
+/// Registers cast handlers for Light spells.
+pub fn plugin(app: &mut App) {
+    app
+        .register_spell_behavior(SpellType::HolyBeam, radiant_beam::cast_radiant_beam)
+        .register_spell_behavior(SpellType::RadiantBeam, radiant_beam::cast_radiant_beam)
+        .register_spell_behavior(SpellType::Radiance, radiance::cast_radiance)
+        .register_spell_behavior(SpellType::Smite, solar_flare::cast_solar_flare)
+        .register_spell_behavior(SpellType::DivineLight, halo_shield::cast_halo_shield)
+        .register_spell_behavior(SpellType::Consecration, beacon::cast_beacon)
+        .register_spell_behavior(SpellType::Purify, purify::cast_purify)
+        .register_spell_behavior(SpellType::Judgment, judgment::cast_judgment);
+}
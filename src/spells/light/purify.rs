@@ -5,11 +5,12 @@
 //! The spell triggers automatically when the cooldown is ready.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::movement::components::from_xz;
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 
 // Import debuff types that Purify can cleanse
@@ -18,6 +19,7 @@ use crate::spells::light::solar_flare::BlindedDebuff;
 use crate::spells::poison::venom_spray::PoisonStack;
 use crate::spells::fire::fireball::BurnEffect;
 use crate::spells::dark::void_pulse::WeakenedDebuff;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Purify spell
 pub const PURIFY_RADIUS: f32 = 6.0;
@@ -110,7 +112,7 @@ pub fn trigger_purify_burst(
     mut commands: Commands,
     caster_query: Query<(&PurifyCaster, &Transform)>,
     player_query: Query<Entity, With<Player>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for (caster, caster_transform) in caster_query.iter() {
@@ -121,16 +123,15 @@ pub fn trigger_purify_burst(
         let caster_pos = from_xz(caster_transform.translation);
 
         // Deal damage to all enemies in range
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(caster_pos, caster.radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = caster_pos.distance(enemy_pos);
 
             if distance <= caster.radius {
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    caster.damage,
-                    Element::Light,
-                ));
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, caster.damage, Element::Light)
+                        .by_spell(SpellType::Purify),
+                );
             }
         }
 
@@ -188,9 +189,23 @@ pub fn fire_purify_with_damage(
     commands.entity(player_entity).insert(PurifyCaster::new(damage));
 }
 
+/// Cast handler registered for SpellType::Purify.
+pub fn cast_purify(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Purify attaches a caster component to the player that cleanses debuffs and damages enemies
+    if let Some((player_entity, _, _)) = ctx.player {
+        fire_purify_with_damage(
+            commands,
+            ctx.spell,
+            ctx.damage,
+            player_entity,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -558,7 +573,7 @@ mod tests {
                 },
                 Health::new(100.0),
                 Transform::from_translation(Vec3::ZERO),
-                BlindedDebuff::new(5.0),
+                BlindedDebuff::new(5.0, &mut rand::thread_rng()),
             )).id();
 
             // Create ready caster
@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Radiance spell
 pub const RADIANCE_PULSE_RADIUS: f32 = 8.0;
@@ -79,7 +81,7 @@ pub fn radiance_pulse_system(
     mut commands: Commands,
     time: Res<Time>,
     mut aura_query: Query<(Entity, &mut RadianceAura, &Transform)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
@@ -92,10 +94,14 @@ pub fn radiance_pulse_system(
 
         if aura.pulse_timer.just_finished() {
             // Damage all enemies within pulse radius
-            for (enemy_entity, enemy_transform) in enemy_query.iter() {
+            for (enemy_entity, enemy_transform) in spatial_enemies.near(aura.center, aura.pulse_radius) {
                 let enemy_pos = from_xz(enemy_transform.translation);
                 if aura.contains(enemy_pos) {
-                    damage_events.write(DamageEvent::new(enemy_entity, aura.damage));
+                    damage_events.write(
+                        DamageEvent::new(enemy_entity, aura.damage)
+                            .over_time()
+                            .by_spell(SpellType::Radiance),
+                    );
                 }
             }
 
@@ -192,9 +198,22 @@ pub fn fire_radiance_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Radiance.
+pub fn cast_radiance(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_radiance_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
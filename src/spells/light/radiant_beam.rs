@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default height of radiant beam center above ground (used for tests)
 pub const RADIANT_BEAM_DEFAULT_Y_HEIGHT: f32 = 0.5;
@@ -38,6 +40,8 @@ pub struct RadiantBeam {
     pub damage: f32,
     /// Y height in 3D world (fires from Whisper's height)
     pub y_height: f32,
+    /// Spell that fired the beam (HolyBeam or RadiantBeam)
+    pub spell: Option<SpellType>,
 }
 
 impl RadiantBeam {
@@ -58,12 +62,19 @@ impl RadiantBeam {
             max_lifetime: 0.5,
             damage,
             y_height,
+            spell: None,
         }
     }
 
     /// Creates a RadiantBeam from a Spell component.
     pub fn from_spell(start_pos: Vec2, direction: Vec2, spell: &Spell, y_height: f32) -> Self {
-        Self::with_height(start_pos, direction, spell.damage(), y_height)
+        Self::with_height(start_pos, direction, spell.damage(), y_height).by_spell(spell.spell_type)
+    }
+
+    /// Attribute the beam's damage to the spell that fired it
+    pub fn by_spell(mut self, spell: SpellType) -> Self {
+        self.spell = Some(spell);
+        self
     }
 
     /// Calculate the beam thickness based on lifetime progress.
@@ -108,7 +119,7 @@ pub fn update_radiant_beams(
 /// Uses XZ plane for collision detection in 3D space (Y axis is height).
 pub fn radiant_beam_collision_system(
     beam_query: Query<&RadiantBeam>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for beam in beam_query.iter() {
@@ -121,7 +132,7 @@ pub fn radiant_beam_collision_system(
         let beam_end = beam.end_pos;
         let beam_length = (beam_end - beam_start).length();
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.along(beam_start, beam_end, RADIANT_BEAM_COLLISION_RADIUS) {
             // Extract XZ coordinates from enemy 3D position
             let enemy_pos = from_xz(enemy_transform.translation);
 
@@ -137,7 +148,7 @@ pub fn radiant_beam_collision_system(
                 // If enemy is close enough to the beam
                 if distance_to_line < RADIANT_BEAM_COLLISION_RADIUS {
                     // Send damage event to enemy
-                    damage_events.write(DamageEvent::new(enemy_entity, beam.damage));
+                    damage_events.write(DamageEvent::new(enemy_entity, beam.damage).by_spell(beam.spell));
                 }
             }
         }
@@ -213,7 +224,7 @@ pub fn fire_radiant_beam(
 #[allow(clippy::too_many_arguments)]
 pub fn fire_radiant_beam_with_damage(
     commands: &mut Commands,
-    _spell: &Spell,
+    spell: &Spell,
     damage: f32,
     spawn_position: Vec3,
     target_pos: Vec2,
@@ -224,7 +235,8 @@ pub fn fire_radiant_beam_with_damage(
     let spawn_xz = from_xz(spawn_position);
     let direction = (target_pos - spawn_xz).normalize();
 
-    let beam = RadiantBeam::with_height(spawn_xz, direction, damage, spawn_position.y);
+    let beam = RadiantBeam::with_height(spawn_xz, direction, damage, spawn_position.y)
+        .by_spell(spell.spell_type);
 
     // Spawn beam at Whisper's full 3D position
     if let (Some(meshes), Some(materials)) = (game_meshes, game_materials) {
@@ -243,9 +255,35 @@ pub fn fire_radiant_beam_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::RadiantBeam / SpellType::HolyBeam.
+pub fn cast_radiant_beam(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_radiant_beam_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+
+    // Play radiant beam sound effect
+    if let (Some(asset_server), Some(weapon_channel), Some(sound_limiter)) =
+        (ctx.asset_server, ctx.weapon_channel.as_deref_mut(), ctx.sound_limiter.as_deref_mut()) {
+        crate::audio::plugin::play_limited_sound_with_volume(
+            weapon_channel,
+            asset_server,
+            "sounds/72639__chipfork71__laser01rev.wav",
+            sound_limiter,
+            0.7,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
 
     mod radiant_beam_component_tests {
@@ -417,6 +455,7 @@ mod tests {
                 max_lifetime: 0.5,
                 damage: 22.0,
                 y_height: RADIANT_BEAM_DEFAULT_Y_HEIGHT,
+                spell: None,
             });
 
             // Create enemy on the beam line with Health component
@@ -467,6 +506,7 @@ mod tests {
                 max_lifetime: 0.5,
                 damage: 22.0,
                 y_height: RADIANT_BEAM_DEFAULT_Y_HEIGHT,
+                spell: None,
             });
 
             // Create enemy on beam line at X=100 but at very high Y - should still collide
@@ -517,6 +557,7 @@ mod tests {
                 max_lifetime: 0.5,
                 damage: 22.0,
                 y_height: RADIANT_BEAM_DEFAULT_Y_HEIGHT,
+                spell: None,
             });
 
             // Create enemy on Z axis at Z=50
@@ -567,6 +608,7 @@ mod tests {
                 max_lifetime: 0.5,
                 damage: 22.0,
                 y_height: RADIANT_BEAM_DEFAULT_Y_HEIGHT,
+                spell: None,
             });
 
             // Enemy at 0.9 units from beam line (within threshold) - should be hit
@@ -896,6 +938,7 @@ mod tests {
                 max_lifetime: 0.5,
                 damage: 22.0,
                 y_height: RADIANT_BEAM_DEFAULT_Y_HEIGHT,
+                spell: None,
             }).id();
 
             app.update();
@@ -4,7 +4,9 @@
 //! location. Enemies within the zone receive a SanctifiedDebuff component that causes them
 //! to take increased damage from all sources. The debuff is removed when enemies leave the zone.
 
+use std::collections::HashMap;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
@@ -180,60 +182,65 @@ pub fn sanctified_zone_duration_system(
 pub fn sanctified_zone_debuff_system(
     mut commands: Commands,
     zone_query: Query<(Entity, &SanctifiedZone)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
-    mut debuff_query: Query<&mut SanctifiedDebuff>,
+    spatial_enemies: SpatialEnemies,
+    mut debuff_query: Query<(Entity, &mut SanctifiedDebuff), With<Enemy>>,
 ) {
-    for (enemy_entity, enemy_transform) in enemy_query.iter() {
-        let enemy_pos = from_xz(enemy_transform.translation);
-
-        // Track which zones this enemy is currently in
-        let mut zones_enemy_is_in: Vec<(Entity, f32)> = Vec::new();
-
-        for (zone_entity, zone) in zone_query.iter() {
+    // Track which zones each nearby enemy is currently in
+    let mut zones_by_enemy: HashMap<Entity, Vec<(Entity, f32)>> = HashMap::new();
+    for (zone_entity, zone) in zone_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(zone.center, zone.radius) {
+            let enemy_pos = from_xz(enemy_transform.translation);
             if zone.contains(enemy_pos) {
-                zones_enemy_is_in.push((zone_entity, zone.damage_multiplier));
+                zones_by_enemy
+                    .entry(enemy_entity)
+                    .or_default()
+                    .push((zone_entity, zone.damage_multiplier));
             }
         }
+    }
 
-        if let Ok(mut debuff) = debuff_query.get_mut(enemy_entity) {
-            // Enemy already has debuff - update zone list
-            // First, remove zones the enemy is no longer in
-            let zones_to_remove: Vec<Entity> = debuff
-                .source_zones
-                .iter()
-                .filter(|&&z| !zones_enemy_is_in.iter().any(|(ze, _)| *ze == z))
-                .copied()
-                .collect();
+    for (enemy_entity, mut debuff) in debuff_query.iter_mut() {
+        // Enemy already has debuff - update zone list
+        let zones_enemy_is_in = zones_by_enemy.remove(&enemy_entity).unwrap_or_default();
+
+        // First, remove zones the enemy is no longer in
+        let zones_to_remove: Vec<Entity> = debuff
+            .source_zones
+            .iter()
+            .filter(|&&z| !zones_enemy_is_in.iter().any(|(ze, _)| *ze == z))
+            .copied()
+            .collect();
+
+        for zone in zones_to_remove {
+            debuff.remove_zone(zone);
+        }
 
-            for zone in zones_to_remove {
-                debuff.remove_zone(zone);
-            }
+        // Add any new zones
+        for (zone_entity, _) in &zones_enemy_is_in {
+            debuff.add_zone(*zone_entity);
+        }
 
-            // Add any new zones
-            for (zone_entity, _) in &zones_enemy_is_in {
-                debuff.add_zone(*zone_entity);
-            }
+        // Remove debuff component if no longer in any zone
+        if !debuff.has_active_zones() {
+            commands.entity(enemy_entity).remove::<SanctifiedDebuff>();
+        }
+    }
 
-            // Remove debuff component if no longer in any zone
-            if !debuff.has_active_zones() {
-                commands.entity(enemy_entity).remove::<SanctifiedDebuff>();
-            }
-        } else if !zones_enemy_is_in.is_empty() {
-            // Enemy doesn't have debuff but is in a zone - add it
-            // Use the highest damage multiplier from all zones
-            let max_multiplier = zones_enemy_is_in
-                .iter()
-                .map(|(_, m)| *m)
-                .fold(f32::NEG_INFINITY, f32::max);
-            let first_zone = zones_enemy_is_in[0].0;
-
-            let mut debuff = SanctifiedDebuff::new(max_multiplier, first_zone);
-            for (zone_entity, _) in zones_enemy_is_in.iter().skip(1) {
-                debuff.add_zone(*zone_entity);
-            }
+    // Enemies left over don't have the debuff but are in a zone - add it
+    for (enemy_entity, zones_enemy_is_in) in zones_by_enemy {
+        // Use the highest damage multiplier from all zones
+        let max_multiplier = zones_enemy_is_in
+            .iter()
+            .map(|(_, m)| *m)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let first_zone = zones_enemy_is_in[0].0;
 
-            commands.entity(enemy_entity).insert(debuff);
+        let mut debuff = SanctifiedDebuff::new(max_multiplier, first_zone);
+        for (zone_entity, _) in zones_enemy_is_in.iter().skip(1) {
+            debuff.add_zone(*zone_entity);
         }
+
+        commands.entity(enemy_entity).insert(debuff);
     }
 }
 
@@ -7,12 +7,15 @@
 use std::collections::HashSet;
 use bevy::prelude::*;
 use rand::Rng;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Solar Flare spell
 pub const SOLAR_FLARE_SPEED: f32 = 18.0;
@@ -49,6 +52,10 @@ pub struct SolarFlareProjectile {
     pub blind_duration: f32,
 }
 
+impl ObstacleProjectile for SolarFlareProjectile {
+    const SPELL_TYPE: SpellType = SpellType::Smite;
+}
+
 impl SolarFlareProjectile {
     /// Create a new solar flare projectile.
     pub fn new(start_position: Vec2, direction: Vec2, damage: f32) -> Self {
@@ -141,8 +148,7 @@ pub struct BlindedDebuff {
 }
 
 impl BlindedDebuff {
-    pub fn new(duration_secs: f32) -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn new(duration_secs: f32, rng: &mut impl Rng) -> Self {
         let angle = rng.gen_range(0.0..std::f32::consts::TAU);
         let direction = Vec2::new(angle.cos(), angle.sin());
 
@@ -156,8 +162,8 @@ impl BlindedDebuff {
         }
     }
 
-    pub fn default_config() -> Self {
-        Self::new(BLIND_DURATION)
+    pub fn default_config(rng: &mut impl Rng) -> Self {
+        Self::new(BLIND_DURATION, rng)
     }
 
     /// Check if the debuff has expired.
@@ -166,13 +172,12 @@ impl BlindedDebuff {
     }
 
     /// Tick the debuff timers.
-    pub fn tick(&mut self, delta: std::time::Duration) {
+    pub fn tick(&mut self, delta: std::time::Duration, rng: &mut impl Rng) {
         self.duration.tick(delta);
         self.direction_change_timer.tick(delta);
 
         // Change direction periodically
         if self.direction_change_timer.just_finished() {
-            let mut rng = rand::thread_rng();
             let angle = rng.gen_range(0.0..std::f32::consts::TAU);
             self.random_direction = Vec2::new(angle.cos(), angle.sin());
         }
@@ -212,7 +217,7 @@ pub fn solar_flare_movement_system(
 pub fn solar_flare_collision_system(
     mut commands: Commands,
     projectile_query: Query<(Entity, &Transform, &SolarFlareProjectile)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut explosion_events: MessageWriter<SolarFlareExplosionEvent>,
 ) {
     for (projectile_entity, projectile_transform, projectile) in projectile_query.iter() {
@@ -220,7 +225,7 @@ pub fn solar_flare_collision_system(
         let mut should_explode = false;
 
         // Check for enemy collision
-        for (_enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (_enemy_entity, enemy_transform) in spatial_enemies.near(projectile_xz, SOLAR_FLARE_COLLISION_RADIUS) {
             let enemy_xz = from_xz(enemy_transform.translation);
             let distance = projectile_xz.distance(enemy_xz);
 
@@ -288,25 +293,25 @@ pub fn solar_flare_spawn_explosion_system(
 pub fn solar_flare_explosion_damage_system(
     mut commands: Commands,
     mut explosion_query: Query<&mut SolarFlareExplosion>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
     for mut explosion in explosion_query.iter_mut() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(explosion.center, explosion.radius) {
             let enemy_xz = from_xz(enemy_transform.translation);
 
             if explosion.contains(enemy_xz) && !explosion.has_hit(enemy_entity) {
                 // Apply damage
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    explosion.damage,
-                    Element::Light,
-                ));
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, explosion.damage, Element::Light)
+                        .by_spell(SpellType::Smite),
+                );
 
                 // Apply blind debuff
                 commands
                     .entity(enemy_entity)
-                    .try_insert(BlindedDebuff::new(explosion.blind_duration));
+                    .try_insert(BlindedDebuff::new(explosion.blind_duration, &mut *rng));
 
                 explosion.mark_hit(enemy_entity);
             }
@@ -334,9 +339,10 @@ pub fn blinded_debuff_tick_system(
     mut commands: Commands,
     mut blinded_query: Query<(Entity, &mut BlindedDebuff)>,
     time: Res<Time>,
+    mut rng: ResMut<GameRng>,
 ) {
     for (entity, mut blinded) in blinded_query.iter_mut() {
-        blinded.tick(time.delta());
+        blinded.tick(time.delta(), &mut *rng);
 
         if blinded.is_expired() {
             commands.entity(entity).remove::<BlindedDebuff>();
@@ -398,9 +404,23 @@ pub fn fire_solar_flare_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Smite.
+pub fn cast_solar_flare(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_solar_flare_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -533,7 +553,7 @@ mod tests {
 
         #[test]
         fn test_blinded_debuff_new() {
-            let debuff = BlindedDebuff::new(3.0);
+            let debuff = BlindedDebuff::new(3.0, &mut rand::thread_rng());
 
             assert!(!debuff.is_expired());
             // Direction should be normalized
@@ -542,27 +562,27 @@ mod tests {
 
         #[test]
         fn test_blinded_debuff_default_config() {
-            let debuff = BlindedDebuff::default_config();
+            let debuff = BlindedDebuff::default_config(&mut rand::thread_rng());
 
             assert!(!debuff.is_expired());
         }
 
         #[test]
         fn test_blinded_debuff_expires() {
-            let mut debuff = BlindedDebuff::new(1.0);
+            let mut debuff = BlindedDebuff::new(1.0, &mut rand::thread_rng());
 
             assert!(!debuff.is_expired());
 
-            debuff.tick(Duration::from_secs_f32(0.5));
+            debuff.tick(Duration::from_secs_f32(0.5), &mut rand::thread_rng());
             assert!(!debuff.is_expired());
 
-            debuff.tick(Duration::from_secs_f32(0.6));
+            debuff.tick(Duration::from_secs_f32(0.6), &mut rand::thread_rng());
             assert!(debuff.is_expired());
         }
 
         #[test]
         fn test_blinded_debuff_get_direction() {
-            let debuff = BlindedDebuff::new(3.0);
+            let debuff = BlindedDebuff::new(3.0, &mut rand::thread_rng());
             let direction = debuff.get_direction();
 
             assert!((direction.length() - 1.0).abs() < 0.001);
@@ -570,13 +590,13 @@ mod tests {
 
         #[test]
         fn test_blinded_debuff_changes_direction() {
-            let mut debuff = BlindedDebuff::new(5.0);
+            let mut debuff = BlindedDebuff::new(5.0, &mut rand::thread_rng());
             let initial_direction = debuff.random_direction;
 
             // Tick past the direction change interval multiple times
             // Direction is random so we can't guarantee it changes,
             // but the tick should work without error
-            debuff.tick(Duration::from_secs_f32(BLIND_DIRECTION_CHANGE_INTERVAL + 0.01));
+            debuff.tick(Duration::from_secs_f32(BLIND_DIRECTION_CHANGE_INTERVAL + 0.01), &mut rand::thread_rng());
 
             // Direction might or might not be different (it's random)
             // Just verify the tick works and direction is still normalized
@@ -806,6 +826,7 @@ mod tests {
         fn setup_test_app() -> App {
             let mut app = App::new();
             app.add_message::<DamageEvent>();
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -994,6 +1015,7 @@ mod tests {
         fn setup_test_app() -> App {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -1001,7 +1023,7 @@ mod tests {
         fn test_blinded_debuff_removed_when_expired() {
             let mut app = setup_test_app();
 
-            let mut debuff = BlindedDebuff::new(0.5);
+            let mut debuff = BlindedDebuff::new(0.5, &mut rand::thread_rng());
             debuff.duration.tick(Duration::from_secs_f32(0.6)); // Force expired
 
             let enemy_entity = app.world_mut().spawn((
@@ -1021,7 +1043,7 @@ mod tests {
         fn test_blinded_debuff_persists_before_expiry() {
             let mut app = setup_test_app();
 
-            let debuff = BlindedDebuff::new(10.0);
+            let debuff = BlindedDebuff::new(10.0, &mut rand::thread_rng());
 
             let enemy_entity = app.world_mut().spawn((
                 Enemy { speed: 50.0, strength: 10.0 },
@@ -1,11 +1,13 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Arc Surge spell
 pub const ARC_SURGE_PULSE_INTERVAL: f32 = 0.8;
@@ -79,7 +81,7 @@ pub fn arc_surge_pulse_system(
     time: Res<Time>,
     player_query: Query<&Transform, With<Player>>,
     mut surge_query: Query<&mut ArcSurge>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     let Ok(player_transform) = player_query.single() else {
@@ -92,33 +94,20 @@ pub fn arc_surge_pulse_system(
         surge.pulse_timer.tick(time.delta());
 
         if surge.pulse_timer.just_finished() {
-            // Collect enemies within range
-            let mut enemies_in_range: Vec<(Entity, f32)> = enemy_query
-                .iter()
-                .filter_map(|(entity, transform)| {
-                    let enemy_pos = from_xz(transform.translation);
-                    let distance = player_pos.distance(enemy_pos);
-                    if distance <= surge.pulse_range {
-                        Some((entity, distance))
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            // Sort by distance (closest first) for deterministic behavior
-            enemies_in_range.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
-
-            // Take up to targets_per_pulse enemies
-            let targets: Vec<Entity> = enemies_in_range
+            // Closest enemies first for deterministic behavior, up to targets_per_pulse in range
+            let targets: Vec<Entity> = spatial_enemies
+                .nearest_k(player_pos, surge.targets_per_pulse as usize)
                 .into_iter()
-                .take(surge.targets_per_pulse as usize)
+                .filter(|(_, enemy_pos)| player_pos.distance(*enemy_pos) <= surge.pulse_range)
                 .map(|(entity, _)| entity)
                 .collect();
 
             // Apply damage to all selected targets
             for target in targets {
-                damage_events.write(DamageEvent::new(target, surge.damage_per_target));
+                damage_events.write(
+                    DamageEvent::new(target, surge.damage_per_target)
+                        .by_spell(SpellType::Spark),
+                );
             }
         }
     }
@@ -175,9 +164,22 @@ pub fn fire_arc_surge_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Spark.
+pub fn cast_arc_surge(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_arc_surge_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
 
     mod arc_surge_component_tests {
@@ -1,11 +1,14 @@
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Chain Lightning spell
 pub const CHAIN_LIGHTNING_JUMP_RANGE: f32 = 8.0;
@@ -140,7 +143,7 @@ pub fn chain_lightning_movement_system(
 pub fn chain_lightning_hit_system(
     mut commands: Commands,
     mut bolt_query: Query<(Entity, &mut ChainLightningBolt, &Transform)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
@@ -151,9 +154,9 @@ pub fn chain_lightning_hit_system(
         }
 
         // Check if we reached the target
-        let Ok((_, target_transform)) = enemy_query.get(bolt.target) else {
+        let Some(target_transform) = spatial_enemies.get(bolt.target) else {
             // Target despawned, try to find new target or despawn bolt
-            if !try_find_next_target(&mut commands, bolt_entity, &mut bolt, &enemy_query, game_meshes.as_deref(), game_materials.as_deref()) {
+            if !try_find_next_target(&mut commands, bolt_entity, &mut bolt, &spatial_enemies, game_meshes.as_deref(), game_materials.as_deref()) {
                 commands.entity(bolt_entity).despawn();
             }
             continue;
@@ -168,7 +171,10 @@ pub fn chain_lightning_hit_system(
             // Apply damage
             let target_entity = bolt.target;
             let damage = bolt.current_damage;
-            damage_events.write(DamageEvent::new(target_entity, damage));
+            damage_events.write(
+                DamageEvent::new(target_entity, damage)
+                    .by_spell(SpellType::ChainLightning),
+            );
             bolt.mark_hit(target_entity);
             bolt.damage_applied = true;
 
@@ -176,7 +182,7 @@ pub fn chain_lightning_hit_system(
             spawn_lightning_arc(&mut commands, bolt.start_pos, target_pos, game_meshes.as_deref(), game_materials.as_deref());
 
             // Try to find next target
-            if !try_find_next_target(&mut commands, bolt_entity, &mut bolt, &enemy_query, game_meshes.as_deref(), game_materials.as_deref()) {
+            if !try_find_next_target(&mut commands, bolt_entity, &mut bolt, &spatial_enemies, game_meshes.as_deref(), game_materials.as_deref()) {
                 commands.entity(bolt_entity).despawn();
             }
         }
@@ -188,7 +194,7 @@ fn try_find_next_target(
     commands: &mut Commands,
     bolt_entity: Entity,
     bolt: &mut ChainLightningBolt,
-    enemy_query: &Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: &SpatialEnemies,
     _game_meshes: Option<&GameMeshes>,
     _game_materials: Option<&GameMaterials>,
 ) -> bool {
@@ -197,7 +203,7 @@ fn try_find_next_target(
     }
 
     // Get current target position for distance calculation
-    let Ok((_, current_target_transform)) = enemy_query.get(bolt.target) else {
+    let Some(current_target_transform) = spatial_enemies.get(bolt.target) else {
         return false;
     };
     let current_target_pos = from_xz(current_target_transform.translation);
@@ -205,7 +211,7 @@ fn try_find_next_target(
     // Find nearest unvisited enemy within range
     let mut best_target: Option<(Entity, Vec2, f32)> = None;
 
-    for (enemy_entity, enemy_transform) in enemy_query.iter() {
+    for (enemy_entity, enemy_transform) in spatial_enemies.near(current_target_pos, bolt.jump_range) {
         // Skip already-hit enemies
         if bolt.already_hit(enemy_entity) {
             continue;
@@ -362,6 +368,21 @@ pub fn fire_chain_lightning_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::ChainLightning.
+pub fn cast_chain_lightning(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Chain lightning targets a specific entity
+    let target_entity = ctx.target_entity;
+    fire_chain_lightning_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        target_entity,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,13 +5,16 @@
 //! player (Whisper) to the targeted enemy and deals damage over time.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::player::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Electrocute spell
 pub const ELECTROCUTE_DURATION: f32 = 3.0;
@@ -104,11 +107,11 @@ pub fn electrocute_damage_system(
         }
 
         if electrocute.should_damage() {
-            damage_events.write(DamageEvent::with_element(
-                electrocute.target,
-                electrocute.damage_per_tick,
-                Element::Lightning,
-            ));
+            damage_events.write(
+                DamageEvent::with_element(electrocute.target, electrocute.damage_per_tick, Element::Lightning)
+                    .over_time()
+                    .by_spell(SpellType::Electrocute),
+            );
         }
     }
 }
@@ -225,11 +228,11 @@ pub fn fire_electrocute(
     commands: &mut Commands,
     spell: &Spell,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform, &Enemy)>,
+    enemies: &SpatialEnemies,
     _game_meshes: Option<&GameMeshes>,
     _game_materials: Option<&GameMaterials>,
 ) {
-    fire_electrocute_with_damage(commands, spell, spell.damage(), spawn_position, enemy_query);
+    fire_electrocute_with_damage(commands, spell, spell.damage(), spawn_position, enemies);
 }
 
 /// Cast Electrocute spell with explicit damage
@@ -239,27 +242,12 @@ pub fn fire_electrocute_with_damage(
     _spell: &Spell,
     damage: f32,
     spawn_position: Vec3,
-    enemy_query: &Query<(Entity, &Transform, &Enemy)>,
+    enemies: &SpatialEnemies,
 ) {
     let spawn_xz = from_xz(spawn_position);
 
     // Find nearest enemy within range
-    let mut nearest_enemy: Option<(Entity, f32)> = None;
-
-    for (enemy_entity, enemy_transform, _enemy) in enemy_query.iter() {
-        let enemy_pos = from_xz(enemy_transform.translation);
-        let distance = spawn_xz.distance(enemy_pos);
-
-        if distance <= ELECTROCUTE_RANGE {
-            match nearest_enemy {
-                None => nearest_enemy = Some((enemy_entity, distance)),
-                Some((_, nearest_dist)) if distance < nearest_dist => {
-                    nearest_enemy = Some((enemy_entity, distance));
-                }
-                _ => {}
-            }
-        }
-    }
+    let nearest_enemy = enemies.nearest(spawn_xz, ELECTROCUTE_RANGE);
 
     // Create electrocute effect targeting the nearest enemy
     if let Some((enemy_entity, _)) = nearest_enemy {
@@ -271,6 +259,17 @@ pub fn fire_electrocute_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Electrocute.
+pub fn cast_electrocute(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_electrocute_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.enemies,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
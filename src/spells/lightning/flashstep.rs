@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::movement::components::{from_xz, to_xz};
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default teleport distance in world units
 pub const FLASHSTEP_DISTANCE: f32 = 8.0;
@@ -78,7 +80,7 @@ impl FlashstepTeleport {
 /// System that applies lightning burst damage to nearby enemies.
 pub fn lightning_burst_damage_system(
     mut burst_query: Query<&mut LightningBurst>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut burst in burst_query.iter_mut() {
@@ -87,12 +89,15 @@ pub fn lightning_burst_damage_system(
         }
 
         // Apply damage to all enemies in radius
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(burst.position, burst.radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = burst.position.distance(enemy_pos);
 
             if distance <= burst.radius {
-                damage_events.write(DamageEvent::new(enemy_entity, burst.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, burst.damage)
+                        .by_spell(SpellType::Flashstep),
+                );
             }
         }
 
@@ -194,6 +199,34 @@ pub fn fire_flashstep_with_damage(
     ));
 }
 
+/// Cast handler registered for SpellType::Flashstep.
+pub fn cast_flashstep(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Flashstep requires player entity and position
+    if let Some((player_entity, player_transform, player)) = ctx.player {
+        // Use player's last movement direction, or direction toward nearest enemy if stationary
+        let direction = if player.last_movement_direction.length() > 0.1 {
+            // Convert 3D direction to 2D on XZ plane
+            Vec2::new(
+                player.last_movement_direction.x,
+                player.last_movement_direction.z,
+            ).normalize()
+        } else {
+            // Stationary: teleport toward nearest enemy
+            let player_pos_xz = from_xz(player_transform.translation);
+            (ctx.target_pos - player_pos_xz).normalize()
+        };
+
+        fire_flashstep_with_damage(
+            commands,
+            ctx.spell,
+            ctx.damage,
+            player_entity,
+            player_transform.translation,
+            direction,
+        );
+    }
+}
+
 /// Cast flashstep spell using spell damage.
 #[allow(clippy::too_many_arguments)]
 pub fn fire_flashstep(
@@ -216,6 +249,7 @@ pub fn fire_flashstep(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use crate::combat::Health;
     use crate::spell::SpellType;
     use std::time::Duration;
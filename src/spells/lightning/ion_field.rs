@@ -1,9 +1,12 @@
+use std::collections::HashMap;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 
 /// Default configuration for Ion Field spell
@@ -86,37 +89,39 @@ pub fn ion_field_duration_system(
 pub fn ion_field_track_enemies_system(
     mut commands: Commands,
     field_query: Query<(Entity, &IonField)>,
-    enemy_query: Query<(Entity, &Transform, Option<&InIonField>), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    marked_query: Query<(Entity, &InIonField), With<Enemy>>,
 ) {
-    for (enemy_entity, enemy_transform, in_field) in enemy_query.iter() {
-        let enemy_pos = from_xz(enemy_transform.translation);
-
-        // Find if enemy is inside any field
-        let mut inside_field: Option<Entity> = None;
-        for (field_entity, field) in field_query.iter() {
+    // Find the first field each nearby enemy is inside
+    let mut inside_fields: HashMap<Entity, Entity> = HashMap::new();
+    for (field_entity, field) in field_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(field.center, field.radius) {
+            let enemy_pos = from_xz(enemy_transform.translation);
             if field.contains(enemy_pos) {
-                inside_field = Some(field_entity);
-                break;
+                inside_fields.entry(enemy_entity).or_insert(field_entity);
             }
         }
+    }
 
-        match (inside_field, in_field) {
-            // Enemy entered a field
-            (Some(field_entity), None) => {
-                commands.entity(enemy_entity).insert(InIonField { field_entity });
-            }
+    for (enemy_entity, in_field) in marked_query.iter() {
+        match inside_fields.remove(&enemy_entity) {
             // Enemy exited their field (or field despawned)
-            (None, Some(_)) => {
+            None => {
                 commands.entity(enemy_entity).remove::<InIonField>();
             }
             // Enemy moved to a different field
-            (Some(new_field), Some(current)) if new_field != current.field_entity => {
+            Some(new_field) if new_field != in_field.field_entity => {
                 commands.entity(enemy_entity).insert(InIonField { field_entity: new_field });
             }
             // No change needed
-            _ => {}
+            Some(_) => {}
         }
     }
+
+    // Enemies left over weren't marked yet: they entered a field
+    for (enemy_entity, field_entity) in inside_fields {
+        commands.entity(enemy_entity).insert(InIonField { field_entity });
+    }
 }
 
 /// System that applies damage to enemies inside Ion Fields
@@ -133,7 +138,11 @@ pub fn ion_field_damage_system(
             // Damage all enemies inside this field
             for (enemy_entity, in_field) in enemy_query.iter() {
                 if in_field.field_entity == field_entity {
-                    damage_events.write(DamageEvent::new(enemy_entity, field.damage_per_tick));
+                    damage_events.write(
+                        DamageEvent::new(enemy_entity, field.damage_per_tick)
+                            .over_time()
+                            .by_spell(SpellType::Electrocute),
+                    );
                 }
             }
         }
@@ -1,3 +1,7 @@
+use bevy::prelude::App;
+
+use crate::spell::{SpellBehaviorAppExt, SpellType};
+
 pub mod arc_surge;
 pub mod chain_lightning;
 pub mod electrocute;
@@ -17,3 +21,16 @@ pub use overload::*;
 pub use static_orb::*;
 pub use stormcall::*;
 pub use thunder_strike::*;
+
+/// Registers cast handlers for Lightning spells.
+pub fn plugin(app: &mut App) {
+    app
+        .register_spell_behavior(SpellType::Spark, arc_surge::cast_arc_surge)
+        .register_spell_behavior(SpellType::ChainLightning, chain_lightning::cast_chain_lightning)
+        .register_spell_behavior(SpellType::ThunderStrike, thunder_strike::cast_thunder_strike)
+        .register_spell_behavior(SpellType::StaticField, static_orb::cast_static_orb)
+        .register_spell_behavior(SpellType::Flashstep, flashstep::cast_flashstep)
+        .register_spell_behavior(SpellType::Overcharge, overload::cast_overload)
+        .register_spell_behavior(SpellType::Electrocute, electrocute::cast_electrocute)
+        .register_spell_behavior(SpellType::StormCall, stormcall::cast_stormcall);
+}
@@ -1,11 +1,14 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Overload spell
 pub const OVERLOAD_MAX_CHARGE: f32 = 100.0;
@@ -204,7 +207,7 @@ pub fn overload_blast_system(
     mut commands: Commands,
     time: Res<Time>,
     mut blast_query: Query<(Entity, &mut OverloadBlast, &mut Transform), Without<Enemy>>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for (entity, mut blast, mut transform) in blast_query.iter_mut() {
@@ -222,12 +225,15 @@ pub fn overload_blast_system(
 
             // Damage all enemies within blast radius
             let blast_origin = from_xz(blast.origin);
-            for (enemy_entity, enemy_transform) in enemy_query.iter() {
+            for (enemy_entity, enemy_transform) in spatial_enemies.near(blast_origin, blast.radius) {
                 let enemy_pos = from_xz(enemy_transform.translation);
                 let distance = blast_origin.distance(enemy_pos);
 
                 if distance <= blast.radius {
-                    damage_events.write(DamageEvent::new(enemy_entity, blast.damage));
+                    damage_events.write(
+                        DamageEvent::new(enemy_entity, blast.damage)
+                            .by_spell(SpellType::Overcharge),
+                    );
                 }
             }
         }
@@ -292,6 +298,18 @@ pub fn fire_overload_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Overcharge.
+pub fn cast_overload(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_overload_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
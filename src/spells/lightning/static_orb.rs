@@ -1,11 +1,13 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::frost::ice_shard::SlowedDebuff;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Static Orb spell
 pub const STATIC_ORB_ZAP_INTERVAL: f32 = 0.5;
@@ -85,36 +87,19 @@ pub fn static_orb_zap_system(
     mut commands: Commands,
     time: Res<Time>,
     mut orb_query: Query<&mut StaticOrb>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut orb in orb_query.iter_mut() {
         orb.zap_timer.tick(time.delta());
 
         if orb.zap_timer.just_finished() {
-            // Find the nearest enemy within range
-            let mut nearest: Option<(Entity, f32)> = None;
-
-            for (enemy_entity, enemy_transform) in enemy_query.iter() {
-                let enemy_pos = from_xz(enemy_transform.translation);
-                let distance = orb.position.distance(enemy_pos);
-
-                if distance <= orb.zap_range {
-                    match nearest {
-                        Some((_, best_distance)) if distance < best_distance => {
-                            nearest = Some((enemy_entity, distance));
-                        }
-                        None => {
-                            nearest = Some((enemy_entity, distance));
-                        }
-                        _ => {}
-                    }
-                }
-            }
-
-            // Zap the nearest enemy if found
-            if let Some((enemy_entity, _)) = nearest {
-                damage_events.write(DamageEvent::new(enemy_entity, orb.damage_per_zap));
+            // Zap the nearest enemy within range, if any
+            if let Some((enemy_entity, _)) = spatial_enemies.nearest(orb.position, orb.zap_range) {
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, orb.damage_per_zap)
+                        .by_spell(SpellType::StaticField),
+                );
                 // Apply slow effect
                 commands.entity(enemy_entity).try_insert(SlowedDebuff::new(orb.slow_duration, orb.slow_multiplier));
             }
@@ -174,9 +159,22 @@ pub fn fire_static_orb_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::StaticField.
+pub fn cast_static_orb(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_static_orb_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
 
     mod static_orb_component_tests {
@@ -1,12 +1,14 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::{from_xz, to_xz};
 use crate::player::components::Player;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
-use rand::Rng as _;
+use rand::Rng;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Stormcall spell
 pub const STORMCALL_MARKER_COUNT_MIN: u8 = 3;
@@ -157,14 +159,13 @@ pub fn stormcall_marker_move_system(
     time: Res<Time>,
     player_query: Query<&Transform, With<Player>>,
     mut marker_query: Query<(&mut StormcallMarker, &mut Transform), Without<Player>>,
+    mut rng: ResMut<GameRng>,
 ) {
     let Ok(player_transform) = player_query.single() else {
         return;
     };
     let player_pos = from_xz(player_transform.translation);
 
-    let mut rng = rand::thread_rng();
-
     for (mut marker, mut transform) in marker_query.iter_mut() {
         marker.move_timer.tick(time.delta());
 
@@ -218,7 +219,7 @@ pub fn stormcall_marker_strike_system(
 /// System that applies area damage when lightning strike lands.
 pub fn stormcall_strike_damage_system(
     mut strike_query: Query<&mut StormcallStrike>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut strike in strike_query.iter_mut() {
@@ -227,12 +228,15 @@ pub fn stormcall_strike_damage_system(
         }
 
         // Apply damage to all enemies in radius
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(strike.center, strike.radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = strike.center.distance(enemy_pos);
 
             if distance <= strike.radius {
-                damage_events.write(DamageEvent::new(enemy_entity, strike.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, strike.damage)
+                        .by_spell(SpellType::StormCall),
+                );
             }
         }
 
@@ -269,6 +273,7 @@ pub fn fire_stormcall(
     spawn_position: Vec3,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     fire_stormcall_with_damage(
         commands,
@@ -277,6 +282,7 @@ pub fn fire_stormcall(
         spawn_position,
         game_meshes,
         game_materials,
+        rng,
     );
 }
 
@@ -291,9 +297,9 @@ pub fn fire_stormcall_with_damage(
     spawn_position: Vec3,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     let player_pos = from_xz(spawn_position);
-    let mut rng = rand::thread_rng();
 
     // Random marker count between min and max (inclusive)
     let marker_count = rng.gen_range(STORMCALL_MARKER_COUNT_MIN..=STORMCALL_MARKER_COUNT_MAX);
@@ -329,9 +335,23 @@ pub fn fire_stormcall_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::StormCall.
+pub fn cast_stormcall(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_stormcall_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+        ctx.rng,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
 
     mod stormcall_controller_tests {
@@ -524,6 +544,7 @@ mod tests {
         fn setup_test_app() -> App {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -1063,6 +1084,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1088,6 +1110,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1119,6 +1142,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1148,6 +1172,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1179,6 +1204,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1212,6 +1238,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1239,6 +1266,7 @@ mod tests {
                     spawn_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -1,10 +1,12 @@
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::{from_xz, to_xz};
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default strike delay in seconds (time from target marker to strike)
 pub const THUNDER_STRIKE_DELAY: f32 = 0.5;
@@ -131,7 +133,7 @@ pub fn update_thunder_strike_markers(
 /// System that applies area damage when thunder strike lands.
 pub fn thunder_strike_damage_system(
     mut strike_query: Query<&mut ThunderStrike>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut strike in strike_query.iter_mut() {
@@ -140,12 +142,15 @@ pub fn thunder_strike_damage_system(
         }
 
         // Apply damage to all enemies in radius
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(strike.center, strike.radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = strike.center.distance(enemy_pos);
 
             if distance <= strike.radius {
-                damage_events.write(DamageEvent::new(enemy_entity, strike.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, strike.damage)
+                        .by_spell(SpellType::ThunderStrike),
+                );
             }
         }
 
@@ -227,9 +232,23 @@ pub fn fire_thunder_strike_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::ThunderStrike.
+pub fn cast_thunder_strike(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_thunder_strike_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
 
     mod thunder_strike_marker_tests {
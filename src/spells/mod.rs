@@ -1,3 +1,7 @@
+use bevy::prelude::App;
+
+use crate::game::resources::GameRng;
+
 pub mod chaos;
 pub mod dark;
 pub mod fire;
@@ -33,3 +37,18 @@ pub use psychic::echo_thought;
 pub use psychic::mind_lash;
 pub use psychic::psionic_burst;
 pub use psychic::psychic_scream;
+
+/// Registers cast handlers for every built-in spell.
+pub fn plugin(app: &mut App) {
+    // Cast handlers roll through `SpellCastContext::rng`
+    app.init_resource::<GameRng>().add_plugins((
+        chaos::plugin,
+        dark::plugin,
+        fire::plugin,
+        frost::plugin,
+        light::plugin,
+        lightning::plugin,
+        poison::plugin,
+        psychic::plugin,
+    ));
+}
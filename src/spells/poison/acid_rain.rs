@@ -5,13 +5,16 @@
 //! Each droplet damages enemies on contact and applies poison DOT.
 
 use bevy::prelude::*;
+use rand::Rng;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use crate::spells::poison::venom_spray::PoisonStack;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Acid Rain spell
 pub const ACID_RAIN_ZONE_RADIUS: f32 = 4.5;
@@ -163,6 +166,18 @@ pub fn spawn_acid_rain_zone_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::CorrosivePool.
+pub fn cast_acid_rain(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    spawn_acid_rain_zone_with_damage(
+        commands,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// System that ticks zone timers and spawns droplets.
 pub fn acid_rain_spawn_droplets_system(
     mut commands: Commands,
@@ -170,14 +185,15 @@ pub fn acid_rain_spawn_droplets_system(
     mut zone_query: Query<(Entity, &mut AcidRainZone, &Transform)>,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for (zone_entity, mut zone, _zone_transform) in zone_query.iter_mut() {
         zone.tick(time.delta());
 
         if zone.should_spawn_droplet() {
             // Spawn droplet at random position within zone radius
-            let angle = rand::random::<f32>() * std::f32::consts::TAU;
-            let distance = rand::random::<f32>().sqrt() * zone.radius;
+            let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+            let distance = rng.gen::<f32>().sqrt() * zone.radius;
             let offset = Vec2::new(angle.cos() * distance, angle.sin() * distance);
             let droplet_xz = zone.center + offset;
 
@@ -216,7 +232,7 @@ pub fn acid_rain_move_droplets_system(
 pub fn acid_rain_droplet_collision_system(
     mut commands: Commands,
     droplet_query: Query<(Entity, &AcidDroplet, &Transform)>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut poison_query: Query<&mut PoisonStack>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
@@ -224,7 +240,7 @@ pub fn acid_rain_droplet_collision_system(
         let droplet_pos = from_xz(droplet_transform.translation);
         let droplet_y = droplet_transform.translation.y;
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(droplet_pos, 1.0) {
             let enemy_pos = from_xz(enemy_transform.translation);
             // Enemy hitbox height check - droplets pass through at enemy level
             let enemy_height = 0.75; // Approximate enemy height
@@ -236,17 +252,18 @@ pub fn acid_rain_droplet_collision_system(
                 let distance = droplet_pos.distance(enemy_pos);
                 if distance < 1.0 {
                     // Deal direct damage
-                    damage_events.write(DamageEvent::with_element(
-                        enemy_entity,
-                        droplet.damage,
-                        Element::Poison,
-                    ));
+                    damage_events.write(
+                        DamageEvent::with_element(enemy_entity, droplet.damage, Element::Poison)
+                            .by_spell(SpellType::CorrosivePool),
+                    );
 
                     // Apply or refresh poison stack
                     if let Ok(mut existing_stack) = poison_query.get_mut(enemy_entity) {
                         existing_stack.add_stack();
                     } else {
-                        commands.entity(enemy_entity).insert(PoisonStack::new());
+                        commands
+                            .entity(enemy_entity)
+                            .insert(PoisonStack::new().by_spell(SpellType::CorrosivePool));
                     }
 
                     // Despawn droplet on hit
@@ -285,6 +302,7 @@ pub fn acid_rain_cleanup_zone_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -463,6 +481,7 @@ mod tests {
         fn setup_spawn_test_app() -> App {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<GameRng>();
             app
         }
 
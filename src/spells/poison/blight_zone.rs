@@ -6,12 +6,14 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Blight Zone spell
 pub const BLIGHT_ZONE_RADIUS: f32 = 4.0;
@@ -142,10 +144,21 @@ pub fn spawn_blight_zone_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Blight.
+pub fn cast_blight_zone(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    spawn_blight_zone_with_damage(
+        commands,
+        ctx.damage,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// System that applies damage to enemies in blight zones.
 pub fn blight_zone_damage_system(
     mut zone_query: Query<&mut BlightZone>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     time: Res<Time>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
@@ -153,15 +166,15 @@ pub fn blight_zone_damage_system(
         zone.tick(time.delta());
 
         if zone.should_damage() {
-            for (enemy_entity, enemy_transform) in enemy_query.iter() {
+            for (enemy_entity, enemy_transform) in spatial_enemies.near(zone.center, zone.radius) {
                 let enemy_pos = from_xz(enemy_transform.translation);
 
                 if zone.can_damage(enemy_entity, enemy_pos) {
-                    damage_events.write(DamageEvent::with_element(
-                        enemy_entity,
-                        zone.tick_damage,
-                        Element::Poison,
-                    ));
+                    damage_events.write(
+                        DamageEvent::with_element(enemy_entity, zone.tick_damage, Element::Poison)
+                            .over_time()
+                            .by_spell(SpellType::Blight),
+                    );
                     zone.mark_hit(enemy_entity);
                 }
             }
@@ -184,6 +197,7 @@ pub fn blight_zone_cleanup_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
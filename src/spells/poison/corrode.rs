@@ -107,9 +107,9 @@ pub fn apply_corroded_on_poison_damage(
     }
 }
 
-// Note: Damage amplification for CorrodedDebuff is handled in
-// crate::combat::systems::apply_damage_system which checks for
-// damage-modifying debuffs and applies their multipliers.
+// Note: Damage amplification for CorrodedDebuff is handled by its
+// DamageModifier impl in crate::combat::pipeline, which
+// crate::combat::systems::apply_damage_system runs for every hit.
 
 #[cfg(test)]
 mod tests {
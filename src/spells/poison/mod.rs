@@ -1,9 +1,14 @@
+use bevy::prelude::App;
+
+use crate::spell::{SpellBehaviorAppExt, SpellType};
+
 pub mod acid_rain;
 pub mod blight_zone;
 pub mod corrode;
 pub mod neurotoxin;
 pub mod poison_cloud;
 pub mod toxic_glob;
+pub mod venom_bolt;
 pub mod venom_spray;
 pub mod virulence;
 
@@ -13,5 +18,19 @@ pub use corrode::*;
 pub use neurotoxin::*;
 pub use poison_cloud::*;
 pub use toxic_glob::*;
+pub use venom_bolt::*;
 pub use venom_spray::*;
 pub use virulence::*;
+
+/// Registers cast handlers for Poison spells.
+pub fn plugin(app: &mut App) {
+    app
+        .register_spell_behavior(SpellType::VenomBolt, venom_bolt::cast_venom_bolt)
+        .register_spell_behavior(SpellType::PlagueCloud, poison_cloud::cast_poison_cloud)
+        .register_spell_behavior(SpellType::ToxicSpray, venom_spray::cast_venom_spray)
+        .register_spell_behavior(SpellType::Miasma, toxic_glob::cast_toxic_glob)
+        .register_spell_behavior(SpellType::CorrosivePool, acid_rain::cast_acid_rain)
+        .register_spell_behavior(SpellType::Pandemic, virulence::cast_virulence)
+        .register_spell_behavior(SpellType::Blight, blight_zone::cast_blight_zone)
+        .register_spell_behavior(SpellType::Necrosis, neurotoxin::cast_neurotoxin);
+}
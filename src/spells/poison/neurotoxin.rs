@@ -10,7 +10,10 @@ use rand::Rng;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
+use crate::game::resources::GameRng;
 use crate::movement::components::to_xz;
+use crate::spell::SpellType;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Neurotoxin debuff
 pub const NEUROTOXIN_DURATION: f32 = 5.0;
@@ -96,9 +99,8 @@ pub fn neurotoxin_debuff_tick_system(
     mut commands: Commands,
     time: Res<Time>,
     mut debuff_query: Query<(Entity, &mut NeurotoxinDebuff)>,
+    mut rng: ResMut<GameRng>,
 ) {
-    let mut rng = rand::thread_rng();
-
     for (entity, mut debuff) in debuff_query.iter_mut() {
         debuff.tick(time.delta());
 
@@ -107,7 +109,7 @@ pub fn neurotoxin_debuff_tick_system(
         } else {
             // Update jitter direction at intervals
             if debuff.tick_jitter(time.delta()) {
-                debuff.recalculate_jitter(&mut rng);
+                debuff.recalculate_jitter(&mut *rng);
             }
         }
     }
@@ -159,6 +161,36 @@ pub fn apply_neurotoxin_on_poison_damage(
     }
 }
 
+/// Cast Necrosis spell - afflicts the target with neurotoxin and deals poison damage.
+/// `damage` is the pre-calculated final damage (including attunement multiplier)
+pub fn fire_neurotoxin_with_damage(
+    commands: &mut Commands,
+    damage: f32,
+    target_entity: Entity,
+    damage_events: &mut MessageWriter<DamageEvent>,
+) {
+    commands
+        .entity(target_entity)
+        .try_insert(NeurotoxinDebuff::default());
+    damage_events.write(
+        DamageEvent::with_element(target_entity, damage, Element::Poison)
+            .by_spell(SpellType::Necrosis),
+    );
+}
+
+/// Cast handler registered for SpellType::Necrosis.
+pub fn cast_neurotoxin(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Neurotoxin makes the target stagger erratically while it takes poison damage
+    if let Some(events) = ctx.damage_events.as_deref_mut() {
+        fire_neurotoxin_with_damage(
+            commands,
+            ctx.damage,
+            ctx.target_entity,
+            events,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -284,6 +316,7 @@ mod tests {
         fn setup_test_app() -> App {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -705,4 +738,37 @@ mod tests {
             assert_eq!(final_pos, initial_pos, "Enemy without debuff should not be affected by jitter");
         }
     }
+
+    mod fire_neurotoxin_tests {
+        use super::*;
+
+        #[test]
+        fn test_fire_neurotoxin_applies_debuff_and_deals_poison_damage() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+
+            let enemy = app
+                .world_mut()
+                .spawn(Enemy {
+                    speed: 50.0,
+                    strength: 10.0,
+                })
+                .id();
+
+            let _ = app.world_mut().run_system_once(
+                move |mut commands: Commands, mut events: MessageWriter<DamageEvent>| {
+                    fire_neurotoxin_with_damage(&mut commands, 12.0, enemy, &mut events);
+                },
+            );
+
+            assert!(app.world().get::<NeurotoxinDebuff>(enemy).is_some());
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let mut cursor = messages.get_cursor();
+            let events: Vec<_> = cursor.read(messages).collect();
+            assert_eq!(events.len(), 1);
+            assert!(events[0].is_poison());
+            assert_eq!(events[0].amount, 12.0);
+        }
+    }
 }
@@ -1,11 +1,14 @@
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Poison Cloud spell
 pub const POISON_CLOUD_PROJECTILE_SPEED: f32 = 12.0;
@@ -41,6 +44,10 @@ pub struct PoisonCloudProjectile {
     pub tick_damage: f32,
 }
 
+impl ObstacleProjectile for PoisonCloudProjectile {
+    const SPELL_TYPE: SpellType = SpellType::PlagueCloud;
+}
+
 impl PoisonCloudProjectile {
     pub fn new(start_pos: Vec2, target_pos: Vec2, damage: f32) -> Self {
         let tick_damage = damage * POISON_CLOUD_TICK_DAMAGE_RATIO;
@@ -212,7 +219,7 @@ pub fn poison_cloud_spawn_zone_system(
 /// System that applies damage to enemies in poison cloud zones
 pub fn poison_cloud_damage_system(
     mut zone_query: Query<&mut PoisonCloudZone>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     time: Res<Time>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
@@ -220,15 +227,15 @@ pub fn poison_cloud_damage_system(
         zone.tick(time.delta());
 
         if zone.should_damage() {
-            for (enemy_entity, enemy_transform) in enemy_query.iter() {
+            for (enemy_entity, enemy_transform) in spatial_enemies.near(zone.center, zone.radius) {
                 let enemy_pos = from_xz(enemy_transform.translation);
 
                 if zone.can_damage(enemy_entity, enemy_pos) {
-                    damage_events.write(DamageEvent::with_element(
-                        enemy_entity,
-                        zone.tick_damage,
-                        Element::Poison,
-                    ));
+                    damage_events.write(
+                        DamageEvent::with_element(enemy_entity, zone.tick_damage, Element::Poison)
+                            .over_time()
+                            .by_spell(SpellType::PlagueCloud),
+                    );
                     zone.mark_hit(enemy_entity);
                 }
             }
@@ -308,9 +315,23 @@ pub fn fire_poison_cloud_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::PlagueCloud.
+pub fn cast_poison_cloud(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_poison_cloud_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
@@ -7,12 +7,16 @@
 use std::collections::HashSet;
 use bevy::prelude::*;
 use rand::Rng;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::events::ProjectileObstacleHit;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Toxic Glob spell
 pub const TOXIC_GLOB_SPEED: f32 = 4.0; // Slow-moving projectile
@@ -48,9 +52,12 @@ pub struct ToxicGlobProjectile {
     pub puddle_count: u32,
 }
 
+impl ObstacleProjectile for ToxicGlobProjectile {
+    const SPELL_TYPE: SpellType = SpellType::Miasma;
+}
+
 impl ToxicGlobProjectile {
-    pub fn new(direction: Vec2, damage: f32) -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn new(direction: Vec2, damage: f32, rng: &mut impl Rng) -> Self {
         let puddle_count = rng.gen_range(TOXIC_GLOB_MIN_PUDDLES..=TOXIC_GLOB_MAX_PUDDLES);
         Self {
             direction: direction.normalize_or_zero(),
@@ -168,6 +175,7 @@ pub fn toxic_glob_lifetime_system(
     time: Res<Time>,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for (entity, transform, mut glob) in projectile_query.iter_mut() {
         glob.tick(time.delta());
@@ -182,6 +190,7 @@ pub fn toxic_glob_lifetime_system(
                 glob.puddle_count,
                 game_meshes.as_deref(),
                 game_materials.as_deref(),
+                &mut *rng,
             );
             commands.entity(entity).despawn();
         }
@@ -192,14 +201,15 @@ pub fn toxic_glob_lifetime_system(
 pub fn toxic_glob_collision_system(
     mut commands: Commands,
     projectile_query: Query<(Entity, &Transform, &ToxicGlobProjectile)>,
-    enemy_query: Query<&Transform, With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     game_meshes: Option<Res<GameMeshes>>,
     game_materials: Option<Res<GameMaterials>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for (entity, glob_transform, glob) in projectile_query.iter() {
         let glob_pos = from_xz(glob_transform.translation);
 
-        for enemy_transform in enemy_query.iter() {
+        for (_, enemy_transform) in spatial_enemies.near(glob_pos, glob.collision_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
 
             if glob.collides_with(glob_pos, enemy_pos) {
@@ -211,6 +221,7 @@ pub fn toxic_glob_collision_system(
                     glob.puddle_count,
                     game_meshes.as_deref(),
                     game_materials.as_deref(),
+                    &mut *rng,
                 );
                 commands.entity(entity).despawn();
                 break; // Only burst once
@@ -219,10 +230,37 @@ pub fn toxic_glob_collision_system(
     }
 }
 
+/// System that bursts toxic globs into puddles where they hit a rock or arena wall
+pub fn toxic_glob_obstacle_burst_system(
+    mut commands: Commands,
+    mut obstacle_hits: MessageReader<ProjectileObstacleHit>,
+    projectile_query: Query<&ToxicGlobProjectile>,
+    game_meshes: Option<Res<GameMeshes>>,
+    game_materials: Option<Res<GameMaterials>>,
+    mut rng: ResMut<GameRng>,
+) {
+    for hit in obstacle_hits.read() {
+        let Ok(glob) = projectile_query.get(hit.projectile) else {
+            continue;
+        };
+
+        spawn_puddles(
+            &mut commands,
+            from_xz(hit.position),
+            glob.damage,
+            glob.puddle_count,
+            game_meshes.as_deref(),
+            game_materials.as_deref(),
+            &mut *rng,
+        );
+        commands.entity(hit.projectile).despawn();
+    }
+}
+
 /// System that applies damage to enemies in poison puddles
 pub fn poison_puddle_damage_system(
     mut puddle_query: Query<&mut PoisonPuddle>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     time: Res<Time>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
@@ -230,15 +268,15 @@ pub fn poison_puddle_damage_system(
         puddle.tick(time.delta());
 
         if puddle.should_damage() {
-            for (enemy_entity, enemy_transform) in enemy_query.iter() {
+            for (enemy_entity, enemy_transform) in spatial_enemies.near(puddle.center, puddle.radius) {
                 let enemy_pos = from_xz(enemy_transform.translation);
 
                 if puddle.can_damage(enemy_entity, enemy_pos) {
-                    damage_events.write(DamageEvent::with_element(
-                        enemy_entity,
-                        puddle.tick_damage,
-                        Element::Poison,
-                    ));
+                    damage_events.write(
+                        DamageEvent::with_element(enemy_entity, puddle.tick_damage, Element::Poison)
+                            .over_time()
+                            .by_spell(SpellType::Miasma),
+                    );
                     puddle.mark_hit(enemy_entity);
                 }
             }
@@ -266,9 +304,8 @@ fn spawn_puddles(
     puddle_count: u32,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
-    let mut rng = rand::thread_rng();
-
     for _ in 0..puddle_count {
         // Random offset from burst position
         let angle = rng.gen_range(0.0..std::f32::consts::TAU);
@@ -305,6 +342,7 @@ pub fn fire_toxic_glob(
     target_pos: Vec2,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     fire_toxic_glob_with_damage(
         commands,
@@ -314,6 +352,7 @@ pub fn fire_toxic_glob(
         target_pos,
         game_meshes,
         game_materials,
+        rng,
     );
 }
 
@@ -327,11 +366,12 @@ pub fn fire_toxic_glob_with_damage(
     target_pos: Vec2,
     game_meshes: Option<&GameMeshes>,
     game_materials: Option<&GameMaterials>,
+    rng: &mut impl Rng,
 ) {
     let start_pos = from_xz(spawn_position);
     let direction = (target_pos - start_pos).normalize_or_zero();
 
-    let projectile = ToxicGlobProjectile::new(direction, damage);
+    let projectile = ToxicGlobProjectile::new(direction, damage, rng);
 
     if let (Some(meshes), Some(materials)) = (game_meshes, game_materials) {
         commands.spawn((
@@ -350,9 +390,24 @@ pub fn fire_toxic_glob_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Miasma.
+pub fn cast_toxic_glob(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_toxic_glob_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+        ctx.rng,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -363,7 +418,7 @@ mod tests {
         #[test]
         fn test_projectile_new() {
             let direction = Vec2::new(1.0, 0.0);
-            let projectile = ToxicGlobProjectile::new(direction, 30.0)
+            let projectile = ToxicGlobProjectile::new(direction, 30.0, &mut rand::thread_rng())
                 .with_puddle_count(4); // Fixed count for test
 
             assert_eq!(projectile.direction, direction);
@@ -376,14 +431,14 @@ mod tests {
         #[test]
         fn test_projectile_normalizes_direction() {
             let unnormalized = Vec2::new(3.0, 4.0);
-            let projectile = ToxicGlobProjectile::new(unnormalized, 30.0);
+            let projectile = ToxicGlobProjectile::new(unnormalized, 30.0, &mut rand::thread_rng());
 
             assert!((projectile.direction.length() - 1.0).abs() < 0.001);
         }
 
         #[test]
         fn test_projectile_is_expired() {
-            let mut projectile = ToxicGlobProjectile::new(Vec2::X, 30.0);
+            let mut projectile = ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng());
             assert!(!projectile.is_expired());
 
             projectile.tick(Duration::from_secs_f32(TOXIC_GLOB_LIFETIME + 0.1));
@@ -392,7 +447,7 @@ mod tests {
 
         #[test]
         fn test_projectile_collides_within_radius() {
-            let projectile = ToxicGlobProjectile::new(Vec2::X, 30.0);
+            let projectile = ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng());
             let glob_pos = Vec2::ZERO;
             let close_pos = Vec2::new(0.5, 0.0);
 
@@ -401,7 +456,7 @@ mod tests {
 
         #[test]
         fn test_projectile_does_not_collide_outside_radius() {
-            let projectile = ToxicGlobProjectile::new(Vec2::X, 30.0);
+            let projectile = ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng());
             let glob_pos = Vec2::ZERO;
             let far_pos = Vec2::new(10.0, 0.0);
 
@@ -500,7 +555,7 @@ mod tests {
 
             let entity = app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ToxicGlobProjectile::new(Vec2::X, 30.0),
+                ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng()),
             )).id();
 
             // Advance time
@@ -528,7 +583,7 @@ mod tests {
 
             let entity = app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ToxicGlobProjectile::new(Vec2::new(0.0, 1.0), 30.0),
+                ToxicGlobProjectile::new(Vec2::new(0.0, 1.0), 30.0, &mut rand::thread_rng()),
             )).id();
 
             // Advance time
@@ -557,6 +612,7 @@ mod tests {
         fn setup_test_app() -> App {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -564,7 +620,7 @@ mod tests {
         fn test_glob_despawns_on_timeout() {
             let mut app = setup_test_app();
 
-            let mut glob = ToxicGlobProjectile::new(Vec2::X, 30.0)
+            let mut glob = ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng())
                 .with_puddle_count(3);
             glob.lifetime = Timer::from_seconds(0.0, TimerMode::Once);
             glob.lifetime.tick(Duration::from_secs(1)); // Force expired
@@ -589,7 +645,7 @@ mod tests {
         fn test_glob_spawns_puddles_on_timeout() {
             let mut app = setup_test_app();
 
-            let mut glob = ToxicGlobProjectile::new(Vec2::X, 30.0)
+            let mut glob = ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng())
                 .with_puddle_count(3);
             glob.lifetime = Timer::from_seconds(0.0, TimerMode::Once);
             glob.lifetime.tick(Duration::from_secs(1));
@@ -618,7 +674,7 @@ mod tests {
 
             let entity = app.world_mut().spawn((
                 Transform::from_translation(Vec3::ZERO),
-                ToxicGlobProjectile::new(Vec2::X, 30.0),
+                ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng()),
             )).id();
 
             // Small time advance
@@ -640,6 +696,7 @@ mod tests {
         fn setup_test_app() -> App {
             let mut app = App::new();
             app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<GameRng>();
             app
         }
 
@@ -649,7 +706,7 @@ mod tests {
 
             let glob_entity = app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ToxicGlobProjectile::new(Vec2::X, 30.0).with_puddle_count(4),
+                ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng()).with_puddle_count(4),
             )).id();
 
             // Enemy at same position (collision)
@@ -669,7 +726,7 @@ mod tests {
 
             app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ToxicGlobProjectile::new(Vec2::X, 30.0).with_puddle_count(4),
+                ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng()).with_puddle_count(4),
             ));
 
             // Enemy at same position (collision)
@@ -691,7 +748,7 @@ mod tests {
 
             let glob_entity = app.world_mut().spawn((
                 Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
-                ToxicGlobProjectile::new(Vec2::X, 30.0),
+                ToxicGlobProjectile::new(Vec2::X, 30.0, &mut rand::thread_rng()),
             )).id();
 
             // Enemy far away
@@ -885,6 +942,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -912,6 +970,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -941,6 +1000,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -968,6 +1028,7 @@ mod tests {
                     target_pos,
                     None,
                     None,
+                    &mut rand::thread_rng(),
                 );
             }
             app.update();
@@ -0,0 +1,433 @@
+//! Venom Bolt spell - A toxic projectile that poisons on contact.
+//!
+//! A Poison element spell (VenomBolt SpellType) that fires a fast projectile
+//! toward the target. On impact it deals poison damage and adds a poison stack
+//! to the enemy, sharing the stacking DOT with Venom Spray.
+
+use std::collections::HashSet;
+use bevy::prelude::*;
+use crate::collision::components::ObstacleProjectile;
+use crate::collision::spatial::SpatialEnemies;
+use crate::combat::DamageEvent;
+use crate::element::Element;
+use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::movement::components::from_xz;
+use crate::spell::SpellType;
+use crate::spell::components::Spell;
+use crate::spells::poison::venom_spray::PoisonStack;
+use crate::spell::SpellCastContext;
+
+/// Default configuration for Venom Bolt spell
+pub const VENOM_BOLT_SPEED: f32 = 22.0;
+pub const VENOM_BOLT_LIFETIME: f32 = 4.0;
+pub const VENOM_BOLT_SPREAD_ANGLE: f32 = 12.0;
+pub const VENOM_BOLT_COLLISION_RADIUS: f32 = 1.0;
+
+/// Get the poison element color for visual effects
+pub fn venom_bolt_color() -> Color {
+    Element::Poison.color()
+}
+
+/// VenomBoltProjectile component - a projectile that deals poison damage
+/// and applies a poison stack to the enemy it hits.
+#[derive(Component, Debug, Clone)]
+pub struct VenomBoltProjectile {
+    /// Direction of travel on XZ plane
+    pub direction: Vec2,
+    /// Speed in units per second
+    pub speed: f32,
+    /// Lifetime timer
+    pub lifetime: Timer,
+    /// Direct hit damage
+    pub damage: f32,
+}
+
+impl ObstacleProjectile for VenomBoltProjectile {
+    const SPELL_TYPE: SpellType = SpellType::VenomBolt;
+}
+
+impl VenomBoltProjectile {
+    pub fn new(direction: Vec2, speed: f32, lifetime_secs: f32, damage: f32) -> Self {
+        Self {
+            direction,
+            speed,
+            lifetime: Timer::from_seconds(lifetime_secs, TimerMode::Once),
+            damage,
+        }
+    }
+
+    pub fn from_spell(direction: Vec2, spell: &Spell) -> Self {
+        Self::new(direction, VENOM_BOLT_SPEED, VENOM_BOLT_LIFETIME, spell.damage())
+    }
+}
+
+/// Collision event for venom bolt hitting an enemy
+#[derive(Message)]
+pub struct VenomBoltEnemyCollisionEvent {
+    pub venom_bolt_entity: Entity,
+    pub enemy_entity: Entity,
+}
+
+/// System that moves venom bolt projectiles
+pub fn venom_bolt_movement_system(
+    mut venom_bolt_query: Query<(&mut Transform, &VenomBoltProjectile)>,
+    time: Res<Time>,
+) {
+    for (mut transform, venom_bolt) in venom_bolt_query.iter_mut() {
+        let movement = venom_bolt.direction * venom_bolt.speed * time.delta_secs();
+        // Movement on XZ plane: direction.x -> X axis, direction.y -> Z axis
+        transform.translation += Vec3::new(movement.x, 0.0, movement.y);
+    }
+}
+
+/// System that handles venom bolt lifetime
+pub fn venom_bolt_lifetime_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut venom_bolt_query: Query<(Entity, &mut VenomBoltProjectile)>,
+) {
+    for (entity, mut venom_bolt) in venom_bolt_query.iter_mut() {
+        venom_bolt.lifetime.tick(time.delta());
+
+        if venom_bolt.lifetime.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// System that detects venom bolt-enemy collisions and fires events
+pub fn venom_bolt_collision_detection(
+    venom_bolt_query: Query<(Entity, &Transform), With<VenomBoltProjectile>>,
+    spatial_enemies: SpatialEnemies,
+    mut collision_events: MessageWriter<VenomBoltEnemyCollisionEvent>,
+) {
+    for (venom_bolt_entity, venom_bolt_transform) in venom_bolt_query.iter() {
+        let venom_bolt_xz = from_xz(venom_bolt_transform.translation);
+
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(venom_bolt_xz, VENOM_BOLT_COLLISION_RADIUS) {
+            let enemy_xz = from_xz(enemy_transform.translation);
+
+            if venom_bolt_xz.distance(enemy_xz) < VENOM_BOLT_COLLISION_RADIUS {
+                collision_events.write(VenomBoltEnemyCollisionEvent {
+                    venom_bolt_entity,
+                    enemy_entity,
+                });
+                break; // Only hit one enemy per venom bolt
+            }
+        }
+    }
+}
+
+/// System that applies effects when venom bolts collide with enemies.
+/// Sends a poison DamageEvent and adds a poison stack to the enemy.
+pub fn venom_bolt_collision_effects(
+    mut commands: Commands,
+    mut collision_events: MessageReader<VenomBoltEnemyCollisionEvent>,
+    venom_bolt_query: Query<&VenomBoltProjectile>,
+    mut poison_query: Query<&mut PoisonStack>,
+    mut damage_events: MessageWriter<DamageEvent>,
+) {
+    let mut bolts_to_despawn = HashSet::new();
+    let mut hits: Vec<(Entity, f32)> = Vec::new();
+
+    for event in collision_events.read() {
+        // A bolt can only deliver its payload once
+        if !bolts_to_despawn.insert(event.venom_bolt_entity) {
+            continue;
+        }
+
+        if let Ok(venom_bolt) = venom_bolt_query.get(event.venom_bolt_entity) {
+            hits.push((event.enemy_entity, venom_bolt.damage));
+        }
+    }
+
+    for bolt_entity in bolts_to_despawn {
+        commands.entity(bolt_entity).try_despawn();
+    }
+
+    for (enemy_entity, damage) in hits {
+        damage_events.write(
+            DamageEvent::with_element(enemy_entity, damage, Element::Poison)
+                .by_spell(SpellType::VenomBolt),
+        );
+
+        // Add a poison stack, or start a new one
+        if let Ok(mut stack) = poison_query.get_mut(enemy_entity) {
+            stack.add_stack();
+        } else {
+            commands
+                .entity(enemy_entity)
+                .try_insert(PoisonStack::new().by_spell(SpellType::VenomBolt));
+        }
+    }
+}
+
+/// Cast venom bolt spell - spawns poison projectiles toward the target
+/// `spawn_position` is Whisper's full 3D position, `target_pos` is enemy position on XZ plane
+pub fn fire_venom_bolt(
+    commands: &mut Commands,
+    spell: &Spell,
+    spawn_position: Vec3,
+    target_pos: Vec2,
+    game_meshes: Option<&GameMeshes>,
+    game_materials: Option<&GameMaterials>,
+) {
+    fire_venom_bolt_with_damage(
+        commands,
+        spell,
+        spell.damage(),
+        spawn_position,
+        target_pos,
+        game_meshes,
+        game_materials,
+    );
+}
+
+/// Cast venom bolt spell with explicit damage - spawns poison projectiles toward the target
+/// `spawn_position` is Whisper's full 3D position, `target_pos` is enemy position on XZ plane
+/// `damage` is the pre-calculated final damage (including attunement multiplier)
+#[allow(clippy::too_many_arguments)]
+pub fn fire_venom_bolt_with_damage(
+    commands: &mut Commands,
+    spell: &Spell,
+    damage: f32,
+    spawn_position: Vec3,
+    target_pos: Vec2,
+    game_meshes: Option<&GameMeshes>,
+    game_materials: Option<&GameMaterials>,
+) {
+    let spawn_xz = from_xz(spawn_position);
+    let base_direction = (target_pos - spawn_xz).normalize_or_zero();
+
+    // Get projectile count based on spell level (1 at level 1-4, 2 at 5-9, 3 at 10)
+    let projectile_count = spell.projectile_count().max(1);
+    let spread_angle_rad = VENOM_BOLT_SPREAD_ANGLE.to_radians();
+
+    for i in 0..projectile_count {
+        let angle_offset = if projectile_count == 1 {
+            0.0
+        } else {
+            let half_spread = (projectile_count - 1) as f32 / 2.0;
+            (i as f32 - half_spread) * spread_angle_rad
+        };
+
+        let cos_offset = angle_offset.cos();
+        let sin_offset = angle_offset.sin();
+        let direction = Vec2::new(
+            base_direction.x * cos_offset - base_direction.y * sin_offset,
+            base_direction.x * sin_offset + base_direction.y * cos_offset,
+        );
+
+        let venom_bolt = VenomBoltProjectile::new(
+            direction,
+            VENOM_BOLT_SPEED,
+            VENOM_BOLT_LIFETIME,
+            damage,
+        );
+
+        if let (Some(meshes), Some(materials)) = (game_meshes, game_materials) {
+            commands.spawn((
+                Mesh3d(meshes.bullet.clone()),
+                MeshMaterial3d(materials.poison_projectile.clone()),
+                Transform::from_translation(spawn_position),
+                venom_bolt,
+            ));
+        } else {
+            // Fallback for tests without mesh resources
+            commands.spawn((
+                Transform::from_translation(spawn_position),
+                venom_bolt,
+            ));
+        }
+    }
+}
+
+/// Cast handler registered for SpellType::VenomBolt.
+pub fn cast_venom_bolt(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_venom_bolt_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enemies::components::Enemy;
+    use std::time::Duration;
+    use bevy::app::App;
+    use bevy::ecs::system::RunSystemOnce;
+    use crate::spell::SpellType;
+
+    mod venom_bolt_projectile_tests {
+        use super::*;
+
+        #[test]
+        fn test_venom_bolt_projectile_new() {
+            let direction = Vec2::new(1.0, 0.0);
+            let venom_bolt = VenomBoltProjectile::new(direction, 22.0, 4.0, 15.0);
+
+            assert_eq!(venom_bolt.direction, direction);
+            assert_eq!(venom_bolt.speed, 22.0);
+            assert_eq!(venom_bolt.damage, 15.0);
+            assert_eq!(venom_bolt.lifetime.duration(), Duration::from_secs_f32(4.0));
+        }
+
+        #[test]
+        fn test_venom_bolt_from_spell() {
+            let spell = Spell::new(SpellType::VenomBolt);
+            let venom_bolt = VenomBoltProjectile::from_spell(Vec2::Y, &spell);
+
+            assert_eq!(venom_bolt.speed, VENOM_BOLT_SPEED);
+            assert_eq!(venom_bolt.damage, spell.damage());
+        }
+
+        #[test]
+        fn test_venom_bolt_uses_poison_element_color() {
+            assert_eq!(venom_bolt_color(), Element::Poison.color());
+        }
+    }
+
+    mod fire_venom_bolt_tests {
+        use super::*;
+
+        #[test]
+        fn test_fire_venom_bolt_spawns_projectile_toward_target() {
+            let mut app = App::new();
+            let spell = Spell::new(SpellType::VenomBolt);
+
+            {
+                let mut commands = app.world_mut().commands();
+                fire_venom_bolt(
+                    &mut commands,
+                    &spell,
+                    Vec3::new(0.0, 0.5, 0.0),
+                    Vec2::new(10.0, 0.0),
+                    None,
+                    None,
+                );
+            }
+            app.update();
+
+            let mut query = app.world_mut().query::<&VenomBoltProjectile>();
+            let bolts: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(bolts.len(), 1);
+            assert!(bolts[0].direction.x > 0.9, "Venom bolt should move toward target");
+        }
+
+        #[test]
+        fn test_fire_venom_bolt_spawns_more_projectiles_at_higher_levels() {
+            let mut app = App::new();
+            let mut spell = Spell::new(SpellType::VenomBolt);
+            spell.level = 5;
+
+            {
+                let mut commands = app.world_mut().commands();
+                fire_venom_bolt_with_damage(
+                    &mut commands,
+                    &spell,
+                    50.0,
+                    Vec3::new(0.0, 0.5, 0.0),
+                    Vec2::new(10.0, 0.0),
+                    None,
+                    None,
+                );
+            }
+            app.update();
+
+            let mut query = app.world_mut().query::<&VenomBoltProjectile>();
+            let bolts: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(bolts.len(), 2);
+            for bolt in bolts {
+                assert_eq!(bolt.damage, 50.0);
+            }
+        }
+    }
+
+    mod venom_bolt_system_tests {
+        use super::*;
+
+        #[test]
+        fn test_venom_bolt_movement_on_xz_plane() {
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin::default());
+
+            let entity = app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+                VenomBoltProjectile::new(Vec2::new(0.0, 1.0), 20.0, 4.0, 10.0),
+            )).id();
+
+            {
+                let mut time = app.world_mut().get_resource_mut::<Time>().unwrap();
+                time.advance_by(Duration::from_secs(1));
+            }
+
+            let _ = app.world_mut().run_system_once(venom_bolt_movement_system);
+
+            let transform = app.world().get::<Transform>(entity).unwrap();
+            assert_eq!(transform.translation, Vec3::new(0.0, 0.5, 20.0));
+        }
+
+        #[test]
+        fn test_venom_bolt_despawns_after_lifetime() {
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin::default());
+
+            let entity = app.world_mut().spawn((
+                Transform::default(),
+                VenomBoltProjectile::new(Vec2::X, 20.0, 4.0, 10.0),
+            )).id();
+
+            {
+                let mut time = app.world_mut().get_resource_mut::<Time>().unwrap();
+                time.advance_by(Duration::from_secs(5));
+            }
+
+            let _ = app.world_mut().run_system_once(venom_bolt_lifetime_system);
+            app.update();
+
+            assert!(app.world().get_entity(entity).is_err());
+        }
+
+        #[test]
+        fn test_venom_bolt_hit_deals_poison_damage_and_applies_stack() {
+            let mut app = App::new();
+            app.add_message::<VenomBoltEnemyCollisionEvent>();
+            app.add_message::<DamageEvent>();
+            app.add_systems(
+                Update,
+                (venom_bolt_collision_detection, venom_bolt_collision_effects).chain(),
+            );
+
+            let enemy = app.world_mut().spawn((
+                Enemy { speed: 2.0, strength: 10.0 },
+                Transform::from_translation(Vec3::new(0.5, 0.375, 0.0)),
+            )).id();
+            let bolt = app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+                VenomBoltProjectile::new(Vec2::X, 20.0, 4.0, 12.0),
+            )).id();
+
+            app.update();
+
+            assert!(app.world().get_entity(bolt).is_err(), "Bolt should despawn on hit");
+            assert!(
+                app.world().get::<PoisonStack>(enemy).is_some(),
+                "Enemy should be poisoned"
+            );
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let mut cursor = messages.get_cursor();
+            let events: Vec<_> = cursor.read(messages).collect();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].target, enemy);
+            assert_eq!(events[0].amount, 12.0);
+            assert!(events[0].is_poison());
+        }
+    }
+}
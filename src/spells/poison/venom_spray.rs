@@ -6,12 +6,14 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Venom Spray spell
 pub const VENOM_SPRAY_CONE_ANGLE: f32 = 60.0; // degrees
@@ -114,6 +116,8 @@ pub struct PoisonStack {
     pub tick_timer: Timer,
     /// Duration before stacks expire
     pub duration: Timer,
+    /// Spell that applied the first stack, credited with the ticks
+    pub spell: Option<SpellType>,
 }
 
 impl PoisonStack {
@@ -124,9 +128,16 @@ impl PoisonStack {
             damage_per_stack: POISON_STACK_DAMAGE_PER_STACK,
             tick_timer: Timer::from_seconds(POISON_STACK_TICK_INTERVAL, TimerMode::Repeating),
             duration: Timer::from_seconds(POISON_STACK_DURATION, TimerMode::Once),
+            spell: None,
         }
     }
 
+    /// Attribute the poison ticks to the spell that applied the stack
+    pub fn by_spell(mut self, spell: SpellType) -> Self {
+        self.spell = Some(spell);
+        self
+    }
+
     /// Add a stack, capped at max_stacks
     pub fn add_stack(&mut self) {
         if self.stacks < self.max_stacks {
@@ -168,12 +179,12 @@ impl Default for PoisonStack {
 pub fn venom_spray_hit_detection(
     mut commands: Commands,
     mut cone_query: Query<&mut VenomSprayCone>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut poison_query: Query<&mut PoisonStack>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut cone in cone_query.iter_mut() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(cone.origin, cone.range) {
             let enemy_pos = from_xz(enemy_transform.translation);
 
             if cone.can_damage(enemy_entity, enemy_pos) {
@@ -181,17 +192,18 @@ pub fn venom_spray_hit_detection(
                 cone.mark_hit(enemy_entity);
 
                 // Apply initial damage
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    cone.base_damage,
-                    Element::Poison,
-                ));
+                damage_events.write(
+                    DamageEvent::with_element(enemy_entity, cone.base_damage, Element::Poison)
+                        .by_spell(SpellType::ToxicSpray),
+                );
 
                 // Apply or add poison stack
                 if let Ok(mut existing_stack) = poison_query.get_mut(enemy_entity) {
                     existing_stack.add_stack();
                 } else {
-                    commands.entity(enemy_entity).insert(PoisonStack::new());
+                    commands
+                        .entity(enemy_entity)
+                        .insert(PoisonStack::new().by_spell(SpellType::ToxicSpray));
                 }
             }
         }
@@ -209,7 +221,11 @@ pub fn poison_stack_damage_tick(
 
         if stack.should_damage() {
             let damage = stack.tick_damage();
-            damage_events.write(DamageEvent::with_element(entity, damage, Element::Poison));
+            damage_events.write(
+                DamageEvent::with_element(entity, damage, Element::Poison)
+                    .over_time()
+                    .by_spell(stack.spell),
+            );
         }
     }
 }
@@ -295,9 +311,23 @@ pub fn fire_venom_spray_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::ToxicSpray.
+pub fn cast_venom_spray(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_venom_spray_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use bevy::ecs::system::RunSystemOnce;
     use crate::spell::SpellType;
@@ -6,11 +6,14 @@
 
 use bevy::prelude::*;
 
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::events::EnemyDeathEvent;
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
+use crate::spell::SpellCastContext;
 
 /// Default configuration for Virulence spell
 pub const VIRULENCE_DEFAULT_DAMAGE: f32 = 10.0;
@@ -138,7 +141,7 @@ pub fn spread_virulent_poison_on_death(
     mut commands: Commands,
     death_events: Option<MessageReader<EnemyDeathEvent>>,
     virulent_query: Query<&VirulentPoison>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     let Some(mut death_events) = death_events else {
@@ -165,7 +168,7 @@ pub fn spread_virulent_poison_on_death(
         let death_pos = from_xz(event.position);
 
         // Find nearby enemies and spread poison to them
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(death_pos, virulent.spread_radius) {
             // Don't spread to the dead enemy itself (entity may still exist briefly)
             if enemy_entity == event.enemy_entity {
                 continue;
@@ -181,16 +184,55 @@ pub fn spread_virulent_poison_on_death(
                     .try_insert(spread_poison.clone());
 
                 // Also deal poison damage to trigger other poison effects
-                damage_events.write(DamageEvent::with_element(
-                    enemy_entity,
-                    spread_poison.spread_damage,
-                    Element::Poison,
-                ));
+                damage_events.write(
+                    DamageEvent::with_element(
+                        enemy_entity,
+                        spread_poison.spread_damage,
+                        Element::Poison,
+                    )
+                    .by_spell(SpellType::Pandemic),
+                );
             }
         }
     }
 }
 
+/// Cast Pandemic spell - marks the target with virulent poison and deals poison damage.
+/// The marker is applied directly so the cast starts a chain even before the
+/// damage event is processed.
+/// `damage` is the pre-calculated final damage (including attunement multiplier)
+pub fn fire_virulence_with_damage(
+    commands: &mut Commands,
+    damage: f32,
+    target_entity: Entity,
+    damage_events: &mut MessageWriter<DamageEvent>,
+) {
+    commands.entity(target_entity).try_insert(VirulentPoison::new(
+        damage,
+        VIRULENCE_DEFAULT_DURATION,
+        VIRULENCE_DEFAULT_SPREAD_RADIUS,
+        0,
+        VIRULENCE_MAX_CHAIN_DEPTH,
+    ));
+    damage_events.write(
+        DamageEvent::with_element(target_entity, damage, Element::Poison)
+            .by_spell(SpellType::Pandemic),
+    );
+}
+
+/// Cast handler registered for SpellType::Pandemic.
+pub fn cast_virulence(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Virulence marks the target so its poison spreads on death
+    if let Some(events) = ctx.damage_events.as_deref_mut() {
+        fire_virulence_with_damage(
+            commands,
+            ctx.damage,
+            ctx.target_entity,
+            events,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -568,6 +610,7 @@ mod tests {
                 enemy_entity: dying_enemy,
                 position: Vec3::new(0.0, 0.0, 0.0),
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             let _ = app
@@ -616,6 +659,7 @@ mod tests {
                 enemy_entity: dying_enemy,
                 position: Vec3::new(0.0, 0.0, 0.0),
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             let _ = app
@@ -664,6 +708,7 @@ mod tests {
                 enemy_entity: dying_enemy,
                 position: Vec3::new(0.0, 0.0, 0.0),
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             let _ = app
@@ -711,6 +756,7 @@ mod tests {
                 enemy_entity: dying_enemy,
                 position: Vec3::new(0.0, 0.0, 0.0),
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             let _ = app
@@ -757,6 +803,7 @@ mod tests {
                 enemy_entity: dying_enemy,
                 position: Vec3::new(0.0, 0.0, 0.0),
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             let _ = app
@@ -825,6 +872,7 @@ mod tests {
                 enemy_entity: dying_enemy,
                 position: Vec3::new(0.0, 0.0, 0.0),
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             let _ = app
@@ -879,6 +927,7 @@ mod tests {
                 enemy_entity: dying_enemy,
                 position: Vec3::new(0.0, 0.0, 0.0),
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             let _ = app
@@ -925,6 +974,7 @@ mod tests {
                 enemy_entity: dying_enemy,
                 position: Vec3::new(0.0, 0.0, 0.0),
                 enemy_level: 1,
+                affix_count: 0,
             });
 
             let _ = app
@@ -940,4 +990,39 @@ mod tests {
             );
         }
     }
+
+    mod fire_virulence_tests {
+        use super::*;
+
+        #[test]
+        fn test_fire_virulence_marks_target_and_deals_poison_damage() {
+            let mut app = App::new();
+            app.add_message::<DamageEvent>();
+
+            let enemy = app
+                .world_mut()
+                .spawn(Enemy {
+                    speed: 50.0,
+                    strength: 10.0,
+                })
+                .id();
+
+            let _ = app.world_mut().run_system_once(
+                move |mut commands: Commands, mut events: MessageWriter<DamageEvent>| {
+                    fire_virulence_with_damage(&mut commands, 18.0, enemy, &mut events);
+                },
+            );
+
+            let virulent = app.world().get::<VirulentPoison>(enemy).unwrap();
+            assert_eq!(virulent.spread_damage, 18.0);
+            assert_eq!(virulent.chain_depth, 0);
+
+            let messages = app.world().resource::<Messages<DamageEvent>>();
+            let mut cursor = messages.get_cursor();
+            let events: Vec<_> = cursor.read(messages).collect();
+            assert_eq!(events.len(), 1);
+            assert!(events[0].is_poison());
+            assert_eq!(events[0].target, enemy);
+        }
+    }
 }
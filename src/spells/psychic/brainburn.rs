@@ -5,12 +5,15 @@
 //! longer they remain inside, accumulating stacks that increase damage.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default radius of the Brainburn aura
 pub const BRAINBURN_DEFAULT_RADIUS: f32 = 5.0;
@@ -181,37 +184,36 @@ pub fn apply_brainburn_stacks_system(
     mut commands: Commands,
     time: Res<Time>,
     mut aura_query: Query<(&Transform, &mut BrainburnAura)>,
-    mut enemy_query: Query<(Entity, &Transform, Option<&mut BrainburnStack>), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    mut stack_query: Query<(&Transform, &mut BrainburnStack), With<Enemy>>,
 ) {
     for (aura_transform, mut aura) in aura_query.iter_mut() {
         aura.stack_timer.tick(time.delta());
 
         let aura_pos = from_xz(aura_transform.translation);
 
-        for (enemy_entity, enemy_transform, stack_opt) in enemy_query.iter_mut() {
+        // Enemies with stacks track whether they are still inside
+        for (enemy_transform, mut stack) in stack_query.iter_mut() {
             let enemy_pos = from_xz(enemy_transform.translation);
-            let distance = aura_pos.distance(enemy_pos);
-            let in_aura = distance <= aura.radius;
-
-            match stack_opt {
-                Some(mut stack) => {
-                    stack.in_aura = in_aura;
-                    if in_aura && aura.stack_timer.just_finished() {
-                        stack.add_stack();
-                    }
-                }
-                None => {
-                    if in_aura {
-                        // First time entering aura - add BrainburnStack component
-                        let mut stack = BrainburnStack::with_max_stacks(aura.max_stacks);
-                        stack.in_aura = true;
-                        if aura.stack_timer.just_finished() {
-                            stack.add_stack();
-                        }
-                        commands.entity(enemy_entity).insert(stack);
-                    }
-                }
+            stack.in_aura = aura_pos.distance(enemy_pos) <= aura.radius;
+            if stack.in_aura && aura.stack_timer.just_finished() {
+                stack.add_stack();
+            }
+        }
+
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(aura_pos, aura.radius) {
+            let enemy_pos = from_xz(enemy_transform.translation);
+            if stack_query.contains(enemy_entity) || aura_pos.distance(enemy_pos) > aura.radius {
+                continue;
+            }
+
+            // First time entering aura - add BrainburnStack component
+            let mut stack = BrainburnStack::with_max_stacks(aura.max_stacks);
+            stack.in_aura = true;
+            if aura.stack_timer.just_finished() {
+                stack.add_stack();
             }
+            commands.entity(enemy_entity).insert(stack);
         }
     }
 }
@@ -230,7 +232,11 @@ pub fn tick_brainburn_damage_system(
             for (enemy_entity, stack) in stack_query.iter() {
                 if stack.stacks > 0 {
                     let damage = aura.damage_per_stack * stack.damage_multiplier();
-                    damage_events.write(DamageEvent::new(enemy_entity, damage));
+                    damage_events.write(
+                        DamageEvent::new(enemy_entity, damage)
+                            .over_time()
+                            .by_spell(SpellType::Confusion),
+                    );
                 }
             }
         }
@@ -333,6 +339,18 @@ pub fn fire_brainburn_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::Confusion.
+pub fn cast_brainburn(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_brainburn_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
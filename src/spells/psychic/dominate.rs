@@ -5,12 +5,15 @@
 //! brief duration, attacking other enemies before the effect wears off.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::PlayerPosition;
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default range within which Dominate can target enemies
 pub const DOMINATE_DEFAULT_RANGE: f32 = 10.0;
@@ -21,6 +24,9 @@ pub const DOMINATE_DEFAULT_DURATION: f32 = 5.0;
 /// Default damage the dominated enemy deals to other enemies per hit
 pub const DOMINATE_ALLY_DAMAGE: f32 = 15.0;
 
+/// Melee range within which dominated enemies attack other enemies
+pub const DOMINATE_ATTACK_RANGE: f32 = 3.0;
+
 /// Attack interval for dominated enemies
 pub const DOMINATE_ATTACK_INTERVAL: f32 = 1.0;
 
@@ -108,7 +114,8 @@ pub fn cast_dominate_system(
     mut commands: Commands,
     _player_position: Res<PlayerPosition>,
     dominate_query: Query<(Entity, &Transform, &DominateEffect)>,
-    mut enemy_query: Query<(Entity, &Transform, &Enemy), Without<DominatedEnemy>>,
+    spatial_enemies: SpatialEnemies,
+    enemy_query: Query<&Enemy, Without<DominatedEnemy>>,
 ) {
     for (dominate_entity, dominate_transform, effect) in dominate_query.iter() {
         let cast_pos = from_xz(dominate_transform.translation);
@@ -116,7 +123,10 @@ pub fn cast_dominate_system(
         // Find the nearest enemy within range that isn't already dominated
         let mut nearest_enemy: Option<(Entity, f32, f32, f32)> = None;
 
-        for (enemy_entity, enemy_transform, enemy) in enemy_query.iter_mut() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(cast_pos, effect.range) {
+            let Ok(enemy) = enemy_query.get(enemy_entity) else {
+                continue;
+            };
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = cast_pos.distance(enemy_pos);
 
@@ -146,7 +156,8 @@ pub fn cast_dominate_system(
 pub fn update_dominated_enemies_system(
     time: Res<Time>,
     mut dominated_query: Query<(Entity, &Transform, &mut DominatedEnemy)>,
-    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<DominatedEnemy>)>,
+    spatial_enemies: SpatialEnemies,
+    not_dominated_query: Query<(), (With<Enemy>, Without<DominatedEnemy>)>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for (dominated_entity, dominated_transform, mut dominated) in dominated_query.iter_mut() {
@@ -163,17 +174,16 @@ pub fn update_dominated_enemies_system(
             // Find the nearest non-dominated enemy to attack
             let mut nearest_target: Option<(Entity, f32)> = None;
 
-            for (enemy_entity, enemy_transform) in enemy_query.iter() {
-                // Skip self
-                if enemy_entity == dominated_entity {
+            for (enemy_entity, enemy_transform) in spatial_enemies.near(dominated_pos, DOMINATE_ATTACK_RANGE) {
+                // Skip self and other dominated enemies
+                if enemy_entity == dominated_entity || !not_dominated_query.contains(enemy_entity) {
                     continue;
                 }
 
                 let enemy_pos = from_xz(enemy_transform.translation);
                 let distance = dominated_pos.distance(enemy_pos);
 
-                // Attack range (use a reasonable melee range)
-                if distance <= 3.0
+                if distance <= DOMINATE_ATTACK_RANGE
                     && (nearest_target.is_none() || distance < nearest_target.as_ref().unwrap().1)
                 {
                     nearest_target = Some((enemy_entity, distance));
@@ -183,11 +193,10 @@ pub fn update_dominated_enemies_system(
             // Deal damage to nearest target
             if let Some((target_entity, _)) = nearest_target {
                 dominated.current_target = Some(target_entity);
-                damage_events.write(DamageEvent::with_source(
-                    target_entity,
-                    DOMINATE_ALLY_DAMAGE,
-                    dominated_entity,
-                ));
+                damage_events.write(
+                    DamageEvent::with_source(target_entity, DOMINATE_ALLY_DAMAGE, dominated_entity)
+                        .by_spell(SpellType::Dominate),
+                );
             } else {
                 dominated.current_target = None;
             }
@@ -198,36 +207,47 @@ pub fn update_dominated_enemies_system(
 /// System that makes dominated enemies move toward other enemies instead of the player.
 #[allow(clippy::type_complexity)]
 pub fn dominated_enemy_targeting_system(
-    mut dominated_query: Query<(&mut Transform, &Enemy, &DominatedEnemy)>,
-    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<DominatedEnemy>)>,
+    mut enemies: ParamSet<(
+        SpatialEnemies,
+        Query<(&mut Transform, &Enemy), With<DominatedEnemy>>,
+    )>,
+    dominated_entities: Query<Entity, (With<Enemy>, With<DominatedEnemy>)>,
     time: Res<Time>,
 ) {
-    for (mut dominated_transform, enemy, _dominated) in dominated_query.iter_mut() {
-        let dominated_pos = from_xz(dominated_transform.translation);
-
-        // Find the nearest non-dominated enemy to chase
-        let mut nearest_target: Option<Vec2> = None;
-        let mut nearest_distance = f32::MAX;
-
-        for (_enemy_entity, enemy_transform) in enemy_query.iter() {
-            let enemy_pos = from_xz(enemy_transform.translation);
-            let distance = dominated_pos.distance(enemy_pos);
-
-            if distance < nearest_distance {
-                nearest_distance = distance;
-                nearest_target = Some(enemy_pos);
+    // Find the nearest non-dominated enemy for each dominated one to chase.
+    // Every other dominated enemy may rank closer, so ask for one more than there are.
+    let dominated_count = dominated_entities.iter().count();
+    let mut chases: Vec<(Entity, Vec2)> = Vec::new();
+    {
+        let spatial_enemies = enemies.p0();
+        for dominated_entity in dominated_entities.iter() {
+            let Some(dominated_transform) = spatial_enemies.get(dominated_entity) else {
+                continue;
+            };
+            let dominated_pos = from_xz(dominated_transform.translation);
+            let nearest_target = spatial_enemies
+                .nearest_k(dominated_pos, dominated_count + 1)
+                .into_iter()
+                .find(|(enemy_entity, _)| !dominated_entities.contains(*enemy_entity));
+            if let Some((_, target_pos)) = nearest_target {
+                chases.push((dominated_entity, target_pos));
             }
         }
+    }
 
-        // Move toward the nearest enemy instead of the player
-        if let Some(target_pos) = nearest_target {
-            let direction = (target_pos - dominated_pos).normalize_or_zero();
-            let movement = direction * enemy.speed * time.delta_secs();
+    // Move toward the nearest enemy instead of the player
+    let mut dominated_query = enemies.p1();
+    for (dominated_entity, target_pos) in chases {
+        let Ok((mut dominated_transform, enemy)) = dominated_query.get_mut(dominated_entity) else {
+            continue;
+        };
+        let dominated_pos = from_xz(dominated_transform.translation);
+        let direction = (target_pos - dominated_pos).normalize_or_zero();
+        let movement = direction * enemy.speed * time.delta_secs();
 
-            // Apply movement on XZ plane
-            dominated_transform.translation.x += movement.x;
-            dominated_transform.translation.z += movement.y;
-        }
+        // Apply movement on XZ plane
+        dominated_transform.translation.x += movement.x;
+        dominated_transform.translation.z += movement.y;
     }
 }
 
@@ -260,6 +280,15 @@ pub fn fire_dominate(
     );
 }
 
+/// Cast handler registered for SpellType::Dominate.
+pub fn cast_dominate(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_dominate(
+        commands,
+        ctx.spell,
+        ctx.origin,
+    );
+}
+
 /// Cast Dominate spell with explicit configuration.
 #[allow(clippy::too_many_arguments)]
 pub fn fire_dominate_with_config(
@@ -605,6 +634,48 @@ mod tests {
                 "Dominated enemy should move toward other enemy (positive X)"
             );
         }
+
+        #[test]
+        fn test_dominated_enemy_skips_closer_dominated_enemies() {
+            use crate::collision::spatial::{rebuild_enemy_spatial_hash, EnemySpatialHash};
+
+            let mut app = App::new();
+            app.add_plugins(bevy::time::TimePlugin::default());
+            app.init_resource::<EnemySpatialHash>();
+
+            let dominated_entity = app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)),
+                Enemy { speed: 10.0, strength: 10.0 },
+                DominatedEnemy::new(10.0, 10.0, 10.0),
+            )).id();
+
+            // Another dominated enemy close by on negative X
+            app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(-1.0, 0.5, 0.0)),
+                Enemy { speed: 10.0, strength: 10.0 },
+                DominatedEnemy::new(10.0, 10.0, 10.0),
+            ));
+
+            // The only valid target, far away on positive X
+            app.world_mut().spawn((
+                Transform::from_translation(Vec3::new(30.0, 0.5, 0.0)),
+                Enemy { speed: 2.0, strength: 10.0 },
+            ));
+
+            let _ = app.world_mut().run_system_once(rebuild_enemy_spatial_hash);
+            {
+                let mut time = app.world_mut().get_resource_mut::<Time>().unwrap();
+                time.advance_by(Duration::from_secs(1));
+            }
+
+            let _ = app.world_mut().run_system_once(dominated_enemy_targeting_system);
+
+            let transform = app.world().get::<Transform>(dominated_entity).unwrap();
+            assert!(
+                transform.translation.x > 0.0,
+                "Dominated enemy should chase the free enemy, not its dominated neighbour"
+            );
+        }
     }
 
     mod cleanup_dominate_tests {
@@ -9,6 +9,7 @@ use crate::element::Element;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::spell::components::Spell;
 use crate::spell::SpellType;
+use crate::spell::SpellCastContext;
 
 /// Default delay before echo spawns (seconds)
 pub const ECHO_THOUGHT_DELAY: f32 = 0.5;
@@ -378,6 +379,17 @@ pub fn fire_echo_thought(
     );
 }
 
+/// Cast handler registered for SpellType::Hallucination.
+pub fn cast_echo_thought(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_echo_thought(
+        commands,
+        ctx.spell,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// Cast Echo Thought spell with explicit echo count.
 #[allow(clippy::too_many_arguments)]
 pub fn fire_echo_thought_with_echoes(
@@ -5,11 +5,13 @@
 //! the cage expires, allowing the player to control the battlefield.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default radius of the Mind Cage zone
 pub const MIND_CAGE_DEFAULT_RADIUS: f32 = 6.0;
@@ -111,14 +113,17 @@ pub fn mind_cage_duration_system(
 }
 
 /// System that marks enemies as caged when they enter a Mind Cage zone.
-#[allow(clippy::type_complexity)]
 pub fn mind_cage_capture_system(
     mut commands: Commands,
     cage_query: Query<(Entity, &MindCage)>,
-    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<CagedEnemy>)>,
+    spatial_enemies: SpatialEnemies,
+    caged_query: Query<(), With<CagedEnemy>>,
 ) {
     for (cage_entity, cage) in cage_query.iter() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(cage.center, cage.radius) {
+            if caged_query.contains(enemy_entity) {
+                continue;
+            }
             let enemy_pos = from_xz(enemy_transform.translation);
             if cage.contains(enemy_pos) {
                 commands.entity(enemy_entity).insert(CagedEnemy::new(cage_entity));
@@ -215,6 +220,18 @@ pub fn fire_mind_cage(
     );
 }
 
+/// Cast handler registered for SpellType::MindBlast.
+pub fn cast_mind_cage(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Mind Cage spawns at target position to trap enemies where they are
+    fire_mind_cage(
+        commands,
+        ctx.spell,
+        Vec3::new(ctx.target_pos.x, ctx.origin.y, ctx.target_pos.y),
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// Cast Mind Cage spell with explicit radius and duration.
 /// `spawn_position` is Whisper's full 3D position.
 #[allow(clippy::too_many_arguments)]
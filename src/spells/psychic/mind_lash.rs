@@ -6,12 +6,14 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Default height of mind lash above ground
 pub const MIND_LASH_DEFAULT_Y_HEIGHT: f32 = 0.5;
@@ -147,7 +149,7 @@ pub fn update_mind_lash_system(
 /// Damage is applied once per cast (not continuous like beams).
 pub fn mind_lash_collision_system(
     mut lash_query: Query<&mut MindLash>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut lash in lash_query.iter_mut() {
@@ -156,7 +158,7 @@ pub fn mind_lash_collision_system(
             continue;
         }
 
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.along(lash.start_pos, lash.start_pos + lash.direction * MIND_LASH_LENGTH, lash.width) {
             // Skip already hit enemies
             if lash.was_hit(enemy_entity) {
                 continue;
@@ -167,7 +169,10 @@ pub fn mind_lash_collision_system(
 
             // Check if enemy is within the lash bounds
             if lash.is_in_lash(enemy_pos) {
-                damage_events.write(DamageEvent::new(enemy_entity, lash.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, lash.damage)
+                        .by_spell(SpellType::MentalSpike),
+                );
                 lash.mark_hit(enemy_entity);
             }
         }
@@ -271,9 +276,23 @@ pub fn fire_mind_lash_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::MentalSpike.
+pub fn cast_mind_lash(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_mind_lash_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.target_pos,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
 
     mod mind_lash_component_tests {
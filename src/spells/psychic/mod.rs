@@ -1,3 +1,7 @@
+use bevy::prelude::App;
+
+use crate::spell::{SpellBehaviorAppExt, SpellType};
+
 pub mod brainburn;
 pub mod dominate;
 pub mod echo_thought;
@@ -15,3 +19,16 @@ pub use mind_lash::*;
 pub use psionic_burst::*;
 pub use psychic_scream::*;
 pub use synapse_shock::*;
+
+/// Registers cast handlers for Psychic spells.
+pub fn plugin(app: &mut App) {
+    app
+        .register_spell_behavior(SpellType::MindBlast, mind_cage::cast_mind_cage)
+        .register_spell_behavior(SpellType::Telekinesis, synapse_shock::cast_synapse_shock)
+        .register_spell_behavior(SpellType::PsychicWave, psionic_burst::cast_psionic_burst)
+        .register_spell_behavior(SpellType::Confusion, brainburn::cast_brainburn)
+        .register_spell_behavior(SpellType::MentalSpike, mind_lash::cast_mind_lash)
+        .register_spell_behavior(SpellType::Hallucination, echo_thought::cast_echo_thought)
+        .register_spell_behavior(SpellType::Dominate, dominate::cast_dominate)
+        .register_spell_behavior(SpellType::PsychicShatter, psychic_scream::cast_psychic_scream);
+}
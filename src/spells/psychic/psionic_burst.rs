@@ -6,12 +6,14 @@
 
 use std::collections::HashSet;
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
-use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Maximum radius the psionic burst will expand to
 pub const PSIONIC_BURST_MAX_RADIUS: f32 = 10.0;
@@ -100,16 +102,19 @@ pub fn psionic_burst_expansion_system(
 /// and applies damage to enemies as the wave passes through them.
 pub fn psionic_burst_collision_system(
     mut burst_query: Query<&mut PsionicBurstWave>,
-    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut burst in burst_query.iter_mut() {
-        for (enemy_entity, enemy_transform) in enemy_query.iter() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(burst.center, burst.current_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = burst.center.distance(enemy_pos);
 
             if burst.should_hit(enemy_entity, distance) {
-                damage_events.write(DamageEvent::new(enemy_entity, burst.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, burst.damage)
+                        .by_spell(SpellType::PsychicWave),
+                );
                 burst.mark_hit(enemy_entity);
             }
         }
@@ -190,9 +195,22 @@ pub fn fire_psionic_burst_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::PsychicWave.
+pub fn cast_psionic_burst(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_psionic_burst_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enemies::components::Enemy;
     use std::time::Duration;
     use crate::spell::SpellType;
 
@@ -5,14 +5,17 @@
 //! applying a disorientation effect that causes them to move erratically.
 
 use bevy::prelude::*;
+use crate::collision::spatial::SpatialEnemies;
 use crate::combat::DamageEvent;
 use crate::element::Element;
 use crate::enemies::components::Enemy;
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::movement::components::from_xz;
+use crate::spell::SpellType;
 use crate::spell::components::Spell;
 use rand::Rng;
 use std::collections::HashSet;
+use crate::spell::SpellCastContext;
 
 /// Radius of the psychic scream AOE burst
 pub const PSYCHIC_SCREAM_RADIUS: f32 = 12.0;
@@ -141,8 +144,7 @@ impl DisorientedEnemy {
     }
 
     /// Generate a new random jitter offset.
-    pub fn randomize_offset(&mut self) {
-        let mut rng = rand::thread_rng();
+    pub fn randomize_offset(&mut self, rng: &mut impl Rng) {
         let angle = rng.gen_range(0.0..std::f32::consts::TAU);
         self.current_offset = Vec2::new(angle.cos(), angle.sin()) * self.jitter;
     }
@@ -168,20 +170,24 @@ pub fn psychic_scream_expansion_system(
 pub fn psychic_scream_collision_system(
     mut commands: Commands,
     mut burst_query: Query<&mut PsychicScreamBurst>,
-    mut enemy_query: Query<(Entity, &Transform, Option<&mut DisorientedEnemy>), With<Enemy>>,
+    spatial_enemies: SpatialEnemies,
+    mut disoriented_query: Query<&mut DisorientedEnemy, With<Enemy>>,
     mut damage_events: MessageWriter<DamageEvent>,
 ) {
     for mut burst in burst_query.iter_mut() {
-        for (enemy_entity, enemy_transform, existing_disorientation) in enemy_query.iter_mut() {
+        for (enemy_entity, enemy_transform) in spatial_enemies.near(burst.center, burst.current_radius) {
             let enemy_pos = from_xz(enemy_transform.translation);
             let distance = burst.center.distance(enemy_pos);
 
             if burst.should_hit(enemy_entity, distance) {
                 // Apply damage
-                damage_events.write(DamageEvent::new(enemy_entity, burst.damage));
+                damage_events.write(
+                    DamageEvent::new(enemy_entity, burst.damage)
+                        .by_spell(SpellType::PsychicShatter),
+                );
 
                 // Apply or refresh disorientation
-                if let Some(mut disoriented) = existing_disorientation {
+                if let Ok(mut disoriented) = disoriented_query.get_mut(enemy_entity) {
                     disoriented.refresh(burst.disorientation_duration);
                 } else {
                     commands.entity(enemy_entity).try_insert(
@@ -199,12 +205,13 @@ pub fn psychic_scream_collision_system(
 pub fn update_disoriented_enemies_system(
     mut query: Query<&mut DisorientedEnemy>,
     time: Res<Time>,
+    mut rng: ResMut<GameRng>,
 ) {
     for mut disoriented in query.iter_mut() {
         disoriented.tick(time.delta());
 
         if disoriented.should_change_direction() {
-            disoriented.randomize_offset();
+            disoriented.randomize_offset(&mut *rng);
         }
     }
 }
@@ -312,6 +319,19 @@ pub fn fire_psychic_scream_with_damage(
     }
 }
 
+/// Cast handler registered for SpellType::PsychicShatter.
+pub fn cast_psychic_scream(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    // Psychic Scream creates a large AOE burst that damages and disorients enemies
+    fire_psychic_scream_with_damage(
+        commands,
+        ctx.spell,
+        ctx.damage,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -512,7 +532,7 @@ mod tests {
             let mut disoriented = DisorientedEnemy::new(5.0);
             assert_eq!(disoriented.current_offset, Vec2::ZERO);
 
-            disoriented.randomize_offset();
+            disoriented.randomize_offset(&mut rand::thread_rng());
 
             // After randomizing, offset should have magnitude approximately equal to jitter
             let magnitude = disoriented.current_offset.length();
@@ -529,7 +549,7 @@ mod tests {
             let mut offsets = Vec::new();
 
             for _ in 0..5 {
-                disoriented.randomize_offset();
+                disoriented.randomize_offset(&mut rand::thread_rng());
                 offsets.push(disoriented.current_offset);
             }
 
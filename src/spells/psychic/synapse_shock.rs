@@ -11,6 +11,7 @@ use crate::enemies::components::Enemy;
 use crate::game::resources::{GameMaterials, GameMeshes};
 use crate::movement::components::from_xz;
 use crate::spell::components::Spell;
+use crate::spell::SpellCastContext;
 
 /// Maximum radius the synapse shock burst will expand to
 pub const SYNAPSE_SHOCK_MAX_RADIUS: f32 = 8.0;
@@ -316,6 +317,17 @@ pub fn fire_synapse_shock(
     );
 }
 
+/// Cast handler registered for SpellType::Telekinesis.
+pub fn cast_synapse_shock(commands: &mut Commands, ctx: &mut SpellCastContext) {
+    fire_synapse_shock(
+        commands,
+        ctx.spell,
+        ctx.origin,
+        ctx.game_meshes,
+        ctx.game_materials,
+    );
+}
+
 /// Cast synapse shock spell with explicit stun duration.
 /// `spawn_position` is Whisper's full 3D position.
 #[allow(clippy::too_many_arguments)]
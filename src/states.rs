@@ -10,6 +10,12 @@ pub enum GameState {
     LevelComplete,
     GameOver,
     Paused,
+    /// Rebinding screen opened from the pause menu
+    Controls,
+    /// Game paused while the player picks a level-up reward
+    LevelUpChoice,
+    /// Meta shop opened from the intro menu
+    MetaShop,
     /// Visual test mode - effect systems run but no game entities spawn
     VisualTest,
 }
@@ -61,6 +67,9 @@ mod tests {
             GameState::LevelComplete,
             GameState::GameOver,
             GameState::Paused,
+            GameState::Controls,
+            GameState::LevelUpChoice,
+            GameState::MetaShop,
             GameState::VisualTest,
         ];
         // Check all pairs are distinct
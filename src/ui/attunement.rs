@@ -2,7 +2,9 @@ use bevy::prelude::*;
 
 use crate::element::Element;
 use crate::inventory::SpellList;
+use crate::profile::resources::{MetaUpgrade, RunUpgrades};
 use crate::spell::{Spell, SpellType};
+use crate::spell::definitions::SpellCatalog;
 use crate::states::GameState;
 use crate::whisper::WhisperAttunement;
 
@@ -21,6 +23,17 @@ pub struct AttunementOption {
     pub element: Element,
 }
 
+/// Spells equipped when the player attunes: Fireball, then `extra` of the
+/// attuned element's most basic spells.
+pub fn starting_spells(element: Element, extra: u32) -> Vec<SpellType> {
+    let extras = SpellType::all()
+        .iter()
+        .filter(|spell_type| spell_type.element() == element && **spell_type != SpellType::Fireball)
+        .take(extra as usize)
+        .copied();
+    std::iter::once(SpellType::Fireball).chain(extras).collect()
+}
+
 /// Setup the attunement selection screen when entering AttunementSelect state.
 pub fn setup_attunement_screen(
     mut commands: Commands,
@@ -168,8 +181,14 @@ pub fn handle_attunement_selection(
     >,
     mut attunement: ResMut<WhisperAttunement>,
     mut spell_list: ResMut<SpellList>,
+    spell_catalog: Res<SpellCatalog>,
+    run_upgrades: Option<Res<RunUpgrades>>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
+    let extra_spells = run_upgrades
+        .map(|upgrades| upgrades.level(MetaUpgrade::StartingSpell))
+        .unwrap_or(0);
+
     for (interaction, mut bg_color, mut border_color, option) in &mut interaction_query {
         let element_color = option.element.color();
 
@@ -178,9 +197,10 @@ pub fn handle_attunement_selection(
                 // Set attunement and transition to game
                 attunement.set_element(option.element);
 
-                // Add default Fireball spell after attunement is selected
-                let fireball = Spell::new(SpellType::Fireball);
-                spell_list.equip(fireball);
+                // Add default Fireball spell (plus any meta upgrade extras) after attunement is selected
+                for spell_type in starting_spells(option.element, extra_spells) {
+                    spell_list.equip(Spell::from_catalog(spell_type, &spell_catalog));
+                }
 
                 next_state.set(GameState::InGame);
             }
@@ -225,6 +245,7 @@ mod tests {
         app.init_state::<GameState>();
         app.init_resource::<WhisperAttunement>();
         app.init_resource::<SpellList>();
+        app.init_resource::<SpellCatalog>();
         app
     }
 
@@ -437,6 +458,52 @@ mod tests {
         }
     }
 
+    mod starting_spells_tests {
+        use super::*;
+
+        #[test]
+        fn fireball_only_without_upgrades() {
+            assert_eq!(starting_spells(Element::Frost, 0), vec![SpellType::Fireball]);
+        }
+
+        #[test]
+        fn extras_come_from_attuned_element() {
+            let spells = starting_spells(Element::Frost, 2);
+            assert_eq!(spells.len(), 3);
+            assert_eq!(spells[0], SpellType::Fireball);
+            assert!(spells[1..].iter().all(|spell| spell.element() == Element::Frost));
+        }
+
+        #[test]
+        fn fire_attunement_skips_duplicate_fireball() {
+            let spells = starting_spells(Element::Fire, 1);
+            assert_eq!(spells, vec![SpellType::Fireball, SpellType::FlameLance]);
+        }
+
+        #[test]
+        fn selection_equips_extra_spells_from_run_upgrades() {
+            let mut app = setup_test_app();
+            let mut upgrades = RunUpgrades::default();
+            upgrades.0.insert(MetaUpgrade::StartingSpell, 1);
+            app.insert_resource(upgrades);
+            app.world_mut().spawn((
+                Button,
+                Interaction::Pressed,
+                BackgroundColor(Color::srgba(0.5, 0.5, 0.5, 0.6)),
+                BorderColor::all(Color::srgb(0.5, 0.5, 0.5)),
+                AttunementOption {
+                    element: Element::Lightning,
+                },
+            ));
+
+            app.add_systems(Update, handle_attunement_selection);
+            app.update();
+
+            let spell_list = app.world().resource::<SpellList>();
+            assert_eq!(spell_list.iter_spells().count(), 2);
+        }
+    }
+
     mod cleanup_attunement_screen_tests {
         use super::*;
 
@@ -0,0 +1,185 @@
+use bevy::prelude::*;
+
+use crate::boss::components::{boss_name, Boss};
+use crate::combat::Health;
+
+/// Width of the boss health bar in pixels
+pub const BOSS_HEALTH_BAR_WIDTH: f32 = 500.0;
+
+/// Root of a boss health bar (top center of the screen).
+/// Despawned once the boss it tracks is gone.
+#[derive(Component)]
+pub struct BossHealthBar {
+    pub boss: Entity,
+}
+
+/// Fill node of a boss health bar, resized to the boss's health percentage.
+#[derive(Component)]
+pub struct BossHealthBarFill {
+    pub boss: Entity,
+}
+
+/// Boss name label above the health bar.
+#[derive(Component)]
+pub struct BossNameText;
+
+/// Spawn a health bar for each boss that doesn't have one yet and despawn
+/// bars whose boss has died.
+pub fn sync_boss_health_bars(
+    mut commands: Commands,
+    boss_query: Query<(Entity, &Boss)>,
+    bar_query: Query<(Entity, &BossHealthBar)>,
+) {
+    for (bar_entity, bar) in bar_query.iter() {
+        if boss_query.get(bar.boss).is_err() {
+            commands.entity(bar_entity).despawn();
+        }
+    }
+
+    for (boss_entity, boss) in boss_query.iter() {
+        if bar_query.iter().any(|(_, bar)| bar.boss == boss_entity) {
+            continue;
+        }
+
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(20.0),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(-BOSS_HEALTH_BAR_WIDTH / 2.0)),
+                    width: Val::Px(BOSS_HEALTH_BAR_WIDTH),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BossHealthBar { boss: boss_entity },
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    Text::new(boss_name(boss.game_level)),
+                    TextFont {
+                        font_size: 26.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.3, 0.2)),
+                    BossNameText,
+                ));
+
+                // Bar background
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(18.0),
+                            margin: UiRect::top(Val::Px(5.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.2, 0.05, 0.05)),
+                    ))
+                    .with_children(|bar| {
+                        bar.spawn((
+                            Node {
+                                width: Val::Percent(100.0), // Will be updated by system
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.8, 0.1, 0.1)),
+                            BossHealthBarFill { boss: boss_entity },
+                        ));
+                    });
+            });
+    }
+}
+
+/// Resize boss health bar fills to match their boss's current health.
+pub fn update_boss_health_bars(
+    boss_query: Query<&Health, With<Boss>>,
+    mut fill_query: Query<(&mut Node, &BossHealthBarFill)>,
+) {
+    for (mut node, fill) in fill_query.iter_mut() {
+        if let Ok(health) = boss_query.get(fill.boss) {
+            node.width = Val::Percent(health.percentage() * 100.0);
+        }
+    }
+}
+
+/// Remove all boss health bars (e.g. when leaving the game).
+pub fn cleanup_boss_health_bars(
+    mut commands: Commands,
+    query: Query<Entity, With<BossHealthBar>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    mod boss_health_bar_tests {
+        use super::*;
+
+        #[test]
+        fn spawns_one_bar_per_boss() {
+            let mut app = App::new();
+            let boss = app.world_mut().spawn((Boss::new(1), Health::new(600.0))).id();
+
+            let _ = app.world_mut().run_system_once(sync_boss_health_bars);
+            let _ = app.world_mut().run_system_once(sync_boss_health_bars);
+
+            let mut query = app.world_mut().query::<&BossHealthBar>();
+            let bars: Vec<_> = query.iter(app.world()).collect();
+            assert_eq!(bars.len(), 1);
+            assert_eq!(bars[0].boss, boss);
+
+            let mut text_query = app.world_mut().query_filtered::<&Text, With<BossNameText>>();
+            let text = text_query.single(app.world()).unwrap();
+            assert_eq!(text.0, boss_name(1));
+        }
+
+        #[test]
+        fn despawns_bar_when_boss_dies() {
+            let mut app = App::new();
+            let boss = app.world_mut().spawn((Boss::new(1), Health::new(600.0))).id();
+            let _ = app.world_mut().run_system_once(sync_boss_health_bars);
+
+            app.world_mut().despawn(boss);
+            let _ = app.world_mut().run_system_once(sync_boss_health_bars);
+
+            let mut query = app.world_mut().query::<&BossHealthBar>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+            let mut fill_query = app.world_mut().query::<&BossHealthBarFill>();
+            assert_eq!(fill_query.iter(app.world()).count(), 0, "Children despawn with the bar");
+        }
+
+        #[test]
+        fn fill_tracks_boss_health() {
+            let mut app = App::new();
+            let mut health = Health::new(600.0);
+            health.take_damage(150.0);
+            app.world_mut().spawn((Boss::new(1), health));
+
+            let _ = app.world_mut().run_system_once(sync_boss_health_bars);
+            let _ = app.world_mut().run_system_once(update_boss_health_bars);
+
+            let mut query = app.world_mut().query_filtered::<&Node, With<BossHealthBarFill>>();
+            let node = query.single(app.world()).unwrap();
+            assert_eq!(node.width, Val::Percent(75.0));
+        }
+
+        #[test]
+        fn cleanup_removes_all_bars() {
+            let mut app = App::new();
+            app.world_mut().spawn((Boss::new(1), Health::new(600.0)));
+            let _ = app.world_mut().run_system_once(sync_boss_health_bars);
+
+            let _ = app.world_mut().run_system_once(cleanup_boss_health_bars);
+
+            let mut query = app.world_mut().query::<&BossHealthBar>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+        }
+    }
+}
@@ -24,6 +24,10 @@ pub struct StartGameButton;
 #[derive(Component)]
 pub struct ExitGameButton;
 
+/// Intro menu button opening the meta shop
+#[derive(Component)]
+pub struct MetaShopButton;
+
 #[derive(Component)]
 pub struct HealthDisplay;
 
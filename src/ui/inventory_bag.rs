@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+use crate::input::bindings::{ActionInput, InputAction};
 use crate::inventory::{InventoryBag, SpellList};
 use crate::spell::Spell;
 use crate::states::GameState;
@@ -351,23 +352,24 @@ pub fn cleanup_inventory_ui(
     drag_state.drag_visual_spawned = false;
 }
 
-/// Handle keyboard input for inventory screen (I and Escape to close).
+/// Handle input for inventory screen (Inventory or Pause action to close,
+/// I and Escape by default).
 pub fn handle_inventory_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyI) || keyboard.just_pressed(KeyCode::Escape) {
+    if input.just_pressed(InputAction::Inventory) || input.just_pressed(InputAction::Pause) {
         next_state.set(GameState::InGame);
     }
 }
 
-/// Handle I key to open inventory from InGame state.
+/// Handle the Inventory action (I by default) to open inventory from InGame state.
 pub fn handle_inventory_toggle(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     current_state: Res<State<GameState>>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyI) && current_state.get() == &GameState::InGame {
+    if input.just_pressed(InputAction::Inventory) && current_state.get() == &GameState::InGame {
         next_state.set(GameState::InventoryOpen);
     }
 }
@@ -570,7 +572,7 @@ pub fn rebuild_spell_info_content(
 
                 // Description
                 panel.spawn((
-                    Text::new(spell.spell_type.description()),
+                    Text::new(spell.description.clone()),
                     TextFont {
                         font_size: 13.0,
                         ..default()
@@ -838,14 +840,14 @@ pub fn end_drag(
     }
 }
 
-/// Cancel drag if escape is pressed.
+/// Cancel drag if the Pause action (Escape by default) is pressed.
 pub fn cancel_drag_on_escape(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     mut drag_state: ResMut<DragState>,
     mut commands: Commands,
     drag_visual_query: Query<Entity, With<DragVisual>>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
+    if input.just_pressed(InputAction::Pause) {
         // Clear all drag state
         drag_state.dragging = None;
         drag_state.drag_start_time = None;
@@ -861,6 +863,7 @@ pub fn cancel_drag_on_escape(
 mod tests {
     use super::*;
     use bevy::ecs::system::RunSystemOnce;
+    use crate::input::bindings::InputMap;
     use crate::spell::SpellType;
 
     fn setup_test_app() -> App {
@@ -1155,6 +1158,7 @@ mod tests {
             let mut app = setup_test_app();
             // Initialize keyboard resource to track input properly
             app.init_resource::<ButtonInput<KeyCode>>();
+            app.init_resource::<InputMap>();
             app
         }
 
@@ -1288,6 +1292,7 @@ mod tests {
             let mut app = setup_test_app();
             // Initialize keyboard resource to track input properly
             app.init_resource::<ButtonInput<KeyCode>>();
+            app.init_resource::<InputMap>();
             app
         }
 
@@ -0,0 +1,522 @@
+use bevy::prelude::*;
+
+use crate::experience::components::PlayerExperience;
+use crate::game::resources::GameRng;
+use crate::input::bindings::{ActionInput, InputAction, InputMap};
+use crate::inventory::bag::InventoryBag;
+use crate::inventory::resources::SpellList;
+use crate::level_up::components::*;
+use crate::level_up::resources::*;
+use crate::level_up::systems::{apply_level_up_reward, roll_level_up_choices};
+use crate::player::components::Player;
+use crate::powerup::components::ActivePowerups;
+use crate::spell::definitions::SpellCatalog;
+use crate::states::GameState;
+use crate::whisper::resources::WhisperAttunement;
+
+/// Card background when idle / hovered
+const CARD_COLOR: Color = Color::srgba(0.1, 0.1, 0.15, 0.95);
+const CARD_HOVER_COLOR: Color = Color::srgba(0.2, 0.2, 0.3, 0.95);
+
+/// Actions that pick the first, second and third choice
+const CHOICE_ACTIONS: [InputAction; 3] = [
+    InputAction::ChooseFirst,
+    InputAction::ChooseSecond,
+    InputAction::ChooseThird,
+];
+
+/// Setup the level-up choice screen when entering LevelUpChoice state.
+pub fn setup_level_up_choice_screen(
+    mut commands: Commands,
+    choices: Res<LevelUpChoices>,
+    reroll_tokens: Option<Res<RerollTokens>>,
+    player_query: Query<&PlayerExperience, With<Player>>,
+    input_map: Res<InputMap>,
+    spell_catalog: Res<SpellCatalog>,
+) {
+    let tokens = reroll_tokens.map(|tokens| tokens.0).unwrap_or(0);
+    spawn_level_up_choice_screen(
+        &mut commands,
+        &choices,
+        level_up_title(&player_query),
+        tokens,
+        &input_map,
+        &spell_catalog,
+    );
+}
+
+fn level_up_title(player_query: &Query<&PlayerExperience, With<Player>>) -> String {
+    match player_query.single() {
+        Ok(experience) => format!("Level {}!", experience.level),
+        Err(_) => "Level Up!".to_string(),
+    }
+}
+
+/// Label of the first binding for `action`, e.g. "1", shown as a key hint
+fn binding_hint(input_map: &InputMap, action: InputAction) -> Option<String> {
+    input_map.bindings(action).iter().next().map(|binding| binding.label())
+}
+
+fn spawn_level_up_choice_screen(
+    commands: &mut Commands,
+    choices: &LevelUpChoices,
+    title: String,
+    reroll_tokens: u32,
+    input_map: &InputMap,
+    spell_catalog: &SpellCatalog,
+) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            LevelUpChoiceScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(title),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.84, 0.0)), // Gold
+            ));
+
+            parent.spawn((
+                Text::new("Choose an upgrade"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // Row of choice cards
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(20.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (index, choice) in choices.choices.iter().enumerate() {
+                        let rarity_color = choice.rarity.color();
+                        let rarity_label = match CHOICE_ACTIONS
+                            .get(index)
+                            .and_then(|action| binding_hint(input_map, *action))
+                        {
+                            Some(hint) => format!("{}  [{}]", choice.rarity.name(), hint),
+                            None => choice.rarity.name().to_string(),
+                        };
+                        let accent = choice
+                            .reward
+                            .element()
+                            .map(|element| element.color())
+                            .unwrap_or(rarity_color);
+
+                        row.spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(220.0),
+                                height: Val::Px(260.0),
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                padding: UiRect::all(Val::Px(15.0)),
+                                row_gap: Val::Px(10.0),
+                                border: UiRect::all(Val::Px(3.0)),
+                                ..default()
+                            },
+                            BackgroundColor(CARD_COLOR),
+                            BorderColor::all(rarity_color),
+                            BorderRadius::all(Val::Px(10.0)),
+                            LevelUpChoiceButton { index },
+                        ))
+                        .with_children(|card| {
+                            card.spawn((
+                                Text::new(rarity_label),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(rarity_color),
+                            ));
+                            card.spawn((
+                                Text::new(choice.reward.title(spell_catalog)),
+                                TextFont {
+                                    font_size: 22.0,
+                                    ..default()
+                                },
+                                TextColor(accent),
+                                TextLayout::new_with_justify(bevy::text::Justify::Center),
+                            ));
+                            card.spawn((
+                                Text::new(choice.reward.description(spell_catalog)),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.8)),
+                                TextLayout::new_with_justify(bevy::text::Justify::Center),
+                            ));
+                        });
+                    }
+                });
+
+            if reroll_tokens > 0 {
+                let reroll_label = match binding_hint(input_map, InputAction::Reroll) {
+                    Some(hint) => format!("Reroll ({}) - {} left", hint, reroll_tokens),
+                    None => format!("Reroll - {} left", reroll_tokens),
+                };
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            margin: UiRect::top(Val::Px(25.0)),
+                            padding: UiRect::axes(Val::Px(20.0), Val::Px(10.0)),
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(CARD_COLOR),
+                        BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.5)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        LevelUpRerollButton,
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new(reroll_label),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Pick a reward by clicking its card or pressing its choice binding (1-3 by
+/// default), then resume the game.
+/// Remaining pending level-ups reopen the screen on the next frame.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn handle_level_up_choice_selection(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &LevelUpChoiceButton),
+        Changed<Interaction>,
+    >,
+    input: ActionInput,
+    choices: Res<LevelUpChoices>,
+    mut pending: ResMut<PendingLevelUps>,
+    spell_list: Option<ResMut<SpellList>>,
+    inventory_bag: Option<ResMut<InventoryBag>>,
+    mut active_powerups: ResMut<ActivePowerups>,
+    spell_catalog: Res<SpellCatalog>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let mut selected = CHOICE_ACTIONS
+        .iter()
+        .position(|action| input.just_pressed(*action));
+
+    for (interaction, mut bg_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => selected = Some(button.index),
+            Interaction::Hovered => *bg_color = BackgroundColor(CARD_HOVER_COLOR),
+            Interaction::None => *bg_color = BackgroundColor(CARD_COLOR),
+        }
+    }
+
+    let Some(choice) = selected.and_then(|index| choices.choices.get(index)) else {
+        return;
+    };
+
+    if let Some(mut spell_list) = spell_list {
+        apply_level_up_reward(
+            &choice.reward,
+            &mut spell_list,
+            inventory_bag.map(|bag| bag.into_inner()),
+            &mut active_powerups,
+            &spell_catalog,
+        );
+    }
+
+    pending.0 = pending.0.saturating_sub(1);
+    next_state.set(GameState::InGame);
+}
+
+/// Spend a reroll token (the Reroll binding or button) to replace the offered
+/// choices, rebuilding the screen around the new roll.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn handle_level_up_reroll(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<LevelUpRerollButton>),
+    >,
+    input: ActionInput,
+    mut reroll_tokens: ResMut<RerollTokens>,
+    mut choices: ResMut<LevelUpChoices>,
+    spell_list: Option<Res<SpellList>>,
+    inventory_bag: Option<Res<InventoryBag>>,
+    attunement: Option<Res<WhisperAttunement>>,
+    screen_query: Query<Entity, With<LevelUpChoiceScreen>>,
+    player_query: Query<&PlayerExperience, With<Player>>,
+    spell_catalog: Res<SpellCatalog>,
+    mut rng: ResMut<GameRng>,
+) {
+    let mut reroll = input.just_pressed(InputAction::Reroll);
+    for (interaction, mut bg_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => reroll = true,
+            Interaction::Hovered => *bg_color = BackgroundColor(CARD_HOVER_COLOR),
+            Interaction::None => *bg_color = BackgroundColor(CARD_COLOR),
+        }
+    }
+
+    if !reroll || reroll_tokens.0 == 0 {
+        return;
+    }
+    reroll_tokens.0 -= 1;
+
+    let empty_list = SpellList::default();
+    choices.choices = roll_level_up_choices(
+        spell_list.as_deref().unwrap_or(&empty_list),
+        inventory_bag.as_deref(),
+        attunement.and_then(|attunement| attunement.element()),
+        &mut *rng,
+    );
+
+    for entity in screen_query.iter() {
+        commands.entity(entity).despawn();
+    }
+    spawn_level_up_choice_screen(
+        &mut commands,
+        &choices,
+        level_up_title(&player_query),
+        reroll_tokens.0,
+        input.map(),
+        &spell_catalog,
+    );
+}
+
+/// Cleanup level-up choice screen entities when exiting the state.
+pub fn cleanup_level_up_choice_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<LevelUpChoiceScreen>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use crate::game::components::Rarity;
+    use crate::powerup::components::PowerupType;
+    use crate::spell::{Spell, SpellType};
+
+    fn setup_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.init_resource::<PendingLevelUps>();
+        app.init_resource::<ActivePowerups>();
+        app.init_resource::<SpellList>();
+        app.init_resource::<InventoryBag>();
+        app.init_resource::<SpellCatalog>();
+        app.init_resource::<GameRng>();
+        app.init_resource::<InputMap>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.insert_resource(LevelUpChoices {
+            choices: vec![
+                LevelUpChoice {
+                    reward: LevelUpReward::Powerup(PowerupType::MaxHealth),
+                    rarity: Rarity::Uncommon,
+                },
+                LevelUpChoice {
+                    reward: LevelUpReward::NewSpell(SpellType::IceShard),
+                    rarity: Rarity::Common,
+                },
+                LevelUpChoice {
+                    reward: LevelUpReward::SpellLevel { slot: 0, spell_type: SpellType::Fireball },
+                    rarity: Rarity::Common,
+                },
+            ],
+        });
+        app.world_mut()
+            .resource_mut::<SpellList>()
+            .equip(Spell::new(SpellType::Fireball));
+        app
+    }
+
+    mod level_up_screen_tests {
+        use super::*;
+
+        #[test]
+        fn setup_spawns_a_card_per_choice() {
+            let mut app = setup_app();
+            let _ = app.world_mut().run_system_once(setup_level_up_choice_screen);
+
+            let mut screen_query = app.world_mut().query::<&LevelUpChoiceScreen>();
+            assert_eq!(screen_query.iter(app.world()).count(), 1);
+            let mut button_query = app.world_mut().query::<&LevelUpChoiceButton>();
+            assert_eq!(button_query.iter(app.world()).count(), 3);
+        }
+
+        #[test]
+        fn cleanup_removes_screen() {
+            let mut app = setup_app();
+            let _ = app.world_mut().run_system_once(setup_level_up_choice_screen);
+            let _ = app.world_mut().run_system_once(cleanup_level_up_choice_screen);
+
+            let mut query = app.world_mut().query::<&LevelUpChoiceButton>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+        }
+
+        #[test]
+        fn reroll_button_only_shown_with_tokens() {
+            let mut app = setup_app();
+            let _ = app.world_mut().run_system_once(setup_level_up_choice_screen);
+            let mut query = app.world_mut().query::<&LevelUpRerollButton>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+
+            app.insert_resource(RerollTokens(1));
+            let _ = app.world_mut().run_system_once(cleanup_level_up_choice_screen);
+            let _ = app.world_mut().run_system_once(setup_level_up_choice_screen);
+            assert_eq!(query.iter(app.world()).count(), 1);
+        }
+    }
+
+    mod level_up_reroll_tests {
+        use super::*;
+
+        fn press_reroll_key(app: &mut App) {
+            let mut keyboard = ButtonInput::<KeyCode>::default();
+            keyboard.press(KeyCode::KeyE);
+            app.insert_resource(keyboard);
+        }
+
+        #[test]
+        fn reroll_spends_a_token_and_rebuilds_screen() {
+            let mut app = setup_app();
+            app.insert_resource(RerollTokens(2));
+            let _ = app.world_mut().run_system_once(setup_level_up_choice_screen);
+            press_reroll_key(&mut app);
+
+            let _ = app.world_mut().run_system_once(handle_level_up_reroll);
+
+            assert_eq!(app.world().resource::<RerollTokens>().0, 1);
+            assert_eq!(
+                app.world().resource::<LevelUpChoices>().choices.len(),
+                crate::level_up::systems::LEVEL_UP_CHOICE_COUNT
+            );
+            let mut screen_query = app.world_mut().query::<&LevelUpChoiceScreen>();
+            assert_eq!(screen_query.iter(app.world()).count(), 1);
+        }
+
+        #[test]
+        fn reroll_does_nothing_without_tokens() {
+            let mut app = setup_app();
+            app.insert_resource(RerollTokens(0));
+            press_reroll_key(&mut app);
+            let before = app.world().resource::<LevelUpChoices>().choices.clone();
+
+            let _ = app.world_mut().run_system_once(handle_level_up_reroll);
+
+            assert_eq!(app.world().resource::<RerollTokens>().0, 0);
+            assert_eq!(app.world().resource::<LevelUpChoices>().choices, before);
+        }
+    }
+
+    mod level_up_selection_tests {
+        use super::*;
+
+        #[test]
+        fn pressing_a_card_applies_reward_and_resumes() {
+            let mut app = setup_app();
+            app.world_mut().resource_mut::<PendingLevelUps>().0 = 2;
+            app.world_mut().spawn((
+                Button,
+                Interaction::Pressed,
+                BackgroundColor(CARD_COLOR),
+                LevelUpChoiceButton { index: 2 },
+            ));
+
+            let _ = app.world_mut().run_system_once(handle_level_up_choice_selection);
+            app.update();
+
+            let spell_list = app.world().resource::<SpellList>();
+            assert_eq!(spell_list.get_spell(0).unwrap().level, 2);
+            assert_eq!(app.world().resource::<PendingLevelUps>().0, 1);
+            let state = app.world().resource::<State<GameState>>();
+            assert_eq!(*state.get(), GameState::InGame);
+        }
+
+        #[test]
+        fn number_key_picks_choice() {
+            let mut app = setup_app();
+            app.world_mut().resource_mut::<PendingLevelUps>().0 = 1;
+            let mut keyboard = ButtonInput::<KeyCode>::default();
+            keyboard.press(KeyCode::Digit1);
+            app.insert_resource(keyboard);
+
+            let _ = app.world_mut().run_system_once(handle_level_up_choice_selection);
+
+            let powerups = app.world().resource::<ActivePowerups>();
+            assert_eq!(powerups.get_stack_count(&PowerupType::MaxHealth), 1);
+            assert_eq!(app.world().resource::<PendingLevelUps>().0, 0);
+        }
+
+        #[test]
+        fn rebound_choice_key_picks_choice() {
+            use crate::input::bindings::{Binding, BindingSlot};
+
+            let mut app = setup_app();
+            app.world_mut().resource_mut::<PendingLevelUps>().0 = 1;
+            app.world_mut()
+                .resource_mut::<InputMap>()
+                .rebind(InputAction::ChooseFirst, BindingSlot::Primary, Binding::Key(KeyCode::KeyQ))
+                .unwrap();
+            let mut keyboard = ButtonInput::<KeyCode>::default();
+            keyboard.press(KeyCode::Digit1);
+            app.insert_resource(keyboard);
+
+            let _ = app.world_mut().run_system_once(handle_level_up_choice_selection);
+            assert_eq!(app.world().resource::<PendingLevelUps>().0, 1, "Old key no longer picks");
+
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(KeyCode::KeyQ);
+            let _ = app.world_mut().run_system_once(handle_level_up_choice_selection);
+
+            let powerups = app.world().resource::<ActivePowerups>();
+            assert_eq!(powerups.get_stack_count(&PowerupType::MaxHealth), 1);
+            assert_eq!(app.world().resource::<PendingLevelUps>().0, 0);
+        }
+
+        #[test]
+        fn nothing_happens_without_input() {
+            let mut app = setup_app();
+            app.world_mut().resource_mut::<PendingLevelUps>().0 = 1;
+
+            let _ = app.world_mut().run_system_once(handle_level_up_choice_selection);
+            app.update();
+
+            assert_eq!(app.world().resource::<PendingLevelUps>().0, 1);
+            let state = app.world().resource::<State<GameState>>();
+            assert_eq!(*state.get(), GameState::Intro);
+        }
+    }
+}
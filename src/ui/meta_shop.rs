@@ -0,0 +1,416 @@
+use bevy::prelude::*;
+
+use crate::input::bindings::{ActionInput, InputAction};
+use crate::profile::resources::{MetaProfile, MetaUpgrade, CURRENCY_NAME};
+use crate::profile::storage::ProfileStorage;
+use crate::states::GameState;
+
+/// Upgrade row background when idle / hovered
+const ROW_COLOR: Color = Color::srgba(0.15, 0.15, 0.2, 0.95);
+const ROW_HOVER_COLOR: Color = Color::srgba(0.25, 0.25, 0.35, 0.95);
+
+/// Back button background when idle / hovered
+const BACK_COLOR: Color = Color::srgb(0.6, 0.2, 0.2);
+const BACK_HOVER_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+
+/// Root marker for the meta shop screen.
+/// Used for cleanup on state exit.
+#[derive(Component)]
+pub struct MetaShopScreen;
+
+/// Button buying the next level of an upgrade.
+#[derive(Component)]
+pub struct MetaUpgradeButton {
+    pub upgrade: MetaUpgrade,
+}
+
+/// Level and price label inside an upgrade button.
+#[derive(Component)]
+pub struct MetaUpgradeLevelText {
+    pub upgrade: MetaUpgrade,
+}
+
+/// Currency balance label.
+#[derive(Component)]
+pub struct MetaShopCurrencyText;
+
+/// Feedback line for the last purchase attempt.
+#[derive(Component)]
+pub struct MetaShopStatusText;
+
+/// Button returning to the intro menu.
+#[derive(Component)]
+pub struct MetaShopBackButton;
+
+/// Level and next price of an upgrade, e.g. "Lv 1/5 - Next: 10 Souls"
+pub fn upgrade_level_label(upgrade: MetaUpgrade, level: u32) -> String {
+    match upgrade.cost(level) {
+        Some(cost) => format!(
+            "Lv {}/{} - Next: {} {}",
+            level,
+            upgrade.max_level(),
+            cost,
+            CURRENCY_NAME
+        ),
+        None => format!("Lv {}/{} - MAX", level, upgrade.max_level()),
+    }
+}
+
+fn currency_label(profile: &MetaProfile) -> String {
+    format!("{}: {}", CURRENCY_NAME, profile.currency)
+}
+
+/// Setup the meta shop screen when entering MetaShop state.
+pub fn setup_meta_shop(
+    mut commands: Commands,
+    profile: Res<MetaProfile>,
+    camera_query: Query<Entity, With<Camera>>,
+) {
+    // Spawn Camera2d if no camera exists (cleanup_intro despawns Camera2d)
+    if camera_query.is_empty() {
+        commands.spawn(Camera2d);
+    }
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.1, 0.1, 0.1)),
+            MetaShopScreen,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Soul Shop"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.84, 0.0)), // Gold
+            ));
+
+            parent.spawn((
+                Text::new(currency_label(&profile)),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.5, 1.0)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+                MetaShopCurrencyText,
+            ));
+
+            for upgrade in MetaUpgrade::all() {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(520.0),
+                            padding: UiRect::all(Val::Px(10.0)),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(ROW_COLOR),
+                        BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.3)),
+                        BorderRadius::all(Val::Px(6.0)),
+                        MetaUpgradeButton { upgrade: *upgrade },
+                    ))
+                    .with_children(|row| {
+                        row.spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        })
+                        .with_children(|info| {
+                            info.spawn((
+                                Text::new(upgrade.name()),
+                                TextFont {
+                                    font_size: 22.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                            info.spawn((
+                                Text::new(upgrade.description()),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+                            ));
+                        });
+
+                        row.spawn((
+                            Text::new(upgrade_level_label(
+                                *upgrade,
+                                profile.upgrade_level(*upgrade),
+                            )),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.7, 0.5, 1.0)),
+                            MetaUpgradeLevelText { upgrade: *upgrade },
+                        ));
+                    });
+            }
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.5, 0.5)),
+                MetaShopStatusText,
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(200.0),
+                        height: Val::Px(50.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(Val::Px(15.0)),
+                        ..default()
+                    },
+                    BackgroundColor(BACK_COLOR),
+                    MetaShopBackButton,
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new("Back"),
+                        TextFont {
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+        });
+}
+
+/// Buy upgrades and save the profile after every purchase.
+/// Back (or the Pause / Back binding, Escape by default) returns to the intro menu.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn handle_meta_shop_buttons(
+    mut upgrade_query: Query<
+        (&Interaction, &mut BackgroundColor, &MetaUpgradeButton),
+        Changed<Interaction>,
+    >,
+    mut back_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<MetaShopBackButton>, Without<MetaUpgradeButton>),
+    >,
+    mut status_query: Query<&mut Text, With<MetaShopStatusText>>,
+    input: ActionInput,
+    mut profile: ResMut<MetaProfile>,
+    storage: Option<Res<ProfileStorage>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, mut bg_color, button) in &mut upgrade_query {
+        match *interaction {
+            Interaction::Pressed => {
+                let status = match profile.purchase_upgrade(button.upgrade) {
+                    Ok(level) => {
+                        if let Some(storage) = storage.as_ref() {
+                            if let Err(err) = storage.save(&profile) {
+                                error!("Failed to save profile: {}", err);
+                            }
+                        }
+                        format!("{} upgraded to level {}", button.upgrade.name(), level)
+                    }
+                    Err(err) => format!("Can't buy {}: {}", button.upgrade.name(), err),
+                };
+                for mut text in status_query.iter_mut() {
+                    text.0 = status.clone();
+                }
+            }
+            Interaction::Hovered => *bg_color = BackgroundColor(ROW_HOVER_COLOR),
+            Interaction::None => *bg_color = BackgroundColor(ROW_COLOR),
+        }
+    }
+
+    let mut back = input.just_pressed(InputAction::Pause);
+    for (interaction, mut bg_color) in &mut back_query {
+        match *interaction {
+            Interaction::Pressed => back = true,
+            Interaction::Hovered => *bg_color = BackgroundColor(BACK_HOVER_COLOR),
+            Interaction::None => *bg_color = BackgroundColor(BACK_COLOR),
+        }
+    }
+    if back {
+        next_state.set(GameState::Intro);
+    }
+}
+
+/// Refresh the balance and upgrade levels after a purchase.
+pub fn update_meta_shop_labels(
+    profile: Res<MetaProfile>,
+    mut currency_query: Query<&mut Text, (With<MetaShopCurrencyText>, Without<MetaUpgradeLevelText>)>,
+    mut level_query: Query<(&mut Text, &MetaUpgradeLevelText)>,
+) {
+    if !profile.is_changed() {
+        return;
+    }
+
+    for mut text in currency_query.iter_mut() {
+        text.0 = currency_label(&profile);
+    }
+    for (mut text, label) in level_query.iter_mut() {
+        text.0 = upgrade_level_label(label.upgrade, profile.upgrade_level(label.upgrade));
+    }
+}
+
+/// Cleanup meta shop entities when exiting the state.
+pub fn cleanup_meta_shop(mut commands: Commands, query: Query<Entity, With<MetaShopScreen>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    fn setup_app(currency: u32) -> App {
+        let mut app = App::new();
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<GameState>();
+        app.insert_resource(MetaProfile {
+            currency,
+            ..default()
+        });
+        app.init_resource::<ProfileStorage>();
+        app.init_resource::<crate::input::bindings::InputMap>();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        let _ = app.world_mut().run_system_once(setup_meta_shop);
+        app
+    }
+
+    fn press_upgrade(app: &mut App, upgrade: MetaUpgrade) {
+        let mut query = app.world_mut().query::<(Entity, &MetaUpgradeButton)>();
+        let entity = query
+            .iter(app.world())
+            .find(|(_, button)| button.upgrade == upgrade)
+            .map(|(entity, _)| entity)
+            .unwrap();
+        app.world_mut().entity_mut(entity).insert(Interaction::Pressed);
+        let _ = app.world_mut().run_system_once(handle_meta_shop_buttons);
+    }
+
+    mod meta_shop_screen_tests {
+        use super::*;
+
+        #[test]
+        fn lists_every_upgrade() {
+            let mut app = setup_app(0);
+            let mut query = app.world_mut().query::<&MetaUpgradeButton>();
+            assert_eq!(query.iter(app.world()).count(), MetaUpgrade::all().len());
+        }
+
+        #[test]
+        fn level_label_shows_price_until_max() {
+            assert_eq!(
+                upgrade_level_label(MetaUpgrade::StartingSpell, 1),
+                format!("Lv 1/2 - Next: 50 {}", CURRENCY_NAME)
+            );
+            assert_eq!(upgrade_level_label(MetaUpgrade::StartingSpell, 2), "Lv 2/2 - MAX");
+        }
+
+        #[test]
+        fn cleanup_removes_screen() {
+            let mut app = setup_app(0);
+            let _ = app.world_mut().run_system_once(cleanup_meta_shop);
+            let mut query = app.world_mut().query::<&MetaUpgradeButton>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+        }
+    }
+
+    mod meta_shop_purchase_tests {
+        use super::*;
+
+        #[test]
+        fn pressing_an_upgrade_buys_it() {
+            let mut app = setup_app(10);
+            press_upgrade(&mut app, MetaUpgrade::MaxHealth);
+            let _ = app.world_mut().run_system_once(update_meta_shop_labels);
+
+            let profile = app.world().resource::<MetaProfile>();
+            assert_eq!(profile.upgrade_level(MetaUpgrade::MaxHealth), 1);
+            assert_eq!(profile.currency, 5);
+
+            let mut query = app.world_mut().query_filtered::<&Text, With<MetaShopCurrencyText>>();
+            assert_eq!(query.single(app.world()).unwrap().0, format!("{}: 5", CURRENCY_NAME));
+        }
+
+        #[test]
+        fn unaffordable_upgrade_reports_why() {
+            let mut app = setup_app(1);
+            press_upgrade(&mut app, MetaUpgrade::RerollToken);
+
+            assert_eq!(
+                app.world().resource::<MetaProfile>().upgrade_level(MetaUpgrade::RerollToken),
+                0
+            );
+            let mut query = app.world_mut().query_filtered::<&Text, With<MetaShopStatusText>>();
+            assert!(query.single(app.world()).unwrap().0.starts_with("Can't buy"));
+        }
+
+        #[test]
+        fn escape_returns_to_intro() {
+            let mut app = setup_app(0);
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::MetaShop);
+            app.update();
+
+            let mut keyboard = ButtonInput::<KeyCode>::default();
+            keyboard.press(KeyCode::Escape);
+            app.insert_resource(keyboard);
+            let _ = app.world_mut().run_system_once(handle_meta_shop_buttons);
+            app.update();
+
+            let state = app.world().resource::<State<GameState>>();
+            assert_eq!(*state.get(), GameState::Intro);
+        }
+
+        #[test]
+        fn rebound_back_key_returns_to_intro() {
+            use crate::input::bindings::{Binding, BindingSlot, InputMap};
+
+            let mut app = setup_app(0);
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::MetaShop);
+            app.update();
+            app.world_mut()
+                .resource_mut::<InputMap>()
+                .rebind(InputAction::Pause, BindingSlot::Primary, Binding::Key(KeyCode::KeyP))
+                .unwrap();
+
+            let mut keyboard = ButtonInput::<KeyCode>::default();
+            keyboard.press(KeyCode::KeyP);
+            app.insert_resource(keyboard);
+            let _ = app.world_mut().run_system_once(handle_meta_shop_buttons);
+            app.update();
+
+            let state = app.world().resource::<State<GameState>>();
+            assert_eq!(*state.get(), GameState::Intro);
+        }
+    }
+}
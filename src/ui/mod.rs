@@ -1,15 +1,25 @@
 pub mod attunement;
+pub mod boss_health_bar;
 pub mod components;
 pub mod inventory_bag;
+pub mod level_up_choice;
 pub mod materials;
+pub mod meta_shop;
 pub mod spell_slot;
+pub mod spell_stats_table;
 pub mod systems;
+pub mod wave_announcement;
 pub mod plugin;
 
 pub use attunement::*;
+pub use boss_health_bar::*;
 pub use components::*;
 pub use inventory_bag::*;
+pub use level_up_choice::*;
 pub use materials::*;
+pub use meta_shop::*;
 pub use spell_slot::*;
+pub use spell_stats_table::*;
 pub use systems::*;
+pub use wave_announcement::*;
 pub use plugin::*;
\ No newline at end of file
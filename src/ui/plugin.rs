@@ -1,12 +1,20 @@
 use bevy::prelude::*;
+use crate::game::resources::GameRng;
 use crate::game::sets::GameSet;
+use crate::input::bindings::InputMap;
 use crate::pause::components::SpellCooldownsVisible;
 use crate::states::*;
 use crate::ui::attunement::*;
+use crate::ui::boss_health_bar::*;
 use crate::ui::inventory_bag::*;
+use crate::ui::level_up_choice::*;
+use crate::level_up::systems::prepare_level_up_choices;
 use crate::ui::materials::RadialCooldownMaterial;
+use crate::ui::meta_shop::*;
 use crate::ui::spell_slot::{refresh_spell_slot_visuals, SpellSlotPlugin};
+use crate::ui::spell_stats_table::*;
 use crate::ui::systems::*;
+use crate::ui::wave_announcement::*;
 use crate::score::*;
 
 /// Run condition: only run if spell cooldowns are visible
@@ -23,10 +31,20 @@ pub fn plugin(app: &mut App) {
         .init_resource::<SelectedBagSlot>()
         .init_resource::<DragState>()
         .init_resource::<SpellCooldownsVisible>()
+        .init_resource::<GameRng>()
+        .init_resource::<InputMap>()
+        .init_resource::<SpellStatsSort>()
         .add_systems(Startup, configure_gizmos)
         .add_systems(OnEnter(GameState::Intro), setup_intro)
         .add_systems(Update, button_interactions.run_if(in_state(GameState::Intro)))
         .add_systems(OnExit(GameState::Intro), cleanup_intro)
+        // Meta shop state systems
+        .add_systems(OnEnter(GameState::MetaShop), setup_meta_shop)
+        .add_systems(Update, (
+            handle_meta_shop_buttons,
+            update_meta_shop_labels,
+        ).chain().run_if(in_state(GameState::MetaShop)))
+        .add_systems(OnExit(GameState::MetaShop), cleanup_meta_shop)
         // Attunement selection state systems
         .add_systems(OnEnter(GameState::AttunementSelect), setup_attunement_screen)
         .add_systems(Update, handle_attunement_selection.run_if(in_state(GameState::AttunementSelect)))
@@ -72,6 +90,22 @@ pub fn plugin(app: &mut App) {
                 .run_if(in_state(GameState::InGame))
                 .run_if(debug_hud_enabled)
         )
+        // Boss health bar (survives pause; removed once the fight or run ends)
+        .add_systems(Update, (
+            sync_boss_health_bars,
+            update_boss_health_bars,
+        ).chain().run_if(in_state(GameState::InGame)))
+        .add_systems(OnEnter(GameState::LevelComplete), cleanup_boss_health_bars)
+        .add_systems(OnEnter(GameState::GameOver), cleanup_boss_health_bars)
+        .add_systems(OnEnter(GameState::Intro), cleanup_boss_health_bars)
+        // Wave event announcements
+        .add_systems(Update, (
+            show_wave_announcements,
+            update_wave_announcements,
+        ).chain().run_if(in_state(GameState::InGame)))
+        .add_systems(OnEnter(GameState::LevelComplete), cleanup_wave_announcements)
+        .add_systems(OnEnter(GameState::GameOver), cleanup_wave_announcements)
+        .add_systems(OnEnter(GameState::Intro), cleanup_wave_announcements)
         // Floating damage numbers
         .add_systems(Update, (
             spawn_floating_damage_numbers,
@@ -82,6 +116,16 @@ pub fn plugin(app: &mut App) {
         .add_systems(PostUpdate, update_spell_cooldowns
             .run_if(in_state(GameState::InGame))
             .run_if(spell_cooldowns_enabled))
+        // Level-up choice state systems (choices are rolled by the level_up plugin)
+        .add_systems(
+            OnEnter(GameState::LevelUpChoice),
+            setup_level_up_choice_screen.after(prepare_level_up_choices),
+        )
+        .add_systems(Update, (
+            handle_level_up_choice_selection,
+            handle_level_up_reroll,
+        ).run_if(in_state(GameState::LevelUpChoice)))
+        .add_systems(OnExit(GameState::LevelUpChoice), cleanup_level_up_choice_screen)
         // Level Complete state systems
         .add_systems(OnEnter(GameState::LevelComplete), (
             setup_level_complete_screen,
@@ -90,10 +134,17 @@ pub fn plugin(app: &mut App) {
         .add_systems(Update, (
             animate_level_complete_overlay,
             handle_continue_button,
+            (handle_spell_stats_sort_buttons, update_spell_stats_table).chain(),
         ).run_if(in_state(GameState::LevelComplete)))
         .add_systems(OnExit(GameState::LevelComplete), cleanup_level_complete_screen)
         // Game Over state systems
-        .add_systems(OnEnter(GameState::GameOver), setup_game_over_ui)
-        .add_systems(Update, game_over_input.run_if(in_state(GameState::GameOver)))
+        .add_systems(
+            OnEnter(GameState::GameOver),
+            setup_game_over_ui.after(crate::profile::systems::record_run_system),
+        )
+        .add_systems(Update, (
+            game_over_input,
+            (handle_spell_stats_sort_buttons, update_spell_stats_table).chain(),
+        ).run_if(in_state(GameState::GameOver)))
         .add_systems(OnExit(GameState::GameOver), cleanup_game_over);
 }
\ No newline at end of file
@@ -186,8 +186,8 @@ pub fn spawn_spell_icon_visual(
         Some(spell) => {
             // Use spell's custom icon or element's default texture
             let icon_path = spell
-                .spell_type
-                .icon_path()
+                .icon
+                .as_deref()
                 .unwrap_or_else(|| spell.element.default_texture_path());
 
             parent.spawn((
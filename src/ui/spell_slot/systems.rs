@@ -49,8 +49,8 @@ pub fn refresh_spell_slot_visuals(
                 *visibility = Visibility::Visible;
                 // Use spell's custom icon or element's default texture
                 let path = spell
-                    .spell_type
-                    .icon_path()
+                    .icon
+                    .as_deref()
                     .unwrap_or_else(|| spell.element.default_texture_path());
                 image_node.image = asset_server.load(path);
             } else {
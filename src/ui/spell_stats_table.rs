@@ -0,0 +1,390 @@
+use bevy::ecs::hierarchy::ChildSpawnerCommands;
+use bevy::prelude::*;
+
+use crate::game::resources::{SpellStatColumn, SpellStatTable, SpellStats};
+use crate::spell::definitions::SpellCatalog;
+
+/// Most spells listed in the table (the rest are dropped from the bottom)
+pub const SPELL_STATS_MAX_ROWS: usize = 8;
+
+/// Width of the spell name column and of each stat column
+const NAME_COLUMN_WIDTH: f32 = 180.0;
+const STAT_COLUMN_WIDTH: f32 = 90.0;
+
+/// Row background, alternating for readability
+const ROW_COLOR: Color = Color::srgba(0.15, 0.15, 0.2, 0.95);
+const ROW_ALT_COLOR: Color = Color::srgba(0.2, 0.2, 0.26, 0.95);
+
+/// Sort header background when idle / hovered / sorting by it
+const HEADER_COLOR: Color = Color::srgba(0.25, 0.25, 0.35, 0.95);
+const HEADER_HOVER_COLOR: Color = Color::srgba(0.35, 0.35, 0.5, 0.95);
+const HEADER_ACTIVE_COLOR: Color = Color::srgb(0.55, 0.45, 0.1);
+
+/// Which per-spell totals a table shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpellStatsScope {
+    /// The level that was just completed
+    Level,
+    /// The whole run
+    Run,
+}
+
+impl SpellStatsScope {
+    /// The table of `SpellStats` this scope reads
+    pub fn table(self, stats: &SpellStats) -> &SpellStatTable {
+        match self {
+            SpellStatsScope::Level => &stats.level,
+            SpellStatsScope::Run => &stats.run,
+        }
+    }
+}
+
+/// Column the spell stats tables are sorted by (highest first).
+/// Kept across screens so the player's choice sticks for the run.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SpellStatsSort(pub SpellStatColumn);
+
+/// Root of a per-spell stats table.
+#[derive(Component, Debug)]
+pub struct SpellStatsTable {
+    pub scope: SpellStatsScope,
+}
+
+/// Column header that sorts the table by its column when clicked.
+#[derive(Component, Debug)]
+pub struct SpellStatsSortButton(pub SpellStatColumn);
+
+/// Text cell of a table row. `column` is `None` for the spell name.
+/// Cells are rewritten in place when the sort changes.
+#[derive(Component, Debug)]
+pub struct SpellStatsCell {
+    pub row: usize,
+    pub column: Option<SpellStatColumn>,
+}
+
+fn header_color(column: SpellStatColumn, sort: SpellStatColumn) -> Color {
+    if column == sort {
+        HEADER_ACTIVE_COLOR
+    } else {
+        HEADER_COLOR
+    }
+}
+
+/// Text of a cell for the given row, or empty past the last row
+fn cell_text(table: &SpellStatTable, catalog: &SpellCatalog, sort: SpellStatColumn, cell: &SpellStatsCell) -> String {
+    let rows = table.rows(sort);
+    let Some(row) = rows.get(cell.row) else {
+        return String::new();
+    };
+    match cell.column {
+        Some(column) => row.cell(column),
+        None => row.spell_type.name(catalog).to_string(),
+    }
+}
+
+/// Spawn a sortable per-spell stats table (spell, damage, kills, casts, DPS).
+/// Shows a placeholder line if no spell has been cast.
+pub fn spawn_spell_stats_table(
+    parent: &mut ChildSpawnerCommands,
+    stats: &SpellStats,
+    catalog: &SpellCatalog,
+    scope: SpellStatsScope,
+    sort: SpellStatColumn,
+) {
+    let table = scope.table(stats);
+    let rows = table.rows(sort);
+    let row_count = rows.len().min(SPELL_STATS_MAX_ROWS);
+
+    parent
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            SpellStatsTable { scope },
+        ))
+        .with_children(|table_node| {
+            if row_count == 0 {
+                table_node.spawn((
+                    Text::new("No spells cast"),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ));
+                return;
+            }
+
+            // Header row: spell label plus one sort button per column
+            table_node
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(2.0),
+                    ..default()
+                })
+                .with_children(|header| {
+                    header.spawn((
+                        Text::new("Spell"),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.84, 0.0)), // Gold
+                        Node {
+                            width: Val::Px(NAME_COLUMN_WIDTH),
+                            padding: UiRect::all(Val::Px(6.0)),
+                            ..default()
+                        },
+                    ));
+                    for column in SpellStatColumn::ALL {
+                        header
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(STAT_COLUMN_WIDTH),
+                                    padding: UiRect::all(Val::Px(6.0)),
+                                    justify_content: JustifyContent::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(header_color(column, sort)),
+                                BorderRadius::all(Val::Px(4.0)),
+                                SpellStatsSortButton(column),
+                            ))
+                            .with_children(|button| {
+                                button.spawn((
+                                    Text::new(column.label()),
+                                    TextFont {
+                                        font_size: 16.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+
+            for (index, row) in rows.iter().take(row_count).enumerate() {
+                let background = if index % 2 == 0 { ROW_COLOR } else { ROW_ALT_COLOR };
+                table_node
+                    .spawn((
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(2.0),
+                            ..default()
+                        },
+                        BackgroundColor(background),
+                    ))
+                    .with_children(|row_node| {
+                        row_node.spawn((
+                            Text::new(row.spell_type.name(catalog)),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            Node {
+                                width: Val::Px(NAME_COLUMN_WIDTH),
+                                padding: UiRect::all(Val::Px(6.0)),
+                                ..default()
+                            },
+                            SpellStatsCell { row: index, column: None },
+                        ));
+                        for column in SpellStatColumn::ALL {
+                            row_node.spawn((
+                                Text::new(row.cell(column)),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                                Node {
+                                    width: Val::Px(STAT_COLUMN_WIDTH),
+                                    padding: UiRect::all(Val::Px(6.0)),
+                                    justify_content: JustifyContent::Center,
+                                    ..default()
+                                },
+                                SpellStatsCell { row: index, column: Some(column) },
+                            ));
+                        }
+                    });
+            }
+        });
+}
+
+/// Sort the spell stats table by the clicked column header.
+#[allow(clippy::type_complexity)]
+pub fn handle_spell_stats_sort_buttons(
+    mut interaction_query: Query<
+        (&Interaction, &SpellStatsSortButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut sort: ResMut<SpellStatsSort>,
+) {
+    for (interaction, button, mut bg_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                sort.set_if_neq(SpellStatsSort(button.0));
+            }
+            Interaction::Hovered => {
+                if button.0 != sort.0 {
+                    *bg_color = BackgroundColor(HEADER_HOVER_COLOR);
+                }
+            }
+            Interaction::None => *bg_color = BackgroundColor(header_color(button.0, sort.0)),
+        }
+    }
+}
+
+/// Rewrite the table rows and header highlight when the sort column changes.
+pub fn update_spell_stats_table(
+    sort: Res<SpellStatsSort>,
+    spell_stats: Res<SpellStats>,
+    spell_catalog: Res<SpellCatalog>,
+    table_query: Query<&SpellStatsTable>,
+    mut cell_query: Query<(&SpellStatsCell, &mut Text)>,
+    mut header_query: Query<(&SpellStatsSortButton, &mut BackgroundColor)>,
+) {
+    if !sort.is_changed() {
+        return;
+    }
+    let Ok(table) = table_query.single() else {
+        return;
+    };
+    let stats = table.scope.table(&spell_stats);
+
+    for (cell, mut text) in cell_query.iter_mut() {
+        text.0 = cell_text(stats, &spell_catalog, sort.0, cell);
+    }
+    for (button, mut bg_color) in header_query.iter_mut() {
+        *bg_color = BackgroundColor(header_color(button.0, sort.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use crate::spell::SpellType;
+
+    fn sample_stats() -> SpellStats {
+        let mut stats = SpellStats::default();
+        stats.record_damage(SpellType::Fireball, 120.0, true);
+        for _ in 0..4 {
+            stats.record_cast(SpellType::Spark);
+            stats.record_damage(SpellType::Spark, 10.0, true);
+        }
+        stats.record_cast(SpellType::Fireball);
+        stats.tick(10.0);
+        stats.reset_level();
+        stats.record_damage(SpellType::IceShard, 5.0, false);
+        stats
+    }
+
+    fn spawn_table(app: &mut App, stats: SpellStats, scope: SpellStatsScope) {
+        let _ = app.world_mut().run_system_once(move |mut commands: Commands| {
+            commands.spawn(Node::default()).with_children(|parent| {
+                spawn_spell_stats_table(parent, &stats, SpellCatalog::built_in(), scope, SpellStatColumn::Damage);
+            });
+        });
+    }
+
+    fn setup_app(scope: SpellStatsScope) -> App {
+        let mut app = App::new();
+        app.insert_resource(sample_stats());
+        app.init_resource::<SpellStatsSort>();
+        app.init_resource::<SpellCatalog>();
+        spawn_table(&mut app, sample_stats(), scope);
+        app
+    }
+
+    fn row_names(app: &mut App) -> Vec<String> {
+        let mut cells: Vec<(usize, String)> = app
+            .world_mut()
+            .query::<(&SpellStatsCell, &Text)>()
+            .iter(app.world())
+            .filter(|(cell, _)| cell.column.is_none())
+            .map(|(cell, text)| (cell.row, text.0.clone()))
+            .collect();
+        cells.sort();
+        cells.into_iter().map(|(_, name)| name).collect()
+    }
+
+    #[test]
+    fn scope_selects_level_or_run_table() {
+        let stats = sample_stats();
+        assert_eq!(SpellStatsScope::Level.table(&stats).len(), 1);
+        assert_eq!(SpellStatsScope::Run.table(&stats).len(), 3);
+    }
+
+    #[test]
+    fn table_spawns_one_row_per_spell_sorted_by_damage() {
+        let mut app = setup_app(SpellStatsScope::Run);
+
+        assert_eq!(row_names(&mut app), vec!["Fireball", "Spark", "Ice Shard"]);
+        let buttons = app
+            .world_mut()
+            .query::<&SpellStatsSortButton>()
+            .iter(app.world())
+            .count();
+        assert_eq!(buttons, SpellStatColumn::ALL.len());
+    }
+
+    #[test]
+    fn empty_table_spawns_placeholder() {
+        let mut app = App::new();
+        spawn_table(&mut app, SpellStats::default(), SpellStatsScope::Level);
+
+        assert!(row_names(&mut app).is_empty());
+        let tables = app.world_mut().query::<&SpellStatsTable>().iter(app.world()).count();
+        assert_eq!(tables, 1);
+    }
+
+    #[test]
+    fn changing_sort_rewrites_rows() {
+        let mut app = setup_app(SpellStatsScope::Run);
+        app.add_systems(Update, update_spell_stats_table);
+        app.update();
+
+        app.world_mut().resource_mut::<SpellStatsSort>().0 = SpellStatColumn::Kills;
+        app.update();
+
+        assert_eq!(row_names(&mut app), vec!["Spark", "Fireball", "Ice Shard"]);
+        let kills: Vec<(usize, String)> = app
+            .world_mut()
+            .query::<(&SpellStatsCell, &Text)>()
+            .iter(app.world())
+            .filter(|(cell, _)| cell.column == Some(SpellStatColumn::Kills))
+            .map(|(cell, text)| (cell.row, text.0.clone()))
+            .collect();
+        assert!(kills.contains(&(0, "4".to_string())));
+
+        let active: Vec<SpellStatColumn> = app
+            .world_mut()
+            .query::<(&SpellStatsSortButton, &BackgroundColor)>()
+            .iter(app.world())
+            .filter(|(_, color)| color.0 == HEADER_ACTIVE_COLOR)
+            .map(|(button, _)| button.0)
+            .collect();
+        assert_eq!(active, vec![SpellStatColumn::Kills]);
+    }
+
+    #[test]
+    fn pressing_header_sets_sort() {
+        let mut app = setup_app(SpellStatsScope::Run);
+        app.add_systems(Update, handle_spell_stats_sort_buttons);
+
+        let casts = app
+            .world_mut()
+            .query::<(Entity, &SpellStatsSortButton)>()
+            .iter(app.world())
+            .find(|(_, button)| button.0 == SpellStatColumn::Casts)
+            .map(|(entity, _)| entity)
+            .unwrap();
+        app.world_mut().entity_mut(casts).insert(Interaction::Pressed);
+        app.update();
+
+        assert_eq!(app.world().resource::<SpellStatsSort>().0, SpellStatColumn::Casts);
+    }
+}
@@ -1,16 +1,19 @@
 use bevy::prelude::*;
 use bevy::ecs::world::World;
 use bevy_kira_audio::AudioControl;
-use crate::combat::components::Health;
-use crate::combat::events::DamageEvent;
+use crate::combat::components::{ElementalResponse, Health};
+use crate::combat::events::DamageDealt;
 use crate::enemies::components::Enemy;
 use crate::states::*;
 use crate::ui::components::*;
 use crate::ui::materials::RadialCooldownMaterial;
 use crate::ui::spell_slot::{spawn_spell_slot, SlotSource, SLOT_SIZE as SPELL_SLOT_MODULE_SIZE};
+use crate::ui::spell_stats_table::{spawn_spell_stats_table, SpellStatsScope, SpellStatsSort};
+use crate::spell::definitions::SpellCatalog;
 use crate::player::components::*;
 use crate::inventory::SpellList;
 use crate::pause::components::SpellCooldownsVisible;
+use crate::input::bindings::{ActionInput, InputAction};
 
 /// Resource to track debug HUD visibility
 #[derive(Resource, Default)]
@@ -85,6 +88,32 @@ pub fn setup_intro(
                 ));
             });
 
+            // Meta shop button
+            menu.spawn((
+                Button,
+                Node {
+                    width: Val::Px(200.0),
+                    height: Val::Px(50.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.4, 0.25, 0.6)),
+                MenuButton,
+                MetaShopButton,
+            ))
+            .with_children(|button| {
+                button.spawn((
+                    Text::new("Soul Shop"),
+                    TextFont {
+                        font_size: 24.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+
             // Exit Game button
             menu.spawn((
                 Button,
@@ -121,17 +150,20 @@ pub fn button_interactions(
             &mut BackgroundColor,
             Option<&StartGameButton>,
             Option<&ExitGameButton>,
+            Option<&MetaShopButton>,
         ),
         (Changed<Interaction>, With<MenuButton>),
     >,
     mut next_state: ResMut<NextState<GameState>>,
     mut app_exit: MessageWriter<AppExit>,
 ) {
-    for (interaction, mut background_color, start_button, exit_button) in &mut interaction_query {
+    for (interaction, mut background_color, start_button, exit_button, shop_button) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 if start_button.is_some() {
                     next_state.set(GameState::InGame);
+                } else if shop_button.is_some() {
+                    next_state.set(GameState::MetaShop);
                 } else if exit_button.is_some() {
                     app_exit.write(AppExit::Success);
                 }
@@ -142,6 +174,8 @@ pub fn button_interactions(
             Interaction::None => {
                 if start_button.is_some() {
                     *background_color = BackgroundColor(Color::srgb(0.2, 0.6, 0.2));
+                } else if shop_button.is_some() {
+                    *background_color = BackgroundColor(Color::srgb(0.4, 0.25, 0.6));
                 } else if exit_button.is_some() {
                     *background_color = BackgroundColor(Color::srgb(0.6, 0.2, 0.2));
                 }
@@ -340,11 +374,13 @@ pub fn update_kill_progress_display(
     mut query: Query<&mut Text, With<KillProgressDisplay>>,
 ) {
     if game_level.is_changed() {
+        // Kills made during the boss fight don't count past the threshold
+        let kills_to_advance = game_level.kills_to_advance();
         for mut text in query.iter_mut() {
             **text = format!(
                 "Kills: {}/{}",
-                game_level.kills_this_level,
-                game_level.kills_to_advance()
+                game_level.kills_this_level.min(kills_to_advance),
+                kills_to_advance
             );
         }
     }
@@ -364,7 +400,13 @@ pub fn update_xp_progress_bar(
 pub fn setup_game_over_ui(
     mut commands: Commands,
     score: Res<crate::score::Score>,
+    last_reward: Option<Res<crate::profile::LastRunReward>>,
+    spell_stats: Option<Res<crate::game::resources::SpellStats>>,
+    spell_catalog: Res<SpellCatalog>,
+    sort: Option<Res<SpellStatsSort>>,
 ) {
+    let sort = sort.map_or_else(Default::default, |sort| sort.0);
+
     // Create game over UI
     commands.spawn((
         Node {
@@ -401,11 +443,38 @@ pub fn setup_game_over_ui(
             },
             TextColor(Color::WHITE),
             Node {
-                margin: UiRect::bottom(Val::Px(50.0)),
+                margin: UiRect::bottom(Val::Px(20.0)),
                 ..default()
             },
         ));
 
+        // Currency earned for the meta-progression profile
+        let earned = last_reward.map_or(0, |reward| reward.0);
+        parent.spawn((
+            Text::new(format!("+{} {}", earned, crate::profile::CURRENCY_NAME)),
+            TextFont {
+                font_size: 26.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.84, 0.0)), // Gold
+            Node {
+                margin: UiRect::bottom(Val::Px(30.0)),
+                ..default()
+            },
+        ));
+
+        // Per-spell breakdown for the whole run
+        if let Some(spell_stats) = spell_stats.as_deref() {
+            parent
+                .spawn(Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                })
+                .with_children(|table| {
+                    spawn_spell_stats_table(table, spell_stats, &spell_catalog, SpellStatsScope::Run, sort);
+                });
+        }
+
         // Restart instruction
         parent.spawn((
             Text::new("Press R to restart or ESC for menu"),
@@ -504,13 +573,13 @@ pub fn update_spell_cooldowns(
 }
 
 pub fn game_over_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyR) {
+    if input.just_pressed(InputAction::Restart) {
         // Restart game - go back to InGame state
         next_state.set(GameState::InGame);
-    } else if keyboard_input.just_pressed(KeyCode::Escape) {
+    } else if input.just_pressed(InputAction::Pause) {
         // Go back to intro menu
         next_state.set(GameState::Intro);
     }
@@ -532,7 +601,7 @@ pub fn cleanup_game_over(
     }
 }
 
-/// Setup debug HUD (hidden by default, toggle with D key)
+/// Setup debug HUD (hidden by default, toggle with F3 unless rebound)
 pub fn setup_debug_hud(mut commands: Commands) {
     commands.spawn((
         Node {
@@ -630,13 +699,13 @@ pub fn setup_debug_hud(mut commands: Commands) {
     });
 }
 
-/// Toggle debug HUD visibility with D key
+/// Toggle debug HUD visibility with the ToggleDebugHud action (F3 by default)
 pub fn toggle_debug_hud(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     mut debug_visible: ResMut<DebugHudVisible>,
     mut hud_query: Query<&mut Visibility, With<DebugHud>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyD) {
+    if input.just_pressed(InputAction::ToggleDebugHud) {
         debug_visible.0 = !debug_visible.0;
         for mut visibility in hud_query.iter_mut() {
             *visibility = if debug_visible.0 {
@@ -753,7 +822,12 @@ pub fn setup_level_complete_screen(
     mut commands: Commands,
     game_level: Res<crate::game::resources::GameLevel>,
     level_stats: Res<crate::game::resources::LevelStats>,
+    spell_stats: Option<Res<crate::game::resources::SpellStats>>,
+    spell_catalog: Res<SpellCatalog>,
+    sort: Option<Res<SpellStatsSort>>,
 ) {
+    let sort = sort.map_or_else(Default::default, |sort| sort.0);
+
     // Root container for entire level complete UI
     commands.spawn((
         Node {
@@ -842,6 +916,11 @@ pub fn setup_level_complete_screen(
                 ));
             });
 
+            // Per-spell breakdown for the level
+            if let Some(spell_stats) = spell_stats.as_deref() {
+                spawn_spell_stats_table(content, spell_stats, &spell_catalog, SpellStatsScope::Level, sort);
+            }
+
             // Continue button
             content.spawn((
                 Node {
@@ -962,38 +1041,66 @@ pub fn cleanup_level_complete_screen(
     }
 }
 
+/// Color of damage numbers for resisted and immune hits
+const RESISTED_DAMAGE_NUMBER_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+
+/// Color of damage numbers for critical hits
+const CRITICAL_DAMAGE_NUMBER_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
+
 /// Spawn floating damage numbers when enemies take damage.
-/// Numbers are colored by element type and animate upward while fading.
+/// Numbers show the resolved damage from `DamageDealt`, are colored by element
+/// type and animate upward while fading. Critical hits are large and gold.
+/// Other hits that modifiers scaled away from their base damage are labelled:
+/// reduced and immune hits are small and grey, amplified hits are larger.
+/// Reaction damage is prefixed with the reaction's name.
 /// Uses screen-space UI nodes positioned via world-to-viewport conversion.
 pub fn spawn_floating_damage_numbers(
     mut commands: Commands,
-    mut damage_events: MessageReader<DamageEvent>,
-    transform_query: Query<&Transform>,
-    enemies: Query<(), With<Enemy>>,
+    mut damage_dealt: MessageReader<DamageDealt>,
+    enemies: Query<&Transform, With<Enemy>>,
 ) {
-    for event in damage_events.read() {
+    for dealt in damage_dealt.read() {
         // Only show for enemies
-        if enemies.get(event.target).is_err() {
-            continue;
-        }
-
-        let Ok(transform) = transform_query.get(event.target) else {
+        let Ok(transform) = enemies.get(dealt.target) else {
             continue;
         };
 
-        let color = event
+        let element_color = dealt
             .element
             .map(|e| e.color())
             .unwrap_or(Color::WHITE);
 
+        let amount = dealt.amount.round() as i32;
+        let (text, font_size, color) = if dealt.critical {
+            (format!("{}!", amount), 36.0, CRITICAL_DAMAGE_NUMBER_COLOR)
+        } else {
+            let multiplier = if dealt.base_amount > 0.0 {
+                dealt.amount / dealt.base_amount
+            } else {
+                1.0
+            };
+            match ElementalResponse::from_multiplier(multiplier) {
+                ElementalResponse::Normal => (format!("{}", amount), 24.0, element_color),
+                ElementalResponse::Resisted => {
+                    (format!("{} resisted", amount), 18.0, RESISTED_DAMAGE_NUMBER_COLOR)
+                }
+                ElementalResponse::Weak => (format!("{} weak!", amount), 32.0, element_color),
+                ElementalResponse::Immune => ("immune".to_string(), 18.0, RESISTED_DAMAGE_NUMBER_COLOR),
+            }
+        };
+        let text = match dealt.reaction {
+            Some(reaction) => format!("{}! {}", reaction, text),
+            None => text,
+        };
+
         // Start position slightly above enemy
         let world_position = transform.translation + Vec3::Y * 1.0;
 
         // Spawn as screen-space UI node (position updated each frame)
         commands.spawn((
-            Text::new(format!("{}", event.amount.round() as i32)),
+            Text::new(text),
             TextFont {
-                font_size: 24.0,
+                font_size,
                 ..default()
             },
             TextColor(color),
@@ -1229,6 +1336,7 @@ mod tests {
             app.init_state::<GameState>();
             app.insert_resource(GameLevel::new());
             app.insert_resource(LevelStats::new());
+            app.init_resource::<SpellCatalog>();
             app
         }
 
@@ -1367,6 +1475,39 @@ mod tests {
             assert_eq!(button_count, 1, "Should spawn exactly one ContinueButton");
         }
 
+        #[test]
+        fn level_complete_shows_level_spell_stats_table() {
+            use crate::game::resources::SpellStats;
+            use crate::spell::SpellType;
+            use crate::ui::spell_stats_table::{SpellStatsCell, SpellStatsTable};
+
+            let mut app = setup_test_app();
+            let mut spell_stats = SpellStats::default();
+            spell_stats.record_damage(SpellType::Spark, 40.0, true);
+            spell_stats.reset_level();
+            spell_stats.record_cast(SpellType::Fireball);
+            spell_stats.record_damage(SpellType::Fireball, 75.0, true);
+            app.insert_resource(spell_stats);
+
+            app.add_systems(Update, setup_level_complete_screen);
+            app.update();
+
+            let tables: Vec<SpellStatsScope> = app.world_mut()
+                .query::<&SpellStatsTable>()
+                .iter(app.world())
+                .map(|table| table.scope)
+                .collect();
+            assert_eq!(tables, vec![SpellStatsScope::Level]);
+
+            let names: Vec<String> = app.world_mut()
+                .query::<(&SpellStatsCell, &Text)>()
+                .iter(app.world())
+                .filter(|(cell, _)| cell.column.is_none())
+                .map(|(_, text)| text.0.clone())
+                .collect();
+            assert_eq!(names, vec!["Fireball".to_string()], "Only this level's spells are listed");
+        }
+
         #[test]
         fn level_complete_shows_correct_level() {
             let mut app = setup_test_app();
@@ -1507,16 +1648,32 @@ mod tests {
 
     mod floating_damage_number_tests {
         use super::*;
+        use crate::combat::DamageKind;
         use crate::element::Element;
         use bevy::ecs::system::RunSystemOnce;
 
         fn setup_test_app() -> App {
             let mut app = App::new();
-            app.add_message::<DamageEvent>();
+            app.add_message::<DamageDealt>();
             app.init_resource::<Time>();
             app
         }
 
+        fn dealt(target: Entity, amount: f32, base_amount: f32, element: Option<Element>) -> DamageDealt {
+            DamageDealt {
+                target,
+                amount,
+                base_amount,
+                source: None,
+                element,
+                kind: DamageKind::Direct,
+                critical: false,
+                killing_blow: false,
+                reaction: None,
+                spell: None,
+            }
+        }
+
         #[test]
         fn spawns_on_enemy_damage() {
             let mut app = setup_test_app();
@@ -1528,7 +1685,7 @@ mod tests {
             )).id();
 
             // Send damage event
-            app.world_mut().write_message(DamageEvent::new(enemy, 25.0));
+            app.world_mut().write_message(dealt(enemy, 25.0, 25.0, None));
 
             // Run the spawn system
             let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
@@ -1552,7 +1709,7 @@ mod tests {
             )).id();
 
             // Send damage with fire element
-            app.world_mut().write_message(DamageEvent::with_element(enemy, 30.0, Element::Fire));
+            app.world_mut().write_message(dealt(enemy, 30.0, 30.0, Some(Element::Fire)));
 
             let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
 
@@ -1576,7 +1733,7 @@ mod tests {
             )).id();
 
             // Send damage without element
-            app.world_mut().write_message(DamageEvent::new(enemy, 20.0));
+            app.world_mut().write_message(dealt(enemy, 20.0, 20.0, None));
 
             let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
 
@@ -1589,6 +1746,81 @@ mod tests {
             assert_eq!(text_color.0, Color::WHITE, "Should use white for non-elemental damage");
         }
 
+        #[test]
+        fn labels_hits_scaled_by_modifiers() {
+            let mut app = setup_test_app();
+
+            let enemy = app.world_mut().spawn((
+                Enemy { speed: 50.0, strength: 10.0 },
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            )).id();
+
+            app.world_mut().write_message(dealt(enemy, 10.0, 20.0, Some(Element::Fire)));
+            app.world_mut().write_message(dealt(enemy, 30.0, 20.0, Some(Element::Frost)));
+            app.world_mut().write_message(dealt(enemy, 0.0, 20.0, Some(Element::Poison)));
+            app.world_mut().write_message(dealt(enemy, 20.0, 20.0, None));
+
+            let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
+
+            let texts: Vec<(String, f32)> = app.world_mut()
+                .query::<(&Text, &TextFont)>()
+                .iter(app.world())
+                .map(|(text, font)| (text.0.clone(), font.font_size))
+                .collect();
+            assert_eq!(texts.len(), 4);
+            assert!(texts.contains(&("10 resisted".to_string(), 18.0)));
+            assert!(texts.contains(&("30 weak!".to_string(), 32.0)));
+            assert!(texts.contains(&("immune".to_string(), 18.0)));
+            assert!(texts.contains(&("20".to_string(), 24.0)));
+        }
+
+        #[test]
+        fn styles_critical_hits() {
+            let mut app = setup_test_app();
+
+            let enemy = app.world_mut().spawn((
+                Enemy { speed: 50.0, strength: 10.0 },
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            )).id();
+
+            let mut crit = dealt(enemy, 50.0, 25.0, Some(Element::Fire));
+            crit.critical = true;
+            app.world_mut().write_message(crit);
+
+            let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
+
+            let (text, font, color) = app.world_mut()
+                .query::<(&Text, &TextFont, &TextColor)>()
+                .single(app.world())
+                .unwrap();
+            assert_eq!(text.0, "50!");
+            assert_eq!(font.font_size, 36.0);
+            assert_eq!(color.0, CRITICAL_DAMAGE_NUMBER_COLOR);
+        }
+
+        #[test]
+        fn labels_reaction_damage() {
+            let mut app = setup_test_app();
+
+            let enemy = app.world_mut().spawn((
+                Enemy { speed: 50.0, strength: 10.0 },
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            )).id();
+
+            app.world_mut().write_message(
+                dealt(enemy, 40.0, 40.0, Some(Element::Fire)).as_reaction("Shatter"),
+            );
+
+            let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
+
+            let text = app.world_mut()
+                .query::<&Text>()
+                .iter(app.world())
+                .next()
+                .unwrap();
+            assert_eq!(text.0, "Shatter! 40");
+        }
+
         #[test]
         fn ignores_non_enemy_damage() {
             let mut app = setup_test_app();
@@ -1599,7 +1831,7 @@ mod tests {
             ).id();
 
             // Send damage event to non-enemy
-            app.world_mut().write_message(DamageEvent::new(entity, 15.0));
+            app.world_mut().write_message(dealt(entity, 15.0, 15.0, None));
 
             let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
 
@@ -1621,7 +1853,7 @@ mod tests {
                 Transform::from_xyz(10.0, 2.0, 5.0),
             )).id();
 
-            app.world_mut().write_message(DamageEvent::new(enemy, 25.0));
+            app.world_mut().write_message(dealt(enemy, 25.0, 25.0, None));
 
             let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
 
@@ -1647,7 +1879,7 @@ mod tests {
                 Transform::from_xyz(0.0, 0.0, 0.0),
             )).id();
 
-            app.world_mut().write_message(DamageEvent::new(enemy, 25.0));
+            app.world_mut().write_message(dealt(enemy, 25.0, 25.0, None));
 
             let _ = app.world_mut().run_system_once(spawn_floating_damage_numbers);
 
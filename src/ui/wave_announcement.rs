@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+
+use crate::enemies::waves::WaveEvent;
+use crate::game::events::WaveEventStarted;
+
+/// Seconds a wave event announcement stays on screen
+pub const WAVE_ANNOUNCEMENT_SECS: f32 = 3.0;
+
+/// Seconds at the end of an announcement spent fading out
+pub const WAVE_ANNOUNCEMENT_FADE_SECS: f32 = 1.0;
+
+/// Announcement text across the upper middle of the screen, shown when a
+/// wave event starts. Only the newest announcement is kept.
+#[derive(Component)]
+pub struct WaveAnnouncement {
+    pub timer: Timer,
+}
+
+/// Text colour of an event's announcement
+pub fn announcement_color(event: &WaveEvent) -> Color {
+    match event {
+        WaveEvent::ClosingRing { .. } => Color::srgb(1.0, 0.6, 0.2),
+        WaveEvent::Stampede { .. } => Color::srgb(1.0, 0.85, 0.3),
+        WaveEvent::EliteAmbush { .. } => Color::srgb(1.0, 0.25, 0.25),
+        WaveEvent::Horde { .. } => Color::srgb(0.8, 0.4, 1.0),
+    }
+}
+
+/// Announce the latest wave event, replacing any announcement still showing.
+pub fn show_wave_announcements(
+    mut commands: Commands,
+    mut wave_events: MessageReader<WaveEventStarted>,
+    existing_query: Query<Entity, With<WaveAnnouncement>>,
+) {
+    let Some(started) = wave_events.read().last() else {
+        return;
+    };
+
+    for entity in existing_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    commands.spawn((
+        Text::new(started.event.announcement()),
+        TextFont {
+            font_size: 48.0,
+            ..default()
+        },
+        TextColor(announcement_color(&started.event)),
+        TextLayout::new_with_justify(bevy::text::Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(22.0),
+            width: Val::Percent(100.0),
+            ..default()
+        },
+        WaveAnnouncement {
+            timer: Timer::from_seconds(WAVE_ANNOUNCEMENT_SECS, TimerMode::Once),
+        },
+    ));
+}
+
+/// Fade announcements out over their last second and despawn them when done.
+pub fn update_wave_announcements(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut WaveAnnouncement, &mut TextColor)>,
+) {
+    for (entity, mut announcement, mut text_color) in query.iter_mut() {
+        announcement.timer.tick(time.delta());
+
+        let remaining = announcement.timer.remaining_secs();
+        text_color.0 = text_color.0.with_alpha((remaining / WAVE_ANNOUNCEMENT_FADE_SECS).min(1.0));
+
+        if announcement.timer.is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Remove any announcement still showing (e.g. when leaving the game).
+pub fn cleanup_wave_announcements(
+    mut commands: Commands,
+    query: Query<Entity, With<WaveAnnouncement>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    use crate::enemies::components::EnemyKind;
+
+    fn announcement_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(bevy::time::TimePlugin);
+        app.add_message::<WaveEventStarted>();
+        app
+    }
+
+    mod wave_announcement_tests {
+        use super::*;
+
+        #[test]
+        fn shows_only_the_latest_event() {
+            let mut app = announcement_app();
+            app.world_mut().write_message(WaveEventStarted {
+                event: WaveEvent::Stampede { kind: EnemyKind::Swarm, count: 8 },
+            });
+            let _ = app.world_mut().run_system_once(show_wave_announcements);
+            app.world_mut().write_message(WaveEventStarted {
+                event: WaveEvent::EliteAmbush { kind: EnemyKind::Tank, count: 1 },
+            });
+            let _ = app.world_mut().run_system_once(show_wave_announcements);
+
+            let mut query = app.world_mut().query_filtered::<&Text, With<WaveAnnouncement>>();
+            let texts: Vec<_> = query.iter(app.world()).map(|text| text.0.clone()).collect();
+            assert_eq!(texts, vec!["Ambush!".to_string()]);
+        }
+
+        #[test]
+        fn nothing_shows_without_events() {
+            let mut app = announcement_app();
+            let _ = app.world_mut().run_system_once(show_wave_announcements);
+
+            let mut query = app.world_mut().query::<&WaveAnnouncement>();
+            assert_eq!(query.iter(app.world()).count(), 0);
+        }
+
+        #[test]
+        fn fades_out_and_despawns() {
+            let mut app = announcement_app();
+            let mut timer = Timer::from_seconds(WAVE_ANNOUNCEMENT_SECS, TimerMode::Once);
+            timer.tick(Duration::from_secs_f32(WAVE_ANNOUNCEMENT_SECS - WAVE_ANNOUNCEMENT_FADE_SECS / 2.0));
+            let entity = app
+                .world_mut()
+                .spawn((WaveAnnouncement { timer }, TextColor(Color::WHITE)))
+                .id();
+
+            let _ = app.world_mut().run_system_once(update_wave_announcements);
+            let alpha = app.world().get::<TextColor>(entity).unwrap().0.alpha();
+            assert!((alpha - 0.5).abs() < 0.01, "Half-faded announcement alpha should be 0.5, got {}", alpha);
+
+            app.world_mut()
+                .get_mut::<WaveAnnouncement>(entity)
+                .unwrap()
+                .timer
+                .tick(Duration::from_secs_f32(WAVE_ANNOUNCEMENT_FADE_SECS));
+            let _ = app.world_mut().run_system_once(update_wave_announcements);
+            assert!(app.world().get_entity(entity).is_err());
+        }
+    }
+}
@@ -48,7 +48,8 @@ pub fn plugin(app: &mut App) {
                         .or(in_state(GameState::AttunementSelect))
                         .or(in_state(GameState::InventoryOpen))
                         .or(in_state(GameState::LevelComplete))
-                        .or(in_state(GameState::Paused)),
+                        .or(in_state(GameState::Paused))
+                        .or(in_state(GameState::Controls)),
                 ),
         )
         // Effect systems (lightning bolts)
@@ -2,7 +2,7 @@ use bevy::ecs::relationship::Relationship;
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::game::resources::{GameMaterials, GameMeshes};
+use crate::game::resources::{GameMaterials, GameMeshes, GameRng};
 use crate::loot::components::{DroppedItem, ItemData, PickupState};
 use crate::player::components::Player;
 use crate::whisper::components::{
@@ -178,6 +178,7 @@ pub fn spawn_whisper_drop(
     game_materials: Option<Res<GameMaterials>>,
     player_query: Query<&Transform, With<Player>>,
     fresh_start: Res<crate::game::resources::FreshGameStart>,
+    mut rng: ResMut<GameRng>,
 ) {
     // Only spawn Whisper on a fresh game start
     if !fresh_start.0 {
@@ -197,8 +198,6 @@ pub fn spawn_whisper_drop(
         .map(|t| t.translation)
         .unwrap_or(Vec3::ZERO);
 
-    let mut rng = rand::thread_rng();
-
     // Spawn close to the player but outside pickup_radius (2.0) so it's visible but not auto-collected
     let angle = rng.gen_range(0.0..std::f32::consts::TAU);
     let distance = rng.gen_range(2.5..3.5);
@@ -517,6 +516,7 @@ mod tests {
         app.init_asset::<Mesh>();
         app.init_asset::<StandardMaterial>();
         app.insert_resource(FreshGameStart(true)); // Fresh start = should spawn
+        app.init_resource::<GameRng>();
 
         // Set up game meshes and materials
         {